@@ -0,0 +1,145 @@
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use memtable_core::{prelude::*, Capacity};
+
+const COL: usize = 16;
+const ROW_CNTS: [usize; 3] = [100, 1_000, 10_000];
+
+/// Wraps a table, only forwarding the required methods so that row shifts
+/// fall back to the cell-by-cell defaults of the table trait
+#[derive(Clone, Default)]
+struct CellByCell<T>(T);
+
+impl<T: Table> Table for CellByCell<T> {
+    type Data = T::Data;
+    type Row = T::Row;
+    type Column = T::Column;
+
+    fn max_row_capacity(&self) -> Capacity {
+        self.0.max_row_capacity()
+    }
+
+    fn max_column_capacity(&self) -> Capacity {
+        self.0.max_column_capacity()
+    }
+
+    fn row_cnt(&self) -> usize {
+        self.0.row_cnt()
+    }
+
+    fn col_cnt(&self) -> usize {
+        self.0.col_cnt()
+    }
+
+    fn cell(&self, row: usize, col: usize) -> Option<&Self::Data> {
+        self.0.cell(row, col)
+    }
+
+    fn mut_cell(&mut self, row: usize, col: usize) -> Option<&mut Self::Data> {
+        self.0.mut_cell(row, col)
+    }
+
+    fn insert_cell(&mut self, row: usize, col: usize, value: Self::Data) -> Option<Self::Data> {
+        self.0.insert_cell(row, col, value)
+    }
+
+    fn remove_cell(&mut self, row: usize, col: usize) -> Option<Self::Data> {
+        self.0.remove_cell(row, col)
+    }
+
+    fn set_preferred_row_cnt(&mut self, capacity: usize) {
+        self.0.set_preferred_row_cnt(capacity)
+    }
+
+    fn set_preferred_col_cnt(&mut self, capacity: usize) {
+        self.0.set_preferred_col_cnt(capacity)
+    }
+}
+
+fn make_table(row_cnt: usize) -> FixedColumnTable<usize, COL> {
+    (0..row_cnt)
+        .flat_map(|row| (0..COL).map(move |col| (row, col, row * COL + col)))
+        .collect()
+}
+
+fn bench_insert_row(c: &mut Criterion) {
+    let mut group = c.benchmark_group("FixedColumnTable::insert_row");
+    for row_cnt in ROW_CNTS.iter().copied() {
+        let table = make_table(row_cnt);
+
+        group.bench_with_input(BenchmarkId::new("arrays", row_cnt), &table, |b, table| {
+            b.iter_batched_ref(
+                || table.clone(),
+                |table| table.insert_row(0, black_box(0..COL)),
+                BatchSize::LargeInput,
+            )
+        });
+
+        let table = CellByCell(table);
+        group.bench_with_input(BenchmarkId::new("cells", row_cnt), &table, |b, table| {
+            b.iter_batched_ref(
+                || table.clone(),
+                |table| table.insert_row(0, black_box(0..COL)),
+                BatchSize::LargeInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+fn bench_remove_row(c: &mut Criterion) {
+    let mut group = c.benchmark_group("FixedColumnTable::remove_row");
+    for row_cnt in ROW_CNTS.iter().copied() {
+        let table = make_table(row_cnt);
+
+        group.bench_with_input(BenchmarkId::new("arrays", row_cnt), &table, |b, table| {
+            b.iter_batched_ref(
+                || table.clone(),
+                |table| table.remove_row(black_box(0)),
+                BatchSize::LargeInput,
+            )
+        });
+
+        let table = CellByCell(table);
+        group.bench_with_input(BenchmarkId::new("cells", row_cnt), &table, |b, table| {
+            b.iter_batched_ref(
+                || table.clone(),
+                |table| table.remove_row(black_box(0)),
+                BatchSize::LargeInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+fn bench_dynamic_remove_row(c: &mut Criterion) {
+    let mut group = c.benchmark_group("DynamicTable::remove_row");
+    for row_cnt in ROW_CNTS.iter().copied() {
+        let table: DynamicTable<usize> = make_table(row_cnt).into_iter().collect();
+
+        group.bench_with_input(BenchmarkId::new("rebuild", row_cnt), &table, |b, table| {
+            b.iter_batched_ref(
+                || table.clone(),
+                |table| table.remove_row(black_box(0)),
+                BatchSize::LargeInput,
+            )
+        });
+
+        let table = CellByCell(table);
+        group.bench_with_input(BenchmarkId::new("cells", row_cnt), &table, |b, table| {
+            b.iter_batched_ref(
+                || table.clone(),
+                |table| table.remove_row(black_box(0)),
+                BatchSize::LargeInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_insert_row,
+    bench_remove_row,
+    bench_dynamic_remove_row
+);
+criterion_main!(benches);
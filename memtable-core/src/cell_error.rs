@@ -0,0 +1,85 @@
+use core::fmt;
+
+/// Represents a cell that prevented an untyped table from being converted
+/// into a typed table, which is produced by the `TryFrom` implementation
+/// generated when deriving tables
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CellError {
+    /// Row of the offending cell
+    pub row: usize,
+
+    /// Column of the offending cell
+    pub column: usize,
+
+    /// Name of the column that the cell belongs to
+    pub name: &'static str,
+
+    /// Name of the nested column within a flattened column that the cell
+    /// belongs to, if any
+    pub nested_name: Option<&'static str>,
+
+    /// Type of data that the column expects
+    pub expected: &'static str,
+
+    /// Name of the variant found within the cell, or `None` if the cell is
+    /// missing
+    pub found: Option<&'static str>,
+}
+
+impl CellError {
+    /// Returns true if the cell is missing rather than holding the wrong
+    /// variant
+    pub fn is_missing(&self) -> bool {
+        self.found.is_none()
+    }
+}
+
+impl fmt::Display for CellError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Cell at row {}, column {} (", self.row, self.column)?;
+        match self.nested_name {
+            Some(nested_name) => write!(f, "{}_{}", self.name, nested_name)?,
+            None => write!(f, "{}", self.name)?,
+        }
+        write!(f, ") ")?;
+
+        match self.found {
+            Some(found) => write!(f, "has variant {}, but expected {}", found, self.expected),
+            None => write!(f, "is missing, but expected {}", self.expected),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CellError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::string::ToString;
+
+    #[test]
+    fn display_should_describe_missing_and_mismatched_cells() {
+        let mut err = CellError {
+            row: 1,
+            column: 2,
+            name: "age",
+            nested_name: None,
+            expected: "u8",
+            found: None,
+        };
+        assert!(err.is_missing());
+        assert_eq!(
+            err.to_string(),
+            "Cell at row 1, column 2 (age) is missing, but expected u8"
+        );
+
+        err.nested_name = Some("zip");
+        err.found = Some("Name");
+        assert!(!err.is_missing());
+        assert_eq!(
+            err.to_string(),
+            "Cell at row 1, column 2 (age_zip) has variant Name, but expected u8"
+        );
+    }
+}
@@ -0,0 +1,414 @@
+use crate::{DynamicTable, Position, Table};
+use core::{fmt, str::FromStr};
+use std::{string::String, vec::Vec};
+
+/// Represents a single cell that could not be parsed into the desired type
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CoerceFailure<E> {
+    /// Position of the cell that failed to parse
+    pub position: Position,
+
+    /// Error produced when parsing the cell
+    pub error: E,
+}
+
+/// Represents the failure to coerce one or more cells of a table into
+/// another type, retaining every cell that failed and why
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CoerceError<E> {
+    /// Cells that failed to parse, in row order
+    pub failures: Vec<CoerceFailure<E>>,
+}
+
+impl<E: fmt::Display> fmt::Display for CoerceError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Failed to coerce {} cell(s)", self.failures.len())?;
+
+        if let Some(x) = self.failures.first() {
+            write!(
+                f,
+                ", first at row {} column {}: {}",
+                x.position.row, x.position.col, x.error
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: fmt::Debug + fmt::Display> std::error::Error for CoerceError<E> {}
+
+/// Represents how numbers are written within the text of a table, used to
+/// parse numbers written for a locale other than the one expected by
+/// [`FromStr`]
+///
+/// Before a cell is parsed, its surrounding whitespace, sign, and currency
+/// symbols are trimmed, thousands separators are removed, and the decimal
+/// separator is replaced with `.`
+///
+/// ### Examples
+///
+/// ```
+/// # use memtable_core::{coerce::NumberFormat, prelude::*};
+/// let table = DynamicTable::from([["1.234,5 €"], ["-€ 7"]]);
+///
+/// let format = NumberFormat::european().with_currency_symbol("€");
+/// let typed = table.coerce_column_with::<f64>(0, &format).unwrap();
+/// assert_eq!(typed, [[1234.5], [-7.0]]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NumberFormat {
+    decimal_separator: char,
+    thousands_separator: Option<char>,
+    currency_symbols: Vec<String>,
+}
+
+impl Default for NumberFormat {
+    /// Defaults to a `.` decimal separator with no thousands separator or
+    /// currency symbols, matching what [`FromStr`] expects
+    fn default() -> Self {
+        Self {
+            decimal_separator: '.',
+            thousands_separator: None,
+            currency_symbols: Vec::new(),
+        }
+    }
+}
+
+impl NumberFormat {
+    /// Creates a new format matching what [`FromStr`] expects
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new format with a `,` decimal separator and `.` thousands
+    /// separator, as commonly written across Europe
+    pub fn european() -> Self {
+        Self::new()
+            .with_decimal_separator(',')
+            .with_thousands_separator('.')
+    }
+
+    /// Sets the character separating the whole and fractional parts of a
+    /// number
+    pub fn with_decimal_separator(self, decimal_separator: char) -> Self {
+        Self {
+            decimal_separator,
+            ..self
+        }
+    }
+
+    /// Sets the character grouping the digits of a number, which is removed
+    /// before parsing; a whitespace separator also removes any other
+    /// whitespace, such as non-breaking spaces
+    pub fn with_thousands_separator(self, thousands_separator: char) -> Self {
+        Self {
+            thousands_separator: Some(thousands_separator),
+            ..self
+        }
+    }
+
+    /// Adds a currency symbol to trim from the start or end of a number
+    pub fn with_currency_symbol(mut self, symbol: impl Into<String>) -> Self {
+        self.currency_symbols.push(symbol.into());
+        self
+    }
+
+    /// Rewrites the text of a number into the form expected by [`FromStr`]
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # use memtable_core::coerce::NumberFormat;
+    /// let format = NumberFormat::new()
+    ///     .with_thousands_separator(',')
+    ///     .with_currency_symbol("$");
+    /// assert_eq!(format.normalize(" -$1,234.50 "), "-1234.50");
+    /// ```
+    pub fn normalize(&self, s: &str) -> String {
+        let mut text = s.trim();
+        let mut sign = None;
+
+        // Signs and currency symbols can appear in either order, such as
+        // -$5 and $-5, so we strip them until nothing changes
+        loop {
+            let len = text.len();
+
+            if sign.is_none() {
+                if let Some(c) = text.chars().next().filter(|c| *c == '-' || *c == '+') {
+                    sign = Some(c);
+                    text = text[1..].trim_start();
+                }
+            }
+
+            for symbol in self.currency_symbols.iter().filter(|x| !x.is_empty()) {
+                if let Some(rest) = text.strip_prefix(symbol.as_str()) {
+                    text = rest.trim_start();
+                }
+                if let Some(rest) = text.strip_suffix(symbol.as_str()) {
+                    text = rest.trim_end();
+                }
+            }
+
+            if text.len() == len {
+                break;
+            }
+        }
+
+        let is_thousands_separator = |c: char| match self.thousands_separator {
+            Some(x) if x.is_whitespace() => c.is_whitespace(),
+            Some(x) => c == x,
+            None => false,
+        };
+
+        let mut normalized = String::with_capacity(text.len() + 1);
+        normalized.extend(sign);
+        for c in text.chars() {
+            if c == self.decimal_separator {
+                normalized.push('.');
+            } else if !is_thousands_separator(c) {
+                normalized.push(c);
+            }
+        }
+        normalized
+    }
+}
+
+/// Represents ability to parse the text of a table into typed data, which is
+/// most commonly needed after loading a table of strings such as from a CSV
+pub trait Coerce {
+    /// Parses every cell of the specified column into a new table with a
+    /// single column and the same rows, where a row without a cell in the
+    /// column has no cell in the new table; fails if any cell cannot be
+    /// parsed, reporting all of them
+    fn coerce_column<U: FromStr>(&self, col: usize)
+        -> Result<DynamicTable<U>, CoerceError<U::Err>>;
+
+    /// Parses every cell of the table into a new table of the same shape,
+    /// failing if any cell cannot be parsed and reporting all of them
+    fn coerce_cells<U: FromStr>(&self) -> Result<DynamicTable<U>, CoerceError<U::Err>>;
+
+    /// Parses every cell of the specified column like
+    /// [`Coerce::coerce_column`], first rewriting each cell from the given
+    /// number format
+    fn coerce_column_with<U: FromStr>(
+        &self,
+        col: usize,
+        format: &NumberFormat,
+    ) -> Result<DynamicTable<U>, CoerceError<U::Err>>;
+
+    /// Parses every cell of the table like [`Coerce::coerce_cells`], first
+    /// rewriting each cell from the given number format
+    fn coerce_cells_with<U: FromStr>(
+        &self,
+        format: &NumberFormat,
+    ) -> Result<DynamicTable<U>, CoerceError<U::Err>>;
+}
+
+impl<T: Table> Coerce for T
+where
+    T::Data: AsRef<str>,
+{
+    fn coerce_column<U: FromStr>(
+        &self,
+        col: usize,
+    ) -> Result<DynamicTable<U>, CoerceError<U::Err>> {
+        parse_column(self, col, str::parse)
+    }
+
+    fn coerce_cells<U: FromStr>(&self) -> Result<DynamicTable<U>, CoerceError<U::Err>> {
+        parse_cells(self, str::parse)
+    }
+
+    fn coerce_column_with<U: FromStr>(
+        &self,
+        col: usize,
+        format: &NumberFormat,
+    ) -> Result<DynamicTable<U>, CoerceError<U::Err>> {
+        parse_column(self, col, |x| format.normalize(x).parse())
+    }
+
+    fn coerce_cells_with<U: FromStr>(
+        &self,
+        format: &NumberFormat,
+    ) -> Result<DynamicTable<U>, CoerceError<U::Err>> {
+        parse_cells(self, |x| format.normalize(x).parse())
+    }
+}
+
+fn parse_column<D, T, U, E, F>(
+    table: &T,
+    col: usize,
+    f: F,
+) -> Result<DynamicTable<U>, CoerceError<E>>
+where
+    D: AsRef<str>,
+    T: Table<Data = D>,
+    F: Fn(&str) -> Result<U, E>,
+{
+    let mut typed = DynamicTable::new();
+    typed.set_preferred_row_cnt(table.row_cnt());
+    typed.set_preferred_col_cnt(1);
+
+    let mut failures = Vec::new();
+
+    for row in 0..table.row_cnt() {
+        match table.cell(row, col).map(|x| f(x.as_ref())) {
+            Some(Ok(x)) => {
+                typed.insert_cell(row, 0, x);
+            }
+            Some(Err(error)) => failures.push(CoerceFailure {
+                position: Position { row, col },
+                error,
+            }),
+            None => {}
+        }
+    }
+
+    if failures.is_empty() {
+        Ok(typed)
+    } else {
+        Err(CoerceError { failures })
+    }
+}
+
+fn parse_cells<D, T, U, E, F>(table: &T, f: F) -> Result<DynamicTable<U>, CoerceError<E>>
+where
+    D: AsRef<str>,
+    T: Table<Data = D>,
+    F: Fn(&str) -> Result<U, E>,
+{
+    let mut typed = DynamicTable::new();
+    typed.set_preferred_row_cnt(table.row_cnt());
+    typed.set_preferred_col_cnt(table.col_cnt());
+
+    let mut failures = Vec::new();
+
+    for row in 0..table.row_cnt() {
+        for col in 0..table.col_cnt() {
+            match table.cell(row, col).map(|x| f(x.as_ref())) {
+                Some(Ok(x)) => {
+                    typed.insert_cell(row, col, x);
+                }
+                Some(Err(error)) => failures.push(CoerceFailure {
+                    position: Position { row, col },
+                    error,
+                }),
+                None => {}
+            }
+        }
+    }
+
+    if failures.is_empty() {
+        Ok(typed)
+    } else {
+        Err(CoerceError { failures })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::num::ParseIntError;
+    use std::vec;
+
+    #[test]
+    fn coerce_column_should_parse_every_cell_in_column() {
+        let table = DynamicTable::from([["a", "1"], ["b", "2"]]);
+        assert_eq!(table.coerce_column::<u8>(1).unwrap(), [[1], [2]]);
+    }
+
+    #[test]
+    fn coerce_column_should_keep_rows_of_missing_cells() {
+        let mut table = DynamicTable::new();
+        table.insert_cell(0, 1, "1");
+        table.insert_cell(2, 1, "3");
+        table.insert_cell(3, 0, "x");
+
+        let typed = table.coerce_column::<u8>(1).unwrap();
+        assert_eq!(typed.row_cnt(), 4);
+        assert_eq!(typed.col_cnt(), 1);
+        assert_eq!(typed.cell(0, 0), Some(&1));
+        assert_eq!(typed.cell(1, 0), None);
+        assert_eq!(typed.cell(2, 0), Some(&3));
+        assert_eq!(typed.cell(3, 0), None);
+    }
+
+    #[test]
+    fn coerce_column_should_report_position_of_every_failure() {
+        let table = DynamicTable::from([["alice", "30"], ["bob", "x"], ["carol", "25"]]);
+        let err = table.coerce_column::<u8>(0).unwrap_err();
+        let positions: Vec<Position> = err.failures.iter().map(|x| x.position).collect();
+
+        assert_eq!(
+            positions,
+            vec![
+                Position { row: 0, col: 0 },
+                Position { row: 1, col: 0 },
+                Position { row: 2, col: 0 },
+            ]
+        );
+    }
+
+    #[test]
+    fn coerce_column_should_include_parse_error() {
+        let table = DynamicTable::from([["alice", "30"], ["bob", "x"], ["carol", "25"]]);
+        let err = table.coerce_column::<u8>(1).unwrap_err();
+        assert_eq!(
+            err.failures,
+            vec![CoerceFailure {
+                position: Position { row: 1, col: 1 },
+                error: "x".parse::<u8>().unwrap_err(),
+            }]
+        );
+    }
+
+    #[test]
+    fn coerce_cells_should_produce_table_of_same_shape() {
+        let table = DynamicTable::from([["1", "2"], ["3", "4"]]);
+        let typed = table.coerce_cells::<usize>().unwrap();
+
+        assert_eq!(typed, [[1, 2], [3, 4]]);
+    }
+
+    #[test]
+    fn coerce_cells_should_fail_if_any_cell_fails() {
+        let table = DynamicTable::from([["alice", "30"], ["bob", "x"], ["carol", "25"]]);
+        let result: Result<DynamicTable<u8>, CoerceError<ParseIntError>> = table.coerce_cells();
+        assert_eq!(result.unwrap_err().failures.len(), 4);
+    }
+
+    #[test]
+    fn coerce_column_with_should_parse_european_numbers() {
+        let table = DynamicTable::from([["1.234,5"], ["0,25"], ["12"]]);
+
+        let typed = table
+            .coerce_column_with::<f64>(0, &NumberFormat::european())
+            .unwrap();
+        assert_eq!(typed, [[1234.5], [0.25], [12.0]]);
+    }
+
+    #[test]
+    fn coerce_cells_with_should_report_cells_that_still_fail() {
+        let table = DynamicTable::from([["$1,000", "£5"], ["($2)", "3 USD"]]);
+        let format = NumberFormat::new()
+            .with_thousands_separator(',')
+            .with_currency_symbol("$")
+            .with_currency_symbol("USD");
+
+        let err = table.coerce_cells_with::<i32>(&format).unwrap_err();
+        let positions: Vec<Position> = err.failures.iter().map(|x| x.position).collect();
+        assert_eq!(
+            positions,
+            vec![Position { row: 0, col: 1 }, Position { row: 1, col: 0 }]
+        );
+    }
+
+    #[test]
+    fn normalize_should_remove_whitespace_thousands_separators() {
+        let format = NumberFormat::european().with_thousands_separator(' ');
+        assert_eq!(format.normalize("1\u{a0}234 567,8"), "1234567.8");
+        assert_eq!(format.normalize("+ 12"), "+12");
+    }
+}
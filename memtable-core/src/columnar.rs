@@ -0,0 +1,319 @@
+use crate::Table;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::vec::Vec;
+
+/// Value shared by consecutive cells within a column alongside how many cells
+/// share it, where a value of `None` represents cells that are missing
+type Run<V> = (Option<V>, usize);
+
+/// Encoding of a table as its columns, each made up of runs of cells
+#[derive(Serialize)]
+struct ColumnarRef<'a, D> {
+    row_cnt: usize,
+    col_cnt: usize,
+    columns: Vec<Vec<Run<&'a D>>>,
+}
+
+/// Owned counterpart of [`ColumnarRef`] used when deserializing
+#[derive(Deserialize)]
+struct ColumnarOwned<D> {
+    row_cnt: usize,
+    col_cnt: usize,
+    columns: Vec<Vec<Run<D>>>,
+}
+
+/// Serializes a table column by column, where consecutive cells of a column
+/// holding the same value are written once alongside how many times they
+/// repeat, which greatly shrinks wide tables whose columns are uniform
+///
+/// Use alongside [`deserialize`] via `#[serde(with = "memtable::columnar")]`
+/// on a field holding a table, or wrap a reference to the table in
+/// [`Columnar`]
+pub fn serialize<T, S>(table: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Table,
+    T::Data: Serialize + PartialEq,
+    S: Serializer,
+{
+    let row_cnt = table.row_cnt();
+    let col_cnt = table.col_cnt();
+    let mut columns = Vec::with_capacity(col_cnt);
+
+    for col in 0..col_cnt {
+        let mut runs: Vec<Run<&T::Data>> = Vec::new();
+        for row in 0..row_cnt {
+            let value = table.cell(row, col);
+            match runs.last_mut() {
+                Some((last, len)) if *last == value => *len += 1,
+                _ => runs.push((value, 1)),
+            }
+        }
+        columns.push(runs);
+    }
+
+    ColumnarRef {
+        row_cnt,
+        col_cnt,
+        columns,
+    }
+    .serialize(serializer)
+}
+
+/// Default maximum total cells, rows multiplied by columns, of a table
+/// produced by [`deserialize`]
+pub const DEFAULT_MAX_CELLS: usize = 1 << 24;
+
+/// Deserializes a table written by [`serialize`], cloning the value of each
+/// run into every cell that it covers
+///
+/// Fails if the table would span more than [`DEFAULT_MAX_CELLS`] cells, or
+/// more rows or columns than `T` can hold. Use
+/// [`deserialize_with_max_cells`] to choose a different maximum
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: Table + Default,
+    T::Data: Deserialize<'de> + Clone,
+    D: Deserializer<'de>,
+{
+    deserialize_with_max_cells(deserializer, DEFAULT_MAX_CELLS)
+}
+
+/// Deserializes a table written by [`serialize`], failing if the table would
+/// span more than `max_cells` cells, rows multiplied by columns
+///
+/// A single run can describe any number of cells in only a few bytes, so the
+/// size of the table is checked against `max_cells` and the row & column
+/// capacity of `T` before any cell is created
+pub fn deserialize_with_max_cells<'de, T, D>(
+    deserializer: D,
+    max_cells: usize,
+) -> Result<T, D::Error>
+where
+    T: Table + Default,
+    T::Data: Deserialize<'de> + Clone,
+    D: Deserializer<'de>,
+{
+    let ColumnarOwned {
+        row_cnt,
+        col_cnt,
+        columns,
+    } = ColumnarOwned::<T::Data>::deserialize(deserializer)?;
+
+    if columns.len() != col_cnt {
+        return Err(de::Error::invalid_length(
+            columns.len(),
+            &"columns matching the total columns",
+        ));
+    }
+
+    if !matches!(row_cnt.checked_mul(col_cnt), Some(cnt) if cnt <= max_cells) {
+        return Err(de::Error::custom(format_args!(
+            "table of {} rows and {} columns exceeds the maximum of {} cells",
+            row_cnt, col_cnt, max_cells
+        )));
+    }
+
+    let mut table = T::default();
+    if let Some(max) = table.max_row_capacity().limit() {
+        if row_cnt > max {
+            return Err(de::Error::custom(format_args!(
+                "table of {} rows exceeds the capacity of {} rows",
+                row_cnt, max
+            )));
+        }
+    }
+
+    if let Some(max) = table.max_column_capacity().limit() {
+        if col_cnt > max {
+            return Err(de::Error::custom(format_args!(
+                "table of {} columns exceeds the capacity of {} columns",
+                col_cnt, max
+            )));
+        }
+    }
+
+    for (col, runs) in columns.into_iter().enumerate() {
+        let mut row: usize = 0;
+        for (value, len) in runs {
+            let end = row
+                .checked_add(len)
+                .filter(|end| *end <= row_cnt)
+                .ok_or_else(|| {
+                    de::Error::invalid_value(
+                        de::Unexpected::Unsigned(len as u64),
+                        &"run fitting within the total rows",
+                    )
+                })?;
+
+            if let Some(value) = value {
+                for r in row..end {
+                    table.insert_cell(r, col, value.clone());
+                }
+            }
+            row = end;
+        }
+
+        if row != row_cnt {
+            return Err(de::Error::invalid_length(
+                row,
+                &"runs covering the total rows",
+            ));
+        }
+    }
+
+    table.set_preferred_row_cnt(row_cnt);
+    table.set_preferred_col_cnt(col_cnt);
+    Ok(table)
+}
+
+/// Represents a table that is serialized column by column with runs of
+/// repeated values, as done by [`serialize`] and [`deserialize`]
+///
+/// Serializing borrows the table, as in `Columnar(&table)`, while
+/// deserializing produces an owned table, as in `Columnar<DynamicTable<T>>`
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Columnar<T>(pub T);
+
+impl<T> Columnar<T> {
+    /// Consumes the wrapper, returning the table
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Serialize for Columnar<&T>
+where
+    T: Table,
+    T::Data: Serialize + PartialEq,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize(self.0, serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Columnar<T>
+where
+    T: Table + Default,
+    T::Data: Deserialize<'de> + Clone,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize(deserializer).map(Self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DynamicTable, FixedTable};
+    use std::string::{String, ToString};
+
+    #[test]
+    fn serialize_should_write_runs_of_each_column() {
+        let mut table = DynamicTable::from([
+            ["red".to_string(), "small".to_string()],
+            ["red".to_string(), "small".to_string()],
+            ["blue".to_string(), "small".to_string()],
+        ]);
+        table.remove_cell(0, 1);
+
+        let json = serde_json::to_value(Columnar(&table)).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "row_cnt": 3,
+                "col_cnt": 2,
+                "columns": [
+                    [["red", 2], ["blue", 1]],
+                    [[null, 1], ["small", 2]],
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn deserialize_should_round_trip_table() {
+        let mut table = DynamicTable::from([
+            ["red".to_string(), "small".to_string()],
+            ["red".to_string(), "small".to_string()],
+            ["blue".to_string(), "small".to_string()],
+        ]);
+        table.remove_cell(1, 0);
+        table.set_preferred_row_cnt(5);
+
+        let json = serde_json::to_string(&Columnar(&table)).unwrap();
+        let Columnar(new_table): Columnar<DynamicTable<String>> =
+            serde_json::from_str(&json).unwrap();
+        assert_eq!(new_table, table);
+    }
+
+    #[test]
+    fn should_support_serde_with_attribute() {
+        #[derive(Serialize, Deserialize)]
+        struct Snapshot {
+            #[serde(with = "crate::columnar")]
+            table: DynamicTable<String>,
+        }
+
+        let table = DynamicTable::from([
+            ["red".to_string(), "small".to_string()],
+            ["red".to_string(), "small".to_string()],
+            ["blue".to_string(), "small".to_string()],
+        ]);
+
+        let json = serde_json::to_string(&Snapshot {
+            table: table.clone(),
+        })
+        .unwrap();
+        let snapshot: Snapshot = serde_json::from_str(&json).unwrap();
+        assert_eq!(snapshot.table, table);
+    }
+
+    #[test]
+    fn deserialize_should_fail_if_runs_do_not_match_rows() {
+        let json = r#"{"row_cnt":2,"col_cnt":1,"columns":[[["a",3]]]}"#;
+        let result: Result<Columnar<DynamicTable<String>>, _> = serde_json::from_str(json);
+        assert!(result.is_err());
+
+        let json = r#"{"row_cnt":2,"col_cnt":1,"columns":[[["a",1]]]}"#;
+        let result: Result<Columnar<DynamicTable<String>>, _> = serde_json::from_str(json);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn deserialize_should_fail_if_columns_do_not_match_size() {
+        let json = r#"{"row_cnt":1,"col_cnt":2,"columns":[[["a",1]]]}"#;
+        let result: Result<Columnar<DynamicTable<String>>, _> = serde_json::from_str(json);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn deserialize_should_fail_if_table_exceeds_maximum_cells() {
+        let json = r#"{"row_cnt":18446744073709551615,"col_cnt":1,"columns":[[["a",18446744073709551615]]]}"#;
+        let result: Result<Columnar<DynamicTable<String>>, _> = serde_json::from_str(json);
+        assert!(result.is_err());
+
+        let json = r#"{"row_cnt":3,"col_cnt":2,"columns":[[["a",3]],[["b",3]]]}"#;
+        let result: Result<DynamicTable<String>, _> =
+            deserialize_with_max_cells(&mut serde_json::Deserializer::from_str(json), 5);
+        assert!(result.is_err());
+
+        let result: Result<DynamicTable<String>, _> =
+            deserialize_with_max_cells(&mut serde_json::Deserializer::from_str(json), 6);
+        assert_eq!(result.unwrap().cell(2, 1).map(String::as_str), Some("b"));
+    }
+
+    #[test]
+    fn deserialize_should_fail_if_table_exceeds_capacity() {
+        let json = r#"{"row_cnt":2,"col_cnt":1,"columns":[[["a",2]]]}"#;
+        let result: Result<Columnar<FixedTable<String, 1, 1>>, _> = serde_json::from_str(json);
+        assert!(result.is_err());
+
+        let json = r#"{"row_cnt":1,"col_cnt":2,"columns":[[["a",1]],[["b",1]]]}"#;
+        let result: Result<Columnar<FixedTable<String, 1, 1>>, _> = serde_json::from_str(json);
+        assert!(result.is_err());
+
+        let json = r#"{"row_cnt":1,"col_cnt":1,"columns":[[["a",1]]]}"#;
+        let result: Result<Columnar<FixedTable<String, 1, 1>>, _> = serde_json::from_str(json);
+        assert_eq!(result.unwrap().0.cell(0, 0).map(String::as_str), Some("a"));
+    }
+}
@@ -0,0 +1,223 @@
+use crate::{Position, Table};
+
+/// Represents a movable position within a table that can read and write the
+/// cell it points to, allowing a table to be walked in irregular patterns
+/// without tracking raw indexes
+///
+/// The cursor may point outside of the table, in which case reads return
+/// nothing. Rows and columns inserted or removed through the cursor shift
+/// its position so that it keeps pointing at the same cell where possible
+#[derive(Debug)]
+pub struct TableCursor<'a, D, T: Table<Data = D>> {
+    table: &'a mut T,
+    pos: Position,
+}
+
+impl<'a, D, T: Table<Data = D>> TableCursor<'a, D, T> {
+    /// Creates a new cursor pointing at the specified position of the table
+    pub fn new(table: &'a mut T, pos: Position) -> Self {
+        Self { table, pos }
+    }
+
+    /// Returns the position the cursor points at
+    pub fn position(&self) -> Position {
+        self.pos
+    }
+
+    /// Returns true if the cursor points within the rows and columns of the
+    /// table
+    pub fn is_valid(&self) -> bool {
+        self.pos.row < self.table.row_cnt() && self.pos.col < self.table.col_cnt()
+    }
+
+    /// Moves the cursor to the specified position, returning true if the
+    /// position is within the table
+    pub fn seek(&mut self, pos: Position) -> bool {
+        self.pos = pos;
+        self.is_valid()
+    }
+
+    /// Returns reference to the cell the cursor points at
+    pub fn peek(&self) -> Option<&D> {
+        self.table.cell(self.pos.row, self.pos.col)
+    }
+
+    /// Returns mutable reference to the cell the cursor points at
+    pub fn peek_mut(&mut self) -> Option<&mut D> {
+        self.table.mut_cell(self.pos.row, self.pos.col)
+    }
+
+    /// Replaces the given value into the cell the cursor points at,
+    /// returning the previous value contained in the cell
+    pub fn set(&mut self, value: D) -> Option<D> {
+        self.table.insert_cell(self.pos.row, self.pos.col, value)
+    }
+
+    /// Removes the value from the cell the cursor points at, returning the
+    /// value if it existed
+    pub fn take(&mut self) -> Option<D> {
+        self.table.remove_cell(self.pos.row, self.pos.col)
+    }
+
+    /// Moves the cursor to the next cell in its row, returning the new
+    /// position, or leaves the cursor in place and returns none if it is at
+    /// the last column
+    pub fn next_in_row(&mut self) -> Option<Position> {
+        self.move_to(self.pos.row, self.pos.col.checked_add(1))
+    }
+
+    /// Moves the cursor to the previous cell in its row, returning the new
+    /// position, or leaves the cursor in place and returns none if it is at
+    /// the first column
+    pub fn prev_in_row(&mut self) -> Option<Position> {
+        self.move_to(self.pos.row, self.pos.col.checked_sub(1))
+    }
+
+    /// Moves the cursor to the next cell in its column, returning the new
+    /// position, or leaves the cursor in place and returns none if it is at
+    /// the last row
+    pub fn next_in_column(&mut self) -> Option<Position> {
+        let row = self.pos.row.checked_add(1)?;
+        self.move_to(row, Some(self.pos.col))
+    }
+
+    /// Moves the cursor to the previous cell in its column, returning the new
+    /// position, or leaves the cursor in place and returns none if it is at
+    /// the first row
+    pub fn prev_in_column(&mut self) -> Option<Position> {
+        let row = self.pos.row.checked_sub(1)?;
+        self.move_to(row, Some(self.pos.col))
+    }
+
+    fn move_to(&mut self, row: usize, col: Option<usize>) -> Option<Position> {
+        let pos = Position::new(row, col?);
+        if pos.row < self.table.row_cnt() && pos.col < self.table.col_cnt() {
+            self.pos = pos;
+            Some(pos)
+        } else {
+            None
+        }
+    }
+
+    /// Inserts a row at the cursor's row, moving the cursor down so that it
+    /// keeps pointing at the same cell
+    ///
+    /// The cursor stays in place if the table did not gain a row, such as
+    /// when it is already at its maximum row capacity
+    pub fn insert_row<I: IntoIterator<Item = D>>(&mut self, cells: I) {
+        let row_cnt = self.table.row_cnt();
+        self.table.insert_row(self.pos.row, cells);
+        if self.table.row_cnt() > row_cnt {
+            self.pos.row += 1;
+        }
+    }
+
+    /// Removes the cursor's row, leaving the cursor pointing at the row that
+    /// took its place
+    pub fn remove_row(&mut self) -> Option<T::Row> {
+        self.table.remove_row(self.pos.row)
+    }
+
+    /// Inserts a column at the cursor's column, moving the cursor right so
+    /// that it keeps pointing at the same cell
+    ///
+    /// The cursor stays in place if the table did not gain a column, such as
+    /// when it is already at its maximum column capacity
+    pub fn insert_column<I: IntoIterator<Item = D>>(&mut self, cells: I) {
+        let col_cnt = self.table.col_cnt();
+        self.table.insert_column(self.pos.col, cells);
+        if self.table.col_cnt() > col_cnt {
+            self.pos.col += 1;
+        }
+    }
+
+    /// Removes the cursor's column, leaving the cursor pointing at the column
+    /// that took its place
+    pub fn remove_column(&mut self) -> Option<T::Column> {
+        self.table.remove_column(self.pos.col)
+    }
+
+    /// Returns reference to the table the cursor walks
+    pub fn table(&self) -> &T {
+        self.table
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FixedTable;
+
+    #[test]
+    fn should_walk_rows_and_columns() {
+        let mut table = FixedTable::from([[1, 2, 3], [4, 5, 6]]);
+        let mut cursor = TableCursor::new(&mut table, Position::new(0, 0));
+
+        assert_eq!(cursor.peek(), Some(&1));
+        assert_eq!(cursor.next_in_row(), Some(Position::new(0, 1)));
+        assert_eq!(cursor.next_in_column(), Some(Position::new(1, 1)));
+        assert_eq!(cursor.peek(), Some(&5));
+
+        assert_eq!(cursor.next_in_column(), None);
+        assert_eq!(cursor.next_in_row(), Some(Position::new(1, 2)));
+        assert_eq!(cursor.next_in_row(), None);
+        assert_eq!(cursor.position(), Position::new(1, 2));
+
+        assert_eq!(cursor.prev_in_column(), Some(Position::new(0, 2)));
+        assert_eq!(cursor.prev_in_row(), Some(Position::new(0, 1)));
+        assert_eq!(cursor.prev_in_column(), None);
+    }
+
+    #[test]
+    fn seek_should_report_if_position_is_within_table() {
+        let mut table = FixedTable::from([[1, 2], [3, 4]]);
+        let mut cursor = TableCursor::new(&mut table, Position::new(0, 0));
+
+        assert!(cursor.seek(Position::new(1, 0)));
+        assert_eq!(cursor.peek(), Some(&3));
+
+        assert!(!cursor.seek(Position::new(2, 0)));
+        assert!(!cursor.is_valid());
+        assert_eq!(cursor.peek(), None);
+    }
+
+    #[test]
+    fn should_write_cell_at_position() {
+        let mut table = FixedTable::from([[1, 2], [3, 4]]);
+        let mut cursor = TableCursor::new(&mut table, Position::new(1, 1));
+
+        assert_eq!(cursor.set(5), Some(4));
+        *cursor.peek_mut().unwrap() *= 2;
+        cursor.prev_in_row();
+        assert_eq!(cursor.take(), Some(3));
+
+        assert_eq!(table, [[1, 2], [0, 10]]);
+    }
+
+    #[test]
+    fn should_stay_in_place_if_table_cannot_grow() {
+        let mut table = FixedTable::from([[1, 2], [3, 4]]);
+        let mut cursor = TableCursor::new(&mut table, Position::new(1, 1));
+
+        cursor.insert_row([5, 6]);
+        cursor.insert_column([7, 8]);
+        assert_eq!(cursor.position(), Position::new(1, 1));
+        assert!(cursor.is_valid());
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[test]
+    fn should_keep_pointing_at_same_cell_across_insertions() {
+        let mut table = crate::DynamicTable::from([[1, 2], [3, 4]]);
+        let mut cursor = TableCursor::new(&mut table, Position::new(1, 1));
+
+        cursor.insert_row(std::vec![5, 6]);
+        cursor.insert_column(std::vec![7, 8, 9]);
+        assert_eq!(cursor.position(), Position::new(2, 2));
+        assert_eq!(cursor.peek(), Some(&4));
+
+        assert!(cursor.remove_row().is_some());
+        assert!(!cursor.is_valid());
+        assert_eq!(cursor.table().row_cnt(), 2);
+    }
+}
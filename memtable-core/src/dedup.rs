@@ -0,0 +1,248 @@
+use crate::Table;
+use core::{
+    hash::Hash,
+    ops::{Deref, DerefMut},
+};
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+use std::{collections::HashMap, vec::Vec};
+
+/// Encoding of a table that references each of its distinct values by index
+#[derive(Serialize)]
+struct DedupRef<'a, D> {
+    row_cnt: usize,
+    col_cnt: usize,
+    values: Vec<&'a D>,
+    cells: Vec<Option<usize>>,
+}
+
+/// Owned counterpart of [`DedupRef`] used when deserializing
+#[derive(Deserialize)]
+struct DedupOwned<D> {
+    row_cnt: usize,
+    col_cnt: usize,
+    values: Vec<D>,
+    cells: Vec<Option<usize>>,
+}
+
+/// Serializes a table by writing each distinct value once and having every
+/// cell refer to its value by index, which greatly shrinks tables with many
+/// repeated values
+///
+/// Use alongside [`deserialize`] via `#[serde(with = "memtable::dedup")]`
+/// on a field holding a table, or wrap the table in [`Deduplicated`]
+pub fn serialize<T, S>(table: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Table,
+    T::Data: Serialize + Eq + Hash,
+    S: Serializer,
+{
+    let row_cnt = table.row_cnt();
+    let col_cnt = table.col_cnt();
+    let mut values = Vec::new();
+    let mut indexes = HashMap::new();
+    let cell_cnt = row_cnt
+        .checked_mul(col_cnt)
+        .ok_or_else(|| ser::Error::custom("total cells of table overflow usize"))?;
+    let mut cells = Vec::with_capacity(cell_cnt);
+
+    for row in 0..row_cnt {
+        for col in 0..col_cnt {
+            cells.push(table.cell(row, col).map(|value| {
+                *indexes.entry(value).or_insert_with(|| {
+                    values.push(value);
+                    values.len() - 1
+                })
+            }));
+        }
+    }
+
+    DedupRef {
+        row_cnt,
+        col_cnt,
+        values,
+        cells,
+    }
+    .serialize(serializer)
+}
+
+/// Deserializes a table written by [`serialize`], cloning each distinct
+/// value into every cell that refers to it
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: Table + Default,
+    T::Data: Deserialize<'de> + Clone,
+    D: Deserializer<'de>,
+{
+    let DedupOwned {
+        row_cnt,
+        col_cnt,
+        values,
+        cells,
+    } = DedupOwned::deserialize(deserializer)?;
+
+    let cell_cnt = row_cnt.checked_mul(col_cnt).ok_or_else(|| {
+        de::Error::invalid_value(
+            de::Unexpected::Unsigned(row_cnt as u64),
+            &"total rows whose cells fit within usize",
+        )
+    })?;
+
+    if cells.len() != cell_cnt {
+        return Err(de::Error::invalid_length(
+            cells.len(),
+            &"cells matching the total rows and columns",
+        ));
+    }
+
+    let mut table = T::default();
+    for (i, idx) in cells.into_iter().enumerate() {
+        if let Some(idx) = idx {
+            let value: &T::Data = values.get(idx).ok_or_else(|| {
+                de::Error::invalid_value(de::Unexpected::Unsigned(idx as u64), &"index of a value")
+            })?;
+            table.insert_cell(i / col_cnt, i % col_cnt, value.clone());
+        }
+    }
+
+    table.set_preferred_row_cnt(row_cnt);
+    table.set_preferred_col_cnt(col_cnt);
+    Ok(table)
+}
+
+/// Represents a table that is serialized with each distinct value written
+/// once, as done by [`serialize`] and [`deserialize`]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Deduplicated<T>(pub T);
+
+impl<T> Deduplicated<T> {
+    /// Consumes the wrapper, returning the table
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Deduplicated<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Deduplicated<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> Serialize for Deduplicated<T>
+where
+    T: Table,
+    T::Data: Serialize + Eq + Hash,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize(&self.0, serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Deduplicated<T>
+where
+    T: Table + Default,
+    T::Data: Deserialize<'de> + Clone,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize(deserializer).map(Self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DynamicTable;
+    use std::{
+        format,
+        string::{String, ToString},
+    };
+
+    #[test]
+    fn serialize_should_write_each_distinct_value_once() {
+        let mut table = DynamicTable::from([["red", "small"], ["red", "large"], ["blue", "small"]]);
+        table.remove_cell(2, 1);
+
+        let json = serde_json::to_value(Deduplicated(table)).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "row_cnt": 3,
+                "col_cnt": 2,
+                "values": ["red", "small", "large", "blue"],
+                "cells": [0, 1, 0, 2, 3, null],
+            })
+        );
+    }
+
+    #[test]
+    fn deserialize_should_round_trip_table() {
+        let mut table: DynamicTable<String> = DynamicTable::new();
+        table.push_row(vec!["red".to_string(), "small".to_string()]);
+        table.push_row(vec!["red".to_string(), "large".to_string()]);
+        table.remove_cell(0, 0);
+
+        let json = serde_json::to_string(&Deduplicated(table.clone())).unwrap();
+        let Deduplicated(new_table): Deduplicated<DynamicTable<String>> =
+            serde_json::from_str(&json).unwrap();
+        assert_eq!(new_table, table);
+    }
+
+    #[test]
+    fn deserialize_should_support_values_borrowed_from_input() {
+        let json = r#"{"row_cnt":1,"col_cnt":2,"values":["a"],"cells":[0,0]}"#;
+        let Deduplicated(table): Deduplicated<DynamicTable<&str>> =
+            serde_json::from_str(json).unwrap();
+        assert_eq!(table, [["a", "a"]]);
+    }
+
+    #[test]
+    fn should_support_serde_with_attribute() {
+        #[derive(Serialize, Deserialize)]
+        struct Snapshot {
+            #[serde(with = "crate::dedup")]
+            table: DynamicTable<String>,
+        }
+
+        let mut table = DynamicTable::new();
+        table.push_row(vec!["red".to_string(), "small".to_string()]);
+        table.push_row(vec!["blue".to_string(), "small".to_string()]);
+
+        let json = serde_json::to_string(&Snapshot {
+            table: table.clone(),
+        })
+        .unwrap();
+        let snapshot: Snapshot = serde_json::from_str(&json).unwrap();
+        assert_eq!(snapshot.table, table);
+    }
+
+    #[test]
+    fn deserialize_should_fail_if_cell_refers_to_missing_value() {
+        let json = r#"{"row_cnt":1,"col_cnt":1,"values":[],"cells":[0]}"#;
+        let result: Result<Deduplicated<DynamicTable<String>>, _> = serde_json::from_str(json);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn deserialize_should_fail_if_cells_do_not_match_size() {
+        let json = r#"{"row_cnt":2,"col_cnt":1,"values":["a"],"cells":[0]}"#;
+        let result: Result<Deduplicated<DynamicTable<String>>, _> = serde_json::from_str(json);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn deserialize_should_fail_if_total_cells_overflow() {
+        let json = format!(
+            r#"{{"row_cnt":{},"col_cnt":2,"values":[],"cells":[]}}"#,
+            usize::MAX
+        );
+        let result: Result<Deduplicated<DynamicTable<String>>, _> = serde_json::from_str(&json);
+        assert!(result.is_err());
+    }
+}
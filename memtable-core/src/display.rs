@@ -0,0 +1,313 @@
+use crate::{text, Table};
+use core::{
+    cmp,
+    fmt::{self, Write},
+};
+use std::{
+    borrow::Cow,
+    string::{String, ToString},
+};
+
+/// Represents the style used when rendering a table as text
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TextStyle {
+    /// Renders the table as a plain grid whose columns are separated by `|`
+    /// and whose headers are underlined
+    Grid,
+
+    /// Renders the table as a GitHub-flavored markdown table
+    Markdown,
+}
+
+impl Default for TextStyle {
+    /// Defaults to a plain grid
+    fn default() -> Self {
+        Self::Grid
+    }
+}
+
+/// Represents a table that can be displayed as aligned text, optionally
+/// including a row of headers
+///
+/// Missing cells are rendered as blank, and each column is padded to the
+/// width of its widest cell or header, measured in characters
+#[derive(Debug)]
+pub struct TextTable<'a, D, T: Table<Data = D>> {
+    table: &'a T,
+    headers: Option<&'a [&'a str]>,
+    style: TextStyle,
+}
+
+impl<'a, D, T: Table<Data = D>> TextTable<'a, D, T> {
+    /// Creates a new text rendering of the table in the grid style without
+    /// any headers
+    pub fn new(table: &'a T) -> Self {
+        Self {
+            table,
+            headers: None,
+            style: TextStyle::default(),
+        }
+    }
+
+    /// Sets the headers to render above the table's rows
+    pub fn with_headers(self, headers: &'a [&'a str]) -> Self {
+        Self {
+            headers: Some(headers),
+            ..self
+        }
+    }
+
+    /// Sets the style used to render the table
+    pub fn with_style(self, style: TextStyle) -> Self {
+        Self { style, ..self }
+    }
+
+    /// Returns the total columns rendered, which covers both the table's
+    /// columns and its headers
+    fn col_cnt(&self) -> usize {
+        let header_cnt = self.headers.map(<[&str]>::len).unwrap_or_default();
+        self.table.col_cnt().max(header_cnt)
+    }
+
+    fn header(&self, col: usize) -> &'a str {
+        self.headers.and_then(|x| x.get(col)).copied().unwrap_or("")
+    }
+
+    /// Returns the header as written, which is escaped for markdown
+    fn header_text(&self, col: usize) -> Cow<'a, str> {
+        match self.style {
+            TextStyle::Grid => Cow::Borrowed(self.header(col)),
+            TextStyle::Markdown => text::escape_markdown(self.header(col)),
+        }
+    }
+}
+
+impl<'a, D: fmt::Display, T: Table<Data = D>> TextTable<'a, D, T> {
+    fn write_line(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        widths: &[usize],
+        mut write_cell: impl FnMut(&mut fmt::Formatter<'_>, usize) -> Result<usize, fmt::Error>,
+    ) -> fmt::Result {
+        let is_markdown = self.style == TextStyle::Markdown;
+        let col_cnt = self.col_cnt();
+
+        if is_markdown {
+            f.write_str("| ")?;
+        }
+
+        for (col, width) in widths.iter().copied().enumerate() {
+            if col > 0 {
+                f.write_str(" | ")?;
+            }
+
+            let written = write_cell(f, col)?;
+
+            // Grid style skips padding the last column to avoid trailing space
+            if is_markdown || col + 1 < col_cnt {
+                write_padding(f, ' ', width.saturating_sub(written))?;
+            }
+        }
+
+        if is_markdown {
+            f.write_str(" |")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, D: fmt::Display, T: Table<Data = D>> fmt::Display for TextTable<'a, D, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let col_cnt = self.col_cnt();
+        let is_markdown = self.style == TextStyle::Markdown;
+
+        // Markdown cells are measured after escaping as that is what gets
+        // written
+        let mut widths = if is_markdown {
+            (0..self.table.col_cnt())
+                .map(|col| {
+                    (0..self.table.row_cnt())
+                        .filter_map(|row| self.table.cell(row, col))
+                        .map(|x| markdown_text(x).chars().count())
+                        .fold(0, usize::max)
+                })
+                .collect()
+        } else {
+            self.table.column_display_widths(None)
+        };
+        widths.resize(col_cnt, 0);
+        for (col, width) in widths.iter_mut().enumerate() {
+            *width = cmp::max(*width, self.header_text(col).chars().count());
+        }
+
+        let mut is_first_line = true;
+
+        // Markdown tables require a header row, so we render blank headers
+        // when none are provided
+        if self.headers.is_some() || is_markdown {
+            self.write_line(f, &widths, |f, col| {
+                let header = self.header_text(col);
+                f.write_str(&header)?;
+                Ok(header.chars().count())
+            })?;
+            f.write_char('\n')?;
+
+            let (start, sep, end) = if is_markdown {
+                ("|", "|", "|")
+            } else {
+                ("", "+", "")
+            };
+
+            f.write_str(start)?;
+            for (col, width) in widths.iter().copied().enumerate() {
+                if col > 0 {
+                    f.write_str(sep)?;
+                }
+
+                // Columns are surrounded by a space on each side except for
+                // the outer edges of the grid style
+                let extra = match (is_markdown, col == 0, col + 1 == col_cnt) {
+                    (true, _, _) => 2,
+                    (false, true, true) => 0,
+                    (false, true, false) | (false, false, true) => 1,
+                    (false, false, false) => 2,
+                };
+                write_padding(f, '-', width + extra)?;
+            }
+            f.write_str(end)?;
+
+            is_first_line = false;
+        }
+
+        for row in 0..self.table.row_cnt() {
+            if !is_first_line {
+                f.write_char('\n')?;
+            }
+            is_first_line = false;
+
+            self.write_line(f, &widths, |f, col| match self.table.cell(row, col) {
+                Some(x) if is_markdown => {
+                    let text = markdown_text(x);
+                    f.write_str(&text)?;
+                    Ok(text.chars().count())
+                }
+                Some(x) => {
+                    write!(f, "{}", x)?;
+                    Ok(text_width(x))
+                }
+                None => Ok(0),
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the text of the value escaped for use within a markdown table
+fn markdown_text<D: fmt::Display>(value: D) -> String {
+    text::escape_markdown(&value.to_string()).into_owned()
+}
+
+/// Returns the total characters written when displaying the value
+pub(crate) fn text_width<D: fmt::Display>(value: D) -> usize {
+    struct Counter(usize);
+
+    impl Write for Counter {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            self.0 += s.chars().count();
+            Ok(())
+        }
+    }
+
+    let mut counter = Counter(0);
+    let _ = write!(counter, "{}", value);
+    counter.0
+}
+
+fn write_padding(f: &mut fmt::Formatter<'_>, c: char, cnt: usize) -> fmt::Result {
+    for _ in 0..cnt {
+        f.write_char(c)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FixedTable, Table};
+    use std::{format, string::String};
+
+    #[test]
+    fn should_render_rows_as_aligned_grid() {
+        let table = FixedTable::from([["a", "bbb"], ["cc", "d"]]);
+        let text = format!("{}", TextTable::new(&table));
+        assert_eq!(text, "a  | bbb\ncc | d");
+    }
+
+    #[test]
+    fn should_render_headers_above_grid() {
+        let table = FixedTable::from([[1, 2], [345, 6]]);
+        let text = format!("{}", TextTable::new(&table).with_headers(&["first", "b"]));
+        assert_eq!(text, "first | b\n------+--\n1     | 2\n345   | 6");
+    }
+
+    #[test]
+    fn should_render_missing_cells_as_blank() {
+        let mut table = FixedTable::from([["a", "b"], ["c", "d"]]);
+        table.remove_cell(0, 0);
+        let text = format!("{}", TextTable::new(&table));
+        assert_eq!(text, "  | b\nc | d");
+    }
+
+    #[test]
+    fn should_render_markdown_table() {
+        let table = FixedTable::from([["a", "bbb"]]);
+        let text = format!(
+            "{}",
+            TextTable::new(&table)
+                .with_headers(&["x", "y"])
+                .with_style(TextStyle::Markdown)
+        );
+        assert_eq!(text, "| x | y   |\n|---|-----|\n| a | bbb |");
+    }
+
+    #[test]
+    fn should_escape_cells_of_markdown_table() {
+        let table = FixedTable::from([["a|b", "c\nd"]]);
+        let text = format!(
+            "{}",
+            TextTable::new(&table)
+                .with_headers(&["x|y", "z"])
+                .with_style(TextStyle::Markdown)
+        );
+        assert_eq!(
+            text,
+            "| x\\|y | z      |\n|------|--------|\n| a\\|b | c<br>d |"
+        );
+    }
+
+    #[test]
+    fn should_render_blank_headers_for_markdown_table_without_headers() {
+        let table = FixedTable::from([["a"]]);
+        let text = format!("{}", TextTable::new(&table).with_style(TextStyle::Markdown));
+        assert_eq!(text, "|   |\n|---|\n| a |");
+    }
+
+    #[test]
+    fn should_render_headers_of_empty_table() {
+        let table = FixedTable::<String, 0, 0>::new();
+        let text = format!("{}", TextTable::new(&table).with_headers(&["a", "b"]));
+        assert_eq!(text, "a | b\n--+--");
+    }
+
+    #[test]
+    fn column_display_widths_should_measure_rendered_text() {
+        let mut table = FixedTable::from([["é", "bb", ""], ["cccc", "d", ""]]);
+        table.remove_cell(0, 1);
+
+        assert_eq!(table.column_display_widths(None), [4, 1, 0]);
+        assert_eq!(table.column_display_widths(Some(1)), [1, 0, 0]);
+        assert_eq!(table.column_display_widths(Some(0)), [0, 0, 0]);
+    }
+}
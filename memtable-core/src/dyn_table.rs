@@ -0,0 +1,225 @@
+use crate::{list::DynamicList, Capacity, Table};
+use std::boxed::Box;
+
+/// Represents an iterator of refs through cells that has had its type erased
+pub type DynCells<'a, D> = Box<dyn Iterator<Item = &'a D> + 'a>;
+
+/// Represents the subset of [`Table`] that can be used as a trait object,
+/// which allows tables of different types to be stored together, such as
+/// `Vec<Box<dyn DynTable<Data = String>>>`
+///
+/// Every table implements this trait, and a boxed trait object implements
+/// [`Table`] in turn, so the full set of table methods remains available.
+/// Methods are prefixed with `dyn_` to avoid clashing with those of
+/// [`Table`] when both traits are in scope
+pub trait DynTable {
+    /// The type of data stored in individual cells within the table
+    type Data;
+
+    /// Returns the maximum row capacity of the table
+    fn dyn_max_row_capacity(&self) -> Capacity;
+
+    /// Returns the maximum column capacity of the table
+    fn dyn_max_column_capacity(&self) -> Capacity;
+
+    /// Returns the total rows contained in the table
+    fn dyn_row_cnt(&self) -> usize;
+
+    /// Returns the total columns contained in the table
+    fn dyn_col_cnt(&self) -> usize;
+
+    /// Updates the preferred row count of the table
+    fn dyn_set_preferred_row_cnt(&mut self, cnt: usize);
+
+    /// Updates the preferred column count of the table
+    fn dyn_set_preferred_col_cnt(&mut self, cnt: usize);
+
+    /// Removes all cells outside of the current row & column counts
+    fn dyn_truncate(&mut self);
+
+    /// Adjusts the row & column counts to fit the cells held by the table
+    fn dyn_shrink_to_fit(&mut self);
+
+    /// Returns reference to the cell found at the specified row and column
+    fn dyn_cell(&self, row: usize, col: usize) -> Option<&Self::Data>;
+
+    /// Returns mutable reference to the cell found at the specified row and
+    /// column
+    fn dyn_mut_cell(&mut self, row: usize, col: usize) -> Option<&mut Self::Data>;
+
+    /// Replaces the given value into the cell of the table at the specified
+    /// row and column, returning the previous value contained in the cell
+    fn dyn_insert_cell(&mut self, row: usize, col: usize, value: Self::Data) -> Option<Self::Data>;
+
+    /// Removes the given value from the cell at the specified position,
+    /// returning the value if it existed
+    fn dyn_remove_cell(&mut self, row: usize, col: usize) -> Option<Self::Data>;
+
+    /// Returns an iterator of refs through a specific row in the table
+    fn dyn_row(&self, idx: usize) -> DynCells<'_, Self::Data>;
+
+    /// Returns an iterator through all rows in the table, each of which is
+    /// an iterator of refs through the row's cells
+    fn dyn_rows(&self) -> Box<dyn Iterator<Item = DynCells<'_, Self::Data>> + '_>;
+}
+
+impl<T: Table> DynTable for T {
+    type Data = T::Data;
+
+    fn dyn_max_row_capacity(&self) -> Capacity {
+        self.max_row_capacity()
+    }
+
+    fn dyn_max_column_capacity(&self) -> Capacity {
+        self.max_column_capacity()
+    }
+
+    fn dyn_row_cnt(&self) -> usize {
+        self.row_cnt()
+    }
+
+    fn dyn_col_cnt(&self) -> usize {
+        self.col_cnt()
+    }
+
+    fn dyn_set_preferred_row_cnt(&mut self, cnt: usize) {
+        self.set_preferred_row_cnt(cnt)
+    }
+
+    fn dyn_set_preferred_col_cnt(&mut self, cnt: usize) {
+        self.set_preferred_col_cnt(cnt)
+    }
+
+    fn dyn_truncate(&mut self) {
+        self.truncate()
+    }
+
+    fn dyn_shrink_to_fit(&mut self) {
+        self.shrink_to_fit()
+    }
+
+    fn dyn_cell(&self, row: usize, col: usize) -> Option<&Self::Data> {
+        self.cell(row, col)
+    }
+
+    fn dyn_mut_cell(&mut self, row: usize, col: usize) -> Option<&mut Self::Data> {
+        self.mut_cell(row, col)
+    }
+
+    fn dyn_insert_cell(&mut self, row: usize, col: usize, value: Self::Data) -> Option<Self::Data> {
+        self.insert_cell(row, col, value)
+    }
+
+    fn dyn_remove_cell(&mut self, row: usize, col: usize) -> Option<Self::Data> {
+        self.remove_cell(row, col)
+    }
+
+    fn dyn_row(&self, idx: usize) -> DynCells<'_, Self::Data> {
+        Box::new(self.row(idx))
+    }
+
+    fn dyn_rows(&self) -> Box<dyn Iterator<Item = DynCells<'_, Self::Data>> + '_> {
+        Box::new((0..self.row_cnt()).map(move |idx| self.dyn_row(idx)))
+    }
+}
+
+impl<'a, D> Table for Box<dyn DynTable<Data = D> + 'a> {
+    type Data = D;
+    type Row = DynamicList<D>;
+    type Column = DynamicList<D>;
+
+    fn max_row_capacity(&self) -> Capacity {
+        (**self).dyn_max_row_capacity()
+    }
+
+    fn max_column_capacity(&self) -> Capacity {
+        (**self).dyn_max_column_capacity()
+    }
+
+    fn row_cnt(&self) -> usize {
+        (**self).dyn_row_cnt()
+    }
+
+    fn col_cnt(&self) -> usize {
+        (**self).dyn_col_cnt()
+    }
+
+    fn set_preferred_row_cnt(&mut self, cnt: usize) {
+        (**self).dyn_set_preferred_row_cnt(cnt)
+    }
+
+    fn set_preferred_col_cnt(&mut self, cnt: usize) {
+        (**self).dyn_set_preferred_col_cnt(cnt)
+    }
+
+    fn truncate(&mut self) {
+        (**self).dyn_truncate()
+    }
+
+    fn shrink_to_fit(&mut self) {
+        (**self).dyn_shrink_to_fit()
+    }
+
+    fn cell(&self, row: usize, col: usize) -> Option<&Self::Data> {
+        (**self).dyn_cell(row, col)
+    }
+
+    fn mut_cell(&mut self, row: usize, col: usize) -> Option<&mut Self::Data> {
+        (**self).dyn_mut_cell(row, col)
+    }
+
+    fn insert_cell(&mut self, row: usize, col: usize, value: Self::Data) -> Option<Self::Data> {
+        (**self).dyn_insert_cell(row, col, value)
+    }
+
+    fn remove_cell(&mut self, row: usize, col: usize) -> Option<Self::Data> {
+        (**self).dyn_remove_cell(row, col)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DynamicTable, FixedTable};
+    use std::vec::Vec;
+
+    #[test]
+    fn should_support_tables_of_different_types_together() {
+        let tables: Vec<Box<dyn DynTable<Data = u8>>> = std::vec![
+            Box::new(DynamicTable::from([[1, 2], [3, 4]])),
+            Box::new(FixedTable::from([[5, 6, 7]])),
+        ];
+
+        assert_eq!(tables[0].dyn_row_cnt(), 2);
+        assert_eq!(tables[1].dyn_col_cnt(), 3);
+        assert_eq!(tables[1].dyn_cell(0, 2), Some(&7));
+
+        let rows: Vec<Vec<u8>> = tables[0]
+            .dyn_rows()
+            .map(|row| row.copied().collect())
+            .collect();
+        assert_eq!(rows, [[1, 2], [3, 4]]);
+    }
+
+    #[test]
+    fn boxed_trait_object_should_support_full_table_api() {
+        let mut tables: Vec<Box<dyn DynTable<Data = u8>>> = std::vec![
+            Box::new(DynamicTable::from([[1, 2], [3, 4]])),
+            Box::new(FixedTable::from([[5, 6, 7]])),
+        ];
+
+        tables[0].push_row(std::vec![8, 9]);
+        assert_eq!(tables[0].row_cnt(), 3);
+        assert_eq!(tables[0].remove_row(0).unwrap(), [1, 2]);
+        assert_eq!(
+            tables[0].cells().copied().collect::<Vec<u8>>(),
+            [3, 4, 8, 9]
+        );
+
+        assert_eq!(tables[1].insert_cell(0, 0, 10), Some(5));
+        assert_eq!(
+            tables[1].dyn_row(0).copied().collect::<Vec<u8>>(),
+            [10, 6, 7]
+        );
+    }
+}
@@ -0,0 +1,385 @@
+use crate::Position;
+use core::{fmt, str::FromStr};
+
+/// Represents the failure to parse a spreadsheet-style cell reference
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "docs", doc(cfg(a1)))]
+pub enum A1Error {
+    /// Reference has no column letters (e.g. `"7"`)
+    MissingColumn,
+
+    /// Reference has no row number (e.g. `"B"`)
+    MissingRow,
+
+    /// Reference contains a character that does not belong (e.g. `"B7!"`)
+    InvalidCharacter(char),
+
+    /// Reference has a row number of zero, as rows start at one
+    ZeroRow,
+
+    /// Reference has a row or column too large to be represented
+    Overflow,
+
+    /// Range does not have exactly one `:` between two references
+    InvalidRange,
+}
+
+impl fmt::Display for A1Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingColumn => write!(f, "Reference is missing column letters"),
+            Self::MissingRow => write!(f, "Reference is missing row number"),
+            Self::InvalidCharacter(c) => write!(f, "Reference contains invalid character {:?}", c),
+            Self::ZeroRow => write!(f, "Reference has row 0, but rows start at 1"),
+            Self::Overflow => write!(f, "Reference is too large"),
+            Self::InvalidRange => write!(f, "Range must be two references separated by ':'"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for A1Error {}
+
+/// Converts column letters (e.g. `"A"`, `"Z"`, `"AA"`) into the index of the
+/// column, starting at zero; letters are case insensitive
+///
+/// ### Examples
+///
+/// ```
+/// use memtable_core::exts::a1::column_label_to_index;
+///
+/// assert_eq!(column_label_to_index("A"), Some(0));
+/// assert_eq!(column_label_to_index("z"), Some(25));
+/// assert_eq!(column_label_to_index("AA"), Some(26));
+/// assert_eq!(column_label_to_index("A1"), None);
+/// assert_eq!(column_label_to_index(""), None);
+/// ```
+pub fn column_label_to_index(label: &str) -> Option<usize> {
+    if label.is_empty() {
+        return None;
+    }
+
+    // Labels are one-based, so the largest index needs one more than fits
+    let mut idx: u128 = 0;
+    for c in label.chars() {
+        if !c.is_ascii_alphabetic() {
+            return None;
+        }
+
+        let digit = (c.to_ascii_uppercase() as u8 - b'A') as u128 + 1;
+        idx = idx.checked_mul(26)?.checked_add(digit)?;
+    }
+
+    core::convert::TryFrom::try_from(idx - 1).ok()
+}
+
+/// Represents the letters of a column (e.g. `A`, `Z`, `AA`) for display
+///
+/// ### Examples
+///
+/// ```
+/// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+/// # fn main() {}
+/// # #[cfg(any(feature = "alloc", feature = "std"))]
+/// # fn main() {
+/// use memtable_core::exts::a1::ColumnLabel;
+///
+/// assert_eq!(ColumnLabel(0).to_string(), "A");
+/// assert_eq!(ColumnLabel(27).to_string(), "AB");
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "docs", doc(cfg(a1)))]
+pub struct ColumnLabel(pub usize);
+
+impl fmt::Display for ColumnLabel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // A usize has at most 64 bits, which needs at most 14 letters
+        let mut buf = [0u8; 14];
+        let mut i = buf.len();
+        let mut n = self.0 as u128 + 1;
+
+        while n > 0 {
+            i -= 1;
+            buf[i] = b'A' + ((n - 1) % 26) as u8;
+            n = (n - 1) / 26;
+        }
+
+        // Letters are always ascii, so this will never fail
+        f.write_str(core::str::from_utf8(&buf[i..]).map_err(|_| fmt::Error)?)
+    }
+}
+
+/// Represents a spreadsheet-style reference (e.g. `B7`) to the position of
+/// a cell, where columns are letters and rows are numbers starting at one
+///
+/// ### Examples
+///
+/// ```
+/// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+/// # fn main() {}
+/// # #[cfg(any(feature = "alloc", feature = "std"))]
+/// # fn main() {
+/// use memtable_core::{exts::a1::A1, Position};
+///
+/// let a1: A1 = "B7".parse().unwrap();
+/// assert_eq!(a1.0, Position { row: 6, col: 1 });
+///
+/// assert_eq!(A1(Position { row: 2, col: 26 }).to_string(), "AA3");
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "docs", doc(cfg(a1)))]
+pub struct A1(pub Position);
+
+impl From<Position> for A1 {
+    fn from(position: Position) -> Self {
+        Self(position)
+    }
+}
+
+impl From<A1> for Position {
+    fn from(a1: A1) -> Self {
+        a1.0
+    }
+}
+
+impl fmt::Display for A1 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", ColumnLabel(self.0.col), self.0.row as u128 + 1)
+    }
+}
+
+impl FromStr for A1 {
+    type Err = A1Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_a1(s).map(Self)
+    }
+}
+
+/// Parses a spreadsheet-style reference (e.g. `"B7"` or `"aa3"`) into the
+/// position of the cell it refers to
+///
+/// ### Examples
+///
+/// ```
+/// use memtable_core::{exts::a1::{parse_a1, A1Error}, Position};
+///
+/// assert_eq!(parse_a1("A1"), Ok(Position { row: 0, col: 0 }));
+/// assert_eq!(parse_a1("AA3"), Ok(Position { row: 2, col: 26 }));
+/// assert_eq!(parse_a1("A0"), Err(A1Error::ZeroRow));
+/// ```
+pub fn parse_a1(s: &str) -> Result<Position, A1Error> {
+    let split = s
+        .find(|c: char| !c.is_ascii_alphabetic())
+        .unwrap_or(s.len());
+    let (letters, digits) = s.split_at(split);
+
+    if letters.is_empty() {
+        return match digits.chars().next() {
+            Some(c) if !c.is_ascii_digit() => Err(A1Error::InvalidCharacter(c)),
+            _ => Err(A1Error::MissingColumn),
+        };
+    }
+
+    if digits.is_empty() {
+        return Err(A1Error::MissingRow);
+    }
+
+    if let Some(c) = digits.chars().find(|c| !c.is_ascii_digit()) {
+        return Err(A1Error::InvalidCharacter(c));
+    }
+
+    let col = column_label_to_index(letters).ok_or(A1Error::Overflow)?;
+    let row = digits.parse::<usize>().map_err(|_| A1Error::Overflow)?;
+    if row == 0 {
+        return Err(A1Error::ZeroRow);
+    }
+
+    Ok(Position { row: row - 1, col })
+}
+
+/// Represents a rectangular range of cells (e.g. `A1:C10`), including both
+/// corners
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "docs", doc(cfg(a1)))]
+pub struct A1Range {
+    /// Top-left corner of the range
+    pub start: Position,
+
+    /// Bottom-right corner of the range
+    pub end: Position,
+}
+
+impl A1Range {
+    /// Creates a new range spanning the two corners, which can be given in
+    /// any order
+    pub fn new(a: Position, b: Position) -> Self {
+        Self {
+            start: Position {
+                row: a.row.min(b.row),
+                col: a.col.min(b.col),
+            },
+            end: Position {
+                row: a.row.max(b.row),
+                col: a.col.max(b.col),
+            },
+        }
+    }
+
+    /// Returns true if the position lies within the range
+    pub fn contains(&self, position: Position) -> bool {
+        (self.start.row..=self.end.row).contains(&position.row)
+            && (self.start.col..=self.end.col).contains(&position.col)
+    }
+
+    /// Returns an iterator through the positions of every cell in the range,
+    /// row by row
+    pub fn positions(&self) -> A1RangeIter {
+        A1RangeIter {
+            range: *self,
+            next: Some(self.start),
+        }
+    }
+}
+
+impl fmt::Display for A1Range {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", A1(self.start), A1(self.end))
+    }
+}
+
+impl FromStr for A1Range {
+    type Err = A1Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_a1_range(s)
+    }
+}
+
+impl IntoIterator for A1Range {
+    type Item = Position;
+    type IntoIter = A1RangeIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.positions()
+    }
+}
+
+/// Parses a spreadsheet-style range (e.g. `"A1:C10"`) into the rectangle of
+/// cells it covers
+///
+/// ### Examples
+///
+/// ```
+/// use memtable_core::{exts::a1::parse_a1_range, Position};
+///
+/// let range = parse_a1_range("A1:B2").unwrap();
+/// let mut positions = range.positions();
+/// assert_eq!(positions.next(), Some(Position { row: 0, col: 0 }));
+/// assert_eq!(positions.next(), Some(Position { row: 0, col: 1 }));
+/// assert_eq!(positions.next(), Some(Position { row: 1, col: 0 }));
+/// assert_eq!(positions.next(), Some(Position { row: 1, col: 1 }));
+/// assert_eq!(positions.next(), None);
+/// ```
+pub fn parse_a1_range(s: &str) -> Result<A1Range, A1Error> {
+    let mut parts = s.split(':');
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(a), Some(b), None) => Ok(A1Range::new(parse_a1(a)?, parse_a1(b)?)),
+        _ => Err(A1Error::InvalidRange),
+    }
+}
+
+/// Represents an iterator through the positions of every cell in an
+/// [`A1Range`], row by row
+#[derive(Debug)]
+#[cfg_attr(feature = "docs", doc(cfg(a1)))]
+pub struct A1RangeIter {
+    range: A1Range,
+    next: Option<Position>,
+}
+
+impl Iterator for A1RangeIter {
+    type Item = Position;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let pos = self.next?;
+
+        self.next = if pos.col < self.range.end.col {
+            Some(Position::new(pos.row, pos.col + 1))
+        } else if pos.row < self.range.end.row {
+            Some(Position::new(pos.row + 1, self.range.start.col))
+        } else {
+            None
+        };
+
+        Some(pos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn column_label_to_index_should_support_multiple_letters() {
+        assert_eq!(column_label_to_index("Z"), Some(25));
+        assert_eq!(column_label_to_index("AZ"), Some(51));
+        assert_eq!(column_label_to_index("BA"), Some(52));
+        assert_eq!(column_label_to_index("ZZ"), Some(701));
+        assert_eq!(column_label_to_index("AAA"), Some(702));
+    }
+
+    #[test]
+    fn column_label_to_index_should_return_none_on_overflow() {
+        assert_eq!(column_label_to_index("ZZZZZZZZZZZZZZZZZZZZ"), None);
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[test]
+    fn column_label_should_round_trip_with_column_label_to_index() {
+        use std::string::ToString;
+
+        for idx in [0, 1, 25, 26, 51, 52, 701, 702, 18277, usize::MAX].iter() {
+            let label = ColumnLabel(*idx).to_string();
+            assert_eq!(column_label_to_index(&label), Some(*idx), "{}", label);
+        }
+    }
+
+    #[test]
+    fn parse_a1_should_report_what_is_wrong() {
+        assert_eq!(parse_a1("7"), Err(A1Error::MissingColumn));
+        assert_eq!(parse_a1(""), Err(A1Error::MissingColumn));
+        assert_eq!(parse_a1("B"), Err(A1Error::MissingRow));
+        assert_eq!(parse_a1("B7!"), Err(A1Error::InvalidCharacter('!')));
+        assert_eq!(parse_a1("$B7"), Err(A1Error::InvalidCharacter('$')));
+        assert_eq!(parse_a1("B0"), Err(A1Error::ZeroRow));
+        assert_eq!(parse_a1("B99999999999999999999999"), Err(A1Error::Overflow));
+    }
+
+    #[test]
+    fn parse_a1_range_should_normalize_corners() {
+        let range = parse_a1_range("C10:A1").unwrap();
+        assert_eq!(range.start, Position::new(0, 0));
+        assert_eq!(range.end, Position::new(9, 2));
+        assert_eq!(range.positions().count(), 30);
+        assert!(range.contains(Position::new(5, 1)));
+        assert!(!range.contains(Position::new(5, 3)));
+    }
+
+    #[test]
+    fn parse_a1_range_should_fail_without_exactly_one_separator() {
+        assert_eq!(parse_a1_range("A1"), Err(A1Error::InvalidRange));
+        assert_eq!(parse_a1_range("A1:B2:C3"), Err(A1Error::InvalidRange));
+        assert_eq!(parse_a1_range("A1:B"), Err(A1Error::MissingRow));
+    }
+
+    #[test]
+    fn range_of_single_cell_should_yield_one_position() {
+        let range = parse_a1_range("B2:B2").unwrap();
+        let mut positions = range.into_iter();
+        assert_eq!(positions.next(), Some(Position::new(1, 1)));
+        assert_eq!(positions.next(), None);
+    }
+}
@@ -0,0 +1,646 @@
+use crate::{
+    list::*,
+    utils::{self, CellVec, SortedCells},
+    Capacity, DynamicTable, FixedColumnTable, FixedRowTable, Position, Table,
+};
+use core::{alloc::Allocator, cmp, mem};
+use std::{alloc::Global, vec::Vec};
+
+/// Represents an inmemory table containing rows & columns of some data `T`,
+/// capable of growing and shrinking in size dynamically, whose cells are
+/// allocated with the allocator `A` rather than the global allocator
+///
+/// The standard map does not accept an allocator, so cells are kept in a
+/// single buffer sorted by position instead. Inserting or removing a cell
+/// anywhere but the end of the buffer is `O(n)`, so prefer building the
+/// table row by row. Use [`AllocDynamicTable::into_table`] to
+/// move the cells into a [`DynamicTable`] once the allocator is no longer
+/// needed
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "docs", doc(cfg(allocator_api)))]
+pub struct AllocDynamicTable<T, A: Allocator = Global> {
+    cells: SortedCells<Vec<(Position, T), A>>,
+}
+
+impl<T, A: Allocator> CellVec for Vec<(Position, T), A> {
+    type Data = T;
+
+    fn as_cells(&self) -> &[(Position, T)] {
+        self
+    }
+
+    fn as_mut_cells(&mut self) -> &mut [(Position, T)] {
+        self
+    }
+
+    fn insert_cell_at(&mut self, idx: usize, cell: (Position, T)) {
+        self.insert(idx, cell)
+    }
+
+    fn remove_cell_at(&mut self, idx: usize) -> (Position, T) {
+        self.remove(idx)
+    }
+
+    fn retain_cells<F: FnMut(&Position) -> bool>(&mut self, mut f: F) {
+        self.retain(|(pos, _)| f(pos))
+    }
+}
+
+impl<T> AllocDynamicTable<T> {
+    /// Creates a new, empty table using the global allocator
+    pub fn new() -> Self {
+        Self::new_in(Global)
+    }
+}
+
+impl<T> Default for AllocDynamicTable<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, A: Allocator> AllocDynamicTable<T, A> {
+    /// Creates a new, empty table whose cells are allocated with `alloc`
+    pub fn new_in(alloc: A) -> Self {
+        Self {
+            cells: SortedCells::new(Vec::new_in(alloc)),
+        }
+    }
+
+    /// Creates a new, empty table with room for the given number of cells
+    /// allocated with `alloc` up front
+    pub fn with_capacity_in(capacity: usize, alloc: A) -> Self {
+        Self {
+            cells: SortedCells::new(Vec::with_capacity_in(capacity, alloc)),
+        }
+    }
+
+    /// Returns the allocator that the cells of the table are allocated with
+    pub fn allocator(&self) -> &A {
+        self.cells.cells.allocator()
+    }
+
+    /// Consumes the table, moving its cells into a [`DynamicTable`] with the
+    /// same row & column counts
+    pub fn into_table(self) -> DynamicTable<T> {
+        self.cells.into_table()
+    }
+}
+
+impl<T: Clone, A: Allocator + Clone> Clone for AllocDynamicTable<T, A> {
+    fn clone(&self) -> Self {
+        Self {
+            cells: self.cells.clone(),
+        }
+    }
+}
+
+impl<T, A: Allocator> Table for AllocDynamicTable<T, A> {
+    type Data = T;
+    type Row = DynamicList<Self::Data>;
+    type Column = DynamicList<Self::Data>;
+
+    fn max_row_capacity(&self) -> Capacity {
+        Capacity::Unlimited
+    }
+
+    fn max_column_capacity(&self) -> Capacity {
+        Capacity::Unlimited
+    }
+
+    fn row_cnt(&self) -> usize {
+        self.cells.row_cnt
+    }
+
+    fn col_cnt(&self) -> usize {
+        self.cells.col_cnt
+    }
+
+    fn cell(&self, row: usize, col: usize) -> Option<&Self::Data> {
+        self.cells.cell(row, col)
+    }
+
+    fn mut_cell(&mut self, row: usize, col: usize) -> Option<&mut Self::Data> {
+        self.cells.mut_cell(row, col)
+    }
+
+    /// Inserts the cell into the buffer at its sorted position, which shifts
+    /// every cell after it and is `O(n)` unless the cell is positioned after
+    /// all others
+    fn insert_cell(&mut self, row: usize, col: usize, value: Self::Data) -> Option<Self::Data> {
+        self.cells.insert_cell(row, col, value)
+    }
+
+    /// Removes the cell from the buffer, which shifts every cell after it and
+    /// is `O(n)` unless the cell is positioned after all others
+    fn remove_cell(&mut self, row: usize, col: usize) -> Option<Self::Data> {
+        self.cells.remove_cell(row, col)
+    }
+
+    /// Will adjust the internal row count tracker to the specified capacity
+    ///
+    /// Note that this does **not** remove any cells from the table in their
+    /// old positions. To do that, call [`Self::truncate`].
+    fn set_preferred_row_cnt(&mut self, cnt: usize) {
+        self.cells.row_cnt = cnt;
+    }
+
+    /// Will adjust the internal column count tracker to the specified capacity
+    ///
+    /// Note that this does **not** remove any cells from the table in their
+    /// old positions. To do that, call [`Self::truncate`].
+    fn set_preferred_col_cnt(&mut self, cnt: usize) {
+        self.cells.col_cnt = cnt;
+    }
+
+    /// Removes all cells contained within the table that are outside the
+    /// current row & column capacity
+    fn truncate(&mut self) {
+        self.cells.truncate()
+    }
+
+    /// Adjusts the table's row & column capacity to fit where cells exist,
+    /// which includes cells outside of the current capacity
+    fn shrink_to_fit(&mut self) {
+        self.cells.shrink_to_fit()
+    }
+}
+
+/// Represents an inmemory table containing rows & columns of some data `T`
+/// with a fixed capacity across columns, but ability to grow dynamically with
+/// rows, whose rows are allocated with the allocator `A` rather than the
+/// global allocator
+///
+/// Behaves like [`FixedColumnTable`], which can be produced from it via
+/// [`AllocFixedColumnTable::into_table`]
+#[derive(Debug)]
+#[cfg_attr(feature = "docs", doc(cfg(allocator_api)))]
+pub struct AllocFixedColumnTable<T: Default, const COL: usize, A: Allocator = Global> {
+    cells: Vec<[T; COL], A>,
+    row_cnt: usize,
+    col_cnt: usize,
+}
+
+impl<T: Default, const COL: usize> AllocFixedColumnTable<T, COL> {
+    /// Creates a new, empty table using the global allocator
+    pub fn new() -> Self {
+        Self::new_in(Global)
+    }
+}
+
+impl<T: Default, const COL: usize> Default for AllocFixedColumnTable<T, COL> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Default, const COL: usize, A: Allocator> AllocFixedColumnTable<T, COL, A> {
+    /// Creates a new, empty table whose rows are allocated with `alloc`
+    pub fn new_in(alloc: A) -> Self {
+        Self {
+            cells: Vec::new_in(alloc),
+            row_cnt: 0,
+            col_cnt: 0,
+        }
+    }
+
+    /// Creates a new, empty table with room for the given number of rows
+    /// allocated with `alloc` up front
+    pub fn with_capacity_in(capacity: usize, alloc: A) -> Self {
+        Self {
+            cells: Vec::with_capacity_in(capacity, alloc),
+            row_cnt: 0,
+            col_cnt: 0,
+        }
+    }
+
+    /// Returns the allocator that the rows of the table are allocated with
+    pub fn allocator(&self) -> &A {
+        self.cells.allocator()
+    }
+
+    /// Consumes the table, moving its cells into a [`FixedColumnTable`] with
+    /// the same row & column counts
+    pub fn into_table(mut self) -> FixedColumnTable<T, COL> {
+        let (row_cnt, col_cnt) = (self.row_cnt, self.col_cnt);
+        let mut table = FixedColumnTable::new();
+        for (row, cells) in self.cells.drain(..).enumerate() {
+            for (col, x) in IntoIterator::into_iter(cells).enumerate() {
+                table.insert_cell(row, col, x);
+            }
+        }
+        table.set_preferred_row_cnt(row_cnt);
+        table.set_preferred_col_cnt(col_cnt);
+        table
+    }
+}
+
+impl<T: Default + Clone, const COL: usize, A: Allocator + Clone> Clone
+    for AllocFixedColumnTable<T, COL, A>
+{
+    fn clone(&self) -> Self {
+        Self {
+            cells: self.cells.clone(),
+            row_cnt: self.row_cnt,
+            col_cnt: self.col_cnt,
+        }
+    }
+}
+
+impl<T: Default + PartialEq, const COL: usize, A: Allocator> PartialEq
+    for AllocFixedColumnTable<T, COL, A>
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.row_cnt == other.row_cnt
+            && self.col_cnt == other.col_cnt
+            && self.cells[..] == other.cells[..]
+    }
+}
+
+impl<T: Default + Eq, const COL: usize, A: Allocator> Eq for AllocFixedColumnTable<T, COL, A> {}
+
+impl<T: Default, const COL: usize, A: Allocator> Table for AllocFixedColumnTable<T, COL, A> {
+    type Data = T;
+    type Row = FixedList<Self::Data, COL>;
+    type Column = DynamicList<Self::Data>;
+
+    fn max_row_capacity(&self) -> Capacity {
+        Capacity::Unlimited
+    }
+
+    fn max_column_capacity(&self) -> Capacity {
+        Capacity::Limited(COL)
+    }
+
+    fn row_cnt(&self) -> usize {
+        self.row_cnt
+    }
+
+    fn col_cnt(&self) -> usize {
+        self.col_cnt
+    }
+
+    fn cell(&self, row: usize, col: usize) -> Option<&Self::Data> {
+        if row < self.row_cnt && col < self.col_cnt {
+            Some(&self.cells[row][col])
+        } else {
+            None
+        }
+    }
+
+    fn mut_cell(&mut self, row: usize, col: usize) -> Option<&mut Self::Data> {
+        if row < self.row_cnt && col < self.col_cnt {
+            Some(&mut self.cells[row][col])
+        } else {
+            None
+        }
+    }
+
+    fn insert_cell(&mut self, row: usize, col: usize, value: Self::Data) -> Option<Self::Data> {
+        // Allow inserting anywhere in the allocated space, not just virtual
+        if col < COL {
+            let mut did_grow = false;
+            if row >= self.row_cnt {
+                self.cells.resize_with(row + 1, utils::default_array);
+                self.row_cnt = row + 1;
+                did_grow = true;
+            }
+
+            if col >= self.col_cnt {
+                self.col_cnt = col + 1;
+                did_grow = true;
+            }
+
+            // Perform operation, but if growing our virtual range, don't
+            // return anything and pretend that it was empty
+            let old_value = mem::replace(&mut self.cells[row][col], value);
+            if !did_grow {
+                Some(old_value)
+            } else {
+                None
+            }
+        } else {
+            None
+        }
+    }
+
+    fn remove_cell(&mut self, row: usize, col: usize) -> Option<Self::Data> {
+        // Like the other fixed tables, removing a cell leaves behind its
+        // default value rather than shrinking the table
+        self.mut_cell(row, col).map(mem::take)
+    }
+
+    /// Will adjust the internal row count tracker to the specified capacity,
+    /// allocating default rows to fill any new space
+    ///
+    /// Note that this does **not** remove any cells from the table in their
+    /// old positions. To do that, call [`Self::truncate`], which will reset
+    /// them to their default value.
+    fn set_preferred_row_cnt(&mut self, cnt: usize) {
+        if cnt > self.cells.len() {
+            self.cells.resize_with(cnt, utils::default_array);
+        }
+        self.row_cnt = cnt;
+    }
+
+    /// Will adjust the internal column count tracker to the specified capacity,
+    /// capping at COL.
+    ///
+    /// Note that this does **not** remove any cells from the table in their
+    /// old positions. To do that, call [`Self::truncate`], which will reset
+    /// them to their default value.
+    fn set_preferred_col_cnt(&mut self, cnt: usize) {
+        self.col_cnt = cmp::min(cnt, COL);
+    }
+
+    /// Removes all rows outside of the current row capacity and resets all
+    /// cells outside of the current column capacity to their default value
+    fn truncate(&mut self) {
+        self.cells.truncate(self.row_cnt);
+        for cells in self.cells.iter_mut() {
+            for x in cells.iter_mut().skip(self.col_cnt) {
+                *x = T::default();
+            }
+        }
+    }
+}
+
+/// Represents an inmemory table containing rows & columns of some data `T`
+/// with a fixed capacity across rows, but ability to grow dynamically with
+/// columns, whose rows are allocated with the allocator `A` rather than the
+/// global allocator
+///
+/// Behaves like [`FixedRowTable`], which can be produced from it via
+/// [`AllocFixedRowTable::into_table`]
+#[derive(Debug)]
+#[cfg_attr(feature = "docs", doc(cfg(allocator_api)))]
+pub struct AllocFixedRowTable<T: Default, const ROW: usize, A: Allocator + Clone = Global> {
+    cells: [Vec<T, A>; ROW],
+    row_cnt: usize,
+    col_cnt: usize,
+    alloc: A,
+}
+
+impl<T: Default, const ROW: usize> AllocFixedRowTable<T, ROW> {
+    /// Creates a new, empty table using the global allocator
+    pub fn new() -> Self {
+        Self::new_in(Global)
+    }
+}
+
+impl<T: Default, const ROW: usize> Default for AllocFixedRowTable<T, ROW> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Default, const ROW: usize, A: Allocator + Clone> AllocFixedRowTable<T, ROW, A> {
+    /// Creates a new, empty table whose rows are each allocated with a clone
+    /// of `alloc`
+    pub fn new_in(alloc: A) -> Self {
+        Self {
+            cells: utils::make_array(|_| Vec::new_in(alloc.clone())),
+            row_cnt: 0,
+            col_cnt: 0,
+            alloc,
+        }
+    }
+
+    /// Creates a new, empty table with room for the given number of columns
+    /// in each row allocated with a clone of `alloc` up front
+    pub fn with_capacity_in(capacity: usize, alloc: A) -> Self {
+        Self {
+            cells: utils::make_array(|_| Vec::with_capacity_in(capacity, alloc.clone())),
+            row_cnt: 0,
+            col_cnt: 0,
+            alloc,
+        }
+    }
+
+    /// Returns the allocator that the rows of the table are allocated with
+    pub fn allocator(&self) -> &A {
+        &self.alloc
+    }
+
+    /// Consumes the table, moving its cells into a [`FixedRowTable`] with the
+    /// same row & column counts
+    pub fn into_table(self) -> FixedRowTable<T, ROW> {
+        let (row_cnt, col_cnt) = (self.row_cnt, self.col_cnt);
+        let mut table = FixedRowTable::new();
+        for (row, cells) in IntoIterator::into_iter(self.cells).enumerate() {
+            for (col, x) in cells.into_iter().enumerate() {
+                table.insert_cell(row, col, x);
+            }
+        }
+        table.set_preferred_row_cnt(row_cnt);
+        table.set_preferred_col_cnt(col_cnt);
+        table
+    }
+}
+
+impl<T: Default + Clone, const ROW: usize, A: Allocator + Clone> Clone
+    for AllocFixedRowTable<T, ROW, A>
+{
+    fn clone(&self) -> Self {
+        Self {
+            cells: self.cells.clone(),
+            row_cnt: self.row_cnt,
+            col_cnt: self.col_cnt,
+            alloc: self.alloc.clone(),
+        }
+    }
+}
+
+impl<T: Default + PartialEq, const ROW: usize, A: Allocator + Clone> PartialEq
+    for AllocFixedRowTable<T, ROW, A>
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.row_cnt == other.row_cnt
+            && self.col_cnt == other.col_cnt
+            && self.cells[..] == other.cells[..]
+    }
+}
+
+impl<T: Default + Eq, const ROW: usize, A: Allocator + Clone> Eq for AllocFixedRowTable<T, ROW, A> {}
+
+impl<T: Default, const ROW: usize, A: Allocator + Clone> Table for AllocFixedRowTable<T, ROW, A> {
+    type Data = T;
+    type Row = DynamicList<Self::Data>;
+    type Column = FixedList<Self::Data, ROW>;
+
+    fn max_row_capacity(&self) -> Capacity {
+        Capacity::Limited(ROW)
+    }
+
+    fn max_column_capacity(&self) -> Capacity {
+        Capacity::Unlimited
+    }
+
+    fn row_cnt(&self) -> usize {
+        self.row_cnt
+    }
+
+    fn col_cnt(&self) -> usize {
+        self.col_cnt
+    }
+
+    fn cell(&self, row: usize, col: usize) -> Option<&Self::Data> {
+        // NOTE: Rows are not grown to match when another row grows the
+        //       col_cnt, so the row itself may be shorter than col_cnt
+        if row < self.row_cnt && col < self.col_cnt {
+            self.cells[row].get(col)
+        } else {
+            None
+        }
+    }
+
+    fn mut_cell(&mut self, row: usize, col: usize) -> Option<&mut Self::Data> {
+        if row < self.row_cnt && col < self.col_cnt {
+            self.cells[row].get_mut(col)
+        } else {
+            None
+        }
+    }
+
+    fn insert_cell(&mut self, row: usize, col: usize, value: Self::Data) -> Option<Self::Data> {
+        // Allow inserting anywhere in the allocated space, not just virtual
+        if row < ROW {
+            let mut did_grow = false;
+            if col >= self.col_cnt {
+                self.col_cnt = col + 1;
+                did_grow = true;
+            }
+
+            if row >= self.row_cnt {
+                self.row_cnt = row + 1;
+                did_grow = true;
+            }
+
+            // Even if the virtual size is updated, the actual row may not
+            // have grown to fit
+            if col >= self.cells[row].len() {
+                self.cells[row].resize_with(col + 1, T::default);
+                did_grow = true;
+            }
+
+            // Perform operation, but if growing our virtual range, don't
+            // return anything and pretend that it was empty
+            let old_value = mem::replace(&mut self.cells[row][col], value);
+            if !did_grow {
+                Some(old_value)
+            } else {
+                None
+            }
+        } else {
+            None
+        }
+    }
+
+    fn remove_cell(&mut self, row: usize, col: usize) -> Option<Self::Data> {
+        // Like the other fixed tables, removing a cell leaves behind its
+        // default value rather than shrinking the table
+        if row < self.row_cnt && col < self.cells[row].len() {
+            Some(mem::take(&mut self.cells[row][col]))
+        } else {
+            None
+        }
+    }
+
+    /// Will adjust the internal row count tracker to the specified capacity,
+    /// capping at ROW.
+    ///
+    /// Note that this does **not** remove any cells from the table in their
+    /// old positions. To do that, call [`Self::truncate`], which will reset
+    /// them to their default value.
+    fn set_preferred_row_cnt(&mut self, cnt: usize) {
+        self.row_cnt = cmp::min(cnt, ROW);
+    }
+
+    /// Will adjust the internal column count tracker to the specified capacity
+    ///
+    /// Note that this does **not** remove any cells from the table in their
+    /// old positions. To do that, call [`Self::truncate`].
+    fn set_preferred_col_cnt(&mut self, cnt: usize) {
+        self.col_cnt = cnt;
+    }
+
+    /// Removes all columns outside of the current column capacity and resets
+    /// all cells outside of the current row capacity to their default value
+    fn truncate(&mut self) {
+        let col_cnt = self.col_cnt;
+        self.cells.iter_mut().for_each(|x| x.truncate(col_cnt));
+        for cells in self.cells[self.row_cnt..].iter_mut() {
+            for x in cells.iter_mut() {
+                *x = T::default();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::vec;
+
+    #[test]
+    fn alloc_dynamic_table_should_keep_cells_sorted_by_position() {
+        let mut table = AllocDynamicTable::new_in(Global);
+        assert_eq!(table.insert_cell(1, 1, "d"), None);
+        assert_eq!(table.insert_cell(0, 1, "b"), None);
+        assert_eq!(table.insert_cell(0, 0, "a"), None);
+        assert_eq!(table.insert_cell(0, 1, "c"), Some("b"));
+        assert_eq!(table.remove_cell(0, 0), Some("a"));
+
+        assert_eq!(table.row_cnt(), 2);
+        assert_eq!(table.col_cnt(), 2);
+        assert_eq!(table.cell(0, 0), None);
+        assert_eq!(table.cell(0, 1), Some(&"c"));
+        assert_eq!(table.cell(1, 1), Some(&"d"));
+    }
+
+    #[test]
+    fn alloc_dynamic_table_into_table_should_keep_cells_and_counts() {
+        let mut table = AllocDynamicTable::new();
+        table.push_row(vec![1, 2]);
+        table.push_row(vec![3, 4]);
+        table.set_preferred_col_cnt(3);
+
+        let table = table.into_table();
+        assert_eq!(table.row_cnt(), 2);
+        assert_eq!(table.col_cnt(), 3);
+        assert_eq!(table.cell(1, 0), Some(&3));
+        assert_eq!(table.cell(1, 2), None);
+    }
+
+    #[test]
+    fn alloc_fixed_column_table_should_behave_like_fixed_column_table() {
+        let mut table: AllocFixedColumnTable<u8, 2> = AllocFixedColumnTable::new_in(Global);
+        assert_eq!(table.insert_cell(1, 1, 4), None);
+        assert_eq!(table.insert_cell(0, 2, 9), None);
+        assert_eq!(table.insert_cell(1, 1, 5), Some(4));
+        assert_eq!(table.remove_cell(1, 1), Some(5));
+
+        let mut expected = FixedColumnTable::<u8, 2>::new();
+        expected.insert_cell(1, 1, 0);
+
+        assert_eq!(table.row_cnt(), 2);
+        assert_eq!(table.col_cnt(), 2);
+        assert_eq!(table.into_table(), expected);
+    }
+
+    #[test]
+    fn alloc_fixed_row_table_should_behave_like_fixed_row_table() {
+        let mut table: AllocFixedRowTable<u8, 2> = AllocFixedRowTable::new_in(Global);
+        assert_eq!(table.insert_cell(1, 1, 4), None);
+        assert_eq!(table.insert_cell(2, 0, 9), None);
+        assert_eq!(table.insert_cell(1, 1, 5), Some(4));
+        assert_eq!(table.remove_cell(1, 1), Some(5));
+
+        let mut expected = FixedRowTable::<u8, 2>::new();
+        expected.insert_cell(1, 1, 0);
+
+        assert_eq!(table.row_cnt(), 2);
+        assert_eq!(table.col_cnt(), 2);
+        assert_eq!(table.into_table(), expected);
+    }
+}
@@ -0,0 +1,216 @@
+use crate::{
+    list::*,
+    utils::{CellVec, SortedCells},
+    Capacity, DynamicTable, Position, Table,
+};
+use ::bumpalo::{collections::Vec as BumpVec, Bump};
+
+/// Represents an inmemory table containing rows & columns of some data `T`,
+/// capable of growing and shrinking in size dynamically, whose cells are
+/// allocated within a [`Bump`] arena rather than the global allocator
+///
+/// Cells are kept in a single buffer sorted by position, so a table that is
+/// built, transformed, and discarded only bumps a pointer within the arena
+/// rather than allocating per cell. Use [`DynamicTableIn::into_table`] to
+/// keep the cells once the arena is about to be reset
+///
+/// Looking up a cell is a binary search over the buffer, but inserting or
+/// removing a cell shifts every cell positioned after it, which is `O(n)` in
+/// the total cells. Filling the table in row order, as [`Table::push_row`]
+/// does, only ever appends to the buffer and stays cheap. Anything that
+/// touches every row, such as [`Table::push_column`], [`Table::insert_row`],
+/// [`Table::remove_column`], or [`Table::shift_rows`], moves cells in the
+/// middle of the buffer once per cell and is `O(n^2)` overall, so prefer
+/// building the table row by row
+#[derive(Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "docs", doc(cfg(bumpalo)))]
+pub struct DynamicTableIn<'a, T> {
+    cells: SortedCells<BumpVec<'a, (Position, T)>>,
+}
+
+impl<'a, T> CellVec for BumpVec<'a, (Position, T)> {
+    type Data = T;
+
+    fn as_cells(&self) -> &[(Position, T)] {
+        self
+    }
+
+    fn as_mut_cells(&mut self) -> &mut [(Position, T)] {
+        self
+    }
+
+    fn insert_cell_at(&mut self, idx: usize, cell: (Position, T)) {
+        self.insert(idx, cell)
+    }
+
+    fn remove_cell_at(&mut self, idx: usize) -> (Position, T) {
+        self.remove(idx)
+    }
+
+    fn retain_cells<F: FnMut(&Position) -> bool>(&mut self, mut f: F) {
+        self.retain(|(pos, _)| f(pos))
+    }
+}
+
+impl<'a, T> DynamicTableIn<'a, T> {
+    /// Creates a new, empty table whose cells are allocated within the arena
+    pub fn new_in(bump: &'a Bump) -> Self {
+        Self {
+            cells: SortedCells::new(BumpVec::new_in(bump)),
+        }
+    }
+
+    /// Creates a new, empty table with room for the given number of cells
+    /// allocated within the arena up front
+    pub fn with_capacity_in(capacity: usize, bump: &'a Bump) -> Self {
+        Self {
+            cells: SortedCells::new(BumpVec::with_capacity_in(capacity, bump)),
+        }
+    }
+
+    /// Returns the arena that the cells of the table are allocated within
+    pub fn bump(&self) -> &'a Bump {
+        self.cells.cells.bump()
+    }
+
+    /// Consumes the table, moving its cells out of the arena into a
+    /// [`DynamicTable`] with the same row & column counts
+    pub fn into_table(self) -> DynamicTable<T> {
+        self.cells.into_table()
+    }
+}
+
+impl<'a, T: Clone> Clone for DynamicTableIn<'a, T> {
+    fn clone(&self) -> Self {
+        let mut cells = BumpVec::with_capacity_in(self.cells.cells.len(), self.bump());
+        cells.extend(self.cells.cells.iter().cloned());
+        Self {
+            cells: SortedCells {
+                cells,
+                row_cnt: self.cells.row_cnt,
+                col_cnt: self.cells.col_cnt,
+            },
+        }
+    }
+}
+
+impl<'a, T> Table for DynamicTableIn<'a, T> {
+    type Data = T;
+    type Row = DynamicList<Self::Data>;
+    type Column = DynamicList<Self::Data>;
+
+    fn max_row_capacity(&self) -> Capacity {
+        Capacity::Unlimited
+    }
+
+    fn max_column_capacity(&self) -> Capacity {
+        Capacity::Unlimited
+    }
+
+    fn row_cnt(&self) -> usize {
+        self.cells.row_cnt
+    }
+
+    fn col_cnt(&self) -> usize {
+        self.cells.col_cnt
+    }
+
+    fn cell(&self, row: usize, col: usize) -> Option<&Self::Data> {
+        self.cells.cell(row, col)
+    }
+
+    fn mut_cell(&mut self, row: usize, col: usize) -> Option<&mut Self::Data> {
+        self.cells.mut_cell(row, col)
+    }
+
+    /// Inserts the cell into the buffer at its sorted position, which shifts
+    /// every cell after it and is `O(n)` unless the cell is positioned after
+    /// all others
+    fn insert_cell(&mut self, row: usize, col: usize, value: Self::Data) -> Option<Self::Data> {
+        self.cells.insert_cell(row, col, value)
+    }
+
+    /// Removes the cell from the buffer, which shifts every cell after it and
+    /// is `O(n)` unless the cell is positioned after all others
+    fn remove_cell(&mut self, row: usize, col: usize) -> Option<Self::Data> {
+        self.cells.remove_cell(row, col)
+    }
+
+    /// Will adjust the internal row count tracker to the specified capacity
+    ///
+    /// Note that this does **not** remove any cells from the table in their
+    /// old positions. To do that, call [`Self::truncate`].
+    fn set_preferred_row_cnt(&mut self, cnt: usize) {
+        self.cells.row_cnt = cnt;
+    }
+
+    /// Will adjust the internal column count tracker to the specified capacity
+    ///
+    /// Note that this does **not** remove any cells from the table in their
+    /// old positions. To do that, call [`Self::truncate`].
+    fn set_preferred_col_cnt(&mut self, cnt: usize) {
+        self.cells.col_cnt = cnt;
+    }
+
+    /// Removes all cells contained within the table that are outside the
+    /// current row & column capacity
+    fn truncate(&mut self) {
+        self.cells.truncate()
+    }
+
+    /// Adjusts the table's row & column capacity to fit where cells exist,
+    /// which includes cells outside of the current capacity
+    fn shrink_to_fit(&mut self) {
+        self.cells.shrink_to_fit()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_cell_should_keep_cells_sorted_by_position() {
+        let bump = Bump::new();
+        let mut table = DynamicTableIn::new_in(&bump);
+        assert_eq!(table.insert_cell(1, 1, "d"), None);
+        assert_eq!(table.insert_cell(0, 1, "b"), None);
+        assert_eq!(table.insert_cell(0, 0, "a"), None);
+        assert_eq!(table.insert_cell(0, 1, "c"), Some("b"));
+
+        assert_eq!(table.row_cnt(), 2);
+        assert_eq!(table.col_cnt(), 2);
+        assert_eq!(table.cell(0, 0), Some(&"a"));
+        assert_eq!(table.cell(0, 1), Some(&"c"));
+        assert_eq!(table.cell(1, 0), None);
+        assert_eq!(table.cell(1, 1), Some(&"d"));
+    }
+
+    #[test]
+    fn remove_cell_should_return_the_removed_value() {
+        let bump = Bump::new();
+        let mut table = DynamicTableIn::new_in(&bump);
+        table.push_row(vec![1, 2, 3]);
+
+        assert_eq!(table.remove_cell(0, 1), Some(2));
+        assert_eq!(table.remove_cell(0, 1), None);
+        assert_eq!(table.cell(0, 2), Some(&3));
+    }
+
+    #[test]
+    fn into_table_should_move_cells_out_of_the_arena() {
+        let bump = Bump::new();
+        let mut table = DynamicTableIn::new_in(&bump);
+        table.push_row(vec![1, 2]);
+        table.push_row(vec![3, 4]);
+        table.set_preferred_col_cnt(3);
+
+        let table = table.into_table();
+        drop(bump);
+
+        assert_eq!(table.row_cnt(), 2);
+        assert_eq!(table.col_cnt(), 3);
+        assert_eq!(table.cell(1, 0), Some(&3));
+        assert_eq!(table.cell(1, 2), None);
+    }
+}
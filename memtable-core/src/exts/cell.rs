@@ -46,6 +46,30 @@ macro_rules! impl_cell {
                     }
                 )+
             }
+
+            /// Samples a random variant, each with equal probability, holding
+            /// a random value of its type
+            #[cfg(feature = "rand")]
+            #[cfg_attr(feature = "docs", doc(cfg(all(cell, rand))))]
+            impl<$($variant),+> ::rand::distributions::Distribution<$name<$($variant),+>>
+                for ::rand::distributions::Standard
+            where
+                $(::rand::distributions::Standard: ::rand::distributions::Distribution<$variant>),+
+            {
+                #[allow(unused_assignments)]
+                fn sample<__R: ::rand::Rng + ?Sized>(&self, rng: &mut __R) -> $name<$($variant),+> {
+                    let mut idx = rng.gen_range(0..[$(stringify!($variant)),+].len());
+
+                    $(
+                        if idx == 0 {
+                            return $name::$variant(rng.gen());
+                        }
+                        idx -= 1;
+                    )+
+
+                    unreachable!()
+                }
+            }
         }
     };
 }
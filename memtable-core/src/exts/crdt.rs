@@ -0,0 +1,341 @@
+use crate::{list::DynamicList, Capacity, Position, Table};
+use std::collections::BTreeMap;
+
+/// Represents a logical timestamp used to order changes across replicas,
+/// where the replica id breaks ties so every replica picks the same winner
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde-1", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "docs", doc(cfg(crdt)))]
+pub struct Stamp {
+    /// Lamport time of the change
+    pub time: u64,
+
+    /// Id of the replica that made the change
+    pub replica: u64,
+}
+
+/// Represents a value alongside the stamp of the change that produced it
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-1", derive(serde::Serialize, serde::Deserialize))]
+struct Register<T> {
+    value: T,
+    stamp: Stamp,
+}
+
+impl<T> Register<T> {
+    /// Replaces this register with the other if the other is newer
+    fn merge(&mut self, other: Register<T>) {
+        if other.stamp > self.stamp {
+            *self = other;
+        }
+    }
+}
+
+/// Represents a table whose cells are last-writer-wins registers, allowing
+/// two copies edited independently (e.g. offline) to be reconciled with
+/// [`LwwTable::merge`]
+///
+/// Each copy must be given a unique replica id. Every change is stamped
+/// with a Lamport timestamp, and when two copies disagree on a cell, the
+/// change with the larger stamp wins; ties on time are broken by replica id,
+/// so merging in any order produces the same table on every replica.
+/// Removed cells are kept as tombstones so that removals also propagate.
+/// The row & column counts only ever grow when merged, taking the larger
+/// count of the two copies, so a copy that shrank its table cannot hide the
+/// cells that another copy placed beyond the smaller size
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-1", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "docs", doc(cfg(crdt)))]
+pub struct LwwTable<T> {
+    replica: u64,
+    time: u64,
+    cells: BTreeMap<Position, Register<Option<T>>>,
+    row_cnt: usize,
+    col_cnt: usize,
+}
+
+impl<T> LwwTable<T> {
+    /// Creates a new, empty table for the replica with the given id
+    pub fn new(replica: u64) -> Self {
+        Self {
+            replica,
+            time: 0,
+            cells: BTreeMap::new(),
+            row_cnt: 0,
+            col_cnt: 0,
+        }
+    }
+
+    /// Returns the id of the replica that owns this copy of the table
+    pub fn replica(&self) -> u64 {
+        self.replica
+    }
+
+    /// Returns the stamp of the last change made to the cell at the given
+    /// row & column, including its removal
+    pub fn stamp(&self, row: usize, col: usize) -> Option<Stamp> {
+        self.cells.get(&Position { row, col }).map(|x| x.stamp)
+    }
+
+    /// Merges the changes of another copy of the table into this one, keeping
+    /// the most recent change to every cell and the larger of the two sizes
+    pub fn merge(&mut self, other: &Self)
+    where
+        T: Clone,
+    {
+        for (pos, theirs) in other.cells.iter() {
+            match self.cells.get_mut(pos) {
+                Some(ours) => ours.merge(theirs.clone()),
+                None => {
+                    self.cells.insert(*pos, theirs.clone());
+                }
+            }
+        }
+
+        self.row_cnt = self.row_cnt.max(other.row_cnt);
+        self.col_cnt = self.col_cnt.max(other.col_cnt);
+
+        // Make sure our next change is ordered after everything we have seen
+        self.time = self.time.max(other.time);
+    }
+
+    /// Removes tombstones of removed cells, reducing memory usage
+    ///
+    /// Only do this once every replica has merged the removals, otherwise
+    /// removed cells may reappear when merging with a replica that has not
+    pub fn compact(&mut self) {
+        self.cells.retain(|_, x| x.value.is_some());
+    }
+
+    /// Advances the clock, returning the stamp for a new change
+    fn tick(&mut self) -> Stamp {
+        self.time += 1;
+        Stamp {
+            time: self.time,
+            replica: self.replica,
+        }
+    }
+}
+
+impl<T> Table for LwwTable<T> {
+    type Data = T;
+    type Row = DynamicList<Self::Data>;
+    type Column = DynamicList<Self::Data>;
+
+    fn max_row_capacity(&self) -> Capacity {
+        Capacity::Unlimited
+    }
+
+    fn max_column_capacity(&self) -> Capacity {
+        Capacity::Unlimited
+    }
+
+    fn row_cnt(&self) -> usize {
+        self.row_cnt
+    }
+
+    fn col_cnt(&self) -> usize {
+        self.col_cnt
+    }
+
+    fn cell(&self, row: usize, col: usize) -> Option<&Self::Data> {
+        self.cells
+            .get(&Position { row, col })
+            .and_then(|x| x.value.as_ref())
+    }
+
+    /// Returns a mutable reference to the cell, treating the access as a
+    /// change to the cell so that any modification wins over older changes
+    /// when merged
+    fn mut_cell(&mut self, row: usize, col: usize) -> Option<&mut Self::Data> {
+        let stamp = self.tick();
+        match self.cells.get_mut(&Position { row, col }) {
+            Some(Register {
+                value: Some(value),
+                stamp: x,
+            }) => {
+                *x = stamp;
+                Some(value)
+            }
+            _ => None,
+        }
+    }
+
+    fn insert_cell(&mut self, row: usize, col: usize, value: Self::Data) -> Option<Self::Data> {
+        if row >= self.row_cnt() {
+            self.set_preferred_row_cnt(row + 1);
+        }
+
+        if col >= self.col_cnt() {
+            self.set_preferred_col_cnt(col + 1);
+        }
+
+        let stamp = self.tick();
+        self.cells
+            .insert(
+                Position { row, col },
+                Register {
+                    value: Some(value),
+                    stamp,
+                },
+            )
+            .and_then(|x| x.value)
+    }
+
+    fn remove_cell(&mut self, row: usize, col: usize) -> Option<Self::Data> {
+        let pos = Position { row, col };
+        if self.cells.contains_key(&pos) {
+            let stamp = self.tick();
+            self.cells
+                .insert(pos, Register { value: None, stamp })
+                .and_then(|x| x.value)
+        } else {
+            None
+        }
+    }
+
+    /// Changes the row count of this copy, though a smaller count is
+    /// replaced by the larger count of any copy that this one merges with
+    fn set_preferred_row_cnt(&mut self, cnt: usize) {
+        self.row_cnt = cnt;
+    }
+
+    /// Changes the column count of this copy, though a smaller count is
+    /// replaced by the larger count of any copy that this one merges with
+    fn set_preferred_col_cnt(&mut self, cnt: usize) {
+        self.col_cnt = cnt;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::vec;
+
+    #[test]
+    fn merge_should_include_cells_only_changed_on_other_replica() {
+        let mut a = LwwTable::new(1);
+        let mut b = LwwTable::new(2);
+        a.insert_cell(0, 0, "a");
+        b.insert_cell(1, 1, "b");
+
+        a.merge(&b);
+        assert_eq!(a.cell(0, 0), Some(&"a"));
+        assert_eq!(a.cell(1, 1), Some(&"b"));
+        assert_eq!(a.row_cnt(), 2);
+        assert_eq!(a.col_cnt(), 2);
+    }
+
+    #[test]
+    fn merge_should_keep_most_recent_change_to_cell() {
+        let mut a = LwwTable::new(1);
+        a.insert_cell(0, 0, "first");
+
+        let mut b = a.clone();
+        b.replica = 2;
+        b.insert_cell(0, 0, "second");
+
+        a.merge(&b);
+        assert_eq!(a.cell(0, 0), Some(&"second"));
+    }
+
+    #[test]
+    fn merge_should_break_ties_using_replica_id() {
+        let mut a = LwwTable::new(1);
+        let mut b = LwwTable::new(2);
+        a.insert_cell(0, 0, "a");
+        b.insert_cell(0, 0, "b");
+
+        let mut ab = a.clone();
+        ab.merge(&b);
+        let mut ba = b.clone();
+        ba.merge(&a);
+
+        assert_eq!(ab.cell(0, 0), Some(&"b"));
+        assert_eq!(ba.cell(0, 0), Some(&"b"));
+    }
+
+    #[test]
+    fn merge_should_propagate_removals() {
+        let mut a = LwwTable::new(1);
+        a.insert_cell(0, 0, 5);
+
+        let mut b = LwwTable::new(2);
+        b.merge(&a);
+        b.remove_cell(0, 0);
+
+        a.merge(&b);
+        assert_eq!(a.cell(0, 0), None);
+    }
+
+    #[test]
+    fn merge_should_order_later_local_changes_after_merged_changes() {
+        let mut a = LwwTable::new(1);
+        let mut b = LwwTable::new(2);
+        for i in 0..10 {
+            b.insert_cell(0, 0, i);
+        }
+
+        a.merge(&b);
+        a.insert_cell(0, 0, 100);
+
+        b.merge(&a);
+        assert_eq!(b.cell(0, 0), Some(&100));
+    }
+
+    #[test]
+    fn merge_should_converge_regardless_of_order() {
+        let mut a = LwwTable::new(1);
+        let mut b = LwwTable::new(2);
+        let mut c = LwwTable::new(3);
+
+        a.push_row(vec![1, 2, 3]);
+        b.push_row(vec![4, 5]);
+        c.insert_cell(2, 2, 9);
+        *b.mut_cell(0, 1).unwrap() = 50;
+
+        let mut x = a.clone();
+        x.merge(&b);
+        x.merge(&c);
+
+        let mut y = c.clone();
+        y.merge(&b);
+        y.merge(&a);
+
+        for row in 0..3 {
+            for col in 0..3 {
+                assert_eq!(x.cell(row, col), y.cell(row, col));
+            }
+        }
+        assert_eq!(x.row_cnt(), y.row_cnt());
+        assert_eq!(x.col_cnt(), y.col_cnt());
+    }
+
+    #[test]
+    fn merge_should_keep_larger_row_and_column_counts() {
+        let mut a = LwwTable::new(1);
+        a.insert_cell(4, 1, "a");
+
+        let mut b = LwwTable::new(2);
+        b.merge(&a);
+        b.set_preferred_row_cnt(2);
+        b.set_preferred_col_cnt(1);
+
+        a.merge(&b);
+        b.merge(&a);
+        assert_eq!((a.row_cnt(), a.col_cnt()), (5, 2));
+        assert_eq!((b.row_cnt(), b.col_cnt()), (5, 2));
+        assert_eq!(b.cell(4, 1), Some(&"a"));
+    }
+
+    #[test]
+    fn compact_should_drop_tombstones() {
+        let mut table = LwwTable::new(1);
+        table.insert_cell(0, 0, 1);
+        table.remove_cell(0, 0);
+        assert!(table.stamp(0, 0).is_some());
+
+        table.compact();
+        assert!(table.stamp(0, 0).is_none());
+    }
+}
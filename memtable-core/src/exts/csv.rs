@@ -1,5 +1,10 @@
-use crate::Table;
+use crate::{
+    progress::{CancelToken, ControlFlow, PartialCompletion},
+    wrappers::HeaderTable,
+    DynamicTable, Table,
+};
 use ::csv as csv_lib;
+use core::{cmp, fmt, ops::Range};
 use std::{fs::File, io, path::Path};
 
 /// Represents ability to load data from a CSV
@@ -22,24 +27,122 @@ pub trait FromCsv {
     fn from_csv_file<P: AsRef<Path>>(p: P) -> io::Result<Self::Output> {
         Self::from_csv(File::open(p)?)
     }
+
+    /// Loads a table like [`FromCsv::from_csv`], reporting the bytes read so
+    /// far and the `total` bytes expected after each record
+    ///
+    /// If the callback breaks, loading stops and an error of kind
+    /// [`io::ErrorKind::Other`] wrapping a [`PartialCompletion`] is
+    /// returned
+    ///
+    /// By default, the whole CSV is loaded and progress is reported once at
+    /// the end, as the bytes read are only known to implementations that
+    /// parse the CSV themselves
+    fn from_csv_with_progress<R, F, C>(
+        reader: R,
+        total: usize,
+        mut f: F,
+    ) -> io::Result<Self::Output>
+    where
+        R: io::Read,
+        F: FnMut(usize, usize) -> C,
+        C: Into<ControlFlow>,
+    {
+        let output = Self::from_csv(reader)?;
+        f(total, total);
+        Ok(output)
+    }
+
+    /// Loads a table like [`FromCsv::from_csv`], checking the token before
+    /// reading anything and after each record, stopping once it is cancelled
+    ///
+    /// If cancelled, an error of kind [`io::ErrorKind::Other`] wrapping
+    /// a [`PartialCompletion`] counting the bytes read is returned
+    #[inline]
+    fn from_csv_cancellable<R: io::Read>(
+        reader: R,
+        token: &CancelToken,
+    ) -> io::Result<Self::Output> {
+        if token.is_cancelled() {
+            return Err(PartialCompletion { done: 0, total: 0 }.into());
+        }
+
+        Self::from_csv_with_progress(reader, 0, |_, _| token.check())
+    }
+
+    /// Loads a table from a CSV str, reporting progress in bytes
+    #[inline]
+    fn from_csv_str_with_progress<F, C>(s: &str, f: F) -> io::Result<Self::Output>
+    where
+        F: FnMut(usize, usize) -> C,
+        C: Into<ControlFlow>,
+    {
+        Self::from_csv_with_progress(s.as_bytes(), s.len(), f)
+    }
+
+    /// Loads a table from a CSV file found at the given path, reporting
+    /// progress in bytes
+    #[inline]
+    fn from_csv_file_with_progress<P, F, C>(p: P, f: F) -> io::Result<Self::Output>
+    where
+        P: AsRef<Path>,
+        F: FnMut(usize, usize) -> C,
+        C: Into<ControlFlow>,
+    {
+        let file = File::open(p)?;
+        let total = file.metadata()?.len() as usize;
+        Self::from_csv_with_progress(file, total, f)
+    }
 }
 
 impl<T: Table<Data = String> + Default> FromCsv for T {
     type Output = T;
 
     fn from_csv<R: io::Read>(reader: R) -> io::Result<Self::Output> {
+        Self::from_csv_with_progress(reader, 0, |_, _| ControlFlow::Continue)
+    }
+
+    fn from_csv_with_progress<R, F, C>(
+        reader: R,
+        total: usize,
+        mut f: F,
+    ) -> io::Result<Self::Output>
+    where
+        R: io::Read,
+        F: FnMut(usize, usize) -> C,
+        C: Into<ControlFlow>,
+    {
+        trace_span!("from_csv", total_bytes = total);
         let mut table = T::default();
 
         let mut rdr = csv_lib::ReaderBuilder::new()
             .has_headers(false)
             .from_reader(reader);
-        for (row, result) in rdr.records().enumerate() {
-            let record = result?;
+        let mut record = csv_lib::StringRecord::new();
+        let mut row = 0;
+        while rdr.read_record(&mut record)? {
             for col in 0..record.len() {
                 table.insert_cell(row, col, record[col].to_string());
             }
+            row += 1;
+
+            let done = rdr.position().byte() as usize;
+            if f(done, total).into().is_break() {
+                trace_event!(rows = row, bytes = done, "stopped parsing csv");
+                return Err(PartialCompletion {
+                    done,
+                    total: cmp::max(done, total),
+                }
+                .into());
+            }
         }
 
+        trace_event!(
+            rows = row,
+            bytes = rdr.position().byte(),
+            cells = table.len(),
+            "parsed csv"
+        );
         Ok(table)
     }
 }
@@ -63,21 +166,421 @@ pub trait ToCsv {
     fn to_csv_file<P: AsRef<Path>>(&self, p: P) -> io::Result<()> {
         self.to_csv(File::create(p)?)
     }
+
+    /// Writes a table like [`ToCsv::to_csv`], reporting the rows written so
+    /// far and the total rows after each row
+    ///
+    /// If the callback breaks, writing stops and an error of kind
+    /// [`io::ErrorKind::Other`] wrapping a [`PartialCompletion`] is
+    /// returned, leaving the rows already written in the writer
+    ///
+    /// By default, the whole table is written and progress is reported once
+    /// at the end as a single step, as the rows are only known to
+    /// implementations that write them one at a time
+    fn to_csv_with_progress<W, F, C>(&self, writer: W, mut f: F) -> io::Result<()>
+    where
+        W: io::Write,
+        F: FnMut(usize, usize) -> C,
+        C: Into<ControlFlow>,
+    {
+        self.to_csv(writer)?;
+        f(1, 1);
+        Ok(())
+    }
 }
 
 impl<D: AsRef<[u8]>, T: Table<Data = D>> ToCsv for T {
     fn to_csv<W: io::Write>(&self, writer: W) -> io::Result<()> {
+        self.to_csv_with_progress(writer, |_, _| ControlFlow::Continue)
+    }
+
+    fn to_csv_with_progress<W, F, C>(&self, writer: W, mut f: F) -> io::Result<()>
+    where
+        W: io::Write,
+        F: FnMut(usize, usize) -> C,
+        C: Into<ControlFlow>,
+    {
         let mut wtr = csv_lib::WriterBuilder::new()
             .has_headers(false)
             .from_writer(writer);
-        for row in self.rows() {
-            wtr.write_record(row)?;
+        let total = self.row_cnt();
+        trace_span!("to_csv", rows = total, cols = self.col_cnt());
+        for row in 0..total {
+            // Missing cells are written as empty fields so that every record
+            // has a field per column
+            wtr.write_record(
+                self.row_complete(row)
+                    .map(|x| x.map(AsRef::as_ref).unwrap_or_default()),
+            )?;
+
+            if f(row + 1, total).into().is_break() {
+                wtr.flush()?;
+                return Err(PartialCompletion {
+                    done: row + 1,
+                    total,
+                }
+                .into());
+            }
         }
 
         Ok(())
     }
 }
 
+/// Represents a value parsed from the text of a CSV cell whose type was
+/// inferred by [`FromCsvInferred`]
+#[cfg_attr(feature = "docs", doc(cfg(csv)))]
+#[derive(Clone, Debug, PartialEq)]
+pub enum DynamicCell {
+    /// A whole number such as `-12`
+    Int(i64),
+
+    /// A number with a fractional part such as `1.5`
+    Float(f64),
+
+    /// Either `true` or `false`, ignoring case
+    Bool(bool),
+
+    /// A calendar date written as `YYYY-MM-DD`
+    Date {
+        /// Year of the date
+        year: i32,
+        /// Month of the date, starting at 1
+        month: u8,
+        /// Day of the month, starting at 1
+        day: u8,
+    },
+
+    /// Any other text
+    Text(String),
+}
+
+impl fmt::Display for DynamicCell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Int(x) => write!(f, "{}", x),
+            Self::Float(x) => write!(f, "{}", x),
+            Self::Bool(x) => write!(f, "{}", x),
+            Self::Date { year, month, day } => write!(f, "{:04}-{:02}-{:02}", year, month, day),
+            Self::Text(x) => f.write_str(x),
+        }
+    }
+}
+
+/// Represents the type inferred for a column of a CSV
+#[cfg_attr(feature = "docs", doc(cfg(csv)))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CsvType {
+    /// Every cell is a whole number
+    Int,
+
+    /// Every cell is a number, at least one with a fractional part
+    Float,
+
+    /// Every cell is `true` or `false`
+    Bool,
+
+    /// Every cell is a date written as `YYYY-MM-DD`
+    Date,
+
+    /// Cells are of mixed types or are plain text
+    Text,
+}
+
+impl CsvType {
+    /// Returns the narrowest type of the text of a single cell, where text
+    /// such as `NaN` and `inf` is not treated as a number
+    fn of(s: &str) -> Self {
+        if s.parse::<i64>().is_ok() {
+            Self::Int
+        } else if parse_finite(s).is_some() {
+            Self::Float
+        } else if s.eq_ignore_ascii_case("true") || s.eq_ignore_ascii_case("false") {
+            Self::Bool
+        } else if parse_date(s).is_some() {
+            Self::Date
+        } else {
+            Self::Text
+        }
+    }
+
+    /// Returns the narrowest type able to hold values of both types
+    fn merge(self, other: Self) -> Self {
+        match (self, other) {
+            (x, y) if x == y => x,
+            (Self::Int, Self::Float) | (Self::Float, Self::Int) => Self::Float,
+            _ => Self::Text,
+        }
+    }
+
+    /// Parses the text of a cell as this type, falling back to text if the
+    /// cell does not fit the type
+    fn parse(self, s: &str) -> DynamicCell {
+        let cell = match self {
+            Self::Int => s.parse().ok().map(DynamicCell::Int),
+            Self::Float => parse_finite(s).map(DynamicCell::Float),
+            Self::Bool => match CsvType::of(s) {
+                Self::Bool => Some(DynamicCell::Bool(s.eq_ignore_ascii_case("true"))),
+                _ => None,
+            },
+            Self::Date => {
+                parse_date(s).map(|(year, month, day)| DynamicCell::Date { year, month, day })
+            }
+            Self::Text => None,
+        };
+
+        cell.unwrap_or_else(|| DynamicCell::Text(s.to_string()))
+    }
+}
+
+/// Parses a number that is neither infinite nor `NaN`
+fn parse_finite(s: &str) -> Option<f64> {
+    s.parse::<f64>().ok().filter(|x| x.is_finite())
+}
+
+/// Parses a date written as `YYYY-MM-DD` into its year, month, and day
+fn parse_date(s: &str) -> Option<(i32, u8, u8)> {
+    let mut parts = s.splitn(3, '-');
+    let (year, month, day) = (parts.next()?, parts.next()?, parts.next()?);
+
+    if year.len() != 4 || month.len() != 2 || day.len() != 2 {
+        return None;
+    }
+    if !s.bytes().all(|b| b.is_ascii_digit() || b == b'-') {
+        return None;
+    }
+
+    let year: i32 = year.parse().ok()?;
+    let month: u8 = month.parse().ok()?;
+    let day: u8 = day.parse().ok()?;
+
+    let is_leap_year = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year => 29,
+        2 => 28,
+        _ => return None,
+    };
+
+    if (1..=days_in_month).contains(&day) {
+        Some((year, month, day))
+    } else {
+        None
+    }
+}
+
+/// Represents a column of a CSV as described by its inferred schema
+#[cfg_attr(feature = "docs", doc(cfg(csv)))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CsvColumn {
+    /// Name of the column taken from the header row, if there is one
+    pub name: Option<String>,
+
+    /// Type inferred from the cells of the column
+    pub ty: CsvType,
+
+    /// Whether or not the column has empty cells
+    pub nullable: bool,
+}
+
+/// Represents the shape of a CSV as inferred from its cells
+#[cfg_attr(feature = "docs", doc(cfg(csv)))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CsvSchema {
+    /// Whether or not the first row was determined to be a header row
+    pub has_headers: bool,
+
+    /// Description of each column
+    pub columns: Vec<CsvColumn>,
+}
+
+impl CsvSchema {
+    /// Infers the schema of a table of CSV text
+    ///
+    /// The first row is treated as a header row if some column has a
+    /// non-text type across the remaining rows but its first cell does not
+    /// fit that type. Empty cells are ignored when inferring types
+    pub fn infer<D: AsRef<str>, T: Table<Data = D>>(table: &T) -> Self {
+        let infer_column = |col: usize, rows: Range<usize>| {
+            let mut ty = None;
+            let mut nullable = false;
+
+            for row in rows {
+                match table.cell(row, col).map(AsRef::as_ref) {
+                    Some(x) if !x.is_empty() => {
+                        let x_ty = CsvType::of(x);
+                        ty = Some(ty.map_or(x_ty, |ty: CsvType| ty.merge(x_ty)));
+                    }
+                    _ => nullable = true,
+                }
+            }
+
+            CsvColumn {
+                name: None,
+                ty: ty.unwrap_or(CsvType::Text),
+                nullable,
+            }
+        };
+
+        let row_cnt = table.row_cnt();
+        let body: Vec<CsvColumn> = (0..table.col_cnt())
+            .map(|col| infer_column(col, 1..row_cnt))
+            .collect();
+
+        let has_headers = row_cnt > 1
+            && body.iter().enumerate().any(|(col, column)| {
+                match table.cell(0, col).map(AsRef::as_ref) {
+                    Some(x) if !x.is_empty() && column.ty != CsvType::Text => {
+                        column.ty.merge(CsvType::of(x)) != column.ty
+                    }
+                    _ => false,
+                }
+            });
+
+        let columns = if has_headers {
+            body.into_iter()
+                .enumerate()
+                .map(|(col, column)| CsvColumn {
+                    name: table.cell(0, col).map(|x| x.as_ref().to_string()),
+                    ..column
+                })
+                .collect()
+        } else {
+            (0..table.col_cnt())
+                .map(|col| infer_column(col, 0..row_cnt))
+                .collect()
+        };
+
+        Self {
+            has_headers,
+            columns,
+        }
+    }
+
+    /// Converts a table of CSV text into a table of values typed according to
+    /// the schema, dropping the header row if there is one
+    ///
+    /// Empty cells are left missing, and cells that do not fit the type of
+    /// their column are kept as text
+    pub fn apply<D: AsRef<str>, T: Table<Data = D>>(&self, table: &T) -> DynamicTable<DynamicCell> {
+        let skip = if self.has_headers { 1 } else { 0 };
+        let mut typed = DynamicTable::new();
+        typed.set_preferred_row_cnt(table.row_cnt().saturating_sub(skip));
+        typed.set_preferred_col_cnt(table.col_cnt());
+
+        for row in skip..table.row_cnt() {
+            for col in 0..table.col_cnt() {
+                let ty = self.columns.get(col).map_or(CsvType::Text, |x| x.ty);
+                match table.cell(row, col).map(AsRef::as_ref) {
+                    Some(x) if !x.is_empty() => {
+                        typed.insert_cell(row - skip, col, ty.parse(x));
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        typed
+    }
+}
+
+/// Represents ability to load a CSV into a table of typed values, inferring
+/// the type of each column and whether the CSV has a header row
+///
+/// ### Examples
+///
+/// ```
+/// # use memtable_core::{prelude::*, exts::csv::{CsvType, DynamicCell, FromCsvInferred}};
+/// let (table, schema) = DynamicTable::from_csv_str_inferred("id,ok\n1,true\n2,false").unwrap();
+///
+/// assert!(schema.has_headers);
+/// assert_eq!(schema.columns[0].name.as_deref(), Some("id"));
+/// assert_eq!(schema.columns[1].ty, CsvType::Bool);
+/// assert_eq!(table.cell(1, 0), Some(&DynamicCell::Int(2)));
+/// ```
+#[cfg_attr(feature = "docs", doc(cfg(csv)))]
+pub trait FromCsvInferred: Sized {
+    /// Loads a table of typed values from some instance of the [`io::Read`]
+    /// trait, returning the table alongside its inferred schema
+    fn from_csv_inferred<R: io::Read>(reader: R) -> io::Result<(Self, CsvSchema)>;
+
+    /// Loads a table of typed values from a CSV str
+    #[inline]
+    fn from_csv_str_inferred(s: &str) -> io::Result<(Self, CsvSchema)> {
+        Self::from_csv_inferred(s.as_bytes())
+    }
+}
+
+impl FromCsvInferred for DynamicTable<DynamicCell> {
+    fn from_csv_inferred<R: io::Read>(reader: R) -> io::Result<(Self, CsvSchema)> {
+        let table = DynamicTable::<String>::from_csv(reader)?;
+        let schema = CsvSchema::infer(&table);
+        Ok((schema.apply(&table), schema))
+    }
+}
+
+impl<U: Table<Data = String> + Default> HeaderTable<String, U> {
+    /// Loads a table from a CSV whose first record is a header row naming
+    /// the columns, where empty headers leave their column unnamed
+    ///
+    /// Fails with an error of kind [`io::ErrorKind::InvalidData`] if a
+    /// name is used more than once
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # use memtable_core::{prelude::*, wrappers::HeaderTable};
+    /// let table: HeaderTable<String, DynamicTable<String>> =
+    ///     HeaderTable::from_csv_with_headers("id,name\n1,alice\n".as_bytes()).unwrap();
+    ///
+    /// assert_eq!(table.column_index("name"), Some(1));
+    /// assert_eq!(table.cell(0, 1).map(String::as_str), Some("alice"));
+    /// ```
+    pub fn from_csv_with_headers<R: io::Read>(reader: R) -> io::Result<Self> {
+        let mut table = U::from_csv(reader)?;
+        let names: Vec<Option<String>> = table
+            .row_complete(0)
+            .map(|x| x.filter(|x| !x.is_empty()).cloned())
+            .collect();
+        table.remove_row(0);
+
+        Self::with_optional_names(table, names)
+            .map_err(|x| io::Error::new(io::ErrorKind::InvalidData, x))
+    }
+}
+
+impl<D: AsRef<[u8]>, U: Table<Data = D>> HeaderTable<D, U> {
+    /// Writes the table to a CSV, starting with a header row naming the
+    /// columns where unnamed columns have an empty header
+    ///
+    /// Names of columns past the table's column count are left out so that
+    /// every record has the same number of fields
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # use memtable_core::{prelude::*, wrappers::HeaderTable};
+    /// let table = DynamicTable::from([["1", "alice"]]);
+    /// let table = HeaderTable::new(table, vec!["id", "name"]).unwrap();
+    ///
+    /// let mut buf = Vec::new();
+    /// table.to_csv_with_headers(&mut buf).unwrap();
+    /// assert_eq!(buf, b"id,name\n1,alice\n");
+    /// ```
+    pub fn to_csv_with_headers<W: io::Write>(&self, mut writer: W) -> io::Result<()> {
+        let col_cnt = self.col_cnt();
+        let mut wtr = csv_lib::WriterBuilder::new()
+            .has_headers(false)
+            .from_writer(&mut writer);
+        wtr.write_record((0..col_cnt).map(|col| self.column_name(col).unwrap_or_default()))?;
+        wtr.flush()?;
+        drop(wtr);
+
+        self.as_inner().to_csv(writer)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -119,6 +622,37 @@ mod tests {
         assert_eq!(table[(1, 2)], "f");
     }
 
+    #[test]
+    fn header_table_should_keep_names_through_csv_round_trip() {
+        let table: HeaderTable<String, TestTable<String>> =
+            HeaderTable::from_csv_with_headers("a,,c\n1,2,3\n".as_bytes()).unwrap();
+        assert_eq!(
+            table.names().collect::<Vec<_>>(),
+            vec![Some("a"), None, Some("c")]
+        );
+        assert_eq!(table.row_cnt(), 1);
+
+        let mut buf = Vec::new();
+        table.to_csv_with_headers(&mut buf).unwrap();
+        assert_eq!(buf, b"a,,c\n1,2,3\n");
+    }
+
+    #[test]
+    fn header_table_to_csv_should_skip_names_past_last_column() {
+        let table = HeaderTable::new(TestTable::from([["1", "2"]]), vec!["a", "b", "c"]).unwrap();
+
+        let mut buf = Vec::new();
+        table.to_csv_with_headers(&mut buf).unwrap();
+        assert_eq!(buf, b"a,b\n1,2\n");
+    }
+
+    #[test]
+    fn header_table_from_csv_should_fail_if_name_is_repeated() {
+        let result: io::Result<HeaderTable<String, TestTable<String>>> =
+            HeaderTable::from_csv_with_headers("a,a\n1,2\n".as_bytes());
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::InvalidData);
+    }
+
     #[test]
     fn to_csv_str_should_convert_into_csv() {
         let mut file = NamedTempFile::new().unwrap();
@@ -133,4 +667,177 @@ mod tests {
         file.read_to_string(&mut buffer).unwrap();
         assert_eq!(buffer, "a,b,c\nd,e,f\n")
     }
+
+    #[test]
+    fn to_csv_str_should_write_missing_cells_as_empty_fields() {
+        let mut table = TestTable::new();
+        table.push_row(vec!["a", "b", "c"]);
+        table.push_row(vec!["d", "e", "f"]);
+        table.remove_cell(0, 0);
+        table.remove_cell(1, 1);
+
+        assert_eq!(table.to_csv_str().unwrap(), ",b,c\nd,,f\n");
+    }
+
+    #[test]
+    fn infer_should_detect_header_from_type_mismatch() {
+        let table =
+            TestTable::from_csv_str("name,age,joined\nalice,30,2021-02-28\nbob,,2020-02-29\n")
+                .unwrap();
+        let schema = CsvSchema::infer(&table);
+
+        assert!(schema.has_headers);
+        assert_eq!(
+            schema.columns,
+            vec![
+                CsvColumn {
+                    name: Some("name".to_string()),
+                    ty: CsvType::Text,
+                    nullable: false,
+                },
+                CsvColumn {
+                    name: Some("age".to_string()),
+                    ty: CsvType::Int,
+                    nullable: true,
+                },
+                CsvColumn {
+                    name: Some("joined".to_string()),
+                    ty: CsvType::Date,
+                    nullable: false,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn infer_should_not_detect_header_if_first_row_fits_types() {
+        let table = TestTable::from_csv_str("1,a\n2.5,b\n3,c\n").unwrap();
+        let schema = CsvSchema::infer(&table);
+
+        assert!(!schema.has_headers);
+        assert_eq!(schema.columns[0].ty, CsvType::Float);
+        assert_eq!(schema.columns[1].ty, CsvType::Text);
+        assert_eq!(schema.columns[0].name, None);
+    }
+
+    #[test]
+    fn infer_should_treat_non_finite_numbers_as_text() {
+        let table = TestTable::from_csv_str("1.5,1\nNaN,inf\n2,-infinity\n").unwrap();
+        let schema = CsvSchema::infer(&table);
+
+        assert!(!schema.has_headers);
+        assert_eq!(schema.columns[0].ty, CsvType::Text);
+        assert_eq!(schema.columns[1].ty, CsvType::Text);
+        assert_eq!(
+            CsvType::Float.parse("inf"),
+            DynamicCell::Text("inf".to_string())
+        );
+    }
+
+    #[test]
+    fn from_csv_inferred_should_produce_typed_cells() {
+        let (table, schema) =
+            DynamicTable::from_csv_str_inferred("x,flag,when\n1.5,TRUE,2021-13-01\n2,false,\n")
+                .unwrap();
+
+        assert!(schema.has_headers);
+        assert_eq!(schema.columns[2].ty, CsvType::Text);
+        assert_eq!(table.row_cnt(), 2);
+        assert_eq!(table.cell(0, 0), Some(&DynamicCell::Float(1.5)));
+        assert_eq!(table.cell(1, 0), Some(&DynamicCell::Float(2.0)));
+        assert_eq!(table.cell(0, 1), Some(&DynamicCell::Bool(true)));
+        assert_eq!(
+            table.cell(0, 2),
+            Some(&DynamicCell::Text("2021-13-01".to_string()))
+        );
+        assert_eq!(table.cell(1, 2), None);
+    }
+
+    #[test]
+    fn from_csv_str_with_progress_should_report_bytes_read() {
+        let mut reports = Vec::new();
+        let table = TestTable::from_csv_str_with_progress("a,b\nc,d\n", |done, total| {
+            reports.push((done, total));
+        })
+        .unwrap();
+
+        assert_eq!(table, [["a", "b"], ["c", "d"]]);
+        assert_eq!(reports, vec![(4, 8), (8, 8)]);
+    }
+
+    #[test]
+    fn from_csv_with_progress_should_stop_if_callback_breaks() {
+        let err = TestTable::from_csv_str_with_progress("a\nb\nc\n", |done, _| {
+            if done >= 4 {
+                ControlFlow::Break
+            } else {
+                ControlFlow::Continue
+            }
+        })
+        .unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::Other);
+        let partial = err.get_ref().unwrap().downcast_ref::<PartialCompletion>();
+        assert_eq!(partial, Some(&PartialCompletion { done: 4, total: 6 }));
+    }
+
+    #[test]
+    fn to_csv_with_progress_should_keep_rows_written_before_stopping() {
+        let table = TestTable::from([["a"], ["b"], ["c"]]);
+        let mut buf = Vec::new();
+
+        let err = table
+            .to_csv_with_progress(&mut buf, |done, total| {
+                assert_eq!(total, 3);
+                if done == 2 {
+                    ControlFlow::Break
+                } else {
+                    ControlFlow::Continue
+                }
+            })
+            .unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::Other);
+        assert_eq!(buf, b"a\nb\n");
+    }
+
+    #[test]
+    fn from_csv_cancellable_should_stop_if_cancelled_during_first_record() {
+        let token = CancelToken::new();
+        let err = TestTable::from_csv_cancellable(
+            CancelOnRead {
+                bytes: "a\nb\n".as_bytes(),
+                token: token.clone(),
+            },
+            &token,
+        )
+        .unwrap_err();
+
+        let partial = err.get_ref().unwrap().downcast_ref::<PartialCompletion>();
+        assert_eq!(partial, Some(&PartialCompletion { done: 2, total: 2 }));
+    }
+
+    /// Reader that cancels the token as soon as it is first read
+    struct CancelOnRead<'a> {
+        bytes: &'a [u8],
+        token: CancelToken,
+    }
+
+    impl io::Read for CancelOnRead<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.token.cancel();
+            self.bytes.read(buf)
+        }
+    }
+
+    #[test]
+    fn from_csv_cancellable_should_stop_once_cancelled() {
+        let token = CancelToken::new();
+        assert!(TestTable::from_csv_cancellable("a\nb\n".as_bytes(), &token).is_ok());
+
+        token.cancel();
+        let err = TestTable::from_csv_cancellable("a\nb\n".as_bytes(), &token).unwrap_err();
+        let partial = err.get_ref().unwrap().downcast_ref::<PartialCompletion>();
+        assert_eq!(partial, Some(&PartialCompletion { done: 0, total: 0 }));
+    }
 }
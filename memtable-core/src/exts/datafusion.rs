@@ -0,0 +1,239 @@
+use crate::Table;
+use ::datafusion::{
+    arrow::{
+        array::{ArrayRef, BooleanArray, PrimitiveArray, StringArray},
+        datatypes::{self, DataType, Field, Schema},
+        error::ArrowError,
+        record_batch::RecordBatch,
+    },
+    datasource::MemTable,
+    error::DataFusionError,
+};
+use std::sync::Arc;
+
+/// Represents data that can be stored in a cell of an arrow column
+///
+/// Implementing this for the data type of a table derived via the `Table`
+/// macro enables converting the derived table as well
+#[cfg_attr(feature = "docs", doc(cfg(datafusion)))]
+pub trait ArrowCell {
+    /// Returns the arrow type of a column holding the data
+    fn data_type() -> DataType;
+
+    /// Builds an arrow column from the cells of a table's column, where
+    /// missing cells become nulls
+    fn to_array(cells: Vec<Option<&Self>>) -> ArrayRef;
+}
+
+impl ArrowCell for String {
+    fn data_type() -> DataType {
+        DataType::Utf8
+    }
+
+    fn to_array(cells: Vec<Option<&Self>>) -> ArrayRef {
+        Arc::new(StringArray::from(
+            cells
+                .into_iter()
+                .map(|x| x.map(String::as_str))
+                .collect::<Vec<Option<&str>>>(),
+        ))
+    }
+}
+
+impl ArrowCell for &str {
+    fn data_type() -> DataType {
+        DataType::Utf8
+    }
+
+    fn to_array(cells: Vec<Option<&Self>>) -> ArrayRef {
+        Arc::new(StringArray::from(
+            cells
+                .into_iter()
+                .map(|x| x.copied())
+                .collect::<Vec<Option<&str>>>(),
+        ))
+    }
+}
+
+impl ArrowCell for bool {
+    fn data_type() -> DataType {
+        DataType::Boolean
+    }
+
+    fn to_array(cells: Vec<Option<&Self>>) -> ArrayRef {
+        Arc::new(BooleanArray::from(
+            cells
+                .into_iter()
+                .map(|x| x.copied())
+                .collect::<Vec<Option<bool>>>(),
+        ))
+    }
+}
+
+macro_rules! impl_arrow_cell {
+    ($($t:ty => $variant:ident, $arrow:ident);* $(;)?) => {$(
+        impl ArrowCell for $t {
+            fn data_type() -> DataType {
+                DataType::$variant
+            }
+
+            fn to_array(cells: Vec<Option<&Self>>) -> ArrayRef {
+                Arc::new(PrimitiveArray::<datatypes::$arrow>::from(
+                    cells.into_iter().map(|x| x.copied()).collect::<Vec<Option<$t>>>(),
+                ))
+            }
+        }
+    )*};
+}
+
+impl_arrow_cell!(
+    i8 => Int8, Int8Type;
+    i16 => Int16, Int16Type;
+    i32 => Int32, Int32Type;
+    i64 => Int64, Int64Type;
+    u8 => UInt8, UInt8Type;
+    u16 => UInt16, UInt16Type;
+    u32 => UInt32, UInt32Type;
+    u64 => UInt64, UInt64Type;
+    f32 => Float32, Float32Type;
+    f64 => Float64, Float64Type;
+);
+
+/// Represents ability to convert a table into arrow data that DataFusion can
+/// query with sql
+///
+/// ```ignore
+/// let ctx = SessionContext::new();
+/// ctx.register_table("people", Arc::new(table.to_table_provider()?))?;
+/// let df = ctx.sql("SELECT c0 FROM people WHERE c1 > 30").await?;
+/// ```
+#[cfg_attr(feature = "docs", doc(cfg(datafusion)))]
+pub trait ToRecordBatch {
+    /// Converts the table into a record batch whose columns are named `c0`,
+    /// `c1`, and so on
+    fn to_record_batch(&self) -> Result<RecordBatch, ArrowError>;
+
+    /// Converts the table into a record batch whose columns are given the
+    /// provided names, failing if there is not a name for every column
+    fn to_record_batch_with_names(&self, names: &[&str]) -> Result<RecordBatch, ArrowError>;
+
+    /// Converts the table into a [`MemTable`], which implements DataFusion's
+    /// `TableProvider` so the table can be registered and queried with sql
+    ///
+    /// The provider holds a copy of the table as it was when converted, so
+    /// later changes to the table are not seen by queries
+    fn to_table_provider(&self) -> Result<MemTable, DataFusionError> {
+        let batch = self.to_record_batch()?;
+        MemTable::try_new(batch.schema(), vec![vec![batch]])
+    }
+}
+
+impl<D: ArrowCell, T: Table<Data = D>> ToRecordBatch for T {
+    fn to_record_batch(&self) -> Result<RecordBatch, ArrowError> {
+        let names: Vec<String> = (0..self.col_cnt()).map(|col| format!("c{}", col)).collect();
+        let names: Vec<&str> = names.iter().map(String::as_str).collect();
+        self.to_record_batch_with_names(&names)
+    }
+
+    fn to_record_batch_with_names(&self, names: &[&str]) -> Result<RecordBatch, ArrowError> {
+        if names.len() != self.col_cnt() {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Expected {} column names, but got {}",
+                self.col_cnt(),
+                names.len()
+            )));
+        }
+
+        let schema = Schema::new(
+            names
+                .iter()
+                .map(|name| Field::new(*name, D::data_type(), true))
+                .collect::<Vec<_>>(),
+        );
+        let columns = (0..self.col_cnt())
+            .map(|col| D::to_array((0..self.row_cnt()).map(|row| self.cell(row, col)).collect()))
+            .collect();
+
+        RecordBatch::try_new(Arc::new(schema), columns)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DynamicTable;
+    use ::datafusion::arrow::array::{Array, Int32Array};
+
+    #[test]
+    fn to_record_batch_should_convert_each_column() {
+        let mut table = DynamicTable::from([[1, 2], [3, 4], [5, 6]]);
+        table.remove_cell(1, 0);
+
+        let batch = table.to_record_batch().unwrap();
+        assert_eq!(batch.num_rows(), 3);
+        assert_eq!(batch.num_columns(), 2);
+        assert_eq!(batch.schema().field(0).name(), "c0");
+        assert_eq!(batch.schema().field(1).data_type(), &DataType::Int32);
+
+        let col = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .unwrap();
+        assert_eq!(col.value(0), 1);
+        assert!(col.is_null(1));
+        assert_eq!(col.value(2), 5);
+    }
+
+    #[test]
+    fn to_record_batch_with_names_should_name_columns() {
+        let table = DynamicTable::from([["alice", "paris"]]);
+
+        let batch = table.to_record_batch_with_names(&["name", "city"]).unwrap();
+        assert_eq!(batch.schema().field(0).name(), "name");
+        assert_eq!(batch.schema().field(1).name(), "city");
+        assert_eq!(batch.schema().field(1).data_type(), &DataType::Utf8);
+    }
+
+    #[test]
+    fn to_record_batch_with_names_should_fail_if_names_do_not_match_columns() {
+        let table = DynamicTable::from([[true, false]]);
+        assert!(table.to_record_batch_with_names(&["a"]).is_err());
+    }
+
+    #[test]
+    fn to_table_provider_should_hold_table_schema() {
+        use ::datafusion::datasource::TableProvider;
+
+        let table = DynamicTable::from([[1.5f64], [2.5]]);
+
+        let provider = table.to_table_provider().unwrap();
+        assert_eq!(provider.schema().fields().len(), 1);
+        assert_eq!(provider.schema().field(0).data_type(), &DataType::Float64);
+    }
+
+    #[tokio::test]
+    async fn to_table_provider_should_be_queryable_with_sql() {
+        use ::datafusion::prelude::SessionContext;
+
+        let table = DynamicTable::from([[1, 20], [2, 40], [3, 60]]);
+
+        let ctx = SessionContext::new();
+        ctx.register_table("t", Arc::new(table.to_table_provider().unwrap()))
+            .unwrap();
+        let batches = ctx
+            .sql("SELECT c0 FROM t WHERE c1 > 30")
+            .await
+            .unwrap()
+            .collect()
+            .await
+            .unwrap();
+
+        let col = batches[0]
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .unwrap();
+        assert_eq!(col.values(), &[2, 3]);
+    }
+}
@@ -0,0 +1,1135 @@
+use super::a1::{parse_a1, A1Error, A1Range};
+use crate::{
+    list::{DynamicList, List},
+    utils, Capacity, Position, Table,
+};
+use core::{fmt, iter::Peekable, mem, str::CharIndices};
+use std::{
+    boxed::Box,
+    collections::{BTreeMap, BTreeSet},
+    string::String,
+    vec::Vec,
+};
+
+/// Represents an arithmetic expression that computes the value of a cell
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "docs", doc(cfg(formula)))]
+pub enum Expr {
+    /// A constant number
+    Number(f64),
+
+    /// The value of another cell, where an empty cell is zero
+    Ref(Position),
+
+    /// The sum of the values of every cell in a range
+    Sum(A1Range),
+
+    /// The negation of an expression
+    Neg(Box<Expr>),
+
+    /// The sum of two expressions
+    Add(Box<Expr>, Box<Expr>),
+
+    /// The difference of two expressions
+    Sub(Box<Expr>, Box<Expr>),
+
+    /// The product of two expressions
+    Mul(Box<Expr>, Box<Expr>),
+
+    /// The quotient of two expressions
+    Div(Box<Expr>, Box<Expr>),
+
+    /// A reference to a cell or range that was removed from the table, which
+    /// fails to compute with [`EvalError::InvalidReference`]
+    InvalidRef,
+}
+
+impl Expr {
+    /// Parses an expression such as `"=A1 * 2 + SUM(B1:B10)"`, where the
+    /// leading `=` is optional
+    ///
+    /// Supports numbers, A1-style references, `SUM` over a range,
+    /// parentheses, unary `-`, and the `+`, `-`, `*`, and `/` operators
+    pub fn parse(s: &str) -> Result<Self, FormulaError> {
+        let s = s.trim_start();
+        let s = s.strip_prefix('=').unwrap_or(s);
+        let mut parser = Parser {
+            input: s,
+            chars: s.char_indices().peekable(),
+        };
+
+        let expr = parser.expr()?;
+        match parser.peek() {
+            None => Ok(expr),
+            Some((offset, c)) => Err(FormulaError::Unexpected {
+                offset,
+                found: Some(c),
+            }),
+        }
+    }
+
+    /// Visits every cell and range that the expression references
+    fn visit_refs(&self, f: &mut impl FnMut(Reference)) {
+        match self {
+            Self::Number(_) | Self::InvalidRef => {}
+            Self::Ref(x) => f(Reference::Cell(*x)),
+            Self::Sum(x) => f(Reference::Range(*x)),
+            Self::Neg(x) => x.visit_refs(f),
+            Self::Add(a, b) | Self::Sub(a, b) | Self::Mul(a, b) | Self::Div(a, b) => {
+                a.visit_refs(f);
+                b.visit_refs(f);
+            }
+        }
+    }
+
+    /// Rewrites every cell and range that the expression references, where
+    /// a reference that maps to `None` becomes [`Expr::InvalidRef`]
+    fn remap(
+        &self,
+        cell: &impl Fn(Position) -> Option<Position>,
+        range: &impl Fn(A1Range) -> Option<A1Range>,
+    ) -> Self {
+        let remap = |x: &Self| Box::new(x.remap(cell, range));
+        match self {
+            Self::Number(x) => Self::Number(*x),
+            Self::Ref(x) => cell(*x).map_or(Self::InvalidRef, Self::Ref),
+            Self::Sum(x) => range(*x).map_or(Self::InvalidRef, Self::Sum),
+            Self::Neg(x) => Self::Neg(remap(x)),
+            Self::Add(a, b) => Self::Add(remap(a), remap(b)),
+            Self::Sub(a, b) => Self::Sub(remap(a), remap(b)),
+            Self::Mul(a, b) => Self::Mul(remap(a), remap(b)),
+            Self::Div(a, b) => Self::Div(remap(a), remap(b)),
+            Self::InvalidRef => Self::InvalidRef,
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+enum Reference {
+    Cell(Position),
+    Range(A1Range),
+}
+
+/// Represents the failure to parse an expression
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "docs", doc(cfg(formula)))]
+pub enum FormulaError {
+    /// Encountered a character that does not belong at the given byte offset,
+    /// or the end of the expression if `found` is `None`
+    Unexpected {
+        /// Byte offset within the expression
+        offset: usize,
+        /// Character that was found, if any
+        found: Option<char>,
+    },
+
+    /// Reference to a cell is not valid
+    InvalidReference(A1Error),
+
+    /// Function is not supported
+    UnknownFunction(String),
+}
+
+impl fmt::Display for FormulaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unexpected {
+                offset,
+                found: Some(c),
+            } => write!(f, "Unexpected {:?} at offset {}", c, offset),
+            Self::Unexpected {
+                offset,
+                found: None,
+            } => {
+                write!(f, "Unexpected end of formula at offset {}", offset)
+            }
+            Self::InvalidReference(x) => write!(f, "Invalid reference: {}", x),
+            Self::UnknownFunction(x) => write!(f, "Unknown function {}", x),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FormulaError {}
+
+/// Represents the failure to compute the value of a cell
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "docs", doc(cfg(formula)))]
+pub enum EvalError {
+    /// Cell depends on itself, directly or through other cells
+    Cycle,
+
+    /// Cell divides by zero
+    DivisionByZero,
+
+    /// Cell references a cell or range that was removed from the table
+    InvalidReference,
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Cycle => write!(f, "Formula depends on itself"),
+            Self::DivisionByZero => write!(f, "Formula divides by zero"),
+            Self::InvalidReference => write!(f, "Formula references a removed cell"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EvalError {}
+
+struct Parser<'a> {
+    input: &'a str,
+    chars: Peekable<CharIndices<'a>>,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&mut self) -> Option<(usize, char)> {
+        while let Some((_, c)) = self.chars.peek() {
+            if c.is_whitespace() {
+                self.chars.next();
+            } else {
+                break;
+            }
+        }
+
+        self.chars.peek().copied()
+    }
+
+    fn unexpected(&mut self) -> FormulaError {
+        match self.peek() {
+            Some((offset, c)) => FormulaError::Unexpected {
+                offset,
+                found: Some(c),
+            },
+            None => FormulaError::Unexpected {
+                offset: self.input.len(),
+                found: None,
+            },
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), FormulaError> {
+        match self.peek() {
+            Some((_, c)) if c == expected => {
+                self.chars.next();
+                Ok(())
+            }
+            _ => Err(self.unexpected()),
+        }
+    }
+
+    /// Consumes the characters while they satisfy the predicate, returning them
+    fn take_while(&mut self, f: impl Fn(char) -> bool) -> &'a str {
+        let start = match self.chars.peek() {
+            Some((offset, _)) => *offset,
+            None => return "",
+        };
+
+        let mut end = start;
+        while let Some((offset, c)) = self.chars.peek().copied() {
+            if !f(c) {
+                break;
+            }
+            end = offset + c.len_utf8();
+            self.chars.next();
+        }
+
+        &self.input[start..end]
+    }
+
+    fn expr(&mut self) -> Result<Expr, FormulaError> {
+        let mut expr = self.term()?;
+
+        loop {
+            match self.peek() {
+                Some((_, '+')) => {
+                    self.chars.next();
+                    expr = Expr::Add(Box::new(expr), Box::new(self.term()?));
+                }
+                Some((_, '-')) => {
+                    self.chars.next();
+                    expr = Expr::Sub(Box::new(expr), Box::new(self.term()?));
+                }
+                _ => return Ok(expr),
+            }
+        }
+    }
+
+    fn term(&mut self) -> Result<Expr, FormulaError> {
+        let mut expr = self.factor()?;
+
+        loop {
+            match self.peek() {
+                Some((_, '*')) => {
+                    self.chars.next();
+                    expr = Expr::Mul(Box::new(expr), Box::new(self.factor()?));
+                }
+                Some((_, '/')) => {
+                    self.chars.next();
+                    expr = Expr::Div(Box::new(expr), Box::new(self.factor()?));
+                }
+                _ => return Ok(expr),
+            }
+        }
+    }
+
+    fn factor(&mut self) -> Result<Expr, FormulaError> {
+        match self.peek() {
+            Some((_, '-')) => {
+                self.chars.next();
+                Ok(Expr::Neg(Box::new(self.factor()?)))
+            }
+            Some((_, '(')) => {
+                self.chars.next();
+                let expr = self.expr()?;
+                self.expect(')')?;
+                Ok(expr)
+            }
+            Some((_, c)) if c.is_ascii_digit() || c == '.' => {
+                let s = self.take_while(|c| c.is_ascii_digit() || c == '.');
+                s.parse().map(Expr::Number).map_err(|_| self.unexpected())
+            }
+            Some((_, c)) if c.is_ascii_alphabetic() => {
+                let name = self.take_while(|c| c.is_ascii_alphanumeric());
+                if matches!(self.chars.peek(), Some((_, '('))) {
+                    self.function(name)
+                } else {
+                    parse_a1(name)
+                        .map(Expr::Ref)
+                        .map_err(FormulaError::InvalidReference)
+                }
+            }
+            _ => Err(self.unexpected()),
+        }
+    }
+
+    fn function(&mut self, name: &str) -> Result<Expr, FormulaError> {
+        if !name.eq_ignore_ascii_case("SUM") {
+            return Err(FormulaError::UnknownFunction(name.into()));
+        }
+
+        self.expect('(')?;
+        self.peek();
+        let start = self.take_while(|c| c.is_ascii_alphanumeric());
+        self.expect(':')?;
+        self.peek();
+        let end = self.take_while(|c| c.is_ascii_alphanumeric());
+        self.expect(')')?;
+
+        let start = parse_a1(start).map_err(FormulaError::InvalidReference)?;
+        let end = parse_a1(end).map_err(FormulaError::InvalidReference)?;
+        Ok(Expr::Sum(A1Range::new(start, end)))
+    }
+}
+
+/// Represents what was placed into a cell of a [`FormulaTable`]
+#[derive(Clone, Debug, PartialEq)]
+enum Input {
+    Value(f64),
+    Formula(Expr),
+}
+
+/// Represents the direction of the lines of cells moved by a [`FormulaTable`]
+#[derive(Copy, Clone)]
+enum Axis {
+    Row,
+    Column,
+}
+
+impl Axis {
+    /// Returns the index of the line holding the position
+    fn of(self, pos: Position) -> usize {
+        match self {
+            Self::Row => pos.row,
+            Self::Column => pos.col,
+        }
+    }
+
+    /// Returns the position moved to the line at the given index
+    fn with(self, pos: Position, idx: usize) -> Position {
+        match self {
+            Self::Row => Position::new(idx, pos.col),
+            Self::Column => Position::new(pos.row, idx),
+        }
+    }
+}
+
+/// Represents a table of numbers where cells can also hold formulas that
+/// compute their value from other cells, like a spreadsheet
+///
+/// Whenever a cell changes, every formula that depends on it (directly or
+/// through other formulas) is recalculated. Reading a cell through the
+/// [`Table`] trait returns its computed value; a formula that fails to
+/// compute (see [`EvalError`]) has no value, and its error is available via
+/// [`FormulaTable::error`]. As changes made through a mutable reference
+/// cannot be observed, [`Table::mut_cell`] only returns a reference to a
+/// cell holding a number that no formula depends on; use
+/// [`Table::insert_cell`] to change any other cell instead
+///
+/// Inserting, removing, and shifting rows and columns moves formulas along
+/// with their cells and rewrites their references to follow the cells they
+/// point to, like a spreadsheet; a formula referencing a removed cell fails
+/// with [`EvalError::InvalidReference`]
+///
+/// ### Examples
+///
+/// ```
+/// use memtable_core::{exts::formula::FormulaTable, Table};
+///
+/// let mut table = FormulaTable::new();
+/// table.insert_cell(0, 0, 2.0);
+/// table.insert_cell(1, 0, 3.0);
+/// table.set_formula(2, 0, "=SUM(A1:A2) * 10").unwrap();
+/// assert_eq!(table.cell(2, 0), Some(&50.0));
+///
+/// table.insert_cell(0, 0, 4.0);
+/// assert_eq!(table.cell(2, 0), Some(&70.0));
+/// ```
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "docs", doc(cfg(formula)))]
+pub struct FormulaTable {
+    inputs: BTreeMap<Position, Input>,
+    values: BTreeMap<Position, f64>,
+    errors: BTreeMap<Position, EvalError>,
+
+    /// Formulas that reference each cell directly
+    dependents: BTreeMap<Position, BTreeSet<Position>>,
+
+    /// Formulas that reference each range
+    range_dependents: Vec<(A1Range, Position)>,
+
+    row_cnt: usize,
+    col_cnt: usize,
+}
+
+impl FormulaTable {
+    /// Creates a new, empty table
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses the expression and places it into the cell at the specified row
+    /// and column, recalculating the cell and everything that depends on it
+    pub fn set_formula(&mut self, row: usize, col: usize, expr: &str) -> Result<(), FormulaError> {
+        let expr = Expr::parse(expr)?;
+        self.set_input(Position { row, col }, Input::Formula(expr));
+        Ok(())
+    }
+
+    /// Returns the formula held by the cell at the specified row and column
+    pub fn formula(&self, row: usize, col: usize) -> Option<&Expr> {
+        match self.inputs.get(&Position { row, col }) {
+            Some(Input::Formula(x)) => Some(x),
+            _ => None,
+        }
+    }
+
+    /// Returns the error produced when computing the formula held by the cell
+    /// at the specified row and column
+    pub fn error(&self, row: usize, col: usize) -> Option<EvalError> {
+        self.errors.get(&Position { row, col }).copied()
+    }
+
+    fn set_input(&mut self, pos: Position, input: Input) {
+        if pos.row >= self.row_cnt {
+            self.row_cnt = pos.row + 1;
+        }
+
+        if pos.col >= self.col_cnt {
+            self.col_cnt = pos.col + 1;
+        }
+
+        self.unlink(pos);
+        self.link(pos, &input);
+        self.inputs.insert(pos, input);
+        self.recalc(pos);
+    }
+
+    /// Adds the cell to the dependents of everything its formula references
+    fn link(&mut self, pos: Position, input: &Input) {
+        if let Input::Formula(expr) = input {
+            let dependents = &mut self.dependents;
+            let range_dependents = &mut self.range_dependents;
+            expr.visit_refs(&mut |r| match r {
+                Reference::Cell(x) => {
+                    dependents.entry(x).or_default().insert(pos);
+                }
+                Reference::Range(x) => range_dependents.push((x, pos)),
+            });
+        }
+    }
+
+    /// Returns true if any formula references the cell
+    fn has_dependents(&self, pos: Position) -> bool {
+        self.dependents.contains_key(&pos)
+            || self
+                .range_dependents
+                .iter()
+                .any(|(range, _)| range.contains(pos))
+    }
+
+    /// Returns the number held by the cell or the value computed by its
+    /// formula
+    fn value(&self, pos: Position) -> Option<&f64> {
+        match self.inputs.get(&pos) {
+            Some(Input::Value(x)) => Some(x),
+            _ => self.values.get(&pos),
+        }
+    }
+
+    fn remove_input(&mut self, pos: Position) -> Option<Input> {
+        self.unlink(pos);
+        let input = self.inputs.remove(&pos);
+        self.recalc(pos);
+        input
+    }
+
+    /// Removes the cell from the dependents of everything its formula
+    /// references
+    fn unlink(&mut self, pos: Position) {
+        if let Some(Input::Formula(expr)) = self.inputs.get(&pos) {
+            let dependents = &mut self.dependents;
+            expr.visit_refs(&mut |r| {
+                if let Reference::Cell(x) = r {
+                    if let Some(set) = dependents.get_mut(&x) {
+                        set.remove(&pos);
+                        if set.is_empty() {
+                            dependents.remove(&x);
+                        }
+                    }
+                }
+            });
+            self.range_dependents.retain(|(_, x)| *x != pos);
+        }
+    }
+
+    /// Recomputes the cell and every formula that depends on it
+    fn recalc(&mut self, pos: Position) {
+        let mut dirty = BTreeSet::new();
+        let mut queue = std::vec![pos];
+
+        while let Some(pos) = queue.pop() {
+            if dirty.insert(pos) {
+                if let Some(set) = self.dependents.get(&pos) {
+                    queue.extend(set.iter().copied());
+                }
+                queue.extend(
+                    self.range_dependents
+                        .iter()
+                        .filter(|(range, _)| range.contains(pos))
+                        .map(|(_, x)| *x),
+                );
+            }
+        }
+
+        self.eval_dirty(dirty);
+    }
+
+    /// Recomputes every dirty cell
+    fn eval_dirty(&mut self, mut dirty: BTreeSet<Position>) {
+        for pos in dirty.iter() {
+            self.values.remove(pos);
+            self.errors.remove(pos);
+        }
+
+        let positions: Vec<Position> = dirty.iter().copied().collect();
+        let mut visiting = BTreeSet::new();
+        for pos in positions {
+            let _ = self.eval_cell(pos, &mut dirty, &mut visiting);
+        }
+    }
+
+    /// Computes the value of the cell, first computing any cells it depends
+    /// on that are dirty
+    fn eval_cell(
+        &mut self,
+        pos: Position,
+        dirty: &mut BTreeSet<Position>,
+        visiting: &mut BTreeSet<Position>,
+    ) -> Result<f64, EvalError> {
+        if !dirty.contains(&pos) {
+            return match (self.value(pos), self.errors.get(&pos)) {
+                (Some(x), _) => Ok(*x),
+                (None, Some(x)) => Err(*x),
+                (None, None) => Ok(0.0),
+            };
+        }
+
+        if !visiting.insert(pos) {
+            return Err(EvalError::Cycle);
+        }
+
+        let result = match self.inputs.get(&pos).cloned() {
+            Some(Input::Value(x)) => Ok(x),
+            Some(Input::Formula(expr)) => self.eval(&expr, dirty, visiting),
+            None => Ok(0.0),
+        };
+
+        visiting.remove(&pos);
+        dirty.remove(&pos);
+
+        if let Some(Input::Formula(_)) = self.inputs.get(&pos) {
+            match result {
+                Ok(x) => {
+                    self.values.insert(pos, x);
+                }
+                Err(x) => {
+                    self.errors.insert(pos, x);
+                }
+            }
+        }
+
+        result
+    }
+
+    fn eval(
+        &mut self,
+        expr: &Expr,
+        dirty: &mut BTreeSet<Position>,
+        visiting: &mut BTreeSet<Position>,
+    ) -> Result<f64, EvalError> {
+        Ok(match expr {
+            Expr::Number(x) => *x,
+            Expr::Ref(x) => self.eval_cell(*x, dirty, visiting)?,
+            Expr::Sum(range) => {
+                let mut total = 0.0;
+                for pos in range.positions() {
+                    total += self.eval_cell(pos, dirty, visiting)?;
+                }
+                total
+            }
+            Expr::InvalidRef => return Err(EvalError::InvalidReference),
+            Expr::Neg(x) => -self.eval(x, dirty, visiting)?,
+            Expr::Add(a, b) => self.eval(a, dirty, visiting)? + self.eval(b, dirty, visiting)?,
+            Expr::Sub(a, b) => self.eval(a, dirty, visiting)? - self.eval(b, dirty, visiting)?,
+            Expr::Mul(a, b) => self.eval(a, dirty, visiting)? * self.eval(b, dirty, visiting)?,
+            Expr::Div(a, b) => {
+                let a = self.eval(a, dirty, visiting)?;
+                let b = self.eval(b, dirty, visiting)?;
+                if b == 0.0 {
+                    return Err(EvalError::DivisionByZero);
+                }
+                a / b
+            }
+        })
+    }
+
+    /// Moves every number and formula to the position given by `cell`,
+    /// dropping those that map to `None`, rewriting the references of each
+    /// formula with `cell` and `range`, and recomputing every formula
+    fn rearrange(
+        &mut self,
+        cell: impl Fn(Position) -> Option<Position>,
+        range: impl Fn(A1Range) -> Option<A1Range>,
+    ) {
+        let inputs = mem::take(&mut self.inputs);
+        self.values.clear();
+        self.errors.clear();
+        self.dependents.clear();
+        self.range_dependents.clear();
+
+        for (pos, input) in inputs {
+            if let Some(pos) = cell(pos) {
+                let input = match input {
+                    Input::Formula(expr) => Input::Formula(expr.remap(&cell, &range)),
+                    x => x,
+                };
+                self.link(pos, &input);
+                self.inputs.insert(pos, input);
+            }
+        }
+
+        let dirty = self.inputs.keys().copied().collect();
+        self.eval_dirty(dirty);
+    }
+
+    /// Inserts `n` empty lines at `idx`, moving down or right every cell on
+    /// or after it
+    fn insert_lines(&mut self, axis: Axis, idx: usize, n: usize) {
+        let cell = |pos: Position| {
+            Some(if axis.of(pos) >= idx {
+                axis.with(pos, axis.of(pos) + n)
+            } else {
+                pos
+            })
+        };
+
+        self.rearrange(cell, |x| Some(A1Range::new(cell(x.start)?, cell(x.end)?)));
+        match axis {
+            Axis::Row if idx < self.row_cnt => self.row_cnt += n,
+            Axis::Column if idx < self.col_cnt => self.col_cnt += n,
+            _ => {}
+        }
+    }
+
+    /// Removes the line at `idx`, moving up or left every cell after it and
+    /// shrinking every range that spans it
+    fn remove_line(&mut self, axis: Axis, idx: usize) {
+        let cell = |pos: Position| match axis.of(pos) {
+            x if x == idx => None,
+            x if x > idx => Some(axis.with(pos, x - 1)),
+            _ => Some(pos),
+        };
+
+        let range = |x: A1Range| {
+            let start = axis.of(x.start);
+            let end = axis.of(x.end);
+            if start == idx && end == idx {
+                return None;
+            }
+
+            Some(A1Range::new(
+                axis.with(x.start, if start > idx { start - 1 } else { start }),
+                axis.with(x.end, if end >= idx { end - 1 } else { end }),
+            ))
+        };
+
+        self.rearrange(cell, range);
+        match axis {
+            Axis::Row => self.row_cnt -= 1,
+            Axis::Column => self.col_cnt -= 1,
+        }
+    }
+
+    /// Shifts every line within the table's rows and columns by `offset`,
+    /// where a range that no longer covers the same cells once shifted
+    /// becomes invalid
+    fn shift_lines(&mut self, axis: Axis, offset: isize, wrap: bool) {
+        let (row_cnt, col_cnt) = (self.row_cnt, self.col_cnt);
+        let len = match axis {
+            Axis::Row => row_cnt,
+            Axis::Column => col_cnt,
+        };
+
+        let cell = |pos: Position| {
+            if pos.row < row_cnt && pos.col < col_cnt {
+                utils::shifted_index(axis.of(pos), len, offset, wrap).map(|x| axis.with(pos, x))
+            } else {
+                Some(pos)
+            }
+        };
+
+        let range = |x: A1Range| {
+            let start = cell(x.start)?;
+            let end = cell(x.end)?;
+            let span = axis.of(x.end) - axis.of(x.start);
+            if axis.of(end).checked_sub(axis.of(start)) == Some(span) {
+                Some(A1Range::new(start, end))
+            } else {
+                None
+            }
+        };
+
+        self.rearrange(cell, range);
+    }
+}
+
+impl Table for FormulaTable {
+    type Data = f64;
+    type Row = DynamicList<Self::Data>;
+    type Column = DynamicList<Self::Data>;
+
+    fn max_row_capacity(&self) -> Capacity {
+        Capacity::Unlimited
+    }
+
+    fn max_column_capacity(&self) -> Capacity {
+        Capacity::Unlimited
+    }
+
+    fn row_cnt(&self) -> usize {
+        self.row_cnt
+    }
+
+    fn col_cnt(&self) -> usize {
+        self.col_cnt
+    }
+
+    /// Returns the computed value of the cell
+    fn cell(&self, row: usize, col: usize) -> Option<&Self::Data> {
+        self.value(Position { row, col })
+    }
+
+    /// Returns a reference to the cell only if it holds a number that no
+    /// formula depends on, as changes cannot be observed to recalculate the
+    /// formulas that depend on the cell
+    fn mut_cell(&mut self, row: usize, col: usize) -> Option<&mut Self::Data> {
+        let pos = Position { row, col };
+        if self.has_dependents(pos) {
+            return None;
+        }
+
+        match self.inputs.get_mut(&pos) {
+            Some(Input::Value(x)) => Some(x),
+            _ => None,
+        }
+    }
+
+    /// Places the number into the cell, replacing any formula, and returns
+    /// the cell's previously computed value
+    fn insert_cell(&mut self, row: usize, col: usize, value: Self::Data) -> Option<Self::Data> {
+        let pos = Position { row, col };
+        let old = self.value(pos).copied();
+        self.set_input(pos, Input::Value(value));
+        old
+    }
+
+    /// Removes the number or formula from the cell, returning the cell's
+    /// previously computed value
+    fn remove_cell(&mut self, row: usize, col: usize) -> Option<Self::Data> {
+        let pos = Position { row, col };
+        let old = self.value(pos).copied();
+        self.remove_input(pos).and(old)
+    }
+
+    /// Inserts the row, moving formulas below it down and rewriting their
+    /// references
+    fn insert_row<I: IntoIterator<Item = Self::Data>>(&mut self, row: usize, cells: I) {
+        self.insert_rows(row, core::iter::once(cells));
+    }
+
+    /// Inserts the rows, moving formulas below them down and rewriting their
+    /// references
+    fn insert_rows<I, R>(&mut self, row: usize, rows: I)
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = Self::Data>,
+    {
+        let rows: Vec<R> = rows.into_iter().collect();
+        self.insert_lines(Axis::Row, row, rows.len());
+
+        for (i, cells) in rows.into_iter().enumerate() {
+            for (col, x) in cells.into_iter().enumerate() {
+                self.insert_cell(row + i, col, x);
+            }
+        }
+    }
+
+    /// Removes the row, moving formulas below it up and rewriting their
+    /// references, where references to the removed row become invalid
+    fn remove_row(&mut self, row: usize) -> Option<Self::Row> {
+        if row >= self.row_cnt {
+            return None;
+        }
+
+        let removed = Self::Row::new_filled_with(self.col_cnt, |col| self.cell(row, col).copied());
+        self.remove_line(Axis::Row, row);
+        Some(removed)
+    }
+
+    /// Inserts the column, moving formulas right of it and rewriting their
+    /// references
+    fn insert_column<I: IntoIterator<Item = Self::Data>>(&mut self, col: usize, cells: I) {
+        self.insert_columns(col, core::iter::once(cells));
+    }
+
+    /// Inserts the columns, moving formulas right of them and rewriting their
+    /// references
+    fn insert_columns<I, C>(&mut self, col: usize, cols: I)
+    where
+        I: IntoIterator<Item = C>,
+        C: IntoIterator<Item = Self::Data>,
+    {
+        let cols: Vec<C> = cols.into_iter().collect();
+        self.insert_lines(Axis::Column, col, cols.len());
+
+        for (i, cells) in cols.into_iter().enumerate() {
+            for (row, x) in cells.into_iter().enumerate() {
+                self.insert_cell(row, col + i, x);
+            }
+        }
+    }
+
+    /// Removes the column, moving formulas right of it left and rewriting
+    /// their references, where references to the removed column become
+    /// invalid
+    fn remove_column(&mut self, col: usize) -> Option<Self::Column> {
+        if col >= self.col_cnt {
+            return None;
+        }
+
+        let removed =
+            Self::Column::new_filled_with(self.row_cnt, |row| self.cell(row, col).copied());
+        self.remove_line(Axis::Column, col);
+        Some(removed)
+    }
+
+    /// Shifts the rows along with their formulas, rewriting references to
+    /// follow the cells they point to
+    fn shift_rows(&mut self, offset: isize, wrap: bool) {
+        self.shift_lines(Axis::Row, offset, wrap);
+    }
+
+    /// Shifts the columns along with their formulas, rewriting references to
+    /// follow the cells they point to
+    fn shift_columns(&mut self, offset: isize, wrap: bool) {
+        self.shift_lines(Axis::Column, offset, wrap);
+    }
+
+    fn set_preferred_row_cnt(&mut self, cnt: usize) {
+        self.row_cnt = cnt;
+    }
+
+    fn set_preferred_col_cnt(&mut self, cnt: usize) {
+        self.col_cnt = cnt;
+    }
+
+    /// Removes the numbers and formulas outside of the current row & column
+    /// counts, recalculating any formulas that referenced them
+    fn truncate(&mut self) {
+        let (row_cnt, col_cnt) = (self.row_cnt, self.col_cnt);
+        let outside: Vec<Position> = self
+            .inputs
+            .keys()
+            .filter(|pos| pos.row >= row_cnt || pos.col >= col_cnt)
+            .copied()
+            .collect();
+
+        for pos in outside {
+            self.remove_input(pos);
+        }
+    }
+
+    /// Adjusts the row & column counts to fit the cells holding a number or
+    /// formula, which includes cells outside of the current counts
+    fn shrink_to_fit(&mut self) {
+        let (row_cnt, col_cnt) = self.inputs.keys().fold((0, 0), |acc, pos| {
+            (
+                core::cmp::max(acc.0, pos.row + 1),
+                core::cmp::max(acc.1, pos.col + 1),
+            )
+        });
+
+        self.row_cnt = row_cnt;
+        self.col_cnt = col_cnt;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::vec;
+
+    #[test]
+    fn parse_should_respect_operator_precedence() {
+        let mut table = FormulaTable::new();
+        table.set_formula(0, 0, "1 + 2 * 3 - -4 / (1 + 1)").unwrap();
+        assert_eq!(table.cell(0, 0), Some(&9.0));
+    }
+
+    #[test]
+    fn parse_should_report_unexpected_characters() {
+        assert_eq!(
+            Expr::parse("=1 + ?"),
+            Err(FormulaError::Unexpected {
+                offset: 4,
+                found: Some('?')
+            })
+        );
+        assert_eq!(
+            Expr::parse("(1 + 2"),
+            Err(FormulaError::Unexpected {
+                offset: 6,
+                found: None
+            })
+        );
+        assert_eq!(
+            Expr::parse("AVG(A1:A2)"),
+            Err(FormulaError::UnknownFunction("AVG".into()))
+        );
+        assert_eq!(
+            Expr::parse("A0"),
+            Err(FormulaError::InvalidReference(A1Error::ZeroRow))
+        );
+    }
+
+    #[test]
+    fn formulas_should_recalculate_when_dependencies_change() {
+        let mut table = FormulaTable::new();
+        table.set_formula(0, 1, "A1 * 2").unwrap();
+        table.set_formula(0, 2, "B1 + 1").unwrap();
+        assert_eq!(table.cell(0, 2), Some(&1.0));
+
+        table.insert_cell(0, 0, 5.0);
+        assert_eq!(table.cell(0, 1), Some(&10.0));
+        assert_eq!(table.cell(0, 2), Some(&11.0));
+
+        table.remove_cell(0, 0);
+        assert_eq!(table.cell(0, 2), Some(&1.0));
+    }
+
+    #[test]
+    fn formulas_should_recalculate_when_cell_in_range_changes() {
+        let mut table = FormulaTable::new();
+        table.set_formula(3, 0, "SUM(A1:A3)").unwrap();
+        table.insert_cell(0, 0, 1.0);
+        table.insert_cell(2, 0, 2.0);
+        assert_eq!(table.cell(3, 0), Some(&3.0));
+    }
+
+    #[test]
+    fn replacing_formula_should_stop_tracking_old_dependencies() {
+        let mut table = FormulaTable::new();
+        table.set_formula(0, 1, "A1").unwrap();
+        table.set_formula(0, 1, "7").unwrap();
+        table.insert_cell(0, 0, 5.0);
+
+        assert_eq!(table.cell(0, 1), Some(&7.0));
+        assert!(table.dependents.is_empty());
+    }
+
+    #[test]
+    fn truncate_should_recalculate_formulas_referencing_removed_cells() {
+        let mut table = FormulaTable::new();
+        table.insert_cell(0, 0, 2.0);
+        table.insert_cell(1, 0, 3.0);
+        table.set_formula(0, 1, "A1 + A2").unwrap();
+        table.set_formula(1, 1, "A2").unwrap();
+
+        table.set_preferred_row_cnt(1);
+        table.truncate();
+        assert_eq!(table.cell(0, 1), Some(&2.0));
+
+        table.set_preferred_row_cnt(2);
+        assert_eq!(table.cell(1, 0), None);
+        assert_eq!(table.formula(1, 1), None);
+    }
+
+    #[test]
+    fn shrink_to_fit_should_fit_counts_to_inputs() {
+        let mut table = FormulaTable::new();
+        table.set_formula(2, 3, "1").unwrap();
+        table.remove_cell(2, 3);
+        table.insert_cell(1, 1, 5.0);
+
+        table.shrink_to_fit();
+        assert_eq!((table.row_cnt(), table.col_cnt()), (2, 2));
+    }
+
+    #[test]
+    fn cycles_should_produce_errors_until_broken() {
+        let mut table = FormulaTable::new();
+        table.set_formula(0, 0, "B1 + 1").unwrap();
+        table.set_formula(0, 1, "A1 + 1").unwrap();
+
+        assert_eq!(table.cell(0, 0), None);
+        assert_eq!(table.error(0, 0), Some(EvalError::Cycle));
+        assert_eq!(table.error(0, 1), Some(EvalError::Cycle));
+
+        table.insert_cell(0, 1, 1.0);
+        assert_eq!(table.cell(0, 0), Some(&2.0));
+        assert_eq!(table.error(0, 0), None);
+    }
+
+    #[test]
+    fn errors_should_propagate_to_dependents() {
+        let mut table = FormulaTable::new();
+        table.set_formula(0, 0, "1 / B1").unwrap();
+        table.set_formula(1, 0, "A1 + 1").unwrap();
+
+        assert_eq!(table.error(0, 0), Some(EvalError::DivisionByZero));
+        assert_eq!(table.error(1, 0), Some(EvalError::DivisionByZero));
+
+        table.insert_cell(0, 1, 4.0);
+        assert_eq!(table.cell(1, 0), Some(&1.25));
+    }
+
+    #[test]
+    fn mut_cell_should_only_return_numbers_without_dependents() {
+        let mut table = FormulaTable::new();
+        table.insert_cell(0, 0, 1.0);
+        table.insert_cell(0, 1, 2.0);
+        table.set_formula(0, 2, "A1 + 1").unwrap();
+
+        assert!(table.mut_cell(0, 0).is_none());
+        assert!(table.mut_cell(0, 2).is_none());
+
+        *table.mut_cell(0, 1).unwrap() = 5.0;
+        assert_eq!(table.cell(0, 1), Some(&5.0));
+
+        table.set_formula(1, 0, "B1 * 2").unwrap();
+        assert_eq!(table.cell(1, 0), Some(&10.0));
+    }
+
+    #[test]
+    fn insert_row_should_move_formulas_and_rewrite_references() {
+        let mut table = FormulaTable::new();
+        table.insert_cell(0, 0, 1.0);
+        table.insert_cell(1, 0, 2.0);
+        table.set_formula(2, 0, "SUM(A1:A2)").unwrap();
+        table.set_formula(2, 1, "A2 * 10").unwrap();
+
+        table.insert_row(1, vec![4.0]);
+        assert_eq!(table.row_cnt(), 4);
+        assert_eq!(
+            table.formula(3, 0),
+            Some(&Expr::parse("SUM(A1:A3)").unwrap())
+        );
+        assert_eq!(table.formula(3, 1), Some(&Expr::parse("A3 * 10").unwrap()));
+        assert_eq!(table.cell(3, 0), Some(&7.0));
+        assert_eq!(table.cell(3, 1), Some(&20.0));
+
+        table.insert_cell(2, 0, 3.0);
+        assert_eq!(table.cell(3, 0), Some(&8.0));
+        assert_eq!(table.cell(3, 1), Some(&30.0));
+    }
+
+    #[test]
+    fn remove_row_should_move_formulas_and_invalidate_removed_references() {
+        let mut table = FormulaTable::new();
+        table.insert_cell(0, 0, 1.0);
+        table.insert_cell(1, 0, 2.0);
+        table.insert_cell(2, 0, 3.0);
+        table.set_formula(3, 0, "SUM(A1:A3)").unwrap();
+        table.set_formula(3, 1, "A2 + A3").unwrap();
+
+        assert_eq!(table.remove_row(1), Some(DynamicList::from([2.0])));
+        assert_eq!(table.row_cnt(), 3);
+        assert_eq!(
+            table.formula(2, 0),
+            Some(&Expr::parse("SUM(A1:A2)").unwrap())
+        );
+        assert_eq!(table.cell(2, 0), Some(&4.0));
+        assert_eq!(table.cell(2, 1), None);
+        assert_eq!(table.error(2, 1), Some(EvalError::InvalidReference));
+    }
+
+    #[test]
+    fn insert_and_remove_column_should_rewrite_references() {
+        let mut table = FormulaTable::new();
+        table.insert_cell(0, 0, 1.0);
+        table.insert_cell(0, 1, 2.0);
+        table.set_formula(0, 2, "A1 + B1").unwrap();
+
+        table.insert_column(1, vec![5.0]);
+        assert_eq!(table.formula(0, 3), Some(&Expr::parse("A1 + C1").unwrap()));
+        assert_eq!(table.cell(0, 3), Some(&3.0));
+
+        table.remove_column(0);
+        assert_eq!(table.error(0, 2), Some(EvalError::InvalidReference));
+    }
+
+    #[test]
+    fn shift_rows_should_move_formulas_and_rewrite_references() {
+        let mut table = FormulaTable::new();
+        table.insert_cell(0, 0, 1.0);
+        table.set_formula(1, 0, "A1 + 1").unwrap();
+        table.insert_cell(2, 0, 0.0);
+
+        table.shift_rows(1, true);
+        assert_eq!(table.formula(2, 0), Some(&Expr::parse("A2 + 1").unwrap()));
+        assert_eq!(table.cell(2, 0), Some(&2.0));
+
+        table.shift_rows(-2, false);
+        assert_eq!(
+            table.formula(0, 0),
+            Some(&Expr::Add(
+                Box::new(Expr::InvalidRef),
+                Box::new(Expr::Number(1.0))
+            ))
+        );
+        assert_eq!(table.error(0, 0), Some(EvalError::InvalidReference));
+    }
+}
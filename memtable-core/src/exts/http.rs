@@ -0,0 +1,379 @@
+use super::csv::FromCsv;
+use crate::Table;
+use ::csv as csv_lib;
+use futures_core::Stream;
+use serde::Serialize;
+use serde_json::Value;
+use std::{
+    io,
+    ops::Range,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Represents a format of table data sent over http
+#[cfg_attr(feature = "docs", doc(cfg(http)))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum HttpFormat {
+    /// Rows of comma-separated values
+    Csv,
+
+    /// A json array of rows, each being an array of cells
+    Json,
+}
+
+impl HttpFormat {
+    /// Determines the format from the value of a `Content-Type` header,
+    /// ignoring parameters such as the charset
+    pub fn from_content_type(content_type: &str) -> Option<Self> {
+        let mime = content_type
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase();
+
+        match mime.as_str() {
+            "text/csv" | "application/csv" => Some(Self::Csv),
+            "application/json" | "text/json" => Some(Self::Json),
+            _ if mime.ends_with("+json") => Some(Self::Json),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of the `Content-Type` header for the format
+    pub fn content_type(self) -> &'static str {
+        match self {
+            Self::Csv => "text/csv; charset=utf-8",
+            Self::Json => "application/json",
+        }
+    }
+}
+
+/// Represents ability to load a table from the body of an http response
+///
+/// Fetching is left to the http client so that this crate does not pin a
+/// version of one; with reqwest, a table is loaded as follows:
+///
+/// ```ignore
+/// let res = reqwest::get(url).await?;
+/// let format = res
+///     .headers()
+///     .get(reqwest::header::CONTENT_TYPE)
+///     .and_then(|x| x.to_str().ok())
+///     .and_then(HttpFormat::from_content_type)
+///     .unwrap_or(HttpFormat::Csv);
+/// let table = DynamicTable::from_http_body(&res.bytes().await?, format)?;
+/// ```
+#[cfg_attr(feature = "docs", doc(cfg(http)))]
+pub trait FromHttpBody {
+    /// Represents the type to produce from the body
+    type Output;
+
+    /// Loads a table from the body in the given format
+    ///
+    /// Json cells that are not strings are converted to their json text,
+    /// except for nulls, which leave the cell empty
+    fn from_http_body(body: &[u8], format: HttpFormat) -> io::Result<Self::Output>;
+}
+
+impl<T: Table<Data = String> + Default> FromHttpBody for T {
+    type Output = T;
+
+    fn from_http_body(body: &[u8], format: HttpFormat) -> io::Result<Self::Output> {
+        match format {
+            HttpFormat::Csv => T::from_csv(body),
+            HttpFormat::Json => {
+                let rows: Vec<Vec<Value>> = serde_json::from_slice(body)?;
+                let mut table = T::default();
+
+                for (row, cells) in rows.into_iter().enumerate() {
+                    for (col, cell) in cells.into_iter().enumerate() {
+                        match cell {
+                            Value::Null => {}
+                            Value::String(x) => {
+                                table.insert_cell(row, col, x);
+                            }
+                            x => {
+                                table.insert_cell(row, col, x.to_string());
+                            }
+                        }
+                    }
+                }
+
+                Ok(table)
+            }
+        }
+    }
+}
+
+/// Represents ability to stream a table as the body of an http response,
+/// one chunk per row
+///
+/// The chunks are both an [`Iterator`] and a [`Stream`], so they can be
+/// passed directly to any framework that accepts a stream of bytes, such as
+/// within an axum handler:
+///
+/// ```ignore
+/// let body = Body::from_stream(table.into_json_chunks());
+/// ([(CONTENT_TYPE, HttpFormat::Json.content_type())], body)
+/// ```
+///
+/// or within a warp filter:
+///
+/// ```ignore
+/// let body = hyper::Body::wrap_stream(table.into_csv_chunks());
+/// warp::reply::with_header(
+///     warp::reply::Response::new(body),
+///     CONTENT_TYPE,
+///     HttpFormat::Csv.content_type(),
+/// )
+/// ```
+#[cfg_attr(feature = "docs", doc(cfg(http)))]
+pub trait ToHttpBody: Table + Sized {
+    /// Converts the table into an iterator of csv chunks
+    fn into_csv_chunks(self) -> CsvChunks<Self> {
+        CsvChunks {
+            rows: 0..self.row_cnt(),
+            table: self,
+        }
+    }
+
+    /// Converts the table into an iterator of json chunks
+    fn into_json_chunks(self) -> JsonChunks<Self> {
+        JsonChunks {
+            rows: 0..self.row_cnt(),
+            table: self,
+            done: false,
+        }
+    }
+}
+
+impl<T: Table> ToHttpBody for T {}
+
+/// Represents an iterator of chunks of csv, one per row of a table
+#[cfg_attr(feature = "docs", doc(cfg(http)))]
+#[derive(Debug)]
+pub struct CsvChunks<T: Table> {
+    table: T,
+    rows: Range<usize>,
+}
+
+impl<D: AsRef<[u8]>, T: Table<Data = D>> Iterator for CsvChunks<T> {
+    type Item = io::Result<Vec<u8>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let row = self.rows.next()?;
+        let mut wtr = csv_lib::WriterBuilder::new()
+            .has_headers(false)
+            .from_writer(Vec::new());
+
+        Some(
+            wtr.write_record(
+                self.table
+                    .row_complete(row)
+                    .map(|x| x.map(AsRef::as_ref).unwrap_or_default()),
+            )
+            .map_err(io::Error::from)
+            .and_then(|_| wtr.into_inner().map_err(|x| x.into_error())),
+        )
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.rows.size_hint()
+    }
+}
+
+impl<D: AsRef<[u8]>, T: Table<Data = D> + Unpin> Stream for CsvChunks<T> {
+    type Item = io::Result<Vec<u8>>;
+
+    fn poll_next(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Poll::Ready(self.get_mut().next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.rows.size_hint()
+    }
+}
+
+/// Represents an iterator of chunks of a json array, one per row of a table,
+/// where missing cells are written as nulls
+#[cfg_attr(feature = "docs", doc(cfg(http)))]
+#[derive(Debug)]
+pub struct JsonChunks<T: Table> {
+    table: T,
+    rows: Range<usize>,
+    done: bool,
+}
+
+impl<D: Serialize, T: Table<Data = D>> Iterator for JsonChunks<T> {
+    type Item = io::Result<Vec<u8>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let row = match self.rows.next() {
+            Some(row) => row,
+            None => {
+                self.done = true;
+                let end: &[u8] = if self.table.row_cnt() == 0 {
+                    b"[]"
+                } else {
+                    b"]"
+                };
+                return Some(Ok(end.to_vec()));
+            }
+        };
+
+        let mut buf = if row == 0 {
+            b"[".to_vec()
+        } else {
+            b",".to_vec()
+        };
+        let cells: Vec<Option<&D>> = (0..self.table.col_cnt())
+            .map(|col| self.table.cell(row, col))
+            .collect();
+
+        Some(
+            serde_json::to_writer(&mut buf, &cells)
+                .map(|_| buf)
+                .map_err(io::Error::from),
+        )
+    }
+}
+
+impl<D: Serialize, T: Table<Data = D> + Unpin> Stream for JsonChunks<T> {
+    type Item = io::Result<Vec<u8>>;
+
+    fn poll_next(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Poll::Ready(self.get_mut().next())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DynamicTable;
+    use std::task::{RawWaker, RawWakerVTable, Waker};
+
+    fn collect<I: Iterator<Item = io::Result<Vec<u8>>>>(chunks: I) -> String {
+        let bytes: Vec<u8> = chunks.flat_map(Result::unwrap).collect();
+        String::from_utf8(bytes).unwrap()
+    }
+
+    #[test]
+    fn format_should_be_determined_from_content_type() {
+        assert_eq!(
+            HttpFormat::from_content_type("text/csv; charset=utf-8"),
+            Some(HttpFormat::Csv)
+        );
+        assert_eq!(
+            HttpFormat::from_content_type("Application/JSON"),
+            Some(HttpFormat::Json)
+        );
+        assert_eq!(
+            HttpFormat::from_content_type("application/vnd.api+json"),
+            Some(HttpFormat::Json)
+        );
+        assert_eq!(HttpFormat::from_content_type("text/html"), None);
+    }
+
+    #[test]
+    fn from_http_body_should_load_csv() {
+        let table = DynamicTable::from_http_body(b"a,b\nc,\"d,e\"\n", HttpFormat::Csv).unwrap();
+        assert_eq!(table, [["a", "b"], ["c", "d,e"]]);
+    }
+
+    #[test]
+    fn from_http_body_should_load_json() {
+        let body = br#"[["a", 1, true], [null, "b"]]"#;
+        let table: DynamicTable<String> =
+            DynamicTable::from_http_body(body, HttpFormat::Json).unwrap();
+
+        assert_eq!(table.cell(0, 1).map(String::as_str), Some("1"));
+        assert_eq!(table.cell(0, 2).map(String::as_str), Some("true"));
+        assert_eq!(table.cell(1, 0), None);
+        assert_eq!(table.cell(1, 1).map(String::as_str), Some("b"));
+    }
+
+    #[test]
+    fn from_http_body_should_fail_if_json_is_not_rows() {
+        let result = DynamicTable::from_http_body(br#"{"a": 1}"#, HttpFormat::Json);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn csv_chunks_should_stream_one_row_at_a_time() {
+        let table = DynamicTable::from([["a", "b"], ["c", "d,e"]]);
+
+        let mut chunks = table.into_csv_chunks();
+        assert_eq!(Iterator::size_hint(&chunks), (2, Some(2)));
+        assert_eq!(Stream::size_hint(&chunks), (2, Some(2)));
+        assert_eq!(chunks.next().unwrap().unwrap(), b"a,b\n");
+        assert_eq!(chunks.next().unwrap().unwrap(), b"c,\"d,e\"\n");
+        assert!(chunks.next().is_none());
+    }
+
+    #[test]
+    fn json_chunks_should_stream_an_array_of_rows() {
+        let mut table = DynamicTable::from([[1, 2], [3, 4]]);
+        table.remove_cell(1, 0);
+
+        assert_eq!(collect(table.into_json_chunks()), "[[1,2],[null,4]]");
+        assert_eq!(collect(DynamicTable::<u8>::new().into_json_chunks()), "[]");
+    }
+
+    #[test]
+    fn chunks_should_be_polled_as_a_stream() {
+        fn noop_raw_waker() -> RawWaker {
+            fn clone(_: *const ()) -> RawWaker {
+                noop_raw_waker()
+            }
+            fn noop(_: *const ()) {}
+
+            static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+            RawWaker::new(std::ptr::null(), &VTABLE)
+        }
+
+        let waker = unsafe { Waker::from_raw(noop_raw_waker()) };
+        let mut cx = Context::from_waker(&waker);
+
+        let mut chunks = DynamicTable::from([["a", "b"]]).into_csv_chunks();
+        assert_eq!(Stream::size_hint(&chunks), (1, Some(1)));
+        match Pin::new(&mut chunks).poll_next(&mut cx) {
+            Poll::Ready(Some(Ok(x))) => assert_eq!(x, b"a,b\n"),
+            x => panic!("Unexpected poll: {:?}", x),
+        }
+        assert!(matches!(
+            Pin::new(&mut chunks).poll_next(&mut cx),
+            Poll::Ready(None)
+        ));
+
+        let mut chunks = DynamicTable::from([[1]]).into_json_chunks();
+        let mut json = Vec::new();
+        while let Poll::Ready(Some(x)) = Pin::new(&mut chunks).poll_next(&mut cx) {
+            json.extend(x.unwrap());
+        }
+        assert_eq!(json, b"[[1]]");
+    }
+
+    #[test]
+    fn chunks_should_round_trip_through_from_http_body() {
+        let table = DynamicTable::from([["a", "b"], ["c", "d"]]);
+
+        let json = collect(table.clone().into_json_chunks());
+        let csv = collect(table.into_csv_chunks());
+
+        let expected = [["a", "b"], ["c", "d"]];
+        assert_eq!(
+            DynamicTable::from_http_body(json.as_bytes(), HttpFormat::Json).unwrap(),
+            expected
+        );
+        assert_eq!(
+            DynamicTable::from_http_body(csv.as_bytes(), HttpFormat::Csv).unwrap(),
+            expected
+        );
+    }
+}
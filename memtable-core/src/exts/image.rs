@@ -0,0 +1,153 @@
+use crate::Table;
+use ::image as img;
+use core::{convert::TryFrom, ops::Deref};
+use img::{GrayImage, ImageBuffer, Luma, Pixel};
+
+/// Represents the ability to convert a table into an image, where each row
+/// of the table becomes a row of pixels and each column a column of pixels
+pub trait ToImage: Table {
+    /// Converts the table into an image, producing each pixel from the cell
+    /// at the same position using the given function; missing cells become
+    /// pixels whose channels are all zero
+    ///
+    /// ### Panics
+    ///
+    /// Panics if the table has more than `u32::MAX` rows or columns
+    fn to_image_with<P, F>(&self, mut f: F) -> ImageBuffer<P, Vec<P::Subpixel>>
+    where
+        P: Pixel + 'static,
+        P::Subpixel: 'static,
+        F: FnMut(&Self::Data) -> P,
+    {
+        let width = u32::try_from(self.col_cnt()).expect("Too many columns for image");
+        let height = u32::try_from(self.row_cnt()).expect("Too many rows for image");
+        let mut image = ImageBuffer::new(width, height);
+
+        for y in 0..height {
+            for x in 0..width {
+                if let Some(data) = self.cell(y as usize, x as usize) {
+                    image.put_pixel(x, y, f(data));
+                }
+            }
+        }
+
+        image
+    }
+
+    /// Converts a table of pixels into an image
+    ///
+    /// ### Panics
+    ///
+    /// Panics if the table has more than `u32::MAX` rows or columns
+    fn to_image(&self) -> ImageBuffer<Self::Data, Vec<<Self::Data as Pixel>::Subpixel>>
+    where
+        Self::Data: Pixel + 'static,
+        <Self::Data as Pixel>::Subpixel: 'static,
+    {
+        self.to_image_with(|x| *x)
+    }
+
+    /// Converts a table of intensities into a grayscale image, which is handy
+    /// for visualizing heatmaps
+    ///
+    /// ### Panics
+    ///
+    /// Panics if the table has more than `u32::MAX` rows or columns
+    fn to_gray_image(&self) -> GrayImage
+    where
+        Self::Data: Clone + Into<u8>,
+    {
+        self.to_image_with(|x| Luma([x.clone().into()]))
+    }
+}
+
+impl<T: Table> ToImage for T {}
+
+/// Represents the ability to create a table from an image, where each row of
+/// pixels becomes a row of the table and each column of pixels a column
+pub trait FromImage: Table + Default {
+    /// Creates a table from an image, producing each cell from the pixel at
+    /// the same position using the given function
+    fn from_image_with<P, C, F>(image: &ImageBuffer<P, C>, mut f: F) -> Self
+    where
+        P: Pixel + 'static,
+        C: Deref<Target = [P::Subpixel]>,
+        F: FnMut(&P) -> Self::Data,
+    {
+        let mut table = Self::default();
+        let (width, height) = image.dimensions();
+
+        for (x, y, pixel) in image.enumerate_pixels() {
+            table.insert_cell(y as usize, x as usize, f(pixel));
+        }
+
+        table.set_preferred_row_cnt(height as usize);
+        table.set_preferred_col_cnt(width as usize);
+        table
+    }
+
+    /// Creates a table of pixels from an image
+    fn from_image<C>(image: &ImageBuffer<Self::Data, C>) -> Self
+    where
+        Self::Data: Pixel + 'static,
+        C: Deref<Target = [<Self::Data as Pixel>::Subpixel]>,
+    {
+        Self::from_image_with(image, |x| *x)
+    }
+
+    /// Creates a table of intensities from a grayscale image
+    fn from_gray_image(image: &GrayImage) -> Self
+    where
+        Self::Data: From<u8>,
+    {
+        Self::from_image_with(image, |x| Self::Data::from(x.0[0]))
+    }
+}
+
+impl<T: Table + Default> FromImage for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DynamicTable, FixedTable};
+    use img::Rgb;
+
+    #[test]
+    fn to_gray_image_should_map_rows_to_pixel_rows() {
+        let table = FixedTable::from([[0u8, 64, 128], [192, 255, 32]]);
+        let image = table.to_gray_image();
+
+        assert_eq!(image.dimensions(), (3, 2));
+        assert_eq!(image.get_pixel(2, 0), &Luma([128]));
+        assert_eq!(image.get_pixel(0, 1), &Luma([192]));
+    }
+
+    #[test]
+    fn to_image_should_use_zeroed_pixels_for_missing_cells() {
+        let mut table = DynamicTable::new();
+        table.insert_cell(0, 0, Rgb([1u8, 2, 3]));
+        table.insert_cell(1, 1, Rgb([4, 5, 6]));
+
+        let image = table.to_image();
+        assert_eq!(image.get_pixel(0, 0), &Rgb([1, 2, 3]));
+        assert_eq!(image.get_pixel(1, 0), &Rgb([0, 0, 0]));
+        assert_eq!(image.get_pixel(1, 1), &Rgb([4, 5, 6]));
+    }
+
+    #[test]
+    fn from_image_should_restore_table_converted_to_image() {
+        let mut table = DynamicTable::new();
+        table.push_row(vec![Rgb([1u8, 2, 3]), Rgb([4, 5, 6])]);
+        let image = table.to_image();
+
+        assert_eq!(DynamicTable::from_image(&image), table);
+    }
+
+    #[test]
+    fn from_gray_image_should_convert_intensities() {
+        let image = GrayImage::from_raw(2, 2, vec![1, 2, 3, 4]).unwrap();
+        let table: DynamicTable<u16> = DynamicTable::from_gray_image(&image);
+
+        assert_eq!(table, [[1, 2], [3, 4]]);
+    }
+}
@@ -0,0 +1,303 @@
+use crate::{list::*, Capacity, Table};
+use bytemuck::Pod;
+use core::{convert::TryFrom, marker::PhantomData, mem};
+use memmap2::{MmapMut, MmapOptions};
+use std::{
+    fs::File,
+    io::{self, Write},
+    path::Path,
+};
+
+/// Bytes at the start of every file laid out for a [`MmapTable`]
+pub const MAGIC: &[u8; 8] = b"MEMTABLE";
+
+/// Length of the header, which is the magic bytes followed by the size of
+/// each cell, the total rows, and the total columns as little-endian `u64`
+const HEADER_LEN: usize = 32;
+
+/// Represents a dense table of plain-old-data cells backed by a
+/// memory-mapped file, which lets datasets larger than available memory be
+/// read without copying them into the process first
+///
+/// The file consists of a header describing the shape of the table followed
+/// by every cell in row-major order, which is produced by
+/// [`MmapTable::write_to`] or [`MmapTable::write_file`]. The shape is fixed
+/// by the file, so the table cannot grow beyond it.
+///
+/// The file is mapped copy-on-write, so modifying cells only affects the
+/// pages touched in memory and never writes back to the file
+///
+/// Cells are stored in the native endianness of the machine that wrote the
+/// file, as they are mapped directly into memory, so a file is only portable
+/// between machines of the same endianness. The header is always
+/// little-endian
+#[derive(Debug)]
+#[cfg_attr(feature = "docs", doc(cfg(mmap)))]
+pub struct MmapTable<T: Pod> {
+    mmap: MmapMut,
+    row_cnt: usize,
+    col_cnt: usize,
+    _data: PhantomData<T>,
+}
+
+impl<T: Pod> MmapTable<T> {
+    /// Maps the file at the given path as a table
+    ///
+    /// Fails with [`io::ErrorKind::InvalidData`] if the file was not laid out
+    /// for a table whose cells are the size of `T`
+    ///
+    /// ### Safety
+    ///
+    /// The file must not be modified or truncated by this or any other
+    /// process while it is mapped. Otherwise the cells would change
+    /// underneath the table, which is undefined behavior, and reading
+    /// past the end of a truncated file would crash the process
+    pub unsafe fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let file = File::open(path)?;
+
+        // SAFETY: The file is mapped privately, so writes made through the
+        //         table never reach it, and the caller guarantees that
+        //         nothing else modifies the file while it is mapped
+        let mmap = MmapOptions::new().map_copy(&file)?;
+        trace_span!("mmap_open", bytes = mmap.len());
+
+        if mmap.len() < HEADER_LEN || &mmap[..MAGIC.len()] != MAGIC {
+            return Err(invalid_data("Missing table header"));
+        }
+
+        let cell_size = read_u64(&mmap, 8)?;
+        let row_cnt = read_u64(&mmap, 16)?;
+        let col_cnt = read_u64(&mmap, 24)?;
+        if cell_size != mem::size_of::<T>() {
+            return Err(invalid_data(format!(
+                "Cells are {} bytes, but expected {}",
+                cell_size,
+                mem::size_of::<T>()
+            )));
+        }
+
+        let required = row_cnt
+            .checked_mul(col_cnt)
+            .and_then(|x| x.checked_mul(cell_size))
+            .and_then(|x| x.checked_add(data_offset::<T>()))
+            .ok_or_else(|| invalid_data("Table shape is too large"))?;
+        if mmap.len() < required {
+            return Err(invalid_data(format!(
+                "Table needs {} bytes, but file has {}",
+                required,
+                mmap.len()
+            )));
+        }
+
+        Ok(Self {
+            mmap,
+            row_cnt,
+            col_cnt,
+            _data: PhantomData,
+        })
+    }
+
+    /// Writes the given table to the writer in the layout expected by
+    /// [`MmapTable::open`], where missing cells are written as zeroes
+    ///
+    /// Cells are written in the native endianness of this machine
+    pub fn write_to<U: Table<Data = T>, W: Write>(table: &U, mut writer: W) -> io::Result<()> {
+        trace_span!("mmap_write", rows = table.row_cnt(), cols = table.col_cnt());
+        let mut header = [0; HEADER_LEN];
+        header[..MAGIC.len()].copy_from_slice(MAGIC);
+        header[8..16].copy_from_slice(&(mem::size_of::<T>() as u64).to_le_bytes());
+        header[16..24].copy_from_slice(&(table.row_cnt() as u64).to_le_bytes());
+        header[24..32].copy_from_slice(&(table.col_cnt() as u64).to_le_bytes());
+        writer.write_all(&header)?;
+
+        // Pad so that the cells are aligned once mapped, as mappings start
+        // at the beginning of a page
+        let padding = data_offset::<T>() - HEADER_LEN;
+        writer.write_all(&vec![0; padding])?;
+
+        let zeroed = T::zeroed();
+        for row in 0..table.row_cnt() {
+            for col in 0..table.col_cnt() {
+                let cell = table.cell(row, col).unwrap_or(&zeroed);
+                writer.write_all(bytemuck::bytes_of(cell))?;
+            }
+        }
+
+        writer.flush()
+    }
+
+    /// Writes the given table to a file at the given path, creating it if it
+    /// does not exist and truncating it otherwise
+    pub fn write_file<U: Table<Data = T>, P: AsRef<Path>>(table: &U, path: P) -> io::Result<()> {
+        Self::write_to(table, io::BufWriter::new(File::create(path)?))
+    }
+
+    /// Returns every cell of the table in row-major order
+    pub fn as_slice(&self) -> &[T] {
+        let start = data_offset::<T>();
+        let end = start + self.row_cnt * self.col_cnt * mem::size_of::<T>();
+        bytemuck::cast_slice(&self.mmap[start..end])
+    }
+
+    /// Returns every cell of the table in row-major order as mutable
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        let start = data_offset::<T>();
+        let end = start + self.row_cnt * self.col_cnt * mem::size_of::<T>();
+        bytemuck::cast_slice_mut(&mut self.mmap[start..end])
+    }
+
+    /// Returns the cells of the specified row, or `None` if the row does not
+    /// exist
+    pub fn row_slice(&self, row: usize) -> Option<&[T]> {
+        if row < self.row_cnt {
+            let start = row * self.col_cnt;
+            Some(&self.as_slice()[start..start + self.col_cnt])
+        } else {
+            None
+        }
+    }
+
+    fn index_of(&self, row: usize, col: usize) -> Option<usize> {
+        if row < self.row_cnt && col < self.col_cnt {
+            Some(row * self.col_cnt + col)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: Pod> Table for MmapTable<T> {
+    type Data = T;
+    type Row = DynamicList<Self::Data>;
+    type Column = DynamicList<Self::Data>;
+
+    fn max_row_capacity(&self) -> Capacity {
+        Capacity::Limited(self.row_cnt)
+    }
+
+    fn max_column_capacity(&self) -> Capacity {
+        Capacity::Limited(self.col_cnt)
+    }
+
+    fn row_cnt(&self) -> usize {
+        self.row_cnt
+    }
+
+    fn col_cnt(&self) -> usize {
+        self.col_cnt
+    }
+
+    fn cell(&self, row: usize, col: usize) -> Option<&Self::Data> {
+        let idx = self.index_of(row, col)?;
+        self.as_slice().get(idx)
+    }
+
+    fn mut_cell(&mut self, row: usize, col: usize) -> Option<&mut Self::Data> {
+        let idx = self.index_of(row, col)?;
+        self.as_mut_slice().get_mut(idx)
+    }
+
+    fn insert_cell(&mut self, row: usize, col: usize, value: Self::Data) -> Option<Self::Data> {
+        // Cells can only be replaced as the shape is fixed by the file
+        self.mut_cell(row, col).map(|x| mem::replace(x, value))
+    }
+
+    fn remove_cell(&mut self, row: usize, col: usize) -> Option<Self::Data> {
+        // Like the fixed tables, removing a cell leaves behind its zeroed
+        // value rather than shrinking the table
+        self.mut_cell(row, col)
+            .map(|x| mem::replace(x, T::zeroed()))
+    }
+}
+
+/// Returns the offset of the first cell within the file, which follows the
+/// header rounded up to the alignment of the cells
+fn data_offset<T>() -> usize {
+    aligned_offset(HEADER_LEN, mem::align_of::<T>())
+}
+
+/// Rounds the offset up to the next multiple of the alignment
+fn aligned_offset(offset: usize, align: usize) -> usize {
+    offset.div_ceil(align) * align
+}
+
+fn read_u64(bytes: &[u8], offset: usize) -> io::Result<usize> {
+    let mut buf = [0; 8];
+    buf.copy_from_slice(&bytes[offset..offset + 8]);
+    usize::try_from(u64::from_le_bytes(buf)).map_err(|_| invalid_data("Header value is too large"))
+}
+
+fn invalid_data<E: Into<Box<dyn std::error::Error + Send + Sync>>>(error: E) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DynamicTable, FixedTable};
+    use tempfile::NamedTempFile;
+
+    #[test]
+    fn aligned_offset_should_round_up_to_multiple_of_alignment() {
+        assert_eq!(aligned_offset(HEADER_LEN, 1), HEADER_LEN);
+        assert_eq!(aligned_offset(HEADER_LEN, 8), HEADER_LEN);
+        assert_eq!(aligned_offset(HEADER_LEN, 64), 64);
+
+        // Neither a power of two nor a divisor of the header length
+        assert_eq!(aligned_offset(HEADER_LEN, 5), 35);
+        assert_eq!(aligned_offset(HEADER_LEN, 12), 36);
+    }
+
+    #[test]
+    fn open_should_read_cells_written_by_write_file() {
+        let table = FixedTable::from([[1u32, 2, 3], [4, 5, 6]]);
+        let file = NamedTempFile::new().unwrap();
+        MmapTable::write_file(&table, file.path()).unwrap();
+
+        let mmap = unsafe { MmapTable::<u32>::open(file.path()) }.unwrap();
+        assert_eq!(mmap.row_cnt(), 2);
+        assert_eq!(mmap.col_cnt(), 3);
+        assert_eq!(mmap.cell(1, 2), Some(&6));
+        assert_eq!(mmap.cell(2, 0), None);
+        assert_eq!(mmap.row_slice(0), Some(&[1, 2, 3][..]));
+        assert_eq!(mmap.as_slice(), &[1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn write_to_should_zero_missing_cells() {
+        let mut table = DynamicTable::new();
+        table.insert_cell(0, 0, 7u64);
+        table.insert_cell(1, 1, 9u64);
+        let file = NamedTempFile::new().unwrap();
+        MmapTable::write_file(&table, file.path()).unwrap();
+
+        let mmap = unsafe { MmapTable::<u64>::open(file.path()) }.unwrap();
+        assert_eq!(mmap.as_slice(), &[7, 0, 0, 9]);
+    }
+
+    #[test]
+    fn modifying_cells_should_not_change_the_file() {
+        let table = FixedTable::from([[1u16, 2], [3, 4]]);
+        let file = NamedTempFile::new().unwrap();
+        MmapTable::write_file(&table, file.path()).unwrap();
+
+        let mut mmap = unsafe { MmapTable::<u16>::open(file.path()) }.unwrap();
+        assert_eq!(mmap.insert_cell(0, 1, 20), Some(2));
+        assert_eq!(mmap.remove_cell(1, 0), Some(3));
+        assert_eq!(mmap.insert_cell(2, 0, 5), None);
+        assert_eq!(mmap.as_slice(), &[1, 20, 0, 4]);
+
+        let mmap = unsafe { MmapTable::<u16>::open(file.path()) }.unwrap();
+        assert_eq!(mmap.as_slice(), &[1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn open_should_fail_if_cell_size_does_not_match() {
+        let table = FixedTable::from([[1u32, 2]]);
+        let file = NamedTempFile::new().unwrap();
+        MmapTable::write_file(&table, file.path()).unwrap();
+
+        let err = unsafe { MmapTable::<u64>::open(file.path()) }.unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+}
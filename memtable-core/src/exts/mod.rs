@@ -1,18 +1,112 @@
+/// Contains utilities to convert between spreadsheet-style references
+/// (e.g. `B7` and `A1:C10`) and positions within tables
+#[cfg(feature = "a1")]
+#[cfg_attr(feature = "docs", doc(cfg(a1)))]
+pub mod a1;
+
+/// Contains tables whose cells are allocated with a custom allocator, which
+/// requires a nightly compiler
+#[cfg(all(feature = "allocator-api", any(feature = "alloc", feature = "std")))]
+#[cfg_attr(feature = "docs", doc(cfg(all(allocator_api, any(alloc, std)))))]
+pub mod allocator_api;
+
+/// Contains tables whose cells are allocated within a bump arena
+#[cfg(all(feature = "bumpalo", any(feature = "alloc", feature = "std")))]
+#[cfg_attr(feature = "docs", doc(cfg(all(bumpalo, any(alloc, std)))))]
+pub mod bumpalo;
+
 /// Contains `CellX` data structures that enable easy multi-type tables
 /// by acting as an abstraction of the data sources
 #[cfg(feature = "cell")]
 #[cfg_attr(feature = "docs", doc(cfg(cell)))]
 pub mod cell;
 
+/// Contains tables that can be edited independently and merged together
+/// without conflicts
+#[cfg(all(feature = "crdt", any(feature = "alloc", feature = "std")))]
+#[cfg_attr(feature = "docs", doc(cfg(all(crdt, any(alloc, std)))))]
+pub mod crdt;
+
 /// Contains traits that enable converting between table and csv data
 #[cfg(all(feature = "csv", feature = "std"))]
 #[cfg_attr(feature = "docs", doc(cfg(all(csv, std))))]
 pub mod csv;
 
+/// Contains conversions from tables into arrow data queryable by DataFusion
+#[cfg(all(feature = "datafusion", feature = "std"))]
+#[cfg_attr(feature = "docs", doc(cfg(all(datafusion, std))))]
+pub mod datafusion;
+
+/// Contains a table whose cells can hold formulas computed from other cells
+#[cfg(all(feature = "formula", any(feature = "alloc", feature = "std")))]
+#[cfg_attr(feature = "docs", doc(cfg(all(formula, any(alloc, std)))))]
+pub mod formula;
+
+/// Contains helpers to load tables from and stream tables as http bodies
+#[cfg(all(feature = "http", feature = "std"))]
+#[cfg_attr(feature = "docs", doc(cfg(all(http, std))))]
+pub mod http;
+
+/// Contains traits that enable converting between tables and images
+#[cfg(all(feature = "image", feature = "std"))]
+#[cfg_attr(feature = "docs", doc(cfg(all(image, std))))]
+pub mod image;
+
+/// Contains a read-only table backend that memory-maps a file of dense cells
+#[cfg(all(feature = "mmap", feature = "std"))]
+#[cfg_attr(feature = "docs", doc(cfg(all(mmap, std))))]
+pub mod mmap;
+
+/// Contains conversions between tables and nalgebra matrices
+#[cfg(all(feature = "nalgebra", feature = "std"))]
+#[cfg_attr(feature = "docs", doc(cfg(all(nalgebra, std))))]
+pub mod nalgebra;
+
+/// Contains conversions between tables and a canonical protobuf encoding
+#[cfg(all(feature = "proto", feature = "std"))]
+#[cfg_attr(feature = "docs", doc(cfg(all(proto, std))))]
+pub mod proto;
+
+/// Contains traits to generate tables of random data
+#[cfg(feature = "rand")]
+#[cfg_attr(feature = "docs", doc(cfg(rand)))]
+pub mod rand;
+
+/// Contains parallel construction of tables and loading of CSV data via rayon
+#[cfg(all(feature = "rayon", feature = "std"))]
+#[cfg_attr(feature = "docs", doc(cfg(all(rayon, std))))]
+pub mod rayon;
+
+/// Contains traits that enable searching and editing table text using
+/// regular expressions
+#[cfg(all(feature = "regex", feature = "std"))]
+#[cfg_attr(feature = "docs", doc(cfg(all(regex, std))))]
+pub mod regex;
+
 /// Support for using sled as a backing data storage for tables
-#[cfg(all(feature = "sled-1", feature = "std"))]
-#[cfg_attr(feature = "docs", doc(cfg(all(sled, std))))]
+#[cfg(all(any(feature = "sled-1", feature = "sled-next"), feature = "std"))]
+#[cfg_attr(feature = "docs", doc(cfg(all(any(sled, sled_next), std))))]
 pub mod sled;
 
+/// Contains a minimal SQL layer to run SELECT statements over tables
+#[cfg(all(feature = "sql", feature = "std"))]
+#[cfg_attr(feature = "docs", doc(cfg(all(sql, std))))]
+pub mod sql;
+
+/// Contains a handle to share a table across async tasks via tokio
+#[cfg(all(feature = "tokio", feature = "std"))]
+#[cfg_attr(feature = "docs", doc(cfg(all(tokio, std))))]
+pub mod tokio;
+
+/// Contains adapters to drive grid-based user interfaces from tables
+#[cfg(feature = "ui")]
+#[cfg_attr(feature = "docs", doc(cfg(ui)))]
+pub mod ui;
+
+/// Support for using browser storage as a backing data storage for tables
+#[cfg(feature = "web")]
+#[cfg_attr(feature = "docs", doc(cfg(web)))]
+pub mod web;
+
 /// Contains relevant traits, structs, and more for extensions to tables
 pub mod prelude;
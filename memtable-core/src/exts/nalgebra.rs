@@ -0,0 +1,127 @@
+use crate::{DynamicTable, FixedTable, Table};
+use ::nalgebra as na;
+
+impl<T, const ROW: usize, const COL: usize> From<na::SMatrix<T, ROW, COL>>
+    for FixedTable<T, ROW, COL>
+where
+    T: na::Scalar + Default,
+{
+    /// Converts a statically-sized matrix into a fixed table where the
+    /// matrix's rows and columns map directly to the table's rows and columns
+    fn from(matrix: na::SMatrix<T, ROW, COL>) -> Self {
+        let mut table = Self::new();
+
+        for (row, values) in matrix.row_iter().enumerate() {
+            for (col, value) in values.iter().enumerate() {
+                table.insert_cell(row, col, value.clone());
+            }
+        }
+
+        table
+    }
+}
+
+impl<T, const ROW: usize, const COL: usize> From<FixedTable<T, ROW, COL>>
+    for na::SMatrix<T, ROW, COL>
+where
+    T: na::Scalar + Default,
+{
+    /// Converts a fixed table into a statically-sized matrix, filling in any
+    /// cells outside of the table's current row & column count with the
+    /// default value
+    fn from(table: FixedTable<T, ROW, COL>) -> Self {
+        Self::from_fn(|row, col| table.cell(row, col).cloned().unwrap_or_default())
+    }
+}
+
+impl<T> From<na::DMatrix<T>> for DynamicTable<T>
+where
+    T: na::Scalar,
+{
+    /// Converts a dynamically-sized matrix into a dynamic table where the
+    /// matrix's rows and columns map directly to the table's rows and columns
+    fn from(matrix: na::DMatrix<T>) -> Self {
+        let mut table = Self::new();
+        table.set_preferred_row_cnt(matrix.nrows());
+        table.set_preferred_col_cnt(matrix.ncols());
+
+        for (row, values) in matrix.row_iter().enumerate() {
+            for (col, value) in values.iter().enumerate() {
+                table.insert_cell(row, col, value.clone());
+            }
+        }
+
+        table
+    }
+}
+
+impl<T> From<DynamicTable<T>> for na::DMatrix<T>
+where
+    T: na::Scalar + Default,
+{
+    /// Converts a dynamic table into a dynamically-sized matrix of the same
+    /// dimensions, filling in any missing cells with the default value
+    fn from(table: DynamicTable<T>) -> Self {
+        Self::from_fn(table.row_cnt(), table.col_cnt(), |row, col| {
+            table.cell(row, col).cloned().unwrap_or_default()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixed_table_from_smatrix_should_preserve_rows_and_columns() {
+        let matrix = na::Matrix2x3::new(1, 2, 3, 4, 5, 6);
+        let table = FixedTable::<usize, 2, 3>::from(matrix);
+
+        assert_eq!(table.row_cnt(), 2);
+        assert_eq!(table.col_cnt(), 3);
+        assert_eq!(table.cell(0, 0), Some(&1));
+        assert_eq!(table.cell(0, 2), Some(&3));
+        assert_eq!(table.cell(1, 0), Some(&4));
+        assert_eq!(table.cell(1, 2), Some(&6));
+    }
+
+    #[test]
+    fn smatrix_from_fixed_table_should_preserve_rows_and_columns() {
+        let table = FixedTable::from([[1, 2, 3], [4, 5, 6]]);
+        let matrix = na::SMatrix::<usize, 2, 3>::from(table);
+
+        assert_eq!(matrix, na::Matrix2x3::new(1, 2, 3, 4, 5, 6));
+    }
+
+    #[test]
+    fn smatrix_from_fixed_table_should_fill_unused_cells_with_default() {
+        let mut table = FixedTable::<usize, 2, 2>::new();
+        table.insert_cell(0, 0, 1);
+
+        let matrix = na::SMatrix::<usize, 2, 2>::from(table);
+        assert_eq!(matrix, na::Matrix2::new(1, 0, 0, 0));
+    }
+
+    #[test]
+    fn dynamic_table_from_dmatrix_should_preserve_rows_and_columns() {
+        let matrix = na::DMatrix::from_row_slice(2, 3, &[1, 2, 3, 4, 5, 6]);
+        let table = DynamicTable::from(matrix);
+
+        assert_eq!(table.row_cnt(), 2);
+        assert_eq!(table.col_cnt(), 3);
+        assert_eq!(table, [[1, 2, 3], [4, 5, 6]]);
+    }
+
+    #[test]
+    fn dmatrix_from_dynamic_table_should_fill_missing_cells_with_default() {
+        let mut table = DynamicTable::new();
+        table.insert_cell(0, 0, 1);
+        table.insert_cell(1, 2, 6);
+
+        let matrix = na::DMatrix::from(table);
+        assert_eq!(
+            matrix,
+            na::DMatrix::from_row_slice(2, 3, &[1, 0, 0, 0, 0, 6])
+        );
+    }
+}
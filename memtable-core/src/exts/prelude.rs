@@ -8,17 +8,113 @@
 //!
 //! # Prelude contents
 //!
-//! If the `sled` feature is enabled, the prelude re-exports the following:
+//! If the `a1` feature is enabled, the prelude re-exports the following:
+//!
+//! * [`a1::A1`] struct, which converts between spreadsheet-style references
+//!   like `B7` and positions within a table
+//! * [`a1::A1Range`] struct, which represents a rectangular range of cells
+//!   like `A1:C10`
+//!
+//! If the `allocator-api` feature is enabled, the prelude re-exports the following:
+//!
+//! * [`allocator_api::AllocDynamicTable`] struct, which provides a dynamic
+//!   table whose cells are allocated with a custom allocator
+//! * [`allocator_api::AllocFixedColumnTable`] struct, which provides a table
+//!   with a fixed number of columns whose rows are allocated with a custom
+//!   allocator
+//!
+//! If the `bumpalo` feature is enabled, the prelude re-exports the following:
+//!
+//! * [`bumpalo::DynamicTableIn`] struct, which provides a table whose cells
+//!   are allocated within a bump arena
+//!
+//! If the `datafusion` feature is enabled, the prelude re-exports the following:
+//!
+//! * [`datafusion::ToRecordBatch`] trait, which enables converting a table
+//!   into arrow data that DataFusion can query with sql
+//!
+//! If the `formula` feature is enabled, the prelude re-exports the following:
+//!
+//! * [`formula::FormulaTable`] struct, which provides a table of numbers and
+//!   formulas that recalculate whenever the cells they reference change
+//!
+//! If the `sled` or `sled-next` feature is enabled, the prelude re-exports the following:
 //!
 //! * [`sled::SledTable`] struct, which wraps around other tables and provides
 //!   persistent storage via the sled database
 //!
+//! If the `sql` feature is enabled, the prelude re-exports the following:
+//!
+//! * [`sql::QuerySql`] trait, which enables running a SELECT statement over
+//!   a table whose first row names its columns
+//!
+//! If the `tokio` feature is enabled, the prelude re-exports the following:
+//!
+//! * [`tokio::TableHandle`] struct, which owns a table within a dedicated
+//!   task and shares access to it across async tasks via a channel
+//!
+//! If the `ui` feature is enabled, the prelude re-exports the following:
+//!
+//! * [`ui::TableModel`] struct, which wraps around other tables and provides
+//!   cached cell text for grid-based user interfaces
+//!
+//! If the `web` feature is enabled, the prelude re-exports the following:
+//!
+//! * [`web::WebTable`] struct, which wraps around other tables and provides
+//!   persistent storage via the browser's `localStorage`
+//!
+//! If the `mmap` feature is enabled, the prelude re-exports the following:
+//!
+//! * [`mmap::MmapTable`] struct, which provides a table of plain-old-data
+//!   cells backed by a memory-mapped file
+//!
+//! If the `proto` feature is enabled, the prelude re-exports the following:
+//!
+//! * [`proto::ToProto`] trait, which enables encoding a table as protobuf
+//! * [`proto::FromProto`] trait, which enables decoding a table from
+//!   protobuf
+//!
+//! If the `rand` feature is enabled, the prelude re-exports the following:
+//!
+//! * [`rand::RandomTable`] trait, which enables creating tables filled with
+//!   random data
+//!
+//! If the `regex` feature is enabled, the prelude re-exports the following:
+//!
+//! * [`regex::SearchRegex`] trait, which enables finding regular expression
+//!   matches within a table
+//! * [`regex::ReplaceRegex`] trait, which enables replacing regular
+//!   expression matches within a table
+//!
 //! If the `csv` feature is enabled, the prelude re-exports the following:
 //!
 //! * [`csv::ToCsv`] trait, which enables converting a
 //!   table to a CSV
 //! * [`csv::FromCsv`] trait, which enables converting
 //!   CSV to a table
+//! * [`csv::FromCsvInferred`] trait, which enables converting CSV to a
+//!   table of typed values, inferring column types and headers
+//!
+//! If the `http` feature is enabled, the prelude re-exports the following:
+//!
+//! * [`http::HttpFormat`] enum, which represents the csv and json formats
+//!   of table data sent over http
+//! * [`http::FromHttpBody`] trait, which enables loading a table from the
+//!   body of an http response
+//! * [`http::ToHttpBody`] trait, which enables streaming a table as the body
+//!   of an http response
+//!
+//! If the `image` feature is enabled, the prelude re-exports the following:
+//!
+//! * [`image::ToImage`] trait, which enables converting a table into an
+//!   image
+//! * [`image::FromImage`] trait, which enables converting an image into a
+//!   table
+//!
+//! If the `crdt` feature is enabled, the prelude re-exports the following:
+//!
+//! * [`crdt::LwwTable`] struct, which provides a table of last-writer-wins
+//!   cells that can be merged with other copies of itself
 //!
 //! If the `cell` feature is enabled, the prelude re-exports the following:
 //!
@@ -73,17 +169,97 @@
 //! * [`cell::Cell26`] enum, which provides a simple way to
 //!   configure a table to have 1 of 26 possible data types
 //!
+#[cfg(feature = "a1")]
+#[cfg_attr(feature = "docs", doc(cfg(a1)))]
+#[doc(inline)]
+pub use crate::exts::a1::{A1Range, A1};
+
+#[cfg(all(feature = "allocator-api", any(feature = "alloc", feature = "std")))]
+#[cfg_attr(feature = "docs", doc(cfg(allocator_api)))]
+#[doc(inline)]
+pub use crate::exts::allocator_api::{AllocDynamicTable, AllocFixedColumnTable};
+
+#[cfg(all(feature = "bumpalo", any(feature = "alloc", feature = "std")))]
+#[cfg_attr(feature = "docs", doc(cfg(bumpalo)))]
+#[doc(inline)]
+pub use crate::exts::bumpalo::DynamicTableIn;
+
 #[cfg(feature = "cell")]
 #[cfg_attr(feature = "docs", doc(cfg(cell)))]
 #[doc(inline)]
 pub use crate::exts::cell::*;
 
+#[cfg(feature = "crdt")]
+#[cfg_attr(feature = "docs", doc(cfg(crdt)))]
+#[doc(inline)]
+pub use crate::exts::crdt::LwwTable;
+
 #[cfg(feature = "csv")]
 #[cfg_attr(feature = "docs", doc(cfg(csv)))]
 #[doc(inline)]
-pub use crate::exts::csv::{FromCsv, ToCsv};
+pub use crate::exts::csv::{FromCsv, FromCsvInferred, ToCsv};
+
+#[cfg(all(feature = "datafusion", feature = "std"))]
+#[cfg_attr(feature = "docs", doc(cfg(datafusion)))]
+#[doc(inline)]
+pub use crate::exts::datafusion::ToRecordBatch;
+
+#[cfg(all(feature = "formula", any(feature = "alloc", feature = "std")))]
+#[cfg_attr(feature = "docs", doc(cfg(formula)))]
+#[doc(inline)]
+pub use crate::exts::formula::FormulaTable;
+
+#[cfg(feature = "http")]
+#[cfg_attr(feature = "docs", doc(cfg(http)))]
+#[doc(inline)]
+pub use crate::exts::http::{FromHttpBody, HttpFormat, ToHttpBody};
+
+#[cfg(all(feature = "image", feature = "std"))]
+#[cfg_attr(feature = "docs", doc(cfg(image)))]
+#[doc(inline)]
+pub use crate::exts::image::{FromImage, ToImage};
 
-#[cfg(feature = "sled-1")]
-#[cfg_attr(feature = "docs", doc(cfg(sled)))]
+#[cfg(all(feature = "mmap", feature = "std"))]
+#[cfg_attr(feature = "docs", doc(cfg(mmap)))]
+#[doc(inline)]
+pub use crate::exts::mmap::MmapTable;
+
+#[cfg(all(feature = "proto", feature = "std"))]
+#[cfg_attr(feature = "docs", doc(cfg(proto)))]
+#[doc(inline)]
+pub use crate::exts::proto::{FromProto, ToProto};
+
+#[cfg(feature = "rand")]
+#[cfg_attr(feature = "docs", doc(cfg(rand)))]
+#[doc(inline)]
+pub use crate::exts::rand::RandomTable;
+
+#[cfg(feature = "regex")]
+#[cfg_attr(feature = "docs", doc(cfg(regex)))]
+#[doc(inline)]
+pub use crate::exts::regex::{ReplaceRegex, SearchRegex};
+
+#[cfg(any(feature = "sled-1", feature = "sled-next"))]
+#[cfg_attr(feature = "docs", doc(cfg(any(sled, sled_next))))]
 #[doc(inline)]
 pub use crate::exts::sled::SledTable;
+
+#[cfg(all(feature = "sql", feature = "std"))]
+#[cfg_attr(feature = "docs", doc(cfg(sql)))]
+#[doc(inline)]
+pub use crate::exts::sql::QuerySql;
+
+#[cfg(feature = "tokio")]
+#[cfg_attr(feature = "docs", doc(cfg(tokio)))]
+#[doc(inline)]
+pub use crate::exts::tokio::TableHandle;
+
+#[cfg(feature = "ui")]
+#[cfg_attr(feature = "docs", doc(cfg(ui)))]
+#[doc(inline)]
+pub use crate::exts::ui::TableModel;
+
+#[cfg(feature = "web")]
+#[cfg_attr(feature = "docs", doc(cfg(web)))]
+#[doc(inline)]
+pub use crate::exts::web::{WebStorage, WebTable};
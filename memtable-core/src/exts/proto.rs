@@ -0,0 +1,526 @@
+use crate::Table;
+use prost::Message;
+use std::{convert::TryFrom, fmt};
+
+/// Canonical protobuf schema describing the encoding produced by [`ToProto`]
+/// and consumed by [`FromProto`], which can be handed to protoc or prost to
+/// generate matching types in other services
+pub const SCHEMA: &str = include_str!("../../proto/memtable.proto");
+
+/// Represents the value of a cell as found within the `value` oneof of the
+/// schema's `Cell` message
+#[cfg_attr(feature = "docs", doc(cfg(proto)))]
+#[derive(Clone, Debug, PartialEq)]
+pub enum ProtoValue {
+    /// Text written to the `text` field
+    Text(String),
+
+    /// Bytes written to the `raw` field
+    Raw(Vec<u8>),
+
+    /// Boolean written to the `boolean` field
+    Boolean(bool),
+
+    /// Signed integer written to the `int` field
+    Int(i64),
+
+    /// Unsigned integer written to the `uint` field
+    Uint(u64),
+
+    /// Floating point number written to the `float` field
+    Float(f64),
+}
+
+/// Represents data that can be stored in a cell of the schema's `Cell`
+/// message
+///
+/// Implementing this for the data type of a table derived via the `Table`
+/// macro enables encoding the derived table as well
+#[cfg_attr(feature = "docs", doc(cfg(proto)))]
+pub trait ProtoCell: Sized {
+    /// Converts the data into the value of a cell
+    fn to_proto_value(&self) -> ProtoValue;
+
+    /// Converts the value of a cell back into the data, returning none if the
+    /// value is of a different kind or out of range
+    fn from_proto_value(value: ProtoValue) -> Option<Self>;
+}
+
+impl ProtoCell for ProtoValue {
+    fn to_proto_value(&self) -> ProtoValue {
+        self.clone()
+    }
+
+    fn from_proto_value(value: ProtoValue) -> Option<Self> {
+        Some(value)
+    }
+}
+
+impl ProtoCell for String {
+    fn to_proto_value(&self) -> ProtoValue {
+        ProtoValue::Text(self.clone())
+    }
+
+    fn from_proto_value(value: ProtoValue) -> Option<Self> {
+        match value {
+            ProtoValue::Text(x) => Some(x),
+            _ => None,
+        }
+    }
+}
+
+impl ProtoCell for Vec<u8> {
+    fn to_proto_value(&self) -> ProtoValue {
+        ProtoValue::Raw(self.clone())
+    }
+
+    fn from_proto_value(value: ProtoValue) -> Option<Self> {
+        match value {
+            ProtoValue::Raw(x) => Some(x),
+            _ => None,
+        }
+    }
+}
+
+impl ProtoCell for bool {
+    fn to_proto_value(&self) -> ProtoValue {
+        ProtoValue::Boolean(*self)
+    }
+
+    fn from_proto_value(value: ProtoValue) -> Option<Self> {
+        match value {
+            ProtoValue::Boolean(x) => Some(x),
+            _ => None,
+        }
+    }
+}
+
+macro_rules! impl_proto_cell {
+    ($variant:ident, $inner:ty, $($t:ty)*) => {$(
+        impl ProtoCell for $t {
+            fn to_proto_value(&self) -> ProtoValue {
+                ProtoValue::$variant(*self as $inner)
+            }
+
+            fn from_proto_value(value: ProtoValue) -> Option<Self> {
+                match value {
+                    ProtoValue::$variant(x) => <$t>::try_from(x).ok(),
+                    _ => None,
+                }
+            }
+        }
+    )*};
+}
+
+impl_proto_cell!(Int, i64, i8 i16 i32 i64 isize);
+impl_proto_cell!(Uint, u64, u8 u16 u32 u64 usize);
+
+impl ProtoCell for f64 {
+    fn to_proto_value(&self) -> ProtoValue {
+        ProtoValue::Float(*self)
+    }
+
+    fn from_proto_value(value: ProtoValue) -> Option<Self> {
+        match value {
+            ProtoValue::Float(x) => Some(x),
+            _ => None,
+        }
+    }
+}
+
+impl ProtoCell for f32 {
+    fn to_proto_value(&self) -> ProtoValue {
+        ProtoValue::Float(*self as f64)
+    }
+
+    fn from_proto_value(value: ProtoValue) -> Option<Self> {
+        match value {
+            ProtoValue::Float(x) => Some(x as f32),
+            _ => None,
+        }
+    }
+}
+
+/// Contains the messages of the canonical schema, mirroring those generated
+/// by prost from `proto/memtable.proto` so they can be embedded within the
+/// messages of other services
+#[cfg_attr(feature = "docs", doc(cfg(proto)))]
+pub mod pb {
+    /// The schema's `Table` message, listing only the cells that are present
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct Table {
+        /// Total rows within the table
+        #[prost(uint64, tag = "1")]
+        pub row_cnt: u64,
+
+        /// Total columns within the table
+        #[prost(uint64, tag = "2")]
+        pub col_cnt: u64,
+
+        /// Cells that are present within the table
+        #[prost(message, repeated, tag = "3")]
+        pub cells: Vec<Cell>,
+    }
+
+    /// The schema's `Cell` message
+    #[derive(Clone, PartialEq, prost::Message)]
+    pub struct Cell {
+        /// Row of the cell
+        #[prost(uint64, tag = "1")]
+        pub row: u64,
+
+        /// Column of the cell
+        #[prost(uint64, tag = "2")]
+        pub col: u64,
+
+        /// Value of the cell, which is only missing if written by a newer
+        /// schema with a kind of value unknown to this one
+        #[prost(oneof = "cell::Value", tags = "3, 4, 5, 6, 7, 8")]
+        pub value: Option<cell::Value>,
+    }
+
+    /// Contains the `value` oneof of the schema's `Cell` message
+    pub mod cell {
+        use prost::{
+            bytes::{Buf, BufMut},
+            encoding::{self, DecodeContext, WireType},
+            DecodeError,
+        };
+
+        /// The `value` oneof of the schema's `Cell` message
+        ///
+        /// Written by hand rather than derived, as the methods that prost
+        /// derives for a oneof carry no documentation
+        #[derive(Clone, Debug, PartialEq)]
+        pub enum Value {
+            /// The `text` field
+            Text(String),
+
+            /// The `raw` field
+            Raw(Vec<u8>),
+
+            /// The `boolean` field
+            Boolean(bool),
+
+            /// The `int` field
+            Int(i64),
+
+            /// The `uint` field
+            Uint(u64),
+
+            /// The `float` field
+            Float(f64),
+        }
+
+        impl Value {
+            /// Encodes the value as the field matching its kind
+            pub fn encode<B: BufMut>(&self, buf: &mut B) {
+                match self {
+                    Self::Text(x) => encoding::string::encode(3, x, buf),
+                    Self::Raw(x) => encoding::bytes::encode(4, x, buf),
+                    Self::Boolean(x) => encoding::bool::encode(5, x, buf),
+                    Self::Int(x) => encoding::sint64::encode(6, x, buf),
+                    Self::Uint(x) => encoding::uint64::encode(7, x, buf),
+                    Self::Float(x) => encoding::double::encode(8, x, buf),
+                }
+            }
+
+            /// Decodes the field with the given tag into `field`, merging
+            /// into the existing value if it is of the same kind and
+            /// replacing it otherwise
+            ///
+            /// ### Panics
+            ///
+            /// Panics if `tag` is not one of the fields of the oneof
+            pub fn merge<B: Buf>(
+                field: &mut Option<Value>,
+                tag: u32,
+                wire_type: WireType,
+                buf: &mut B,
+                ctx: DecodeContext,
+            ) -> Result<(), DecodeError> {
+                macro_rules! merge {
+                    ($variant:ident, $encoding:ident) => {
+                        match field {
+                            Some(Self::$variant(value)) => {
+                                encoding::$encoding::merge(wire_type, value, buf, ctx)
+                            }
+                            _ => {
+                                let mut value = Default::default();
+                                encoding::$encoding::merge(wire_type, &mut value, buf, ctx)?;
+                                *field = Some(Self::$variant(value));
+                                Ok(())
+                            }
+                        }
+                    };
+                }
+
+                match tag {
+                    3 => merge!(Text, string),
+                    4 => merge!(Raw, bytes),
+                    5 => merge!(Boolean, bool),
+                    6 => merge!(Int, sint64),
+                    7 => merge!(Uint, uint64),
+                    8 => merge!(Float, double),
+                    _ => unreachable!("invalid Value tag: {}", tag),
+                }
+            }
+
+            /// Returns the total bytes needed to encode the value
+            pub fn encoded_len(&self) -> usize {
+                match self {
+                    Self::Text(x) => encoding::string::encoded_len(3, x),
+                    Self::Raw(x) => encoding::bytes::encoded_len(4, x),
+                    Self::Boolean(x) => encoding::bool::encoded_len(5, x),
+                    Self::Int(x) => encoding::sint64::encoded_len(6, x),
+                    Self::Uint(x) => encoding::uint64::encoded_len(7, x),
+                    Self::Float(x) => encoding::double::encoded_len(8, x),
+                }
+            }
+        }
+    }
+}
+
+impl From<ProtoValue> for pb::cell::Value {
+    fn from(value: ProtoValue) -> Self {
+        match value {
+            ProtoValue::Text(x) => Self::Text(x),
+            ProtoValue::Raw(x) => Self::Raw(x),
+            ProtoValue::Boolean(x) => Self::Boolean(x),
+            ProtoValue::Int(x) => Self::Int(x),
+            ProtoValue::Uint(x) => Self::Uint(x),
+            ProtoValue::Float(x) => Self::Float(x),
+        }
+    }
+}
+
+impl From<pb::cell::Value> for ProtoValue {
+    fn from(value: pb::cell::Value) -> Self {
+        match value {
+            pb::cell::Value::Text(x) => Self::Text(x),
+            pb::cell::Value::Raw(x) => Self::Raw(x),
+            pb::cell::Value::Boolean(x) => Self::Boolean(x),
+            pb::cell::Value::Int(x) => Self::Int(x),
+            pb::cell::Value::Uint(x) => Self::Uint(x),
+            pb::cell::Value::Float(x) => Self::Float(x),
+        }
+    }
+}
+
+/// Represents an error encountered while decoding a table from protobuf
+#[cfg_attr(feature = "docs", doc(cfg(proto)))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProtoError {
+    /// The bytes were not a valid `Table` message
+    Decode(prost::DecodeError),
+
+    /// A count or position does not fit within a `usize`
+    OutOfRange(u64),
+
+    /// A cell's value could not be converted into the table's data
+    IncompatibleValue {
+        /// Row of the cell
+        row: usize,
+
+        /// Column of the cell
+        col: usize,
+    },
+}
+
+impl fmt::Display for ProtoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Decode(x) => write!(f, "Failed to decode: {}", x),
+            Self::OutOfRange(x) => write!(f, "{} is out of range", x),
+            Self::IncompatibleValue { row, col } => {
+                write!(f, "Incompatible value in cell ({}, {})", row, col)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ProtoError {}
+
+impl From<prost::DecodeError> for ProtoError {
+    fn from(x: prost::DecodeError) -> Self {
+        Self::Decode(x)
+    }
+}
+
+/// Represents ability to encode a table as the schema's `Table` message
+#[cfg_attr(feature = "docs", doc(cfg(proto)))]
+pub trait ToProto {
+    /// Converts the table into the schema's `Table` message
+    fn to_proto_message(&self) -> pb::Table;
+
+    /// Encodes the table into protobuf bytes
+    fn to_proto(&self) -> Vec<u8> {
+        self.to_proto_message().encode_to_vec()
+    }
+}
+
+impl<D: ProtoCell, T: Table<Data = D>> ToProto for T {
+    fn to_proto_message(&self) -> pb::Table {
+        pb::Table {
+            row_cnt: self.row_cnt() as u64,
+            col_cnt: self.col_cnt() as u64,
+            cells: self
+                .occupied_positions()
+                .filter_map(|pos| {
+                    self.cell(pos.row, pos.col).map(|cell| pb::Cell {
+                        row: pos.row as u64,
+                        col: pos.col as u64,
+                        value: Some(cell.to_proto_value().into()),
+                    })
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Represents ability to decode a table from the schema's `Table` message
+#[cfg_attr(feature = "docs", doc(cfg(proto)))]
+pub trait FromProto {
+    /// Represents the type to produce from protobuf bytes
+    type Output;
+
+    /// Converts the schema's `Table` message into a table
+    fn from_proto_message(message: pb::Table) -> Result<Self::Output, ProtoError>;
+
+    /// Decodes a table from protobuf bytes
+    fn from_proto(bytes: &[u8]) -> Result<Self::Output, ProtoError> {
+        Self::from_proto_message(pb::Table::decode(bytes)?)
+    }
+}
+
+impl<D: ProtoCell, T: Table<Data = D> + Default> FromProto for T {
+    type Output = T;
+
+    fn from_proto_message(message: pb::Table) -> Result<Self::Output, ProtoError> {
+        let mut table = T::default();
+
+        for cell in message.cells {
+            let row = to_usize(cell.row)?;
+            let col = to_usize(cell.col)?;
+            if let Some(value) = cell.value {
+                let data = D::from_proto_value(value.into())
+                    .ok_or(ProtoError::IncompatibleValue { row, col })?;
+                table.insert_cell(row, col, data);
+            }
+        }
+
+        table.set_preferred_row_cnt(to_usize(message.row_cnt)?);
+        table.set_preferred_col_cnt(to_usize(message.col_cnt)?);
+        Ok(table)
+    }
+}
+
+fn to_usize(x: u64) -> Result<usize, ProtoError> {
+    usize::try_from(x).map_err(|_| ProtoError::OutOfRange(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DynamicTable;
+
+    #[test]
+    fn to_proto_should_follow_schema() {
+        let mut table = DynamicTable::new();
+        table.insert_cell(0, 1, String::from("hi"));
+        table.insert_cell(1, 0, String::from("yo"));
+        table.remove_cell(1, 0);
+
+        assert_eq!(
+            table.to_proto(),
+            [
+                0x08, 2, // row_cnt
+                0x10, 2, // col_cnt
+                0x1a, 6, // cells
+                0x10, 1, // col
+                0x1a, 2, b'h', b'i', // text
+            ]
+        );
+    }
+
+    #[test]
+    fn from_proto_should_round_trip_table() {
+        let mut table = DynamicTable::from([[-1i64, 2, 300], [i64::MIN, 0, i64::MAX]]);
+        table.remove_cell(0, 1);
+        table.set_preferred_row_cnt(3);
+
+        let new_table = DynamicTable::<i64>::from_proto(&table.to_proto()).unwrap();
+        assert_eq!(new_table, table);
+        assert_eq!(new_table.row_cnt(), 3);
+    }
+
+    #[test]
+    fn from_proto_should_support_mixed_values() {
+        let mut table = DynamicTable::new();
+        table.push_row(vec![
+            ProtoValue::Text(String::from("a")),
+            ProtoValue::Raw(vec![1, 2]),
+            ProtoValue::Boolean(true),
+            ProtoValue::Uint(u64::MAX),
+            ProtoValue::Float(1.5),
+        ]);
+
+        let new_table = DynamicTable::<ProtoValue>::from_proto(&table.to_proto()).unwrap();
+        assert_eq!(new_table, table);
+    }
+
+    #[test]
+    fn to_proto_should_be_decodable_as_message() {
+        let table = DynamicTable::from([[String::from("a")]]);
+
+        let message = pb::Table::decode(table.to_proto().as_slice()).unwrap();
+        assert_eq!(message, table.to_proto_message());
+        assert_eq!(
+            message.cells,
+            [pb::Cell {
+                row: 0,
+                col: 0,
+                value: Some(pb::cell::Value::Text(String::from("a"))),
+            }]
+        );
+    }
+
+    #[test]
+    fn from_proto_should_skip_unknown_fields() {
+        let mut bytes = DynamicTable::from([[1u8]]).to_proto();
+        bytes.extend_from_slice(&[0x25, 0, 0, 0, 0, 0x48, 1]);
+
+        let table = DynamicTable::<u8>::from_proto(&bytes).unwrap();
+        assert_eq!(table, [[1]]);
+    }
+
+    #[test]
+    fn from_proto_should_fail_if_value_is_incompatible() {
+        let bytes = DynamicTable::from([[300u16]]).to_proto();
+
+        assert_eq!(
+            DynamicTable::<u8>::from_proto(&bytes),
+            Err(ProtoError::IncompatibleValue { row: 0, col: 0 })
+        );
+        assert_eq!(
+            DynamicTable::<String>::from_proto(&bytes),
+            Err(ProtoError::IncompatibleValue { row: 0, col: 0 })
+        );
+    }
+
+    #[test]
+    fn from_proto_should_fail_if_bytes_are_truncated() {
+        let bytes = DynamicTable::from([[String::from("abc")]]).to_proto();
+
+        assert!(matches!(
+            DynamicTable::<String>::from_proto(&bytes[..bytes.len() - 1]),
+            Err(ProtoError::Decode(_))
+        ));
+    }
+
+    #[test]
+    fn schema_should_describe_table_and_cell_messages() {
+        assert!(SCHEMA.contains("message Table"));
+        assert!(SCHEMA.contains("message Cell"));
+    }
+}
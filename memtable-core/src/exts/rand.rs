@@ -0,0 +1,74 @@
+use crate::Table;
+use ::rand::{distributions::Distribution, Rng};
+
+/// Represents the ability to create tables filled with random data, which is
+/// handy for property tests and benchmarks
+pub trait RandomTable: Table + Default {
+    /// Creates a table with the specified rows and columns, filling every
+    /// cell with a value sampled from the distribution using the given
+    /// random number generator
+    ///
+    /// Tables with a limited capacity are filled up to that capacity
+    fn random<R, D>(rows: usize, cols: usize, rng: &mut R, distribution: D) -> Self
+    where
+        R: Rng + ?Sized,
+        D: Distribution<Self::Data>,
+    {
+        let mut table = Self::default();
+
+        for row in 0..rows {
+            for col in 0..cols {
+                table.insert_cell(row, col, distribution.sample(rng));
+            }
+        }
+
+        table.set_preferred_row_cnt(rows);
+        table.set_preferred_col_cnt(cols);
+        table
+    }
+}
+
+impl<T: Table + Default> RandomTable for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::rand::{
+        distributions::{Standard, Uniform},
+        rngs::mock::StepRng,
+    };
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[test]
+    fn random_should_fill_every_cell_using_distribution() {
+        let mut rng = StepRng::new(0, 1 << 40);
+        let table = crate::DynamicTable::random(3, 4, &mut rng, Uniform::new(10, 20));
+
+        assert_eq!(table.row_cnt(), 3);
+        assert_eq!(table.col_cnt(), 4);
+        assert_eq!(table.len(), 12);
+        assert!(table.cells().all(|x| (10..20).contains(x)));
+    }
+
+    #[test]
+    fn random_should_stop_at_capacity_of_fixed_table() {
+        let mut rng = StepRng::new(0, 1);
+        let table: crate::FixedTable<u8, 2, 2> = RandomTable::random(3, 3, &mut rng, Standard);
+
+        assert_eq!(table.row_cnt(), 2);
+        assert_eq!(table.col_cnt(), 2);
+    }
+
+    #[cfg(all(feature = "cell", any(feature = "alloc", feature = "std")))]
+    #[test]
+    fn random_should_support_cell_enums() {
+        use crate::exts::cell::Cell2;
+
+        let mut rng = StepRng::new(0, 1 << 62);
+        let table: crate::DynamicTable<Cell2<u8, bool>> =
+            RandomTable::random(4, 4, &mut rng, Standard);
+
+        assert!(table.cells().any(Cell2::is_a));
+        assert!(table.cells().any(Cell2::is_b));
+    }
+}
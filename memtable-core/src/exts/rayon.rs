@@ -0,0 +1,129 @@
+use crate::{DynamicTable, Position};
+use ::rayon::prelude::*;
+use std::collections::HashMap;
+
+#[cfg(feature = "csv")]
+use {::csv as csv_lib, std::io};
+
+/// Builds the cells on each thread into a separate shard, which are then
+/// merged such that a later cell at the same position replaces an earlier one
+fn collect_shards<T, I>(iter: I) -> HashMap<Position, T>
+where
+    T: Send,
+    I: ParallelIterator<Item = (Position, T)>,
+{
+    iter.fold(HashMap::new, |mut shard, (pos, x)| {
+        shard.insert(pos, x);
+        shard
+    })
+    .reduce(HashMap::new, |mut earlier, mut later| {
+        // Merge the smaller shard into the larger one, keeping the cells of
+        // the later shard when both have the same position
+        if earlier.len() <= later.len() {
+            for (pos, x) in earlier {
+                later.entry(pos).or_insert(x);
+            }
+            later
+        } else {
+            earlier.extend(later);
+            earlier
+        }
+    })
+}
+
+impl<T: Send, V: Into<T> + Send> FromParallelIterator<(usize, usize, V)> for DynamicTable<T> {
+    /// Produces a table from the provided parallel iterator of
+    /// (row, col, value), building shards of cells on multiple threads
+    fn from_par_iter<I>(iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = (usize, usize, V)>,
+    {
+        trace_span!("from_par_iter");
+        let cells = collect_shards(
+            iter.into_par_iter()
+                .map(|(row, col, x)| (Position { row, col }, x.into())),
+        );
+        Self::from(cells)
+    }
+}
+
+impl<T: Send, V: Into<T> + Send> FromParallelIterator<(Position, V)> for DynamicTable<T> {
+    /// Produces a table from the provided parallel iterator of
+    /// (position, value), building shards of cells on multiple threads
+    fn from_par_iter<I>(iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = (Position, V)>,
+    {
+        trace_span!("from_par_iter");
+        let cells = collect_shards(iter.into_par_iter().map(|(p, x)| (p, x.into())));
+        Self::from(cells)
+    }
+}
+
+#[cfg(feature = "csv")]
+#[cfg_attr(feature = "docs", doc(cfg(csv)))]
+impl DynamicTable<String> {
+    /// Loads a table from some instance of the [`io::Read`] trait like
+    /// [`crate::exts::csv::FromCsv::from_csv`], where the records are read
+    /// in order and then turned into cells on multiple threads
+    pub fn from_csv_par<R: io::Read>(reader: R) -> io::Result<Self> {
+        trace_span!("from_csv_par");
+        let mut rdr = csv_lib::ReaderBuilder::new()
+            .has_headers(false)
+            .from_reader(reader);
+        let records = rdr.records().collect::<Result<Vec<_>, _>>()?;
+
+        trace_event!(rows = records.len(), "read csv records");
+        Ok(records
+            .into_par_iter()
+            .enumerate()
+            .flat_map_iter(|(row, record)| {
+                (0..record.len()).map(move |col| (row, col, record[col].to_string()))
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Table;
+
+    #[test]
+    fn from_par_iter_should_calculate_row_and_column_counts() {
+        let table: DynamicTable<usize> = (0..1000usize)
+            .into_par_iter()
+            .map(|i| (i / 10, i % 10, i))
+            .collect();
+
+        assert_eq!(table.row_cnt(), 100);
+        assert_eq!(table.col_cnt(), 10);
+        assert_eq!(table.cell(57, 3), Some(&573));
+        assert_eq!(table.cells().count(), 1000);
+    }
+
+    #[test]
+    fn from_par_iter_should_keep_the_last_value_at_a_position() {
+        let table: DynamicTable<usize> = (0..1000usize)
+            .into_par_iter()
+            .map(|i| (Position { row: 0, col: i % 2 }, i))
+            .collect();
+
+        assert_eq!(table.row_cnt(), 1);
+        assert_eq!(table.col_cnt(), 2);
+        assert_eq!(table.cell(0, 0), Some(&998));
+        assert_eq!(table.cell(0, 1), Some(&999));
+    }
+
+    #[cfg(feature = "csv")]
+    #[test]
+    fn from_csv_par_should_match_sequential_loading() {
+        use crate::exts::csv::FromCsv;
+
+        let csv = "a,b,c\n1,2,3\n,,4\n";
+        let table = DynamicTable::from_csv_par(csv.as_bytes()).unwrap();
+        let expected = DynamicTable::<String>::from_csv_str(csv).unwrap();
+
+        assert_eq!(table, expected);
+    }
+}
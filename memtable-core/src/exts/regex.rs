@@ -0,0 +1,178 @@
+use crate::{Position, Table};
+use ::regex::{Regex, Replacer};
+
+/// Represents a single match of a regular expression within a cell
+#[cfg_attr(feature = "docs", doc(cfg(regex)))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RegexMatch<'a> {
+    /// Position of the cell containing the match
+    pub position: Position,
+
+    /// Groups captured by the match, where the first group is always the
+    /// entire match and any subsequent group is `None` if it did not
+    /// participate in the match
+    pub captures: Vec<Option<&'a str>>,
+}
+
+impl<'a> RegexMatch<'a> {
+    /// Returns the text of the entire match
+    pub fn as_str(&self) -> &'a str {
+        self.captures[0].unwrap_or_default()
+    }
+}
+
+/// Represents ability to search a table's text using regular expressions
+#[cfg_attr(feature = "docs", doc(cfg(regex)))]
+pub trait SearchRegex {
+    /// Returns every match of the regular expression across all cells,
+    /// searching row by row; a cell can contribute more than one match
+    fn search_regex(&self, re: &Regex) -> Vec<RegexMatch<'_>>;
+
+    /// Returns the positions of all cells containing at least one match of
+    /// the regular expression
+    fn positions_matching_regex(&self, re: &Regex) -> Vec<Position>;
+}
+
+impl<T: Table> SearchRegex for T
+where
+    T::Data: AsRef<str>,
+{
+    fn search_regex(&self, re: &Regex) -> Vec<RegexMatch<'_>> {
+        let mut matches = Vec::new();
+
+        for row in 0..self.row_cnt() {
+            for col in 0..self.col_cnt() {
+                if let Some(text) = self.cell(row, col) {
+                    for caps in re.captures_iter(text.as_ref()) {
+                        matches.push(RegexMatch {
+                            position: Position { row, col },
+                            captures: caps.iter().map(|m| m.map(|m| m.as_str())).collect(),
+                        });
+                    }
+                }
+            }
+        }
+
+        matches
+    }
+
+    fn positions_matching_regex(&self, re: &Regex) -> Vec<Position> {
+        self.find_all_cells(|text| re.is_match(text.as_ref()))
+    }
+}
+
+/// Represents ability to edit a table's text in place using regular expressions
+#[cfg_attr(feature = "docs", doc(cfg(regex)))]
+pub trait ReplaceRegex {
+    /// Replaces all matches of the regular expression within every cell using
+    /// the given replacement, returning the total cells that were changed
+    fn replace_regex<R: Replacer>(&mut self, re: &Regex, rep: R) -> usize;
+}
+
+impl<D: AsRef<str> + From<String>, T: Table<Data = D>> ReplaceRegex for T {
+    fn replace_regex<R: Replacer>(&mut self, re: &Regex, mut rep: R) -> usize {
+        let mut cnt = 0;
+
+        for row in 0..self.row_cnt() {
+            for col in 0..self.col_cnt() {
+                if let Some(cell) = self.mut_cell(row, col) {
+                    if re.is_match(cell.as_ref()) {
+                        let text = re.replace_all(cell.as_ref(), rep.by_ref()).into_owned();
+                        *cell = D::from(text);
+                        cnt += 1;
+                    }
+                }
+            }
+        }
+
+        cnt
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DynamicTable;
+
+    #[test]
+    fn search_regex_should_return_positions_and_captures_of_all_matches() {
+        let table = DynamicTable::from([
+            ["id-1", "alice@example.com"],
+            ["id-2", "bob@example.org"],
+            ["id-3", "n/a"],
+        ]);
+        let re = Regex::new(r"(\w+)@example\.(com|org)").unwrap();
+
+        let matches = table.search_regex(&re);
+        assert_eq!(
+            matches,
+            vec![
+                RegexMatch {
+                    position: Position { row: 0, col: 1 },
+                    captures: vec![Some("alice@example.com"), Some("alice"), Some("com")],
+                },
+                RegexMatch {
+                    position: Position { row: 1, col: 1 },
+                    captures: vec![Some("bob@example.org"), Some("bob"), Some("org")],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn search_regex_should_include_every_match_within_a_cell() {
+        let table = DynamicTable::from([["id-1"], ["id-2"], ["id-3 id-4"]]);
+        let re = Regex::new(r"id-(\d)").unwrap();
+
+        let matches: Vec<(Position, &str)> = table
+            .search_regex(&re)
+            .into_iter()
+            .map(|m| (m.position, m.as_str()))
+            .collect();
+        assert_eq!(
+            matches,
+            vec![
+                (Position { row: 0, col: 0 }, "id-1"),
+                (Position { row: 1, col: 0 }, "id-2"),
+                (Position { row: 2, col: 0 }, "id-3"),
+                (Position { row: 2, col: 0 }, "id-4"),
+            ]
+        );
+    }
+
+    #[test]
+    fn search_regex_should_support_tables_of_str() {
+        let table = DynamicTable::from([["abc", "def"]]);
+        let re = Regex::new("e").unwrap();
+
+        assert_eq!(
+            table.positions_matching_regex(&re),
+            vec![Position { row: 0, col: 1 }]
+        );
+    }
+
+    #[test]
+    fn replace_regex_should_update_matching_cells_in_place() {
+        let mut table = DynamicTable::from([
+            ["alice@example.com".to_string()],
+            ["bob@example.org".to_string()],
+            ["n/a".to_string()],
+        ]);
+        let re = Regex::new(r"@example\.(com|org)").unwrap();
+
+        assert_eq!(table.replace_regex(&re, "@example.net"), 2);
+        assert_eq!(table[(0, 0)], "alice@example.net");
+        assert_eq!(table[(1, 0)], "bob@example.net");
+        assert_eq!(table[(2, 0)], "n/a");
+    }
+
+    #[test]
+    fn replace_regex_should_support_capture_group_expansion() {
+        let mut table = DynamicTable::from([["id-1".to_string()], ["id-3 id-4".to_string()]]);
+        let re = Regex::new(r"id-(\d)").unwrap();
+
+        assert_eq!(table.replace_regex(&re, "#$1"), 2);
+        assert_eq!(table[(0, 0)], "#1");
+        assert_eq!(table[(1, 0)], "#3 #4");
+    }
+}
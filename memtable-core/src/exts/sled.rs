@@ -1,5 +1,9 @@
-use crate::{list::*, Capacity, Table};
-use ::sled::Tree;
+use crate::{
+    list::*,
+    progress::{CancelToken, ControlFlow, PartialCompletion},
+    Capacity, Table,
+};
+use compat::Tree;
 use serde::{Deserialize, Serialize};
 use std::{convert::TryFrom, sync::Mutex};
 
@@ -60,6 +64,24 @@ where
     /// Reloads the data in the table from sled, optionally refreshing the
     /// row and column capacities first
     pub fn reload(&mut self, refresh_capacities: bool) -> utils::Result<()> {
+        self.reload_with_progress(refresh_capacities, |_, _| ControlFlow::Continue)
+    }
+
+    /// Reloads the data in the table like [`SledTable::reload`], reporting
+    /// the cells checked so far and the total cells after each row
+    ///
+    /// If the callback breaks, reloading stops and an io error of kind
+    /// [`std::io::ErrorKind::Other`] wrapping a [`PartialCompletion`]
+    /// is returned, leaving the rows already reloaded in the table
+    pub fn reload_with_progress<F, P>(
+        &mut self,
+        refresh_capacities: bool,
+        mut f: F,
+    ) -> utils::Result<()>
+    where
+        F: FnMut(usize, usize) -> P,
+        P: Into<ControlFlow>,
+    {
         let (row_cnt, col_cnt) = if refresh_capacities {
             let (row_cnt, col_cnt) = utils::row_and_col_cnts(&self.tree)?;
             let row_cnt = row_cnt.unwrap_or_default();
@@ -72,6 +94,7 @@ where
             (self.row_cnt(), self.col_cnt())
         };
 
+        trace_span!("sled_reload", cells = row_cnt * col_cnt);
         for row in 0..row_cnt {
             for col in 0..col_cnt {
                 let value = utils::load_cell(&self.tree, row, col)?;
@@ -79,6 +102,12 @@ where
                     self.table.insert_cell(row, col, value);
                 }
             }
+
+            let done = (row + 1) * col_cnt;
+            let total = row_cnt * col_cnt;
+            if f(done, total).into().is_break() {
+                return Err(utils::Error::Io(PartialCompletion { done, total }.into()));
+            }
         }
         Ok(())
     }
@@ -114,18 +143,40 @@ where
     /// Flushes any changes to sled, optionally rewriting the entire table
     /// prior to flushing
     pub fn flush(&mut self, rewrite: bool) -> utils::Result<usize> {
+        self.flush_cancellable(rewrite, &CancelToken::new())
+    }
+
+    /// Flushes any changes to sled like [`SledTable::flush`], checking the
+    /// token before rewriting each cell and stopping once it is cancelled
+    ///
+    /// If cancelled, an io error of kind [`std::io::ErrorKind::Other`]
+    /// wrapping a [`PartialCompletion`] counting the cells rewritten is
+    /// returned; cells rewritten before then are still flushed
+    pub fn flush_cancellable(
+        &mut self,
+        rewrite: bool,
+        token: &CancelToken,
+    ) -> utils::Result<usize> {
         use crate::iter::CellIter;
+        trace_span!("sled_flush", rewrite, cells = self.table.len());
 
         if rewrite {
             utils::set_preferred_row_cnt(&self.tree, self.table.row_cnt())?;
             utils::set_preferred_col_cnt(&self.tree, self.table.col_cnt())?;
 
-            for (pos, cell) in self.table.cells().zip_with_position() {
+            let total = self.table.len();
+            for (done, (pos, cell)) in self.table.cells().zip_with_position().enumerate() {
+                if token.is_cancelled() {
+                    compat::flush(&self.tree)?;
+                    return Err(utils::Error::Io(PartialCompletion { done, total }.into()));
+                }
+
                 let _ = utils::insert_cell(&self.tree, pos.row, pos.col, cell)?;
             }
         }
 
-        let cnt = self.tree.flush()?;
+        let cnt = compat::flush(&self.tree)?;
+        trace_event!(bytes = cnt, "flushed sled tree");
         Ok(cnt)
     }
 }
@@ -222,6 +273,30 @@ where
 
         self.table.set_preferred_col_cnt(capacity);
     }
+
+    /// Will remove the cells outside of the row & column counts from both the
+    /// inner table and the [`sled::Tree`]
+    fn truncate(&mut self) {
+        if let Err(x) =
+            utils::remove_cells_outside(&self.tree, self.table.row_cnt(), self.table.col_cnt())
+        {
+            self.push_error(x);
+        }
+
+        self.table.truncate();
+    }
+
+    /// Will shrink the inner table to fit its cells and replicate the new
+    /// row & column counts in the [`sled::Tree`]
+    fn shrink_to_fit(&mut self) {
+        self.table.shrink_to_fit();
+
+        if let Err(x) =
+            utils::set_row_and_col_cnts(&self.tree, self.table.row_cnt(), self.table.col_cnt())
+        {
+            self.push_error(x);
+        }
+    }
 }
 
 impl<D, R, C, T> TryFrom<Tree> for SledTable<D, R, C, T>
@@ -241,13 +316,122 @@ where
     }
 }
 
+/// Abstracts over the differences between the sled releases so the rest of
+/// this module only deals in raw bytes
+///
+/// The 0.34 series is used with the `sled-1` feature while the 1.0 series is
+/// used with the `sled-next` feature, taking precedence if both are enabled
+#[cfg(not(feature = "sled-next"))]
+mod compat {
+    use super::utils::Result;
+    use ::sled::{transaction::TransactionResult, IVec};
+    use std::io;
+
+    pub use ::sled::Tree;
+
+    pub fn get(tree: &Tree, key: impl AsRef<[u8]>) -> Result<Option<IVec>> {
+        Ok(tree.get(key)?)
+    }
+
+    pub fn insert(tree: &Tree, key: impl AsRef<[u8]>, value: Vec<u8>) -> Result<Option<IVec>> {
+        Ok(tree.insert(key, value)?)
+    }
+
+    pub fn remove(tree: &Tree, key: impl AsRef<[u8]>) -> Result<Option<IVec>> {
+        Ok(tree.remove(key)?)
+    }
+
+    /// Returns every key within the tree
+    pub fn keys(tree: &Tree) -> Result<Vec<Vec<u8>>> {
+        tree.iter().keys().map(|x| Ok(x?.to_vec())).collect()
+    }
+
+    /// Flushes the tree, returning the total bytes flushed
+    pub fn flush(tree: &Tree) -> Result<usize> {
+        Ok(tree.flush()?)
+    }
+
+    /// Loads the values of both keys from the same view of the tree
+    pub fn get_pair(tree: &Tree, a: &str, b: &str) -> Result<(Option<IVec>, Option<IVec>)> {
+        let result: TransactionResult<_, io::Error> =
+            tree.transaction(|tx_db| Ok((tx_db.get(a)?, tx_db.get(b)?)));
+        Ok(result?)
+    }
+
+    /// Inserts the values of both keys atomically
+    pub fn insert_pair(tree: &Tree, a: (&str, Vec<u8>), b: (&str, Vec<u8>)) -> Result<()> {
+        let result: TransactionResult<(), io::Error> = tree.transaction(|tx_db| {
+            tx_db.insert(a.0, a.1.as_slice())?;
+            tx_db.insert(b.0, b.1.as_slice())?;
+            Ok(())
+        });
+        Ok(result?)
+    }
+}
+
+/// Abstracts over the differences between the sled releases so the rest of
+/// this module only deals in raw bytes
+///
+/// The 0.34 series is used with the `sled-1` feature while the 1.0 series is
+/// used with the `sled-next` feature, taking precedence if both are enabled
+#[cfg(feature = "sled-next")]
+mod compat {
+    use super::utils::Result;
+    use ::sled_next::{Batch, InlineArray};
+
+    pub use ::sled_next::Tree;
+
+    pub fn get(tree: &Tree, key: impl AsRef<[u8]>) -> Result<Option<InlineArray>> {
+        Ok(tree.get(key)?)
+    }
+
+    pub fn insert(
+        tree: &Tree,
+        key: impl AsRef<[u8]>,
+        value: Vec<u8>,
+    ) -> Result<Option<InlineArray>> {
+        Ok(tree.insert(key, value)?)
+    }
+
+    pub fn remove(tree: &Tree, key: impl AsRef<[u8]>) -> Result<Option<InlineArray>> {
+        Ok(tree.remove(key)?)
+    }
+
+    /// Returns every key within the tree
+    pub fn keys(tree: &Tree) -> Result<Vec<Vec<u8>>> {
+        tree.iter().map(|x| Ok(x?.0.to_vec())).collect()
+    }
+
+    /// Flushes the tree, returning the total objects flushed as the 1.0
+    /// series no longer reports bytes
+    pub fn flush(tree: &Tree) -> Result<usize> {
+        Ok(tree.flush()?.objects_flushed as usize)
+    }
+
+    /// Loads the values of both keys; the 1.0 series has no read transactions,
+    /// so unlike 0.34 the two reads are not isolated from concurrent writes
+    pub fn get_pair(
+        tree: &Tree,
+        a: &str,
+        b: &str,
+    ) -> Result<(Option<InlineArray>, Option<InlineArray>)> {
+        Ok((tree.get(a)?, tree.get(b)?))
+    }
+
+    /// Inserts the values of both keys atomically
+    pub fn insert_pair(tree: &Tree, a: (&str, Vec<u8>), b: (&str, Vec<u8>)) -> Result<()> {
+        let mut batch = Batch::default();
+        batch.insert(a.0, a.1);
+        batch.insert(b.0, b.1);
+        tree.apply_batch(batch)?;
+        Ok(())
+    }
+}
+
 mod utils {
-    use ::sled::{
-        transaction::{abort, TransactionError},
-        Tree,
-    };
+    use super::compat::{self, Tree};
     use serde::{Deserialize, Serialize};
-    use std::{fmt, io, mem};
+    use std::{convert::TryInto, fmt, io, mem};
 
     const ROW_CNT_KEY: &str = "row_cnt";
     const COL_CNT_KEY: &str = "col_cnt";
@@ -259,8 +443,11 @@ mod utils {
         FailedToSerialize(bincode::Error),
         FailedToDeserialize(bincode::Error),
         Io(io::Error),
+        #[cfg(not(feature = "sled-next"))]
         Sled(::sled::Error),
-        MissingValue { key: String },
+        MissingValue {
+            key: String,
+        },
     }
 
     impl From<io::Error> for Error {
@@ -269,8 +456,10 @@ mod utils {
         }
     }
 
-    impl<T: fmt::Display> From<TransactionError<T>> for Error {
-        fn from(x: TransactionError<T>) -> Self {
+    #[cfg(not(feature = "sled-next"))]
+    impl<T: fmt::Display> From<::sled::transaction::TransactionError<T>> for Error {
+        fn from(x: ::sled::transaction::TransactionError<T>) -> Self {
+            use ::sled::transaction::TransactionError;
             match x {
                 TransactionError::Abort(x) => Self::Io(io::Error::new(
                     io::ErrorKind::ConnectionAborted,
@@ -281,55 +470,51 @@ mod utils {
         }
     }
 
-    impl From<sled::Error> for Error {
-        fn from(x: sled::Error) -> Self {
+    #[cfg(not(feature = "sled-next"))]
+    impl From<::sled::Error> for Error {
+        fn from(x: ::sled::Error) -> Self {
             Self::Sled(x)
         }
     }
 
     impl fmt::Display for Error {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-            write!(f, "{}", self)
+            match self {
+                Self::FailedToSerialize(x) => write!(f, "Failed to serialize: {}", x),
+                Self::FailedToDeserialize(x) => write!(f, "Failed to deserialize: {}", x),
+                Self::Io(x) => write!(f, "{}", x),
+                #[cfg(not(feature = "sled-next"))]
+                Self::Sled(x) => write!(f, "{}", x),
+                Self::MissingValue { key } => write!(f, "Missing value for key {}", key),
+            }
         }
     }
 
     impl std::error::Error for Error {}
 
     pub fn row_and_col_cnts(tree: &Tree) -> Result<(Option<usize>, Option<usize>)> {
-        tree.transaction(|tx_db| {
-            let row_cnt = tx_db
-                .get(ROW_CNT_KEY)?
-                .map(bytes_to_value)
-                .transpose()
-                .or_else(abort)?;
-            let col_cnt = tx_db
-                .get(COL_CNT_KEY)?
-                .map(bytes_to_value)
-                .transpose()
-                .or_else(abort)?;
-
-            Ok((row_cnt, col_cnt))
-        })
-        .map_err(Error::from)
+        let (row_cnt, col_cnt) = compat::get_pair(tree, ROW_CNT_KEY, COL_CNT_KEY)?;
+        Ok((
+            row_cnt.map(bytes_to_value).transpose()?,
+            col_cnt.map(bytes_to_value).transpose()?,
+        ))
     }
 
     pub fn set_row_and_col_cnts(tree: &Tree, row: usize, col: usize) -> Result<()> {
-        tree.transaction(|tx_db| {
-            tx_db.insert(ROW_CNT_KEY, value_to_bytes(&row).or_else(abort)?)?;
-            tx_db.insert(COL_CNT_KEY, value_to_bytes(&col).or_else(abort)?)?;
-            Ok(())
-        })
-        .map_err(Error::from)?;
-        Ok(())
+        compat::insert_pair(
+            tree,
+            (ROW_CNT_KEY, value_to_bytes(&row)?),
+            (COL_CNT_KEY, value_to_bytes(&col)?),
+        )
     }
 
     pub fn set_preferred_row_cnt(tree: &Tree, row: usize) -> Result<()> {
-        tree.insert(ROW_CNT_KEY, value_to_bytes(&row)?)?;
+        compat::insert(tree, ROW_CNT_KEY, value_to_bytes(&row)?)?;
         Ok(())
     }
 
     pub fn set_preferred_col_cnt(tree: &Tree, col: usize) -> Result<()> {
-        tree.insert(COL_CNT_KEY, value_to_bytes(&col)?)?;
+        compat::insert(tree, COL_CNT_KEY, value_to_bytes(&col)?)?;
         Ok(())
     }
 
@@ -358,11 +543,25 @@ mod utils {
         remove_value(tree, make_cell_key(row, col))
     }
 
+    /// Removes every cell stored at a position outside of the counts
+    pub fn remove_cells_outside(tree: &Tree, row_cnt: usize, col_cnt: usize) -> Result<()> {
+        for key in compat::keys(tree)? {
+            match parse_cell_key(&key) {
+                Some((row, col)) if row >= row_cnt || col >= col_cnt => {
+                    compat::remove(tree, &key)?;
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
     pub fn load_value<T: for<'de> Deserialize<'de>>(
         tree: &Tree,
         key: impl AsRef<[u8]>,
     ) -> Result<Option<T>> {
-        let value = tree.get(key)?.map(bytes_to_value).transpose()?;
+        let value = compat::get(tree, key)?.map(bytes_to_value).transpose()?;
         Ok(value)
     }
 
@@ -372,14 +571,16 @@ mod utils {
         value: &T,
     ) -> Result<Option<T>> {
         let bytes = value_to_bytes(value)?;
-        tree.insert(key, bytes)?.map(bytes_to_value).transpose()
+        compat::insert(tree, key, bytes)?
+            .map(bytes_to_value)
+            .transpose()
     }
 
     pub fn remove_value<T: for<'de> Deserialize<'de>>(
         tree: &Tree,
         key: impl AsRef<[u8]>,
     ) -> Result<Option<T>> {
-        tree.remove(key)?.map(bytes_to_value).transpose()
+        compat::remove(tree, key)?.map(bytes_to_value).transpose()
     }
 
     fn value_to_bytes<T: Serialize>(value: &T) -> Result<Vec<u8>> {
@@ -398,26 +599,61 @@ mod utils {
 
         buf
     }
+
+    /// Parses the row & column out of a key made by [`make_cell_key`],
+    /// returning `None` for any other key such as the counts
+    fn parse_cell_key(key: &[u8]) -> Option<(usize, usize)> {
+        let size = mem::size_of::<usize>();
+        if key.len() != 2 * size {
+            return None;
+        }
+
+        let (row, col) = key.split_at(size);
+        Some((
+            usize::from_be_bytes(row.try_into().ok()?),
+            usize::from_be_bytes(col.try_into().ok()?),
+        ))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::DynamicTable;
-    use sled::Config;
 
-    #[test]
-    fn should_persist_across_creations() {
-        let db = Config::default()
+    /// Creates a tree in a temporary database, returning the database
+    /// alongside it as the database is deleted once dropped
+    #[cfg(not(feature = "sled-next"))]
+    fn temporary_tree() -> (impl Sized, Tree) {
+        let db = ::sled::Config::default()
             .temporary(true)
             .open()
             .expect("Failed to create sled db");
+        let tree = db
+            .open_tree("test_table")
+            .expect("Failed to create test_table tree");
+        (db, tree)
+    }
 
-        // NOTE: Will be deleted once dropped; uses Arc<...> to track internally,
-        //       so we can clone this without issue
+    /// Creates a tree in a temporary database, returning the database
+    /// alongside it as the database is deleted once dropped
+    #[cfg(feature = "sled-next")]
+    fn temporary_tree() -> (impl Sized, Tree) {
+        let db = ::sled_next::Config::tmp()
+            .expect("Failed to create sled config")
+            .open::<1024>()
+            .expect("Failed to create sled db");
         let tree = db
             .open_tree("test_table")
             .expect("Failed to create test_table tree");
+        (db, tree)
+    }
+
+    #[test]
+    fn should_persist_across_creations() {
+        // NOTE: Will be deleted once dropped; uses Arc<...> to track internally,
+        //       so we can clone this without issue
+        let (_db, tree) = temporary_tree();
 
         // First, load a clean table and populate it
         {
@@ -477,4 +713,78 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn reload_with_progress_should_stop_if_callback_breaks() {
+        let (_db, tree) = temporary_tree();
+
+        let mut table =
+            SledTable::<_, DynamicList<usize>, DynamicList<usize>, DynamicTable<usize>>::try_from(
+                tree,
+            )
+            .expect("Failed to load table");
+        table.push_row(vec![1, 2]);
+        table.push_row(vec![3, 4]);
+        table.push_row(vec![5, 6]);
+
+        let mut reports = Vec::new();
+        let result = table.reload_with_progress(true, |done, total| {
+            reports.push((done, total));
+            if done >= 4 {
+                ControlFlow::Break
+            } else {
+                ControlFlow::Continue
+            }
+        });
+
+        assert!(matches!(
+            result,
+            Err(utils::Error::Io(x)) if x.kind() == std::io::ErrorKind::Other
+        ));
+        assert_eq!(reports, vec![(2, 6), (4, 6)]);
+    }
+
+    #[test]
+    fn flush_cancellable_should_stop_rewriting_once_cancelled() {
+        let (_db, tree) = temporary_tree();
+
+        let mut table =
+            SledTable::<_, DynamicList<usize>, DynamicList<usize>, DynamicTable<usize>>::try_from(
+                tree,
+            )
+            .expect("Failed to load table");
+        table.push_row(vec![1, 2]);
+
+        let token = CancelToken::new();
+        assert!(table.flush_cancellable(true, &token).is_ok());
+
+        token.cancel();
+        assert!(table.flush_cancellable(false, &token).is_ok());
+        assert!(matches!(
+            table.flush_cancellable(true, &token),
+            Err(utils::Error::Io(x)) if x.kind() == std::io::ErrorKind::Other
+        ));
+    }
+
+    #[test]
+    fn truncate_should_remove_cells_outside_of_counts_from_tree() {
+        let (_db, tree) = temporary_tree();
+
+        let mut table =
+            SledTable::<_, DynamicList<usize>, DynamicList<usize>, DynamicTable<usize>>::try_from(
+                tree,
+            )
+            .expect("Failed to load table");
+        table.push_row(vec![1, 2]);
+        table.push_row(vec![3, 4]);
+
+        table.set_preferred_row_cnt(1);
+        table.truncate();
+        table.set_preferred_row_cnt(2);
+        table.reload(false).expect("Failed to reload table");
+
+        assert_eq!(table.cell(0, 1), Some(&2));
+        assert_eq!(table.cell(1, 0), None);
+        assert!(!table.has_errors());
+    }
 }
@@ -0,0 +1,724 @@
+use crate::{DynamicTable, Table};
+use std::{cmp::Ordering, fmt, iter::Peekable, str::Chars};
+
+/// Represents an error encountered while parsing or executing a query
+#[cfg_attr(feature = "docs", doc(cfg(sql)))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SqlError {
+    /// The query is not a valid SELECT statement
+    Syntax(String),
+
+    /// The query refers to a column missing from the table's header row
+    UnknownColumn(String),
+}
+
+impl fmt::Display for SqlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Syntax(x) => write!(f, "Syntax error: {}", x),
+            Self::UnknownColumn(x) => write!(f, "Unknown column: {}", x),
+        }
+    }
+}
+
+impl std::error::Error for SqlError {}
+
+/// Represents ability to run a SELECT statement over a table whose first row
+/// holds the names of its columns
+#[cfg_attr(feature = "docs", doc(cfg(sql)))]
+pub trait QuerySql {
+    /// Parses and runs the query, returning a new table whose first row holds
+    /// the names of the selected columns
+    fn query_sql(&self, sql: &str) -> Result<DynamicTable<String>, SqlError>;
+}
+
+impl<D: AsRef<str>, T: Table<Data = D>> QuerySql for T {
+    fn query_sql(&self, sql: &str) -> Result<DynamicTable<String>, SqlError> {
+        Select::parse(sql)?.execute(self)
+    }
+}
+
+/// Represents a parsed SELECT statement supporting a projection, a WHERE
+/// clause of comparisons joined by AND, OR, and NOT, an ORDER BY clause,
+/// and a LIMIT
+///
+/// Columns are named by the first row of the table being queried, either
+/// bare or wrapped in double quotes, while text literals use single quotes.
+/// Values that both parse as numbers are compared numerically and all others
+/// are compared as text; comparisons against missing cells are never true
+#[cfg_attr(feature = "docs", doc(cfg(sql)))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Select {
+    columns: Option<Vec<String>>,
+    filter: Option<Expr>,
+    order_by: Vec<(String, bool)>,
+    limit: Option<usize>,
+}
+
+impl Select {
+    /// Parses a SELECT statement, allowing it to be run over many tables
+    pub fn parse(sql: &str) -> Result<Self, SqlError> {
+        Parser::new(sql)?.select()
+    }
+
+    /// Runs the statement over the table, whose first row holds the names of
+    /// its columns
+    pub fn execute<D: AsRef<str>, T: Table<Data = D>>(
+        &self,
+        table: &T,
+    ) -> Result<DynamicTable<String>, SqlError> {
+        let headers: Vec<&str> = (0..table.col_cnt())
+            .map(|col| table.cell(0, col).map(AsRef::as_ref).unwrap_or_default())
+            .collect();
+        let find = |name: &str| {
+            headers
+                .iter()
+                .position(|x| *x == name)
+                .or_else(|| headers.iter().position(|x| x.eq_ignore_ascii_case(name)))
+                .ok_or_else(|| SqlError::UnknownColumn(name.to_string()))
+        };
+
+        let columns = match &self.columns {
+            Some(names) => names
+                .iter()
+                .map(|x| find(x))
+                .collect::<Result<Vec<usize>, SqlError>>()?,
+            None => (0..headers.len()).collect(),
+        };
+        let filter = self.filter.as_ref().map(|x| x.resolve(&find)).transpose()?;
+        let order_by = self
+            .order_by
+            .iter()
+            .map(|(name, asc)| Ok((find(name)?, *asc)))
+            .collect::<Result<Vec<(usize, bool)>, SqlError>>()?;
+
+        let cell = |row: usize, col: usize| table.cell(row, col).map(AsRef::as_ref);
+        let mut rows: Vec<usize> = (1..table.row_cnt())
+            .filter(|row| match &filter {
+                Some(x) => x.eval(&|col| cell(*row, col)),
+                None => true,
+            })
+            .collect();
+
+        rows.sort_by(|a, b| {
+            order_by.iter().fold(Ordering::Equal, |ord, (col, asc)| {
+                ord.then_with(|| {
+                    let ord = match (cell(*a, *col), cell(*b, *col)) {
+                        (Some(x), Some(y)) => compare(x, y),
+                        (x, y) => x.is_some().cmp(&y.is_some()),
+                    };
+                    if *asc {
+                        ord
+                    } else {
+                        ord.reverse()
+                    }
+                })
+            })
+        });
+        rows.truncate(self.limit.unwrap_or(rows.len()));
+
+        let mut output = DynamicTable::new();
+        for (i, row) in Some(0).into_iter().chain(rows).enumerate() {
+            for (j, col) in columns.iter().enumerate() {
+                if let Some(x) = cell(row, *col) {
+                    output.insert_cell(i, j, x.to_string());
+                }
+            }
+        }
+
+        output.set_preferred_col_cnt(columns.len());
+        Ok(output)
+    }
+}
+
+/// Compares two values numerically if both are numbers and as text if
+/// neither is, where numbers always come before text so that the ordering is
+/// total and sorting a mix of both is consistent
+fn compare(a: &str, b: &str) -> Ordering {
+    match (a.trim().parse::<f64>(), b.trim().parse::<f64>()) {
+        (Ok(x), Ok(y)) => x.total_cmp(&y),
+        (Ok(_), Err(_)) => Ordering::Less,
+        (Err(_), Ok(_)) => Ordering::Greater,
+        (Err(_), Err(_)) => a.cmp(b),
+    }
+}
+
+/// Represents an operator comparing two operands
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum CmpOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+impl CmpOp {
+    fn test(self, ord: Ordering) -> bool {
+        match self {
+            Self::Eq => ord == Ordering::Equal,
+            Self::Ne => ord != Ordering::Equal,
+            Self::Lt => ord == Ordering::Less,
+            Self::Le => ord != Ordering::Greater,
+            Self::Gt => ord == Ordering::Greater,
+            Self::Ge => ord != Ordering::Less,
+        }
+    }
+}
+
+/// Represents one side of a comparison, being a column or a literal
+#[derive(Clone, Debug, PartialEq)]
+enum Operand<C> {
+    Column(C),
+    Literal(String),
+}
+
+/// Represents a boolean expression within a WHERE clause, whose columns are
+/// first named and later resolved to indexes
+#[derive(Clone, Debug, PartialEq)]
+enum Expr<C = String> {
+    Cmp(Operand<C>, CmpOp, Operand<C>),
+    And(Box<Expr<C>>, Box<Expr<C>>),
+    Or(Box<Expr<C>>, Box<Expr<C>>),
+    Not(Box<Expr<C>>),
+}
+
+impl Expr {
+    fn resolve<F>(&self, find: &F) -> Result<Expr<usize>, SqlError>
+    where
+        F: Fn(&str) -> Result<usize, SqlError>,
+    {
+        let operand = |x: &Operand<String>| match x {
+            Operand::Column(name) => find(name).map(Operand::Column),
+            Operand::Literal(x) => Ok(Operand::Literal(x.clone())),
+        };
+
+        Ok(match self {
+            Self::Cmp(a, op, b) => Expr::Cmp(operand(a)?, *op, operand(b)?),
+            Self::And(a, b) => Expr::And(Box::new(a.resolve(find)?), Box::new(b.resolve(find)?)),
+            Self::Or(a, b) => Expr::Or(Box::new(a.resolve(find)?), Box::new(b.resolve(find)?)),
+            Self::Not(x) => Expr::Not(Box::new(x.resolve(find)?)),
+        })
+    }
+}
+
+impl Expr<usize> {
+    fn eval<'a>(&'a self, cell: &dyn Fn(usize) -> Option<&'a str>) -> bool {
+        let operand = |x: &'a Operand<usize>| match x {
+            Operand::Column(col) => cell(*col),
+            Operand::Literal(x) => Some(x.as_str()),
+        };
+
+        match self {
+            Self::Cmp(a, op, b) => match (operand(a), operand(b)) {
+                (Some(a), Some(b)) => op.test(compare(a, b)),
+                _ => false,
+            },
+            Self::And(a, b) => a.eval(cell) && b.eval(cell),
+            Self::Or(a, b) => a.eval(cell) || b.eval(cell),
+            Self::Not(x) => !x.eval(cell),
+        }
+    }
+}
+
+/// Represents a token of a query
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Word(String),
+    Quoted(String),
+    Text(String),
+    Number(String),
+    Op(CmpOp),
+    Comma,
+    Star,
+    LParen,
+    RParen,
+}
+
+fn tokenize(sql: &str) -> Result<Vec<Token>, SqlError> {
+    fn quoted(chars: &mut Peekable<Chars<'_>>, quote: char) -> Result<String, SqlError> {
+        let mut s = String::new();
+        loop {
+            match chars.next() {
+                Some(c) if c == quote => {
+                    // A doubled quote stands for the quote itself
+                    if chars.peek() == Some(&quote) {
+                        chars.next();
+                        s.push(quote);
+                    } else {
+                        return Ok(s);
+                    }
+                }
+                Some(c) => s.push(c),
+                None => return Err(SqlError::Syntax(format!("Unterminated {}", quote))),
+            }
+        }
+    }
+
+    let mut tokens = Vec::new();
+    let mut chars = sql.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        let token = match c {
+            c if c.is_whitespace() || c == ';' => continue,
+            ',' => Token::Comma,
+            '*' => Token::Star,
+            '(' => Token::LParen,
+            ')' => Token::RParen,
+            '=' => Token::Op(CmpOp::Eq),
+            '!' if chars.peek() == Some(&'=') => {
+                chars.next();
+                Token::Op(CmpOp::Ne)
+            }
+            '<' | '>' => {
+                let op = match (c, chars.peek()) {
+                    ('<', Some('=')) => Some(CmpOp::Le),
+                    ('<', Some('>')) => Some(CmpOp::Ne),
+                    ('>', Some('=')) => Some(CmpOp::Ge),
+                    _ => None,
+                };
+                match op {
+                    Some(op) => {
+                        chars.next();
+                        Token::Op(op)
+                    }
+                    None if c == '<' => Token::Op(CmpOp::Lt),
+                    None => Token::Op(CmpOp::Gt),
+                }
+            }
+            '\'' => Token::Text(quoted(&mut chars, '\'')?),
+            '"' => Token::Quoted(quoted(&mut chars, '"')?),
+            c if c.is_ascii_digit() || c == '-' || c == '.' => {
+                let mut s = c.to_string();
+                while let Some(c) = chars.peek().filter(|c| c.is_ascii_digit() || **c == '.') {
+                    s.push(*c);
+                    chars.next();
+                }
+                Token::Number(s)
+            }
+            c if c.is_alphanumeric() || c == '_' => {
+                let mut s = c.to_string();
+                while let Some(c) = chars.peek().filter(|c| c.is_alphanumeric() || **c == '_') {
+                    s.push(*c);
+                    chars.next();
+                }
+                Token::Word(s)
+            }
+            c => return Err(SqlError::Syntax(format!("Unexpected character {:?}", c))),
+        };
+        tokens.push(token);
+    }
+
+    Ok(tokens)
+}
+
+/// Words that cannot be used as bare column names
+const KEYWORDS: &[&str] = &[
+    "SELECT", "FROM", "WHERE", "AND", "OR", "NOT", "ORDER", "BY", "ASC", "DESC", "LIMIT",
+];
+
+/// Parses a query through recursive descent over its tokens
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn new(sql: &str) -> Result<Self, SqlError> {
+        Ok(Self {
+            tokens: tokenize(sql)?,
+            pos: 0,
+        })
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn is_keyword(&self, keyword: &str) -> bool {
+        matches!(self.peek(), Some(Token::Word(x)) if x.eq_ignore_ascii_case(keyword))
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        let is_keyword = self.is_keyword(keyword);
+        if is_keyword {
+            self.pos += 1;
+        }
+        is_keyword
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> Result<(), SqlError> {
+        if self.eat_keyword(keyword) {
+            Ok(())
+        } else {
+            Err(self.unexpected(keyword))
+        }
+    }
+
+    fn eat(&mut self, token: &Token) -> bool {
+        let is_token = self.peek() == Some(token);
+        if is_token {
+            self.pos += 1;
+        }
+        is_token
+    }
+
+    fn unexpected(&self, expected: &str) -> SqlError {
+        match self.peek() {
+            Some(token) => SqlError::Syntax(format!("Expected {}, found {:?}", expected, token)),
+            None => SqlError::Syntax(format!("Expected {}, found end of query", expected)),
+        }
+    }
+
+    fn column(&mut self) -> Result<String, SqlError> {
+        match self.peek() {
+            Some(Token::Word(x)) if !KEYWORDS.iter().any(|k| x.eq_ignore_ascii_case(k)) => {}
+            Some(Token::Quoted(_)) => {}
+            _ => return Err(self.unexpected("column")),
+        }
+
+        match self.next() {
+            Some(Token::Word(x)) | Some(Token::Quoted(x)) => Ok(x),
+            _ => unreachable!(),
+        }
+    }
+
+    fn select(&mut self) -> Result<Select, SqlError> {
+        self.expect_keyword("SELECT")?;
+
+        let columns = if self.eat(&Token::Star) {
+            None
+        } else {
+            let mut columns = vec![self.column()?];
+            while self.eat(&Token::Comma) {
+                columns.push(self.column()?);
+            }
+            Some(columns)
+        };
+
+        // The table is the one being queried, so its name is only skipped
+        if self.eat_keyword("FROM") {
+            self.column()?;
+        }
+
+        let filter = if self.eat_keyword("WHERE") {
+            Some(self.or()?)
+        } else {
+            None
+        };
+
+        let mut order_by = Vec::new();
+        if self.eat_keyword("ORDER") {
+            self.expect_keyword("BY")?;
+            loop {
+                let column = self.column()?;
+                let asc = !self.eat_keyword("DESC");
+                if asc {
+                    self.eat_keyword("ASC");
+                }
+                order_by.push((column, asc));
+
+                if !self.eat(&Token::Comma) {
+                    break;
+                }
+            }
+        }
+
+        let limit = if self.eat_keyword("LIMIT") {
+            match self.next() {
+                Some(Token::Number(x)) => Some(
+                    x.parse()
+                        .map_err(|_| SqlError::Syntax(format!("Invalid limit {}", x)))?,
+                ),
+                _ => {
+                    self.pos -= 1;
+                    return Err(self.unexpected("limit"));
+                }
+            }
+        } else {
+            None
+        };
+
+        if self.peek().is_some() {
+            return Err(self.unexpected("end of query"));
+        }
+
+        Ok(Select {
+            columns,
+            filter,
+            order_by,
+            limit,
+        })
+    }
+
+    fn or(&mut self) -> Result<Expr, SqlError> {
+        let mut expr = self.and()?;
+        while self.eat_keyword("OR") {
+            expr = Expr::Or(Box::new(expr), Box::new(self.and()?));
+        }
+        Ok(expr)
+    }
+
+    fn and(&mut self) -> Result<Expr, SqlError> {
+        let mut expr = self.not()?;
+        while self.eat_keyword("AND") {
+            expr = Expr::And(Box::new(expr), Box::new(self.not()?));
+        }
+        Ok(expr)
+    }
+
+    fn not(&mut self) -> Result<Expr, SqlError> {
+        if self.eat_keyword("NOT") {
+            return Ok(Expr::Not(Box::new(self.not()?)));
+        }
+
+        if self.eat(&Token::LParen) {
+            let expr = self.or()?;
+            return if self.eat(&Token::RParen) {
+                Ok(expr)
+            } else {
+                Err(self.unexpected(")"))
+            };
+        }
+
+        let a = self.operand()?;
+        let op = match self.next() {
+            Some(Token::Op(op)) => op,
+            _ => {
+                self.pos -= 1;
+                return Err(self.unexpected("comparison"));
+            }
+        };
+        let b = self.operand()?;
+        Ok(Expr::Cmp(a, op, b))
+    }
+
+    fn operand(&mut self) -> Result<Operand<String>, SqlError> {
+        match self.peek() {
+            Some(Token::Text(_)) | Some(Token::Number(_)) => match self.next() {
+                Some(Token::Text(x)) | Some(Token::Number(x)) => Ok(Operand::Literal(x)),
+                _ => unreachable!(),
+            },
+            _ => self.column().map(Operand::Column),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_select_all_columns_and_rows() {
+        let table = DynamicTable::from([
+            ["name", "age", "city"],
+            ["alice", "30", "paris"],
+            ["bob", "9", "rome"],
+            ["carol", "41", "paris"],
+            ["dave", "25", "oslo"],
+        ])
+        .query_sql("SELECT * FROM people")
+        .unwrap();
+        assert_eq!(table.row_cnt(), 5);
+        assert_eq!(table.col_cnt(), 3);
+        assert!(table.cells().eq(DynamicTable::from([
+            ["name", "age", "city"],
+            ["alice", "30", "paris"],
+            ["bob", "9", "rome"],
+            ["carol", "41", "paris"],
+            ["dave", "25", "oslo"],
+        ])
+        .cells()));
+    }
+
+    #[test]
+    fn should_project_columns_in_given_order() {
+        let table = DynamicTable::from([
+            ["name", "age", "city"],
+            ["alice", "30", "paris"],
+            ["bob", "9", "rome"],
+            ["carol", "41", "paris"],
+            ["dave", "25", "oslo"],
+        ])
+        .query_sql("select city, NAME from t")
+        .unwrap();
+        assert_eq!(
+            table,
+            [
+                ["city", "name"],
+                ["paris", "alice"],
+                ["rome", "bob"],
+                ["paris", "carol"],
+                ["oslo", "dave"],
+            ]
+        );
+    }
+
+    #[test]
+    fn should_filter_rows_with_where_clause() {
+        let table = DynamicTable::from([
+            ["name", "age", "city"],
+            ["alice", "30", "paris"],
+            ["bob", "9", "rome"],
+            ["carol", "41", "paris"],
+            ["dave", "25", "oslo"],
+        ])
+        .query_sql("SELECT name FROM t WHERE city = 'paris' AND NOT age < 35")
+        .unwrap();
+        assert_eq!(table, [["name"], ["carol"]]);
+
+        let table = DynamicTable::from([
+            ["name", "age", "city"],
+            ["alice", "30", "paris"],
+            ["bob", "9", "rome"],
+            ["carol", "41", "paris"],
+            ["dave", "25", "oslo"],
+        ])
+        .query_sql("SELECT name WHERE (age >= 30 OR city <> 'oslo') AND name != 'alice'")
+        .unwrap();
+        assert_eq!(table, [["name"], ["bob"], ["carol"]]);
+    }
+
+    #[test]
+    fn should_compare_numbers_numerically() {
+        let table = DynamicTable::from([
+            ["name", "age", "city"],
+            ["alice", "30", "paris"],
+            ["bob", "9", "rome"],
+            ["carol", "41", "paris"],
+            ["dave", "25", "oslo"],
+        ])
+        .query_sql("SELECT name WHERE age > 10")
+        .unwrap();
+        assert_eq!(table, [["name"], ["alice"], ["carol"], ["dave"]]);
+    }
+
+    #[test]
+    fn should_order_numbers_before_text() {
+        let table = DynamicTable::from([["x"], ["b"], ["10"], ["NaN"], ["a"], ["-inf"], ["9"]]);
+
+        let table = table.query_sql("SELECT x ORDER BY x").unwrap();
+        assert_eq!(
+            table,
+            [["x"], ["-inf"], ["9"], ["10"], ["NaN"], ["a"], ["b"]]
+        );
+
+        let table = DynamicTable::from([["x"], ["a"], ["5"]]);
+        let table = table.query_sql("SELECT x WHERE x < 'a'").unwrap();
+        assert_eq!(table, [["x"], ["5"]]);
+    }
+
+    #[test]
+    fn should_order_and_limit_rows() {
+        let table = DynamicTable::from([
+            ["name", "age", "city"],
+            ["alice", "30", "paris"],
+            ["bob", "9", "rome"],
+            ["carol", "41", "paris"],
+            ["dave", "25", "oslo"],
+        ])
+        .query_sql("SELECT name, age FROM t ORDER BY city DESC, age LIMIT 3")
+        .unwrap();
+        assert_eq!(
+            table,
+            [
+                ["name", "age"],
+                ["bob", "9"],
+                ["alice", "30"],
+                ["carol", "41"]
+            ]
+        );
+    }
+
+    #[test]
+    fn should_support_quoted_columns_and_text() {
+        let table = DynamicTable::from([["first name", "quote"], ["al", "it's"]]);
+        let table = table
+            .query_sql(r#"SELECT "first name" WHERE quote = 'it''s'"#)
+            .unwrap();
+        assert_eq!(table, [["first name"], ["al"]]);
+    }
+
+    #[test]
+    fn should_never_match_missing_cells() {
+        let mut table = DynamicTable::from([
+            ["name", "age", "city"],
+            ["alice", "30", "paris"],
+            ["bob", "9", "rome"],
+            ["carol", "41", "paris"],
+            ["dave", "25", "oslo"],
+        ]);
+        table.remove_cell(2, 2);
+
+        let table = table
+            .query_sql("SELECT name WHERE city != 'paris'")
+            .unwrap();
+        assert_eq!(table, [["name"], ["dave"]]);
+    }
+
+    #[test]
+    fn should_fail_if_column_is_unknown() {
+        assert_eq!(
+            DynamicTable::from([
+                ["name", "age", "city"],
+                ["alice", "30", "paris"],
+                ["bob", "9", "rome"],
+                ["carol", "41", "paris"],
+                ["dave", "25", "oslo"],
+            ])
+            .query_sql("SELECT name WHERE height > 3"),
+            Err(SqlError::UnknownColumn(String::from("height")))
+        );
+    }
+
+    #[test]
+    fn should_fail_if_query_is_invalid() {
+        let table = DynamicTable::from([
+            ["name", "age", "city"],
+            ["alice", "30", "paris"],
+            ["bob", "9", "rome"],
+            ["carol", "41", "paris"],
+            ["dave", "25", "oslo"],
+        ]);
+        assert!(matches!(
+            table.query_sql("SELECT"),
+            Err(SqlError::Syntax(_))
+        ));
+        assert!(matches!(
+            table.query_sql("SELECT name WHERE age >"),
+            Err(SqlError::Syntax(_))
+        ));
+        assert!(matches!(
+            table.query_sql("SELECT name LIMIT ten"),
+            Err(SqlError::Syntax(_))
+        ));
+        assert!(matches!(
+            table.query_sql("SELECT name WHERE name = 'bob"),
+            Err(SqlError::Syntax(_))
+        ));
+    }
+
+    #[test]
+    fn parsed_select_should_be_reusable() {
+        let select = Select::parse("SELECT name ORDER BY age LIMIT 1").unwrap();
+        assert_eq!(
+            select
+                .execute(&DynamicTable::from([
+                    ["name", "age", "city"],
+                    ["alice", "30", "paris"],
+                    ["bob", "9", "rome"],
+                    ["carol", "41", "paris"],
+                    ["dave", "25", "oslo"],
+                ]))
+                .unwrap(),
+            [["name"], ["bob"]]
+        );
+    }
+}
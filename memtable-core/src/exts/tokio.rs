@@ -0,0 +1,209 @@
+use crate::Table;
+use ::tokio::sync::{mpsc, oneshot};
+use std::{error, fmt};
+
+/// Total commands that can be queued for the table's task before senders
+/// have to wait for the task to catch up
+const DEFAULT_BUFFER: usize = 32;
+
+/// Represents some command that operates on the table owned by the task
+type Command<T> = Box<dyn FnOnce(&mut T) + Send>;
+
+/// Represents the error returned when the task owning the table is no longer
+/// running, meaning that the command could not be processed
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "docs", doc(cfg(tokio)))]
+pub struct TableClosed;
+
+impl fmt::Display for TableClosed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Table task is no longer running")
+    }
+}
+
+impl error::Error for TableClosed {}
+
+/// Represents a cheaply-cloneable handle to a table that is owned by a
+/// dedicated tokio task, which processes commands sent over a channel one
+/// at a time; this allows sharing a table across async tasks without locks
+#[cfg_attr(feature = "docs", doc(cfg(tokio)))]
+pub struct TableHandle<T: Table> {
+    tx: mpsc::Sender<Command<T>>,
+}
+
+impl<T> TableHandle<T>
+where
+    T: Table + Send + 'static,
+    T::Data: Send + 'static,
+{
+    /// Spawns a new task on the current tokio runtime that takes ownership of
+    /// the table, returning a handle to communicate with it
+    ///
+    /// The task runs until every handle has been dropped
+    pub fn spawn(table: T) -> Self {
+        Self::spawn_with_buffer(table, DEFAULT_BUFFER)
+    }
+
+    /// Like [`TableHandle::spawn`], but with an explicit limit on the commands
+    /// that can be queued before senders wait
+    pub fn spawn_with_buffer(mut table: T, buffer: usize) -> Self {
+        let (tx, mut rx) = mpsc::channel::<Command<T>>(buffer);
+
+        ::tokio::spawn(async move {
+            while let Some(cmd) = rx.recv().await {
+                cmd(&mut table);
+            }
+        });
+
+        Self { tx }
+    }
+
+    /// Runs the function against the table within the table's task, returning
+    /// the result
+    pub async fn query<R, F>(&self, f: F) -> Result<R, TableClosed>
+    where
+        R: Send + 'static,
+        F: FnOnce(&T) -> R + Send + 'static,
+    {
+        self.update(move |table| f(table)).await
+    }
+
+    /// Runs the function against the table within the table's task, allowing
+    /// the table to be modified, and returns the result
+    pub async fn update<R, F>(&self, f: F) -> Result<R, TableClosed>
+    where
+        R: Send + 'static,
+        F: FnOnce(&mut T) -> R + Send + 'static,
+    {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        let cmd: Command<T> = Box::new(move |table| {
+            // If the caller stopped waiting, there is nobody to report to
+            let _ = reply_tx.send(f(table));
+        });
+
+        self.tx.send(cmd).await.map_err(|_| TableClosed)?;
+        reply_rx.await.map_err(|_| TableClosed)
+    }
+
+    /// Retrieves a copy of the cell at the specified row & column
+    pub async fn get(&self, row: usize, col: usize) -> Result<Option<T::Data>, TableClosed>
+    where
+        T::Data: Clone,
+    {
+        self.query(move |table| table.cell(row, col).cloned()).await
+    }
+
+    /// Inserts a cell at the specified row & column, returning the cell that
+    /// was replaced
+    pub async fn insert(
+        &self,
+        row: usize,
+        col: usize,
+        value: T::Data,
+    ) -> Result<Option<T::Data>, TableClosed> {
+        self.update(move |table| table.insert_cell(row, col, value))
+            .await
+    }
+
+    /// Pushes a row to the end of the table, returning the index of the new row
+    pub async fn push_row<I>(&self, cells: I) -> Result<usize, TableClosed>
+    where
+        I: IntoIterator<Item = T::Data> + Send + 'static,
+    {
+        self.update(move |table| {
+            let row = table.row_cnt();
+            table.push_row(cells);
+            row
+        })
+        .await
+    }
+
+    /// Returns the total rows contained in the table
+    pub async fn row_cnt(&self) -> Result<usize, TableClosed> {
+        self.query(|table| table.row_cnt()).await
+    }
+
+    /// Returns the total columns contained in the table
+    pub async fn col_cnt(&self) -> Result<usize, TableClosed> {
+        self.query(|table| table.col_cnt()).await
+    }
+
+    /// Returns true if the task owning the table is no longer running
+    pub fn is_closed(&self) -> bool {
+        self.tx.is_closed()
+    }
+}
+
+impl<T: Table> Clone for TableHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            tx: self.tx.clone(),
+        }
+    }
+}
+
+impl<T: Table> fmt::Debug for TableHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TableHandle")
+            .field("closed", &self.tx.is_closed())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DynamicTable;
+
+    #[::tokio::test]
+    async fn insert_and_get_should_operate_on_owned_table() {
+        let handle = TableHandle::spawn(DynamicTable::new());
+
+        assert_eq!(handle.insert(0, 0, 5).await, Ok(None));
+        assert_eq!(handle.insert(0, 0, 6).await, Ok(Some(5)));
+        assert_eq!(handle.get(0, 0).await, Ok(Some(6)));
+        assert_eq!(handle.get(1, 1).await, Ok(None));
+    }
+
+    #[::tokio::test]
+    async fn push_row_should_return_index_of_new_row() {
+        let handle = TableHandle::spawn(DynamicTable::new());
+
+        assert_eq!(handle.push_row(vec![1, 2, 3]).await, Ok(0));
+        assert_eq!(handle.push_row(vec![4, 5, 6]).await, Ok(1));
+        assert_eq!(handle.row_cnt().await, Ok(2));
+        assert_eq!(handle.col_cnt().await, Ok(3));
+    }
+
+    #[::tokio::test]
+    async fn query_should_return_result_of_function() {
+        let handle = TableHandle::spawn(DynamicTable::from([[1, 2], [3, 4]]));
+
+        let sum = handle.query(|table| table.cells().sum::<usize>()).await;
+        assert_eq!(sum, Ok(10));
+    }
+
+    #[::tokio::test]
+    async fn clones_should_share_the_same_table() {
+        let handle = TableHandle::spawn(DynamicTable::new());
+        let other = handle.clone();
+
+        let task = ::tokio::spawn(async move { other.insert(0, 0, "hello").await });
+        task.await.unwrap().unwrap();
+
+        assert_eq!(handle.get(0, 0).await, Ok(Some("hello")));
+    }
+
+    #[::tokio::test]
+    async fn update_should_fail_if_task_has_stopped() {
+        let handle = TableHandle::spawn(DynamicTable::<usize>::new());
+
+        // Panicking within a command brings down the task that owns the table
+        let _ = handle
+            .update::<(), _>(|_| panic!("intentional failure"))
+            .await;
+
+        assert_eq!(handle.row_cnt().await, Err(TableClosed));
+        assert!(handle.is_closed());
+    }
+}
@@ -0,0 +1,178 @@
+use crate::{Position, Table};
+use std::{collections::HashMap, fmt, string::ToString};
+
+/// Represents a grid model backed by some table, suitable for driving widgets
+/// like egui's table or tui-rs's `Table` that repeatedly ask for the row
+/// count, column count, and the text of individual cells
+///
+/// Cell text is rendered once and cached until the underlying table is
+/// accessed mutably through [`TableModel::table_mut`] or the cache is
+/// explicitly invalidated
+#[cfg_attr(feature = "docs", doc(cfg(ui)))]
+pub struct TableModel<T: Table, F = fn(&<T as Table>::Data) -> String> {
+    table: T,
+    cache: HashMap<Position, String>,
+    to_text: F,
+}
+
+impl<T: Table> TableModel<T>
+where
+    T::Data: fmt::Display,
+{
+    /// Creates a new model around the table that renders each cell using
+    /// its [`fmt::Display`] implementation
+    pub fn new(table: T) -> Self {
+        Self::with_formatter(table, ToString::to_string)
+    }
+}
+
+impl<T: Table, F: Fn(&T::Data) -> String> TableModel<T, F> {
+    /// Creates a new model around the table that renders each cell using the
+    /// provided function
+    pub fn with_formatter(table: T, f: F) -> Self {
+        Self {
+            table,
+            cache: HashMap::new(),
+            to_text: f,
+        }
+    }
+
+    /// Returns a reference to the underlying table
+    pub fn table(&self) -> &T {
+        &self.table
+    }
+
+    /// Returns a mutable reference to the underlying table, clearing all
+    /// cached cell text as the table may change
+    pub fn table_mut(&mut self) -> &mut T {
+        self.invalidate();
+        &mut self.table
+    }
+
+    /// Consumes the model, returning the underlying table
+    pub fn into_inner(self) -> T {
+        self.table
+    }
+
+    /// Returns the total rows to display
+    pub fn row_count(&self) -> usize {
+        self.table.row_cnt()
+    }
+
+    /// Returns the total columns to display
+    pub fn column_count(&self) -> usize {
+        self.table.col_cnt()
+    }
+
+    /// Returns the text to display for the cell at the given row & column,
+    /// rendering and caching it if not already cached; an empty string is
+    /// returned if the cell does not exist
+    pub fn cell_text(&mut self, row: usize, col: usize) -> &str {
+        let table = &self.table;
+        let to_text = &self.to_text;
+
+        self.cache
+            .entry(Position { row, col })
+            .or_insert_with(|| table.cell(row, col).map(to_text).unwrap_or_default())
+    }
+
+    /// Returns the text to display for each cell in the given row
+    pub fn row_text(&mut self, row: usize) -> Vec<&str> {
+        let col_cnt = self.column_count();
+
+        // Make sure that everything in the row is cached first so we can
+        // hand out immutable references to all of them at once
+        for col in 0..col_cnt {
+            self.cell_text(row, col);
+        }
+
+        let cache = &self.cache;
+        (0..col_cnt)
+            .map(|col| cache[&Position { row, col }].as_str())
+            .collect()
+    }
+
+    /// Clears the cached text of the cell at the given row & column
+    pub fn invalidate_cell(&mut self, row: usize, col: usize) {
+        self.cache.remove(&Position { row, col });
+    }
+
+    /// Clears all cached cell text
+    pub fn invalidate(&mut self) {
+        self.cache.clear();
+    }
+}
+
+impl<T: Table + fmt::Debug, F> fmt::Debug for TableModel<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TableModel")
+            .field("table", &self.table)
+            .field("cache", &self.cache)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DynamicTable;
+
+    #[test]
+    fn row_and_column_count_should_match_table() {
+        let model = TableModel::new(DynamicTable::from([[1, 2, 3], [4, 5, 6]]));
+        assert_eq!(model.row_count(), 2);
+        assert_eq!(model.column_count(), 3);
+    }
+
+    #[test]
+    fn cell_text_should_render_cell_using_display() {
+        let mut model = TableModel::new(DynamicTable::from([[1, 2], [3, 4]]));
+        assert_eq!(model.cell_text(1, 0), "3");
+    }
+
+    #[test]
+    fn cell_text_should_return_empty_string_if_cell_missing() {
+        let mut table = DynamicTable::new();
+        table.insert_cell(1, 1, 5);
+
+        let mut model = TableModel::new(table);
+        assert_eq!(model.cell_text(0, 0), "");
+    }
+
+    #[test]
+    fn cell_text_should_use_custom_formatter_if_provided() {
+        let mut model =
+            TableModel::with_formatter(DynamicTable::from([[1, 2]]), |x| format!("#{}", x));
+        assert_eq!(model.cell_text(0, 1), "#2");
+    }
+
+    #[test]
+    fn row_text_should_return_text_of_each_cell_in_row() {
+        let mut model = TableModel::new(DynamicTable::from([[1, 2], [3, 4]]));
+        assert_eq!(model.row_text(1), vec!["3", "4"]);
+    }
+
+    #[test]
+    fn cell_text_should_be_cached_until_invalidated() {
+        let mut model = TableModel::new(DynamicTable::from([[1, 2]]));
+        assert_eq!(model.cell_text(0, 0), "1");
+
+        // Bypass the model's invalidation so we can observe the cache
+        model
+            .cache
+            .insert(Position { row: 0, col: 0 }, String::from("cached"));
+        assert_eq!(model.cell_text(0, 0), "cached");
+
+        model.invalidate_cell(0, 0);
+        assert_eq!(model.cell_text(0, 0), "1");
+    }
+
+    #[test]
+    fn table_mut_should_clear_cache() {
+        let mut model = TableModel::new(DynamicTable::from([[1, 2]]));
+        assert_eq!(model.cell_text(0, 0), "1");
+
+        model.table_mut().insert_cell(0, 0, 10);
+        assert_eq!(model.cell_text(0, 0), "10");
+    }
+}
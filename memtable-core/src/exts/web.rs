@@ -0,0 +1,798 @@
+use crate::{Capacity, Table};
+use serde::{de::DeserializeOwned, Serialize};
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    fmt,
+};
+#[cfg(feature = "web-indexeddb")]
+use {
+    std::{
+        future::Future,
+        pin::Pin,
+        rc::Rc,
+        task::{Context, Poll, Waker},
+    },
+    wasm_bindgen::{closure::Closure, JsCast, JsValue},
+};
+
+/// Total errors to keep around, dropping older ones after reaching limit
+const ERROR_BUFFER_SIZE: usize = 10;
+
+/// Represents a synchronous string-based key-value store such as the
+/// browser's `localStorage` and `sessionStorage`
+///
+/// The methods mirror those of `web_sys::Storage`, so a browser store only
+/// needs a small wrapper to be used, while [`IndexedDbStorage`] covers
+/// IndexedDB when the **web-indexeddb** feature is enabled:
+///
+/// ```ignore
+/// struct LocalStorage(web_sys::Storage);
+///
+/// impl WebStorage for LocalStorage {
+///     fn get_item(&self, key: &str) -> Result<Option<String>, String> {
+///         self.0.get_item(key).map_err(|x| format!("{:?}", x))
+///     }
+///
+///     fn set_item(&self, key: &str, value: &str) -> Result<(), String> {
+///         self.0.set_item(key, value).map_err(|x| format!("{:?}", x))
+///     }
+///
+///     fn remove_item(&self, key: &str) -> Result<(), String> {
+///         self.0.remove_item(key).map_err(|x| format!("{:?}", x))
+///     }
+///
+///     fn keys(&self) -> Result<Vec<String>, String> {
+///         let len = self.0.length().map_err(|x| format!("{:?}", x))?;
+///         Ok((0..len).filter_map(|i| self.0.key(i).ok().flatten()).collect())
+///     }
+/// }
+/// ```
+pub trait WebStorage {
+    /// Returns the value stored under the key, if there is one
+    fn get_item(&self, key: &str) -> Result<Option<String>, String>;
+
+    /// Stores the value under the key, replacing any existing value
+    fn set_item(&self, key: &str, value: &str) -> Result<(), String>;
+
+    /// Removes the value stored under the key, if there is one
+    fn remove_item(&self, key: &str) -> Result<(), String>;
+
+    /// Returns every key currently held in the storage
+    fn keys(&self) -> Result<Vec<String>, String>;
+}
+
+/// Represents a [`WebStorage`] kept entirely in memory, which is useful for
+/// tests and for running the same code outside of a browser
+#[derive(Clone, Debug, Default)]
+pub struct MemoryStorage(RefCell<HashMap<String, String>>);
+
+impl MemoryStorage {
+    /// Creates a new, empty storage
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the total items held in the storage
+    pub fn len(&self) -> usize {
+        self.0.borrow().len()
+    }
+
+    /// Returns true if the storage holds no items
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl WebStorage for MemoryStorage {
+    fn get_item(&self, key: &str) -> Result<Option<String>, String> {
+        Ok(self.0.borrow().get(key).cloned())
+    }
+
+    fn set_item(&self, key: &str, value: &str) -> Result<(), String> {
+        self.0
+            .borrow_mut()
+            .insert(key.to_string(), value.to_string());
+        Ok(())
+    }
+
+    fn remove_item(&self, key: &str) -> Result<(), String> {
+        self.0.borrow_mut().remove(key);
+        Ok(())
+    }
+
+    fn keys(&self) -> Result<Vec<String>, String> {
+        Ok(self.0.borrow().keys().cloned().collect())
+    }
+}
+
+impl<S: WebStorage> WebStorage for &S {
+    fn get_item(&self, key: &str) -> Result<Option<String>, String> {
+        (**self).get_item(key)
+    }
+
+    fn set_item(&self, key: &str, value: &str) -> Result<(), String> {
+        (**self).set_item(key, value)
+    }
+
+    fn remove_item(&self, key: &str) -> Result<(), String> {
+        (**self).remove_item(key)
+    }
+
+    fn keys(&self) -> Result<Vec<String>, String> {
+        (**self).keys()
+    }
+}
+
+/// Name of the object store within the database opened by
+/// [`IndexedDbStorage::open`] that holds every item
+#[cfg(feature = "web-indexeddb")]
+const INDEXED_DB_STORE: &str = "memtable";
+
+/// Represents a [`WebStorage`] backed by an IndexedDB database, which is able
+/// to hold far more data than `localStorage`
+///
+/// IndexedDB is asynchronous, so every item is loaded into memory when the
+/// storage is opened and read from there; writes update memory right away
+/// and are sent to the database in the background, where any failure is
+/// recorded and made available through [`IndexedDbStorage::take_errors`]
+#[cfg(feature = "web-indexeddb")]
+#[cfg_attr(feature = "docs", doc(cfg(web_indexeddb)))]
+pub struct IndexedDbStorage {
+    db: web_sys::IdbDatabase,
+    items: RefCell<HashMap<String, String>>,
+    errors: Rc<RefCell<Vec<String>>>,
+    on_error: Closure<dyn FnMut(web_sys::Event)>,
+}
+
+#[cfg(feature = "web-indexeddb")]
+impl IndexedDbStorage {
+    /// Opens (creating if needed) the database with the given name, loading
+    /// all of its items into memory
+    pub async fn open(name: &str) -> Result<Self, String> {
+        let factory = web_sys::window()
+            .ok_or_else(|| String::from("No window available"))?
+            .indexed_db()
+            .map_err(js_error)?
+            .ok_or_else(|| String::from("IndexedDB is not available"))?;
+
+        // Kept alive until the database opens, as the upgrade happens first
+        let on_upgrade = Closure::once(|event: web_sys::Event| {
+            let db = event
+                .target()
+                .and_then(|x| x.dyn_into::<web_sys::IdbOpenDbRequest>().ok())
+                .and_then(|x| x.result().ok())
+                .and_then(|x| x.dyn_into::<web_sys::IdbDatabase>().ok());
+            if let Some(db) = db {
+                let _ = db.create_object_store(INDEXED_DB_STORE);
+            }
+        });
+
+        let request = factory.open_with_u32(name, 1).map_err(js_error)?;
+        request.set_onupgradeneeded(Some(on_upgrade.as_ref().unchecked_ref()));
+        let db: web_sys::IdbDatabase = RequestFuture::new(&request)
+            .await?
+            .dyn_into()
+            .map_err(js_error)?;
+        drop(on_upgrade);
+
+        // Both requests are issued before awaiting either so that they share
+        // the transaction, which commits once it has nothing left to do
+        let store = db
+            .transaction_with_str(INDEXED_DB_STORE)
+            .and_then(|tx| tx.object_store(INDEXED_DB_STORE))
+            .map_err(js_error)?;
+        let keys = RequestFuture::new(&store.get_all_keys().map_err(js_error)?);
+        let values = RequestFuture::new(&store.get_all().map_err(js_error)?);
+        let keys = js_sys::Array::from(&keys.await?);
+        let values = js_sys::Array::from(&values.await?);
+
+        let items = keys
+            .iter()
+            .zip(values.iter())
+            .filter_map(|(key, value)| Some((key.as_string()?, value.as_string()?)))
+            .collect();
+
+        let errors = Rc::new(RefCell::new(Vec::new()));
+        let on_error = {
+            let errors = Rc::clone(&errors);
+            Closure::wrap(Box::new(move |event: web_sys::Event| {
+                let message = event
+                    .target()
+                    .and_then(|x| x.dyn_into::<web_sys::IdbRequest>().ok())
+                    .and_then(|x| x.error().ok().flatten())
+                    .map(|x| x.message())
+                    .unwrap_or_else(|| String::from("Request failed"));
+
+                let mut errors = errors.borrow_mut();
+                errors.push(message);
+                if errors.len() > ERROR_BUFFER_SIZE {
+                    let extra = errors.len() - ERROR_BUFFER_SIZE;
+                    drop(errors.drain(0..extra));
+                }
+            }) as Box<dyn FnMut(web_sys::Event)>)
+        };
+
+        Ok(Self {
+            db,
+            items: RefCell::new(items),
+            errors,
+            on_error,
+        })
+    }
+
+    /// Returns true if writes sent to the database have failed since errors
+    /// were last taken
+    pub fn has_errors(&self) -> bool {
+        !self.errors.borrow().is_empty()
+    }
+
+    /// Removes the errors reported by failed writes and returns them
+    pub fn take_errors(&self) -> Vec<String> {
+        self.errors.borrow_mut().drain(..).collect()
+    }
+
+    /// Sends a write to the database within its own transaction, recording
+    /// the error if the write later fails
+    fn write(
+        &self,
+        f: impl FnOnce(&web_sys::IdbObjectStore) -> Result<web_sys::IdbRequest, JsValue>,
+    ) -> Result<(), String> {
+        let mode = web_sys::IdbTransactionMode::Readwrite;
+        let request = self
+            .db
+            .transaction_with_str_and_mode(INDEXED_DB_STORE, mode)
+            .and_then(|tx| tx.object_store(INDEXED_DB_STORE))
+            .and_then(|store| f(&store))
+            .map_err(js_error)?;
+        request.set_onerror(Some(self.on_error.as_ref().unchecked_ref()));
+        Ok(())
+    }
+}
+
+#[cfg(feature = "web-indexeddb")]
+impl fmt::Debug for IndexedDbStorage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IndexedDbStorage")
+            .field("db", &self.db.name())
+            .field("items", &self.items)
+            .field("errors", &self.errors)
+            .finish()
+    }
+}
+
+#[cfg(feature = "web-indexeddb")]
+impl WebStorage for IndexedDbStorage {
+    fn get_item(&self, key: &str) -> Result<Option<String>, String> {
+        Ok(self.items.borrow().get(key).cloned())
+    }
+
+    fn set_item(&self, key: &str, value: &str) -> Result<(), String> {
+        let (key_js, value_js) = (JsValue::from_str(key), JsValue::from_str(value));
+        self.write(|store| store.put_with_key(&value_js, &key_js))?;
+        self.items
+            .borrow_mut()
+            .insert(key.to_string(), value.to_string());
+        Ok(())
+    }
+
+    fn remove_item(&self, key: &str) -> Result<(), String> {
+        self.write(|store| store.delete(&JsValue::from_str(key)))?;
+        self.items.borrow_mut().remove(key);
+        Ok(())
+    }
+
+    fn keys(&self) -> Result<Vec<String>, String> {
+        Ok(self.items.borrow().keys().cloned().collect())
+    }
+}
+
+/// Represents a future that resolves with the result of an IndexedDB request
+/// once it succeeds, or with its error once it fails
+#[cfg(feature = "web-indexeddb")]
+struct RequestFuture {
+    request: web_sys::IdbRequest,
+    state: Rc<RefCell<RequestState>>,
+    _on_done: Closure<dyn FnMut(web_sys::Event)>,
+}
+
+#[cfg(feature = "web-indexeddb")]
+#[derive(Default)]
+struct RequestState {
+    done: bool,
+    waker: Option<Waker>,
+}
+
+#[cfg(feature = "web-indexeddb")]
+impl RequestFuture {
+    fn new(request: &web_sys::IdbRequest) -> Self {
+        let state = Rc::new(RefCell::new(RequestState::default()));
+        let on_done = {
+            let state = Rc::clone(&state);
+            Closure::wrap(Box::new(move |_: web_sys::Event| {
+                let mut state = state.borrow_mut();
+                state.done = true;
+                if let Some(waker) = state.waker.take() {
+                    waker.wake();
+                }
+            }) as Box<dyn FnMut(web_sys::Event)>)
+        };
+
+        request.set_onsuccess(Some(on_done.as_ref().unchecked_ref()));
+        request.set_onerror(Some(on_done.as_ref().unchecked_ref()));
+
+        Self {
+            request: request.clone(),
+            state,
+            _on_done: on_done,
+        }
+    }
+}
+
+#[cfg(feature = "web-indexeddb")]
+impl Future for RequestFuture {
+    type Output = Result<JsValue, String>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.borrow_mut();
+        if !state.done {
+            state.waker = Some(cx.waker().clone());
+            return Poll::Pending;
+        }
+        drop(state);
+
+        match self.request.error() {
+            Ok(Some(x)) => Poll::Ready(Err(x.message())),
+            _ => Poll::Ready(self.request.result().map_err(js_error)),
+        }
+    }
+}
+
+#[cfg(feature = "web-indexeddb")]
+impl Drop for RequestFuture {
+    fn drop(&mut self) {
+        // The handler is about to be freed, so it must no longer be called
+        self.request.set_onsuccess(None);
+        self.request.set_onerror(None);
+    }
+}
+
+#[cfg(feature = "web-indexeddb")]
+fn js_error(x: JsValue) -> String {
+    format!("{:?}", x)
+}
+
+/// Represents an error encountered while persisting a [`WebTable`]
+#[derive(Debug)]
+pub enum WebError {
+    /// A value could not be converted to json
+    FailedToSerialize(serde_json::Error),
+
+    /// A value read from storage was not valid json for its type
+    FailedToDeserialize(serde_json::Error),
+
+    /// The storage itself failed, such as by exceeding its quota
+    Storage(String),
+}
+
+impl fmt::Display for WebError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::FailedToSerialize(x) => write!(f, "Failed to serialize: {}", x),
+            Self::FailedToDeserialize(x) => write!(f, "Failed to deserialize: {}", x),
+            Self::Storage(x) => write!(f, "Storage failed: {}", x),
+        }
+    }
+}
+
+impl std::error::Error for WebError {}
+
+/// Represents a table that is replicated into a [`WebStorage`] such as the
+/// browser's `localStorage`, allowing its data to survive page reloads
+///
+/// Each cell is stored as json under its own key beneath the table's prefix,
+/// so multiple tables can share the same storage
+#[derive(Debug)]
+#[cfg_attr(feature = "docs", doc(cfg(web)))]
+pub struct WebTable<T: Table, S: WebStorage> {
+    storage: S,
+    prefix: String,
+    table: T,
+    errors: Vec<WebError>,
+}
+
+impl<T, S> WebTable<T, S>
+where
+    T: Table,
+    T::Data: Serialize + DeserializeOwned,
+    S: WebStorage,
+{
+    /// Creates a new web table using the provided storage, key prefix, and
+    /// factory function to create the inmemory table that takes in the
+    /// current row and column capacities
+    pub fn new(
+        storage: S,
+        prefix: impl Into<String>,
+        new_table: impl FnOnce(usize, usize) -> T,
+    ) -> Result<Self, WebError> {
+        let prefix = prefix.into();
+        let (row_cnt, col_cnt) = load_cnts(&storage, &prefix)?;
+
+        let mut table = new_table(row_cnt, col_cnt);
+        table.set_preferred_row_cnt(row_cnt);
+        table.set_preferred_col_cnt(col_cnt);
+
+        let mut this = Self {
+            storage,
+            prefix,
+            table,
+            errors: Vec::new(),
+        };
+
+        this.reload(false)?;
+        Ok(this)
+    }
+
+    /// Returns a reference to the storage backing the table
+    pub fn storage(&self) -> &S {
+        &self.storage
+    }
+
+    /// Reloads the data in the table from storage, optionally refreshing the
+    /// row and column capacities first
+    pub fn reload(&mut self, refresh_capacities: bool) -> Result<(), WebError> {
+        let (row_cnt, col_cnt) = if refresh_capacities {
+            let (row_cnt, col_cnt) = load_cnts(&self.storage, &self.prefix)?;
+            self.table.set_preferred_row_cnt(row_cnt);
+            self.table.set_preferred_col_cnt(col_cnt);
+            (row_cnt, col_cnt)
+        } else {
+            (self.row_cnt(), self.col_cnt())
+        };
+
+        for row in 0..row_cnt {
+            for col in 0..col_cnt {
+                let key = cell_key(&self.prefix, row, col);
+                if let Some(value) = load_value(&self.storage, &key)? {
+                    self.table.insert_cell(row, col, value);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns true if this table has uncleared errors
+    pub fn has_errors(&self) -> bool {
+        !self.errors.is_empty()
+    }
+
+    /// Removes errors in table without returning them
+    pub fn clear_errors(&mut self) {
+        self.errors.clear();
+    }
+
+    /// Removes errors in table and returns them
+    pub fn take_errors(&mut self) -> Vec<WebError> {
+        self.errors.drain(..).collect()
+    }
+
+    /// Adds a new error to the end of the queue, removing LRU errors until
+    /// error buffer is at or under max capacity
+    fn push_error(&mut self, e: WebError) {
+        self.errors.push(e);
+
+        if self.errors.len() > ERROR_BUFFER_SIZE {
+            let extra = self.errors.len() - ERROR_BUFFER_SIZE;
+            drop(self.errors.drain(0..extra));
+        }
+    }
+
+    /// Flushes the table to storage, optionally rewriting the entire table,
+    /// and returns the total items written
+    ///
+    /// As changes are written to storage as they are made, this only writes
+    /// anything when rewriting, which repairs storage after earlier errors
+    /// and removes any other keys beneath the table's prefix, such as cells
+    /// whose removal failed to reach storage
+    pub fn flush(&mut self, rewrite: bool) -> Result<usize, WebError> {
+        if !rewrite {
+            return Ok(0);
+        }
+
+        let row_cnt = self.table.row_cnt();
+        let col_cnt = self.table.col_cnt();
+        store_cnts(&self.storage, &self.prefix, row_cnt, col_cnt)?;
+
+        let mut written = HashSet::new();
+        written.insert(row_cnt_key(&self.prefix));
+        written.insert(col_cnt_key(&self.prefix));
+
+        for row in 0..row_cnt {
+            for col in 0..col_cnt {
+                if let Some(cell) = self.table.cell(row, col) {
+                    let key = cell_key(&self.prefix, row, col);
+                    store_value(&self.storage, &key, cell)?;
+                    written.insert(key);
+                }
+            }
+        }
+
+        let prefix = format!("{}/", self.prefix);
+        for key in self.storage.keys().map_err(WebError::Storage)? {
+            if key.starts_with(&prefix) && !written.contains(&key) {
+                self.storage.remove_item(&key).map_err(WebError::Storage)?;
+            }
+        }
+
+        Ok(written.len())
+    }
+
+    /// Writes the current row and column counts to storage, recording any
+    /// error encountered
+    fn store_cnts(&mut self) {
+        if let Err(x) = store_cnts(
+            &self.storage,
+            &self.prefix,
+            self.table.row_cnt(),
+            self.table.col_cnt(),
+        ) {
+            self.push_error(x);
+        }
+    }
+}
+
+impl<T, S> Table for WebTable<T, S>
+where
+    T: Table,
+    T::Data: Serialize + DeserializeOwned,
+    S: WebStorage,
+{
+    type Data = T::Data;
+    type Row = T::Row;
+    type Column = T::Column;
+
+    fn max_row_capacity(&self) -> Capacity {
+        self.table.max_row_capacity()
+    }
+
+    fn max_column_capacity(&self) -> Capacity {
+        self.table.max_column_capacity()
+    }
+
+    fn row_cnt(&self) -> usize {
+        self.table.row_cnt()
+    }
+
+    fn col_cnt(&self) -> usize {
+        self.table.col_cnt()
+    }
+
+    fn cell(&self, row: usize, col: usize) -> Option<&Self::Data> {
+        self.table.cell(row, col)
+    }
+
+    /// Returns mutable reference to the cell; changes made through it are
+    /// only written to storage by [`WebTable::flush`] with a rewrite
+    fn mut_cell(&mut self, row: usize, col: usize) -> Option<&mut Self::Data> {
+        self.table.mut_cell(row, col)
+    }
+
+    /// Will insert the data into the cell, write it to storage, and update
+    /// the row and column counts within storage
+    fn insert_cell(&mut self, row: usize, col: usize, value: Self::Data) -> Option<Self::Data> {
+        if let Err(x) = store_value(&self.storage, &cell_key(&self.prefix, row, col), &value) {
+            self.push_error(x);
+        }
+
+        let value = self.table.insert_cell(row, col, value);
+        self.store_cnts();
+        value
+    }
+
+    /// Will remove the data from the cell, remove it from storage, and
+    /// update the row and column counts within storage
+    fn remove_cell(&mut self, row: usize, col: usize) -> Option<Self::Data> {
+        if let Err(x) = self
+            .storage
+            .remove_item(&cell_key(&self.prefix, row, col))
+            .map_err(WebError::Storage)
+        {
+            self.push_error(x);
+        }
+
+        let value = self.table.remove_cell(row, col);
+        self.store_cnts();
+        value
+    }
+
+    /// Will set the row capacity of the inner table and write it to storage
+    fn set_preferred_row_cnt(&mut self, cnt: usize) {
+        self.table.set_preferred_row_cnt(cnt);
+        self.store_cnts();
+    }
+
+    /// Will set the column capacity of the inner table and write it to
+    /// storage
+    fn set_preferred_col_cnt(&mut self, cnt: usize) {
+        self.table.set_preferred_col_cnt(cnt);
+        self.store_cnts();
+    }
+}
+
+fn row_cnt_key(prefix: &str) -> String {
+    format!("{}/row_cnt", prefix)
+}
+
+fn col_cnt_key(prefix: &str) -> String {
+    format!("{}/col_cnt", prefix)
+}
+
+fn cell_key(prefix: &str, row: usize, col: usize) -> String {
+    format!("{}/{},{}", prefix, row, col)
+}
+
+fn load_cnts<S: WebStorage>(storage: &S, prefix: &str) -> Result<(usize, usize), WebError> {
+    let row_cnt = load_value(storage, &row_cnt_key(prefix))?;
+    let col_cnt = load_value(storage, &col_cnt_key(prefix))?;
+    Ok((row_cnt.unwrap_or_default(), col_cnt.unwrap_or_default()))
+}
+
+fn store_cnts<S: WebStorage>(
+    storage: &S,
+    prefix: &str,
+    row_cnt: usize,
+    col_cnt: usize,
+) -> Result<(), WebError> {
+    store_value(storage, &row_cnt_key(prefix), &row_cnt)?;
+    store_value(storage, &col_cnt_key(prefix), &col_cnt)
+}
+
+fn load_value<S: WebStorage, T: DeserializeOwned>(
+    storage: &S,
+    key: &str,
+) -> Result<Option<T>, WebError> {
+    storage
+        .get_item(key)
+        .map_err(WebError::Storage)?
+        .map(|x| serde_json::from_str(&x).map_err(WebError::FailedToDeserialize))
+        .transpose()
+}
+
+fn store_value<S: WebStorage, T: Serialize>(
+    storage: &S,
+    key: &str,
+    value: &T,
+) -> Result<(), WebError> {
+    let text = serde_json::to_string(value).map_err(WebError::FailedToSerialize)?;
+    storage.set_item(key, &text).map_err(WebError::Storage)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DynamicTable;
+
+    fn load(storage: &MemoryStorage) -> WebTable<DynamicTable<usize>, &MemoryStorage> {
+        WebTable::new(storage, "test_table", |_, _| DynamicTable::new())
+            .expect("Failed to load table")
+    }
+
+    #[test]
+    fn should_persist_across_creations() {
+        let storage = MemoryStorage::new();
+
+        {
+            let mut table = load(&storage);
+            assert!(table.is_empty(), "Table populated unexpectedly");
+
+            table.push_row(vec![1, 2, 3, 4]);
+            table.push_row(vec![5, 6, 7, 8]);
+            assert!(!table.has_errors());
+        }
+
+        {
+            let mut table = load(&storage);
+            assert_eq!(table.pop_row().expect("Missing row 2"), vec![5, 6, 7, 8]);
+            assert_eq!(table.pop_row().expect("Missing row 1"), vec![1, 2, 3, 4]);
+            assert_eq!(table.pop_row(), None);
+            assert!(!table.has_errors());
+        }
+
+        assert!(load(&storage).is_empty());
+    }
+
+    #[test]
+    fn flush_should_write_changes_made_through_mut_cell_when_rewriting() {
+        let storage = MemoryStorage::new();
+
+        {
+            let mut table = load(&storage);
+            table.push_row(vec![1, 2]);
+            *table.mut_cell(0, 1).unwrap() = 5;
+
+            assert_eq!(table.flush(false).unwrap(), 0);
+            assert_eq!(load(&storage).cell(0, 1), Some(&2));
+
+            assert_eq!(table.flush(true).unwrap(), 4);
+        }
+
+        assert_eq!(load(&storage).cell(0, 1), Some(&5));
+    }
+
+    #[test]
+    fn flush_should_remove_stale_keys_when_rewriting() {
+        let storage = MemoryStorage::new();
+        storage.set_item("other_table/0,0", "1").unwrap();
+
+        let mut table = load(&storage);
+        table.push_row(vec![1, 2]);
+        table.push_row(vec![3, 4]);
+
+        // Simulate removals that never reached storage
+        storage.set_item("test_table/5,5", "9").unwrap();
+        table.table.remove_cell(0, 1);
+        table.table.pop_row();
+
+        assert_eq!(table.flush(true).unwrap(), 3);
+        assert_eq!(storage.get_item("test_table/0,1").unwrap(), None);
+        assert_eq!(storage.get_item("test_table/1,0").unwrap(), None);
+        assert_eq!(storage.get_item("test_table/5,5").unwrap(), None);
+        assert_eq!(
+            storage.get_item("other_table/0,0").unwrap(),
+            Some(String::from("1"))
+        );
+        assert_eq!(storage.len(), 4);
+    }
+
+    #[test]
+    fn reload_should_refresh_capacities_when_requested() {
+        let storage = MemoryStorage::new();
+        let mut table = load(&storage);
+
+        {
+            let mut other = load(&storage);
+            other.push_row(vec![1, 2]);
+        }
+
+        table.reload(false).unwrap();
+        assert!(table.is_empty());
+
+        table.reload(true).unwrap();
+        assert_eq!(table.row(0).copied().collect::<Vec<usize>>(), [1, 2]);
+    }
+
+    #[test]
+    fn should_record_errors_from_storage() {
+        struct FailingStorage;
+
+        impl WebStorage for FailingStorage {
+            fn get_item(&self, _key: &str) -> Result<Option<String>, String> {
+                Ok(None)
+            }
+
+            fn set_item(&self, _key: &str, _value: &str) -> Result<(), String> {
+                Err(String::from("quota exceeded"))
+            }
+
+            fn remove_item(&self, _key: &str) -> Result<(), String> {
+                Ok(())
+            }
+
+            fn keys(&self) -> Result<Vec<String>, String> {
+                Ok(Vec::new())
+            }
+        }
+
+        let mut table =
+            WebTable::new(FailingStorage, "test_table", |_, _| DynamicTable::new()).unwrap();
+        table.push_row(vec![1usize]);
+
+        assert_eq!(table.cell(0, 0), Some(&1));
+        assert!(table.has_errors());
+        assert_eq!(
+            table.take_errors()[0].to_string(),
+            "Storage failed: quota exceeded"
+        );
+        assert!(!table.has_errors());
+    }
+}
@@ -0,0 +1,26 @@
+/// Represents a type whose fields can be expanded into multiple columns of
+/// another table, which is implemented when deriving a table and used by
+/// fields marked with `#[column(flatten)]`
+pub trait FlattenColumns: Sized {
+    /// Type of data stored within each of the columns
+    type Data;
+
+    /// Iterator over the data of each of the columns, in order
+    type IntoCells: Iterator<Item = Self::Data>;
+
+    /// Total columns that the type expands into
+    const COLUMN_CNT: usize;
+
+    /// Names of the columns that the type expands into, in order
+    const COLUMN_NAMES: &'static [&'static str];
+
+    /// Converts into the data of each of the columns, in order
+    fn into_cells(self) -> Self::IntoCells;
+
+    /// Builds from the data of each of the columns, in order, returning
+    /// `None` if any required data is missing or of the wrong type
+    ///
+    /// Exactly [`Self::COLUMN_CNT`] items are consumed from the iterator
+    /// regardless of whether the type can be built
+    fn from_cells<I: Iterator<Item = Option<Self::Data>>>(cells: I) -> Option<Self>;
+}
@@ -0,0 +1,501 @@
+use crate::{Position, ShapeError, Table};
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+use std::{
+    collections::{BTreeSet, VecDeque},
+    vec::Vec,
+};
+
+/// Represents which cells are considered neighbors of a cell in a grid
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Connectivity {
+    /// Cells directly above, below, left, and right are neighbors
+    Four,
+
+    /// Cells directly above, below, left, and right as well as the cells
+    /// diagonal to the cell are neighbors
+    Eight,
+}
+
+/// Predicate used when every existing cell is passable
+type AnyCell<D> = fn(Position, &D) -> bool;
+
+/// Offsets of neighboring cells, where the first four are directly adjacent
+/// and the last four are diagonal
+const OFFSETS: [(isize, isize); 8] = [
+    (-1, 0),
+    (0, -1),
+    (0, 1),
+    (1, 0),
+    (-1, -1),
+    (-1, 1),
+    (1, -1),
+    (1, 1),
+];
+
+impl Connectivity {
+    /// Returns an iterator over the positions of the neighbors of the given
+    /// position that lie within a grid of the specified size
+    pub fn neighbors(
+        self,
+        pos: Position,
+        row_cnt: usize,
+        col_cnt: usize,
+    ) -> impl Iterator<Item = Position> {
+        self.offsets()
+            .iter()
+            .filter_map(move |offset| apply_offset(pos, *offset, row_cnt, col_cnt))
+    }
+
+    fn offsets(self) -> &'static [(isize, isize)] {
+        match self {
+            Self::Four => &OFFSETS[..4],
+            Self::Eight => &OFFSETS,
+        }
+    }
+}
+
+/// Moves the position by the offset, returning `None` if the new position
+/// lies outside of a grid of the specified size
+fn apply_offset(
+    pos: Position,
+    (dr, dc): (isize, isize),
+    row_cnt: usize,
+    col_cnt: usize,
+) -> Option<Position> {
+    let row = pos.row as isize + dr;
+    let col = pos.col as isize + dc;
+
+    if row >= 0 && col >= 0 && (row as usize) < row_cnt && (col as usize) < col_cnt {
+        Some(Position::new(row as usize, col as usize))
+    } else {
+        None
+    }
+}
+
+/// Represents a table treated as a two-dimensional grid of cells, such as a
+/// game board or a matrix
+pub trait GridTable: Table {
+    /// Returns an iterator over the cells in the first and last rows and
+    /// columns of the table alongside their positions, moving through each
+    /// row before moving on to the next; missing cells are skipped
+    fn border_cells(&self) -> BorderCells<'_, Self::Data, Self> {
+        BorderCells::new(self)
+    }
+
+    /// Grows the table by one cell on each side, filling the new cells with
+    /// copies of the given value; fails without changing the table if it
+    /// cannot hold the extra rows or columns
+    fn add_border(&mut self, value: Self::Data) -> Result<(), ShapeError>
+    where
+        Self::Data: Clone,
+    {
+        let row_cnt = self.row_cnt();
+        let col_cnt = self.col_cnt();
+
+        for (capacity, required) in [
+            (self.max_row_capacity(), row_cnt + 2),
+            (self.max_column_capacity(), col_cnt + 2),
+        ]
+        .iter()
+        .copied()
+        {
+            if matches!(capacity.limit(), Some(x) if x < required) {
+                return Err(ShapeError::ExceedsCapacity { capacity, required });
+            }
+        }
+
+        self.insert_column(0, (0..row_cnt).map(|_| value.clone()));
+        self.push_column((0..row_cnt).map(|_| value.clone()));
+        self.insert_row(0, (0..col_cnt + 2).map(|_| value.clone()));
+        self.push_row((0..col_cnt + 2).map(|_| value.clone()));
+
+        Ok(())
+    }
+
+    /// Returns the positions of every cell reachable from the cell at the
+    /// specified row and column by moving between neighboring cells whose
+    /// values are considered equal to the starting cell's value by `eq`,
+    /// beginning with the starting cell and ordered by distance from it;
+    /// missing cells are never part of a region
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[cfg_attr(feature = "docs", doc(cfg(any(alloc, std))))]
+    fn connected_region<F>(
+        &self,
+        row: usize,
+        col: usize,
+        connectivity: Connectivity,
+        mut eq: F,
+    ) -> Vec<Position>
+    where
+        F: FnMut(&Self::Data, &Self::Data) -> bool,
+    {
+        let start = match self.cell(row, col) {
+            Some(x) => x,
+            None => return Vec::new(),
+        };
+
+        let row_cnt = self.row_cnt();
+        let col_cnt = self.col_cnt();
+        let mut region = Vec::new();
+        let mut visited = BTreeSet::new();
+        let mut queue = VecDeque::new();
+
+        visited.insert(Position::new(row, col));
+        queue.push_back(Position::new(row, col));
+
+        while let Some(pos) = queue.pop_front() {
+            region.push(pos);
+
+            for next in connectivity.neighbors(pos, row_cnt, col_cnt) {
+                if !visited.contains(&next)
+                    && matches!(self.cell(next.row, next.col), Some(x) if eq(start, x))
+                {
+                    visited.insert(next);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        region
+    }
+
+    /// Replaces the value of every cell in the region connected to the cell
+    /// at the specified row and column that shares its value with a copy of
+    /// the new value, returning the total cells that were replaced
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[cfg_attr(feature = "docs", doc(cfg(any(alloc, std))))]
+    fn flood_fill(
+        &mut self,
+        row: usize,
+        col: usize,
+        connectivity: Connectivity,
+        value: Self::Data,
+    ) -> usize
+    where
+        Self::Data: PartialEq + Clone,
+    {
+        let region = self.connected_region(row, col, connectivity, PartialEq::eq);
+
+        for pos in region.iter() {
+            self.insert_cell(pos.row, pos.col, value.clone());
+        }
+
+        region.len()
+    }
+
+    /// Returns an iterator over every pair of neighboring cells as edges of
+    /// a graph, suitable for pathfinding; see
+    /// [`GridTable::adjacency_edges_where`] for details
+    fn adjacency_edges(
+        &self,
+        connectivity: Connectivity,
+    ) -> AdjacencyEdges<'_, Self::Data, Self, AnyCell<Self::Data>> {
+        AdjacencyEdges::new(self, connectivity, |_, _| true)
+    }
+
+    /// Returns an iterator over every pair of neighboring cells that are both
+    /// passable according to the predicate as edges of a graph, suitable for
+    /// pathfinding; missing cells are never passable
+    ///
+    /// Edges are directed, so each pair of neighbors produces an edge in both
+    /// directions, and are ordered by the position of the cell they start
+    /// from
+    fn adjacency_edges_where<P>(
+        &self,
+        connectivity: Connectivity,
+        passable: P,
+    ) -> AdjacencyEdges<'_, Self::Data, Self, P>
+    where
+        P: FnMut(Position, &Self::Data) -> bool,
+    {
+        AdjacencyEdges::new(self, connectivity, passable)
+    }
+}
+
+impl<T: Table> GridTable for T {}
+
+/// Represents an iterator over the edges between neighboring passable cells
+/// of a table
+#[derive(Debug)]
+pub struct AdjacencyEdges<'a, D, T: Table<Data = D>, P: FnMut(Position, &D) -> bool> {
+    table: &'a T,
+    connectivity: Connectivity,
+    passable: P,
+    pos: Position,
+    offset: usize,
+}
+
+impl<'a, D, T: Table<Data = D>, P: FnMut(Position, &D) -> bool> AdjacencyEdges<'a, D, T, P> {
+    /// Produces an iterator that starts at the first cell of the table,
+    /// considering cells passable based on the given predicate
+    pub fn new(table: &'a T, connectivity: Connectivity, passable: P) -> Self {
+        Self {
+            table,
+            connectivity,
+            passable,
+            pos: Position::new(0, 0),
+            offset: 0,
+        }
+    }
+
+    fn is_passable(&mut self, pos: Position) -> bool {
+        match self.table.cell(pos.row, pos.col) {
+            Some(x) => (self.passable)(pos, x),
+            None => false,
+        }
+    }
+}
+
+impl<'a, D, T: Table<Data = D>, P: FnMut(Position, &D) -> bool> Iterator
+    for AdjacencyEdges<'a, D, T, P>
+{
+    type Item = (Position, Position);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let row_cnt = self.table.row_cnt();
+        let col_cnt = self.table.col_cnt();
+        let offsets = self.connectivity.offsets();
+
+        while self.pos.row < row_cnt && self.pos.col < col_cnt {
+            let from = self.pos;
+
+            // Skip the rest of an impassable cell's neighbors
+            if self.offset == 0 && !self.is_passable(from) {
+                self.offset = offsets.len();
+            }
+
+            while self.offset < offsets.len() {
+                let offset = offsets[self.offset];
+                self.offset += 1;
+
+                if let Some(to) = apply_offset(from, offset, row_cnt, col_cnt) {
+                    if self.is_passable(to) {
+                        return Some((from, to));
+                    }
+                }
+            }
+
+            self.offset = 0;
+            if from.col + 1 < col_cnt {
+                self.pos.col += 1;
+            } else {
+                self.pos = Position::new(from.row + 1, 0);
+            }
+        }
+
+        None
+    }
+}
+
+/// Represents an iterator over the cells in the first and last rows and
+/// columns of a table
+#[derive(Debug)]
+pub struct BorderCells<'a, D, T: Table<Data = D>> {
+    table: &'a T,
+    row: usize,
+    col: usize,
+}
+
+impl<'a, D, T: Table<Data = D>> BorderCells<'a, D, T> {
+    /// Produces an iterator that starts at the first cell of the table
+    pub fn new(table: &'a T) -> Self {
+        Self {
+            table,
+            row: 0,
+            col: 0,
+        }
+    }
+}
+
+impl<'a, D: 'a, T: Table<Data = D>> Iterator for BorderCells<'a, D, T> {
+    type Item = (Position, &'a D);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let row_cnt = self.table.row_cnt();
+        let col_cnt = self.table.col_cnt();
+
+        while self.row < row_cnt && self.col < col_cnt {
+            let pos = Position::new(self.row, self.col);
+
+            // Rows between the first and last only have their first and last
+            // cells on the border, so jump from one to the other
+            let is_inner_row = self.row > 0 && self.row + 1 < row_cnt;
+            if is_inner_row && self.col == 0 && col_cnt > 1 {
+                self.col = col_cnt - 1;
+            } else if self.col + 1 < col_cnt {
+                self.col += 1;
+            } else {
+                self.row += 1;
+                self.col = 0;
+            }
+
+            if let Some(x) = self.table.cell(pos.row, pos.col) {
+                return Some((pos, x));
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Capacity, FixedTable};
+
+    #[test]
+    fn border_cells_should_skip_inner_cells() {
+        let table = FixedTable::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        let mut cells = table.border_cells().map(|(_, x)| *x);
+
+        for expected in [1, 2, 3, 4, 6, 7, 8, 9].iter() {
+            assert_eq!(cells.next(), Some(*expected));
+        }
+        assert_eq!(cells.next(), None);
+    }
+
+    #[test]
+    fn border_cells_should_include_every_cell_of_single_column() {
+        let table = FixedTable::from([[1], [2], [3]]);
+        let mut cells = table.border_cells();
+
+        assert_eq!(cells.next(), Some((Position::new(0, 0), &1)));
+        assert_eq!(cells.next(), Some((Position::new(1, 0), &2)));
+        assert_eq!(cells.next(), Some((Position::new(2, 0), &3)));
+        assert_eq!(cells.next(), None);
+    }
+
+    #[test]
+    fn add_border_should_fail_if_exceeding_capacity() {
+        let mut table = FixedTable::from([[1]]);
+
+        assert_eq!(
+            table.add_border(0),
+            Err(ShapeError::ExceedsCapacity {
+                capacity: Capacity::Limited(1),
+                required: 3
+            })
+        );
+        assert_eq!(table, [[1]]);
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[test]
+    fn add_border_should_surround_table_with_value() {
+        let mut table = crate::DynamicTable::from([[1, 2], [3, 4]]);
+        table.add_border(0).unwrap();
+
+        assert_eq!(
+            table,
+            [[0, 0, 0, 0], [0, 1, 2, 0], [0, 3, 4, 0], [0, 0, 0, 0]]
+        );
+    }
+
+    #[test]
+    fn neighbors_should_exclude_positions_outside_grid() {
+        let mut neighbors = Connectivity::Four.neighbors(Position::new(0, 0), 2, 2);
+        assert_eq!(neighbors.next(), Some(Position::new(0, 1)));
+        assert_eq!(neighbors.next(), Some(Position::new(1, 0)));
+        assert_eq!(neighbors.next(), None);
+
+        let neighbors = Connectivity::Eight.neighbors(Position::new(1, 1), 3, 3);
+        assert_eq!(neighbors.count(), 8);
+    }
+
+    #[test]
+    fn adjacency_edges_should_connect_neighbors_in_both_directions() {
+        let table = FixedTable::from([[1, 2]]);
+        let mut edges = table.adjacency_edges(Connectivity::Eight);
+
+        assert_eq!(
+            edges.next(),
+            Some((Position::new(0, 0), Position::new(0, 1)))
+        );
+        assert_eq!(
+            edges.next(),
+            Some((Position::new(0, 1), Position::new(0, 0)))
+        );
+        assert_eq!(edges.next(), None);
+    }
+
+    #[test]
+    fn adjacency_edges_where_should_skip_impassable_cells() {
+        let table = FixedTable::from([['.', '#'], ['.', '.']]);
+        let mut edges = table.adjacency_edges_where(Connectivity::Four, |_, x| *x == '.');
+
+        assert_eq!(
+            edges.next(),
+            Some((Position::new(0, 0), Position::new(1, 0)))
+        );
+        assert_eq!(
+            edges.next(),
+            Some((Position::new(1, 0), Position::new(0, 0)))
+        );
+        assert_eq!(
+            edges.next(),
+            Some((Position::new(1, 0), Position::new(1, 1)))
+        );
+        assert_eq!(
+            edges.next(),
+            Some((Position::new(1, 1), Position::new(1, 0)))
+        );
+        assert_eq!(edges.next(), None);
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[test]
+    fn connected_region_should_follow_connectivity() {
+        let table = FixedTable::from([[1, 0, 0], [0, 1, 0], [0, 0, 1]]);
+
+        let region = table.connected_region(0, 0, Connectivity::Four, PartialEq::eq);
+        assert_eq!(region, [Position::new(0, 0)]);
+
+        let region = table.connected_region(0, 0, Connectivity::Eight, PartialEq::eq);
+        assert_eq!(
+            region,
+            [
+                Position::new(0, 0),
+                Position::new(1, 1),
+                Position::new(2, 2)
+            ]
+        );
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[test]
+    fn connected_region_should_not_cross_missing_cells() {
+        let mut table = crate::DynamicTable::new();
+        table.insert_cell(0, 0, 1);
+        table.insert_cell(0, 2, 1);
+        table.insert_cell(1, 0, 1);
+
+        assert_eq!(
+            table
+                .connected_region(0, 0, Connectivity::Four, PartialEq::eq)
+                .len(),
+            2
+        );
+        assert!(table
+            .connected_region(1, 1, Connectivity::Four, PartialEq::eq)
+            .is_empty());
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[test]
+    fn flood_fill_should_replace_connected_cells_with_same_value() {
+        let mut table = FixedTable::from([[0, 0, 1], [1, 0, 1], [0, 1, 0]]);
+
+        assert_eq!(table.flood_fill(0, 0, Connectivity::Four, 7), 3);
+        assert_eq!(table, [[7, 7, 1], [1, 7, 1], [0, 1, 0]]);
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[test]
+    fn add_border_should_produce_filled_table_if_empty() {
+        let mut table = crate::DynamicTable::new();
+        table.add_border(7).unwrap();
+
+        assert_eq!(table, [[7, 7], [7, 7]]);
+    }
+}
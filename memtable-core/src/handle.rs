@@ -0,0 +1,245 @@
+use crate::{iter, Table};
+
+/// Represents a single row of a table that can be read and modified without
+/// needing to pass around the table and the row's index separately
+///
+/// The handle is bound to the columns that exist in the table, so setting a
+/// cell never grows the table
+#[derive(Debug)]
+pub struct RowHandle<'a, D, T: Table<Data = D>> {
+    table: &'a mut T,
+    row: usize,
+}
+
+impl<'a, D, T: Table<Data = D>> RowHandle<'a, D, T> {
+    /// Creates a new handle to the row of the table at the specified index
+    pub fn new(table: &'a mut T, row: usize) -> Self {
+        Self { table, row }
+    }
+
+    /// Returns the index of the row within the table
+    pub fn index(&self) -> usize {
+        self.row
+    }
+
+    /// Returns the total cells in the row, which is the total columns of the
+    /// table
+    pub fn len(&self) -> usize {
+        self.table.col_cnt()
+    }
+
+    /// Returns true if the row has no cells
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns reference to the cell found in the specified column
+    pub fn get(&self, col: usize) -> Option<&D> {
+        self.table.cell(self.row, col)
+    }
+
+    /// Returns mutable reference to the cell found in the specified column
+    pub fn get_mut(&mut self, col: usize) -> Option<&mut D> {
+        self.table.mut_cell(self.row, col)
+    }
+
+    /// Replaces the given value into the cell in the specified column,
+    /// returning the previous value contained in the cell
+    ///
+    /// Returns the value back as an error if the column is out of bounds
+    pub fn set(&mut self, col: usize, value: D) -> Result<Option<D>, D> {
+        if col < self.len() {
+            Ok(self.table.insert_cell(self.row, col, value))
+        } else {
+            Err(value)
+        }
+    }
+
+    /// Returns an iterator of refs through the cells of the row
+    pub fn iter(&self) -> iter::Row<'_, D, T> {
+        iter::Row::new(self.table, self.row)
+    }
+}
+
+/// Represents a single column of a table that can be read and modified
+/// without needing to pass around the table and the column's index
+/// separately
+///
+/// The handle is bound to the rows that exist in the table, so setting a
+/// cell never grows the table
+#[derive(Debug)]
+pub struct ColumnHandle<'a, D, T: Table<Data = D>> {
+    table: &'a mut T,
+    col: usize,
+}
+
+impl<'a, D, T: Table<Data = D>> ColumnHandle<'a, D, T> {
+    /// Creates a new handle to the column of the table at the specified index
+    pub fn new(table: &'a mut T, col: usize) -> Self {
+        Self { table, col }
+    }
+
+    /// Returns the index of the column within the table
+    pub fn index(&self) -> usize {
+        self.col
+    }
+
+    /// Returns the total cells in the column, which is the total rows of the
+    /// table
+    pub fn len(&self) -> usize {
+        self.table.row_cnt()
+    }
+
+    /// Returns true if the column has no cells
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns reference to the cell found in the specified row
+    pub fn get(&self, row: usize) -> Option<&D> {
+        self.table.cell(row, self.col)
+    }
+
+    /// Returns mutable reference to the cell found in the specified row
+    pub fn get_mut(&mut self, row: usize) -> Option<&mut D> {
+        self.table.mut_cell(row, self.col)
+    }
+
+    /// Replaces the given value into the cell in the specified row,
+    /// returning the previous value contained in the cell
+    ///
+    /// Returns the value back as an error if the row is out of bounds
+    pub fn set(&mut self, row: usize, value: D) -> Result<Option<D>, D> {
+        if row < self.len() {
+            Ok(self.table.insert_cell(row, self.col, value))
+        } else {
+            Err(value)
+        }
+    }
+
+    /// Swaps the cells of the column found in the two rows, returning false
+    /// if either row is out of bounds
+    pub fn swap(&mut self, row1: usize, row2: usize) -> bool {
+        if row1 >= self.len() || row2 >= self.len() {
+            return false;
+        }
+
+        if row1 != row2 {
+            let cell1 = self.table.remove_cell(row1, self.col);
+            let cell2 = self.table.remove_cell(row2, self.col);
+
+            if let Some(x) = cell2 {
+                self.table.insert_cell(row1, self.col, x);
+            }
+
+            if let Some(x) = cell1 {
+                self.table.insert_cell(row2, self.col, x);
+            }
+        }
+
+        true
+    }
+
+    /// Returns an iterator of refs through the cells of the column
+    pub fn iter(&self) -> iter::Column<'_, D, T> {
+        iter::Column::new(self.table, self.col)
+    }
+
+    /// Calls the function with the row and a mutable reference of each cell
+    /// in the column
+    ///
+    /// This takes the place of an iterator of mutable refs, which cannot be
+    /// provided for any table as it would need to borrow several cells of
+    /// the table mutably at once
+    pub fn for_each_mut<F: FnMut(usize, &mut D)>(&mut self, mut f: F) {
+        for row in 0..self.len() {
+            if let Some(x) = self.table.mut_cell(row, self.col) {
+                f(row, x);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FixedTable;
+
+    #[test]
+    fn get_should_return_cell_in_column_of_row() {
+        let mut table = FixedTable::from([[1, 2], [3, 4]]);
+        let handle = RowHandle::new(&mut table, 1);
+
+        assert_eq!(handle.index(), 1);
+        assert_eq!(handle.len(), 2);
+        assert_eq!(handle.get(0), Some(&3));
+        assert_eq!(handle.get(1), Some(&4));
+        assert_eq!(handle.get(2), None);
+    }
+
+    #[test]
+    fn set_should_replace_cell_in_column_of_row() {
+        let mut table = FixedTable::from([[1, 2], [3, 4]]);
+        let mut handle = RowHandle::new(&mut table, 0);
+
+        assert_eq!(handle.set(1, 5), Ok(Some(2)));
+        *handle.get_mut(0).unwrap() += 10;
+        assert_eq!(table, [[11, 5], [3, 4]]);
+    }
+
+    #[test]
+    fn set_should_return_value_if_column_out_of_bounds() {
+        let mut table = FixedTable::from([[1, 2]]);
+        let mut handle = RowHandle::new(&mut table, 0);
+
+        assert_eq!(handle.set(2, 5), Err(5));
+    }
+
+    #[test]
+    fn iter_should_iterate_through_cells_of_row() {
+        let mut table = FixedTable::from([[1, 2], [3, 4]]);
+        let handle = RowHandle::new(&mut table, 1);
+
+        let mut cells = handle.iter();
+        assert_eq!(cells.next(), Some(&3));
+        assert_eq!(cells.next(), Some(&4));
+        assert_eq!(cells.next(), None);
+    }
+
+    #[test]
+    fn column_handle_should_get_and_set_cells_in_column() {
+        let mut table = FixedTable::from([[1, 2], [3, 4]]);
+        let mut handle = ColumnHandle::new(&mut table, 1);
+
+        assert_eq!(handle.index(), 1);
+        assert_eq!(handle.len(), 2);
+        assert_eq!(handle.get(0), Some(&2));
+        assert_eq!(handle.set(1, 5), Ok(Some(4)));
+        assert_eq!(handle.set(2, 6), Err(6));
+        assert_eq!(table, [[1, 2], [3, 5]]);
+    }
+
+    #[test]
+    fn column_handle_should_swap_cells_in_column() {
+        let mut table = FixedTable::from([[1, 2], [3, 4], [5, 6]]);
+        let mut handle = ColumnHandle::new(&mut table, 0);
+
+        assert!(handle.swap(0, 2));
+        assert!(handle.swap(1, 1));
+        assert!(!handle.swap(0, 3));
+        assert_eq!(table, [[5, 2], [3, 4], [1, 6]]);
+    }
+
+    #[test]
+    fn column_handle_should_iterate_through_cells_of_column() {
+        let mut table = FixedTable::from([[1, 2], [3, 4]]);
+        let mut handle = ColumnHandle::new(&mut table, 1);
+
+        handle.for_each_mut(|row, x| *x *= 10 + row);
+
+        let mut cells = handle.iter();
+        assert_eq!(cells.next(), Some(&20));
+        assert_eq!(cells.next(), Some(&44));
+        assert_eq!(cells.next(), None);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{iter::*, list::*, utils, Capacity, Position, Table};
+use crate::{iter::*, list::*, memory::*, utils, Capacity, Position, Table};
 use core::{
     cmp,
     iter::FromIterator,
@@ -46,22 +46,44 @@ impl<T: Default, const COL: usize> FixedColumnTable<T, COL> {
 
     /// Removes all cells contained within the table that are outside the
     /// current row capacity
+    ///
+    /// Same as [`Table::truncate`], available without importing the trait
     pub fn truncate(&mut self) {
-        // Shrink to row_cnt total rows
-        self.cells.truncate(self.row_cnt);
-
-        // Now go through each row and re-assign columns to default values
-        for row in self.cells.iter_mut() {
-            for cell in row[self.col_cnt..COL].iter_mut() {
-                *cell = T::default();
-            }
-        }
+        <Self as Table>::truncate(self)
     }
 
     /// Returns an iterator over the cells and their positions within the table
-    pub fn iter(&self) -> ZipPosition<&T, Cells<T, FixedColumnTable<T, COL>>> {
+    pub fn iter(&self) -> ZipPosition<&T, Cells<'_, T, FixedColumnTable<T, COL>>> {
         self.into_iter()
     }
+
+    /// Returns an iterator over the rows within the current row capacity as
+    /// the underlying arrays, avoiding the per-cell lookups of [`Table::row`]
+    ///
+    /// Each array spans all `COL` columns, including those outside of the
+    /// current column capacity; call [`Table::truncate`] first to reset them
+    pub fn rows_as_arrays(&self) -> impl Iterator<Item = &[T; COL]> {
+        self.cells.iter().take(self.row_cnt)
+    }
+
+    /// Consumes the table, returning an iterator over the rows within the
+    /// current row capacity as the underlying arrays
+    ///
+    /// Like [`Self::rows_as_arrays`], each array spans all `COL` columns
+    pub fn into_rows_as_arrays(mut self) -> impl Iterator<Item = [T; COL]> {
+        self.cells.truncate(self.row_cnt);
+        self.cells.into_iter()
+    }
+}
+
+impl<T: Default + HeapSize, const COL: usize> MemoryUsage for FixedColumnTable<T, COL> {
+    fn memory_usage(&self) -> usize {
+        self.cells.heap_size()
+    }
+
+    fn shrink_all(&mut self) {
+        self.cells.shrink_to_fit();
+    }
 }
 
 impl<T: Default, const COL: usize> Table for FixedColumnTable<T, COL> {
@@ -140,6 +162,118 @@ impl<T: Default, const COL: usize> Table for FixedColumnTable<T, COL> {
         }
     }
 
+    /// Swaps the cells in place when both are within the current row &
+    /// column capacity
+    fn swap_cells(&mut self, a: Position, b: Position) {
+        if self.has_cell(a.row, a.col) && self.has_cell(b.row, b.col) {
+            utils::swap_in_rows(&mut self.cells, a, b);
+        } else {
+            utils::swap_cells_by_removal(self, a, b);
+        }
+    }
+
+    /// Shifts the rows as whole arrays rather than cell-by-cell, resetting
+    /// vacant rows to their default value
+    fn shift_rows(&mut self, offset: isize, wrap: bool) {
+        utils::shift_slice(
+            &mut self.cells[..self.row_cnt],
+            offset,
+            wrap,
+            utils::default_array,
+        );
+    }
+
+    /// Shifts the columns within each row's array rather than cell-by-cell,
+    /// resetting vacant cells to their default value
+    fn shift_columns(&mut self, offset: isize, wrap: bool) {
+        let col_cnt = self.col_cnt;
+        for row in self.cells[..self.row_cnt].iter_mut() {
+            utils::shift_slice(&mut row[..col_cnt], offset, wrap, T::default);
+        }
+    }
+
+    /// Inserts a row by shifting the rows on or after it as whole arrays
+    /// rather than cell-by-cell
+    ///
+    /// Rows outside of the current row capacity are dropped first, just as
+    /// growing the table would do
+    fn insert_row<I: IntoIterator<Item = Self::Data>>(&mut self, row: usize, cells: I) {
+        trace_span!(
+            "insert_row",
+            row,
+            shifted_cells = self.row_cnt.saturating_sub(row) * self.col_cnt
+        );
+
+        if row < self.row_cnt {
+            self.cells.truncate(self.row_cnt);
+            self.cells.insert(row, utils::default_array());
+            self.row_cnt += 1;
+        }
+
+        for (col, x) in cells.into_iter().enumerate() {
+            self.insert_cell(row, col, x);
+        }
+    }
+
+    /// Inserts the rows by shifting the rows on or after them as whole arrays
+    /// and only once, rather than cell-by-cell
+    ///
+    /// Rows outside of the current row capacity are dropped first, just as
+    /// growing the table would do
+    fn insert_rows<I, R>(&mut self, row: usize, rows: I)
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = Self::Data>,
+    {
+        let rows: Vec<R> = rows.into_iter().collect();
+        let n = rows.len();
+
+        trace_span!(
+            "insert_rows",
+            row,
+            rows = n,
+            shifted_cells = self.row_cnt.saturating_sub(row) * self.col_cnt
+        );
+
+        if row < self.row_cnt {
+            self.cells.truncate(self.row_cnt);
+            self.cells
+                .splice(row..row, (0..n).map(|_| utils::default_array()));
+            self.row_cnt += n;
+        }
+
+        for (i, cells) in rows.into_iter().enumerate() {
+            for (col, x) in cells.into_iter().enumerate() {
+                self.insert_cell(row + i, col, x);
+            }
+        }
+    }
+
+    /// Removes a row by shifting the rows after it as whole arrays rather
+    /// than cell-by-cell
+    ///
+    /// Rows outside of the current row capacity are left in place
+    fn remove_row(&mut self, row: usize) -> Option<Self::Row> {
+        if row >= self.row_cnt {
+            return None;
+        }
+
+        trace_span!(
+            "remove_row",
+            row,
+            shifted_cells = (self.row_cnt - row - 1) * self.col_cnt
+        );
+
+        let last = self.row_cnt - 1;
+        self.cells[row..=last].rotate_left(1);
+        let mut cells = mem::replace(&mut self.cells[last], utils::default_array());
+        self.row_cnt = last;
+
+        Some(FixedList::new_filled_with(self.col_cnt, |col| {
+            Some(mem::take(&mut cells[col]))
+        }))
+    }
+
     /// Will adjust the internal row count tracker to the specified capacity
     ///
     /// Note that this does **not** remove any cells from the table in their
@@ -164,6 +298,20 @@ impl<T: Default, const COL: usize> Table for FixedColumnTable<T, COL> {
     fn set_preferred_col_cnt(&mut self, capacity: usize) {
         self.col_cnt = cmp::min(capacity, COL);
     }
+
+    /// Removes all rows outside of the current row capacity and resets all
+    /// cells outside of the current column capacity to their default value
+    fn truncate(&mut self) {
+        // Shrink to row_cnt total rows
+        self.cells.truncate(self.row_cnt);
+
+        // Now go through each row and re-assign columns to default values
+        for row in self.cells.iter_mut() {
+            for cell in row[self.col_cnt..COL].iter_mut() {
+                *cell = T::default();
+            }
+        }
+    }
 }
 
 impl<T: Default, U, const T_COL: usize, const U_ROW: usize, const U_COL: usize>
@@ -283,6 +431,28 @@ impl<T: Default, const COL: usize> IndexMut<(usize, usize)> for FixedColumnTable
     }
 }
 
+impl<T: Default, const COL: usize> Index<usize> for FixedColumnTable<T, COL> {
+    type Output = [T];
+
+    /// Indexes into a table by a specific row, returning a reference to the
+    /// cells of the row that can be further indexed by column, otherwise
+    /// panicking
+    fn index(&self, row: usize) -> &Self::Output {
+        assert!(row < self.row_cnt, "Row index out of range");
+        &self.cells[row][..self.col_cnt]
+    }
+}
+
+impl<T: Default, const COL: usize> IndexMut<usize> for FixedColumnTable<T, COL> {
+    /// Indexes into a table by a specific row, returning a mutable reference
+    /// to the cells of the row that can be further indexed by column,
+    /// otherwise panicking
+    fn index_mut(&mut self, row: usize) -> &mut Self::Output {
+        assert!(row < self.row_cnt, "Row index out of range");
+        &mut self.cells[row][..self.col_cnt]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -325,10 +495,10 @@ mod tests {
     fn cell_should_return_ref_to_cell_at_location() {
         // Sets capacity to that of the 2D array provided
         let table = FixedColumnTable::from(vec![["a", "b"], ["c", "d"]]);
-        assert_eq!(table.cell(0, 0).as_deref(), Some(&"a"));
-        assert_eq!(table.cell(0, 1).as_deref(), Some(&"b"));
-        assert_eq!(table.cell(1, 0).as_deref(), Some(&"c"));
-        assert_eq!(table.cell(1, 1).as_deref(), Some(&"d"));
+        assert_eq!(table.cell(0, 0), Some(&"a"));
+        assert_eq!(table.cell(0, 1), Some(&"b"));
+        assert_eq!(table.cell(1, 0), Some(&"c"));
+        assert_eq!(table.cell(1, 1), Some(&"d"));
         assert_eq!(table.cell(1, 2), None);
     }
 
@@ -342,18 +512,18 @@ mod tests {
         // If we change the capacity to be smaller, cell should respect that
         table.set_preferred_row_cnt(1);
         table.set_preferred_col_cnt(1);
-        assert_eq!(table.cell(0, 0).as_deref(), Some(&"a"));
-        assert_eq!(table.cell(0, 1).as_deref(), None);
-        assert_eq!(table.cell(1, 0).as_deref(), None);
-        assert_eq!(table.cell(1, 1).as_deref(), None);
+        assert_eq!(table.cell(0, 0), Some(&"a"));
+        assert_eq!(table.cell(0, 1), None);
+        assert_eq!(table.cell(1, 0), None);
+        assert_eq!(table.cell(1, 1), None);
 
         // Capacity changes don't actually overwrite anything
         table.set_preferred_row_cnt(2);
         table.set_preferred_col_cnt(2);
-        assert_eq!(table.cell(0, 0).as_deref(), Some(&"a"));
-        assert_eq!(table.cell(0, 1).as_deref(), Some(&"b"));
-        assert_eq!(table.cell(1, 0).as_deref(), Some(&"c"));
-        assert_eq!(table.cell(1, 1).as_deref(), Some(&"d"));
+        assert_eq!(table.cell(0, 0), Some(&"a"));
+        assert_eq!(table.cell(0, 1), Some(&"b"));
+        assert_eq!(table.cell(1, 0), Some(&"c"));
+        assert_eq!(table.cell(1, 1), Some(&"d"));
     }
 
     #[test]
@@ -365,10 +535,10 @@ mod tests {
         *table.mut_cell(1, 1).unwrap() = "h";
         assert_eq!(table.mut_cell(2, 0), None);
 
-        assert_eq!(table.cell(0, 0).as_deref(), Some(&"e"));
-        assert_eq!(table.cell(0, 1).as_deref(), Some(&"f"));
-        assert_eq!(table.cell(1, 0).as_deref(), Some(&"g"));
-        assert_eq!(table.cell(1, 1).as_deref(), Some(&"h"));
+        assert_eq!(table.cell(0, 0), Some(&"e"));
+        assert_eq!(table.cell(0, 1), Some(&"f"));
+        assert_eq!(table.cell(1, 0), Some(&"g"));
+        assert_eq!(table.cell(1, 1), Some(&"h"));
     }
 
     #[test]
@@ -392,7 +562,7 @@ mod tests {
 
         assert_eq!(table.insert_cell(0, 0, 123), None);
         assert_eq!(table.insert_cell(0, 0, 999), Some(123));
-        assert_eq!(table.cell(0, 0).as_deref(), Some(&999))
+        assert_eq!(table.cell(0, 0), Some(&999))
     }
 
     #[test]
@@ -472,6 +642,31 @@ mod tests {
         let _ = table[(1, 0)];
     }
 
+    #[test]
+    fn index_by_row_should_return_cells_of_row() {
+        let mut table = FixedColumnTable::from([[1, 2, 3], [4, 5, 6]]);
+        table.set_preferred_col_cnt(2);
+
+        assert_eq!(table[1], [4, 5]);
+        assert_eq!(table[1][0], 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_by_row_should_panic_if_row_not_found() {
+        let table = FixedColumnTable::from([[1, 2, 3], [4, 5, 6]]);
+        let _ = &table[2];
+    }
+
+    #[test]
+    fn index_mut_by_row_should_return_mutable_cells_of_row() {
+        let mut table = FixedColumnTable::from([[1, 2, 3], [4, 5, 6]]);
+        table[0][1] = 999;
+        table[1].swap(0, 2);
+
+        assert_eq!(table, [[1, 999, 3], [6, 5, 4]]);
+    }
+
     #[test]
     fn index_mut_by_row_and_column_should_return_mutable_cell() {
         let mut table = FixedColumnTable::from(vec![[1, 2, 3]]);
@@ -537,6 +732,23 @@ mod tests {
         assert_eq!(table, [["a", "b", "c"], ["d", "e", "f"], ["g", "h", "i"]]);
     }
 
+    #[test]
+    fn insert_rows_should_shift_down_all_rows_on_or_after_specified_row() {
+        let mut table = FixedColumnTable::from(vec![["a", "b", "c"], ["d", "e", "f"]]);
+
+        table.insert_rows(1, vec![vec!["g", "h", "i"], vec!["j", "k", "l"]]);
+
+        assert_eq!(
+            table,
+            [
+                ["a", "b", "c"],
+                ["g", "h", "i"],
+                ["j", "k", "l"],
+                ["d", "e", "f"]
+            ]
+        );
+    }
+
     #[test]
     fn insert_column_should_append_if_comes_after_last_column_if_capacity_remaining() {
         let mut table = FixedColumnTable::from([["a", "b", "c", "g"], ["d", "e", "f", "h"]]);
@@ -629,6 +841,34 @@ mod tests {
         assert_eq!(table, [["a", "b", "c"], ["d", "e", "f"], ["g", "h", "i"]]);
     }
 
+    #[test]
+    fn row_shifts_should_not_bring_rows_outside_capacity_back() {
+        let mut table = FixedColumnTable::from([["a", "b", "c"], ["d", "e", "f"], ["g", "h", "i"]]);
+        table.set_preferred_row_cnt(2);
+
+        table.insert_row(0, ["x", "y", "z"].iter().copied());
+        assert_eq!(table, [["x", "y", "z"], ["a", "b", "c"], ["d", "e", "f"]]);
+
+        assert_eq!(table.remove_row(0).unwrap(), ["x", "y", "z"]);
+        table.set_preferred_row_cnt(3);
+        assert_eq!(table, [["a", "b", "c"], ["d", "e", "f"], ["", "", ""]]);
+    }
+
+    #[test]
+    fn rows_as_arrays_should_yield_rows_within_row_capacity() {
+        let mut table = FixedColumnTable::from([["a", "b"], ["c", "d"], ["e", "f"]]);
+        table.set_preferred_row_cnt(2);
+
+        assert_eq!(
+            table.rows_as_arrays().collect::<Vec<_>>(),
+            [&["a", "b"], &["c", "d"]]
+        );
+        assert_eq!(
+            table.into_rows_as_arrays().collect::<Vec<_>>(),
+            [["a", "b"], ["c", "d"]]
+        );
+    }
+
     #[test]
     fn pop_row_should_remove_last_row() {
         let mut table = FixedColumnTable::from([["a", "b", "c"], ["d", "e", "f"], ["g", "h", "i"]]);
@@ -679,4 +919,20 @@ mod tests {
 
         assert_eq!(table, [["a", "b"], ["d", "e"], ["g", "h"]]);
     }
+
+    #[test]
+    fn shrink_all_should_release_unused_capacity() {
+        let mut table: FixedColumnTable<usize, 2> = FixedColumnTable::new();
+        for i in 0..10 {
+            table.push_row(vec![i, i]);
+        }
+        for _ in 0..9 {
+            table.pop_row();
+        }
+
+        let before = table.memory_usage();
+        table.shrink_all();
+        assert!(table.memory_usage() < before);
+        assert_eq!(table, [[0, 0]]);
+    }
 }
@@ -0,0 +1,363 @@
+use crate::{list::*, memory::*, Capacity, Position, Table};
+use core::{iter::FromIterator, mem, ops::Deref};
+use std::{collections::BTreeMap, sync::Arc, vec::Vec};
+
+/// Total rows grouped together into a single shared chunk
+const CHUNK_ROWS: usize = 64;
+
+/// Represents an inmemory table containing rows & columns of some data `T`,
+/// capable of producing cheap snapshots of itself that stay unchanged while
+/// the table continues to be modified
+///
+/// Rows are grouped into reference-counted chunks, so a snapshot only copies
+/// pointers to the chunks. The first change to a chunk that is shared with
+/// a snapshot copies that chunk alone, leaving all other chunks shared
+#[cfg_attr(feature = "docs", doc(cfg(any(alloc, std))))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CowTable<T> {
+    /// Represents the table's data (cells) as shared chunks of rows
+    chunks: Vec<Arc<BTreeMap<Position, T>>>,
+
+    /// Represents the total rows contained in the table based on the largest
+    /// row position found
+    row_cnt: usize,
+
+    /// Represents the total columns contained in the table based on the largest
+    /// column position found
+    col_cnt: usize,
+}
+
+impl<T> CowTable<T> {
+    /// Creates a new, empty table
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a read-only view of the table as it is right now, which will
+    /// not reflect any changes made to the table afterward
+    pub fn snapshot(&self) -> TableSnapshot<T> {
+        TableSnapshot(Self {
+            chunks: self.chunks.clone(),
+            row_cnt: self.row_cnt,
+            col_cnt: self.col_cnt,
+        })
+    }
+
+    fn chunk(&self, row: usize) -> Option<&BTreeMap<Position, T>> {
+        self.chunks.get(row / CHUNK_ROWS).map(Deref::deref)
+    }
+}
+
+impl<T: Clone> CowTable<T> {
+    /// Returns the chunk containing the row, creating it if it does not exist
+    /// and copying it if it is shared with a snapshot
+    fn mut_chunk(&mut self, row: usize) -> &mut BTreeMap<Position, T> {
+        let idx = row / CHUNK_ROWS;
+        while self.chunks.len() <= idx {
+            self.chunks.push(Arc::new(BTreeMap::new()));
+        }
+
+        Arc::make_mut(&mut self.chunks[idx])
+    }
+}
+
+impl<T> Default for CowTable<T> {
+    fn default() -> Self {
+        Self {
+            chunks: Vec::new(),
+            row_cnt: 0,
+            col_cnt: 0,
+        }
+    }
+}
+
+impl<T: Clone> Table for CowTable<T> {
+    type Data = T;
+    type Row = DynamicList<Self::Data>;
+    type Column = DynamicList<Self::Data>;
+
+    fn max_row_capacity(&self) -> Capacity {
+        Capacity::Unlimited
+    }
+
+    fn max_column_capacity(&self) -> Capacity {
+        Capacity::Unlimited
+    }
+
+    fn row_cnt(&self) -> usize {
+        self.row_cnt
+    }
+
+    fn col_cnt(&self) -> usize {
+        self.col_cnt
+    }
+
+    fn cell(&self, row: usize, col: usize) -> Option<&Self::Data> {
+        self.chunk(row)?.get(&Position { row, col })
+    }
+
+    fn mut_cell(&mut self, row: usize, col: usize) -> Option<&mut Self::Data> {
+        // Avoid copying a shared chunk if there is nothing to change
+        if self.has_cell(row, col) {
+            self.mut_chunk(row).get_mut(&Position { row, col })
+        } else {
+            None
+        }
+    }
+
+    fn insert_cell(&mut self, row: usize, col: usize, value: Self::Data) -> Option<Self::Data> {
+        // If cell exceeds current row range, adjust it
+        if row >= self.row_cnt {
+            self.row_cnt = row + 1;
+        }
+
+        // If cell exceeds current column range, adjust it
+        if col >= self.col_cnt {
+            self.col_cnt = col + 1;
+        }
+
+        self.mut_chunk(row).insert(Position { row, col }, value)
+    }
+
+    fn remove_cell(&mut self, row: usize, col: usize) -> Option<Self::Data> {
+        // Avoid copying a shared chunk if there is nothing to remove
+        if self.has_cell(row, col) {
+            self.mut_chunk(row).remove(&Position { row, col })
+        } else {
+            None
+        }
+    }
+
+    /// Will adjust the internal row count tracker to the specified capacity
+    fn set_preferred_row_cnt(&mut self, cnt: usize) {
+        self.row_cnt = cnt;
+    }
+
+    /// Will adjust the internal column count tracker to the specified capacity
+    fn set_preferred_col_cnt(&mut self, cnt: usize) {
+        self.col_cnt = cnt;
+    }
+
+    /// Removes all cells outside of the current row & column counts, dropping
+    /// the chunks past the last row and only copying shared chunks that hold
+    /// such cells
+    fn truncate(&mut self) {
+        let row_cnt = self.row_cnt;
+        let col_cnt = self.col_cnt;
+        let in_range = |pos: &Position| pos.row < row_cnt && pos.col < col_cnt;
+
+        // Keep only the chunks holding rows within the row count
+        self.chunks.truncate(match row_cnt {
+            0 => 0,
+            _ => (row_cnt - 1) / CHUNK_ROWS + 1,
+        });
+        for chunk in self.chunks.iter_mut() {
+            if !chunk.keys().all(in_range) {
+                Arc::make_mut(chunk).retain(|pos, _| in_range(pos));
+            }
+        }
+    }
+
+    /// Adjusts the table's row & column counts to fit where cells exist,
+    /// which includes cells outside of the current counts
+    fn shrink_to_fit(&mut self) {
+        let (max_row, max_col) =
+            self.chunks
+                .iter()
+                .flat_map(|chunk| chunk.keys())
+                .fold((0, 0), |acc, pos| {
+                    (
+                        core::cmp::max(acc.0, pos.row + 1),
+                        core::cmp::max(acc.1, pos.col + 1),
+                    )
+                });
+
+        self.row_cnt = max_row;
+        self.col_cnt = max_col;
+    }
+}
+
+impl<T: HeapSize> MemoryUsage for CowTable<T> {
+    /// Estimates memory based on every chunk referenced by the table, including
+    /// chunks that are shared with snapshots
+    fn memory_usage(&self) -> usize {
+        let chunks = self.chunks.capacity() * mem::size_of::<Arc<BTreeMap<Position, T>>>();
+        let cells: usize = self
+            .chunks
+            .iter()
+            .map(|chunk| {
+                mem::size_of::<BTreeMap<Position, T>>()
+                    + chunk.len() * mem::size_of::<(Position, T)>()
+                    + chunk.values().map(HeapSize::heap_size).sum::<usize>()
+            })
+            .sum();
+
+        chunks + cells
+    }
+
+    fn shrink_all(&mut self) {
+        self.chunks.shrink_to_fit();
+    }
+}
+
+impl<T: Clone, V: Into<T>> FromIterator<(usize, usize, V)> for CowTable<T> {
+    /// Produces a table from the provided iterator of (row, col, value)
+    fn from_iter<I: IntoIterator<Item = (usize, usize, V)>>(iter: I) -> Self {
+        let mut table = Self::new();
+        for (row, col, x) in iter {
+            table.insert_cell(row, col, x.into());
+        }
+        table
+    }
+}
+
+impl<T: Clone, V: Into<T>> FromIterator<(Position, V)> for CowTable<T> {
+    /// Produces a table from the provided iterator of (position, value)
+    fn from_iter<I: IntoIterator<Item = (Position, V)>>(iter: I) -> Self {
+        iter.into_iter().map(|(p, x)| (p.row, p.col, x)).collect()
+    }
+}
+
+impl<T: Clone + Default, const ROW: usize, const COL: usize> From<[[T; COL]; ROW]> for CowTable<T> {
+    /// Creates a new table from the 2D array
+    fn from(mut matrix: [[T; COL]; ROW]) -> Self {
+        let mut table = Self::new();
+
+        #[allow(clippy::needless_range_loop)]
+        for row in 0..ROW {
+            for col in 0..COL {
+                table.insert_cell(row, col, mem::take(&mut matrix[row][col]));
+            }
+        }
+
+        table
+    }
+}
+
+/// Represents a read-only view of a [`CowTable`] at a specific point in time
+#[cfg_attr(feature = "docs", doc(cfg(any(alloc, std))))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TableSnapshot<T>(CowTable<T>);
+
+impl<T> TableSnapshot<T> {
+    /// Converts the snapshot into a table that can be modified, which shares
+    /// its chunks with the original table until either of them changes
+    pub fn into_table(self) -> CowTable<T> {
+        self.0
+    }
+}
+
+impl<T> Deref for TableSnapshot<T> {
+    type Target = CowTable<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::vec;
+
+    #[test]
+    fn insert_cell_should_grow_row_and_column_counts() {
+        let mut table = CowTable::new();
+        table.insert_cell(100, 3, "a");
+
+        assert_eq!(table.row_cnt(), 101);
+        assert_eq!(table.col_cnt(), 4);
+        assert_eq!(table.cell(100, 3), Some(&"a"));
+        assert_eq!(table.cell(0, 0), None);
+    }
+
+    #[test]
+    fn snapshot_should_not_reflect_later_changes() {
+        let mut table = CowTable::new();
+        table.push_row(vec![1, 2, 3]);
+        table.push_row(vec![4, 5, 6]);
+
+        let snapshot = table.snapshot();
+        table.insert_cell(0, 0, 10);
+        table.remove_cell(1, 1);
+        table.push_row(vec![7, 8, 9]);
+
+        assert_eq!(snapshot.row_cnt(), 2);
+        assert_eq!(snapshot.cell(0, 0), Some(&1));
+        assert_eq!(snapshot.cell(1, 1), Some(&5));
+
+        assert_eq!(table.row_cnt(), 3);
+        assert_eq!(table.cell(0, 0), Some(&10));
+        assert_eq!(table.cell(1, 1), None);
+    }
+
+    #[test]
+    fn snapshot_should_share_chunks_until_changed() {
+        let mut table = CowTable::new();
+        table.insert_cell(0, 0, 1);
+        table.insert_cell(CHUNK_ROWS, 0, 2);
+
+        let snapshot = table.snapshot();
+        assert!(Arc::ptr_eq(&table.chunks[0], &snapshot.chunks[0]));
+        assert!(Arc::ptr_eq(&table.chunks[1], &snapshot.chunks[1]));
+
+        // Only the chunk that is changed should be copied
+        table.insert_cell(0, 0, 10);
+        assert!(!Arc::ptr_eq(&table.chunks[0], &snapshot.chunks[0]));
+        assert!(Arc::ptr_eq(&table.chunks[1], &snapshot.chunks[1]));
+    }
+
+    #[test]
+    fn mut_cell_should_not_copy_shared_chunk_if_cell_missing() {
+        let mut table = CowTable::new();
+        table.insert_cell(0, 0, 1);
+
+        let snapshot = table.snapshot();
+        assert_eq!(table.mut_cell(0, 1), None);
+        assert_eq!(table.remove_cell(0, 1), None);
+        assert!(Arc::ptr_eq(&table.chunks[0], &snapshot.chunks[0]));
+    }
+
+    #[test]
+    fn truncate_should_only_copy_chunks_holding_cells_outside_of_counts() {
+        let mut table = CowTable::new();
+        table.insert_cell(0, 0, 1);
+        table.insert_cell(0, 3, 2);
+        table.insert_cell(CHUNK_ROWS, 0, 3);
+        table.insert_cell(CHUNK_ROWS * 2, 0, 4);
+
+        let snapshot = table.snapshot();
+        table.set_preferred_row_cnt(CHUNK_ROWS + 1);
+        table.set_preferred_col_cnt(2);
+        table.truncate();
+
+        assert_eq!(table.chunks.len(), 2);
+        assert!(!Arc::ptr_eq(&table.chunks[0], &snapshot.chunks[0]));
+        assert!(Arc::ptr_eq(&table.chunks[1], &snapshot.chunks[1]));
+        assert_eq!(snapshot.cell(0, 3), Some(&2));
+
+        table.shrink_to_fit();
+        assert_eq!((table.row_cnt(), table.col_cnt()), (CHUNK_ROWS + 1, 1));
+    }
+
+    #[test]
+    fn from_iter_should_place_cells_at_positions() {
+        let table: CowTable<usize> = vec![(0, 1, 5usize), (70, 0, 6)].into_iter().collect();
+        assert_eq!(table.row_cnt(), 71);
+        assert_eq!(table.col_cnt(), 2);
+        assert_eq!(table.cell(0, 1), Some(&5));
+        assert_eq!(table.cell(70, 0), Some(&6));
+    }
+
+    #[test]
+    fn into_table_should_produce_independent_table() {
+        let mut table = CowTable::new();
+        table.push_row(vec![1, 2]);
+
+        let mut copy = table.snapshot().into_table();
+        *copy.mut_cell(0, 0).unwrap() = 100;
+
+        assert_eq!(table.cell(0, 0), Some(&1));
+        assert_eq!(copy.cell(0, 0), Some(&100));
+    }
+}
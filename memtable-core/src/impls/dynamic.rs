@@ -1,11 +1,17 @@
-use crate::{iter::*, list::*, Capacity, Position, Table};
+use crate::{iter::*, list::*, memory::*, utils, Capacity, Position, ShapeError, Table};
 use core::{
     cmp,
+    convert::TryFrom,
     iter::FromIterator,
     mem,
     ops::{Index, IndexMut},
 };
 
+use std::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
 #[cfg(feature = "std")]
 use std::collections::HashMap;
 
@@ -14,13 +20,31 @@ use hashbrown::HashMap;
 
 /// Represents an inmemory table containing rows & columns of some data `T`,
 /// capable of growing and shrinking in size dynamically
-#[cfg_attr(feature = "serde-1", serde_with::serde_as)]
+///
+/// As cells are not stored contiguously by row, the table can only be
+/// indexed by `table[(row, col)]` rather than `table[row]`; use
+/// [`Table::row`] or [`Table::row_handle`] to work with a single row instead
+///
+/// With the `serde` feature, a table of borrowed data such as
+/// `DynamicTable<&'a str>` can be deserialized without copying its cells,
+/// pointing into the buffer being deserialized instead. The buffer must
+/// outlive the table, and formats that escape strings (like json) fail to
+/// borrow any cell that contains an escape sequence
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde-1", derive(serde::Serialize, serde::Deserialize))]
 pub struct DynamicTable<T> {
     /// Represents the table's data (cells) as a mapping between a cell's
     /// position and its actual content (private)
-    #[cfg_attr(feature = "serde-1", serde_as("Vec<(_, _)>"))]
+    #[cfg_attr(
+        feature = "serde-1",
+        serde(
+            bound(
+                serialize = "T: serde::Serialize",
+                deserialize = "T: serde::Deserialize<'de>"
+            ),
+            with = "serde_with::As::<Vec<(serde_with::Same, serde_with::Same)>>"
+        )
+    )]
     cells: HashMap<Position, T>,
 
     /// Represents the total rows contained in the table based on the largest
@@ -40,27 +64,224 @@ impl<T> DynamicTable<T> {
 
     /// Removes all cells contained within the table that are outside the
     /// current row & column capacity
+    ///
+    /// Same as [`Table::truncate`], available without importing the trait
     pub fn truncate(&mut self) {
-        let row_cnt = self.row_cnt;
-        let col_cnt = self.col_cnt;
-        self.cells
-            .retain(|pos, _| pos.row < row_cnt && pos.col < col_cnt);
+        <Self as Table>::truncate(self)
     }
 
     /// Shrinks the table's row & column capacity to fit where cells exist
+    ///
+    /// Same as [`Table::shrink_to_fit`], available without importing the trait
     pub fn shrink_to_fit(&mut self) {
-        let (max_row, max_col) = self.cells.keys().fold((0, 0), |acc, pos| {
-            (cmp::max(acc.0, pos.row + 1), cmp::max(acc.1, pos.col + 1))
-        });
-
-        self.row_cnt = max_row;
-        self.col_cnt = max_col;
+        <Self as Table>::shrink_to_fit(self)
     }
 
     /// Returns an iterator over the cells and their positions within the table
-    pub fn iter(&self) -> ZipPosition<&T, Cells<T, DynamicTable<T>>> {
+    pub fn iter(&self) -> ZipPosition<&T, Cells<'_, T, DynamicTable<T>>> {
         self.into_iter()
     }
+
+    /// Creates a new table from nested rows of cells, failing if any row has
+    /// a different number of cells than the first row
+    pub fn try_from_nested_vec(rows: Vec<Vec<T>>) -> Result<Self, ShapeError> {
+        let expected = rows.first().map(Vec::len).unwrap_or_default();
+
+        if let Some(row) = rows.iter().find(|row| row.len() != expected) {
+            return Err(ShapeError::ColumnMismatch {
+                expected,
+                actual: row.len(),
+            });
+        }
+
+        Ok(Self::from(rows))
+    }
+
+    /// Creates a new table from nested rows of cells, filling the end of any
+    /// row shorter than the longest row with copies of the given value
+    pub fn from_nested_vec_padded(rows: Vec<Vec<T>>, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        let col_cnt = rows.iter().map(Vec::len).max().unwrap_or_default();
+        let mut table = Self::from(rows);
+
+        for row in 0..table.row_cnt {
+            for col in 0..col_cnt {
+                if !table.has_cell(row, col) {
+                    table.insert_cell(row, col, fill.clone());
+                }
+            }
+        }
+
+        table
+    }
+
+    /// Consumes the table and returns its cells as nested rows, each holding
+    /// a cell for every column of the table where missing cells are filled
+    /// in with the default value
+    pub fn into_nested_vec(mut self) -> Vec<Vec<T>>
+    where
+        T: Default,
+    {
+        let col_cnt = self.col_cnt;
+        (0..self.row_cnt)
+            .map(|row| {
+                (0..col_cnt)
+                    .map(|col| self.remove_cell(row, col).unwrap_or_default())
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Consumes the table and splits it into tiles of `rows` by `cols` cells,
+    /// returning a table whose cell at `(r, c)` is the tile covering rows
+    /// starting at `r * rows` and columns starting at `c * cols`
+    ///
+    /// Tiles along the bottom and right edges hold whatever rows and columns
+    /// remain, so they may be smaller than the rest. Cells outside of the
+    /// current row & column capacity are dropped.
+    ///
+    /// Panics if either `rows` or `cols` is zero
+    pub fn split_into_tiles(self, rows: usize, cols: usize) -> DynamicTable<DynamicTable<T>> {
+        assert!(
+            rows > 0 && cols > 0,
+            "Tiles must have at least one row and column"
+        );
+        trace_span!("split_into_tiles", rows, cols);
+
+        let (row_cnt, col_cnt) = (self.row_cnt, self.col_cnt);
+        let mut tiles = DynamicTable::new();
+        // Count partial tiles without adding to the counts, which would
+        // overflow when asking for tiles as tall or wide as possible
+        let tile_row_cnt = row_cnt / rows + (row_cnt % rows != 0) as usize;
+        let tile_col_cnt = col_cnt / cols + (col_cnt % cols != 0) as usize;
+        for tile_row in 0..tile_row_cnt {
+            for tile_col in 0..tile_col_cnt {
+                let mut tile = DynamicTable::new();
+                tile.row_cnt = cmp::min(rows, row_cnt - tile_row * rows);
+                tile.col_cnt = cmp::min(cols, col_cnt - tile_col * cols);
+                tiles.insert_cell(tile_row, tile_col, tile);
+            }
+        }
+
+        for (pos, x) in self.cells {
+            if pos.row < row_cnt && pos.col < col_cnt {
+                let tile = &mut tiles[(pos.row / rows, pos.col / cols)];
+                tile.cells
+                    .insert(Position::new(pos.row % rows, pos.col % cols), x);
+            }
+        }
+
+        tiles
+    }
+
+    /// Rebuilds the map of cells with each cell within the preferred row and
+    /// column counts moved to the position returned by the function, which
+    /// is given the row & column counts and drops the cell by returning none
+    ///
+    /// Cells outside of the counts stay where they are unless a moved cell
+    /// lands on them, in which case the moved cell replaces them
+    fn shift_positions<F>(&mut self, mut f: F)
+    where
+        F: FnMut(Position, usize, usize) -> Option<Position>,
+    {
+        let (row_cnt, col_cnt) = (self.row_cnt, self.col_cnt);
+        let is_within = |pos: &Position| pos.row < row_cnt && pos.col < col_cnt;
+        let (visible, hidden): (Vec<_>, Vec<_>) = mem::take(&mut self.cells)
+            .into_iter()
+            .partition(|(pos, _)| is_within(pos));
+
+        // Moved cells are added last so that they deterministically replace
+        // any cell outside of the counts at the same position
+        self.cells = hidden.into_iter().collect();
+        self.cells.extend(
+            visible
+                .into_iter()
+                .filter_map(|(pos, x)| f(pos, row_cnt, col_cnt).map(|pos| (pos, x))),
+        );
+    }
+}
+
+impl<T> DynamicTable<DynamicTable<T>> {
+    /// Consumes a table of tiles and places the cells of every tile beside
+    /// and below each other within one table, the reverse of
+    /// [`DynamicTable::split_into_tiles`]
+    ///
+    /// Every tile within a row of tiles must have the same number of rows,
+    /// and every tile within a column of tiles must have the same number of
+    /// columns, which is taken from the first tile present in that row or
+    /// column. A missing tile leaves its cells missing, and a row or column
+    /// of tiles with no tiles at all takes up no space.
+    pub fn flatten_nested(self) -> Result<DynamicTable<T>, ShapeError> {
+        trace_span!("flatten_nested", rows = self.row_cnt, cols = self.col_cnt);
+        let heights: Vec<usize> = (0..self.row_cnt)
+            .map(|row| {
+                (0..self.col_cnt)
+                    .find_map(|col| self.cell(row, col))
+                    .map_or(0, |tile| tile.row_cnt)
+            })
+            .collect();
+        let widths: Vec<usize> = (0..self.col_cnt)
+            .map(|col| {
+                (0..self.row_cnt)
+                    .find_map(|row| self.cell(row, col))
+                    .map_or(0, |tile| tile.col_cnt)
+            })
+            .collect();
+
+        for (pos, tile) in self.iter() {
+            if pos.row < self.row_cnt && pos.col < self.col_cnt {
+                if tile.row_cnt != heights[pos.row] {
+                    return Err(ShapeError::RowMismatch {
+                        expected: heights[pos.row],
+                        actual: tile.row_cnt,
+                    });
+                }
+
+                if tile.col_cnt != widths[pos.col] {
+                    return Err(ShapeError::ColumnMismatch {
+                        expected: widths[pos.col],
+                        actual: tile.col_cnt,
+                    });
+                }
+            }
+        }
+
+        let offsets = |sizes: &[usize]| -> Vec<usize> {
+            sizes
+                .iter()
+                .scan(0, |offset, size| {
+                    let start = *offset;
+                    *offset += size;
+                    Some(start)
+                })
+                .collect()
+        };
+        let (row_offsets, col_offsets) = (offsets(&heights), offsets(&widths));
+
+        let mut table = DynamicTable::new();
+        for (pos, tile) in self.cells {
+            if pos.row < self.row_cnt && pos.col < self.col_cnt {
+                let (row_cnt, col_cnt) = (tile.row_cnt, tile.col_cnt);
+                for (p, x) in tile.cells {
+                    if p.row < row_cnt && p.col < col_cnt {
+                        table.cells.insert(
+                            Position::new(
+                                row_offsets[pos.row] + p.row,
+                                col_offsets[pos.col] + p.col,
+                            ),
+                            x,
+                        );
+                    }
+                }
+            }
+        }
+
+        table.row_cnt = heights.iter().sum();
+        table.col_cnt = widths.iter().sum();
+        Ok(table)
+    }
 }
 
 impl<T> Default for DynamicTable<T> {
@@ -135,6 +356,208 @@ impl<T> Table for DynamicTable<T> {
     fn set_preferred_col_cnt(&mut self, cnt: usize) {
         self.col_cnt = cnt;
     }
+
+    /// Removes all cells contained within the table that are outside the
+    /// current row & column capacity
+    fn truncate(&mut self) {
+        let row_cnt = self.row_cnt;
+        let col_cnt = self.col_cnt;
+        self.cells
+            .retain(|pos, _| pos.row < row_cnt && pos.col < col_cnt);
+    }
+
+    /// Adjusts the table's row & column capacity to fit where cells exist,
+    /// which includes cells outside of the current capacity
+    fn shrink_to_fit(&mut self) {
+        let (max_row, max_col) = self.cells.keys().fold((0, 0), |acc, pos| {
+            (cmp::max(acc.0, pos.row + 1), cmp::max(acc.1, pos.col + 1))
+        });
+
+        self.row_cnt = max_row;
+        self.col_cnt = max_col;
+    }
+
+    /// Rebuilds the map of cells in a single pass with the shifted positions
+    /// rather than removing and reinserting each shifted cell
+    ///
+    /// Like the default implementation, only cells within the preferred row
+    /// and column counts are shifted
+    fn remove_row(&mut self, row: usize) -> Option<Self::Row> {
+        let (row_cnt, col_cnt) = (self.row_cnt, self.col_cnt);
+        if row >= row_cnt {
+            return None;
+        }
+
+        trace_span!(
+            "remove_row",
+            row,
+            shifted_cells = (row_cnt - row - 1) * col_cnt
+        );
+
+        let removed = DynamicList::new_filled_with(col_cnt, |col| self.remove_cell(row, col));
+
+        if row + 1 < row_cnt {
+            self.cells = mem::take(&mut self.cells)
+                .into_iter()
+                .map(|(mut pos, x)| {
+                    if pos.row > row && pos.row < row_cnt && pos.col < col_cnt {
+                        pos.row -= 1;
+                    }
+                    (pos, x)
+                })
+                .collect();
+        }
+
+        self.row_cnt = row_cnt - 1;
+        Some(removed)
+    }
+
+    /// Rebuilds the map of cells in a single pass with the shifted positions
+    /// rather than moving each cell one at a time
+    ///
+    /// Like the default implementation, only cells within the preferred row
+    /// and column counts are shifted
+    fn insert_rows<I, R>(&mut self, row: usize, rows: I)
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = Self::Data>,
+    {
+        let rows: Vec<R> = rows.into_iter().collect();
+        let n = rows.len();
+
+        trace_span!(
+            "insert_rows",
+            row,
+            rows = n,
+            shifted_cells = self.row_cnt.saturating_sub(row) * self.col_cnt
+        );
+
+        if n > 0 && row < self.row_cnt {
+            // Cells hidden below the table would otherwise show up within the
+            // rows revealed by the shift wherever no cell moves onto them
+            let (row_cnt, col_cnt) = (self.row_cnt, self.col_cnt);
+            self.cells.retain(|pos, _| {
+                !(pos.row >= row_cnt && pos.row < row_cnt + n && pos.col < col_cnt)
+            });
+
+            self.shift_positions(|pos, _, _| {
+                Some(if pos.row >= row {
+                    Position::new(pos.row + n, pos.col)
+                } else {
+                    pos
+                })
+            });
+            self.row_cnt += n;
+        }
+
+        for (i, cells) in rows.into_iter().enumerate() {
+            for (col, x) in cells.into_iter().enumerate() {
+                self.insert_cell(row + i, col, x);
+            }
+        }
+    }
+
+    /// Rebuilds the map of cells in a single pass with the shifted positions
+    /// rather than moving each cell one at a time
+    ///
+    /// Like the default implementation, only cells within the preferred row
+    /// and column counts are shifted
+    fn insert_columns<I, C>(&mut self, col: usize, cols: I)
+    where
+        I: IntoIterator<Item = C>,
+        C: IntoIterator<Item = Self::Data>,
+    {
+        let cols: Vec<C> = cols.into_iter().collect();
+        let n = cols.len();
+
+        trace_span!(
+            "insert_columns",
+            col,
+            cols = n,
+            shifted_cells = self.col_cnt.saturating_sub(col) * self.row_cnt
+        );
+
+        if n > 0 && col < self.col_cnt {
+            // Cells hidden right of the table would otherwise show up within
+            // the columns revealed by the shift wherever no cell moves onto
+            // them
+            let (row_cnt, col_cnt) = (self.row_cnt, self.col_cnt);
+            self.cells.retain(|pos, _| {
+                !(pos.col >= col_cnt && pos.col < col_cnt + n && pos.row < row_cnt)
+            });
+
+            self.shift_positions(|pos, _, _| {
+                Some(if pos.col >= col {
+                    Position::new(pos.row, pos.col + n)
+                } else {
+                    pos
+                })
+            });
+            self.col_cnt += n;
+        }
+
+        for (i, cells) in cols.into_iter().enumerate() {
+            for (row, x) in cells.into_iter().enumerate() {
+                self.insert_cell(row, col + i, x);
+            }
+        }
+    }
+
+    /// Rebuilds the map of cells in a single pass with the shifted positions
+    /// rather than moving each cell one at a time
+    ///
+    /// Like the default implementation, only cells within the preferred row
+    /// and column counts are shifted
+    fn shift_rows(&mut self, offset: isize, wrap: bool) {
+        self.shift_positions(|pos, row_cnt, _| {
+            utils::shifted_index(pos.row, row_cnt, offset, wrap).map(|row| Position { row, ..pos })
+        });
+    }
+
+    /// Rebuilds the map of cells in a single pass with the shifted positions
+    /// rather than moving each cell one at a time
+    ///
+    /// Like the default implementation, only cells within the preferred row
+    /// and column counts are shifted
+    fn shift_columns(&mut self, offset: isize, wrap: bool) {
+        self.shift_positions(|pos, _, col_cnt| {
+            utils::shifted_index(pos.col, col_cnt, offset, wrap).map(|col| Position { col, ..pos })
+        });
+    }
+
+    /// Only visits the cells held by the table rather than every position,
+    /// skipping any cell outside of the preferred row and column counts
+    fn occupied_positions(&self) -> OccupiedPositions<'_> {
+        let (row_cnt, col_cnt) = (self.row_cnt, self.col_cnt);
+        std::boxed::Box::new(
+            self.cells
+                .keys()
+                .copied()
+                .filter(move |pos| pos.row < row_cnt && pos.col < col_cnt),
+        )
+    }
+
+    /// Only visits the cells held by the table rather than every position,
+    /// skipping any cell outside of the preferred row and column counts
+    fn any_cell<P: FnMut(Position, &Self::Data) -> bool>(&self, mut f: P) -> bool {
+        self.cells
+            .iter()
+            .filter(|(pos, _)| pos.row < self.row_cnt && pos.col < self.col_cnt)
+            .any(|(pos, x)| f(*pos, x))
+    }
+}
+
+impl<T: HeapSize> MemoryUsage for DynamicTable<T> {
+    /// Estimates memory based on the buckets reserved by the internal map,
+    /// each holding a position, a cell, and a byte of bookkeeping
+    fn memory_usage(&self) -> usize {
+        self.cells.capacity() * (mem::size_of::<(Position, T)>() + 1)
+            + self.cells.values().map(HeapSize::heap_size).sum::<usize>()
+    }
+
+    fn shrink_all(&mut self) {
+        self.cells.shrink_to_fit();
+    }
 }
 
 impl<T: Default, U, const ROW: usize, const COL: usize> PartialEq<[[U; COL]; ROW]>
@@ -226,6 +649,46 @@ impl<T: Default, const ROW: usize, const COL: usize> From<[[T; COL]; ROW]> for D
     }
 }
 
+impl<T> From<Vec<Vec<T>>> for DynamicTable<T> {
+    /// Creates a new table from nested rows of cells, where any row shorter
+    /// than the longest row is missing the cells at its end
+    fn from(rows: Vec<Vec<T>>) -> Self {
+        let mut table = Self::new();
+        let row_cnt = rows.len();
+
+        for (row, cells) in rows.into_iter().enumerate() {
+            for (col, x) in cells.into_iter().enumerate() {
+                table.insert_cell(row, col, x);
+            }
+        }
+
+        // Keep rows that have no cells at all
+        table.set_preferred_row_cnt(row_cnt);
+        table
+    }
+}
+
+impl TryFrom<&str> for DynamicTable<String> {
+    type Error = ShapeError;
+
+    /// Creates a new table of text from a literal of comma-separated values,
+    /// where each non-blank line is a row and surrounding whitespace is
+    /// trimmed from every line and cell, failing if the rows are ragged
+    ///
+    /// Quoting is not supported, so cells cannot contain commas or newlines
+    fn try_from(text: &str) -> Result<Self, Self::Error> {
+        let rows = text
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(|line| line.split(',').map(|x| x.trim().to_string()).collect())
+            .collect();
+        Self::try_from_nested_vec(rows)
+    }
+}
+
+/// Unlike the fixed tables, there is no `Index<usize>` impl for indexing by
+/// row, as the cells of a row are not stored together
 impl<T> Index<(usize, usize)> for DynamicTable<T> {
     type Output = T;
 
@@ -292,10 +755,10 @@ mod tests {
             (1, 0, "c"),
             (1, 1, "d"),
         ]));
-        assert_eq!(table.cell(0, 0).as_deref(), Some(&"a"));
-        assert_eq!(table.cell(0, 1).as_deref(), Some(&"b"));
-        assert_eq!(table.cell(1, 0).as_deref(), Some(&"c"));
-        assert_eq!(table.cell(1, 1).as_deref(), Some(&"d"));
+        assert_eq!(table.cell(0, 0), Some(&"a"));
+        assert_eq!(table.cell(0, 1), Some(&"b"));
+        assert_eq!(table.cell(1, 0), Some(&"c"));
+        assert_eq!(table.cell(1, 1), Some(&"d"));
         assert_eq!(table.cell(1, 2), None);
     }
 
@@ -308,7 +771,7 @@ mod tests {
             (1, 1, "d"),
         ]));
         *table.mut_cell(0, 0).unwrap() = "e";
-        assert_eq!(table.cell(0, 0).as_deref(), Some(&"e"));
+        assert_eq!(table.cell(0, 0), Some(&"e"));
     }
 
     #[test]
@@ -349,7 +812,7 @@ mod tests {
 
         assert!(table.insert_cell(0, 0, "test").is_none());
         assert_eq!(table.insert_cell(0, 0, "other"), Some("test"));
-        assert_eq!(table.cell(0, 0).as_deref(), Some(&"other"))
+        assert_eq!(table.cell(0, 0), Some(&"other"))
     }
 
     #[test]
@@ -493,6 +956,71 @@ mod tests {
         assert_eq!(table, [["g", "h", "i"], ["a", "b", "c"], ["d", "e", "f"]]);
     }
 
+    #[test]
+    fn insert_rows_should_shift_down_sparse_rows_once() {
+        let mut table = DynamicTable::new();
+        table.insert_cell(0, 0, "a");
+        table.insert_cell(1, 1, "b");
+        table.insert_cell(2, 0, "c");
+
+        table.insert_rows(1, vec![vec!["d", "e"], vec!["f", "g"]]);
+
+        assert_eq!((table.row_cnt(), table.col_cnt()), (5, 2));
+        assert_eq!(table.cell(1, 0), Some(&"d"));
+        assert_eq!(table.cell(2, 1), Some(&"g"));
+        assert_eq!(table.cell(3, 1), Some(&"b"));
+        assert_eq!(table.cell(4, 0), Some(&"c"));
+        assert_eq!(table.occupied_positions().count(), 7);
+    }
+
+    #[test]
+    fn insert_rows_should_replace_hidden_cells_below_the_table() {
+        let mut table = DynamicTable::from([[1, 2], [3, 4]]);
+        table.remove_cell(1, 1);
+        table.insert_cell(2, 0, 8);
+        table.insert_cell(2, 1, 9);
+        table.insert_cell(4, 0, 10);
+        table.set_preferred_row_cnt(2);
+
+        table.insert_rows(1, vec![vec![5, 6]].into_iter().filter(|_| true));
+
+        assert_eq!((table.row_cnt(), table.col_cnt()), (3, 2));
+        assert_eq!(table.cell(1, 0), Some(&5));
+        assert_eq!(table.cell(2, 0), Some(&3));
+        assert_eq!(table.cell(2, 1), None);
+
+        table.set_preferred_row_cnt(5);
+        assert_eq!(table.cell(4, 0), Some(&10));
+    }
+
+    #[test]
+    fn insert_columns_should_replace_hidden_cells_right_of_the_table() {
+        let mut table = DynamicTable::from([[1, 2], [3, 4]]);
+        table.remove_cell(1, 1);
+        table.insert_cell(0, 2, 8);
+        table.insert_cell(1, 2, 9);
+        table.set_preferred_col_cnt(2);
+
+        table.insert_columns(1, vec![vec![5, 6]]);
+
+        assert_eq!((table.row_cnt(), table.col_cnt()), (2, 3));
+        assert_eq!(table.cell(0, 1), Some(&5));
+        assert_eq!(table.cell(0, 2), Some(&2));
+        assert_eq!(table.cell(1, 2), None);
+    }
+
+    #[test]
+    fn insert_columns_should_shift_right_all_columns_on_or_after_specified_column() {
+        let mut table = DynamicTable::from([["a", "b", "c"], ["d", "e", "f"]]);
+
+        table.insert_columns(2, vec![vec!["g", "h"], vec!["i", "j"]]);
+
+        assert_eq!(
+            table,
+            [["a", "b", "g", "i", "c"], ["d", "e", "h", "j", "f"]]
+        );
+    }
+
     #[test]
     fn push_row_should_insert_at_end() {
         let mut table = DynamicTable::from([["a", "b", "c"], ["d", "e", "f"]]);
@@ -570,6 +1098,42 @@ mod tests {
         assert_eq!(table, [["a", "b", "c"], ["d", "e", "f"], ["g", "h", "i"]]);
     }
 
+    #[test]
+    fn remove_row_should_only_shift_cells_within_capacity() {
+        let mut table = DynamicTable::from([["a", "b", "c"], ["d", "e", "f"], ["g", "h", "i"]]);
+        table.remove_cell(2, 0);
+        table.set_preferred_col_cnt(2);
+
+        assert_eq!(table.remove_row(0).unwrap(), ["a", "b"]);
+        assert_eq!(table.row_cnt(), 2);
+
+        table.set_preferred_col_cnt(3);
+        assert_eq!(table.cell(0, 0), Some(&"d"));
+        assert_eq!(table.cell(1, 0), None);
+        assert_eq!(table.cell(1, 1), Some(&"h"));
+        assert_eq!(table.cell(0, 2), Some(&"c"));
+        assert_eq!(table.cell(1, 2), Some(&"f"));
+        assert_eq!(table.cell(2, 2), Some(&"i"));
+    }
+
+    #[test]
+    fn shift_should_leave_cells_outside_of_counts_in_place() {
+        let mut table = DynamicTable::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        table.set_preferred_row_cnt(2);
+
+        table.shift_rows(1, true);
+        assert_eq!(table, [[4, 5, 6], [1, 2, 3]]);
+
+        table.shift_columns(-1, false);
+        assert_eq!(table.cell(0, 0), Some(&5));
+        assert_eq!(table.cell(1, 1), Some(&3));
+        assert!(!table.has_cell(0, 2));
+        assert!(!table.has_cell(1, 2));
+
+        table.set_preferred_row_cnt(3);
+        assert_eq!(table.cell(2, 2), Some(&9));
+    }
+
     #[test]
     fn pop_row_should_remove_last_row() {
         let mut table = DynamicTable::from([["a", "b", "c"], ["d", "e", "f"], ["g", "h", "i"]]);
@@ -620,4 +1184,196 @@ mod tests {
 
         assert_eq!(table, [["a", "b"], ["d", "e",], ["g", "h",]]);
     }
+
+    #[test]
+    fn memory_usage_should_include_heap_allocated_by_cells() {
+        let mut table = DynamicTable::new();
+        table.insert_cell(0, 0, std::string::String::with_capacity(100));
+        assert!(table.memory_usage() >= 100);
+    }
+
+    #[test]
+    fn shrink_all_should_release_unused_capacity() {
+        let mut table = DynamicTable::new();
+        for i in 0..100 {
+            table.insert_cell(i, 0, i);
+        }
+        for i in 1..100 {
+            table.remove_cell(i, 0);
+        }
+
+        let before = table.memory_usage();
+        table.shrink_all();
+        assert!(table.memory_usage() < before);
+        assert_eq!(table.cell(0, 0), Some(&0));
+    }
+
+    #[cfg(feature = "serde-1")]
+    #[test]
+    fn should_round_trip_through_serde() {
+        let table = DynamicTable::from([[1, 2], [3, 4]]);
+        let json = serde_json::to_string(&table).unwrap();
+        let new_table: DynamicTable<usize> = serde_json::from_str(&json).unwrap();
+        assert_eq!(new_table, table);
+    }
+
+    #[cfg(feature = "serde-1")]
+    #[test]
+    fn should_support_deserializing_cells_borrowed_from_input() {
+        let json = std::string::String::from(
+            r#"{"cells":[[{"row":0,"col":1},"a"],[{"row":1,"col":0},"b"]],"row_cnt":2,"col_cnt":2}"#,
+        );
+        let table: DynamicTable<&str> = serde_json::from_str(&json).unwrap();
+        assert_eq!(table.cell(0, 1), Some(&"a"));
+        assert_eq!(table.cell(1, 0), Some(&"b"));
+        assert_eq!(table.row_cnt(), 2);
+        assert_eq!(table.col_cnt(), 2);
+
+        // Cells point into the input rather than being copied
+        let range = json.as_ptr() as usize..json.as_ptr() as usize + json.len();
+        assert!(range.contains(&(table[(0, 1)].as_ptr() as usize)));
+    }
+
+    #[test]
+    fn occupied_positions_should_skip_cells_outside_of_preferred_counts() {
+        let mut table = DynamicTable::from([[1, 2], [3, 4]]);
+        table.remove_cell(0, 0);
+        table.set_preferred_col_cnt(1);
+
+        let positions: Vec<Position> = table.occupied_positions().collect();
+        assert_eq!(positions, vec![Position::new(1, 0)]);
+    }
+
+    #[test]
+    fn from_nested_vec_should_leave_end_of_short_rows_missing() {
+        let table = DynamicTable::from(vec![vec![1, 2, 3], vec![4], vec![]]);
+
+        assert_eq!(table.row_cnt(), 3);
+        assert_eq!(table.col_cnt(), 3);
+        assert_eq!(table.cell(0, 2), Some(&3));
+        assert_eq!(table.cell(1, 0), Some(&4));
+        assert_eq!(table.cell(1, 1), None);
+    }
+
+    #[test]
+    fn try_from_nested_vec_should_fail_if_rows_are_ragged() {
+        let result = DynamicTable::try_from_nested_vec(vec![vec![1, 2], vec![3]]);
+        assert_eq!(
+            result,
+            Err(ShapeError::ColumnMismatch {
+                expected: 2,
+                actual: 1,
+            })
+        );
+
+        let table = DynamicTable::try_from_nested_vec(vec![vec![1, 2], vec![3, 4]]).unwrap();
+        assert_eq!(table, [[1, 2], [3, 4]]);
+    }
+
+    #[test]
+    fn split_into_tiles_should_keep_remainder_in_edge_tiles() {
+        let table = DynamicTable::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        let tiles = table.split_into_tiles(2, 2);
+
+        assert_eq!(tiles.row_cnt(), 2);
+        assert_eq!(tiles.col_cnt(), 2);
+        assert_eq!(tiles[(0, 0)], [[1, 2], [4, 5]]);
+        assert_eq!(tiles[(0, 1)], [[3], [6]]);
+        assert_eq!(tiles[(1, 0)], [[7, 8]]);
+        assert_eq!(tiles[(1, 1)], [[9]]);
+    }
+
+    #[test]
+    fn flatten_nested_should_reverse_split_into_tiles() {
+        let mut table = DynamicTable::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+        table.remove_cell(1, 1);
+        table.set_preferred_col_cnt(4);
+
+        let flattened = table.clone().split_into_tiles(2, 3).flatten_nested();
+        assert_eq!(flattened, Ok(table));
+    }
+
+    #[test]
+    fn split_into_tiles_should_support_tiles_as_large_as_possible() {
+        let table = DynamicTable::from([[1, 2, 3], [4, 5, 6]]);
+        let tiles = table.split_into_tiles(1, usize::MAX);
+
+        assert_eq!(tiles.row_cnt(), 2);
+        assert_eq!(tiles.col_cnt(), 1);
+        assert_eq!(tiles[(0, 0)], [[1, 2, 3]]);
+        assert_eq!(tiles[(1, 0)], [[4, 5, 6]]);
+    }
+
+    #[test]
+    fn flatten_nested_should_leave_cells_of_missing_tiles_missing() {
+        let mut tiles =
+            DynamicTable::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]).split_into_tiles(2, 2);
+        tiles.remove_cell(0, 0);
+
+        let mut table = DynamicTable::from([[0, 0, 3], [0, 0, 6], [7, 8, 9]]);
+        for pos in [(0, 0), (0, 1), (1, 0), (1, 1)] {
+            table.remove_cell(pos.0, pos.1);
+        }
+        assert_eq!(tiles.flatten_nested(), Ok(table));
+    }
+
+    #[test]
+    fn flatten_nested_should_fail_if_tiles_do_not_line_up() {
+        let mut tiles = DynamicTable::new();
+        tiles.push_row(vec![
+            DynamicTable::from([[1, 2], [3, 4]]),
+            DynamicTable::from([[5, 6]]),
+        ]);
+        assert_eq!(
+            tiles.flatten_nested(),
+            Err(ShapeError::RowMismatch {
+                expected: 2,
+                actual: 1,
+            })
+        );
+
+        let mut tiles = DynamicTable::new();
+        tiles.push_column(vec![
+            DynamicTable::from([[1, 2]]),
+            DynamicTable::from([[3]]),
+        ]);
+        assert_eq!(
+            tiles.flatten_nested(),
+            Err(ShapeError::ColumnMismatch {
+                expected: 2,
+                actual: 1,
+            })
+        );
+    }
+
+    #[test]
+    fn from_nested_vec_padded_should_fill_end_of_short_rows() {
+        let table = DynamicTable::from_nested_vec_padded(vec![vec![1, 2], vec![3]], 0);
+        assert_eq!(table, [[1, 2], [3, 0]]);
+    }
+
+    #[test]
+    fn into_nested_vec_should_fill_missing_cells_with_default() {
+        let mut table = DynamicTable::from([[1, 2], [3, 4]]);
+        table.remove_cell(0, 1);
+
+        assert_eq!(table.into_nested_vec(), vec![vec![1, 0], vec![3, 4]]);
+    }
+
+    #[test]
+    fn try_from_str_should_parse_comma_separated_rows() {
+        let table = DynamicTable::try_from("name, age\n  alice,30\r\n\nbob , 4\n").unwrap();
+        assert_eq!(table, [["name", "age"], ["alice", "30"], ["bob", "4"]]);
+    }
+
+    #[test]
+    fn try_from_str_should_fail_if_rows_are_ragged() {
+        assert_eq!(
+            DynamicTable::try_from("a,b\n1"),
+            Err(ShapeError::ColumnMismatch {
+                expected: 2,
+                actual: 1,
+            })
+        );
+    }
 }
@@ -1,6 +1,7 @@
-use crate::{iter::*, list::*, utils, Capacity, Position, Table};
+use crate::{iter::*, list::*, memory::*, utils, view::FixedTableView, Capacity, Position, Table};
 use core::{
     cmp,
+    convert::TryFrom,
     iter::FromIterator,
     mem,
     ops::{Index, IndexMut},
@@ -43,20 +44,54 @@ impl<T: Default, const ROW: usize, const COL: usize> FixedTable<T, ROW, COL> {
 
     /// Removes all cells contained within the table that are outside the
     /// current row & column capacity
+    ///
+    /// Same as [`Table::truncate`], available without importing the trait
     pub fn truncate(&mut self) {
-        // Go through all cells NOT part of our virtual space and fill them
-        // in with the default value
-        for row in self.row_cnt..ROW {
-            for col in self.col_cnt..COL {
-                self.cells[row][col] = T::default();
-            }
-        }
+        <Self as Table>::truncate(self)
     }
 
     /// Returns an iterator over the cells and their positions within the table
-    pub fn iter(&self) -> ZipPosition<&T, Cells<T, FixedTable<T, ROW, COL>>> {
+    pub fn iter(&self) -> ZipPosition<&T, Cells<'_, T, FixedTable<T, ROW, COL>>> {
         self.into_iter()
     }
+
+    /// Returns an iterator over the rows within the current row capacity as
+    /// the underlying arrays, avoiding the per-cell lookups of [`Table::row`]
+    ///
+    /// Each array spans all `COL` columns, including those outside of the
+    /// current column capacity; call [`Table::truncate`] first to reset them
+    pub fn rows_as_arrays(&self) -> impl Iterator<Item = &[T; COL]> {
+        self.cells[..self.row_cnt].iter()
+    }
+
+    /// Consumes the table, returning an iterator over the rows within the
+    /// current row capacity as the underlying arrays
+    ///
+    /// Like [`Self::rows_as_arrays`], each array spans all `COL` columns
+    pub fn into_rows_as_arrays(self) -> impl Iterator<Item = [T; COL]> {
+        let mut cells = self.cells;
+        (0..self.row_cnt).map(move |row| mem::replace(&mut cells[row], utils::default_array()))
+    }
+
+    /// Returns a view of the `R2`x`C2` block of cells whose top-left cell is
+    /// at the specified row and column, or `None` if the block does not fit
+    /// within the current row & column capacity
+    pub fn block<const R2: usize, const C2: usize>(
+        &self,
+        top: usize,
+        left: usize,
+    ) -> Option<FixedTableView<'_, T, R2, C2>> {
+        if top.checked_add(R2)? > self.row_cnt || left.checked_add(C2)? > self.col_cnt {
+            return None;
+        }
+
+        let rows = utils::try_make_array(|row| {
+            <&[T; C2]>::try_from(&self.cells[top + row][left..left + C2])
+        })
+        .ok()?;
+
+        Some(FixedTableView::new(rows))
+    }
 }
 
 impl<T: Default, const ROW: usize, const COL: usize> Default for FixedTable<T, ROW, COL> {
@@ -71,6 +106,19 @@ impl<T: Default, const ROW: usize, const COL: usize> Default for FixedTable<T, R
     }
 }
 
+impl<T: Default + HeapSize, const ROW: usize, const COL: usize> MemoryUsage
+    for FixedTable<T, ROW, COL>
+{
+    /// Cells are stored inline, so only memory allocated by the cells
+    /// themselves is counted
+    fn memory_usage(&self) -> usize {
+        self.cells.heap_size()
+    }
+
+    /// Does nothing as the table's storage is fixed in size
+    fn shrink_all(&mut self) {}
+}
+
 impl<T: Default, const ROW: usize, const COL: usize> Table for FixedTable<T, ROW, COL> {
     type Data = T;
     type Row = FixedList<Self::Data, COL>;
@@ -148,6 +196,36 @@ impl<T: Default, const ROW: usize, const COL: usize> Table for FixedTable<T, ROW
         }
     }
 
+    /// Swaps the cells in place when both are within the current row &
+    /// column capacity
+    fn swap_cells(&mut self, a: Position, b: Position) {
+        if self.has_cell(a.row, a.col) && self.has_cell(b.row, b.col) {
+            utils::swap_in_rows(&mut self.cells, a, b);
+        } else {
+            utils::swap_cells_by_removal(self, a, b);
+        }
+    }
+
+    /// Shifts the rows as whole arrays rather than cell-by-cell, resetting
+    /// vacant rows to their default value
+    fn shift_rows(&mut self, offset: isize, wrap: bool) {
+        utils::shift_slice(
+            &mut self.cells[..self.row_cnt],
+            offset,
+            wrap,
+            utils::default_array,
+        );
+    }
+
+    /// Shifts the columns within each row's array rather than cell-by-cell,
+    /// resetting vacant cells to their default value
+    fn shift_columns(&mut self, offset: isize, wrap: bool) {
+        let col_cnt = self.col_cnt;
+        for row in self.cells[..self.row_cnt].iter_mut() {
+            utils::shift_slice(&mut row[..col_cnt], offset, wrap, T::default);
+        }
+    }
+
     /// Will adjust the internal row count tracker to the specified capacity,
     /// capping at ROW.
     ///
@@ -173,6 +251,19 @@ impl<T: Default, const ROW: usize, const COL: usize> Table for FixedTable<T, ROW
     fn set_preferred_col_cnt(&mut self, cnt: usize) {
         self.col_cnt = cmp::min(cnt, COL);
     }
+
+    /// Resets all cells outside of the current row & column capacity to
+    /// their default value
+    fn truncate(&mut self) {
+        for (row, cells) in self.cells.iter_mut().enumerate() {
+            // Rows past our virtual space are reset entirely, otherwise only
+            // the columns past it are
+            let start = if row < self.row_cnt { self.col_cnt } else { 0 };
+            for cell in cells[start..].iter_mut() {
+                *cell = T::default();
+            }
+        }
+    }
 }
 
 impl<
@@ -287,6 +378,28 @@ impl<T: Default, const ROW: usize, const COL: usize> IndexMut<(usize, usize)>
     }
 }
 
+impl<T: Default, const ROW: usize, const COL: usize> Index<usize> for FixedTable<T, ROW, COL> {
+    type Output = [T];
+
+    /// Indexes into a table by a specific row, returning a reference to the
+    /// cells of the row that can be further indexed by column, otherwise
+    /// panicking
+    fn index(&self, row: usize) -> &Self::Output {
+        assert!(row < self.row_cnt, "Row index out of range");
+        &self.cells[row][..self.col_cnt]
+    }
+}
+
+impl<T: Default, const ROW: usize, const COL: usize> IndexMut<usize> for FixedTable<T, ROW, COL> {
+    /// Indexes into a table by a specific row, returning a mutable reference
+    /// to the cells of the row that can be further indexed by column,
+    /// otherwise panicking
+    fn index_mut(&mut self, row: usize) -> &mut Self::Output {
+        assert!(row < self.row_cnt, "Row index out of range");
+        &mut self.cells[row][..self.col_cnt]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -328,10 +441,10 @@ mod tests {
     fn cell_should_return_ref_to_cell_at_location() {
         // Sets capacity to that of the 2D array provided
         let table = FixedTable::from([["a", "b"], ["c", "d"]]);
-        assert_eq!(table.cell(0, 0).as_deref(), Some(&"a"));
-        assert_eq!(table.cell(0, 1).as_deref(), Some(&"b"));
-        assert_eq!(table.cell(1, 0).as_deref(), Some(&"c"));
-        assert_eq!(table.cell(1, 1).as_deref(), Some(&"d"));
+        assert_eq!(table.cell(0, 0), Some(&"a"));
+        assert_eq!(table.cell(0, 1), Some(&"b"));
+        assert_eq!(table.cell(1, 0), Some(&"c"));
+        assert_eq!(table.cell(1, 1), Some(&"d"));
         assert_eq!(table.cell(1, 2), None);
     }
 
@@ -345,18 +458,18 @@ mod tests {
         // If we change the capacity to be smaller, cell should respect that
         table.set_preferred_row_cnt(1);
         table.set_preferred_col_cnt(1);
-        assert_eq!(table.cell(0, 0).as_deref(), Some(&"a"));
-        assert_eq!(table.cell(0, 1).as_deref(), None);
-        assert_eq!(table.cell(1, 0).as_deref(), None);
-        assert_eq!(table.cell(1, 1).as_deref(), None);
+        assert_eq!(table.cell(0, 0), Some(&"a"));
+        assert_eq!(table.cell(0, 1), None);
+        assert_eq!(table.cell(1, 0), None);
+        assert_eq!(table.cell(1, 1), None);
 
         // Capacity changes don't actually overwrite anything
         table.set_preferred_row_cnt(2);
         table.set_preferred_col_cnt(2);
-        assert_eq!(table.cell(0, 0).as_deref(), Some(&"a"));
-        assert_eq!(table.cell(0, 1).as_deref(), Some(&"b"));
-        assert_eq!(table.cell(1, 0).as_deref(), Some(&"c"));
-        assert_eq!(table.cell(1, 1).as_deref(), Some(&"d"));
+        assert_eq!(table.cell(0, 0), Some(&"a"));
+        assert_eq!(table.cell(0, 1), Some(&"b"));
+        assert_eq!(table.cell(1, 0), Some(&"c"));
+        assert_eq!(table.cell(1, 1), Some(&"d"));
     }
 
     #[test]
@@ -368,10 +481,10 @@ mod tests {
         *table.mut_cell(1, 1).unwrap() = "h";
         assert_eq!(table.mut_cell(2, 0), None);
 
-        assert_eq!(table.cell(0, 0).as_deref(), Some(&"e"));
-        assert_eq!(table.cell(0, 1).as_deref(), Some(&"f"));
-        assert_eq!(table.cell(1, 0).as_deref(), Some(&"g"));
-        assert_eq!(table.cell(1, 1).as_deref(), Some(&"h"));
+        assert_eq!(table.cell(0, 0), Some(&"e"));
+        assert_eq!(table.cell(0, 1), Some(&"f"));
+        assert_eq!(table.cell(1, 0), Some(&"g"));
+        assert_eq!(table.cell(1, 1), Some(&"h"));
     }
 
     #[test]
@@ -395,7 +508,7 @@ mod tests {
 
         assert_eq!(table.insert_cell(0, 0, 123), None);
         assert_eq!(table.insert_cell(0, 0, 999), Some(123));
-        assert_eq!(table.cell(0, 0).as_deref(), Some(&999))
+        assert_eq!(table.cell(0, 0), Some(&999))
     }
 
     #[test]
@@ -471,6 +584,31 @@ mod tests {
         let _ = table[(1, 0)];
     }
 
+    #[test]
+    fn index_by_row_should_return_cells_of_row() {
+        let mut table = FixedTable::from([[1, 2, 3], [4, 5, 6]]);
+        table.set_preferred_col_cnt(2);
+
+        assert_eq!(table[1], [4, 5]);
+        assert_eq!(table[1][0], 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_by_row_should_panic_if_row_not_found() {
+        let table = FixedTable::from([[1, 2, 3], [4, 5, 6]]);
+        let _ = &table[2];
+    }
+
+    #[test]
+    fn index_mut_by_row_should_return_mutable_cells_of_row() {
+        let mut table = FixedTable::from([[1, 2, 3], [4, 5, 6]]);
+        table[0][1] = 999;
+        table[1].swap(0, 2);
+
+        assert_eq!(table, [[1, 999, 3], [6, 5, 4]]);
+    }
+
     #[test]
     fn index_mut_by_row_and_column_should_return_mutable_cell() {
         let mut table = FixedTable::from([[1, 2, 3]]);
@@ -652,6 +790,77 @@ mod tests {
         assert_eq!(table, [["a", "b", "c"], ["d", "e", "f"], ["g", "h", "i"]]);
     }
 
+    #[test]
+    fn truncate_should_reset_cells_outside_of_row_and_column_capacity_counts() {
+        let mut table = FixedTable::from([["a", "b", "c"], ["d", "e", "f"], ["g", "h", "i"]]);
+        table.set_preferred_row_cnt(2);
+        table.set_preferred_col_cnt(2);
+        table.truncate();
+
+        table.set_preferred_row_cnt(3);
+        table.set_preferred_col_cnt(3);
+        assert_eq!(table, [["a", "b", ""], ["d", "e", ""], ["", "", ""]]);
+    }
+
+    #[test]
+    fn rows_as_arrays_should_yield_rows_within_row_capacity() {
+        let mut table = FixedTable::from([["a", "b"], ["c", "d"], ["e", "f"]]);
+        table.set_preferred_row_cnt(2);
+
+        {
+            let mut rows = table.rows_as_arrays();
+            assert_eq!(rows.next(), Some(&["a", "b"]));
+            assert_eq!(rows.next(), Some(&["c", "d"]));
+            assert_eq!(rows.next(), None);
+        }
+
+        let mut rows = table.into_rows_as_arrays();
+        assert_eq!(rows.next(), Some(["a", "b"]));
+        assert_eq!(rows.next(), Some(["c", "d"]));
+        assert_eq!(rows.next(), None);
+    }
+
+    #[test]
+    fn block_should_return_view_of_cells_within_capacity() {
+        let mut table = FixedTable::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+
+        let block = table.block::<2, 2>(1, 1).unwrap();
+        assert_eq!(block.rows(), [&[5, 6], &[8, 9]]);
+        assert!(table.block::<3, 3>(0, 0).is_some());
+        assert!(table.block::<2, 2>(2, 0).is_none());
+        assert!(table.block::<1, 1>(0, usize::MAX).is_none());
+
+        table.set_preferred_col_cnt(2);
+        assert!(table.block::<1, 3>(0, 0).is_none());
+    }
+
+    #[test]
+    fn swap_cells_should_swap_cells_within_and_across_rows() {
+        let mut table = FixedTable::from([["a", "b", "c"], ["d", "e", "f"]]);
+
+        table.swap_cells(Position::new(0, 0), Position::new(0, 2));
+        table.swap_cells(Position::new(1, 1), Position::new(0, 1));
+        assert_eq!(table, [["c", "e", "a"], ["d", "b", "f"]]);
+
+        // Positions outside of the actual capacity are left alone
+        table.swap_cells(Position::new(0, 0), Position::new(2, 0));
+        assert_eq!(table, [["c", "e", "a"], ["d", "b", "f"]]);
+    }
+
+    #[test]
+    fn shift_should_reset_vacant_cells_unless_wrapping() {
+        let mut table = FixedTable::from([["a", "b", "c"], ["d", "e", "f"], ["g", "h", "i"]]);
+
+        table.shift_rows(-4, true);
+        assert_eq!(table, [["d", "e", "f"], ["g", "h", "i"], ["a", "b", "c"]]);
+
+        table.shift_columns(1, false);
+        assert_eq!(table, [["", "d", "e"], ["", "g", "h"], ["", "a", "b"]]);
+
+        table.shift_rows(5, false);
+        assert_eq!(table, [["", "", ""], ["", "", ""], ["", "", ""]]);
+    }
+
     #[test]
     fn pop_row_should_remove_last_row() {
         let mut table = FixedTable::from([["a", "b", "c"], ["d", "e", "f"], ["g", "h", "i"]]);
@@ -702,4 +911,14 @@ mod tests {
 
         assert_eq!(table, [["a", "b"], ["d", "e"], ["g", "h"]]);
     }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[test]
+    fn memory_usage_should_only_include_heap_allocated_by_cells() {
+        let mut table: FixedTable<Option<std::string::String>, 2, 2> = FixedTable::new();
+        assert_eq!(table.memory_usage(), 0);
+
+        table.insert_cell(1, 1, Some(std::string::String::with_capacity(10)));
+        assert_eq!(table.memory_usage(), 10);
+    }
 }
@@ -1,3 +1,8 @@
+#[cfg(any(feature = "alloc", feature = "std"))]
+mod cow;
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub use cow::{CowTable, TableSnapshot};
+
 #[cfg(any(feature = "alloc", feature = "std"))]
 mod dynamic;
 #[cfg(any(feature = "alloc", feature = "std"))]
@@ -15,3 +20,10 @@ pub use col::FixedColumnTable;
 mod row;
 #[cfg(any(feature = "alloc", feature = "std"))]
 pub use row::FixedRowTable;
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+mod small;
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub use small::SmallTable;
+
+mod ptr;
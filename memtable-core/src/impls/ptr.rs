@@ -0,0 +1,172 @@
+use crate::{Capacity, Position, Table};
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+use crate::iter::OccupiedPositions;
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+use std::boxed::Box;
+
+/// Implements the table trait for a pointer to a table by forwarding every
+/// method, including the defaults that tables are known to override, so the
+/// pointer behaves exactly like the table it points to
+macro_rules! impl_table_for_ptr {
+    ($($ptr:tt)*) => {
+        impl<T: Table> Table for $($ptr)* {
+            type Data = T::Data;
+            type Row = T::Row;
+            type Column = T::Column;
+
+            fn max_row_capacity(&self) -> Capacity {
+                (**self).max_row_capacity()
+            }
+
+            fn max_column_capacity(&self) -> Capacity {
+                (**self).max_column_capacity()
+            }
+
+            fn row_cnt(&self) -> usize {
+                (**self).row_cnt()
+            }
+
+            fn col_cnt(&self) -> usize {
+                (**self).col_cnt()
+            }
+
+            fn set_preferred_row_cnt(&mut self, cnt: usize) {
+                (**self).set_preferred_row_cnt(cnt)
+            }
+
+            fn set_preferred_col_cnt(&mut self, cnt: usize) {
+                (**self).set_preferred_col_cnt(cnt)
+            }
+
+            fn truncate(&mut self) {
+                (**self).truncate()
+            }
+
+            fn shrink_to_fit(&mut self) {
+                (**self).shrink_to_fit()
+            }
+
+            fn cell(&self, row: usize, col: usize) -> Option<&Self::Data> {
+                (**self).cell(row, col)
+            }
+
+            fn mut_cell(&mut self, row: usize, col: usize) -> Option<&mut Self::Data> {
+                (**self).mut_cell(row, col)
+            }
+
+            fn insert_cell(
+                &mut self,
+                row: usize,
+                col: usize,
+                value: Self::Data,
+            ) -> Option<Self::Data> {
+                (**self).insert_cell(row, col, value)
+            }
+
+            fn remove_cell(&mut self, row: usize, col: usize) -> Option<Self::Data> {
+                (**self).remove_cell(row, col)
+            }
+
+            fn swap_cells(&mut self, a: Position, b: Position) {
+                (**self).swap_cells(a, b)
+            }
+
+            fn move_cell(&mut self, from: Position, to: Position) -> Option<Self::Data> {
+                (**self).move_cell(from, to)
+            }
+
+            fn shift_rows(&mut self, offset: isize, wrap: bool) {
+                (**self).shift_rows(offset, wrap)
+            }
+
+            fn shift_columns(&mut self, offset: isize, wrap: bool) {
+                (**self).shift_columns(offset, wrap)
+            }
+
+            #[cfg(any(feature = "alloc", feature = "std"))]
+            fn occupied_positions(&self) -> OccupiedPositions<'_> {
+                (**self).occupied_positions()
+            }
+
+            fn any_cell<P: FnMut(Position, &Self::Data) -> bool>(&self, f: P) -> bool {
+                (**self).any_cell(f)
+            }
+
+            fn insert_row<I: IntoIterator<Item = Self::Data>>(&mut self, row: usize, cells: I) {
+                (**self).insert_row(row, cells)
+            }
+
+            fn insert_rows<I, R>(&mut self, row: usize, rows: I)
+            where
+                I: IntoIterator<Item = R>,
+                R: IntoIterator<Item = Self::Data>,
+            {
+                (**self).insert_rows(row, rows)
+            }
+
+            fn remove_row(&mut self, row: usize) -> Option<Self::Row> {
+                (**self).remove_row(row)
+            }
+
+            fn insert_column<I: IntoIterator<Item = Self::Data>>(
+                &mut self,
+                col: usize,
+                cells: I,
+            ) {
+                (**self).insert_column(col, cells)
+            }
+
+            fn insert_columns<I, C>(&mut self, col: usize, cols: I)
+            where
+                I: IntoIterator<Item = C>,
+                C: IntoIterator<Item = Self::Data>,
+            {
+                (**self).insert_columns(col, cols)
+            }
+
+            fn remove_column(&mut self, col: usize) -> Option<Self::Column> {
+                (**self).remove_column(col)
+            }
+        }
+    };
+}
+
+impl_table_for_ptr!(&mut T);
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl_table_for_ptr!(Box<T>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FixedTable;
+
+    fn push_sum_row<T: Table<Data = usize>>(mut table: T) {
+        let sum = table.cells().sum::<usize>();
+        let col_cnt = table.col_cnt();
+        table.push_row((0..col_cnt).map(|_| sum));
+    }
+
+    #[test]
+    fn table_should_be_usable_through_mutable_reference() {
+        let mut table = FixedTable::from([[1, 2], [3, 4], [0, 0]]);
+        table.set_preferred_row_cnt(2);
+
+        push_sum_row(&mut table);
+        assert_eq!(table, [[1, 2], [3, 4], [10, 10]]);
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[test]
+    fn table_should_be_usable_through_box() {
+        let mut table = Box::new(crate::DynamicTable::from([[1, 2], [3, 4]]));
+
+        push_sum_row(&mut table);
+        assert_eq!(*table, [[1, 2], [3, 4], [10, 10]]);
+
+        assert_eq!(table.remove_row(0).unwrap(), [1, 2]);
+        assert_eq!(table.row_cnt(), 2);
+    }
+}
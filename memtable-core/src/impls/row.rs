@@ -1,4 +1,4 @@
-use crate::{iter::*, list::*, utils, Capacity, Position, Table};
+use crate::{iter::*, list::*, memory::*, utils, Capacity, Position, Table};
 use core::{
     cmp,
     iter::FromIterator,
@@ -46,22 +46,14 @@ impl<T: Default, const ROW: usize> FixedRowTable<T, ROW> {
 
     /// Removes all cells contained within the table that are outside the
     /// current column capacity
+    ///
+    /// Same as [`Table::truncate`], available without importing the trait
     pub fn truncate(&mut self) {
-        let col_cnt = self.col_cnt;
-
-        // Shrink each row down to the right size
-        self.cells.iter_mut().for_each(|x| x.truncate(col_cnt));
-
-        // Now go through each column and re-assign rows to default values
-        for row in self.cells[self.row_cnt..ROW].iter_mut() {
-            for cell in row {
-                *cell = T::default();
-            }
-        }
+        <Self as Table>::truncate(self)
     }
 
     /// Returns an iterator over the cells and their positions within the table
-    pub fn iter(&self) -> ZipPosition<&T, Cells<T, FixedRowTable<T, ROW>>> {
+    pub fn iter(&self) -> ZipPosition<&T, Cells<'_, T, FixedRowTable<T, ROW>>> {
         self.into_iter()
     }
 }
@@ -78,6 +70,16 @@ impl<T: Default, const ROW: usize> Default for FixedRowTable<T, ROW> {
     }
 }
 
+impl<T: Default + HeapSize, const ROW: usize> MemoryUsage for FixedRowTable<T, ROW> {
+    fn memory_usage(&self) -> usize {
+        self.cells.heap_size()
+    }
+
+    fn shrink_all(&mut self) {
+        self.cells.iter_mut().for_each(Vec::shrink_to_fit);
+    }
+}
+
 impl<T: Default, const ROW: usize> Table for FixedRowTable<T, ROW> {
     type Data = T;
     type Row = DynamicList<Self::Data>;
@@ -100,8 +102,10 @@ impl<T: Default, const ROW: usize> Table for FixedRowTable<T, ROW> {
     }
 
     fn cell(&self, row: usize, col: usize) -> Option<&Self::Data> {
+        // NOTE: Rows are not grown to match when another row grows the
+        //       col_cnt, so the row itself may be shorter than col_cnt
         if row < self.row_cnt && col < self.col_cnt {
-            Some(&self.cells[row][col])
+            self.cells[row].get(col)
         } else {
             None
         }
@@ -109,7 +113,7 @@ impl<T: Default, const ROW: usize> Table for FixedRowTable<T, ROW> {
 
     fn mut_cell(&mut self, row: usize, col: usize) -> Option<&mut Self::Data> {
         if row < self.row_cnt && col < self.col_cnt {
-            Some(&mut self.cells[row][col])
+            self.cells[row].get_mut(col)
         } else {
             None
         }
@@ -164,6 +168,22 @@ impl<T: Default, const ROW: usize> Table for FixedRowTable<T, ROW> {
         }
     }
 
+    /// Swaps the cells in place when both are within the current row &
+    /// column capacity
+    fn swap_cells(&mut self, a: Position, b: Position) {
+        if self.has_cell(a.row, a.col) && self.has_cell(b.row, b.col) {
+            utils::swap_in_rows(&mut self.cells, a, b);
+        } else {
+            utils::swap_cells_by_removal(self, a, b);
+        }
+    }
+
+    /// Shifts the rows by swapping their underlying vecs rather than moving
+    /// them cell-by-cell, clearing vacant rows
+    fn shift_rows(&mut self, offset: isize, wrap: bool) {
+        utils::shift_slice(&mut self.cells[..self.row_cnt], offset, wrap, Vec::new);
+    }
+
     /// Will adjust the internal row count tracker to the specified capacity,
     /// capping at ROW.
     ///
@@ -188,6 +208,22 @@ impl<T: Default, const ROW: usize> Table for FixedRowTable<T, ROW> {
     fn set_preferred_col_cnt(&mut self, cnt: usize) {
         self.col_cnt = cnt;
     }
+
+    /// Removes all columns outside of the current column capacity and resets
+    /// all cells outside of the current row capacity to their default value
+    fn truncate(&mut self) {
+        let col_cnt = self.col_cnt;
+
+        // Shrink each row down to the right size
+        self.cells.iter_mut().for_each(|x| x.truncate(col_cnt));
+
+        // Now go through each column and re-assign rows to default values
+        for row in self.cells[self.row_cnt..ROW].iter_mut() {
+            for cell in row {
+                *cell = T::default();
+            }
+        }
+    }
 }
 
 impl<T: Default, U, const T_ROW: usize, const U_ROW: usize, const U_COL: usize>
@@ -307,6 +343,36 @@ impl<T: Default, const ROW: usize> IndexMut<(usize, usize)> for FixedRowTable<T,
     }
 }
 
+impl<T: Default, const ROW: usize> Index<usize> for FixedRowTable<T, ROW> {
+    type Output = [T];
+
+    /// Indexes into a table by a specific row, returning a reference to the
+    /// cells of the row that can be further indexed by column, otherwise
+    /// panicking
+    ///
+    /// Rows are not grown when another row adds a column, so the row only
+    /// includes its existing cells and may be shorter than the column count
+    fn index(&self, row: usize) -> &Self::Output {
+        assert!(row < self.row_cnt, "Row index out of range");
+        let len = self.cells[row].len().min(self.col_cnt);
+        &self.cells[row][..len]
+    }
+}
+
+impl<T: Default, const ROW: usize> IndexMut<usize> for FixedRowTable<T, ROW> {
+    /// Indexes into a table by a specific row, returning a mutable reference
+    /// to the cells of the row that can be further indexed by column,
+    /// otherwise panicking
+    ///
+    /// Rows are not grown when another row adds a column, so the row only
+    /// includes its existing cells and may be shorter than the column count
+    fn index_mut(&mut self, row: usize) -> &mut Self::Output {
+        assert!(row < self.row_cnt, "Row index out of range");
+        let len = self.cells[row].len().min(self.col_cnt);
+        &mut self.cells[row][..len]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -349,10 +415,10 @@ mod tests {
     fn cell_should_return_ref_to_cell_at_location() {
         // Sets capacity to that of the 2D array provided
         let table = FixedRowTable::from([vec!["a", "b"], vec!["c", "d"]]);
-        assert_eq!(table.cell(0, 0).as_deref(), Some(&"a"));
-        assert_eq!(table.cell(0, 1).as_deref(), Some(&"b"));
-        assert_eq!(table.cell(1, 0).as_deref(), Some(&"c"));
-        assert_eq!(table.cell(1, 1).as_deref(), Some(&"d"));
+        assert_eq!(table.cell(0, 0), Some(&"a"));
+        assert_eq!(table.cell(0, 1), Some(&"b"));
+        assert_eq!(table.cell(1, 0), Some(&"c"));
+        assert_eq!(table.cell(1, 1), Some(&"d"));
         assert_eq!(table.cell(1, 2), None);
     }
 
@@ -366,18 +432,18 @@ mod tests {
         // If we change the capacity to be smaller, cell should respect that
         table.set_preferred_row_cnt(1);
         table.set_preferred_col_cnt(1);
-        assert_eq!(table.cell(0, 0).as_deref(), Some(&"a"));
-        assert_eq!(table.cell(0, 1).as_deref(), None);
-        assert_eq!(table.cell(1, 0).as_deref(), None);
-        assert_eq!(table.cell(1, 1).as_deref(), None);
+        assert_eq!(table.cell(0, 0), Some(&"a"));
+        assert_eq!(table.cell(0, 1), None);
+        assert_eq!(table.cell(1, 0), None);
+        assert_eq!(table.cell(1, 1), None);
 
         // Capacity changes don't actually overwrite anything
         table.set_preferred_row_cnt(2);
         table.set_preferred_col_cnt(2);
-        assert_eq!(table.cell(0, 0).as_deref(), Some(&"a"));
-        assert_eq!(table.cell(0, 1).as_deref(), Some(&"b"));
-        assert_eq!(table.cell(1, 0).as_deref(), Some(&"c"));
-        assert_eq!(table.cell(1, 1).as_deref(), Some(&"d"));
+        assert_eq!(table.cell(0, 0), Some(&"a"));
+        assert_eq!(table.cell(0, 1), Some(&"b"));
+        assert_eq!(table.cell(1, 0), Some(&"c"));
+        assert_eq!(table.cell(1, 1), Some(&"d"));
     }
 
     #[test]
@@ -389,10 +455,10 @@ mod tests {
         *table.mut_cell(1, 1).unwrap() = "h";
         assert_eq!(table.mut_cell(2, 0), None);
 
-        assert_eq!(table.cell(0, 0).as_deref(), Some(&"e"));
-        assert_eq!(table.cell(0, 1).as_deref(), Some(&"f"));
-        assert_eq!(table.cell(1, 0).as_deref(), Some(&"g"));
-        assert_eq!(table.cell(1, 1).as_deref(), Some(&"h"));
+        assert_eq!(table.cell(0, 0), Some(&"e"));
+        assert_eq!(table.cell(0, 1), Some(&"f"));
+        assert_eq!(table.cell(1, 0), Some(&"g"));
+        assert_eq!(table.cell(1, 1), Some(&"h"));
     }
 
     #[test]
@@ -416,7 +482,7 @@ mod tests {
 
         assert_eq!(table.insert_cell(0, 0, 123), None);
         assert_eq!(table.insert_cell(0, 0, 999), Some(123));
-        assert_eq!(table.cell(0, 0).as_deref(), Some(&999))
+        assert_eq!(table.cell(0, 0), Some(&999))
     }
 
     #[test]
@@ -496,6 +562,36 @@ mod tests {
         let _ = table[(1, 0)];
     }
 
+    #[test]
+    fn index_by_row_should_return_existing_cells_of_row() {
+        let mut table = FixedRowTable::from([vec![1, 2, 3], vec![4, 5, 6]]);
+        table.insert_cell(0, 3, 7);
+
+        assert_eq!(table[0], [1, 2, 3, 7]);
+        assert_eq!(table[1], [4, 5, 6]);
+        assert_eq!(table[1][0], 4);
+
+        table.set_preferred_col_cnt(2);
+        assert_eq!(table[0], [1, 2]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_by_row_should_panic_if_row_not_found() {
+        let table = FixedRowTable::from([vec![1, 2, 3]]);
+        let _ = &table[1];
+    }
+
+    #[test]
+    fn index_mut_by_row_should_return_mutable_cells_of_row() {
+        let mut table = FixedRowTable::from([vec![1, 2, 3], vec![4, 5, 6]]);
+        table[0][1] = 999;
+        table[1].swap(0, 2);
+
+        assert_eq!(table[0], [1, 999, 3]);
+        assert_eq!(table[1], [6, 5, 4]);
+    }
+
     #[test]
     fn index_mut_by_row_and_column_should_return_mutable_cell() {
         let mut table = FixedRowTable::from([vec![1, 2, 3]]);
@@ -653,6 +749,21 @@ mod tests {
         assert_eq!(table, [["a", "b", "c"], ["d", "e", "f"], ["g", "h", "i"]]);
     }
 
+    #[test]
+    fn shift_should_support_rows_shorter_than_column_count() {
+        let mut table: FixedRowTable<&str, 2> = FixedRowTable::new();
+        table.insert_cell(0, 0, "a");
+        table.insert_cell(1, 2, "b");
+
+        table.shift_columns(1, true);
+        assert_eq!(table, [["", "a", ""], ["b", "", ""]]);
+
+        table.shift_rows(-1, false);
+        assert_eq!(table.cell(0, 0), Some(&"b"));
+        assert!(!table.has_cell(1, 0));
+        assert_eq!(table.row_cnt(), 2);
+    }
+
     #[test]
     fn pop_row_should_remove_last_row() {
         let mut table = FixedRowTable::from([["a", "b", "c"], ["d", "e", "f"], ["g", "h", "i"]]);
@@ -703,4 +814,17 @@ mod tests {
 
         assert_eq!(table, [["a", "b"], ["d", "e"], ["g", "h"]]);
     }
+
+    #[test]
+    fn shrink_all_should_release_unused_capacity() {
+        let mut table: FixedRowTable<usize, 2> = FixedRowTable::new();
+        table.push_column(vec![1, 2]);
+        table.push_column(vec![3, 4]);
+        table.pop_column();
+
+        let before = table.memory_usage();
+        table.shrink_all();
+        assert!(table.memory_usage() <= before);
+        assert_eq!(table, [[1], [2]]);
+    }
 }
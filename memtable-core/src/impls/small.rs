@@ -0,0 +1,368 @@
+use crate::{list::*, utils, Capacity, Position, Table};
+use core::{cmp, iter::FromIterator, mem};
+
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use hashbrown::HashMap;
+
+/// Represents an inmemory table containing rows & columns of some data `T`
+/// that stores up to `N` cells inline before spilling them onto the heap,
+/// capable of growing and shrinking in size dynamically
+///
+/// Like [`crate::DynamicTable`], cells do not need to be contiguous, but a
+/// table holding `N` or fewer cells does not allocate at all, which suits
+/// workloads creating many tiny tables. Once a table needs more than `N`
+/// cells, they are moved into a map and remain there
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde-1", derive(serde::Serialize, serde::Deserialize))]
+pub struct SmallTable<T, const N: usize> {
+    /// Cells are serialized as a sequence of positions and values, and are
+    /// stored inline again when deserialized if there are at most `N`
+    #[cfg_attr(
+        feature = "serde-1",
+        serde(
+            bound(
+                serialize = "T: serde::Serialize",
+                deserialize = "T: serde::Deserialize<'de>"
+            ),
+            serialize_with = "serialize_cells",
+            deserialize_with = "deserialize_cells"
+        )
+    )]
+    cells: Cells<T, N>,
+    row_cnt: usize,
+    col_cnt: usize,
+}
+
+#[derive(Clone, Debug)]
+enum Cells<T, const N: usize> {
+    Inline([Option<(Position, T)>; N]),
+    Heap(HashMap<Position, T>),
+}
+
+#[cfg(feature = "serde-1")]
+fn serialize_cells<T, S, const N: usize>(
+    cells: &Cells<T, N>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    T: serde::Serialize,
+    S: serde::Serializer,
+{
+    match cells {
+        Cells::Inline(slots) => {
+            serializer.collect_seq(slots.iter().flatten().map(|(pos, x)| (pos, x)))
+        }
+        Cells::Heap(cells) => serializer.collect_seq(cells.iter()),
+    }
+}
+
+#[cfg(feature = "serde-1")]
+fn deserialize_cells<'de, T, D, const N: usize>(deserializer: D) -> Result<Cells<T, N>, D::Error>
+where
+    T: serde::Deserialize<'de>,
+    D: serde::Deserializer<'de>,
+{
+    use serde::Deserialize;
+
+    // Collect into a map first so a position given more than once keeps
+    // only its last value, as it would when inserting
+    let cells: HashMap<Position, T> = std::vec::Vec::<(Position, T)>::deserialize(deserializer)?
+        .into_iter()
+        .collect();
+
+    if cells.len() <= N {
+        let mut cells = cells.into_iter();
+        Ok(Cells::Inline(utils::make_array(|_| cells.next())))
+    } else {
+        Ok(Cells::Heap(cells))
+    }
+}
+
+impl<T, const N: usize> SmallTable<T, N> {
+    /// Creates a new, empty table
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns true if the cells of the table are stored inline rather than
+    /// on the heap
+    pub fn is_inline(&self) -> bool {
+        matches!(self.cells, Cells::Inline(_))
+    }
+
+    fn get(&self, pos: Position) -> Option<&T> {
+        match &self.cells {
+            Cells::Inline(slots) => slots
+                .iter()
+                .flatten()
+                .find(|(p, _)| *p == pos)
+                .map(|(_, x)| x),
+            Cells::Heap(cells) => cells.get(&pos),
+        }
+    }
+
+    fn get_mut(&mut self, pos: Position) -> Option<&mut T> {
+        match &mut self.cells {
+            Cells::Inline(slots) => slots
+                .iter_mut()
+                .flatten()
+                .find(|(p, _)| *p == pos)
+                .map(|(_, x)| x),
+            Cells::Heap(cells) => cells.get_mut(&pos),
+        }
+    }
+
+    fn positions(&self) -> impl Iterator<Item = Position> + '_ {
+        let (inline, heap) = match &self.cells {
+            Cells::Inline(slots) => (Some(slots.iter().flatten().map(|(p, _)| *p)), None),
+            Cells::Heap(cells) => (None, Some(cells.keys().copied())),
+        };
+        inline
+            .into_iter()
+            .flatten()
+            .chain(heap.into_iter().flatten())
+    }
+
+    /// Moves the inline cells into a map with room for one more cell
+    fn spill(&mut self) {
+        if let Cells::Inline(slots) = &mut self.cells {
+            let mut cells = HashMap::with_capacity(N + 1);
+            cells.extend(slots.iter_mut().filter_map(Option::take));
+            self.cells = Cells::Heap(cells);
+        }
+    }
+}
+
+impl<T, const N: usize> Default for SmallTable<T, N> {
+    fn default() -> Self {
+        Self {
+            cells: Cells::Inline(utils::make_array(|_| None)),
+            row_cnt: 0,
+            col_cnt: 0,
+        }
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for SmallTable<T, N> {
+    /// Compares the cells by position, regardless of whether either table
+    /// stores them inline
+    fn eq(&self, other: &Self) -> bool {
+        self.row_cnt == other.row_cnt
+            && self.col_cnt == other.col_cnt
+            && self.positions().count() == other.positions().count()
+            && self.positions().all(|pos| self.get(pos) == other.get(pos))
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for SmallTable<T, N> {}
+
+impl<T, const N: usize> Table for SmallTable<T, N> {
+    type Data = T;
+    type Row = DynamicList<Self::Data>;
+    type Column = DynamicList<Self::Data>;
+
+    fn max_row_capacity(&self) -> Capacity {
+        Capacity::Unlimited
+    }
+
+    fn max_column_capacity(&self) -> Capacity {
+        Capacity::Unlimited
+    }
+
+    fn row_cnt(&self) -> usize {
+        self.row_cnt
+    }
+
+    fn col_cnt(&self) -> usize {
+        self.col_cnt
+    }
+
+    fn cell(&self, row: usize, col: usize) -> Option<&Self::Data> {
+        self.get(Position { row, col })
+    }
+
+    fn mut_cell(&mut self, row: usize, col: usize) -> Option<&mut Self::Data> {
+        self.get_mut(Position { row, col })
+    }
+
+    fn insert_cell(&mut self, row: usize, col: usize, value: Self::Data) -> Option<Self::Data> {
+        if row >= self.row_cnt {
+            self.row_cnt = row + 1;
+        }
+
+        if col >= self.col_cnt {
+            self.col_cnt = col + 1;
+        }
+
+        let pos = Position { row, col };
+        if let Some(x) = self.get_mut(pos) {
+            return Some(mem::replace(x, value));
+        }
+
+        // New cells take the first free slot, spilling onto the heap once
+        // every slot is taken
+        if let Cells::Inline(slots) = &mut self.cells {
+            if let Some(slot) = slots.iter_mut().find(|slot| slot.is_none()) {
+                *slot = Some((pos, value));
+                return None;
+            }
+        }
+
+        self.spill();
+        match &mut self.cells {
+            Cells::Heap(cells) => cells.insert(pos, value),
+            Cells::Inline(_) => unreachable!("BUG: Cells should have spilled onto the heap"),
+        }
+    }
+
+    fn remove_cell(&mut self, row: usize, col: usize) -> Option<Self::Data> {
+        let pos = Position { row, col };
+        match &mut self.cells {
+            Cells::Inline(slots) => slots
+                .iter_mut()
+                .find(|slot| matches!(slot, Some((p, _)) if *p == pos))
+                .and_then(Option::take)
+                .map(|(_, x)| x),
+            Cells::Heap(cells) => cells.remove(&pos),
+        }
+    }
+
+    /// Will adjust the internal row count tracker to the specified capacity
+    ///
+    /// Note that this does **not** remove any cells from the table in their
+    /// old positions. To do that, call [`Self::truncate`].
+    fn set_preferred_row_cnt(&mut self, cnt: usize) {
+        self.row_cnt = cnt;
+    }
+
+    /// Will adjust the internal column count tracker to the specified capacity
+    ///
+    /// Note that this does **not** remove any cells from the table in their
+    /// old positions. To do that, call [`Self::truncate`].
+    fn set_preferred_col_cnt(&mut self, cnt: usize) {
+        self.col_cnt = cnt;
+    }
+
+    /// Removes all cells contained within the table that are outside the
+    /// current row & column capacity
+    fn truncate(&mut self) {
+        let (row_cnt, col_cnt) = (self.row_cnt, self.col_cnt);
+        let is_within = |pos: &Position| pos.row < row_cnt && pos.col < col_cnt;
+        match &mut self.cells {
+            Cells::Inline(slots) => {
+                for slot in slots.iter_mut() {
+                    if matches!(slot, Some((pos, _)) if !is_within(pos)) {
+                        *slot = None;
+                    }
+                }
+            }
+            Cells::Heap(cells) => cells.retain(|pos, _| is_within(pos)),
+        }
+    }
+
+    /// Adjusts the table's row & column capacity to fit where cells exist,
+    /// which includes cells outside of the current capacity
+    fn shrink_to_fit(&mut self) {
+        let (max_row, max_col) = self.positions().fold((0, 0), |acc, pos| {
+            (cmp::max(acc.0, pos.row + 1), cmp::max(acc.1, pos.col + 1))
+        });
+
+        self.row_cnt = max_row;
+        self.col_cnt = max_col;
+    }
+}
+
+impl<T, V: Into<T>, const N: usize> FromIterator<(usize, usize, V)> for SmallTable<T, N> {
+    /// Produces a table from the provided iterator of (row, col, value)
+    fn from_iter<I: IntoIterator<Item = (usize, usize, V)>>(iter: I) -> Self {
+        let mut table = Self::new();
+        for (row, col, x) in iter {
+            table.insert_cell(row, col, x.into());
+        }
+        table
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{vec, vec::Vec};
+
+    #[test]
+    fn insert_cell_should_store_cells_inline_until_capacity_is_exceeded() {
+        let mut table: SmallTable<u8, 2> = SmallTable::new();
+        assert!(table.is_inline());
+
+        assert_eq!(table.insert_cell(0, 0, 1), None);
+        assert_eq!(table.insert_cell(1, 2, 2), None);
+        assert_eq!(table.insert_cell(0, 0, 3), Some(1));
+        assert!(table.is_inline());
+        assert_eq!(table.row_cnt(), 2);
+        assert_eq!(table.col_cnt(), 3);
+
+        assert_eq!(table.insert_cell(2, 0, 4), None);
+        assert!(!table.is_inline());
+        assert_eq!(table.cell(0, 0), Some(&3));
+        assert_eq!(table.cell(1, 2), Some(&2));
+        assert_eq!(table.cell(2, 0), Some(&4));
+        assert_eq!(table.cell(2, 1), None);
+    }
+
+    #[test]
+    fn remove_cell_should_free_inline_slot_for_reuse() {
+        let mut table: SmallTable<u8, 1> = SmallTable::new();
+        table.insert_cell(0, 0, 1);
+
+        assert_eq!(table.remove_cell(0, 0), Some(1));
+        assert_eq!(table.remove_cell(0, 0), None);
+
+        table.insert_cell(0, 1, 2);
+        assert!(table.is_inline());
+        assert_eq!(table.cell(0, 1), Some(&2));
+    }
+
+    #[test]
+    fn eq_should_compare_cells_regardless_of_storage() {
+        let inline: SmallTable<u8, 4> = vec![(0, 0, 1), (1, 1, 2)].into_iter().collect();
+        let heap: SmallTable<u8, 1> = vec![(1, 1, 2), (0, 0, 1)].into_iter().collect();
+        let reordered: SmallTable<u8, 4> = vec![(1, 1, 2), (0, 0, 1)].into_iter().collect();
+
+        assert!(!heap.is_inline());
+        assert_eq!(inline, reordered);
+        assert_eq!(
+            inline.cells().collect::<Vec<_>>(),
+            heap.cells().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn truncate_should_remove_cells_outside_of_counts() {
+        let mut table: SmallTable<u8, 4> = vec![(0, 0, 1), (2, 2, 2)].into_iter().collect();
+        table.set_preferred_row_cnt(1);
+        table.set_preferred_col_cnt(1);
+        table.truncate();
+        table.shrink_to_fit();
+
+        assert_eq!(table.row_cnt(), 1);
+        assert_eq!(table.col_cnt(), 1);
+        assert_eq!(table.cell(0, 0), Some(&1));
+    }
+
+    #[cfg(feature = "serde-1")]
+    #[test]
+    fn should_store_cells_inline_after_json_round_trip_if_they_fit() {
+        let table: SmallTable<u8, 2> = vec![(0, 0, 1), (1, 2, 2)].into_iter().collect();
+        let json = serde_json::to_string(&table).unwrap();
+        let other: SmallTable<u8, 2> = serde_json::from_str(&json).unwrap();
+        assert!(other.is_inline());
+        assert_eq!(other, table);
+
+        let other: SmallTable<u8, 1> = serde_json::from_str(&json).unwrap();
+        assert!(!other.is_inline());
+        assert_eq!(other.cell(1, 2), Some(&2));
+        assert_eq!((other.row_cnt(), other.col_cnt()), (2, 3));
+    }
+}
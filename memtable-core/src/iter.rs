@@ -1,5 +1,11 @@
 use super::{Position, Table};
-use core::marker::PhantomData;
+use core::{cmp, fmt, marker::PhantomData, ops::Range};
+
+/// Represents an iterator over the positions of the cells held by a table,
+/// in no particular order
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[cfg_attr(feature = "docs", doc(cfg(any(alloc, std))))]
+pub type OccupiedPositions<'a> = std::boxed::Box<dyn Iterator<Item = Position> + 'a>;
 
 /// Represents an iterator over some part of a table at the granularity
 /// of individual cells within the table
@@ -126,6 +132,48 @@ impl<'a, D, T: Table<Data = D>> CellIter<&'a D> for Row<'a, D, T> {
     }
 }
 
+/// Represents an iterator over every position within a row of a table,
+/// yielding `None` for missing cells rather than stopping at them
+#[derive(Debug)]
+pub struct RowComplete<'a, D, T: Table<Data = D>> {
+    table: &'a T,
+    row: usize,
+    col: usize,
+}
+
+impl<'a, D, T: Table<Data = D>> RowComplete<'a, D, T> {
+    /// Creates a new iterator over every position in a row for the given
+    /// table at the specified row
+    pub fn new(table: &'a T, row: usize) -> Self {
+        Self { table, row, col: 0 }
+    }
+}
+
+impl<'a, D: 'a, T: Table<Data = D>> Iterator for RowComplete<'a, D, T> {
+    type Item = Option<&'a D>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.row >= self.table.row_cnt() || self.col >= self.table.col_cnt() {
+            return None;
+        }
+
+        let cell = self.table.cell(self.row, self.col);
+        self.col += 1;
+        Some(cell)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = if self.row < self.table.row_cnt() {
+            self.table.col_cnt().saturating_sub(self.col)
+        } else {
+            0
+        };
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, D: 'a, T: Table<Data = D>> ExactSizeIterator for RowComplete<'a, D, T> {}
+
 /// Represents an iterator over cells within a row of a table
 #[derive(Debug)]
 pub struct IntoRow<D, T: Table<Data = D>> {
@@ -181,6 +229,405 @@ impl<D, T: Table<Data = D>> CellIter<D> for IntoRow<D, T> {
     }
 }
 
+/// Represents an iterator over fixed-size pages of rows of a table
+#[derive(Debug)]
+pub struct Pages<'a, D, T: Table<Data = D>> {
+    table: &'a T,
+    page_size: usize,
+    idx: usize,
+}
+
+impl<'a, D, T: Table<Data = D>> Pages<'a, D, T> {
+    /// Produces an iterator that will iterate through all pages of the table,
+    /// each containing up to `page_size` rows
+    ///
+    /// Panics if `page_size` is zero
+    pub fn new(table: &'a T, page_size: usize) -> Self {
+        assert!(page_size > 0, "Page size must be non-zero");
+        Self {
+            table,
+            page_size,
+            idx: 0,
+        }
+    }
+}
+
+impl<'a, D, T: Table<Data = D>> Iterator for Pages<'a, D, T> {
+    type Item = Page<'a, D, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let page = Page::new(self.table, self.idx, self.page_size);
+        if self.idx < page.page_cnt() {
+            self.idx += 1;
+            Some(page)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let page_cnt = Page::new(self.table, 0, self.page_size).page_cnt();
+        let remaining = page_cnt.saturating_sub(self.idx);
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, D, T: Table<Data = D>> ExactSizeIterator for Pages<'a, D, T> {}
+
+/// Represents an iterator over the rows within a single page of a table,
+/// alongside information about where the page sits among all pages
+#[derive(Debug)]
+pub struct Page<'a, D, T: Table<Data = D>> {
+    table: &'a T,
+    idx: usize,
+    page_size: usize,
+    row: usize,
+}
+
+impl<'a, D, T: Table<Data = D>> Page<'a, D, T> {
+    /// Creates a new iterator over the rows of the page at the specified
+    /// index, where each page contains up to `page_size` rows; a page beyond
+    /// the end of the table contains no rows
+    ///
+    /// Panics if `page_size` is zero
+    pub fn new(table: &'a T, idx: usize, page_size: usize) -> Self {
+        assert!(page_size > 0, "Page size must be non-zero");
+        let row = cmp::min(idx.saturating_mul(page_size), table.row_cnt());
+        Self {
+            table,
+            idx,
+            page_size,
+            row,
+        }
+    }
+
+    /// Returns the index of the page, starting at zero
+    pub fn index(&self) -> usize {
+        self.idx
+    }
+
+    /// Returns the maximum rows contained in each page
+    pub fn page_size(&self) -> usize {
+        self.page_size
+    }
+
+    /// Returns the total pages needed to hold all rows of the table
+    pub fn page_cnt(&self) -> usize {
+        let row_cnt = self.table.row_cnt();
+        let page_cnt = row_cnt / self.page_size;
+        if page_cnt * self.page_size < row_cnt {
+            page_cnt + 1
+        } else {
+            page_cnt
+        }
+    }
+
+    /// Returns the total rows within the entire table
+    pub fn total_row_cnt(&self) -> usize {
+        self.table.row_cnt()
+    }
+
+    /// Returns the range of rows within the table covered by the page
+    pub fn row_range(&self) -> Range<usize> {
+        let row_cnt = self.table.row_cnt();
+        let start = cmp::min(self.idx.saturating_mul(self.page_size), row_cnt);
+        let end = cmp::min(start.saturating_add(self.page_size), row_cnt);
+        start..end
+    }
+
+    /// Returns true if there is a page before this one
+    pub fn has_prev(&self) -> bool {
+        self.idx > 0
+    }
+
+    /// Returns true if there is a page after this one
+    pub fn has_next(&self) -> bool {
+        self.idx.saturating_add(1) < self.page_cnt()
+    }
+}
+
+impl<'a, D, T: Table<Data = D>> Iterator for Page<'a, D, T> {
+    type Item = Row<'a, D, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.row < self.row_range().end {
+            let row = Row::new(self.table, self.row);
+            self.row += 1;
+            Some(row)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.row_range().end.saturating_sub(self.row);
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, D, T: Table<Data = D>> ExactSizeIterator for Page<'a, D, T> {}
+
+/// Represents an iterator that removes rows from the front of a table,
+/// producing each row as it is reached
+///
+/// Once dropped, any rows that were not reached are removed from the table as
+/// well, leaving it without rows
+#[derive(Debug)]
+pub struct DrainRows<'a, D, T: Table<Data = D>> {
+    table: &'a mut T,
+    row: usize,
+    row_cnt: usize,
+}
+
+impl<'a, D, T: Table<Data = D>> DrainRows<'a, D, T> {
+    /// Creates a new iterator that drains all rows from the table
+    pub fn new(table: &'a mut T) -> Self {
+        let row_cnt = table.row_cnt();
+        Self {
+            table,
+            row: 0,
+            row_cnt,
+        }
+    }
+}
+
+impl<'a, D, T: Table<Data = D>> Iterator for DrainRows<'a, D, T> {
+    type Item = T::Row;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.row >= self.row_cnt {
+            return None;
+        }
+
+        let row = self.table.take_row(self.row);
+        self.row += 1;
+        row
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.row_cnt - self.row;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, D, T: Table<Data = D>> ExactSizeIterator for DrainRows<'a, D, T> {}
+
+impl<'a, D, T: Table<Data = D>> Drop for DrainRows<'a, D, T> {
+    fn drop(&mut self) {
+        let col_cnt = self.table.col_cnt();
+        for row in self.row..self.row_cnt {
+            for col in 0..col_cnt {
+                self.table.remove_cell(row, col);
+            }
+        }
+
+        self.table.set_preferred_row_cnt(0);
+    }
+}
+
+/// Represents an iterator that consumes a table, producing new tables that
+/// each contain the next band of up to a fixed number of rows
+#[derive(Debug)]
+pub struct IntoRowChunks<D, T: Table<Data = D>> {
+    table: T,
+    chunk_size: usize,
+    row: usize,
+}
+
+impl<D, T: Table<Data = D>> IntoRowChunks<D, T> {
+    /// Creates a new iterator that consumes a table and returns chunks of up
+    /// to `chunk_size` rows from the beginning of the table
+    ///
+    /// Panics if `chunk_size` is zero
+    pub fn new(table: T, chunk_size: usize) -> Self {
+        assert!(chunk_size > 0, "Chunk size must be non-zero");
+        Self {
+            table,
+            chunk_size,
+            row: 0,
+        }
+    }
+}
+
+impl<D, T: Table<Data = D> + Default> Iterator for IntoRowChunks<D, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let row_cnt = self.table.row_cnt();
+        if self.row >= row_cnt {
+            return None;
+        }
+
+        let end = cmp::min(self.row.saturating_add(self.chunk_size), row_cnt);
+        let col_cnt = self.table.col_cnt();
+        let mut chunk = T::default();
+
+        for (new_row, row) in (self.row..end).enumerate() {
+            for col in 0..col_cnt {
+                if let Some(x) = self.table.remove_cell(row, col) {
+                    chunk.insert_cell(new_row, col, x);
+                }
+            }
+        }
+
+        chunk.set_preferred_row_cnt(end - self.row);
+        chunk.set_preferred_col_cnt(col_cnt);
+        self.row = end;
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining_rows = self.table.row_cnt().saturating_sub(self.row);
+        let remaining = remaining_rows / self.chunk_size;
+        let remaining = if remaining * self.chunk_size < remaining_rows {
+            remaining + 1
+        } else {
+            remaining
+        };
+        (remaining, Some(remaining))
+    }
+}
+
+impl<D, T: Table<Data = D> + Default> ExactSizeIterator for IntoRowChunks<D, T> {}
+
+/// Represents an iterator over the positions of cells in a table that are
+/// equal to some value
+///
+/// By default, this scans the table row by row. Tables that already know
+/// where each value lives can instead hand over those positions using
+/// [`PositionsOf::from_positions`]
+#[derive(Debug)]
+pub struct PositionsOf<'a, D, T: Table<Data = D>> {
+    source: PositionsOfSource<'a, D, T>,
+}
+
+#[derive(Debug)]
+enum PositionsOfSource<'a, D, T: Table<Data = D>> {
+    Scan {
+        table: &'a T,
+        value: &'a D,
+        row: usize,
+        col: usize,
+    },
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    Known(std::vec::IntoIter<Position>),
+}
+
+impl<'a, D: PartialEq, T: Table<Data = D>> PositionsOf<'a, D, T> {
+    /// Creates a new iterator over the positions of cells in the table that
+    /// are equal to the given value, starting from the beginning of the table
+    pub fn new(table: &'a T, value: &'a D) -> Self {
+        Self {
+            source: PositionsOfSource::Scan {
+                table,
+                value,
+                row: 0,
+                col: 0,
+            },
+        }
+    }
+
+    /// Creates a new iterator over positions that are already known to hold
+    /// cells equal to some value, which should be ordered row by row
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[cfg_attr(feature = "docs", doc(cfg(any(alloc, std))))]
+    pub fn from_positions(positions: std::vec::Vec<Position>) -> Self {
+        Self {
+            source: PositionsOfSource::Known(positions.into_iter()),
+        }
+    }
+}
+
+impl<'a, D: PartialEq, T: Table<Data = D>> Iterator for PositionsOf<'a, D, T> {
+    type Item = Position;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (table, value, row, col) = match &mut self.source {
+            PositionsOfSource::Scan {
+                table,
+                value,
+                row,
+                col,
+            } => (*table, *value, row, col),
+            #[cfg(any(feature = "alloc", feature = "std"))]
+            PositionsOfSource::Known(positions) => return positions.next(),
+        };
+
+        let row_cnt = table.row_cnt();
+        let col_cnt = table.col_cnt();
+
+        while *row < row_cnt {
+            while *col < col_cnt {
+                let pos = Position {
+                    row: *row,
+                    col: *col,
+                };
+                *col += 1;
+
+                if matches!(table.cell(pos.row, pos.col), Some(x) if x == value) {
+                    return Some(pos);
+                }
+            }
+
+            *row += 1;
+            *col = 0;
+        }
+
+        None
+    }
+}
+
+/// Represents an iterator over the positions within the rows and columns of
+/// a table that have no cell
+#[derive(Debug)]
+pub struct MissingPositions<'a, D, T: Table<Data = D>> {
+    table: &'a T,
+    row: usize,
+    col: usize,
+    _phantom: PhantomData<D>,
+}
+
+impl<'a, D, T: Table<Data = D>> MissingPositions<'a, D, T> {
+    /// Creates a new iterator over the positions of missing cells in the
+    /// table, starting from the beginning of the table
+    pub fn new(table: &'a T) -> Self {
+        Self {
+            table,
+            row: 0,
+            col: 0,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, D, T: Table<Data = D>> Iterator for MissingPositions<'a, D, T> {
+    type Item = Position;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let row_cnt = self.table.row_cnt();
+        let col_cnt = self.table.col_cnt();
+
+        while self.row < row_cnt {
+            while self.col < col_cnt {
+                let pos = Position {
+                    row: self.row,
+                    col: self.col,
+                };
+                self.col += 1;
+
+                if self.table.cell(pos.row, pos.col).is_none() {
+                    return Some(pos);
+                }
+            }
+
+            self.row += 1;
+            self.col = 0;
+        }
+
+        None
+    }
+}
+
 /// Represents an iterator over columns of a table
 #[derive(Debug)]
 pub struct Columns<'a, D, T: Table<Data = D>> {
@@ -270,6 +717,131 @@ impl<'a, D, T: Table<Data = D>> CellIter<&'a D> for Column<'a, D, T> {
     }
 }
 
+/// Represents an iterator over every position within a column of a table,
+/// yielding `None` for missing cells rather than stopping at them
+#[derive(Debug)]
+pub struct ColumnComplete<'a, D, T: Table<Data = D>> {
+    table: &'a T,
+    row: usize,
+    col: usize,
+}
+
+impl<'a, D, T: Table<Data = D>> ColumnComplete<'a, D, T> {
+    /// Creates a new iterator over every position in a column for the given
+    /// table at the specified column
+    pub fn new(table: &'a T, col: usize) -> Self {
+        Self { table, row: 0, col }
+    }
+}
+
+impl<'a, D: 'a, T: Table<Data = D>> Iterator for ColumnComplete<'a, D, T> {
+    type Item = Option<&'a D>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.col >= self.table.col_cnt() || self.row >= self.table.row_cnt() {
+            return None;
+        }
+
+        let cell = self.table.cell(self.row, self.col);
+        self.row += 1;
+        Some(cell)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = if self.col < self.table.col_cnt() {
+            self.table.row_cnt().saturating_sub(self.row)
+        } else {
+            0
+        };
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, D: 'a, T: Table<Data = D>> ExactSizeIterator for ColumnComplete<'a, D, T> {}
+
+/// Represents an iterator over the running result of applying a function to
+/// the cells of a column of a table, yielding `None` for missing cells
+/// rather than stopping at them
+pub struct ScanColumn<'a, D, T: Table<Data = D>, F> {
+    table: &'a T,
+    row: usize,
+    col: usize,
+    acc: Option<D>,
+    f: F,
+}
+
+/// Represents an iterator over the running result of combining the cells of
+/// a column with a fixed operation, such as a sum
+pub type CumulativeColumn<'a, D, T> = ScanColumn<'a, D, T, fn(&D, &D) -> D>;
+
+impl<'a, D, T: Table<Data = D>, F> ScanColumn<'a, D, T, F>
+where
+    F: FnMut(&D, &D) -> D,
+{
+    /// Creates a new iterator over the running result of applying `f` to the
+    /// previous result and each cell of the specified column, where the
+    /// first existing cell becomes the result as-is if `init` is `None`
+    pub fn new(table: &'a T, col: usize, init: Option<D>, f: F) -> Self {
+        Self {
+            table,
+            row: 0,
+            col,
+            acc: init,
+            f,
+        }
+    }
+}
+
+impl<'a, D: fmt::Debug, T: Table<Data = D> + fmt::Debug, F> fmt::Debug for ScanColumn<'a, D, T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ScanColumn")
+            .field("table", &self.table)
+            .field("row", &self.row)
+            .field("col", &self.col)
+            .field("acc", &self.acc)
+            .finish()
+    }
+}
+
+impl<'a, D: Clone, T: Table<Data = D>, F> Iterator for ScanColumn<'a, D, T, F>
+where
+    F: FnMut(&D, &D) -> D,
+{
+    type Item = Option<D>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.col >= self.table.col_cnt() || self.row >= self.table.row_cnt() {
+            return None;
+        }
+
+        let cell = self.table.cell(self.row, self.col);
+        self.row += 1;
+
+        Some(cell.map(|x| {
+            let x = match self.acc.as_ref() {
+                Some(acc) => (self.f)(acc, x),
+                None => x.clone(),
+            };
+            self.acc = Some(x.clone());
+            x
+        }))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = if self.col < self.table.col_cnt() {
+            self.table.row_cnt().saturating_sub(self.row)
+        } else {
+            0
+        };
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, D: Clone, T: Table<Data = D>, F> ExactSizeIterator for ScanColumn<'a, D, T, F> where
+    F: FnMut(&D, &D) -> D
+{
+}
+
 /// Represents an iterator over cells within a column of a table
 #[derive(Debug)]
 pub struct IntoColumn<D, T: Table<Data = D>> {
@@ -375,11 +947,7 @@ impl<'a, D: 'a, T: Table<Data = D>> Iterator for Cells<'a, D, T> {
     fn size_hint(&self) -> (usize, Option<usize>) {
         let consumed = (self.row * self.table.col_cnt()) + self.col;
         let total = self.table.len();
-        let remaining = if total > consumed {
-            total - consumed
-        } else {
-            0
-        };
+        let remaining = total.saturating_sub(consumed);
         (remaining, Some(remaining))
     }
 }
@@ -455,11 +1023,7 @@ impl<D, T: Table<Data = D>> Iterator for IntoCells<D, T> {
     fn size_hint(&self) -> (usize, Option<usize>) {
         let consumed = (self.row * self.table.col_cnt()) + self.col;
         let total = self.table.len();
-        let remaining = if total > consumed {
-            total - consumed
-        } else {
-            0
-        };
+        let remaining = total.saturating_sub(consumed);
         (remaining, Some(remaining))
     }
 }
@@ -622,6 +1186,95 @@ mod tests {
                 assert_eq!(row.size_hint(), (0, Some(0)));
             }
 
+            #[test]
+            fn pages_should_split_rows_into_pages_of_given_size() {
+                let table = $table::from([["a"], ["b"], ["c"], ["d"], ["e"]]);
+
+                let mut pages = table.pages(2);
+                assert_eq!(pages.size_hint(), (3, Some(3)));
+                assert_eq!(pages.next().unwrap().row_range(), 0..2);
+                assert_eq!(pages.next().unwrap().row_range(), 2..4);
+                assert_eq!(pages.next().unwrap().row_range(), 4..5);
+                assert!(pages.next().is_none());
+                assert_eq!(pages.size_hint(), (0, Some(0)));
+            }
+
+            #[test]
+            fn page_should_iterate_through_rows_within_page() {
+                let table = $table::from([["a"], ["b"], ["c"]]);
+
+                let mut page = table.page(1, 2);
+                assert_eq!(page.size_hint(), (1, Some(1)));
+                assert_eq!(page.next().unwrap().next(), Some(&"c"));
+                assert!(page.next().is_none());
+            }
+
+            #[test]
+            fn page_should_report_position_among_all_pages() {
+                let table = $table::from([["a"], ["b"], ["c"]]);
+
+                let page = table.page(0, 2);
+                assert_eq!(page.index(), 0);
+                assert_eq!(page.page_size(), 2);
+                assert_eq!(page.page_cnt(), 2);
+                assert_eq!(page.total_row_cnt(), 3);
+                assert!(!page.has_prev());
+                assert!(page.has_next());
+            }
+
+            #[test]
+            fn page_should_be_empty_if_beyond_end_of_table() {
+                let table = $table::from([["a"], ["b"], ["c"]]);
+
+                let page = table.page(3, 2);
+                assert_eq!(page.row_range(), 3..3);
+                assert_eq!(page.len(), 0);
+                assert!(!page.has_next());
+            }
+
+            #[test]
+            fn into_row_chunks_should_move_rows_into_tables_of_given_size() {
+                let table = $table::from([["a"], ["b"], ["c"]]);
+
+                let mut chunks = table.into_row_chunks(2);
+                assert_eq!(chunks.size_hint(), (2, Some(2)));
+
+                let chunk = chunks.next().unwrap();
+                assert_eq!(chunk.cell(0, 0), Some(&"a"));
+                assert_eq!(chunk.cell(1, 0), Some(&"b"));
+                assert_eq!(chunks.size_hint(), (1, Some(1)));
+
+                let chunk = chunks.next().unwrap();
+                assert_eq!(chunk.cell(0, 0), Some(&"c"));
+                assert!(chunks.next().is_none());
+            }
+
+            #[test]
+            fn positions_of_should_return_positions_of_equal_cells_in_order() {
+                let table = $table::from([["a", "b"], ["b", "c"], ["d", "b"]]);
+
+                let mut positions = table.positions_of(&"b");
+                assert_eq!(positions.next(), Some(Position { row: 0, col: 1 }));
+                assert_eq!(positions.next(), Some(Position { row: 1, col: 0 }));
+                assert_eq!(positions.next(), Some(Position { row: 2, col: 1 }));
+                assert_eq!(positions.next(), None);
+                assert_eq!(positions.next(), None);
+            }
+
+            #[test]
+            fn position_of_should_return_none_if_no_cell_is_equal() {
+                let table = $table::from([["a", "b"]]);
+                assert_eq!(table.position_of(&"a"), Some(Position { row: 0, col: 0 }));
+                assert_eq!(table.position_of(&"c"), None);
+            }
+
+            #[test]
+            #[should_panic]
+            fn pages_should_panic_if_page_size_is_zero() {
+                let table = $table::from([["a"]]);
+                table.pages(0);
+            }
+
             #[test]
             fn columns_next_should_return_next_column_if_available() {
                 let table = $table::from([[""]]);
@@ -881,6 +1534,13 @@ mod tests {
         run_tests!(DynamicTable);
     }
 
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    mod cow {
+        use super::*;
+        use crate::CowTable;
+        run_tests!(CowTable);
+    }
+
     mod fixed {
         use super::*;
         use crate::FixedTable;
@@ -5,17 +5,70 @@
 //! Check out full documentation at
 //! [memtable](https://github.com/chipsenkbeil/memtable-rs).
 #![cfg_attr(feature = "docs", feature(doc_cfg))]
+#![cfg_attr(feature = "allocator-api", feature(allocator_api))]
 #![cfg_attr(not(feature = "std"), no_std)]
 #![forbid(missing_docs, missing_debug_implementations)]
 
+#[macro_use]
+mod trace;
+
 mod capacity;
 
 #[doc(inline)]
 pub use capacity::Capacity;
 
+mod cell_error;
+
+#[doc(inline)]
+pub use cell_error::CellError;
+
+/// Contains traits to parse the text of tables into typed data
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[cfg_attr(feature = "docs", doc(cfg(any(alloc, std))))]
+pub mod coerce;
+
+/// Contains functions to serialize tables column by column with runs of
+/// repeated values, shrinking wide tables whose columns are uniform
+#[cfg(feature = "serde-1")]
+#[cfg_attr(feature = "docs", doc(cfg(serde)))]
+pub mod columnar;
+
+/// Contains a cursor to walk and edit the cells of tables
+pub mod cursor;
+
+/// Contains functions to serialize tables with each distinct value written
+/// once, shrinking tables with many repeated values
+#[cfg(feature = "serde-1")]
+#[cfg_attr(feature = "docs", doc(cfg(serde)))]
+pub mod dedup;
+
+/// Contains structs to render tables as aligned text
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[cfg_attr(feature = "docs", doc(cfg(any(alloc, std))))]
+pub mod display;
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+mod dyn_table;
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[cfg_attr(feature = "docs", doc(cfg(any(alloc, std))))]
+#[doc(inline)]
+pub use dyn_table::{DynCells, DynTable};
+
 /// Contains extensions to the library based on extra features
 pub mod exts;
 
+mod flatten;
+
+#[doc(inline)]
+pub use flatten::FlattenColumns;
+
+/// Contains traits to work with tables as two-dimensional grids
+pub mod grid;
+
+/// Contains handles to read and modify individual rows and columns of tables
+pub mod handle;
+
 /// Contains iterators and associated traits for traversing portions of tables
 pub mod iter;
 
@@ -25,21 +78,105 @@ pub use impls::*;
 /// Contains lists and associated traits for storing items
 pub mod list;
 
+/// Contains traits to estimate and reduce the memory used by tables
+pub mod memory;
+
+/// Contains operations that describe mutations made to tables
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[cfg_attr(feature = "docs", doc(cfg(any(alloc, std))))]
+pub mod ops;
+
+/// Contains traits to work with tables of optional data
+pub mod option;
+
 mod position;
 
 #[doc(inline)]
 pub use position::Position;
 
+mod shape;
+
+#[doc(inline)]
+pub use shape::ShapeError;
+
+mod sort;
+
+#[doc(inline)]
+pub use sort::SortOrder;
+
 /// Contains relevant top-level traits, structs, and more to make use of
 /// this library
 pub mod prelude;
 
+/// Contains types for reporting the progress of long operations
+pub mod progress;
+
+/// Contains tables designed to be shared across threads
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "docs", doc(cfg(std)))]
+pub mod sync;
+
+/// Contains assertions for comparing tables within tests
+#[cfg(all(feature = "test-util", any(feature = "alloc", feature = "std")))]
+#[cfg_attr(feature = "docs", doc(cfg(all(test_util, any(alloc, std)))))]
+pub mod test_util;
+
 mod utils;
 
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[cfg_attr(feature = "docs", doc(cfg(any(alloc, std))))]
+pub use utils::text;
+
+/// Contains batches of changes that are applied to tables all at once
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[cfg_attr(feature = "docs", doc(cfg(any(alloc, std))))]
+pub mod transaction;
+
+/// Contains views that present portions of tables without copying them
+pub mod view;
+
+/// Contains tables that wrap other tables to provide additional behavior
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[cfg_attr(feature = "docs", doc(cfg(any(alloc, std))))]
+pub mod wrappers;
+
+/// Contains a collection of named tables, like the sheets of a workbook
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[cfg_attr(feature = "docs", doc(cfg(any(alloc, std))))]
+pub mod workspace;
+
 /// Re-export alloc as std in the case where we don't have std
 #[cfg(all(feature = "alloc", not(feature = "std")))]
 extern crate alloc as std;
 
+/// Contains items used by code generated when deriving tables, which are not
+/// part of the public API
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    pub use std::{string::String, vec::Vec};
+}
+
+/// Emits the provided items only when alloc is available, which lets code
+/// generated when deriving tables make use of it
+#[doc(hidden)]
+#[macro_export]
+#[cfg(any(feature = "alloc", feature = "std"))]
+macro_rules! __cfg_alloc {
+    ($($item:item)*) => {
+        $($item)*
+    };
+}
+
+/// Emits the provided items only when alloc is available, which lets code
+/// generated when deriving tables make use of it
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(any(feature = "alloc", feature = "std")))]
+macro_rules! __cfg_alloc {
+    ($($item:item)*) => {};
+}
+
 /// Represents an abstract table of data
 pub trait Table: Sized {
     /// The type of data stored in individual cells within the table
@@ -129,6 +266,12 @@ pub trait Table: Sized {
     ///
     /// This is a preference, not an absolute, and is up to each table to
     /// implement if desired; otherwise, this does nothing by default
+    ///
+    /// The tables provided by this crate treat the count as a virtual bound
+    /// for methods like [`Table::cell`], capped at
+    /// [`Table::max_row_capacity`]. Lowering the count hides the cells past
+    /// it rather than removing them, so raising the count again reveals them
+    /// once more; call [`Table::truncate`] to remove them for good
     #[allow(unused_variables)]
     fn set_preferred_row_cnt(&mut self, cnt: usize) {}
 
@@ -136,9 +279,81 @@ pub trait Table: Sized {
     ///
     /// This is a preference, not an absolute, and is up to each table to
     /// implement if desired; otherwise, this does nothing by default
+    ///
+    /// Follows the same semantics as [`Table::set_preferred_row_cnt`], capped
+    /// at [`Table::max_column_capacity`]
     #[allow(unused_variables)]
     fn set_preferred_col_cnt(&mut self, cnt: usize) {}
 
+    /// Removes all cells outside of the current row & column counts, such as
+    /// those hidden by lowering the preferred counts
+    ///
+    /// Tables backed by fixed-size storage reset those cells to their default
+    /// value instead. This does nothing by default, which suits tables that
+    /// never keep cells outside of their counts
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let mut table = DynamicTable::from([[1, 2], [3, 4]]);
+    /// table.set_preferred_row_cnt(1);
+    /// table.truncate();
+    ///
+    /// table.set_preferred_row_cnt(2);
+    /// assert_eq!(table.cell(1, 0), None);
+    /// # }
+    /// ```
+    fn truncate(&mut self) {}
+
+    /// Adjusts the row & column counts to fit the cells held by the table
+    ///
+    /// By default, this only considers the cells within the current counts,
+    /// lowering them to just past the last row and column holding a cell.
+    /// Tables that keep cells outside of their counts may also grow to
+    /// include those cells, so call [`Table::truncate`] first to only shrink.
+    /// Tables backed by fixed-size storage hold a cell at every position
+    /// within their counts and are left unchanged
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let mut table = DynamicTable::from([[1, 2, 3], [4, 5, 6]]);
+    /// table.remove_column(2);
+    /// table.remove_cell(1, 0);
+    /// table.remove_cell(1, 1);
+    /// table.shrink_to_fit();
+    ///
+    /// assert_eq!(table.row_cnt(), 1);
+    /// assert_eq!(table.col_cnt(), 2);
+    /// # }
+    /// ```
+    fn shrink_to_fit(&mut self) {
+        let mut row_cnt = 0;
+        let mut col_cnt = 0;
+
+        for row in 0..self.row_cnt() {
+            for col in 0..self.col_cnt() {
+                if self.has_cell(row, col) {
+                    row_cnt = row + 1;
+                    col_cnt = core::cmp::max(col_cnt, col + 1);
+                }
+            }
+        }
+
+        self.set_preferred_row_cnt(row_cnt);
+        self.set_preferred_col_cnt(col_cnt);
+    }
+
     /// Returns reference to the cell found at the specified row and column
     ///
     /// ### Examples
@@ -267,6 +482,64 @@ pub trait Table: Sized {
     /// ```
     fn remove_cell(&mut self, row: usize, col: usize) -> Option<Self::Data>;
 
+    /// Swaps the cells at the two positions, where a missing cell is swapped
+    /// like any other so that the cell at the other position ends up missing
+    ///
+    /// Nothing happens if either position is outside of the table's maximum
+    /// capacity
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::{prelude::*, Position};
+    /// let mut table = DynamicTable::from([[1, 2], [3, 4]]);
+    /// table.swap_cells(Position::new(0, 0), Position::new(1, 1));
+    /// assert_eq!(table, [[4, 2], [3, 1]]);
+    ///
+    /// table.remove_cell(0, 1);
+    /// table.swap_cells(Position::new(0, 1), Position::new(1, 0));
+    /// assert_eq!(table.cell(0, 1), Some(&3));
+    /// assert_eq!(table.cell(1, 0), None);
+    /// # }
+    /// ```
+    fn swap_cells(&mut self, a: Position, b: Position) {
+        utils::swap_cells_by_removal(self, a, b)
+    }
+
+    /// Moves the cell at one position to another, returning the cell that was
+    /// displaced at the other position
+    ///
+    /// Nothing happens if there is no cell to move, the positions are the
+    /// same, or the destination is outside of the table's maximum capacity
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::{prelude::*, Position};
+    /// let mut table = DynamicTable::from([[1, 2], [3, 4]]);
+    ///
+    /// assert_eq!(table.move_cell(Position::new(0, 0), Position::new(1, 1)), Some(4));
+    /// assert_eq!(table.cell(0, 0), None);
+    /// assert_eq!(table.cell(1, 1), Some(&1));
+    /// # }
+    /// ```
+    fn move_cell(&mut self, from: Position, to: Position) -> Option<Self::Data> {
+        if from == to || !utils::within_capacity(self, to) {
+            return None;
+        }
+
+        let value = self.remove_cell(from.row, from.col)?;
+        self.insert_cell(to.row, to.col, value)
+    }
+
     /// Returns the total cells (rows * columns) contained in the table
     ///
     /// ### Examples
@@ -376,7 +649,7 @@ pub trait Table: Sized {
     /// # }
     /// ```
     ///
-    fn rows(&self) -> iter::Rows<Self::Data, Self> {
+    fn rows(&self) -> iter::Rows<'_, Self::Data, Self> {
         iter::Rows::new(self)
     }
 
@@ -417,10 +690,72 @@ pub trait Table: Sized {
     /// # }
     /// ```
     ///
-    fn row(&self, idx: usize) -> iter::Row<Self::Data, Self> {
+    fn row(&self, idx: usize) -> iter::Row<'_, Self::Data, Self> {
         iter::Row::new(self, idx)
     }
 
+    /// Returns an iterator over every position within a specific row in the
+    /// table, yielding `None` for each missing cell
+    ///
+    /// Unlike [`Table::row`], which stops at the first missing cell, this
+    /// always yields one item per column so that cells of sparse rows are
+    /// not lost
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let mut table = DynamicTable::new();
+    /// table.push_row(vec![1, 2, 3]);
+    /// table.remove_cell(0, 1);
+    ///
+    /// assert_eq!(table.row(0).count(), 1);
+    ///
+    /// let mut cells = table.row_complete(0);
+    /// assert_eq!(cells.next(), Some(Some(&1)));
+    /// assert_eq!(cells.next(), Some(None));
+    /// assert_eq!(cells.next(), Some(Some(&3)));
+    /// assert_eq!(cells.next(), None);
+    /// # }
+    /// ```
+    fn row_complete(&self, idx: usize) -> iter::RowComplete<'_, Self::Data, Self> {
+        iter::RowComplete::new(self, idx)
+    }
+
+    /// Returns a handle to a specific row in the table that can read and
+    /// modify its cells, or `None` if the row is out of bounds
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let mut table = DynamicTable::from([[1, 2], [3, 4]]);
+    ///
+    /// let mut row = table.row_handle(1).unwrap();
+    /// assert_eq!(row.len(), 2);
+    /// assert_eq!(row.get(0), Some(&3));
+    /// assert_eq!(row.set(1, 5), Ok(Some(4)));
+    ///
+    /// assert_eq!(table, [[1, 2], [3, 5]]);
+    /// assert!(table.row_handle(2).is_none());
+    /// # }
+    /// ```
+    fn row_handle(&mut self, idx: usize) -> Option<handle::RowHandle<'_, Self::Data, Self>> {
+        if idx < self.row_cnt() {
+            Some(handle::RowHandle::new(self, idx))
+        } else {
+            None
+        }
+    }
+
     /// Consumes the table and returns an iterator through a specific row in the table
     ///
     /// ### Examples
@@ -462,11 +797,13 @@ pub trait Table: Sized {
         iter::IntoRow::new(self, idx)
     }
 
-    /// Returns an iterator of refs through all columns in the table
+    /// Removes the cells of a specific row and returns them, leaving the row
+    /// empty so that every other row keeps its index
     ///
-    /// ### Examples
+    /// Unlike [`Table::into_row`], the rest of the table remains available,
+    /// and unlike [`Table::remove_row`], no rows are shifted
     ///
-    /// When empty:
+    /// ### Examples
     ///
     /// ```
     /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
@@ -474,12 +811,35 @@ pub trait Table: Sized {
     /// # #[cfg(any(feature = "alloc", feature = "std"))]
     /// # fn main() {
     /// # use memtable_core::prelude::*;
-    /// let table = DynamicTable::<usize>::new();
-    /// assert_eq!(table.columns().len(), 0);
+    /// let mut table = DynamicTable::from([[1, 2], [3, 4], [5, 6]]);
+    ///
+    /// assert_eq!(table.take_row(1), Some(DynamicList::from([3, 4])));
+    /// assert_eq!(table.row_cnt(), 3);
+    /// assert_eq!(table.cell(1, 0), None);
+    /// assert_eq!(table.cell(2, 0), Some(&5));
+    ///
+    /// assert_eq!(table.take_row(3), None);
     /// # }
     /// ```
+    fn take_row(&mut self, idx: usize) -> Option<Self::Row> {
+        if idx >= self.row_cnt() {
+            return None;
+        }
+
+        use list::List;
+        let col_cnt = self.col_cnt();
+        Some(Self::Row::new_filled_with(col_cnt, |col| {
+            self.remove_cell(idx, col)
+        }))
+    }
+
+    /// Returns an iterator that removes each row from the front of the table,
+    /// yielding the owned row only once it is reached
     ///
-    /// When has several columns:
+    /// The table is left without rows once the iterator is dropped, even if
+    /// it was not run to completion
+    ///
+    /// ### Examples
     ///
     /// ```
     /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
@@ -487,26 +847,27 @@ pub trait Table: Sized {
     /// # #[cfg(any(feature = "alloc", feature = "std"))]
     /// # fn main() {
     /// # use memtable_core::prelude::*;
-    /// let mut table = DynamicTable::<usize>::new();
-    /// table.push_column(vec![1, 2, 3]);
-    /// table.push_column(vec![4, 5, 6]);
+    /// let mut table = DynamicTable::from([[1, 2], [3, 4], [5, 6]]);
     ///
-    /// let mut columns = table.columns();
-    /// assert_eq!(columns.next().unwrap().copied().collect::<Vec<usize>>(), vec![1, 2, 3]);
-    /// assert_eq!(columns.next().unwrap().copied().collect::<Vec<usize>>(), vec![4, 5, 6]);
-    /// assert!(columns.next().is_none());
+    /// let mut rows = table.drain_all_rows();
+    /// assert_eq!(rows.len(), 3);
+    /// assert_eq!(rows.next(), Some(DynamicList::from([1, 2])));
+    /// assert_eq!(rows.next(), Some(DynamicList::from([3, 4])));
+    /// drop(rows);
+    ///
+    /// assert_eq!(table.row_cnt(), 0);
     /// # }
     /// ```
-    ///
-    fn columns(&self) -> iter::Columns<Self::Data, Self> {
-        iter::Columns::new(self)
+    fn drain_all_rows(&mut self) -> iter::DrainRows<'_, Self::Data, Self> {
+        iter::DrainRows::new(self)
     }
 
-    /// Returns an iterator of refs through a specific column in the table
+    /// Returns an iterator through pages of the table, each being an
+    /// iterator of refs through up to `page_size` rows
     ///
-    /// ### Examples
+    /// Panics if `page_size` is zero
     ///
-    /// When empty:
+    /// ### Examples
     ///
     /// ```
     /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
@@ -514,12 +875,32 @@ pub trait Table: Sized {
     /// # #[cfg(any(feature = "alloc", feature = "std"))]
     /// # fn main() {
     /// # use memtable_core::prelude::*;
-    /// let table = DynamicTable::<usize>::new();
-    /// assert_eq!(table.column(0).len(), 0);
+    /// let table = DynamicTable::from([[1, 2], [3, 4], [5, 6]]);
+    ///
+    /// let mut pages = table.pages(2);
+    /// assert_eq!(pages.len(), 2);
+    ///
+    /// let page = pages.next().unwrap();
+    /// assert_eq!(page.row_range(), 0..2);
+    /// assert_eq!(page.len(), 2);
+    ///
+    /// let page = pages.next().unwrap();
+    /// assert_eq!(page.row_range(), 2..3);
+    /// assert_eq!(page.len(), 1);
+    ///
+    /// assert!(pages.next().is_none());
     /// # }
     /// ```
+    fn pages(&self, page_size: usize) -> iter::Pages<'_, Self::Data, Self> {
+        iter::Pages::new(self, page_size)
+    }
+
+    /// Returns an iterator of refs through the rows of a specific page of the
+    /// table, where every page contains up to `page_size` rows
     ///
-    /// When has several columns:
+    /// Panics if `page_size` is zero
+    ///
+    /// ### Examples
     ///
     /// ```
     /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
@@ -527,27 +908,35 @@ pub trait Table: Sized {
     /// # #[cfg(any(feature = "alloc", feature = "std"))]
     /// # fn main() {
     /// # use memtable_core::prelude::*;
-    /// let mut table = DynamicTable::<usize>::new();
-    /// table.push_column(vec![1, 2, 3]);
-    /// table.push_column(vec![4, 5, 6]);
+    /// let table = DynamicTable::from([[1, 2], [3, 4], [5, 6]]);
     ///
-    /// let mut cells = table.column(0);
-    /// assert_eq!(cells.next().copied(), Some(1));
-    /// assert_eq!(cells.next().copied(), Some(2));
-    /// assert_eq!(cells.next().copied(), Some(3));
-    /// assert_eq!(cells.next(), None);
+    /// let mut page = table.page(1, 2);
+    /// assert_eq!(page.index(), 1);
+    /// assert_eq!(page.page_cnt(), 2);
+    /// assert!(page.has_prev());
+    /// assert!(!page.has_next());
+    ///
+    /// let mut row = page.next().unwrap();
+    /// assert_eq!(row.next(), Some(&5));
+    /// assert_eq!(row.next(), Some(&6));
+    /// assert!(page.next().is_none());
+    ///
+    /// // Pages past the end of the table have no rows
+    /// assert_eq!(table.page(5, 2).len(), 0);
     /// # }
     /// ```
-    ///
-    fn column(&self, idx: usize) -> iter::Column<Self::Data, Self> {
-        iter::Column::new(self, idx)
+    fn page(&self, idx: usize, page_size: usize) -> iter::Page<'_, Self::Data, Self> {
+        iter::Page::new(self, idx, page_size)
     }
 
-    /// Consumes the table and returns an iterator through a specific column in the table
+    /// Returns an iterator through bands of up to `n` rows of the table, each
+    /// being an iterator of refs through the rows within the band
     ///
-    /// ### Examples
+    /// This is the same as [`Table::pages`], named for batch processing
     ///
-    /// When empty:
+    /// Panics if `n` is zero
+    ///
+    /// ### Examples
     ///
     /// ```
     /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
@@ -555,12 +944,22 @@ pub trait Table: Sized {
     /// # #[cfg(any(feature = "alloc", feature = "std"))]
     /// # fn main() {
     /// # use memtable_core::prelude::*;
-    /// let table = DynamicTable::<usize>::new();
-    /// assert_eq!(table.into_column(0).len(), 0);
+    /// let table = DynamicTable::from([[1, 2], [3, 4], [5, 6]]);
+    ///
+    /// let sizes: Vec<usize> = table.row_chunks(2).map(|chunk| chunk.len()).collect();
+    /// assert_eq!(sizes, vec![2, 1]);
     /// # }
     /// ```
+    fn row_chunks(&self, n: usize) -> iter::Pages<'_, Self::Data, Self> {
+        self.pages(n)
+    }
+
+    /// Consumes the table and returns an iterator of new tables, each
+    /// containing the next band of up to `n` rows
     ///
-    /// When has several columns:
+    /// Panics if `n` is zero
+    ///
+    /// ### Examples
     ///
     /// ```
     /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
@@ -568,25 +967,19 @@ pub trait Table: Sized {
     /// # #[cfg(any(feature = "alloc", feature = "std"))]
     /// # fn main() {
     /// # use memtable_core::prelude::*;
-    /// let mut table = DynamicTable::<usize>::new();
-    /// table.push_column(vec![1, 2, 3]);
-    /// table.push_column(vec![4, 5, 6]);
+    /// let table = DynamicTable::from([[1, 2], [3, 4], [5, 6]]);
     ///
-    /// let mut cells = table.into_column(0);
-    /// assert_eq!(cells.next(), Some(1));
-    /// assert_eq!(cells.next(), Some(2));
-    /// assert_eq!(cells.next(), Some(3));
-    /// assert_eq!(cells.next(), None);
+    /// let mut chunks = table.into_row_chunks(2);
+    /// assert_eq!(chunks.next().unwrap(), [[1, 2], [3, 4]]);
+    /// assert_eq!(chunks.next().unwrap(), [[5, 6]]);
+    /// assert!(chunks.next().is_none());
     /// # }
     /// ```
-    ///
-    fn into_column(self, idx: usize) -> iter::IntoColumn<Self::Data, Self> {
-        iter::IntoColumn::new(self, idx)
+    fn into_row_chunks(self, n: usize) -> iter::IntoRowChunks<Self::Data, Self> {
+        iter::IntoRowChunks::new(self, n)
     }
 
-    /// Returns an iterator of refs through all cells in the table, starting
-    /// from the first row, iterating through all cells from beginning to end,
-    /// and then moving on to the next row
+    /// Returns an iterator of refs through all columns in the table
     ///
     /// ### Examples
     ///
@@ -599,11 +992,11 @@ pub trait Table: Sized {
     /// # fn main() {
     /// # use memtable_core::prelude::*;
     /// let table = DynamicTable::<usize>::new();
-    /// assert_eq!(table.cells().len(), 0);
+    /// assert_eq!(table.columns().len(), 0);
     /// # }
     /// ```
     ///
-    /// When has several rows & columns:
+    /// When has several columns:
     ///
     /// ```
     /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
@@ -612,27 +1005,21 @@ pub trait Table: Sized {
     /// # fn main() {
     /// # use memtable_core::prelude::*;
     /// let mut table = DynamicTable::<usize>::new();
-    /// table.push_row(vec![1, 2, 3]);
-    /// table.push_row(vec![4, 5, 6]);
+    /// table.push_column(vec![1, 2, 3]);
+    /// table.push_column(vec![4, 5, 6]);
     ///
-    /// let mut cells = table.cells();
-    /// assert_eq!(cells.next(), Some(&1));
-    /// assert_eq!(cells.next(), Some(&2));
-    /// assert_eq!(cells.next(), Some(&3));
-    /// assert_eq!(cells.next(), Some(&4));
-    /// assert_eq!(cells.next(), Some(&5));
-    /// assert_eq!(cells.next(), Some(&6));
-    /// assert_eq!(cells.next(), None);
+    /// let mut columns = table.columns();
+    /// assert_eq!(columns.next().unwrap().copied().collect::<Vec<usize>>(), vec![1, 2, 3]);
+    /// assert_eq!(columns.next().unwrap().copied().collect::<Vec<usize>>(), vec![4, 5, 6]);
+    /// assert!(columns.next().is_none());
     /// # }
     /// ```
     ///
-    fn cells(&self) -> iter::Cells<Self::Data, Self> {
-        iter::Cells::new(self)
+    fn columns(&self) -> iter::Columns<'_, Self::Data, Self> {
+        iter::Columns::new(self)
     }
 
-    /// Consumes the table and returns an iterator through all cells in the
-    /// table, starting from the first row, iterating through all cells from
-    /// beginning to end, and then moving on to the next row
+    /// Returns an iterator of refs through a specific column in the table
     ///
     /// ### Examples
     ///
@@ -645,11 +1032,11 @@ pub trait Table: Sized {
     /// # fn main() {
     /// # use memtable_core::prelude::*;
     /// let table = DynamicTable::<usize>::new();
-    /// assert_eq!(table.into_cells().len(), 0);
+    /// assert_eq!(table.column(0).len(), 0);
     /// # }
     /// ```
     ///
-    /// When has several rows & columns:
+    /// When has several columns:
     ///
     /// ```
     /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
@@ -658,32 +1045,29 @@ pub trait Table: Sized {
     /// # fn main() {
     /// # use memtable_core::prelude::*;
     /// let mut table = DynamicTable::<usize>::new();
-    /// table.push_row(vec![1, 2, 3]);
-    /// table.push_row(vec![4, 5, 6]);
+    /// table.push_column(vec![1, 2, 3]);
+    /// table.push_column(vec![4, 5, 6]);
     ///
-    /// let mut cells = table.into_cells();
-    /// assert_eq!(cells.next(), Some(1));
-    /// assert_eq!(cells.next(), Some(2));
-    /// assert_eq!(cells.next(), Some(3));
-    /// assert_eq!(cells.next(), Some(4));
-    /// assert_eq!(cells.next(), Some(5));
-    /// assert_eq!(cells.next(), Some(6));
+    /// let mut cells = table.column(0);
+    /// assert_eq!(cells.next().copied(), Some(1));
+    /// assert_eq!(cells.next().copied(), Some(2));
+    /// assert_eq!(cells.next().copied(), Some(3));
     /// assert_eq!(cells.next(), None);
     /// # }
     /// ```
     ///
-    fn into_cells(self) -> iter::IntoCells<Self::Data, Self> {
-        iter::IntoCells::new(self)
+    fn column(&self, idx: usize) -> iter::Column<'_, Self::Data, Self> {
+        iter::Column::new(self, idx)
     }
 
-    /// Returns whether or not a cell exists at the specified row & column. Note
-    /// that this is not the same as whether or not the table's current row &
-    /// column range would include a cell at that position! Rather, this is
-    /// reporting if a cell actually exists
+    /// Returns an iterator over every position within a specific column in
+    /// the table, yielding `None` for each missing cell
     ///
-    /// ### Examples
+    /// Unlike [`Table::column`], which stops at the first missing cell, this
+    /// always yields one item per row so that cells of sparse columns are
+    /// not lost
     ///
-    /// When has checking for a cell that doesn't exist:
+    /// ### Examples
     ///
     /// ```
     /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
@@ -692,30 +1076,24 @@ pub trait Table: Sized {
     /// # fn main() {
     /// # use memtable_core::prelude::*;
     /// let mut table = DynamicTable::new();
-    /// table.push_row(vec![1, 2, 3]);
-    /// assert!(!table.has_cell(0, 3));
-    /// # }
-    /// ```
+    /// table.push_column(vec![1, 2, 3]);
+    /// table.remove_cell(1, 0);
     ///
-    /// When has checking for a cell that does exist:
+    /// assert_eq!(table.column(0).count(), 1);
     ///
-    /// ```
-    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
-    /// # fn main() {}
-    /// # #[cfg(any(feature = "alloc", feature = "std"))]
-    /// # fn main() {
-    /// # use memtable_core::prelude::*;
-    /// let mut table = DynamicTable::new();
-    /// table.push_row(vec![1, 2, 3]);
-    /// assert!(table.has_cell(0, 2));
+    /// let mut cells = table.column_complete(0);
+    /// assert_eq!(cells.next(), Some(Some(&1)));
+    /// assert_eq!(cells.next(), Some(None));
+    /// assert_eq!(cells.next(), Some(Some(&3)));
+    /// assert_eq!(cells.next(), None);
     /// # }
     /// ```
-    fn has_cell(&self, row: usize, col: usize) -> bool {
-        self.cell(row, col).is_some()
+    fn column_complete(&self, idx: usize) -> iter::ColumnComplete<'_, Self::Data, Self> {
+        iter::ColumnComplete::new(self, idx)
     }
 
-    /// Inserts a new row into the table at the given position, shifting down
-    /// all rows after it
+    /// Returns a handle to a specific column in the table that can read and
+    /// modify its cells, or `None` if the column is out of bounds
     ///
     /// ### Examples
     ///
@@ -725,52 +1103,26 @@ pub trait Table: Sized {
     /// # #[cfg(any(feature = "alloc", feature = "std"))]
     /// # fn main() {
     /// # use memtable_core::prelude::*;
-    /// let mut table = DynamicTable::new();
-    /// table.push_row(vec![1, 2, 3]);
-    /// table.push_row(vec![4, 5, 6]);
-    ///
-    /// table.insert_row(0, vec![7, 8, 9]);
-    ///
-    /// let mut row = table.row(0);
-    /// assert_eq!(row.next(), Some(&7));
-    /// assert_eq!(row.next(), Some(&8));
-    /// assert_eq!(row.next(), Some(&9));
-    /// assert!(row.next().is_none());
+    /// let mut table = DynamicTable::from([[1, 2], [3, 4]]);
     ///
-    /// let mut row = table.row(1);
-    /// assert_eq!(row.next(), Some(&1));
-    /// assert_eq!(row.next(), Some(&2));
-    /// assert_eq!(row.next(), Some(&3));
-    /// assert!(row.next().is_none());
+    /// let mut column = table.column_handle(1).unwrap();
+    /// column.for_each_mut(|_, x| *x *= 10);
+    /// column.swap(0, 1);
     ///
-    /// let mut row = table.row(2);
-    /// assert_eq!(row.next(), Some(&4));
-    /// assert_eq!(row.next(), Some(&5));
-    /// assert_eq!(row.next(), Some(&6));
-    /// assert!(row.next().is_none());
+    /// assert_eq!(table, [[1, 40], [3, 20]]);
+    /// assert!(table.column_handle(2).is_none());
     /// # }
     /// ```
-    fn insert_row<I: IntoIterator<Item = Self::Data>>(&mut self, row: usize, cells: I) {
-        // First, we need to shift down all cells that would appear at this
-        // row or later
-        if self.row_cnt() > row {
-            // NOTE: Need to go in reverse, otherwise we would overwrite the
-            // row below when trying to shift down!
-            for row in (row..self.row_cnt()).rev() {
-                for col in (0..self.col_cnt()).rev() {
-                    if let Some(x) = self.remove_cell(row, col) {
-                        self.insert_cell(row + 1, col, x);
-                    }
-                }
-            }
-        }
-
-        for (col, x) in cells.into_iter().enumerate() {
-            self.insert_cell(row, col, x);
+    fn column_handle(&mut self, idx: usize) -> Option<handle::ColumnHandle<'_, Self::Data, Self>> {
+        if idx < self.col_cnt() {
+            Some(handle::ColumnHandle::new(self, idx))
+        } else {
+            None
         }
     }
 
-    /// Pushes a row to the end of the table
+    /// Returns a cursor pointing at the specified position of the table that
+    /// can be moved between cells to read and write them
     ///
     /// ### Examples
     ///
@@ -779,130 +1131,127 @@ pub trait Table: Sized {
     /// # fn main() {}
     /// # #[cfg(any(feature = "alloc", feature = "std"))]
     /// # fn main() {
-    /// # use memtable_core::prelude::*;
-    /// let mut table = DynamicTable::new();
-    /// table.push_row(vec![1, 2, 3]);
-    /// table.push_row(vec![4, 5, 6]);
+    /// # use memtable_core::{prelude::*, Position};
+    /// let mut table = DynamicTable::from([[1, 2], [3, 4]]);
     ///
-    /// let mut row = table.row(0);
-    /// assert_eq!(row.next(), Some(&1));
-    /// assert_eq!(row.next(), Some(&2));
-    /// assert_eq!(row.next(), Some(&3));
-    /// assert!(row.next().is_none());
+    /// let mut cursor = table.cursor(Position::new(0, 1));
+    /// assert_eq!(cursor.peek(), Some(&2));
     ///
-    /// let mut row = table.row(1);
-    /// assert_eq!(row.next(), Some(&4));
-    /// assert_eq!(row.next(), Some(&5));
-    /// assert_eq!(row.next(), Some(&6));
-    /// assert!(row.next().is_none());
+    /// cursor.next_in_column();
+    /// assert_eq!(cursor.set(5), Some(4));
+    /// assert_eq!(cursor.next_in_row(), None);
+    ///
+    /// assert_eq!(table, [[1, 2], [3, 5]]);
     /// # }
     /// ```
-    fn push_row<I: IntoIterator<Item = Self::Data>>(&mut self, cells: I) {
-        self.insert_row(self.row_cnt(), cells)
+    fn cursor(&mut self, pos: Position) -> cursor::TableCursor<'_, Self::Data, Self> {
+        cursor::TableCursor::new(self, pos)
     }
 
-    /// Removes the row at the specified position, shifting up all rows after it
+    /// Buffers the changes made through the closure and applies them to the
+    /// table all at once, returning the closure's result
     ///
-    /// If the row does not exist, then an empty row will be returned
+    /// Nothing is applied if the closure returns an error. If any check
+    /// registered with the transaction fails once the changes are applied,
+    /// the table is rolled back to its original state and the check's error
+    /// is returned
     ///
     /// ### Examples
     ///
-    /// Removing from the front:
-    ///
     /// ```
     /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
     /// # fn main() {}
     /// # #[cfg(any(feature = "alloc", feature = "std"))]
     /// # fn main() {
     /// # use memtable_core::prelude::*;
-    /// let mut table = DynamicTable::new();
-    /// table.push_row(vec![1, 2, 3]);
-    /// table.push_row(vec![4, 5, 6]);
-    ///
-    /// assert_eq!(table.remove_row(0), Some(DynamicList::from([1, 2, 3])));
-    /// assert_eq!(table.remove_row(0), Some(DynamicList::from([4, 5, 6])));
-    /// assert_eq!(table.remove_row(0), None);
+    /// let mut table = DynamicTable::from([[1, 2], [3, 4]]);
+    ///
+    /// let result = table.transaction(|tx| {
+    ///     tx.insert_cell(0, 0, 5);
+    ///     tx.remove_row(1);
+    ///     tx.check(|t| if t.row_cnt() > 1 { Ok(()) } else { Err("too few rows") });
+    ///     Ok(())
+    /// });
+    /// assert_eq!(result, Err("too few rows"));
+    /// assert_eq!(table, [[1, 2], [3, 4]]);
+    ///
+    /// let result: Result<(), &str> = table.transaction(|tx| {
+    ///     tx.insert_cell(0, 0, 5);
+    ///     tx.remove_row(1);
+    ///     Ok(())
+    /// });
+    /// assert_eq!(result, Ok(()));
+    /// assert_eq!(table, [[5, 2]]);
     /// # }
     /// ```
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[cfg_attr(feature = "docs", doc(cfg(any(alloc, std))))]
+    fn transaction<'c, R, E, F>(&mut self, f: F) -> Result<R, E>
+    where
+        Self: Sized,
+        Self::Data: Clone,
+        F: for<'t> FnOnce(
+            &mut transaction::Transaction<'t, 'c, Self::Data, Self, E>,
+        ) -> Result<R, E>,
+    {
+        transaction::Transaction::run(self, f)
+    }
+
+    /// Creates a new table by applying each operation in order to an empty
+    /// table, such as to rebuild a table from an event log
     ///
-    /// Removing from the back:
+    /// ### Examples
     ///
     /// ```
     /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
     /// # fn main() {}
     /// # #[cfg(any(feature = "alloc", feature = "std"))]
     /// # fn main() {
-    /// # use memtable_core::prelude::*;
-    /// let mut table = DynamicTable::new();
-    /// table.push_row(vec![1, 2, 3]);
-    /// table.push_row(vec![4, 5, 6]);
-    ///
-    /// assert_eq!(table.remove_row(1), Some(DynamicList::from([4, 5, 6])));
-    /// assert_eq!(table.remove_row(1), None);
-    /// assert_eq!(table.remove_row(0), Some(DynamicList::from([1, 2, 3])));
-    /// assert_eq!(table.remove_row(0), None);
+    /// # use memtable_core::{prelude::*, ops::Op};
+    /// let table = DynamicTable::apply_ops(vec![
+    ///     Op::PushRow(vec![1, 2]),
+    ///     Op::PushRow(vec![3, 4]),
+    ///     Op::RemoveColumn(0),
+    ///     Op::InsertCell { row: 0, col: 1, value: 5 },
+    ///     Op::InsertCell { row: 1, col: 1, value: 6 },
+    /// ]);
+    ///
+    /// assert_eq!(table, [[2, 5], [4, 6]]);
     /// # }
     /// ```
-    fn remove_row(&mut self, row: usize) -> Option<Self::Row> {
-        let row_cnt = self.row_cnt();
-        let col_cnt = self.col_cnt();
-
-        // If not in table range, return none
-        if row >= row_cnt {
-            return None;
-        }
-
-        // First, we remove all cells in the specified row and add them to the
-        // temporary table
-        use list::List;
-        let tmp = Self::Row::new_filled_with(col_cnt, |col| self.remove_cell(row, col));
-
-        // Second, we need to shift up all cells that would appear after this row
-        for row in (row + 1)..row_cnt {
-            for col in 0..col_cnt {
-                if let Some(x) = self.remove_cell(row, col) {
-                    self.insert_cell(row - 1, col, x);
-                }
-            }
-        }
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[cfg_attr(feature = "docs", doc(cfg(any(alloc, std))))]
+    fn apply_ops<I: IntoIterator<Item = ops::Op<Self::Data>>>(ops: I) -> Self
+    where
+        Self: Default,
+    {
+        let mut table = Self::default();
 
-        // Flag to table that the preferred row capacity is now one less
-        // if the row we removed was within capacity
-        if row < row_cnt {
-            self.set_preferred_row_cnt(row_cnt - 1);
+        for op in ops {
+            op.apply_to(&mut table);
         }
 
-        Some(tmp)
+        table
     }
 
-    /// Pops a row off the end of the table
+    /// Consumes the table and returns an iterator through a specific column in the table
     ///
     /// ### Examples
     ///
+    /// When empty:
+    ///
     /// ```
     /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
     /// # fn main() {}
     /// # #[cfg(any(feature = "alloc", feature = "std"))]
     /// # fn main() {
     /// # use memtable_core::prelude::*;
-    /// let mut table = DynamicTable::new();
-    /// table.push_row(vec![1, 2, 3]);
-    /// table.push_row(vec![4, 5, 6]);
-    ///
-    /// assert_eq!(table.pop_row(), Some(DynamicList::from([4, 5, 6])));
-    /// assert_eq!(table.pop_row(), Some(DynamicList::from([1, 2, 3])));
-    /// assert_eq!(table.pop_row(), None);
+    /// let table = DynamicTable::<usize>::new();
+    /// assert_eq!(table.into_column(0).len(), 0);
     /// # }
     /// ```
-    fn pop_row(&mut self) -> Option<Self::Row> {
-        let max_rows = self.row_cnt();
-        self.remove_row(if max_rows > 0 { max_rows - 1 } else { 0 })
-    }
-
-    /// Inserts a new column into the table at the given position, shifting right
-    /// all columns after it
     ///
-    /// ### Examples
+    /// When has several columns:
     ///
     /// ```
     /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
@@ -910,52 +1259,24 @@ pub trait Table: Sized {
     /// # #[cfg(any(feature = "alloc", feature = "std"))]
     /// # fn main() {
     /// # use memtable_core::prelude::*;
-    /// let mut table = DynamicTable::new();
+    /// let mut table = DynamicTable::<usize>::new();
     /// table.push_column(vec![1, 2, 3]);
     /// table.push_column(vec![4, 5, 6]);
     ///
-    /// table.insert_column(0, vec![7, 8, 9]);
-    ///
-    /// let mut column = table.column(0);
-    /// assert_eq!(column.next(), Some(&7));
-    /// assert_eq!(column.next(), Some(&8));
-    /// assert_eq!(column.next(), Some(&9));
-    /// assert!(column.next().is_none());
-    ///
-    /// let mut column = table.column(1);
-    /// assert_eq!(column.next(), Some(&1));
-    /// assert_eq!(column.next(), Some(&2));
-    /// assert_eq!(column.next(), Some(&3));
-    /// assert!(column.next().is_none());
-    ///
-    /// let mut column = table.column(2);
-    /// assert_eq!(column.next(), Some(&4));
-    /// assert_eq!(column.next(), Some(&5));
-    /// assert_eq!(column.next(), Some(&6));
-    /// assert!(column.next().is_none());
+    /// let mut cells = table.into_column(0);
+    /// assert_eq!(cells.next(), Some(1));
+    /// assert_eq!(cells.next(), Some(2));
+    /// assert_eq!(cells.next(), Some(3));
+    /// assert_eq!(cells.next(), None);
     /// # }
     /// ```
-    fn insert_column<I: IntoIterator<Item = Self::Data>>(&mut self, col: usize, cells: I) {
-        // First, we need to shift right all cells that would appear at this
-        // column or later
-        if self.col_cnt() > col {
-            // NOTE: Need to go in reverse, otherwise we would overwrite the
-            // column right when trying to shift right!
-            for row in (0..self.row_cnt()).rev() {
-                for col in (col..self.col_cnt()).rev() {
-                    if let Some(x) = self.remove_cell(row, col) {
-                        self.insert_cell(row, col + 1, x);
-                    }
-                }
-            }
-        }
-
-        for (row, x) in cells.into_iter().enumerate() {
-            self.insert_cell(row, col, x);
-        }
+    ///
+    fn into_column(self, idx: usize) -> iter::IntoColumn<Self::Data, Self> {
+        iter::IntoColumn::new(self, idx)
     }
 
-    /// Pushes a column to the end of the table
+    /// Returns a view of the table presenting only the columns at the given
+    /// indexes, in the order provided, without copying any cells
     ///
     /// ### Examples
     ///
@@ -965,52 +1286,1937 @@ pub trait Table: Sized {
     /// # #[cfg(any(feature = "alloc", feature = "std"))]
     /// # fn main() {
     /// # use memtable_core::prelude::*;
-    /// let mut table = DynamicTable::new();
-    /// table.push_column(vec![1, 2, 3]);
-    /// table.push_column(vec![4, 5, 6]);
-    ///
-    /// let mut column = table.column(0);
-    /// assert_eq!(column.next(), Some(&1));
-    /// assert_eq!(column.next(), Some(&2));
-    /// assert_eq!(column.next(), Some(&3));
-    /// assert!(column.next().is_none());
+    /// let table = DynamicTable::from([["a", "b", "c"], ["d", "e", "f"]]);
     ///
-    /// let mut column = table.column(1);
-    /// assert_eq!(column.next(), Some(&4));
-    /// assert_eq!(column.next(), Some(&5));
-    /// assert_eq!(column.next(), Some(&6));
-    /// assert!(column.next().is_none());
+    /// let view = table.select_columns([2, 0]);
+    /// assert_eq!(view.col_cnt(), 2);
+    /// assert_eq!(view.cell(0, 0), Some(&"c"));
+    /// assert_eq!(view.cell(1, 1), Some(&"d"));
     /// # }
     /// ```
-    fn push_column<I: IntoIterator<Item = Self::Data>>(&mut self, cells: I) {
-        self.insert_column(self.col_cnt(), cells)
+    fn select_columns<C: AsRef<[usize]>>(
+        &self,
+        cols: C,
+    ) -> view::SelectedColumns<'_, Self::Data, Self, C> {
+        view::SelectedColumns::new(self, cols)
     }
 
-    /// Removes the column at the specified position, shifting left all columns after it
-    ///
-    /// If the column does not exist, then an empty column will be returned
+    /// Returns a view of the table that hides every cell whose counterpart in
+    /// the mask is not `true`, without copying any cells
     ///
     /// ### Examples
     ///
-    /// Removing from the front:
-    ///
     /// ```
     /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
     /// # fn main() {}
     /// # #[cfg(any(feature = "alloc", feature = "std"))]
     /// # fn main() {
     /// # use memtable_core::prelude::*;
-    /// let mut table = DynamicTable::new();
-    /// table.push_column(vec![1, 2, 3]);
-    /// table.push_column(vec![4, 5, 6]);
+    /// let table = DynamicTable::from([[1, 2], [3, 4], [5, 6]]);
+    /// let mask = DynamicTable::from([[true, true], [false, false], [true, false]]);
     ///
-    /// assert_eq!(table.remove_column(0), Some(DynamicList::from([1, 2, 3])));
-    /// assert_eq!(table.remove_column(0), Some(DynamicList::from([4, 5, 6])));
-    /// assert_eq!(table.remove_column(0), None);
+    /// let view = table.mask(&mask);
+    /// assert_eq!(view.cell(1, 0), None);
+    /// assert_eq!(view.cells().map(|(_, x)| x).sum::<usize>(), 8);
+    /// assert_eq!(view.visible_rows().collect::<Vec<_>>(), vec![0, 2]);
     /// # }
     /// ```
+    fn mask<'a, M: Table<Data = bool>>(
+        &'a self,
+        mask: &'a M,
+    ) -> view::Masked<'a, Self::Data, Self, M> {
+        view::Masked::new(self, mask)
+    }
+
+    /// Returns a view of the table presenting only the rows that satisfy the
+    /// predicate, without copying any cells
     ///
-    /// Removing from the the back:
+    /// The matching rows are found once up front. The view does not borrow
+    /// the table, so the table can still be edited, after which
+    /// [`view::FilteredView::refresh`] finds the matching rows again
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let mut table = DynamicTable::from([["apple", "red"], ["lime", "green"], ["cherry", "red"]]);
+    ///
+    /// let mut view = table.filter_rows_view(|row| row.last() == Some(Some(&"red")));
+    /// assert_eq!(view.rows(), &[0, 2]);
+    /// assert_eq!(view.cell(&table, 1, 0), Some(&"cherry"));
+    ///
+    /// table.insert_cell(1, 1, "red");
+    /// view.refresh(&table);
+    /// assert_eq!(view.rows(), &[0, 1, 2]);
+    /// # }
+    /// ```
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[cfg_attr(feature = "docs", doc(cfg(any(alloc, std))))]
+    fn filter_rows_view<P>(&self, predicate: P) -> view::FilteredView<P>
+    where
+        P: FnMut(iter::RowComplete<'_, Self::Data, Self>) -> bool,
+    {
+        view::FilteredView::new(self, predicate)
+    }
+
+    /// Returns a rendering of the table as aligned text, which can be
+    /// customized with headers and a style prior to being displayed
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let table = DynamicTable::from([["alice", "30"], ["bob", "4"]]);
+    ///
+    /// let text = table.display().with_headers(&["name", "age"]).to_string();
+    /// assert_eq!(text, "name  | age\n------+----\nalice | 30\nbob   | 4");
+    /// # }
+    /// ```
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[cfg_attr(feature = "docs", doc(cfg(any(alloc, std))))]
+    fn display(&self) -> display::TextTable<'_, Self::Data, Self> {
+        display::TextTable::new(self)
+    }
+
+    /// Returns the width of the widest cell within each column, measured in
+    /// characters of the cell's rendered text, such as to size columns of a
+    /// grid up front
+    ///
+    /// If `max_sample` is provided, only that many rows from the start of the
+    /// table are measured. Missing cells have no width
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let table = DynamicTable::from([[1, 200], [3000, 4], [5, 60000]]);
+    ///
+    /// assert_eq!(table.column_display_widths(None), vec![4, 5]);
+    /// assert_eq!(table.column_display_widths(Some(2)), vec![4, 3]);
+    /// # }
+    /// ```
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[cfg_attr(feature = "docs", doc(cfg(any(alloc, std))))]
+    fn column_display_widths(&self, max_sample: Option<usize>) -> std::vec::Vec<usize>
+    where
+        Self::Data: core::fmt::Display,
+    {
+        let row_cnt = match max_sample {
+            Some(n) => core::cmp::min(n, self.row_cnt()),
+            None => self.row_cnt(),
+        };
+
+        (0..self.col_cnt())
+            .map(|col| {
+                (0..row_cnt)
+                    .filter_map(|row| self.cell(row, col))
+                    .map(display::text_width)
+                    .fold(0, usize::max)
+            })
+            .collect()
+    }
+
+    /// Consumes the table and returns a new table containing only the columns
+    /// at the given indexes, in the order provided
+    ///
+    /// Cells are moved rather than copied, so a column that is selected more
+    /// than once is only populated at its first position
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let table = DynamicTable::from([["a", "b", "c"], ["d", "e", "f"]]);
+    ///
+    /// let table = table.into_selected_columns([2, 0]);
+    /// assert_eq!(table, [["c", "a"], ["f", "d"]]);
+    /// # }
+    /// ```
+    fn into_selected_columns<C: AsRef<[usize]>>(mut self, cols: C) -> Self
+    where
+        Self: Default,
+    {
+        let mut table = Self::default();
+        let row_cnt = self.row_cnt();
+        let cols = cols.as_ref();
+
+        for row in 0..row_cnt {
+            for (new_col, col) in cols.iter().enumerate() {
+                if let Some(x) = self.remove_cell(row, *col) {
+                    table.insert_cell(row, new_col, x);
+                }
+            }
+        }
+
+        table.set_preferred_row_cnt(row_cnt);
+        table.set_preferred_col_cnt(cols.len());
+        table
+    }
+
+    /// Consumes the table and returns a new table with its cells rotated a
+    /// quarter turn clockwise, so the first column becomes the first row
+    /// read from the bottom up
+    ///
+    /// A table of `R` rows and `C` columns becomes one of `C` rows and `R`
+    /// columns; cells that fall outside of the new table's maximum capacity
+    /// are dropped
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let table = DynamicTable::from([[1, 2, 3], [4, 5, 6]]);
+    ///
+    /// let table = table.rotate_cw();
+    /// assert_eq!(table, [[4, 1], [5, 2], [6, 3]]);
+    /// # }
+    /// ```
+    fn rotate_cw(self) -> Self
+    where
+        Self: Default,
+    {
+        let row_cnt = self.row_cnt();
+        rotated(self, |pos| Position::new(pos.col, row_cnt - 1 - pos.row))
+    }
+
+    /// Consumes the table and returns a new table with its cells rotated a
+    /// quarter turn counterclockwise, so the last column becomes the first
+    /// row
+    ///
+    /// A table of `R` rows and `C` columns becomes one of `C` rows and `R`
+    /// columns; cells that fall outside of the new table's maximum capacity
+    /// are dropped
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let table = DynamicTable::from([[1, 2, 3], [4, 5, 6]]);
+    ///
+    /// let table = table.rotate_ccw();
+    /// assert_eq!(table, [[3, 6], [2, 5], [1, 4]]);
+    /// # }
+    /// ```
+    fn rotate_ccw(self) -> Self
+    where
+        Self: Default,
+    {
+        let col_cnt = self.col_cnt();
+        rotated(self, |pos| Position::new(col_cnt - 1 - pos.col, pos.row))
+    }
+
+    /// Mirrors the cells of the table in place from left to right, so the
+    /// first column becomes the last
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let mut table = DynamicTable::from([[1, 2, 3], [4, 5, 6]]);
+    ///
+    /// table.flip_horizontal();
+    /// assert_eq!(table, [[3, 2, 1], [6, 5, 4]]);
+    /// # }
+    /// ```
+    fn flip_horizontal(&mut self) {
+        let col_cnt = self.col_cnt();
+        for row in 0..self.row_cnt() {
+            for col in 0..col_cnt / 2 {
+                self.swap_cells(
+                    Position::new(row, col),
+                    Position::new(row, col_cnt - 1 - col),
+                );
+            }
+        }
+    }
+
+    /// Mirrors the cells of the table in place from top to bottom, so the
+    /// first row becomes the last
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let mut table = DynamicTable::from([[1, 2], [3, 4], [5, 6]]);
+    ///
+    /// table.flip_vertical();
+    /// assert_eq!(table, [[5, 6], [3, 4], [1, 2]]);
+    /// # }
+    /// ```
+    fn flip_vertical(&mut self) {
+        let row_cnt = self.row_cnt();
+        for row in 0..row_cnt / 2 {
+            for col in 0..self.col_cnt() {
+                self.swap_cells(
+                    Position::new(row, col),
+                    Position::new(row_cnt - 1 - row, col),
+                );
+            }
+        }
+    }
+
+    /// Shifts the rows of the table by the offset without changing its shape,
+    /// moving them down if positive and up if negative
+    ///
+    /// If `wrap` is true, rows shifted past either end wrap around to the
+    /// other; otherwise, they are dropped and the rows left vacant are empty
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let mut table = DynamicTable::from([[1, 2], [3, 4], [5, 6]]);
+    ///
+    /// table.shift_rows(1, true);
+    /// assert_eq!(table, [[5, 6], [1, 2], [3, 4]]);
+    ///
+    /// table.shift_rows(-2, false);
+    /// assert_eq!(table.cell(0, 0), Some(&3));
+    /// assert_eq!(table.cell(1, 0), None);
+    /// assert_eq!(table.row_cnt(), 3);
+    /// # }
+    /// ```
+    fn shift_rows(&mut self, offset: isize, wrap: bool) {
+        let (row_cnt, col_cnt) = (self.row_cnt(), self.col_cnt());
+        shift_lines(self, row_cnt, col_cnt, offset, wrap, Position::new);
+    }
+
+    /// Shifts the columns of the table by the offset without changing its
+    /// shape, moving them right if positive and left if negative
+    ///
+    /// If `wrap` is true, columns shifted past either end wrap around to the
+    /// other; otherwise, they are dropped and the columns left vacant are
+    /// empty
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let mut table = DynamicTable::from([[1, 2, 3], [4, 5, 6]]);
+    ///
+    /// table.shift_columns(-1, true);
+    /// assert_eq!(table, [[2, 3, 1], [5, 6, 4]]);
+    ///
+    /// table.shift_columns(2, false);
+    /// assert_eq!(table.cell(0, 2), Some(&2));
+    /// assert_eq!(table.cell(0, 0), None);
+    /// assert_eq!(table.col_cnt(), 3);
+    /// # }
+    /// ```
+    fn shift_columns(&mut self, offset: isize, wrap: bool) {
+        let (row_cnt, col_cnt) = (self.row_cnt(), self.col_cnt());
+        shift_lines(self, col_cnt, row_cnt, offset, wrap, |col, row| {
+            Position::new(row, col)
+        });
+    }
+
+    /// Returns an iterator of refs through all cells in the table, starting
+    /// from the first row, iterating through all cells from beginning to end,
+    /// and then moving on to the next row
+    ///
+    /// ### Examples
+    ///
+    /// When empty:
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let table = DynamicTable::<usize>::new();
+    /// assert_eq!(table.cells().len(), 0);
+    /// # }
+    /// ```
+    ///
+    /// When has several rows & columns:
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let mut table = DynamicTable::<usize>::new();
+    /// table.push_row(vec![1, 2, 3]);
+    /// table.push_row(vec![4, 5, 6]);
+    ///
+    /// let mut cells = table.cells();
+    /// assert_eq!(cells.next(), Some(&1));
+    /// assert_eq!(cells.next(), Some(&2));
+    /// assert_eq!(cells.next(), Some(&3));
+    /// assert_eq!(cells.next(), Some(&4));
+    /// assert_eq!(cells.next(), Some(&5));
+    /// assert_eq!(cells.next(), Some(&6));
+    /// assert_eq!(cells.next(), None);
+    /// # }
+    /// ```
+    ///
+    fn cells(&self) -> iter::Cells<'_, Self::Data, Self> {
+        iter::Cells::new(self)
+    }
+
+    /// Returns an iterator through the positions of every cell held by the
+    /// table, in no particular order
+    ///
+    /// By default, this checks every position within the table's rows and
+    /// columns. Sparse tables can override this to only visit the cells they
+    /// hold, which lets algorithms skip scanning mostly-empty tables
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// # use memtable_core::Position;
+    /// let mut table = DynamicTable::new();
+    /// table.insert_cell(0, 3, "a");
+    /// table.insert_cell(5, 1, "b");
+    ///
+    /// let mut positions: Vec<Position> = table.occupied_positions().collect();
+    /// positions.sort();
+    /// assert_eq!(positions, vec![Position::new(0, 3), Position::new(5, 1)]);
+    /// # }
+    /// ```
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[cfg_attr(feature = "docs", doc(cfg(any(alloc, std))))]
+    fn occupied_positions(&self) -> iter::OccupiedPositions<'_> {
+        let col_cnt = self.col_cnt();
+        std::boxed::Box::new(
+            (0..self.row_cnt())
+                .flat_map(move |row| (0..col_cnt).map(move |col| Position { row, col }))
+                .filter(move |pos| self.has_cell(pos.row, pos.col)),
+        )
+    }
+
+    /// Consumes the table and returns an iterator through all cells in the
+    /// table, starting from the first row, iterating through all cells from
+    /// beginning to end, and then moving on to the next row
+    ///
+    /// ### Examples
+    ///
+    /// When empty:
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let table = DynamicTable::<usize>::new();
+    /// assert_eq!(table.into_cells().len(), 0);
+    /// # }
+    /// ```
+    ///
+    /// When has several rows & columns:
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let mut table = DynamicTable::<usize>::new();
+    /// table.push_row(vec![1, 2, 3]);
+    /// table.push_row(vec![4, 5, 6]);
+    ///
+    /// let mut cells = table.into_cells();
+    /// assert_eq!(cells.next(), Some(1));
+    /// assert_eq!(cells.next(), Some(2));
+    /// assert_eq!(cells.next(), Some(3));
+    /// assert_eq!(cells.next(), Some(4));
+    /// assert_eq!(cells.next(), Some(5));
+    /// assert_eq!(cells.next(), Some(6));
+    /// assert_eq!(cells.next(), None);
+    /// # }
+    /// ```
+    ///
+    fn into_cells(self) -> iter::IntoCells<Self::Data, Self> {
+        iter::IntoCells::new(self)
+    }
+
+    /// Returns whether or not a cell exists at the specified row & column. Note
+    /// that this is not the same as whether or not the table's current row &
+    /// column range would include a cell at that position! Rather, this is
+    /// reporting if a cell actually exists
+    ///
+    /// ### Examples
+    ///
+    /// When has checking for a cell that doesn't exist:
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let mut table = DynamicTable::new();
+    /// table.push_row(vec![1, 2, 3]);
+    /// assert!(!table.has_cell(0, 3));
+    /// # }
+    /// ```
+    ///
+    /// When has checking for a cell that does exist:
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let mut table = DynamicTable::new();
+    /// table.push_row(vec![1, 2, 3]);
+    /// assert!(table.has_cell(0, 2));
+    /// # }
+    /// ```
+    fn has_cell(&self, row: usize, col: usize) -> bool {
+        self.cell(row, col).is_some()
+    }
+
+    /// Returns the position of the first cell (searching row by row) that is
+    /// equal to the given value
+    ///
+    /// By default, this scans every cell of the table. Tables that maintain
+    /// an index of their values can override this to answer without scanning
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// # use memtable_core::Position;
+    /// let table = DynamicTable::from([["a", "b"], ["c", "b"]]);
+    ///
+    /// assert_eq!(table.position_of(&"b"), Some(Position { row: 0, col: 1 }));
+    /// assert_eq!(table.position_of(&"d"), None);
+    /// # }
+    /// ```
+    fn position_of(&self, value: &Self::Data) -> Option<Position>
+    where
+        Self::Data: PartialEq,
+    {
+        self.find_cell(|x| x == value)
+    }
+
+    /// Returns an iterator through the positions of all cells (searching row
+    /// by row) that are equal to the given value
+    ///
+    /// By default, this scans the table. Tables that index their values can
+    /// override this to return the positions they already know about through
+    /// [`iter::PositionsOf::from_positions`]
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// # use memtable_core::Position;
+    /// let table = DynamicTable::from([["a", "b"], ["c", "b"]]);
+    ///
+    /// let mut positions = table.positions_of(&"b");
+    /// assert_eq!(positions.next(), Some(Position { row: 0, col: 1 }));
+    /// assert_eq!(positions.next(), Some(Position { row: 1, col: 1 }));
+    /// assert_eq!(positions.next(), None);
+    /// # }
+    /// ```
+    fn positions_of<'a>(&'a self, value: &'a Self::Data) -> iter::PositionsOf<'a, Self::Data, Self>
+    where
+        Self::Data: PartialEq,
+    {
+        iter::PositionsOf::new(self, value)
+    }
+
+    /// Returns true if every position within the rows and columns of the
+    /// table holds a cell, meaning the table has no gaps
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let mut table = DynamicTable::new();
+    /// table.push_row(vec![1, 2, 3]);
+    /// assert!(table.is_rectangular());
+    ///
+    /// table.push_row(vec![4, 5]);
+    /// assert!(!table.is_rectangular());
+    /// # }
+    /// ```
+    fn is_rectangular(&self) -> bool {
+        self.missing_positions().next().is_none()
+    }
+
+    /// Returns an iterator through the positions (searching row by row)
+    /// within the rows and columns of the table that have no cell
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::{prelude::*, Position};
+    /// let mut table = DynamicTable::new();
+    /// table.push_row(vec![1]);
+    /// table.push_row(vec![2, 3, 4]);
+    ///
+    /// let mut positions = table.missing_positions();
+    /// assert_eq!(positions.next(), Some(Position::new(0, 1)));
+    /// assert_eq!(positions.next(), Some(Position::new(0, 2)));
+    /// assert_eq!(positions.next(), None);
+    /// # }
+    /// ```
+    fn missing_positions(&self) -> iter::MissingPositions<'_, Self::Data, Self> {
+        iter::MissingPositions::new(self)
+    }
+
+    /// Fills every gap within the rows and columns of the table with a copy
+    /// of the given value, returning the positions that were filled
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::{prelude::*, Position};
+    /// let mut table = DynamicTable::new();
+    /// table.push_row(vec!["a", "b"]);
+    /// table.push_row(vec!["c"]);
+    ///
+    /// assert_eq!(table.repair(""), vec![Position::new(1, 1)]);
+    /// assert!(table.is_rectangular());
+    /// assert_eq!(table, [["a", "b"], ["c", ""]]);
+    /// # }
+    /// ```
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[cfg_attr(feature = "docs", doc(cfg(any(alloc, std))))]
+    fn repair(&mut self, fill: Self::Data) -> std::vec::Vec<Position>
+    where
+        Self::Data: Clone,
+    {
+        let missing: std::vec::Vec<Position> = self.missing_positions().collect();
+
+        for pos in missing.iter() {
+            self.insert_cell(pos.row, pos.col, fill.clone());
+        }
+
+        missing
+    }
+
+    /// Returns the position of the first cell (searching row by row) that
+    /// satisfies the given predicate
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// # use memtable_core::Position;
+    /// let mut table = DynamicTable::new();
+    /// table.push_row(vec![1, 2, 3]);
+    /// table.push_row(vec![4, 5, 6]);
+    ///
+    /// assert_eq!(table.find_cell(|x| x % 2 == 0), Some(Position { row: 0, col: 1 }));
+    /// assert_eq!(table.find_cell(|x| *x > 4), Some(Position { row: 1, col: 1 }));
+    /// assert_eq!(table.find_cell(|x| *x > 6), None);
+    /// # }
+    /// ```
+    fn find_cell<P: FnMut(&Self::Data) -> bool>(&self, mut f: P) -> Option<Position> {
+        for row in 0..self.row_cnt() {
+            for col in 0..self.col_cnt() {
+                if let Some(x) = self.cell(row, col) {
+                    if f(x) {
+                        return Some(Position { row, col });
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Returns the positions of all cells (searching row by row) that satisfy
+    /// the given predicate
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// # use memtable_core::Position;
+    /// let mut table = DynamicTable::new();
+    /// table.push_row(vec![1, 2, 3]);
+    /// table.push_row(vec![4, 5, 6]);
+    ///
+    /// assert_eq!(
+    ///     table.find_all_cells(|x| x % 2 == 0),
+    ///     vec![
+    ///         Position { row: 0, col: 1 },
+    ///         Position { row: 1, col: 0 },
+    ///         Position { row: 1, col: 2 },
+    ///     ],
+    /// );
+    /// assert!(table.find_all_cells(|x| *x > 6).is_empty());
+    /// # }
+    /// ```
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[cfg_attr(feature = "docs", doc(cfg(any(alloc, std))))]
+    fn find_all_cells<P: FnMut(&Self::Data) -> bool>(&self, mut f: P) -> std::vec::Vec<Position> {
+        let mut positions = std::vec::Vec::new();
+
+        for row in 0..self.row_cnt() {
+            for col in 0..self.col_cnt() {
+                if let Some(x) = self.cell(row, col) {
+                    if f(x) {
+                        positions.push(Position { row, col });
+                    }
+                }
+            }
+        }
+
+        positions
+    }
+
+    /// Returns the index of the first row whose cell in the specified column
+    /// satisfies the given predicate
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let mut table = DynamicTable::new();
+    /// table.push_row(vec!["alice", "admin"]);
+    /// table.push_row(vec!["bob", "user"]);
+    /// table.push_row(vec!["carol", "user"]);
+    ///
+    /// assert_eq!(table.find_row_where(1, |x| *x == "user"), Some(1));
+    /// assert_eq!(table.find_row_where(0, |x| x.starts_with('c')), Some(2));
+    /// assert_eq!(table.find_row_where(0, |x| *x == "dave"), None);
+    /// # }
+    /// ```
+    fn find_row_where<P: FnMut(&Self::Data) -> bool>(&self, col: usize, mut f: P) -> Option<usize> {
+        (0..self.row_cnt()).find(|row| matches!(self.cell(*row, col), Some(x) if f(x)))
+    }
+
+    /// Folds every cell (searching row by row) alongside its position into a
+    /// single value, stopping at the first error
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// # use memtable_core::Position;
+    /// let table = DynamicTable::from([[1u8, 2], [3, 4]]);
+    ///
+    /// let sum = table.try_fold_cells(0u8, |acc, _, x| acc.checked_add(*x).ok_or(()));
+    /// assert_eq!(sum, Ok(10));
+    ///
+    /// let first_odd_in_second_row = table.try_fold_cells((), |_, pos, x| {
+    ///     if pos.row > 0 && x % 2 == 1 { Err(pos) } else { Ok(()) }
+    /// });
+    /// assert_eq!(first_odd_in_second_row, Err(Position { row: 1, col: 0 }));
+    /// # }
+    /// ```
+    fn try_fold_cells<B, E, F>(&self, init: B, mut f: F) -> Result<B, E>
+    where
+        F: FnMut(B, Position, &Self::Data) -> Result<B, E>,
+    {
+        let mut acc = init;
+
+        for row in 0..self.row_cnt() {
+            for col in 0..self.col_cnt() {
+                if let Some(x) = self.cell(row, col) {
+                    acc = f(acc, Position { row, col }, x)?;
+                }
+            }
+        }
+
+        Ok(acc)
+    }
+
+    /// Returns true if any cell alongside its position satisfies the given
+    /// predicate, stopping at the first cell that does
+    ///
+    /// Cells are not guaranteed to be checked in any particular order, which
+    /// lets tables override this to only visit the cells they hold
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let table = DynamicTable::from([[1, 2], [3, 4]]);
+    ///
+    /// assert!(table.any_cell(|pos, x| pos.col == 1 && *x > 3));
+    /// assert!(!table.any_cell(|_, x| *x > 4));
+    /// # }
+    /// ```
+    fn any_cell<P: FnMut(Position, &Self::Data) -> bool>(&self, mut f: P) -> bool {
+        self.try_fold_cells((), |_, pos, x| if f(pos, x) { Err(()) } else { Ok(()) })
+            .is_err()
+    }
+
+    /// Returns true if every cell alongside its position satisfies the given
+    /// predicate, stopping at the first cell that does not
+    ///
+    /// Cells are not guaranteed to be checked in any particular order, which
+    /// lets tables override this to only visit the cells they hold
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let table = DynamicTable::from([[1, 2], [3, 4]]);
+    ///
+    /// assert!(table.all_cells(|pos, x| *x > pos.row));
+    /// assert!(!table.all_cells(|_, x| x % 2 == 0));
+    /// # }
+    /// ```
+    fn all_cells<P: FnMut(Position, &Self::Data) -> bool>(&self, mut f: P) -> bool {
+        !self.any_cell(|pos, x| !f(pos, x))
+    }
+
+    /// Fills every missing cell in the specified column with a copy of the
+    /// closest cell above it, returning the total cells that were filled
+    ///
+    /// Missing cells before the first cell in the column are left alone
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let mut table = DynamicTable::new();
+    /// table.insert_cell(1, 0, "a");
+    /// table.insert_cell(3, 0, "b");
+    /// table.insert_cell(4, 1, "x");
+    ///
+    /// assert_eq!(table.fill_down_column(0), 2);
+    /// assert_eq!(table.cell(0, 0), None);
+    /// assert_eq!(table.cell(2, 0), Some(&"a"));
+    /// assert_eq!(table.cell(4, 0), Some(&"b"));
+    /// # }
+    /// ```
+    fn fill_down_column(&mut self, idx: usize) -> usize
+    where
+        Self::Data: Clone,
+    {
+        let mut last = None;
+        let mut cnt = 0;
+
+        for row in 0..self.row_cnt() {
+            match self.cell(row, idx) {
+                Some(x) => last = Some(x.clone()),
+                None => {
+                    if let Some(x) = last.as_ref() {
+                        self.insert_cell(row, idx, x.clone());
+                        cnt += 1;
+                    }
+                }
+            }
+        }
+
+        cnt
+    }
+
+    /// Fills every missing cell in the specified row with a copy of the
+    /// closest cell to its left, returning the total cells that were filled
+    ///
+    /// Missing cells before the first cell in the row are left alone
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let mut table = DynamicTable::new();
+    /// table.insert_cell(0, 1, "a");
+    /// table.insert_cell(1, 3, "x");
+    ///
+    /// assert_eq!(table.fill_right_row(0), 2);
+    /// assert_eq!(table.cell(0, 0), None);
+    /// assert_eq!(table.cell(0, 2), Some(&"a"));
+    /// assert_eq!(table.cell(0, 3), Some(&"a"));
+    /// # }
+    /// ```
+    fn fill_right_row(&mut self, idx: usize) -> usize
+    where
+        Self::Data: Clone,
+    {
+        let mut last = None;
+        let mut cnt = 0;
+
+        for col in 0..self.col_cnt() {
+            match self.cell(idx, col) {
+                Some(x) => last = Some(x.clone()),
+                None => {
+                    if let Some(x) = last.as_ref() {
+                        self.insert_cell(idx, col, x.clone());
+                        cnt += 1;
+                    }
+                }
+            }
+        }
+
+        cnt
+    }
+
+    /// Returns an iterator through the running result of applying `f` to the
+    /// cells of the specified column from top to bottom, where `f` receives
+    /// the previous result (starting with `init`) and the current cell
+    ///
+    /// Every row of the column yields an entry, where a missing cell yields
+    /// `None` and leaves the running result unchanged, so each entry lines up
+    /// with the row it was computed from
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let table = DynamicTable::from([[3], [1], [4], [2]]);
+    ///
+    /// let column: Vec<_> = table.scan_column(0, 0, |max, x| *max.max(x)).collect();
+    /// assert_eq!(column, [Some(3), Some(3), Some(4), Some(4)]);
+    /// # }
+    /// ```
+    fn scan_column<F>(
+        &self,
+        idx: usize,
+        init: Self::Data,
+        f: F,
+    ) -> iter::ScanColumn<'_, Self::Data, Self, F>
+    where
+        Self::Data: Clone,
+        F: FnMut(&Self::Data, &Self::Data) -> Self::Data,
+    {
+        iter::ScanColumn::new(self, idx, Some(init), f)
+    }
+
+    /// Returns an iterator through the running sum of the cells of the
+    /// specified column, yielding `None` for missing cells
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let mut table = DynamicTable::from([[1], [2], [3]]);
+    /// table.remove_cell(1, 0);
+    ///
+    /// let column: Vec<_> = table.cumsum_column(0).collect();
+    /// assert_eq!(column, [Some(1), None, Some(4)]);
+    /// # }
+    /// ```
+    fn cumsum_column(&self, idx: usize) -> iter::CumulativeColumn<'_, Self::Data, Self>
+    where
+        Self::Data: Clone + core::ops::Add<Output = Self::Data>,
+    {
+        iter::ScanColumn::new(self, idx, None, |acc, x| acc.clone() + x.clone())
+    }
+
+    /// Returns an iterator through the running product of the cells of the
+    /// specified column, yielding `None` for missing cells
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let table = DynamicTable::from([[1], [2], [3], [4]]);
+    ///
+    /// let column: Vec<_> = table.cumprod_column(0).flatten().collect();
+    /// assert_eq!(column, [1, 2, 6, 24]);
+    /// # }
+    /// ```
+    fn cumprod_column(&self, idx: usize) -> iter::CumulativeColumn<'_, Self::Data, Self>
+    where
+        Self::Data: Clone + core::ops::Mul<Output = Self::Data>,
+    {
+        iter::ScanColumn::new(self, idx, None, |acc, x| acc.clone() * x.clone())
+    }
+
+    /// Replaces every cell equal to `old` with a copy of `new`, returning the
+    /// total cells that were replaced
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let mut table = DynamicTable::from([["n/a", "1"], ["2", "n/a"]]);
+    ///
+    /// assert_eq!(table.replace_all(&"n/a", ""), 2);
+    /// assert_eq!(table, [["", "1"], ["2", ""]]);
+    /// # }
+    /// ```
+    fn replace_all(&mut self, old: &Self::Data, new: Self::Data) -> usize
+    where
+        Self::Data: PartialEq + Clone,
+    {
+        (0..self.col_cnt())
+            .map(|col| self.replace_in_column(col, old, new.clone()))
+            .sum()
+    }
+
+    /// Replaces every cell in the specified column equal to `old` with a copy
+    /// of `new`, returning the total cells that were replaced
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let mut table = DynamicTable::from([[0, 0], [1, 0], [0, 2]]);
+    ///
+    /// assert_eq!(table.replace_in_column(0, &0, 9), 2);
+    /// assert_eq!(table, [[9, 0], [1, 0], [9, 2]]);
+    /// # }
+    /// ```
+    fn replace_in_column(&mut self, idx: usize, old: &Self::Data, new: Self::Data) -> usize
+    where
+        Self::Data: PartialEq + Clone,
+    {
+        let mut cnt = 0;
+
+        for row in 0..self.row_cnt() {
+            if self.cell(row, idx) == Some(old) {
+                self.insert_cell(row, idx, new.clone());
+                cnt += 1;
+            }
+        }
+
+        cnt
+    }
+
+    /// Sorts the rows of the table by the values of the specified columns,
+    /// comparing by each column in turn until two rows differ and ordering
+    /// them in that column's direction
+    ///
+    /// The sort is stable, so rows that compare equal keep their order.
+    /// Missing cells are considered smaller than any value
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let mut table = DynamicTable::from([
+    ///     ["sales", "bob", "3"],
+    ///     ["eng", "alice", "5"],
+    ///     ["sales", "carol", "7"],
+    ///     ["eng", "dave", "2"],
+    /// ]);
+    ///
+    /// table.sort_rows_by_columns(&[(0, SortOrder::Ascending), (2, SortOrder::Descending)]);
+    /// assert_eq!(table, [
+    ///     ["eng", "alice", "5"],
+    ///     ["eng", "dave", "2"],
+    ///     ["sales", "carol", "7"],
+    ///     ["sales", "bob", "3"],
+    /// ]);
+    /// # }
+    /// ```
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[cfg_attr(feature = "docs", doc(cfg(any(alloc, std))))]
+    fn sort_rows_by_columns(&mut self, keys: &[(usize, SortOrder)])
+    where
+        Self::Data: Ord,
+    {
+        self.sort_rows_by_columns_with(keys, Ord::cmp)
+    }
+
+    /// Sorts the rows of the table by the values of the specified columns
+    /// like [`Table::sort_rows_by_columns`], comparing values with the given
+    /// function
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let mut table = DynamicTable::from([[2.5, 1.0], [0.5, 2.0], [1.5, 3.0]]);
+    ///
+    /// table.sort_rows_by_columns_with(&[(0, SortOrder::Ascending)], |a, b| {
+    ///     a.partial_cmp(b).unwrap()
+    /// });
+    /// assert_eq!(table, [[0.5, 2.0], [1.5, 3.0], [2.5, 1.0]]);
+    /// # }
+    /// ```
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[cfg_attr(feature = "docs", doc(cfg(any(alloc, std))))]
+    fn sort_rows_by_columns_with<F>(&mut self, keys: &[(usize, SortOrder)], compare: F)
+    where
+        F: FnMut(&Self::Data, &Self::Data) -> core::cmp::Ordering,
+    {
+        let order = sorted_row_order(self, keys, compare);
+        reorder_rows(self, &order);
+    }
+
+    /// Removes every row that is an exact copy of an earlier row, keeping
+    /// the first occurrence and shifting up the rows after each removed row,
+    /// returning the total rows removed
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let mut table = DynamicTable::from([[1, 2], [3, 4], [1, 2], [1, 5]]);
+    ///
+    /// assert_eq!(table.dedup_rows(), 1);
+    /// assert_eq!(table, [[1, 2], [3, 4], [1, 5]]);
+    /// # }
+    /// ```
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[cfg_attr(feature = "docs", doc(cfg(any(alloc, std))))]
+    fn dedup_rows(&mut self) -> usize
+    where
+        Self::Data: core::hash::Hash + Eq,
+    {
+        let keep = unique_rows(self);
+        retain_rows(self, &keep)
+    }
+
+    /// Removes every row whose cell in the specified column matches that of
+    /// an earlier row, keeping the first occurrence and shifting up the rows
+    /// after each removed row, returning the total rows removed
+    ///
+    /// Rows without a cell in the column are always kept
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let mut table = DynamicTable::from([
+    ///     ["alice", "admin"],
+    ///     ["bob", "user"],
+    ///     ["alice", "user"],
+    /// ]);
+    ///
+    /// assert_eq!(table.dedup_rows_by_key(0), 1);
+    /// assert_eq!(table, [["alice", "admin"], ["bob", "user"]]);
+    /// # }
+    /// ```
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[cfg_attr(feature = "docs", doc(cfg(any(alloc, std))))]
+    fn dedup_rows_by_key(&mut self, col: usize) -> usize
+    where
+        Self::Data: core::hash::Hash + Eq,
+    {
+        let keep = unique_rows_by_key(self, col);
+        retain_rows(self, &keep)
+    }
+
+    /// Returns a new table containing copies of the first `n` rows of this
+    /// table, or all of the rows if the table has fewer than `n`
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let table = DynamicTable::from([[1, 2], [3, 4], [5, 6]]);
+    ///
+    /// assert_eq!(table.head(2), [[1, 2], [3, 4]]);
+    /// assert_eq!(table.head(5), [[1, 2], [3, 4], [5, 6]]);
+    /// # }
+    /// ```
+    fn head(&self, n: usize) -> Self
+    where
+        Self: Default,
+        Self::Data: Clone,
+    {
+        let row_cnt = core::cmp::min(n, self.row_cnt());
+        copy_rows(self, 0..row_cnt)
+    }
+
+    /// Returns a new table containing copies of the last `n` rows of this
+    /// table, or all of the rows if the table has fewer than `n`
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let table = DynamicTable::from([[1, 2], [3, 4], [5, 6]]);
+    ///
+    /// assert_eq!(table.tail(2), [[3, 4], [5, 6]]);
+    /// assert_eq!(table.tail(5), [[1, 2], [3, 4], [5, 6]]);
+    /// # }
+    /// ```
+    fn tail(&self, n: usize) -> Self
+    where
+        Self: Default,
+        Self::Data: Clone,
+    {
+        let row_cnt = self.row_cnt();
+        copy_rows(self, row_cnt.saturating_sub(n)..row_cnt)
+    }
+
+    /// Returns a new table containing copies of `n` randomly-chosen rows of
+    /// this table (or all of the rows if the table has fewer than `n`), kept
+    /// in their original order
+    ///
+    /// Randomness is provided by `rng`, which is given an exclusive upper
+    /// bound and must return a number from zero up to that bound. Every row
+    /// has an equal chance of being chosen as long as `rng` is uniform
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let table = DynamicTable::from([[1], [2], [3], [4], [5]]);
+    ///
+    /// // A simple linear congruential generator standing in for a real one
+    /// let mut seed = 42usize;
+    /// let rng = |bound: usize| {
+    ///     seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+    ///     (seed >> 16) % bound
+    /// };
+    ///
+    /// let sample = table.sample(3, rng);
+    /// assert_eq!(sample.row_cnt(), 3);
+    /// assert!(sample.cells().all(|x| (1..=5).contains(x)));
+    /// # }
+    /// ```
+    fn sample<R: FnMut(usize) -> usize>(&self, n: usize, mut rng: R) -> Self
+    where
+        Self: Default,
+        Self::Data: Clone,
+    {
+        let mut table = Self::default();
+        let row_cnt = self.row_cnt();
+        let col_cnt = self.col_cnt();
+        let mut needed = core::cmp::min(n, row_cnt);
+
+        // Selection sampling: each row is chosen with probability equal to
+        // the rows still needed over the rows still remaining, which picks
+        // exactly the rows needed while preserving their order
+        let mut next_row = 0;
+        for row in 0..row_cnt {
+            if needed == 0 {
+                break;
+            }
+
+            if rng(row_cnt - row) < needed {
+                for col in 0..col_cnt {
+                    if let Some(x) = self.cell(row, col) {
+                        table.insert_cell(next_row, col, x.clone());
+                    }
+                }
+
+                next_row += 1;
+                needed -= 1;
+            }
+        }
+
+        table.set_preferred_row_cnt(next_row);
+        table.set_preferred_col_cnt(col_cnt);
+        table
+    }
+
+    /// Inserts a new row into the table at the given position, shifting down
+    /// all rows after it
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let mut table = DynamicTable::new();
+    /// table.push_row(vec![1, 2, 3]);
+    /// table.push_row(vec![4, 5, 6]);
+    ///
+    /// table.insert_row(0, vec![7, 8, 9]);
+    ///
+    /// let mut row = table.row(0);
+    /// assert_eq!(row.next(), Some(&7));
+    /// assert_eq!(row.next(), Some(&8));
+    /// assert_eq!(row.next(), Some(&9));
+    /// assert!(row.next().is_none());
+    ///
+    /// let mut row = table.row(1);
+    /// assert_eq!(row.next(), Some(&1));
+    /// assert_eq!(row.next(), Some(&2));
+    /// assert_eq!(row.next(), Some(&3));
+    /// assert!(row.next().is_none());
+    ///
+    /// let mut row = table.row(2);
+    /// assert_eq!(row.next(), Some(&4));
+    /// assert_eq!(row.next(), Some(&5));
+    /// assert_eq!(row.next(), Some(&6));
+    /// assert!(row.next().is_none());
+    /// # }
+    /// ```
+    fn insert_row<I: IntoIterator<Item = Self::Data>>(&mut self, row: usize, cells: I) {
+        trace_span!(
+            "insert_row",
+            row,
+            shifted_cells = self.row_cnt().saturating_sub(row) * self.col_cnt()
+        );
+
+        // First, we need to shift down all cells that would appear at this
+        // row or later
+        if self.row_cnt() > row {
+            // NOTE: Need to go in reverse, otherwise we would overwrite the
+            // row below when trying to shift down!
+            for row in (row..self.row_cnt()).rev() {
+                for col in (0..self.col_cnt()).rev() {
+                    if let Some(x) = self.remove_cell(row, col) {
+                        self.insert_cell(row + 1, col, x);
+                    }
+                }
+            }
+        }
+
+        for (col, x) in cells.into_iter().enumerate() {
+            self.insert_cell(row, col, x);
+        }
+    }
+
+    /// Inserts the rows in order starting at the specified row, shifting
+    /// down all rows on or after it once by the total rows inserted rather
+    /// than once per row
+    ///
+    /// Without `alloc` or `std`, the rows cannot be counted up front, so each
+    /// row is inserted with [`Table::insert_row`] instead
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let mut table = DynamicTable::from([[1, 2], [7, 8]]);
+    ///
+    /// table.insert_rows(1, vec![vec![3, 4], vec![5, 6]]);
+    /// assert_eq!(table, [[1, 2], [3, 4], [5, 6], [7, 8]]);
+    /// # }
+    /// ```
+    fn insert_rows<I, R>(&mut self, row: usize, rows: I)
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = Self::Data>,
+    {
+        #[cfg(not(any(feature = "alloc", feature = "std")))]
+        for (i, cells) in rows.into_iter().enumerate() {
+            self.insert_row(row + i, cells);
+        }
+
+        #[cfg(any(feature = "alloc", feature = "std"))]
+        {
+            let rows: std::vec::Vec<R> = rows.into_iter().collect();
+            let n = rows.len();
+
+            trace_span!(
+                "insert_rows",
+                row,
+                rows = n,
+                shifted_cells = self.row_cnt().saturating_sub(row) * self.col_cnt()
+            );
+
+            // NOTE: Need to go in reverse, otherwise we would overwrite the rows
+            // below when trying to shift down!
+            if n > 0 && self.row_cnt() > row {
+                for row in (row..self.row_cnt()).rev() {
+                    for col in (0..self.col_cnt()).rev() {
+                        if let Some(x) = self.remove_cell(row, col) {
+                            self.insert_cell(row + n, col, x);
+                        }
+                    }
+                }
+            }
+
+            for (i, cells) in rows.into_iter().enumerate() {
+                for (col, x) in cells.into_iter().enumerate() {
+                    self.insert_cell(row + i, col, x);
+                }
+            }
+        }
+    }
+
+    /// Pushes a row to the end of the table
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let mut table = DynamicTable::new();
+    /// table.push_row(vec![1, 2, 3]);
+    /// table.push_row(vec![4, 5, 6]);
+    ///
+    /// let mut row = table.row(0);
+    /// assert_eq!(row.next(), Some(&1));
+    /// assert_eq!(row.next(), Some(&2));
+    /// assert_eq!(row.next(), Some(&3));
+    /// assert!(row.next().is_none());
+    ///
+    /// let mut row = table.row(1);
+    /// assert_eq!(row.next(), Some(&4));
+    /// assert_eq!(row.next(), Some(&5));
+    /// assert_eq!(row.next(), Some(&6));
+    /// assert!(row.next().is_none());
+    /// # }
+    /// ```
+    fn push_row<I: IntoIterator<Item = Self::Data>>(&mut self, cells: I) {
+        self.insert_row(self.row_cnt(), cells)
+    }
+
+    /// Pushes each of the rows to the end of the table in order
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let mut table = DynamicTable::from([[1, 2]]);
+    /// table.extend_rows(vec![vec![3, 4], vec![5, 6]]);
+    ///
+    /// assert_eq!(table, [[1, 2], [3, 4], [5, 6]]);
+    /// # }
+    /// ```
+    fn extend_rows<I, R>(&mut self, rows: I)
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = Self::Data>,
+    {
+        for row in rows {
+            self.push_row(row);
+        }
+    }
+
+    /// Pushes each of the rows to the end of the table like
+    /// [`Table::extend_rows`], checking the token before each row and
+    /// stopping once it is cancelled
+    ///
+    /// Rows pushed before cancellation remain in the table, and the returned
+    /// [`progress::PartialCompletion`] counts them
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::{prelude::*, progress::CancelToken};
+    /// let token = CancelToken::new();
+    /// let mut table = DynamicTable::new();
+    ///
+    /// let rows = (0..).map(|x| {
+    ///     if x == 2 {
+    ///         token.cancel();
+    ///     }
+    ///     vec![x]
+    /// });
+    /// let partial = table.extend_rows_cancellable(rows, &token).unwrap_err();
+    ///
+    /// assert_eq!(partial.done, 3);
+    /// assert_eq!(table, [[0], [1], [2]]);
+    /// # }
+    /// ```
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[cfg_attr(feature = "docs", doc(cfg(any(alloc, std))))]
+    fn extend_rows_cancellable<I, R>(
+        &mut self,
+        rows: I,
+        token: &progress::CancelToken,
+    ) -> Result<(), progress::PartialCompletion>
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = Self::Data>,
+    {
+        let mut rows = rows.into_iter();
+        let mut done = 0;
+
+        loop {
+            if token.is_cancelled() {
+                return Err(progress::PartialCompletion {
+                    done,
+                    total: done.saturating_add(rows.size_hint().0),
+                });
+            }
+
+            match rows.next() {
+                Some(row) => self.push_row(row),
+                None => return Ok(()),
+            }
+            done += 1;
+        }
+    }
+
+    /// Removes the row at the specified position, shifting up all rows after it
+    ///
+    /// If the row does not exist, then an empty row will be returned
+    ///
+    /// ### Examples
+    ///
+    /// Removing from the front:
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let mut table = DynamicTable::new();
+    /// table.push_row(vec![1, 2, 3]);
+    /// table.push_row(vec![4, 5, 6]);
+    ///
+    /// assert_eq!(table.remove_row(0), Some(DynamicList::from([1, 2, 3])));
+    /// assert_eq!(table.remove_row(0), Some(DynamicList::from([4, 5, 6])));
+    /// assert_eq!(table.remove_row(0), None);
+    /// # }
+    /// ```
+    ///
+    /// Removing from the back:
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let mut table = DynamicTable::new();
+    /// table.push_row(vec![1, 2, 3]);
+    /// table.push_row(vec![4, 5, 6]);
+    ///
+    /// assert_eq!(table.remove_row(1), Some(DynamicList::from([4, 5, 6])));
+    /// assert_eq!(table.remove_row(1), None);
+    /// assert_eq!(table.remove_row(0), Some(DynamicList::from([1, 2, 3])));
+    /// assert_eq!(table.remove_row(0), None);
+    /// # }
+    /// ```
+    fn remove_row(&mut self, row: usize) -> Option<Self::Row> {
+        let row_cnt = self.row_cnt();
+        let col_cnt = self.col_cnt();
+
+        // If not in table range, return none
+        if row >= row_cnt {
+            return None;
+        }
+
+        trace_span!(
+            "remove_row",
+            row,
+            shifted_cells = (row_cnt - row - 1) * col_cnt
+        );
+
+        // First, we remove all cells in the specified row and add them to the
+        // temporary table
+        use list::List;
+        let tmp = Self::Row::new_filled_with(col_cnt, |col| self.remove_cell(row, col));
+
+        // Second, we need to shift up all cells that would appear after this row
+        for row in (row + 1)..row_cnt {
+            for col in 0..col_cnt {
+                if let Some(x) = self.remove_cell(row, col) {
+                    self.insert_cell(row - 1, col, x);
+                }
+            }
+        }
+
+        // Flag to table that the preferred row capacity is now one less
+        // if the row we removed was within capacity
+        if row < row_cnt {
+            self.set_preferred_row_cnt(row_cnt - 1);
+        }
+
+        Some(tmp)
+    }
+
+    /// Pops a row off the end of the table
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let mut table = DynamicTable::new();
+    /// table.push_row(vec![1, 2, 3]);
+    /// table.push_row(vec![4, 5, 6]);
+    ///
+    /// assert_eq!(table.pop_row(), Some(DynamicList::from([4, 5, 6])));
+    /// assert_eq!(table.pop_row(), Some(DynamicList::from([1, 2, 3])));
+    /// assert_eq!(table.pop_row(), None);
+    /// # }
+    /// ```
+    fn pop_row(&mut self) -> Option<Self::Row> {
+        let max_rows = self.row_cnt();
+        self.remove_row(if max_rows > 0 { max_rows - 1 } else { 0 })
+    }
+
+    /// Treats the specified column as a primary key, replacing the first row
+    /// whose key matches that of the given row or pushing the given row to
+    /// the end of the table if no row matches, returning the replaced row
+    ///
+    /// A row without a cell in the key column never matches and is pushed
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let mut table = DynamicTable::new();
+    /// table.push_row(vec!["alice", "admin"]);
+    /// table.push_row(vec!["bob", "user"]);
+    ///
+    /// let old = table.upsert_row_by_key(0, vec!["bob", "admin"]);
+    /// assert_eq!(old, Some(DynamicList::from(["bob", "user"])));
+    /// assert_eq!(table.row(1).copied().collect::<Vec<_>>(), vec!["bob", "admin"]);
+    ///
+    /// assert_eq!(table.upsert_row_by_key(0, vec!["carol", "user"]), None);
+    /// assert_eq!(table.row_cnt(), 3);
+    /// # }
+    /// ```
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[cfg_attr(feature = "docs", doc(cfg(any(alloc, std))))]
+    fn upsert_row_by_key<I: IntoIterator<Item = Self::Data>>(
+        &mut self,
+        key_col: usize,
+        cells: I,
+    ) -> Option<Self::Row>
+    where
+        Self::Data: PartialEq,
+    {
+        let cells: std::vec::Vec<Self::Data> = cells.into_iter().collect();
+        let row = cells
+            .get(key_col)
+            .and_then(|key| self.find_row_where(key_col, |x| x == key));
+
+        match row {
+            Some(row) => {
+                let old = self.remove_row(row);
+                self.insert_row(row, cells);
+                old
+            }
+            None => {
+                self.push_row(cells);
+                None
+            }
+        }
+    }
+
+    /// Inserts a new column into the table at the given position, shifting right
+    /// all columns after it
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let mut table = DynamicTable::new();
+    /// table.push_column(vec![1, 2, 3]);
+    /// table.push_column(vec![4, 5, 6]);
+    ///
+    /// table.insert_column(0, vec![7, 8, 9]);
+    ///
+    /// let mut column = table.column(0);
+    /// assert_eq!(column.next(), Some(&7));
+    /// assert_eq!(column.next(), Some(&8));
+    /// assert_eq!(column.next(), Some(&9));
+    /// assert!(column.next().is_none());
+    ///
+    /// let mut column = table.column(1);
+    /// assert_eq!(column.next(), Some(&1));
+    /// assert_eq!(column.next(), Some(&2));
+    /// assert_eq!(column.next(), Some(&3));
+    /// assert!(column.next().is_none());
+    ///
+    /// let mut column = table.column(2);
+    /// assert_eq!(column.next(), Some(&4));
+    /// assert_eq!(column.next(), Some(&5));
+    /// assert_eq!(column.next(), Some(&6));
+    /// assert!(column.next().is_none());
+    /// # }
+    /// ```
+    fn insert_column<I: IntoIterator<Item = Self::Data>>(&mut self, col: usize, cells: I) {
+        trace_span!(
+            "insert_column",
+            col,
+            shifted_cells = self.col_cnt().saturating_sub(col) * self.row_cnt()
+        );
+
+        // First, we need to shift right all cells that would appear at this
+        // column or later
+        if self.col_cnt() > col {
+            // NOTE: Need to go in reverse, otherwise we would overwrite the
+            // column right when trying to shift right!
+            for row in (0..self.row_cnt()).rev() {
+                for col in (col..self.col_cnt()).rev() {
+                    if let Some(x) = self.remove_cell(row, col) {
+                        self.insert_cell(row, col + 1, x);
+                    }
+                }
+            }
+        }
+
+        for (row, x) in cells.into_iter().enumerate() {
+            self.insert_cell(row, col, x);
+        }
+    }
+
+    /// Inserts the columns in order starting at the specified column,
+    /// shifting right all columns on or after it once by the total columns
+    /// inserted rather than once per column
+    ///
+    /// Without `alloc` or `std`, the columns cannot be counted up front, so
+    /// each column is inserted with [`Table::insert_column`] instead
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let mut table = DynamicTable::from([[1, 4], [5, 8]]);
+    ///
+    /// table.insert_columns(1, vec![vec![2, 6], vec![3, 7]]);
+    /// assert_eq!(table, [[1, 2, 3, 4], [5, 6, 7, 8]]);
+    /// # }
+    /// ```
+    fn insert_columns<I, C>(&mut self, col: usize, cols: I)
+    where
+        I: IntoIterator<Item = C>,
+        C: IntoIterator<Item = Self::Data>,
+    {
+        #[cfg(not(any(feature = "alloc", feature = "std")))]
+        for (i, cells) in cols.into_iter().enumerate() {
+            self.insert_column(col + i, cells);
+        }
+
+        #[cfg(any(feature = "alloc", feature = "std"))]
+        {
+            let cols: std::vec::Vec<C> = cols.into_iter().collect();
+            let n = cols.len();
+
+            trace_span!(
+                "insert_columns",
+                col,
+                cols = n,
+                shifted_cells = self.col_cnt().saturating_sub(col) * self.row_cnt()
+            );
+
+            // NOTE: Need to go in reverse, otherwise we would overwrite the
+            // columns right when trying to shift right!
+            if n > 0 && self.col_cnt() > col {
+                for row in (0..self.row_cnt()).rev() {
+                    for col in (col..self.col_cnt()).rev() {
+                        if let Some(x) = self.remove_cell(row, col) {
+                            self.insert_cell(row, col + n, x);
+                        }
+                    }
+                }
+            }
+
+            for (i, cells) in cols.into_iter().enumerate() {
+                for (row, x) in cells.into_iter().enumerate() {
+                    self.insert_cell(row, col + i, x);
+                }
+            }
+        }
+    }
+
+    /// Pushes a column to the end of the table
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let mut table = DynamicTable::new();
+    /// table.push_column(vec![1, 2, 3]);
+    /// table.push_column(vec![4, 5, 6]);
+    ///
+    /// let mut column = table.column(0);
+    /// assert_eq!(column.next(), Some(&1));
+    /// assert_eq!(column.next(), Some(&2));
+    /// assert_eq!(column.next(), Some(&3));
+    /// assert!(column.next().is_none());
+    ///
+    /// let mut column = table.column(1);
+    /// assert_eq!(column.next(), Some(&4));
+    /// assert_eq!(column.next(), Some(&5));
+    /// assert_eq!(column.next(), Some(&6));
+    /// assert!(column.next().is_none());
+    /// # }
+    /// ```
+    fn push_column<I: IntoIterator<Item = Self::Data>>(&mut self, cells: I) {
+        self.insert_column(self.col_cnt(), cells)
+    }
+
+    /// Removes the column at the specified position, shifting left all columns after it
+    ///
+    /// If the column does not exist, then an empty column will be returned
+    ///
+    /// ### Examples
+    ///
+    /// Removing from the front:
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let mut table = DynamicTable::new();
+    /// table.push_column(vec![1, 2, 3]);
+    /// table.push_column(vec![4, 5, 6]);
+    ///
+    /// assert_eq!(table.remove_column(0), Some(DynamicList::from([1, 2, 3])));
+    /// assert_eq!(table.remove_column(0), Some(DynamicList::from([4, 5, 6])));
+    /// assert_eq!(table.remove_column(0), None);
+    /// # }
+    /// ```
+    ///
+    /// Removing from the the back:
     ///
     /// ```
     /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
@@ -1028,39 +3234,368 @@ pub trait Table: Sized {
     /// assert_eq!(table.remove_column(0), None);
     /// # }
     /// ```
-    fn remove_column(&mut self, col: usize) -> Option<Self::Column> {
-        let row_cnt = self.row_cnt();
-        let col_cnt = self.col_cnt();
+    fn remove_column(&mut self, col: usize) -> Option<Self::Column> {
+        let row_cnt = self.row_cnt();
+        let col_cnt = self.col_cnt();
+
+        // If not in table range, return none
+        if col >= col_cnt {
+            return None;
+        }
+
+        trace_span!(
+            "remove_column",
+            col,
+            shifted_cells = (col_cnt - col - 1) * row_cnt
+        );
+
+        // First, we remove all cells in the specified column and add them to the
+        // temporary table
+        use list::List;
+        let tmp = Self::Column::new_filled_with(row_cnt, |row| self.remove_cell(row, col));
+
+        // Second, we need to shift left all cells that would appear after this column
+        for row in 0..row_cnt {
+            for col in (col + 1)..col_cnt {
+                if let Some(x) = self.remove_cell(row, col) {
+                    self.insert_cell(row, col - 1, x);
+                }
+            }
+        }
+
+        // Flag to table that the preferred column capacity is now one less
+        // if the column we removed was within capacity
+        if col < col_cnt {
+            self.set_preferred_col_cnt(col_cnt - 1);
+        }
+
+        Some(tmp)
+    }
+
+    /// Pops a column off the end of the table
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let mut table = DynamicTable::new();
+    /// table.push_column(vec![1, 2, 3]);
+    /// table.push_column(vec![4, 5, 6]);
+    ///
+    /// assert_eq!(table.pop_column(), Some(DynamicList::from([4, 5, 6])));
+    /// assert_eq!(table.pop_column(), Some(DynamicList::from([1, 2, 3])));
+    /// assert_eq!(table.pop_column(), None);
+    /// # }
+    /// ```
+    fn pop_column(&mut self) -> Option<Self::Column> {
+        let max_cols = self.col_cnt();
+        self.remove_column(if max_cols > 0 { max_cols - 1 } else { 0 })
+    }
+
+    /// Consumes the table and splits it into two tables, the first containing
+    /// the rows before `idx` and the second containing the rows from `idx`
+    /// onward
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let table = DynamicTable::from([[1, 2], [3, 4], [5, 6]]);
+    ///
+    /// let (top, bottom) = table.split_at_row(1);
+    /// assert_eq!(top, [[1, 2]]);
+    /// assert_eq!(bottom, [[3, 4], [5, 6]]);
+    /// # }
+    /// ```
+    fn split_at_row(mut self, idx: usize) -> (Self, Self)
+    where
+        Self: Default,
+    {
+        let row_cnt = self.row_cnt();
+        let col_cnt = self.col_cnt();
+        let idx = core::cmp::min(idx, row_cnt);
+
+        let mut top = Self::default();
+        move_cells(&mut self, &mut top, 0..idx, 0..col_cnt, Position::new(0, 0));
+        top.set_preferred_row_cnt(idx);
+        top.set_preferred_col_cnt(col_cnt);
+
+        let mut bottom = Self::default();
+        move_cells(
+            &mut self,
+            &mut bottom,
+            idx..row_cnt,
+            0..col_cnt,
+            Position::new(0, 0),
+        );
+        bottom.set_preferred_row_cnt(row_cnt - idx);
+        bottom.set_preferred_col_cnt(col_cnt);
+
+        (top, bottom)
+    }
+
+    /// Consumes the table and splits it into two tables, the first containing
+    /// the columns before `idx` and the second containing the columns from
+    /// `idx` onward
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let table = DynamicTable::from([[1, 2, 3], [4, 5, 6]]);
+    ///
+    /// let (left, right) = table.split_at_column(2);
+    /// assert_eq!(left, [[1, 2], [4, 5]]);
+    /// assert_eq!(right, [[3], [6]]);
+    /// # }
+    /// ```
+    fn split_at_column(mut self, idx: usize) -> (Self, Self)
+    where
+        Self: Default,
+    {
+        let row_cnt = self.row_cnt();
+        let col_cnt = self.col_cnt();
+        let idx = core::cmp::min(idx, col_cnt);
+
+        let mut left = Self::default();
+        move_cells(
+            &mut self,
+            &mut left,
+            0..row_cnt,
+            0..idx,
+            Position::new(0, 0),
+        );
+        left.set_preferred_row_cnt(row_cnt);
+        left.set_preferred_col_cnt(idx);
+
+        let mut right = Self::default();
+        move_cells(
+            &mut self,
+            &mut right,
+            0..row_cnt,
+            idx..col_cnt,
+            Position::new(0, 0),
+        );
+        right.set_preferred_row_cnt(row_cnt);
+        right.set_preferred_col_cnt(col_cnt - idx);
+
+        (left, right)
+    }
+
+    /// Moves all rows of the other table below the rows of this table
+    ///
+    /// Fails without changing either table if both tables have rows but a
+    /// different number of columns, or if this table cannot hold the
+    /// additional rows
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let mut table = DynamicTable::from([[1, 2]]);
+    ///
+    /// table.concat_rows(DynamicTable::from([[3, 4], [5, 6]])).unwrap();
+    /// assert_eq!(table, [[1, 2], [3, 4], [5, 6]]);
+    ///
+    /// assert!(table.concat_rows(DynamicTable::from([[7]])).is_err());
+    /// # }
+    /// ```
+    fn concat_rows<T: Table<Data = Self::Data>>(&mut self, mut other: T) -> Result<(), ShapeError> {
+        let row_cnt = self.row_cnt();
+        let col_cnt = self.col_cnt();
+        let other_row_cnt = other.row_cnt();
+        let other_col_cnt = other.col_cnt();
+
+        if row_cnt > 0 && other_row_cnt > 0 && col_cnt != other_col_cnt {
+            return Err(ShapeError::ColumnMismatch {
+                expected: col_cnt,
+                actual: other_col_cnt,
+            });
+        }
+
+        let required = row_cnt + other_row_cnt;
+        let capacity = self.max_row_capacity();
+        if matches!(capacity.limit(), Some(x) if x < required) {
+            return Err(ShapeError::ExceedsCapacity { capacity, required });
+        }
+
+        move_cells(
+            &mut other,
+            self,
+            0..other_row_cnt,
+            0..other_col_cnt,
+            Position::new(row_cnt, 0),
+        );
+        self.set_preferred_row_cnt(required);
+        self.set_preferred_col_cnt(core::cmp::max(col_cnt, other_col_cnt));
+
+        Ok(())
+    }
+
+    /// Moves all columns of the other table beside the columns of this table
+    ///
+    /// Fails without changing either table if both tables have columns but a
+    /// different number of rows, or if this table cannot hold the
+    /// additional columns
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let mut table = DynamicTable::from([[1], [4]]);
+    ///
+    /// table.concat_columns(DynamicTable::from([[2, 3], [5, 6]])).unwrap();
+    /// assert_eq!(table, [[1, 2, 3], [4, 5, 6]]);
+    ///
+    /// assert!(table.concat_columns(DynamicTable::from([[7]])).is_err());
+    /// # }
+    /// ```
+    fn concat_columns<T: Table<Data = Self::Data>>(
+        &mut self,
+        mut other: T,
+    ) -> Result<(), ShapeError> {
+        let row_cnt = self.row_cnt();
+        let col_cnt = self.col_cnt();
+        let other_row_cnt = other.row_cnt();
+        let other_col_cnt = other.col_cnt();
+
+        if col_cnt > 0 && other_col_cnt > 0 && row_cnt != other_row_cnt {
+            return Err(ShapeError::RowMismatch {
+                expected: row_cnt,
+                actual: other_row_cnt,
+            });
+        }
+
+        let required = col_cnt + other_col_cnt;
+        let capacity = self.max_column_capacity();
+        if matches!(capacity.limit(), Some(x) if x < required) {
+            return Err(ShapeError::ExceedsCapacity { capacity, required });
+        }
+
+        move_cells(
+            &mut other,
+            self,
+            0..other_row_cnt,
+            0..other_col_cnt,
+            Position::new(0, col_cnt),
+        );
+        self.set_preferred_row_cnt(core::cmp::max(row_cnt, other_row_cnt));
+        self.set_preferred_col_cnt(required);
+
+        Ok(())
+    }
+
+    /// Copies the cells of the other table into the rectangular region of
+    /// this table whose top-left cell is at the specified row and column,
+    /// overwriting the region so that it matches the other table exactly
+    ///
+    /// Cells missing from the other table are removed from the region.
+    /// Fails without changing the table if it cannot hold the region, or if
+    /// the region would extend past the largest row or column that can be
+    /// counted
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # #[cfg(not(any(feature = "alloc", feature = "std")))]
+    /// # fn main() {}
+    /// # #[cfg(any(feature = "alloc", feature = "std"))]
+    /// # fn main() {
+    /// # use memtable_core::prelude::*;
+    /// let mut table = FixedTable::from([[0, 0, 0], [0, 0, 0], [0, 0, 0]]);
+    ///
+    /// table.set_block(1, 1, &FixedTable::from([[1, 2], [3, 4]])).unwrap();
+    /// assert_eq!(table, [[0, 0, 0], [0, 1, 2], [0, 3, 4]]);
+    ///
+    /// assert!(table.set_block(2, 0, &FixedTable::from([[1], [2]])).is_err());
+    /// # }
+    /// ```
+    fn set_block<T: Table<Data = Self::Data>>(
+        &mut self,
+        top_row: usize,
+        left_col: usize,
+        other: &T,
+    ) -> Result<(), ShapeError>
+    where
+        Self::Data: Clone,
+    {
+        let other_row_cnt = other.row_cnt();
+        let other_col_cnt = other.col_cnt();
+        if other_row_cnt == 0 || other_col_cnt == 0 {
+            return Ok(());
+        }
 
-        // If not in table range, return none
-        if col >= col_cnt {
-            return None;
+        let required_rows = top_row
+            .checked_add(other_row_cnt)
+            .ok_or(ShapeError::Overflow)?;
+        let capacity = self.max_row_capacity();
+        if matches!(capacity.limit(), Some(x) if x < required_rows) {
+            return Err(ShapeError::ExceedsCapacity {
+                capacity,
+                required: required_rows,
+            });
         }
 
-        // First, we remove all cells in the specified column and add them to the
-        // temporary table
-        use list::List;
-        let tmp = Self::Column::new_filled_with(row_cnt, |row| self.remove_cell(row, col));
+        let required_cols = left_col
+            .checked_add(other_col_cnt)
+            .ok_or(ShapeError::Overflow)?;
+        let capacity = self.max_column_capacity();
+        if matches!(capacity.limit(), Some(x) if x < required_cols) {
+            return Err(ShapeError::ExceedsCapacity {
+                capacity,
+                required: required_cols,
+            });
+        }
 
-        // Second, we need to shift left all cells that would appear after this column
-        for row in 0..row_cnt {
-            for col in (col + 1)..col_cnt {
-                if let Some(x) = self.remove_cell(row, col) {
-                    self.insert_cell(row, col - 1, x);
+        for row in 0..other_row_cnt {
+            for col in 0..other_col_cnt {
+                match other.cell(row, col) {
+                    Some(x) => {
+                        self.insert_cell(top_row + row, left_col + col, x.clone());
+                    }
+                    None => {
+                        self.remove_cell(top_row + row, left_col + col);
+                    }
                 }
             }
         }
 
-        // Flag to table that the preferred column capacity is now one less
-        // if the column we removed was within capacity
-        if col < col_cnt {
-            self.set_preferred_col_cnt(col_cnt - 1);
+        if self.row_cnt() < required_rows {
+            self.set_preferred_row_cnt(required_rows);
         }
 
-        Some(tmp)
+        if self.col_cnt() < required_cols {
+            self.set_preferred_col_cnt(required_cols);
+        }
+
+        Ok(())
     }
 
-    /// Pops a column off the end of the table
+    /// Returns a new table containing copies of the cells within the
+    /// specified rows and columns, shifted so that the first cell of the
+    /// ranges becomes the first cell of the new table
+    ///
+    /// Portions of the ranges beyond the table are ignored
     ///
     /// ### Examples
     ///
@@ -1070,19 +3605,270 @@ pub trait Table: Sized {
     /// # #[cfg(any(feature = "alloc", feature = "std"))]
     /// # fn main() {
     /// # use memtable_core::prelude::*;
-    /// let mut table = DynamicTable::new();
-    /// table.push_column(vec![1, 2, 3]);
-    /// table.push_column(vec![4, 5, 6]);
+    /// let table = DynamicTable::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
     ///
-    /// assert_eq!(table.pop_column(), Some(DynamicList::from([4, 5, 6])));
-    /// assert_eq!(table.pop_column(), Some(DynamicList::from([1, 2, 3])));
-    /// assert_eq!(table.pop_column(), None);
+    /// assert_eq!(table.get_block(1..3, 0..2), [[4, 5], [7, 8]]);
+    /// assert_eq!(table.get_block(2..5, 2..5), [[9]]);
     /// # }
     /// ```
-    fn pop_column(&mut self) -> Option<Self::Column> {
-        let max_cols = self.col_cnt();
-        self.remove_column(if max_cols > 0 { max_cols - 1 } else { 0 })
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[cfg_attr(feature = "docs", doc(cfg(any(alloc, std))))]
+    fn get_block(
+        &self,
+        rows: core::ops::Range<usize>,
+        cols: core::ops::Range<usize>,
+    ) -> DynamicTable<Self::Data>
+    where
+        Self::Data: Clone,
+    {
+        let row_end = core::cmp::min(rows.end, self.row_cnt());
+        let col_end = core::cmp::min(cols.end, self.col_cnt());
+        let rows = core::cmp::min(rows.start, row_end)..row_end;
+        let cols = core::cmp::min(cols.start, col_end)..col_end;
+
+        let mut block = DynamicTable::new();
+        for row in rows.clone() {
+            for col in cols.clone() {
+                if let Some(x) = self.cell(row, col) {
+                    block.insert_cell(row - rows.start, col - cols.start, x.clone());
+                }
+            }
+        }
+
+        block.set_preferred_row_cnt(rows.len());
+        block.set_preferred_col_cnt(cols.len());
+        block
+    }
+}
+
+/// Moves every cell of the table into a new table with its rows & columns
+/// swapped, placing each cell at the position given by the function
+fn rotated<T: Table + Default>(mut table: T, mut f: impl FnMut(Position) -> Position) -> T {
+    let row_cnt = table.row_cnt();
+    let col_cnt = table.col_cnt();
+    let mut new_table = T::default();
+
+    for row in 0..row_cnt {
+        for col in 0..col_cnt {
+            if let Some(x) = table.remove_cell(row, col) {
+                let pos = f(Position::new(row, col));
+                new_table.insert_cell(pos.row, pos.col, x);
+            }
+        }
+    }
+
+    new_table.set_preferred_row_cnt(col_cnt);
+    new_table.set_preferred_col_cnt(row_cnt);
+    new_table
+}
+
+/// Shifts the lines (rows or columns) of the table by moving their cells one
+/// at a time, where `pos` maps the index of a line and the index of a cell
+/// within that line to the cell's position
+fn shift_lines<T: Table>(
+    table: &mut T,
+    line_cnt: usize,
+    cell_cnt: usize,
+    offset: isize,
+    wrap: bool,
+    pos: impl Fn(usize, usize) -> Position,
+) {
+    if line_cnt == 0 || offset == 0 {
+        return;
+    }
+
+    if wrap {
+        // Rotating the lines by k is the same as reversing all of them and
+        // then reversing the first k and the rest separately
+        let k = utils::shifted_index(0, line_cnt, offset, true).unwrap_or_default();
+        for &(start, end) in &[(0, line_cnt), (0, k), (k, line_cnt)] {
+            for i in 0..(end - start) / 2 {
+                for j in 0..cell_cnt {
+                    table.swap_cells(pos(start + i, j), pos(end - 1 - i, j));
+                }
+            }
+        }
+    } else {
+        // Visit the lines from the end being shifted towards so that each
+        // cell is moved into a line that has already been emptied
+        for n in 0..line_cnt {
+            let line = if offset > 0 { line_cnt - 1 - n } else { n };
+            let new_line = utils::shifted_index(line, line_cnt, offset, false);
+
+            for j in 0..cell_cnt {
+                let from = pos(line, j);
+                if let (Some(x), Some(new_line)) = (table.remove_cell(from.row, from.col), new_line)
+                {
+                    let to = pos(new_line, j);
+                    table.insert_cell(to.row, to.col, x);
+                }
+            }
+        }
+    }
+}
+
+/// Moves the cells within the rows & columns of one table into another,
+/// placing the first of them at the given position
+fn move_cells<T: Table, U: Table<Data = T::Data>>(
+    from: &mut T,
+    to: &mut U,
+    rows: core::ops::Range<usize>,
+    cols: core::ops::Range<usize>,
+    at: Position,
+) {
+    for row in rows.clone() {
+        for col in cols.clone() {
+            if let Some(x) = from.remove_cell(row, col) {
+                to.insert_cell(at.row + row - rows.start, at.col + col - cols.start, x);
+            }
+        }
+    }
+}
+
+/// Returns the indexes of the rows of the table in the order they would be
+/// in once sorted by the values of the specified columns, where missing
+/// cells come before all others
+#[cfg(any(feature = "alloc", feature = "std"))]
+fn sorted_row_order<T: Table, F>(
+    table: &T,
+    keys: &[(usize, SortOrder)],
+    mut compare: F,
+) -> std::vec::Vec<usize>
+where
+    F: FnMut(&T::Data, &T::Data) -> core::cmp::Ordering,
+{
+    use core::cmp::Ordering;
+
+    let mut order: std::vec::Vec<usize> = (0..table.row_cnt()).collect();
+    order.sort_by(|a, b| {
+        for (col, dir) in keys {
+            let ordering = match (table.cell(*a, *col), table.cell(*b, *col)) {
+                (Some(x), Some(y)) => compare(x, y),
+                (None, Some(_)) => Ordering::Less,
+                (Some(_), None) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            };
+
+            if ordering != Ordering::Equal {
+                return dir.apply(ordering);
+            }
+        }
+
+        Ordering::Equal
+    });
+
+    order
+}
+
+/// Moves the rows of the table so that the row at `order[i]` becomes row `i`
+#[cfg(any(feature = "alloc", feature = "std"))]
+fn reorder_rows<T: Table>(table: &mut T, order: &[usize]) {
+    let col_cnt = table.col_cnt();
+
+    // Take every cell out of the table so that rows can be placed into
+    // their new positions without overwriting each other
+    let mut cells: std::vec::Vec<Option<T::Data>> = (0..order.len())
+        .flat_map(|row| (0..col_cnt).map(move |col| (row, col)))
+        .map(|(row, col)| table.remove_cell(row, col))
+        .collect();
+
+    for (row, old_row) in order.iter().enumerate() {
+        for col in 0..col_cnt {
+            if let Some(x) = cells[old_row * col_cnt + col].take() {
+                table.insert_cell(row, col, x);
+            }
+        }
+    }
+}
+
+/// Flags every row of the table that is not an exact copy of an earlier row
+#[cfg(any(feature = "alloc", feature = "std"))]
+fn unique_rows<T: Table>(table: &T) -> std::vec::Vec<bool>
+where
+    T::Data: core::hash::Hash + Eq,
+{
+    #[cfg(feature = "std")]
+    use std::collections::HashSet;
+
+    #[cfg(all(feature = "alloc", not(feature = "std")))]
+    use hashbrown::HashSet;
+
+    let col_cnt = table.col_cnt();
+    let mut seen = HashSet::new();
+    (0..table.row_cnt())
+        .map(|row| {
+            let cells: std::vec::Vec<_> = (0..col_cnt).map(|col| table.cell(row, col)).collect();
+            seen.insert(cells)
+        })
+        .collect()
+}
+
+/// Flags every row of the table whose cell in the specified column does not
+/// match that of an earlier row, as well as every row without a cell there
+#[cfg(any(feature = "alloc", feature = "std"))]
+fn unique_rows_by_key<T: Table>(table: &T, col: usize) -> std::vec::Vec<bool>
+where
+    T::Data: core::hash::Hash + Eq,
+{
+    #[cfg(feature = "std")]
+    use std::collections::HashSet;
+
+    #[cfg(all(feature = "alloc", not(feature = "std")))]
+    use hashbrown::HashSet;
+
+    let mut seen = HashSet::new();
+    (0..table.row_cnt())
+        .map(|row| match table.cell(row, col) {
+            Some(x) => seen.insert(x),
+            None => true,
+        })
+        .collect()
+}
+
+/// Removes every row not flagged to be kept, shifting up the kept rows to
+/// fill the gaps, returning the total rows removed
+#[cfg(any(feature = "alloc", feature = "std"))]
+fn retain_rows<T: Table>(table: &mut T, keep: &[bool]) -> usize {
+    let col_cnt = table.col_cnt();
+    let mut kept = 0;
+
+    for (row, keep) in keep.iter().enumerate() {
+        for col in 0..col_cnt {
+            let x = table.remove_cell(row, col);
+            if let (true, Some(x)) = (keep, x) {
+                table.insert_cell(kept, col, x);
+            }
+        }
+
+        if *keep {
+            kept += 1;
+        }
+    }
+
+    table.set_preferred_row_cnt(kept);
+    keep.len() - kept
+}
+
+/// Returns a new table containing copies of the rows within the range,
+/// shifted up so that the first row of the range becomes the first row
+fn copy_rows<T: Table + Default>(table: &T, rows: core::ops::Range<usize>) -> T
+where
+    T::Data: Clone,
+{
+    let mut new_table = T::default();
+    let col_cnt = table.col_cnt();
+
+    for (new_row, row) in rows.clone().enumerate() {
+        for col in 0..col_cnt {
+            if let Some(x) = table.cell(row, col) {
+                new_table.insert_cell(new_row, col, x.clone());
+            }
+        }
     }
+
+    new_table.set_preferred_row_cnt(rows.len());
+    new_table.set_preferred_col_cnt(col_cnt);
+    new_table
 }
 
 #[cfg(test)]
@@ -1164,6 +3950,181 @@ mod tests {
         assert_eq!(table.last_requested_row_capacity, Some(1));
     }
 
+    #[test]
+    fn take_row_should_not_change_row_capacity() {
+        let mut table = DummyTable::new(2, 0);
+
+        table.take_row(1);
+        assert_eq!(table.last_requested_row_capacity, None);
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[test]
+    fn drain_all_rows_should_remove_unreached_rows_when_dropped() {
+        let mut table = DynamicTable::from([[1, 2], [3, 4], [5, 6]]);
+        table.set_preferred_row_cnt(4);
+
+        assert_eq!(
+            table.drain_all_rows().next(),
+            Some(list::DynamicList::from([1, 2]))
+        );
+        assert_eq!(table.row_cnt(), 0);
+        assert_eq!(table.col_cnt(), 2);
+        assert!(table.cells().next().is_none());
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[test]
+    fn drain_all_rows_should_yield_every_row_including_empty_ones() {
+        let mut table = DynamicTable::new();
+        table.insert_cell(0, 0, 1);
+        table.insert_cell(2, 1, 2);
+
+        let rows: std::vec::Vec<_> = table.drain_all_rows().collect();
+        assert_eq!(rows.len(), 3);
+        assert!(rows[1].is_empty());
+        assert_eq!(rows[2], list::DynamicList::from([2]));
+    }
+
+    #[test]
+    fn missing_positions_should_report_every_gap_of_a_sparse_table() {
+        let table = DummyTable::new(2, 2);
+
+        assert!(!table.is_rectangular());
+        assert_eq!(table.missing_positions().count(), 4);
+        assert!(DummyTable::new(0, 3).is_rectangular());
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[test]
+    fn repair_should_fill_gaps_left_by_ragged_rows() {
+        let mut table = DynamicTable::new();
+        table.push_row(std::vec![1]);
+        table.push_row(std::vec![2, 3, 4]);
+        table.push_row(std::vec![5, 6]);
+
+        assert_eq!(
+            table.repair(0),
+            [
+                Position::new(0, 1),
+                Position::new(0, 2),
+                Position::new(2, 2)
+            ]
+        );
+        assert!(table.repair(0).is_empty());
+        assert_eq!(table, [[1, 0, 0], [2, 3, 4], [5, 6, 0]]);
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[test]
+    fn rotate_cw_and_rotate_ccw_should_undo_each_other() {
+        let mut table = DynamicTable::new();
+        table.insert_cell(0, 0, 1);
+        table.insert_cell(1, 2, 2);
+
+        let rotated = table.clone().rotate_cw();
+        assert_eq!((rotated.row_cnt(), rotated.col_cnt()), (3, 2));
+        assert_eq!(rotated.cell(0, 1), Some(&1));
+        assert_eq!(rotated.cell(2, 0), Some(&2));
+        assert_eq!(rotated.occupied_positions().count(), 2);
+
+        assert_eq!(rotated.rotate_ccw(), table);
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[test]
+    fn flip_should_move_missing_cells_with_the_rest() {
+        let mut table = DynamicTable::new();
+        table.insert_cell(0, 0, 1);
+        table.insert_cell(1, 2, 2);
+
+        table.flip_horizontal();
+        assert_eq!(table.cell(0, 2), Some(&1));
+        assert_eq!(table.cell(1, 0), Some(&2));
+        assert_eq!(table.occupied_positions().count(), 2);
+
+        table.flip_vertical();
+        assert_eq!(table.cell(1, 2), Some(&1));
+        assert_eq!(table.cell(0, 0), Some(&2));
+        assert_eq!(table.occupied_positions().count(), 2);
+    }
+
+    #[test]
+    fn move_cell_should_not_move_cell_outside_of_max_capacity() {
+        let mut table = FixedTable::from([[1, 2], [3, 4]]);
+
+        assert_eq!(
+            table.move_cell(Position::new(0, 0), Position::new(0, 2)),
+            None
+        );
+        assert_eq!(
+            table.move_cell(Position::new(0, 0), Position::new(0, 0)),
+            None
+        );
+        assert_eq!(table, [[1, 2], [3, 4]]);
+
+        assert_eq!(
+            table.move_cell(Position::new(0, 0), Position::new(1, 1)),
+            Some(4)
+        );
+        assert_eq!(table, [[0, 2], [3, 1]]);
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[test]
+    fn shrink_to_fit_should_only_consider_cells_within_counts_by_default() {
+        /// Forwards only the required methods, leaving the defaults of
+        /// truncate and shrink_to_fit in place
+        struct DefaultsTable(DynamicTable<usize>);
+
+        impl Table for DefaultsTable {
+            type Data = usize;
+            type Row = list::DynamicList<usize>;
+            type Column = list::DynamicList<usize>;
+
+            fn max_row_capacity(&self) -> Capacity {
+                self.0.max_row_capacity()
+            }
+            fn max_column_capacity(&self) -> Capacity {
+                self.0.max_column_capacity()
+            }
+            fn set_preferred_row_cnt(&mut self, cnt: usize) {
+                self.0.set_preferred_row_cnt(cnt)
+            }
+            fn set_preferred_col_cnt(&mut self, cnt: usize) {
+                self.0.set_preferred_col_cnt(cnt)
+            }
+            fn row_cnt(&self) -> usize {
+                self.0.row_cnt()
+            }
+            fn col_cnt(&self) -> usize {
+                self.0.col_cnt()
+            }
+            fn cell(&self, row: usize, col: usize) -> Option<&usize> {
+                self.0.cell(row, col)
+            }
+            fn mut_cell(&mut self, row: usize, col: usize) -> Option<&mut usize> {
+                self.0.mut_cell(row, col)
+            }
+            fn insert_cell(&mut self, row: usize, col: usize, value: usize) -> Option<usize> {
+                self.0.insert_cell(row, col, value)
+            }
+            fn remove_cell(&mut self, row: usize, col: usize) -> Option<usize> {
+                self.0.remove_cell(row, col)
+            }
+        }
+
+        let mut table = DefaultsTable(DynamicTable::new());
+        table.insert_cell(0, 1, 1);
+        table.insert_cell(3, 0, 2);
+        table.insert_cell(4, 4, 3);
+        table.remove_cell(4, 4);
+        table.set_preferred_row_cnt(3);
+        table.shrink_to_fit();
+
+        assert_eq!((table.row_cnt(), table.col_cnt()), (1, 2));
+    }
+
     #[test]
     fn remove_column_should_set_new_column_capacity_if_valid_column_removed() {
         let mut table = DummyTable::new(0, 2);
@@ -1177,4 +4138,299 @@ mod tests {
         table.remove_column(1);
         assert_eq!(table.last_requested_column_capacity, Some(1));
     }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[test]
+    fn head_should_keep_shape_of_sparse_rows() {
+        let mut table = DynamicTable::new();
+        table.insert_cell(0, 2, 1);
+        table.insert_cell(3, 0, 2);
+
+        let head = table.head(2);
+        assert_eq!(head.row_cnt(), 2);
+        assert_eq!(head.col_cnt(), 3);
+        assert_eq!(head.cell(0, 2), Some(&1));
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[test]
+    fn tail_should_shift_rows_to_start_of_new_table() {
+        let table = DynamicTable::from([[1], [2], [3]]);
+        let tail = table.tail(1);
+        assert_eq!(tail, [[3]]);
+        assert_eq!(table.tail(0).row_cnt(), 0);
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[test]
+    fn sample_should_pick_rows_chosen_by_rng_in_order() {
+        let table = DynamicTable::from([[1], [2], [3], [4]]);
+
+        // Always choosing zero picks the earliest rows still available
+        assert_eq!(table.sample(2, |_| 0), [[1], [2]]);
+
+        // Always choosing the largest value skips rows until forced to pick
+        assert_eq!(table.sample(2, |bound| bound - 1), [[3], [4]]);
+
+        assert_eq!(table.sample(10, |_| 0).row_cnt(), 4);
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[test]
+    fn split_at_row_should_put_all_rows_in_first_table_if_idx_out_of_range() {
+        let table = DynamicTable::from([[1, 2], [3, 4]]);
+        let (top, bottom) = table.split_at_row(5);
+
+        assert_eq!(top, [[1, 2], [3, 4]]);
+        assert_eq!(bottom.row_cnt(), 0);
+        assert_eq!(bottom.col_cnt(), 2);
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[test]
+    fn split_and_concat_should_restore_original_table() {
+        let table = DynamicTable::from([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+
+        let (mut top, bottom) = table.clone().split_at_row(2);
+        top.concat_rows(bottom).unwrap();
+        assert_eq!(top, table);
+
+        let (mut left, right) = table.clone().split_at_column(1);
+        left.concat_columns(right).unwrap();
+        assert_eq!(left, table);
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[test]
+    fn concat_rows_should_succeed_if_either_table_has_no_rows() {
+        let mut table = DynamicTable::new();
+        table.concat_rows(DynamicTable::from([[1, 2]])).unwrap();
+        table.concat_rows(DynamicTable::<usize>::new()).unwrap();
+        assert_eq!(table, [[1, 2]]);
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[test]
+    fn concat_columns_should_fail_if_row_counts_differ() {
+        let mut table = DynamicTable::from([[1], [2]]);
+        let err = table.concat_columns(DynamicTable::from([[3]])).unwrap_err();
+
+        assert_eq!(
+            err,
+            ShapeError::RowMismatch {
+                expected: 2,
+                actual: 1
+            }
+        );
+        assert_eq!(table, [[1], [2]]);
+    }
+
+    #[test]
+    fn concat_rows_should_fail_if_exceeding_capacity() {
+        let mut table = FixedTable::from([[1, 2]]);
+        let err = table.concat_rows(FixedTable::from([[3, 4]])).unwrap_err();
+
+        assert_eq!(
+            err,
+            ShapeError::ExceedsCapacity {
+                capacity: Capacity::Limited(1),
+                required: 2
+            }
+        );
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[test]
+    fn upsert_row_by_key_should_push_row_missing_key_cell() {
+        let mut table = DynamicTable::from([[1, 2]]);
+        table.insert_cell(1, 1, 3);
+
+        assert!(table.upsert_row_by_key(1, std::vec![5, 3]).is_some());
+        assert_eq!(table.cell(1, 0), Some(&5));
+
+        assert_eq!(table.upsert_row_by_key(2, std::vec![1, 2]), None);
+        assert_eq!(table.row_cnt(), 3);
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[test]
+    fn sort_rows_by_columns_should_keep_order_of_equal_rows() {
+        let mut table = DynamicTable::from([[2, 0], [1, 1], [2, 2], [1, 3]]);
+        table.sort_rows_by_columns(&[(0, SortOrder::Ascending)]);
+        assert_eq!(table, [[1, 1], [1, 3], [2, 0], [2, 2]]);
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[test]
+    fn sort_rows_by_columns_should_put_missing_cells_first() {
+        let mut table = DynamicTable::new();
+        table.insert_cell(0, 0, 3);
+        table.insert_cell(1, 1, 9);
+        table.insert_cell(2, 0, 1);
+
+        table.sort_rows_by_columns(&[(0, SortOrder::Ascending)]);
+        assert_eq!(table.row_cnt(), 3);
+        assert_eq!(table.cell(0, 0), None);
+        assert_eq!(table.cell(0, 1), Some(&9));
+        assert_eq!(table.cell(1, 0), Some(&1));
+        assert_eq!(table.cell(2, 0), Some(&3));
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[test]
+    fn dedup_rows_should_compare_missing_cells() {
+        let mut table = DynamicTable::new();
+        table.insert_cell(0, 1, 1);
+        table.insert_cell(1, 0, 1);
+        table.insert_cell(2, 1, 1);
+        table.insert_cell(3, 0, 2);
+
+        assert_eq!(table.dedup_rows(), 1);
+        assert_eq!(table.row_cnt(), 3);
+        assert_eq!(table.cell(0, 1), Some(&1));
+        assert_eq!(table.cell(1, 0), Some(&1));
+        assert_eq!(table.cell(2, 0), Some(&2));
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[test]
+    fn dedup_rows_by_key_should_keep_rows_missing_key_cell() {
+        let mut table = DynamicTable::new();
+        table.insert_cell(0, 0, 1);
+        table.insert_cell(1, 1, 2);
+        table.insert_cell(2, 1, 3);
+        table.insert_cell(3, 0, 1);
+
+        assert_eq!(table.dedup_rows_by_key(0), 1);
+        assert_eq!(table.row_cnt(), 3);
+        assert_eq!(table.cell(1, 1), Some(&2));
+        assert_eq!(table.cell(2, 1), Some(&3));
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[test]
+    fn replace_in_column_should_only_replace_cells_of_column() {
+        let mut table = DynamicTable::from([[1, 1], [1, 2]]);
+
+        assert_eq!(table.replace_in_column(1, &1, 3), 1);
+        assert_eq!(table, [[1, 3], [1, 2]]);
+    }
+
+    #[test]
+    fn replace_all_should_return_zero_if_no_cell_matches() {
+        let mut table = DummyTable::new(2, 2);
+        assert_eq!(table.replace_all(&(), ()), 0);
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[test]
+    fn occupied_positions_should_default_to_positions_with_cells_row_by_row() {
+        let table = FixedTable::from([[1, 2], [3, 4]]);
+
+        let positions: std::vec::Vec<Position> = table.occupied_positions().collect();
+        assert_eq!(
+            positions,
+            [
+                Position::new(0, 0),
+                Position::new(0, 1),
+                Position::new(1, 0),
+                Position::new(1, 1),
+            ]
+        );
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[test]
+    fn try_fold_cells_should_stop_at_first_error() {
+        let table = DynamicTable::from([[1, 2], [3, 4]]);
+        let mut visited = 0;
+
+        let result = table.try_fold_cells(0, |acc, pos, x| {
+            visited += 1;
+            if *x == 2 {
+                Err(pos)
+            } else {
+                Ok(acc + x)
+            }
+        });
+
+        assert_eq!(result, Err(Position::new(0, 1)));
+        assert_eq!(visited, 2);
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[test]
+    fn any_cell_should_skip_cells_outside_of_preferred_counts() {
+        let mut table = DynamicTable::from([[1, 2], [3, 4]]);
+        table.set_preferred_row_cnt(1);
+
+        assert!(table.any_cell(|_, x| *x == 2));
+        assert!(!table.any_cell(|_, x| *x == 3));
+        assert!(table.all_cells(|pos, _| pos.row == 0));
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[test]
+    fn cumsum_column_should_yield_none_for_missing_cells() {
+        let mut table = DynamicTable::new();
+        table.insert_cell(0, 0, 1);
+        table.insert_cell(2, 0, 2);
+        table.insert_cell(3, 0, 3);
+        table.insert_cell(1, 1, 9);
+
+        assert_eq!(
+            table.cumsum_column(0).collect::<std::vec::Vec<_>>(),
+            [Some(1), None, Some(3), Some(6)]
+        );
+        assert_eq!(table.cumsum_column(1).flatten().sum::<i32>(), 9);
+        assert_eq!(table.cumsum_column(2).count(), 0);
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[test]
+    fn set_block_should_remove_cells_missing_from_other_table() {
+        let mut table = DynamicTable::from([[1, 2], [3, 4]]);
+        let mut other = DynamicTable::new();
+        other.insert_cell(1, 1, 9);
+
+        table.set_block(1, 1, &other).unwrap();
+        assert_eq!(table.row_cnt(), 3);
+        assert_eq!(table.col_cnt(), 3);
+        assert_eq!(table.cell(1, 1), None);
+        assert_eq!(table.cell(2, 2), Some(&9));
+        assert_eq!(table.cell(1, 0), Some(&3));
+    }
+
+    #[test]
+    fn set_block_should_fail_if_exceeding_column_capacity() {
+        let mut table = FixedTable::from([[1, 2]]);
+        let err = table
+            .set_block(0, 1, &FixedTable::from([[3, 4]]))
+            .unwrap_err();
+
+        assert_eq!(
+            err,
+            ShapeError::ExceedsCapacity {
+                capacity: Capacity::Limited(2),
+                required: 3
+            }
+        );
+        assert_eq!(table, [[1, 2]]);
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[test]
+    fn set_block_should_fail_if_region_overflows() {
+        let mut table = DynamicTable::from([[1, 2]]);
+
+        assert_eq!(
+            table.set_block(usize::MAX, 0, &DynamicTable::from([[3], [4]])),
+            Err(ShapeError::Overflow)
+        );
+        assert_eq!(
+            table.set_block(0, usize::MAX, &DynamicTable::from([[3, 4]])),
+            Err(ShapeError::Overflow)
+        );
+        assert_eq!(table, [[1, 2]]);
+    }
 }
@@ -177,7 +177,7 @@ where
     T: PartialEq<U> + Default,
 {
     fn eq(&self, other: &[U; N]) -> bool {
-        PartialEq::eq(&self.0, &*other)
+        PartialEq::eq(&self.0, other)
     }
 }
 
@@ -186,7 +186,7 @@ where
     T: PartialOrd<T> + Default,
 {
     fn partial_cmp(&self, other: &[T; N]) -> Option<Ordering> {
-        PartialOrd::partial_cmp(&self.0, &*other)
+        PartialOrd::partial_cmp(&self.0, other)
     }
 }
 
@@ -194,6 +194,7 @@ impl<T: Default, const N: usize> IntoIterator for FixedList<T, N> {
     type Item = T;
     type IntoIter = array::IntoIter<Self::Item, N>;
 
+    #[allow(deprecated)]
     fn into_iter(self) -> Self::IntoIter {
         array::IntoIter::new(self.0)
     }
@@ -310,7 +311,7 @@ mod alloc {
         T: PartialEq<U>,
     {
         fn eq(&self, other: &[U; N]) -> bool {
-            PartialEq::eq(&*self.0, &*other)
+            PartialEq::eq(&*self.0, other)
         }
     }
 
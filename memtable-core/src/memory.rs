@@ -0,0 +1,125 @@
+#[cfg(any(feature = "alloc", feature = "std"))]
+use core::mem;
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+use std::{boxed::Box, string::String, vec::Vec};
+
+/// Represents data that can estimate how many bytes it has allocated on the
+/// heap, not including the size of the data itself
+pub trait HeapSize {
+    /// Returns the total bytes allocated on the heap by this data
+    fn heap_size(&self) -> usize;
+}
+
+macro_rules! impl_no_heap {
+    ($($t:ty),+ $(,)?) => {
+        $(
+            impl HeapSize for $t {
+                fn heap_size(&self) -> usize {
+                    0
+                }
+            }
+        )+
+    };
+}
+
+impl_no_heap!(
+    (),
+    bool,
+    char,
+    f32,
+    f64,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+);
+
+impl<T: ?Sized> HeapSize for &T {
+    /// References do not own what they point to, so nothing is counted
+    fn heap_size(&self) -> usize {
+        0
+    }
+}
+
+impl<T: HeapSize> HeapSize for Option<T> {
+    fn heap_size(&self) -> usize {
+        self.as_ref().map(HeapSize::heap_size).unwrap_or_default()
+    }
+}
+
+impl<T: HeapSize, const N: usize> HeapSize for [T; N] {
+    fn heap_size(&self) -> usize {
+        self.iter().map(HeapSize::heap_size).sum()
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl HeapSize for String {
+    fn heap_size(&self) -> usize {
+        self.capacity()
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<T: HeapSize> HeapSize for Vec<T> {
+    fn heap_size(&self) -> usize {
+        self.capacity() * mem::size_of::<T>() + self.iter().map(HeapSize::heap_size).sum::<usize>()
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<T: HeapSize> HeapSize for Box<T> {
+    fn heap_size(&self) -> usize {
+        mem::size_of::<T>() + (**self).heap_size()
+    }
+}
+
+/// Represents a table that can report how much memory it is using and can
+/// release memory that it has reserved but is not using
+pub trait MemoryUsage {
+    /// Returns an estimate of the total bytes the table has allocated on the
+    /// heap, including the heap allocations of the cells themselves
+    fn memory_usage(&self) -> usize;
+
+    /// Releases as much reserved, but unused, memory as possible without
+    /// changing the contents of the table
+    fn shrink_all(&mut self);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn heap_size_should_be_zero_for_primitives() {
+        assert_eq!(5usize.heap_size(), 0);
+        assert_eq!('c'.heap_size(), 0);
+        assert_eq!([1u8, 2, 3].heap_size(), 0);
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[test]
+    fn heap_size_should_include_capacity_of_strings() {
+        let s = String::with_capacity(10);
+        assert_eq!(s.heap_size(), 10);
+        assert_eq!(Some(s).heap_size(), 10);
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[test]
+    fn heap_size_should_include_capacity_and_contents_of_vecs() {
+        let mut v: Vec<String> = Vec::with_capacity(4);
+        v.push(String::with_capacity(3));
+
+        assert_eq!(v.heap_size(), 4 * mem::size_of::<String>() + 3);
+    }
+}
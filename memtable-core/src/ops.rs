@@ -0,0 +1,179 @@
+use crate::Table;
+use std::vec::Vec;
+
+/// Represents a single mutation made to a table
+///
+/// Operations can be serialized into an event log and replayed through
+/// [`Op::apply_to`] or [`Table::apply_ops`] to rebuild the table
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-1", derive(serde::Serialize, serde::Deserialize))]
+pub enum Op<T> {
+    /// Inserted `value` into the cell at `row` & `col`
+    InsertCell {
+        /// Row of the cell
+        row: usize,
+        /// Column of the cell
+        col: usize,
+        /// Value placed into the cell
+        value: T,
+    },
+
+    /// Removed the cell at `row` & `col`
+    RemoveCell {
+        /// Row of the cell
+        row: usize,
+        /// Column of the cell
+        col: usize,
+    },
+
+    /// Inserted a row of `cells` at `row`, shifting later rows down
+    InsertRow {
+        /// Index of the row
+        row: usize,
+        /// Cells of the row
+        cells: Vec<T>,
+    },
+
+    /// Pushed a row of cells to the end of the table
+    PushRow(Vec<T>),
+
+    /// Removed the row at the index, shifting later rows up
+    RemoveRow(usize),
+
+    /// Inserted a column of `cells` at `col`, shifting later columns right
+    InsertColumn {
+        /// Index of the column
+        col: usize,
+        /// Cells of the column
+        cells: Vec<T>,
+    },
+
+    /// Pushed a column of cells to the end of the table
+    PushColumn(Vec<T>),
+
+    /// Removed the column at the index, shifting later columns left
+    RemoveColumn(usize),
+
+    /// Shifted the rows of the table by `offset`, wrapping them around if
+    /// `wrap` is true
+    ShiftRows {
+        /// Total rows to shift by, down if positive and up if negative
+        offset: isize,
+        /// Whether rows shifted past either end wrap around to the other
+        wrap: bool,
+    },
+
+    /// Shifted the columns of the table by `offset`, wrapping them around if
+    /// `wrap` is true
+    ShiftColumns {
+        /// Total columns to shift by, right if positive and left if negative
+        offset: isize,
+        /// Whether columns shifted past either end wrap around to the other
+        wrap: bool,
+    },
+
+    /// Changed the preferred row count of the table
+    SetPreferredRowCnt(usize),
+
+    /// Changed the preferred column count of the table
+    SetPreferredColCnt(usize),
+
+    /// Removed all cells outside of the row & column counts of the table
+    Truncate,
+
+    /// Adjusted the row & column counts of the table to fit its cells
+    ShrinkToFit,
+}
+
+impl<T> Op<T> {
+    /// Applies the operation to the given table
+    pub fn apply_to<U: Table<Data = T>>(self, table: &mut U) {
+        match self {
+            Self::InsertCell { row, col, value } => {
+                table.insert_cell(row, col, value);
+            }
+            Self::RemoveCell { row, col } => {
+                table.remove_cell(row, col);
+            }
+            Self::InsertRow { row, cells } => table.insert_row(row, cells),
+            Self::PushRow(cells) => table.push_row(cells),
+            Self::RemoveRow(row) => {
+                table.remove_row(row);
+            }
+            Self::InsertColumn { col, cells } => table.insert_column(col, cells),
+            Self::PushColumn(cells) => table.push_column(cells),
+            Self::RemoveColumn(col) => {
+                table.remove_column(col);
+            }
+            Self::ShiftRows { offset, wrap } => table.shift_rows(offset, wrap),
+            Self::ShiftColumns { offset, wrap } => table.shift_columns(offset, wrap),
+            Self::SetPreferredRowCnt(cnt) => table.set_preferred_row_cnt(cnt),
+            Self::SetPreferredColCnt(cnt) => table.set_preferred_col_cnt(cnt),
+            Self::Truncate => table.truncate(),
+            Self::ShrinkToFit => table.shrink_to_fit(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DynamicTable;
+
+    #[test]
+    fn apply_ops_should_rebuild_table_from_log() {
+        let ops = std::vec![
+            Op::PushRow(std::vec![1, 2]),
+            Op::PushRow(std::vec![3, 4]),
+            Op::InsertColumn {
+                col: 0,
+                cells: std::vec![5, 6],
+            },
+            Op::InsertCell {
+                row: 1,
+                col: 2,
+                value: 7,
+            },
+            Op::RemoveRow(0),
+            Op::PushColumn(std::vec![8]),
+        ];
+
+        let table = DynamicTable::apply_ops(ops);
+        assert_eq!(table, [[6, 3, 7, 8]]);
+    }
+
+    #[test]
+    fn remove_ops_should_shrink_table() {
+        let mut table = DynamicTable::from([[1, 2], [3, 4]]);
+
+        Op::RemoveColumn(0).apply_to(&mut table);
+        Op::RemoveCell { row: 1, col: 0 }.apply_to(&mut table);
+        Op::SetPreferredRowCnt(1).apply_to(&mut table);
+
+        assert_eq!(table, [[2]]);
+    }
+
+    #[test]
+    fn truncate_and_shrink_ops_should_drop_hidden_cells() {
+        let mut table = DynamicTable::from([[1, 2], [3, 4]]);
+
+        Op::SetPreferredRowCnt(1).apply_to(&mut table);
+        Op::Truncate.apply_to(&mut table);
+        Op::SetPreferredRowCnt(5).apply_to(&mut table);
+        Op::ShrinkToFit.apply_to(&mut table);
+
+        assert_eq!(table, [[1, 2]]);
+    }
+
+    #[cfg(feature = "serde-1")]
+    #[test]
+    fn op_should_round_trip_through_serde() {
+        let op = Op::InsertRow {
+            row: 1,
+            cells: std::vec![1, 2],
+        };
+
+        let json = serde_json::to_string(&op).unwrap();
+        assert_eq!(serde_json::from_str::<Op<u8>>(&json).unwrap(), op);
+    }
+}
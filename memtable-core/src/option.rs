@@ -0,0 +1,248 @@
+use crate::Table;
+use core::marker::PhantomData;
+
+/// Represents a table whose cells are optional, where a cell containing
+/// `None` (or a missing cell within the table's row & column range) is
+/// considered null
+pub trait OptionTable<T>: Table<Data = Option<T>> {
+    /// Returns an iterator over the values of all non-null cells, starting
+    /// from the first row and moving through each column before moving on to
+    /// the next row
+    fn flatten_cells(&self) -> FlattenCells<'_, T, Self> {
+        FlattenCells::new(self)
+    }
+
+    /// Returns the total null cells in the specified column
+    fn count_nulls_in_column(&self, col: usize) -> usize {
+        if col >= self.col_cnt() {
+            return 0;
+        }
+
+        (0..self.row_cnt())
+            .filter(|row| matches!(self.cell(*row, col), None | Some(None)))
+            .count()
+    }
+
+    /// Replaces every null cell within the table's row & column range with
+    /// the given value, returning the total cells that were filled
+    fn fill_nulls(&mut self, value: T) -> usize
+    where
+        T: Clone,
+    {
+        let mut cnt = 0;
+
+        for row in 0..self.row_cnt() {
+            for col in 0..self.col_cnt() {
+                match self.mut_cell(row, col) {
+                    Some(Some(_)) => continue,
+                    Some(x) => *x = Some(value.clone()),
+                    None => {
+                        self.insert_cell(row, col, Some(value.clone()));
+                    }
+                }
+
+                cnt += 1;
+            }
+        }
+
+        cnt
+    }
+
+    /// Replaces every null cell in the specified column with a copy of the
+    /// closest non-null cell above it, returning the total cells that were
+    /// filled; null cells before the first non-null cell are left alone
+    fn fill_down_nulls(&mut self, col: usize) -> usize
+    where
+        T: Clone,
+    {
+        if col >= self.col_cnt() {
+            return 0;
+        }
+
+        let mut last: Option<T> = None;
+        let mut cnt = 0;
+
+        for row in 0..self.row_cnt() {
+            match self.cell(row, col) {
+                Some(Some(x)) => last = Some(x.clone()),
+                _ => {
+                    if let Some(x) = last.as_ref() {
+                        self.insert_cell(row, col, Some(x.clone()));
+                        cnt += 1;
+                    }
+                }
+            }
+        }
+
+        cnt
+    }
+
+    /// Replaces every null cell in the specified row with a copy of the
+    /// closest non-null cell to its left, returning the total cells that were
+    /// filled; null cells before the first non-null cell are left alone
+    fn fill_right_nulls(&mut self, row: usize) -> usize
+    where
+        T: Clone,
+    {
+        if row >= self.row_cnt() {
+            return 0;
+        }
+
+        let mut last: Option<T> = None;
+        let mut cnt = 0;
+
+        for col in 0..self.col_cnt() {
+            match self.cell(row, col) {
+                Some(Some(x)) => last = Some(x.clone()),
+                _ => {
+                    if let Some(x) = last.as_ref() {
+                        self.insert_cell(row, col, Some(x.clone()));
+                        cnt += 1;
+                    }
+                }
+            }
+        }
+
+        cnt
+    }
+}
+
+impl<T, U: Table<Data = Option<T>>> OptionTable<T> for U {}
+
+/// Represents an iterator over the values of all non-null cells in a table
+/// of optional data, visiting every position within the table's rows and
+/// columns so that missing cells are skipped like cells that are `None`
+#[derive(Debug)]
+pub struct FlattenCells<'a, T, U: Table<Data = Option<T>>> {
+    table: &'a U,
+    row: usize,
+    col: usize,
+    _data: PhantomData<T>,
+}
+
+impl<'a, T, U: Table<Data = Option<T>>> FlattenCells<'a, T, U> {
+    /// Creates a new iterator over the non-null cells of the table, starting
+    /// from the beginning of the table
+    pub fn new(table: &'a U) -> Self {
+        Self {
+            table,
+            row: 0,
+            col: 0,
+            _data: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: 'a, U: Table<Data = Option<T>>> Iterator for FlattenCells<'a, T, U> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let row_cnt = self.table.row_cnt();
+        let col_cnt = self.table.col_cnt();
+
+        while self.row < row_cnt {
+            while self.col < col_cnt {
+                let col = self.col;
+                self.col += 1;
+
+                if let Some(Some(x)) = self.table.cell(self.row, col) {
+                    return Some(x);
+                }
+            }
+
+            self.row += 1;
+            self.col = 0;
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FixedTable;
+
+    #[test]
+    fn flatten_cells_should_skip_null_cells() {
+        let table = FixedTable::from([[Some(1), None], [None, Some(4)], [Some(5), None]]);
+        let values: [usize; 3] = {
+            let mut it = table.flatten_cells().copied();
+            [it.next().unwrap(), it.next().unwrap(), it.next().unwrap()]
+        };
+
+        assert_eq!(values, [1, 4, 5]);
+        assert_eq!(table.flatten_cells().count(), 3);
+        assert_eq!(table.flatten_cells().sum::<usize>(), 10);
+    }
+
+    #[test]
+    fn count_nulls_in_column_should_count_cells_that_are_none() {
+        let table = FixedTable::from([[Some(1), None], [None, Some(4)], [Some(5), None]]);
+        assert_eq!(table.count_nulls_in_column(0), 1);
+        assert_eq!(table.count_nulls_in_column(1), 2);
+    }
+
+    #[test]
+    fn count_nulls_in_column_should_return_zero_if_column_out_of_range() {
+        let table = FixedTable::from([[Some(1), None], [None, Some(4)], [Some(5), None]]);
+        assert_eq!(table.count_nulls_in_column(2), 0);
+    }
+
+    #[test]
+    fn fill_nulls_should_replace_every_null_cell() {
+        let mut table = FixedTable::from([[Some(1), None], [None, Some(4)], [Some(5), None]]);
+        assert_eq!(table.fill_nulls(0), 3);
+        assert_eq!(
+            table,
+            [[Some(1), Some(0)], [Some(0), Some(4)], [Some(5), Some(0)]]
+        );
+    }
+
+    #[test]
+    fn fill_down_nulls_should_copy_closest_value_above() {
+        let mut table = FixedTable::from([[None, Some(1)], [Some(2), None], [None, None]]);
+
+        assert_eq!(table.fill_down_nulls(0), 1);
+        assert_eq!(table.fill_down_nulls(1), 2);
+        assert_eq!(
+            table,
+            [[None, Some(1)], [Some(2), Some(1)], [Some(2), Some(1)]]
+        );
+    }
+
+    #[test]
+    fn fill_right_nulls_should_copy_closest_value_to_left() {
+        let mut table = FixedTable::from([[None, Some(1), None, Some(3), None]]);
+
+        assert_eq!(table.fill_right_nulls(0), 2);
+        assert_eq!(table, [[None, Some(1), Some(1), Some(3), Some(3)]]);
+        assert_eq!(table.fill_right_nulls(1), 0);
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[test]
+    fn flatten_cells_should_skip_missing_cells() {
+        let mut table = crate::DynamicTable::new();
+        table.insert_cell(0, 1, Some(1));
+        table.insert_cell(1, 0, None);
+        table.insert_cell(2, 2, Some(3));
+
+        let mut values = table.flatten_cells();
+        assert_eq!(values.next(), Some(&1));
+        assert_eq!(values.next(), Some(&3));
+        assert_eq!(values.next(), None);
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[test]
+    fn fill_nulls_should_fill_missing_cells_within_range() {
+        let mut table = crate::DynamicTable::new();
+        table.insert_cell(0, 0, Some(1));
+        table.insert_cell(1, 1, Some(2));
+
+        assert_eq!(table.count_nulls_in_column(0), 1);
+        assert_eq!(table.fill_nulls(9), 2);
+        assert_eq!(table, [[Some(1), Some(9)], [Some(9), Some(2)]]);
+    }
+}
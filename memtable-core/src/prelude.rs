@@ -18,15 +18,35 @@
 //!   can grow dynamically
 //! * [`FixedColumnTable`] struct, where the total columns is fixed and rows
 //!   can grow dynamically
+//! * [`CowTable`] struct, which is a table that can grow and shrink
+//!   dynamically and produce cheap snapshots of itself
+//! * [`SmallTable`] struct, which is a table that can grow and shrink
+//!   dynamically while storing a handful of cells inline without allocating
 //! * [`Table`] trait, which provides the majority of the methods
 //!   available to operate on a table
+//! * [`SortOrder`] enum, which specifies the direction in which to sort the
+//!   values of a column
 //! * [`iter::CellIter`] trait, which enables examining the row & column
 //!   positions of iterators over individual cells in a table as well as zip
 //!   an iterator with the position of each cell
+//! * [`coerce::Coerce`] trait, which enables parsing the text of a table into
+//!   typed data
+//! * [`memory::MemoryUsage`] trait, which enables estimating and reducing the
+//!   memory used by a table
+//! * [`grid::GridTable`] trait, which enables working with tables as
+//!   two-dimensional grids such as game boards
+//! * [`option::OptionTable`] trait, which enables working with tables whose
+//!   cells are optional, treating `None` as a null value
 //! * [`list::List`] trait, which enables operations on a generic list of items
 //! * [`list::DynamicList`] struct, which is a generic list that is able to
 //!   grow and shrink dynamically without any bounds
 //! * [`list::FixedList`] struct, which is a generic list that is able to grow
 //!   and shrink within a limited bounds
 //!
-pub use crate::{impls::*, iter::CellIter, list::*, Table};
+pub use crate::{
+    grid::GridTable, impls::*, iter::CellIter, list::*, memory::MemoryUsage, option::OptionTable,
+    SortOrder, Table,
+};
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub use crate::coerce::Coerce;
@@ -0,0 +1,125 @@
+use core::fmt;
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+use core::sync::atomic::{AtomicBool, Ordering};
+
+/// Represents whether a long operation should keep going after reporting its
+/// progress, mirroring `core::ops::ControlFlow` which is not available on
+/// every version of Rust supported by this crate
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ControlFlow {
+    /// Keep going with the operation
+    Continue,
+
+    /// Stop the operation as soon as possible
+    Break,
+}
+
+impl ControlFlow {
+    /// Returns true if the operation should stop
+    pub fn is_break(self) -> bool {
+        self == Self::Break
+    }
+
+    /// Returns true if the operation should keep going
+    pub fn is_continue(self) -> bool {
+        self == Self::Continue
+    }
+}
+
+impl Default for ControlFlow {
+    /// Defaults to continuing
+    fn default() -> Self {
+        Self::Continue
+    }
+}
+
+impl From<()> for ControlFlow {
+    /// Treats callbacks that return nothing as always continuing
+    fn from(_: ()) -> Self {
+        Self::Continue
+    }
+}
+
+/// Represents a long operation that stopped before it finished, describing
+/// how far it got
+///
+/// The unit of `done` and `total` depends on the operation, such as bytes
+/// for parsing text or cells for loading a table. When an operation cannot
+/// know its total up front, `total` is the least amount of work it knew of
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PartialCompletion {
+    /// Amount of work completed before stopping
+    pub done: usize,
+
+    /// Total amount of work the operation expected to do
+    pub total: usize,
+}
+
+impl fmt::Display for PartialCompletion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Operation stopped after {} of {}", self.done, self.total)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PartialCompletion {}
+
+#[cfg(feature = "std")]
+impl From<PartialCompletion> for std::io::Error {
+    /// Wraps the completion in an error of kind `Other`, as readers and
+    /// writers retry errors of kind `Interrupted` rather than stopping
+    fn from(x: PartialCompletion) -> Self {
+        Self::other(x)
+    }
+}
+
+/// Represents a request to stop a long operation, which the operation checks
+/// periodically so that it can stop cooperatively
+///
+/// Clones of a token share the same state, so one clone can be given to an
+/// operation while another is cancelled from elsewhere, such as a gui thread
+///
+/// ### Examples
+///
+/// ```
+/// # use memtable_core::{prelude::*, progress::{CancelToken, PartialCompletion}};
+/// let token = CancelToken::new();
+/// token.cancel();
+///
+/// let mut table = DynamicTable::new();
+/// let result = table.extend_rows_cancellable(vec![vec![1, 2], vec![3, 4]], &token);
+/// assert_eq!(result, Err(PartialCompletion { done: 0, total: 2 }));
+/// ```
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[cfg_attr(feature = "docs", doc(cfg(any(alloc, std))))]
+#[derive(Clone, Debug, Default)]
+pub struct CancelToken(std::sync::Arc<AtomicBool>);
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl CancelToken {
+    /// Creates a new token that has not been cancelled
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requests that operations checking this token stop
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    /// Returns true if the token has been cancelled
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+
+    /// Returns whether an operation checking this token should keep going,
+    /// which allows the token to be used as a progress callback
+    pub fn check(&self) -> ControlFlow {
+        if self.is_cancelled() {
+            ControlFlow::Break
+        } else {
+            ControlFlow::Continue
+        }
+    }
+}
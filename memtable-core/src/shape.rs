@@ -0,0 +1,67 @@
+use crate::Capacity;
+use core::fmt;
+
+/// Represents the failure to combine tables because their shapes do not fit
+/// together
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ShapeError {
+    /// The tables have a different number of columns when placing rows of
+    /// one table below the other
+    ColumnMismatch {
+        /// Total columns of the table being added to
+        expected: usize,
+
+        /// Total columns of the table being added
+        actual: usize,
+    },
+
+    /// The tables have a different number of rows when placing columns of
+    /// one table beside the other
+    RowMismatch {
+        /// Total rows of the table being added to
+        expected: usize,
+
+        /// Total rows of the table being added
+        actual: usize,
+    },
+
+    /// The combined table would be larger than the table being added to is
+    /// able to hold
+    ExceedsCapacity {
+        /// Maximum rows or columns the table can hold
+        capacity: Capacity,
+
+        /// Total rows or columns that would be needed
+        required: usize,
+    },
+
+    /// The combined table would need more rows or columns than can be
+    /// counted
+    Overflow,
+}
+
+impl fmt::Display for ShapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ColumnMismatch { expected, actual } => write!(
+                f,
+                "Expected table with {} columns, but got {}",
+                expected, actual
+            ),
+            Self::RowMismatch { expected, actual } => write!(
+                f,
+                "Expected table with {} rows, but got {}",
+                expected, actual
+            ),
+            Self::ExceedsCapacity { capacity, required } => write!(
+                f,
+                "Table needs {} rows or columns, but capacity is {:?}",
+                required, capacity
+            ),
+            Self::Overflow => write!(f, "Table needs more rows or columns than can be counted"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ShapeError {}
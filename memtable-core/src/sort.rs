@@ -0,0 +1,23 @@
+use core::cmp::Ordering;
+
+/// Represents the direction in which to sort the values of a column
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde-1", derive(serde::Serialize, serde::Deserialize))]
+pub enum SortOrder {
+    /// Smallest values come first
+    Ascending,
+
+    /// Largest values come first
+    Descending,
+}
+
+impl SortOrder {
+    /// Applies the direction to an ordering of two values, reversing it if
+    /// descending
+    pub fn apply(self, ordering: Ordering) -> Ordering {
+        match self {
+            Self::Ascending => ordering,
+            Self::Descending => ordering.reverse(),
+        }
+    }
+}
@@ -0,0 +1,2 @@
+mod striped;
+pub use striped::{RowReadGuard, RowWriteGuard, Snapshot, StripedTable};
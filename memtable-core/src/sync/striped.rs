@@ -0,0 +1,332 @@
+use crate::{DynamicTable, Table};
+use std::{
+    fmt,
+    ops::{Deref, DerefMut},
+    sync::{Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard},
+};
+
+/// Default number of stripes used by [`StripedTable::default`]
+const DEFAULT_STRIPE_CNT: usize = 16;
+
+/// Represents a table whose rows are spread across several independently
+/// locked stripes, allowing readers and writers of different rows to proceed
+/// concurrently rather than serializing on a single table-wide lock
+///
+/// Row `i` lives in stripe `i % stripe_cnt`, so rows that share a stripe
+/// also share a lock. Rows are only ever appended, which keeps the mapping
+/// of rows to stripes stable while other threads hold row guards
+pub struct StripedTable<T> {
+    stripes: Vec<RwLock<Vec<Vec<T>>>>,
+    row_cnt: RwLock<usize>,
+    push_lock: Mutex<()>,
+}
+
+impl<T> StripedTable<T> {
+    /// Creates a new, empty table using the specified number of stripes,
+    /// which will be at least one
+    pub fn new(stripe_cnt: usize) -> Self {
+        Self {
+            stripes: (0..stripe_cnt.max(1))
+                .map(|_| RwLock::new(Vec::new()))
+                .collect(),
+            row_cnt: RwLock::new(0),
+            push_lock: Mutex::new(()),
+        }
+    }
+
+    /// Returns the total stripes (independent locks) used by the table
+    pub fn stripe_cnt(&self) -> usize {
+        self.stripes.len()
+    }
+
+    /// Returns the total rows contained in the table
+    pub fn row_cnt(&self) -> usize {
+        *self.row_cnt.read().unwrap()
+    }
+
+    /// Appends a new row to the end of the table, returning its index
+    pub fn push_row<I: IntoIterator<Item = T>>(&self, cells: I) -> usize {
+        let row: Vec<T> = cells.into_iter().collect();
+
+        // Only one push can happen at a time so that rows land in their
+        // stripes in order, but pushes do not block readers of existing rows
+        // other than the brief lock on the destination stripe
+        let _guard = self.push_lock.lock().unwrap();
+        let idx = self.row_cnt();
+        self.stripe(idx).write().unwrap().push(row);
+
+        // Only count the row once it is in place so that anything reading
+        // the row count can also find the row
+        *self.row_cnt.write().unwrap() = idx + 1;
+
+        idx
+    }
+
+    /// Acquires shared access to the row at the specified index, blocking
+    /// until no writer holds the row's stripe
+    pub fn read_row(&self, idx: usize) -> Option<RowReadGuard<'_, T>> {
+        if idx < self.row_cnt() {
+            Some(RowReadGuard {
+                guard: self.stripe(idx).read().unwrap(),
+                idx: self.local_idx(idx),
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Acquires exclusive access to the row at the specified index, blocking
+    /// until no reader or writer holds the row's stripe
+    pub fn write_row(&self, idx: usize) -> Option<RowWriteGuard<'_, T>> {
+        if idx < self.row_cnt() {
+            Some(RowWriteGuard {
+                guard: self.stripe(idx).write().unwrap(),
+                idx: self.local_idx(idx),
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Acquires shared access to every stripe at once, producing a view of
+    /// all rows that is consistent as of a single point in time; writers
+    /// are blocked until the snapshot is dropped
+    pub fn snapshot(&self) -> Snapshot<'_, T> {
+        // Stripes are always locked in the same order, so concurrent
+        // snapshots cannot deadlock with one another
+        let guards = self.stripes.iter().map(|s| s.read().unwrap()).collect();
+
+        // Any row counted here is guaranteed to be in a stripe we now hold
+        let row_cnt = self.row_cnt();
+
+        Snapshot { guards, row_cnt }
+    }
+
+    fn stripe(&self, idx: usize) -> &RwLock<Vec<Vec<T>>> {
+        &self.stripes[idx % self.stripes.len()]
+    }
+
+    fn local_idx(&self, idx: usize) -> usize {
+        idx / self.stripes.len()
+    }
+}
+
+impl<T> Default for StripedTable<T> {
+    /// Creates a new, empty table with a default number of stripes
+    fn default() -> Self {
+        Self::new(DEFAULT_STRIPE_CNT)
+    }
+}
+
+impl<T> fmt::Debug for StripedTable<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StripedTable")
+            .field("stripe_cnt", &self.stripe_cnt())
+            .field("row_cnt", &self.row_cnt())
+            .finish()
+    }
+}
+
+/// Represents shared access to a single row of a [`StripedTable`]
+pub struct RowReadGuard<'a, T> {
+    guard: RwLockReadGuard<'a, Vec<Vec<T>>>,
+    idx: usize,
+}
+
+impl<'a, T> Deref for RowReadGuard<'a, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.guard[self.idx]
+    }
+}
+
+impl<'a, T: fmt::Debug> fmt::Debug for RowReadGuard<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+/// Represents exclusive access to a single row of a [`StripedTable`]
+pub struct RowWriteGuard<'a, T> {
+    guard: RwLockWriteGuard<'a, Vec<Vec<T>>>,
+    idx: usize,
+}
+
+impl<'a, T> Deref for RowWriteGuard<'a, T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.guard[self.idx]
+    }
+}
+
+impl<'a, T> DerefMut for RowWriteGuard<'a, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.guard[self.idx]
+    }
+}
+
+impl<'a, T: fmt::Debug> fmt::Debug for RowWriteGuard<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+/// Represents a consistent, read-only view of every row of a [`StripedTable`]
+pub struct Snapshot<'a, T> {
+    guards: Vec<RwLockReadGuard<'a, Vec<Vec<T>>>>,
+    row_cnt: usize,
+}
+
+impl<'a, T> Snapshot<'a, T> {
+    /// Returns the total rows captured by the snapshot
+    pub fn row_cnt(&self) -> usize {
+        self.row_cnt
+    }
+
+    /// Returns the row at the specified index
+    pub fn row(&self, idx: usize) -> Option<&[T]> {
+        if idx < self.row_cnt {
+            let stripe_cnt = self.guards.len();
+            Some(&self.guards[idx % stripe_cnt][idx / stripe_cnt])
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over the rows captured by the snapshot in order
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.row_cnt).filter_map(move |idx| self.row(idx))
+    }
+
+    /// Copies the rows captured by the snapshot into a new table
+    pub fn to_table(&self) -> DynamicTable<T>
+    where
+        T: Clone,
+    {
+        let mut table = DynamicTable::new();
+        for row in self.rows() {
+            table.push_row(row.iter().cloned());
+        }
+        table
+    }
+}
+
+impl<'a, T> fmt::Debug for Snapshot<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Snapshot")
+            .field("row_cnt", &self.row_cnt)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{sync::Arc, thread};
+
+    #[test]
+    fn push_row_should_return_index_of_new_row() {
+        let table = StripedTable::new(2);
+        assert_eq!(table.push_row(vec![1, 2]), 0);
+        assert_eq!(table.push_row(vec![3, 4]), 1);
+        assert_eq!(table.push_row(vec![5, 6]), 2);
+        assert_eq!(table.row_cnt(), 3);
+    }
+
+    #[test]
+    fn new_should_use_at_least_one_stripe() {
+        let table = StripedTable::<usize>::new(0);
+        assert_eq!(table.stripe_cnt(), 1);
+    }
+
+    #[test]
+    fn read_row_should_return_none_if_row_missing() {
+        let table = StripedTable::new(4);
+        table.push_row(vec![1]);
+        assert!(table.read_row(1).is_none());
+    }
+
+    #[test]
+    fn read_row_should_return_row_across_stripes() {
+        let table = StripedTable::new(2);
+        for i in 0..5 {
+            table.push_row(vec![i, i * 10]);
+        }
+
+        for i in 0..5 {
+            assert_eq!(&*table.read_row(i).unwrap(), &[i, i * 10]);
+        }
+    }
+
+    #[test]
+    fn write_row_should_support_modifying_row() {
+        let table = StripedTable::new(2);
+        table.push_row(vec![1, 2]);
+        table.push_row(vec![3, 4]);
+
+        {
+            let mut row = table.write_row(1).unwrap();
+            row[0] = 30;
+            row.push(5);
+        }
+
+        assert_eq!(&*table.read_row(0).unwrap(), &[1, 2]);
+        assert_eq!(&*table.read_row(1).unwrap(), &[30, 4, 5]);
+    }
+
+    #[test]
+    fn read_row_should_not_block_on_writer_of_row_in_other_stripe() {
+        let table = StripedTable::new(2);
+        table.push_row(vec![1]);
+        table.push_row(vec![2]);
+
+        let _writer = table.write_row(0).unwrap();
+        assert_eq!(&*table.read_row(1).unwrap(), &[2]);
+    }
+
+    #[test]
+    fn snapshot_should_capture_all_rows_in_order() {
+        let table = StripedTable::new(3);
+        for i in 0..7 {
+            table.push_row(vec![i]);
+        }
+
+        let snapshot = table.snapshot();
+        assert_eq!(snapshot.row_cnt(), 7);
+        assert_eq!(
+            snapshot.rows().map(|r| r[0]).collect::<Vec<_>>(),
+            (0..7).collect::<Vec<_>>()
+        );
+
+        let copy = snapshot.to_table();
+        assert_eq!(copy.row_cnt(), 7);
+        assert_eq!(copy.cell(6, 0), Some(&6));
+    }
+
+    #[test]
+    fn table_should_support_concurrent_writers() {
+        let table = Arc::new(StripedTable::new(4));
+        for _ in 0..8 {
+            table.push_row(vec![0usize]);
+        }
+
+        let handles: Vec<_> = (0..8)
+            .map(|i| {
+                let table = Arc::clone(&table);
+                thread::spawn(move || {
+                    for _ in 0..100 {
+                        table.write_row(i).unwrap()[0] += 1;
+                    }
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert!(table.snapshot().rows().all(|r| r[0] == 100));
+    }
+}
@@ -0,0 +1,215 @@
+use crate::Table;
+use core::fmt::{self, Write};
+use std::string::String;
+
+/// Asserts that two tables have the same shape and the same cells, panicking
+/// with a listing of every mismatched cell and its position otherwise
+///
+/// Both tables must hold the same type of data, which must implement
+/// [`PartialEq`] and [`Debug`](core::fmt::Debug)
+///
+/// ### Examples
+///
+/// ```
+/// # use memtable_core::{assert_table_eq, prelude::*};
+/// let left = DynamicTable::from([[1, 2], [3, 4]]);
+/// let right = FixedTable::from([[1, 2], [3, 4]]);
+///
+/// assert_table_eq!(left, right);
+/// ```
+#[macro_export]
+#[cfg_attr(feature = "docs", doc(cfg(all(test_util, any(alloc, std)))))]
+macro_rules! assert_table_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::test_util::assert_table_eq(&$left, &$right)
+    };
+}
+
+/// Asserts that a table matches a pattern of rows, where each cell of the
+/// pattern is either a value the table's cell must equal or `_` to accept
+/// anything, panicking with a listing of every mismatched cell and its
+/// position otherwise
+///
+/// The table must have exactly as many rows as the pattern and, for each
+/// row, no cells beyond those in the pattern's row
+///
+/// ### Examples
+///
+/// ```
+/// # use memtable_core::{assert_table_matches, prelude::*};
+/// let table = DynamicTable::from([[1, 2, 3], [4, 5, 6]]);
+///
+/// assert_table_matches!(table, [[1, _, 3], [_, 5, _]]);
+/// ```
+#[macro_export]
+#[cfg_attr(feature = "docs", doc(cfg(all(test_util, any(alloc, std)))))]
+macro_rules! assert_table_matches {
+    ($table:expr, [$([$($cells:tt)*]),* $(,)?] $(,)?) => {
+        $crate::test_util::assert_table_matches(
+            &$table,
+            &[$($crate::__table_row_pattern!([] $($cells)*)),*],
+        )
+    };
+}
+
+/// Builds a row of a pattern for [`assert_table_matches!`]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __table_row_pattern {
+    ([$($out:expr),*] $(,)?) => {
+        &[$($out),*][..]
+    };
+    ([$($out:expr),*] _ $(, $($rest:tt)*)?) => {
+        $crate::__table_row_pattern!(
+            [$($out,)* ::core::option::Option::None] $($($rest)*)?
+        )
+    };
+    ([$($out:expr),*] $cell:expr $(, $($rest:tt)*)?) => {
+        $crate::__table_row_pattern!(
+            [$($out,)* ::core::option::Option::Some(&$cell)] $($($rest)*)?
+        )
+    };
+}
+
+/// Asserts that two tables have the same shape and the same cells; used by
+/// [`assert_table_eq!`]
+#[track_caller]
+pub fn assert_table_eq<L, R>(left: &L, right: &R)
+where
+    L: Table,
+    R: Table<Data = L::Data>,
+    L::Data: PartialEq + fmt::Debug,
+{
+    let mut diff = String::new();
+
+    if left.row_cnt() != right.row_cnt() || left.col_cnt() != right.col_cnt() {
+        let _ = writeln!(
+            diff,
+            "  shape: left is {}x{}, right is {}x{}",
+            left.row_cnt(),
+            left.col_cnt(),
+            right.row_cnt(),
+            right.col_cnt()
+        );
+    }
+
+    let row_cnt = core::cmp::max(left.row_cnt(), right.row_cnt());
+    let col_cnt = core::cmp::max(left.col_cnt(), right.col_cnt());
+    for row in 0..row_cnt {
+        for col in 0..col_cnt {
+            let (l, r) = (left.cell(row, col), right.cell(row, col));
+            if l != r {
+                let _ = writeln!(
+                    diff,
+                    "  ({}, {}): left = {:?}, right = {:?}",
+                    row, col, l, r
+                );
+            }
+        }
+    }
+
+    if !diff.is_empty() {
+        panic!("assertion failed: tables are not equal\n{}", diff);
+    }
+}
+
+/// Asserts that a table matches a pattern of rows, where `None` accepts any
+/// cell; used by [`assert_table_matches!`]
+#[track_caller]
+pub fn assert_table_matches<T>(table: &T, pattern: &[&[Option<&T::Data>]])
+where
+    T: Table,
+    T::Data: PartialEq + fmt::Debug,
+{
+    let mut diff = String::new();
+
+    if table.row_cnt() != pattern.len() {
+        let _ = writeln!(
+            diff,
+            "  shape: table has {} rows, pattern has {}",
+            table.row_cnt(),
+            pattern.len()
+        );
+    }
+
+    for row in 0..core::cmp::max(table.row_cnt(), pattern.len()) {
+        let expected = pattern.get(row).copied().unwrap_or(&[]);
+
+        for col in 0..core::cmp::max(table.col_cnt(), expected.len()) {
+            let actual = table.cell(row, col);
+            match expected.get(col) {
+                Some(None) => continue,
+                Some(Some(x)) if actual == Some(*x) => continue,
+                Some(Some(x)) => {
+                    let _ = writeln!(
+                        diff,
+                        "  ({}, {}): expected {:?}, found {:?}",
+                        row, col, x, actual
+                    );
+                }
+                None if actual.is_none() => continue,
+                None => {
+                    let _ = writeln!(
+                        diff,
+                        "  ({}, {}): expected no cell, found {:?}",
+                        row, col, actual
+                    );
+                }
+            }
+        }
+    }
+
+    if !diff.is_empty() {
+        panic!("assertion failed: table does not match pattern\n{}", diff);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DynamicTable, FixedTable, Table};
+
+    #[test]
+    fn assert_table_eq_should_accept_tables_of_different_types() {
+        let mut left = DynamicTable::new();
+        left.push_row(std::vec![1, 2]);
+
+        assert_table_eq!(left, FixedTable::from([[1, 2]]));
+    }
+
+    #[test]
+    #[should_panic(expected = "(1, 0): left = Some(3), right = Some(9)")]
+    fn assert_table_eq_should_list_mismatched_cells() {
+        assert_table_eq!(
+            DynamicTable::from([[1, 2], [3, 4]]),
+            DynamicTable::from([[1, 2], [9, 4]])
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "shape: left is 1x2, right is 2x2")]
+    fn assert_table_eq_should_report_different_shapes() {
+        assert_table_eq!(
+            DynamicTable::from([[1, 2]]),
+            DynamicTable::from([[1, 2], [3, 4]])
+        );
+    }
+
+    #[test]
+    fn assert_table_matches_should_accept_wildcards() {
+        let table = DynamicTable::from([["a", "b"], ["c", "d"]]);
+        assert_table_matches!(table, [["a", _], [_, "d"]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "(0, 2): expected no cell, found Some(3)")]
+    fn assert_table_matches_should_reject_extra_cells() {
+        assert_table_matches!(DynamicTable::from([[1, 2, 3]]), [[1, 2]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "(1, 1): expected 5, found Some(4)")]
+    fn assert_table_matches_should_list_mismatched_cells() {
+        let table = DynamicTable::from([[1, 2], [3, 4]]);
+        assert_table_matches!(table, [[_, 2], [3, 2 + 3]]);
+    }
+}
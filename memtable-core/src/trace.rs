@@ -0,0 +1,20 @@
+//! Macros that emit `tracing` spans and events when the `tracing` feature is
+//! enabled, and expand to nothing otherwise so that call sites do not need
+//! their own feature checks
+
+/// Enters a debug span that lasts until the end of the enclosing block
+macro_rules! trace_span {
+    ($($arg:tt)+) => {
+        #[cfg(feature = "tracing")]
+        let _span = ::tracing::debug_span!($($arg)+).entered();
+    };
+}
+
+/// Emits a debug event within the current span
+#[allow(unused_macros)]
+macro_rules! trace_event {
+    ($($arg:tt)+) => {
+        #[cfg(feature = "tracing")]
+        ::tracing::debug!($($arg)+);
+    };
+}
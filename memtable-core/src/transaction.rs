@@ -0,0 +1,408 @@
+use crate::{ops::Op, Capacity, Table};
+use std::{boxed::Box, vec::Vec};
+
+/// Represents a check run against a table once a transaction's changes have
+/// been applied
+type Check<'c, T, E> = Box<dyn FnOnce(&T) -> Result<(), E> + 'c>;
+
+/// Represents a batch of changes to a table that are buffered and then
+/// applied all at once, or not at all
+///
+/// Created by [`Table::transaction`], which applies the changes only if the
+/// transaction's closure succeeds and every check registered through
+/// [`Transaction::check`] passes against the changed table; if a check
+/// fails, the changes are rolled back using a log of the cells they replaced
+pub struct Transaction<'t, 'c, D, T: Table<Data = D>, E> {
+    table: &'t T,
+    changes: Vec<Op<D>>,
+    checks: Vec<Check<'c, T, E>>,
+}
+
+impl<'t, 'c, D, T: Table<Data = D>, E> Transaction<'t, 'c, D, T, E> {
+    fn new(table: &'t T) -> Self {
+        Self {
+            table,
+            changes: Vec::new(),
+            checks: Vec::new(),
+        }
+    }
+
+    /// Returns a reference to the table as it was before the transaction
+    pub fn table(&self) -> &T {
+        self.table
+    }
+
+    /// Returns the total changes buffered by the transaction
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    /// Returns true if the transaction has no buffered changes
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Buffers inserting the value into the cell at the specified row and
+    /// column
+    pub fn insert_cell(&mut self, row: usize, col: usize, value: D) {
+        self.changes.push(Op::InsertCell { row, col, value });
+    }
+
+    /// Buffers removing the cell at the specified row and column
+    pub fn remove_cell(&mut self, row: usize, col: usize) {
+        self.changes.push(Op::RemoveCell { row, col });
+    }
+
+    /// Buffers inserting a row at the specified index
+    pub fn insert_row<I: IntoIterator<Item = D>>(&mut self, row: usize, cells: I) {
+        let cells = cells.into_iter().collect();
+        self.changes.push(Op::InsertRow { row, cells });
+    }
+
+    /// Buffers pushing a row to the end of the table
+    pub fn push_row<I: IntoIterator<Item = D>>(&mut self, cells: I) {
+        self.changes.push(Op::PushRow(cells.into_iter().collect()));
+    }
+
+    /// Buffers removing the row at the specified index
+    pub fn remove_row(&mut self, row: usize) {
+        self.changes.push(Op::RemoveRow(row));
+    }
+
+    /// Buffers inserting a column at the specified index
+    pub fn insert_column<I: IntoIterator<Item = D>>(&mut self, col: usize, cells: I) {
+        let cells = cells.into_iter().collect();
+        self.changes.push(Op::InsertColumn { col, cells });
+    }
+
+    /// Buffers pushing a column to the end of the table
+    pub fn push_column<I: IntoIterator<Item = D>>(&mut self, cells: I) {
+        self.changes
+            .push(Op::PushColumn(cells.into_iter().collect()));
+    }
+
+    /// Buffers removing the column at the specified index
+    pub fn remove_column(&mut self, col: usize) {
+        self.changes.push(Op::RemoveColumn(col));
+    }
+
+    /// Buffers changing the preferred row count of the table
+    pub fn set_preferred_row_cnt(&mut self, cnt: usize) {
+        self.changes.push(Op::SetPreferredRowCnt(cnt));
+    }
+
+    /// Buffers changing the preferred column count of the table
+    pub fn set_preferred_col_cnt(&mut self, cnt: usize) {
+        self.changes.push(Op::SetPreferredColCnt(cnt));
+    }
+
+    /// Buffers removing all cells outside of the row & column counts of the
+    /// table
+    ///
+    /// Rolling back only restores cells hidden by this transaction, so cells
+    /// that were already outside of the counts when the transaction began
+    /// stay removed
+    pub fn truncate(&mut self) {
+        self.changes.push(Op::Truncate);
+    }
+
+    /// Buffers adjusting the row & column counts of the table to fit its
+    /// cells
+    pub fn shrink_to_fit(&mut self) {
+        self.changes.push(Op::ShrinkToFit);
+    }
+
+    /// Registers a check to run against the table once every change has been
+    /// applied, rolling back all of the changes if it fails
+    pub fn check(&mut self, f: impl FnOnce(&T) -> Result<(), E> + 'c) {
+        self.checks.push(Box::new(f));
+    }
+}
+
+impl<'t, 'c, D, T: Table<Data = D>, E> core::fmt::Debug for Transaction<'t, 'c, D, T, E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Transaction")
+            .field("changes", &self.changes.len())
+            .field("checks", &self.checks.len())
+            .finish()
+    }
+}
+
+/// Represents the state replaced by a change to a table, used to roll the
+/// change back
+enum Undo<D> {
+    Cell {
+        row: usize,
+        col: usize,
+        prev: Option<D>,
+    },
+    RowCnt(usize),
+    ColCnt(usize),
+}
+
+/// Represents a table that records the state replaced by every change made
+/// to the table it wraps
+struct Recorder<'a, T: Table> {
+    table: &'a mut T,
+    log: Vec<Undo<T::Data>>,
+
+    /// Largest row & column counts seen since recording began, bounding the
+    /// cells that a truncate may need to restore
+    max_row_cnt: usize,
+    max_col_cnt: usize,
+}
+
+impl<'a, T: Table> Recorder<'a, T> {
+    fn new(table: &'a mut T) -> Self {
+        let max_row_cnt = table.row_cnt();
+        let max_col_cnt = table.col_cnt();
+        Self {
+            table,
+            log: Vec::new(),
+            max_row_cnt,
+            max_col_cnt,
+        }
+    }
+
+    fn track_cnts(&mut self) {
+        self.max_row_cnt = core::cmp::max(self.max_row_cnt, self.table.row_cnt());
+        self.max_col_cnt = core::cmp::max(self.max_col_cnt, self.table.col_cnt());
+    }
+
+    /// Reverts every recorded change, most recent first
+    fn rollback(self) {
+        for undo in self.log.into_iter().rev() {
+            match undo {
+                Undo::Cell {
+                    row,
+                    col,
+                    prev: Some(x),
+                } => {
+                    self.table.insert_cell(row, col, x);
+                }
+                Undo::Cell {
+                    row,
+                    col,
+                    prev: None,
+                } => {
+                    self.table.remove_cell(row, col);
+                }
+                Undo::RowCnt(cnt) => self.table.set_preferred_row_cnt(cnt),
+                Undo::ColCnt(cnt) => self.table.set_preferred_col_cnt(cnt),
+            }
+        }
+    }
+}
+
+impl<'a, T: Table> Table for Recorder<'a, T>
+where
+    T::Data: Clone,
+{
+    type Data = T::Data;
+    type Row = T::Row;
+    type Column = T::Column;
+
+    fn max_row_capacity(&self) -> Capacity {
+        self.table.max_row_capacity()
+    }
+
+    fn max_column_capacity(&self) -> Capacity {
+        self.table.max_column_capacity()
+    }
+
+    fn row_cnt(&self) -> usize {
+        self.table.row_cnt()
+    }
+
+    fn col_cnt(&self) -> usize {
+        self.table.col_cnt()
+    }
+
+    fn set_preferred_row_cnt(&mut self, cnt: usize) {
+        self.log.push(Undo::RowCnt(self.table.row_cnt()));
+        self.table.set_preferred_row_cnt(cnt);
+        self.track_cnts();
+    }
+
+    fn set_preferred_col_cnt(&mut self, cnt: usize) {
+        self.log.push(Undo::ColCnt(self.table.col_cnt()));
+        self.table.set_preferred_col_cnt(cnt);
+        self.track_cnts();
+    }
+
+    /// Logs every cell hidden since recording began before removing it, as
+    /// found by briefly raising the counts back to the largest seen
+    fn truncate(&mut self) {
+        let row_cnt = self.table.row_cnt();
+        let col_cnt = self.table.col_cnt();
+
+        self.table.set_preferred_row_cnt(self.max_row_cnt);
+        self.table.set_preferred_col_cnt(self.max_col_cnt);
+        for row in 0..self.table.row_cnt() {
+            for col in 0..self.table.col_cnt() {
+                if row >= row_cnt || col >= col_cnt {
+                    let prev = self.table.cell(row, col).cloned();
+                    self.log.push(Undo::Cell { row, col, prev });
+                }
+            }
+        }
+        self.table.set_preferred_row_cnt(row_cnt);
+        self.table.set_preferred_col_cnt(col_cnt);
+
+        self.table.truncate();
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.log.push(Undo::RowCnt(self.table.row_cnt()));
+        self.log.push(Undo::ColCnt(self.table.col_cnt()));
+        self.table.shrink_to_fit();
+        self.track_cnts();
+    }
+
+    fn cell(&self, row: usize, col: usize) -> Option<&Self::Data> {
+        self.table.cell(row, col)
+    }
+
+    fn mut_cell(&mut self, row: usize, col: usize) -> Option<&mut Self::Data> {
+        self.table.mut_cell(row, col)
+    }
+
+    fn insert_cell(&mut self, row: usize, col: usize, value: Self::Data) -> Option<Self::Data> {
+        let row_cnt = self.table.row_cnt();
+        let col_cnt = self.table.col_cnt();
+        let prev = self.table.insert_cell(row, col, value);
+
+        // Growing the table is undone by restoring the counts after the cell
+        if self.table.row_cnt() != row_cnt {
+            self.log.push(Undo::RowCnt(row_cnt));
+        }
+        if self.table.col_cnt() != col_cnt {
+            self.log.push(Undo::ColCnt(col_cnt));
+        }
+        self.track_cnts();
+
+        self.log.push(Undo::Cell {
+            row,
+            col,
+            prev: prev.clone(),
+        });
+        prev
+    }
+
+    fn remove_cell(&mut self, row: usize, col: usize) -> Option<Self::Data> {
+        let prev = self.table.remove_cell(row, col);
+        self.log.push(Undo::Cell {
+            row,
+            col,
+            prev: prev.clone(),
+        });
+        prev
+    }
+}
+
+impl<'t, 'c, D: Clone, T: Table<Data = D>, E> Transaction<'t, 'c, D, T, E> {
+    /// Runs the closure against a new transaction over the table, applying
+    /// the buffered changes only if the closure succeeds and all checks pass
+    pub(crate) fn run<R, F>(table: &mut T, f: F) -> Result<R, E>
+    where
+        F: for<'a> FnOnce(&mut Transaction<'a, 'c, D, T, E>) -> Result<R, E>,
+    {
+        let (value, changes, checks) = {
+            let mut tx = Transaction::new(table);
+            let value = f(&mut tx)?;
+            (value, tx.changes, tx.checks)
+        };
+
+        let mut recorder = Recorder::new(table);
+        for change in changes {
+            change.apply_to(&mut recorder);
+        }
+
+        for check in checks {
+            if let Err(x) = check(recorder.table) {
+                recorder.rollback();
+                return Err(x);
+            }
+        }
+
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::DynamicTable;
+    use crate::Table;
+
+    #[test]
+    fn should_apply_all_changes_if_closure_succeeds() {
+        let mut table = DynamicTable::from([[1, 2], [3, 4]]);
+
+        let result: Result<usize, ()> = table.transaction(|tx| {
+            tx.insert_cell(0, 0, 5);
+            tx.push_row(std::vec![6, 7]);
+            tx.remove_row(1);
+            tx.push_column(std::vec![8, 9]);
+            Ok(tx.len())
+        });
+
+        assert_eq!(result, Ok(4));
+        assert_eq!(table, [[5, 2, 8], [6, 7, 9]]);
+    }
+
+    #[test]
+    fn should_apply_nothing_if_closure_fails() {
+        let mut table = DynamicTable::from([[1, 2], [3, 4]]);
+
+        let result: Result<(), &str> = table.transaction(|tx| {
+            tx.insert_cell(0, 0, 5);
+            tx.remove_column(0);
+            assert_eq!(tx.table().cell(0, 0), Some(&1));
+            Err("aborted")
+        });
+
+        assert_eq!(result, Err("aborted"));
+        assert_eq!(table, [[1, 2], [3, 4]]);
+    }
+
+    #[test]
+    fn should_roll_back_all_changes_if_check_fails() {
+        let mut table = DynamicTable::from([[1, 2], [3, 4]]);
+
+        let result = table.transaction(|tx| {
+            tx.remove_row(0);
+            tx.insert_cell(3, 3, 5);
+            tx.insert_column(0, std::vec![6, 7]);
+            tx.set_preferred_row_cnt(1);
+            tx.check(|t| {
+                if t.col_cnt() > 4 {
+                    Err(t.col_cnt())
+                } else {
+                    Ok(())
+                }
+            });
+            Ok(())
+        });
+
+        assert_eq!(result, Err(5));
+        assert_eq!(table.row_cnt(), 2);
+        assert_eq!(table.col_cnt(), 2);
+        assert_eq!(table, [[1, 2], [3, 4]]);
+    }
+
+    #[test]
+    fn should_restore_truncated_cells_if_check_fails() {
+        let mut table = DynamicTable::from([[1, 2], [3, 4]]);
+
+        let result = table.transaction(|tx| {
+            tx.set_preferred_row_cnt(1);
+            tx.truncate();
+            tx.shrink_to_fit();
+            tx.check(|_| Err(()));
+            Ok(())
+        });
+
+        assert_eq!(result, Err(()));
+        assert_eq!(table, [[1, 2], [3, 4]]);
+    }
+}
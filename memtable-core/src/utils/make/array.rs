@@ -1,7 +1,4 @@
-use core::{
-    convert::Infallible,
-    mem::{self, MaybeUninit},
-};
+use core::{convert::Infallible, mem::MaybeUninit};
 
 /// Creates a new array initialized element-by-element using the provided
 /// function to produce `T`
@@ -21,7 +18,7 @@ pub fn try_make_array<T: Sized, E, const N: usize>(
 ) -> Result<[T; N], E> {
     unsafe {
         let mut data: MaybeUninit<[T; N]> = MaybeUninit::uninit();
-        let data_ptr: *mut T = mem::transmute(&mut data);
+        let data_ptr = &mut data as *mut MaybeUninit<[T; N]> as *mut T;
         let mut cnt = 0;
         let mut err = None;
 
@@ -1,8 +1,5 @@
 use super::array::try_make_array;
-use core::{
-    convert::Infallible,
-    mem::{self, MaybeUninit},
-};
+use core::{convert::Infallible, mem::MaybeUninit};
 
 /// Creates a new array initialized element-by-element using the provided
 /// function to produce `T`
@@ -22,7 +19,7 @@ pub fn try_make_table_array<T: Sized, E, const ROW: usize, const COL: usize>(
 ) -> Result<[[T; COL]; ROW], E> {
     unsafe {
         let mut data: MaybeUninit<[[T; COL]; ROW]> = MaybeUninit::uninit();
-        let data_ptr: *mut [T; COL] = mem::transmute(&mut data);
+        let data_ptr = &mut data as *mut MaybeUninit<[[T; COL]; ROW]> as *mut [T; COL];
         let mut cnt = 0;
         let mut err = None;
 
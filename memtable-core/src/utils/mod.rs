@@ -1,8 +1,31 @@
 mod make;
 
+#[allow(unused_imports)]
 pub use make::array::{default_array, make_array, try_make_array};
+#[allow(unused_imports)]
 pub use make::table_array::{default_table_array, make_table_array, try_make_table_array};
 
+mod swap;
+pub use swap::{swap_cells_by_removal, swap_in_rows, within_capacity};
+
+mod shift;
+pub use shift::{shift_slice, shifted_index};
+
+#[cfg(all(
+    any(feature = "allocator-api", feature = "bumpalo"),
+    any(feature = "alloc", feature = "std")
+))]
+mod sorted;
+#[cfg(all(
+    any(feature = "allocator-api", feature = "bumpalo"),
+    any(feature = "alloc", feature = "std")
+))]
+pub use sorted::{CellVec, SortedCells};
+
+/// Contains helpers to escape and normalize the text of cells
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub mod text;
+
 #[cfg(feature = "serde-1")]
 mod ser;
 
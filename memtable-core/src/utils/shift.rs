@@ -0,0 +1,39 @@
+use core::cmp;
+
+/// Returns the index that the item at `idx` within a sequence of `len` items
+/// moves to when the sequence is shifted by the offset, or `None` if the item
+/// is shifted off of either end without wrapping around
+pub fn shifted_index(idx: usize, len: usize, offset: isize, wrap: bool) -> Option<usize> {
+    if wrap {
+        Some((idx + offset.rem_euclid(len as isize) as usize) % len)
+    } else if offset >= 0 {
+        idx.checked_add(offset as usize).filter(|idx| *idx < len)
+    } else {
+        idx.checked_sub(offset.unsigned_abs())
+    }
+}
+
+/// Shifts the items of the slice by the offset, towards the end if positive
+/// and towards the start if negative
+///
+/// Items shifted past either end wrap around to the other if `wrap` is true,
+/// otherwise they are dropped and the items left vacant are reset using the
+/// function
+pub fn shift_slice<T>(items: &mut [T], offset: isize, wrap: bool, mut reset: impl FnMut() -> T) {
+    let len = items.len();
+    if len == 0 {
+        return;
+    }
+
+    if wrap {
+        items.rotate_right(offset.rem_euclid(len as isize) as usize);
+    } else if offset >= 0 {
+        let k = cmp::min(offset as usize, len);
+        items.rotate_right(k);
+        items[..k].iter_mut().for_each(|x| *x = reset());
+    } else {
+        let k = cmp::min(offset.unsigned_abs(), len);
+        items.rotate_left(k);
+        items[len - k..].iter_mut().for_each(|x| *x = reset());
+    }
+}
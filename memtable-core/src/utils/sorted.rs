@@ -0,0 +1,127 @@
+use crate::{DynamicTable, Position, Table};
+use core::{cmp, mem};
+
+/// Represents a vector that can hold the cells of a [`SortedCells`], which
+/// lets tables backed by vectors from different allocators share one core
+pub trait CellVec {
+    /// Type of data held by each cell
+    type Data;
+
+    /// Returns the cells in order of position
+    fn as_cells(&self) -> &[(Position, Self::Data)];
+
+    /// Returns the cells in order of position, allowing their data to change
+    fn as_mut_cells(&mut self) -> &mut [(Position, Self::Data)];
+
+    /// Inserts the cell at the given index, shifting all cells after it
+    fn insert_cell_at(&mut self, idx: usize, cell: (Position, Self::Data));
+
+    /// Removes the cell at the given index, shifting all cells after it
+    fn remove_cell_at(&mut self, idx: usize) -> (Position, Self::Data);
+
+    /// Keeps only the cells whose positions satisfy `f`
+    fn retain_cells<F: FnMut(&Position) -> bool>(&mut self, f: F);
+}
+
+/// Represents cells kept in a single vector sorted by position alongside the
+/// row & column counts of the table holding them
+///
+/// Looking up a cell is a binary search over the vector, but inserting or
+/// removing a cell shifts every cell positioned after it, which is `O(n)` in
+/// the total cells
+#[derive(Clone, Debug)]
+pub struct SortedCells<V> {
+    pub cells: V,
+    pub row_cnt: usize,
+    pub col_cnt: usize,
+}
+
+impl<V: CellVec> SortedCells<V> {
+    pub fn new(cells: V) -> Self {
+        Self {
+            cells,
+            row_cnt: 0,
+            col_cnt: 0,
+        }
+    }
+
+    fn search(&self, row: usize, col: usize) -> Result<usize, usize> {
+        self.cells
+            .as_cells()
+            .binary_search_by(|(pos, _)| (pos.row, pos.col).cmp(&(row, col)))
+    }
+
+    pub fn cell(&self, row: usize, col: usize) -> Option<&V::Data> {
+        let idx = self.search(row, col).ok()?;
+        Some(&self.cells.as_cells()[idx].1)
+    }
+
+    pub fn mut_cell(&mut self, row: usize, col: usize) -> Option<&mut V::Data> {
+        let idx = self.search(row, col).ok()?;
+        Some(&mut self.cells.as_mut_cells()[idx].1)
+    }
+
+    pub fn insert_cell(&mut self, row: usize, col: usize, value: V::Data) -> Option<V::Data> {
+        if row >= self.row_cnt {
+            self.row_cnt = row + 1;
+        }
+
+        if col >= self.col_cnt {
+            self.col_cnt = col + 1;
+        }
+
+        match self.search(row, col) {
+            Ok(idx) => Some(mem::replace(&mut self.cells.as_mut_cells()[idx].1, value)),
+            Err(idx) => {
+                self.cells
+                    .insert_cell_at(idx, (Position { row, col }, value));
+                None
+            }
+        }
+    }
+
+    pub fn remove_cell(&mut self, row: usize, col: usize) -> Option<V::Data> {
+        let idx = self.search(row, col).ok()?;
+        Some(self.cells.remove_cell_at(idx).1)
+    }
+
+    pub fn truncate(&mut self) {
+        let (row_cnt, col_cnt) = (self.row_cnt, self.col_cnt);
+        self.cells
+            .retain_cells(|pos| pos.row < row_cnt && pos.col < col_cnt);
+    }
+
+    pub fn shrink_to_fit(&mut self) {
+        let (max_row, max_col) = self.cells.as_cells().iter().fold((0, 0), |acc, (pos, _)| {
+            (cmp::max(acc.0, pos.row + 1), cmp::max(acc.1, pos.col + 1))
+        });
+
+        self.row_cnt = max_row;
+        self.col_cnt = max_col;
+    }
+
+    /// Moves the cells into a [`DynamicTable`] with the same row & column
+    /// counts
+    pub fn into_table(self) -> DynamicTable<V::Data>
+    where
+        V: IntoIterator<Item = (Position, <V as CellVec>::Data)>,
+    {
+        let mut table: DynamicTable<V::Data> = self.cells.into_iter().collect();
+        table.set_preferred_row_cnt(self.row_cnt);
+        table.set_preferred_col_cnt(self.col_cnt);
+        table
+    }
+}
+
+impl<V: CellVec> PartialEq for SortedCells<V>
+where
+    V::Data: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.row_cnt == other.row_cnt
+            && self.col_cnt == other.col_cnt
+            && self.cells.as_cells() == other.cells.as_cells()
+    }
+}
+
+impl<V: CellVec> Eq for SortedCells<V> where V::Data: Eq {}
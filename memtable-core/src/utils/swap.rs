@@ -0,0 +1,50 @@
+use crate::{Position, Table};
+
+/// Swaps the cells at the two positions by removing both and inserting each
+/// into the other's position, which works for any table
+///
+/// Nothing happens if either position is outside of the table's maximum
+/// capacity, as the cell moved there would otherwise be lost
+pub fn swap_cells_by_removal<T: Table>(table: &mut T, a: Position, b: Position) {
+    if a == b || !within_capacity(table, a) || !within_capacity(table, b) {
+        return;
+    }
+
+    let x = table.remove_cell(a.row, a.col);
+    let y = table.remove_cell(b.row, b.col);
+
+    if let Some(y) = y {
+        table.insert_cell(a.row, a.col, y);
+    }
+
+    if let Some(x) = x {
+        table.insert_cell(b.row, b.col, x);
+    }
+}
+
+/// Swaps the cells at the two positions within rows of contiguous cells
+/// without removing either of them
+///
+/// Panics if either position is outside of the rows
+pub fn swap_in_rows<T, R: AsMut<[T]>>(rows: &mut [R], a: Position, b: Position) {
+    if a.row == b.row {
+        rows[a.row].as_mut().swap(a.col, b.col);
+    } else {
+        let (top, bottom) = if a.row < b.row { (a, b) } else { (b, a) };
+        let (head, tail) = rows.split_at_mut(bottom.row);
+        core::mem::swap(
+            &mut head[top.row].as_mut()[top.col],
+            &mut tail[0].as_mut()[bottom.col],
+        );
+    }
+}
+
+/// Returns true if the position is within the table's maximum capacity
+pub fn within_capacity<T: Table>(table: &T, pos: Position) -> bool {
+    let fits = |idx, capacity: crate::Capacity| match capacity.limit() {
+        Some(limit) => idx < limit,
+        None => true,
+    };
+
+    fits(pos.row, table.max_row_capacity()) && fits(pos.col, table.max_column_capacity())
+}
@@ -0,0 +1,157 @@
+use std::{borrow::Cow, string::String};
+
+/// Escapes the text of a cell to be written as a field of a CSV record
+/// separated by `delimiter`
+///
+/// The text is surrounded by double quotes, with any embedded double quotes
+/// doubled, if it contains the delimiter, a double quote, a line break, or
+/// leading or trailing whitespace; otherwise it is returned unchanged
+///
+/// ### Examples
+///
+/// ```
+/// # use memtable_core::text::escape_csv;
+/// assert_eq!(escape_csv("plain", ','), "plain");
+/// assert_eq!(escape_csv("a,b", ','), "\"a,b\"");
+/// assert_eq!(escape_csv("say \"hi\"\n", ','), "\"say \"\"hi\"\"\n\"");
+/// assert_eq!(escape_csv("a,b", ';'), "a,b");
+/// ```
+pub fn escape_csv(s: &str, delimiter: char) -> Cow<'_, str> {
+    let needs_quotes = s
+        .chars()
+        .any(|c| c == delimiter || c == '"' || c == '\n' || c == '\r')
+        || s.starts_with(char::is_whitespace)
+        || s.ends_with(char::is_whitespace);
+
+    if !needs_quotes {
+        return Cow::Borrowed(s);
+    }
+
+    let mut escaped = String::with_capacity(s.len() + 2);
+    escaped.push('"');
+    for c in s.chars() {
+        if c == '"' {
+            escaped.push('"');
+        }
+        escaped.push(c);
+    }
+    escaped.push('"');
+    Cow::Owned(escaped)
+}
+
+/// Escapes the text of a cell to be written within a markdown table
+///
+/// Backslashes and pipes are escaped with a backslash so they do not end the
+/// cell, and line breaks are replaced with `<br>` as a cell cannot span
+/// multiple lines
+///
+/// ### Examples
+///
+/// ```
+/// # use memtable_core::text::escape_markdown;
+/// assert_eq!(escape_markdown("plain"), "plain");
+/// assert_eq!(escape_markdown("a|b"), "a\\|b");
+/// assert_eq!(escape_markdown("one\r\ntwo"), "one<br>two");
+/// ```
+pub fn escape_markdown(s: &str) -> Cow<'_, str> {
+    if !s.contains(&['\\', '|', '\n', '\r'][..]) {
+        return Cow::Borrowed(s);
+    }
+
+    let mut escaped = String::with_capacity(s.len() + 4);
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' | '|' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\r' => {
+                if chars.peek() == Some(&'\n') {
+                    chars.next();
+                }
+                escaped.push_str("<br>");
+            }
+            '\n' => escaped.push_str("<br>"),
+            c => escaped.push(c),
+        }
+    }
+    Cow::Owned(escaped)
+}
+
+/// Normalizes the text of a cell so that visually identical cells compare
+/// equal, returning the text unchanged if it is already normalized
+///
+/// * `\r\n` and lone `\r` line breaks become `\n`
+/// * Unicode spaces such as the non-breaking space become an ascii space
+/// * Invisible characters such as the byte order mark and zero-width
+///   spaces are removed
+///
+/// Canonical composition of combining characters is not performed
+///
+/// ### Examples
+///
+/// ```
+/// # use memtable_core::text::normalize;
+/// assert_eq!(normalize("a\r\nb\rc"), "a\nb\nc");
+/// assert_eq!(normalize("\u{feff}1\u{a0}000\u{200b}"), "1 000");
+/// assert_eq!(normalize("plain\ttext"), "plain\ttext");
+/// ```
+pub fn normalize(s: &str) -> Cow<'_, str> {
+    fn is_invisible(c: char) -> bool {
+        matches!(
+            c,
+            '\u{200b}' | '\u{200c}' | '\u{200d}' | '\u{2060}' | '\u{feff}'
+        )
+    }
+
+    fn is_unusual_space(c: char) -> bool {
+        c.is_whitespace() && !matches!(c, ' ' | '\t' | '\n')
+    }
+
+    if !s.contains(|c| is_invisible(c) || is_unusual_space(c)) {
+        return Cow::Borrowed(s);
+    }
+
+    let mut normalized = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\r' => {
+                if chars.peek() == Some(&'\n') {
+                    chars.next();
+                }
+                normalized.push('\n');
+            }
+            c if is_invisible(c) => {}
+            c if is_unusual_space(c) => normalized.push(' '),
+            c => normalized.push(c),
+        }
+    }
+    Cow::Owned(normalized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escape_csv_should_quote_leading_and_trailing_whitespace() {
+        assert_eq!(escape_csv(" a", ','), "\" a\"");
+        assert_eq!(escape_csv("a ", ','), "\"a \"");
+        assert_eq!(escape_csv("a b", ','), "a b");
+        assert_eq!(escape_csv("", ','), "");
+    }
+
+    #[test]
+    fn escape_csv_should_borrow_text_that_needs_no_quotes() {
+        assert!(matches!(escape_csv("abc", ','), Cow::Borrowed(_)));
+        assert!(matches!(escape_markdown("abc"), Cow::Borrowed(_)));
+        assert!(matches!(normalize("a\nb"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn escape_markdown_should_escape_backslashes() {
+        assert_eq!(escape_markdown("a\\|b\nc"), "a\\\\\\|b<br>c");
+    }
+}
@@ -0,0 +1,528 @@
+use crate::{iter, Position, Table};
+use core::ops::Index;
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+use std::vec::Vec;
+
+/// Represents a view of a table that presents a subset of its columns in
+/// some order, without copying any of the table's cells
+///
+/// Column `i` of the view is the column at index `cols[i]` of the table, so
+/// columns can be reordered, left out, or repeated
+#[derive(Debug)]
+pub struct SelectedColumns<'a, D, T: Table<Data = D>, C: AsRef<[usize]>> {
+    table: &'a T,
+    cols: C,
+}
+
+impl<'a, D, T: Table<Data = D>, C: AsRef<[usize]>> SelectedColumns<'a, D, T, C> {
+    /// Creates a new view of the table presenting the columns at the given
+    /// indexes in the order provided
+    pub fn new(table: &'a T, cols: C) -> Self {
+        Self { table, cols }
+    }
+
+    /// Returns the indexes of the table's columns presented by the view
+    pub fn selected_columns(&self) -> &[usize] {
+        self.cols.as_ref()
+    }
+
+    /// Returns the index of the table's column that is presented as the
+    /// specified column of the view
+    pub fn source_column(&self, col: usize) -> Option<usize> {
+        self.cols.as_ref().get(col).copied()
+    }
+
+    /// Returns the total rows contained in the view
+    pub fn row_cnt(&self) -> usize {
+        self.table.row_cnt()
+    }
+
+    /// Returns the total columns contained in the view
+    pub fn col_cnt(&self) -> usize {
+        self.cols.as_ref().len()
+    }
+
+    /// Returns reference to the cell found at the specified row and column
+    /// of the view
+    pub fn cell(&self, row: usize, col: usize) -> Option<&'a D> {
+        let table = self.table;
+        table.cell(row, self.source_column(col)?)
+    }
+
+    /// Returns an iterator through the cells of a specific row of the view,
+    /// yielding `None` for any cell that is missing so that every item lines
+    /// up with its column
+    pub fn row(&self, row: usize) -> impl Iterator<Item = Option<&'a D>> + '_
+    where
+        D: 'a,
+    {
+        let table = self.table;
+        self.cols
+            .as_ref()
+            .iter()
+            .map(move |col| table.cell(row, *col))
+    }
+
+    /// Returns an iterator of refs through a specific column of the view
+    pub fn column(&self, col: usize) -> Option<iter::Column<'a, D, T>> {
+        let table = self.table;
+        self.source_column(col).map(|col| table.column(col))
+    }
+
+    /// Copies the cells of the view into a new table
+    pub fn to_table<U: Table<Data = D> + Default>(&self) -> U
+    where
+        D: Clone,
+    {
+        let mut new_table = U::default();
+
+        for row in 0..self.row_cnt() {
+            for col in 0..self.col_cnt() {
+                if let Some(x) = self.cell(row, col) {
+                    new_table.insert_cell(row, col, x.clone());
+                }
+            }
+        }
+
+        new_table.set_preferred_row_cnt(self.row_cnt());
+        new_table.set_preferred_col_cnt(self.col_cnt());
+        new_table
+    }
+}
+
+/// Represents a view of a table that hides every cell whose counterpart in a
+/// mask table is not `true`, without copying or modifying any of the table's
+/// cells
+///
+/// A cell is visible only if the mask has a cell at the same position and
+/// that cell is `true`, so a mask smaller than the table hides everything
+/// outside of it
+#[derive(Debug)]
+pub struct Masked<'a, D, T: Table<Data = D>, M: Table<Data = bool>> {
+    table: &'a T,
+    mask: &'a M,
+}
+
+impl<'a, D, T: Table<Data = D>, M: Table<Data = bool>> Masked<'a, D, T, M> {
+    /// Creates a new view of the table hiding cells based on the given mask
+    pub fn new(table: &'a T, mask: &'a M) -> Self {
+        Self { table, mask }
+    }
+
+    /// Returns the total rows contained in the view
+    pub fn row_cnt(&self) -> usize {
+        self.table.row_cnt()
+    }
+
+    /// Returns the total columns contained in the view
+    pub fn col_cnt(&self) -> usize {
+        self.table.col_cnt()
+    }
+
+    /// Returns true if the mask allows the cell at the specified row and
+    /// column to be seen
+    pub fn is_visible(&self, row: usize, col: usize) -> bool {
+        matches!(self.mask.cell(row, col), Some(true))
+    }
+
+    /// Returns reference to the cell found at the specified row and column
+    /// if it exists and is visible
+    pub fn cell(&self, row: usize, col: usize) -> Option<&'a D> {
+        let table = self.table;
+        if self.is_visible(row, col) {
+            table.cell(row, col)
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator through the cells of a specific row of the view,
+    /// yielding `None` for any cell that is missing or hidden so that every
+    /// item lines up with its column
+    pub fn row(&self, row: usize) -> impl Iterator<Item = Option<&'a D>> + '_
+    where
+        D: 'a,
+    {
+        (0..self.col_cnt()).map(move |col| self.cell(row, col))
+    }
+
+    /// Returns an iterator through the cells of a specific column of the
+    /// view, yielding `None` for any cell that is missing or hidden so that
+    /// every item lines up with its row
+    pub fn column(&self, col: usize) -> impl Iterator<Item = Option<&'a D>> + '_
+    where
+        D: 'a,
+    {
+        (0..self.row_cnt()).map(move |row| self.cell(row, col))
+    }
+
+    /// Returns an iterator through the indexes of rows that have at least
+    /// one visible cell
+    pub fn visible_rows(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.row_cnt()).filter(move |row| self.row(*row).any(|x| x.is_some()))
+    }
+
+    /// Returns an iterator through every visible cell (searching row by row)
+    /// alongside its position
+    pub fn cells(&self) -> impl Iterator<Item = (Position, &'a D)> + '_
+    where
+        D: 'a,
+    {
+        let col_cnt = self.col_cnt();
+        (0..self.row_cnt())
+            .flat_map(move |row| (0..col_cnt).map(move |col| Position { row, col }))
+            .filter_map(move |pos| self.cell(pos.row, pos.col).map(|x| (pos, x)))
+    }
+
+    /// Copies the visible cells of the view into a new table of the same
+    /// shape, leaving hidden cells missing
+    pub fn to_table<U: Table<Data = D> + Default>(&self) -> U
+    where
+        D: Clone,
+    {
+        let mut new_table = U::default();
+
+        for (pos, x) in self.cells() {
+            new_table.insert_cell(pos.row, pos.col, x.clone());
+        }
+
+        new_table.set_preferred_row_cnt(self.row_cnt());
+        new_table.set_preferred_col_cnt(self.col_cnt());
+        new_table
+    }
+}
+
+/// Represents a view of a table that presents only the rows satisfying a
+/// predicate, without copying any of the table's cells
+///
+/// The view only keeps the indexes of the matching rows, found when the view
+/// is created and kept until [`FilteredView::refresh`] is called, so row `i`
+/// of the view is the row at index `rows()[i]` of the table. As the view does
+/// not borrow the table, the table can be edited while the view exists, and
+/// the table is instead given to each method that reads through the view
+#[cfg_attr(feature = "docs", doc(cfg(any(alloc, std))))]
+#[cfg(any(feature = "alloc", feature = "std"))]
+pub struct FilteredView<P> {
+    predicate: P,
+    rows: Vec<usize>,
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<P> FilteredView<P> {
+    /// Creates a new view of the table presenting the rows that satisfy the
+    /// predicate, which is given every cell of a row including missing ones
+    pub fn new<T: Table>(table: &T, predicate: P) -> Self
+    where
+        P: FnMut(iter::RowComplete<'_, T::Data, T>) -> bool,
+    {
+        let mut view = Self {
+            predicate,
+            rows: Vec::new(),
+        };
+        view.refresh(table);
+        view
+    }
+
+    /// Finds the rows of the table that satisfy the predicate again, which
+    /// is needed after the table is edited or the predicate's own state
+    /// changes
+    pub fn refresh<T: Table>(&mut self, table: &T)
+    where
+        P: FnMut(iter::RowComplete<'_, T::Data, T>) -> bool,
+    {
+        trace_span!("filtered_view_refresh", rows = table.row_cnt());
+        let predicate = &mut self.predicate;
+        self.rows.clear();
+        self.rows
+            .extend((0..table.row_cnt()).filter(|row| predicate(table.row_complete(*row))));
+    }
+
+    /// Consumes the view, returning the predicate used to filter rows
+    pub fn into_predicate(self) -> P {
+        self.predicate
+    }
+
+    /// Returns the indexes of the table's rows presented by the view
+    pub fn rows(&self) -> &[usize] {
+        &self.rows
+    }
+
+    /// Returns the index of the table's row that is presented as the
+    /// specified row of the view
+    pub fn source_row(&self, row: usize) -> Option<usize> {
+        self.rows.get(row).copied()
+    }
+
+    /// Returns the total rows contained in the view
+    pub fn row_cnt(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns reference to the cell of the table found at the specified row
+    /// and column of the view
+    pub fn cell<'a, T: Table>(&self, table: &'a T, row: usize, col: usize) -> Option<&'a T::Data> {
+        table.cell(self.source_row(row)?, col)
+    }
+
+    /// Returns an iterator through the cells of a specific row of the view,
+    /// yielding `None` for any cell that is missing so that every item lines
+    /// up with its column
+    pub fn row<'a, T: Table>(
+        &'a self,
+        table: &'a T,
+        row: usize,
+    ) -> impl Iterator<Item = Option<&'a T::Data>> + 'a {
+        (0..table.col_cnt()).map(move |col| self.cell(table, row, col))
+    }
+
+    /// Returns an iterator through the cells of a specific column of the
+    /// view, yielding `None` for any cell that is missing so that every item
+    /// lines up with its row
+    pub fn column<'a, T: Table>(
+        &'a self,
+        table: &'a T,
+        col: usize,
+    ) -> impl Iterator<Item = Option<&'a T::Data>> + 'a {
+        self.rows.iter().map(move |row| table.cell(*row, col))
+    }
+
+    /// Copies the cells of the table presented by the view into a new table
+    pub fn to_table<T, U>(&self, table: &T) -> U
+    where
+        T: Table,
+        T::Data: Clone,
+        U: Table<Data = T::Data> + Default,
+    {
+        let mut new_table = U::default();
+
+        for row in 0..self.row_cnt() {
+            for col in 0..table.col_cnt() {
+                if let Some(x) = self.cell(table, row, col) {
+                    new_table.insert_cell(row, col, x.clone());
+                }
+            }
+        }
+
+        new_table.set_preferred_row_cnt(self.row_cnt());
+        new_table.set_preferred_col_cnt(table.col_cnt());
+        new_table
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std"))]
+impl<P> core::fmt::Debug for FilteredView<P> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("FilteredView")
+            .field("rows", &self.rows)
+            .finish()
+    }
+}
+
+/// Represents a view of a block of cells within a
+/// [`FixedTable`](crate::FixedTable) whose dimensions are known at compile
+/// time, without copying any of the table's cells
+///
+/// Each row of the view references `COL` contiguous cells of the table, so
+/// loops over the view's rows and columns have constant bounds
+#[derive(Debug)]
+pub struct FixedTableView<'a, T, const ROW: usize, const COL: usize> {
+    rows: [&'a [T; COL]; ROW],
+}
+
+impl<'a, T, const ROW: usize, const COL: usize> FixedTableView<'a, T, ROW, COL> {
+    /// Creates a new view from references to each of its rows
+    pub fn new(rows: [&'a [T; COL]; ROW]) -> Self {
+        Self { rows }
+    }
+
+    /// Returns the total rows contained in the view
+    pub fn row_cnt(&self) -> usize {
+        ROW
+    }
+
+    /// Returns the total columns contained in the view
+    pub fn col_cnt(&self) -> usize {
+        COL
+    }
+
+    /// Returns reference to the cell found at the specified row and column
+    /// of the view
+    pub fn cell(&self, row: usize, col: usize) -> Option<&'a T> {
+        self.row(row)?.get(col)
+    }
+
+    /// Returns the cells of a specific row of the view
+    pub fn row(&self, row: usize) -> Option<&'a [T; COL]> {
+        self.rows.get(row).copied()
+    }
+
+    /// Returns the cells of every row of the view
+    pub fn rows(&self) -> [&'a [T; COL]; ROW] {
+        self.rows
+    }
+}
+
+impl<'a, T, const ROW: usize, const COL: usize> Index<(usize, usize)>
+    for FixedTableView<'a, T, ROW, COL>
+{
+    type Output = T;
+
+    /// Indexes into the view by a specific row and column, panicking if
+    /// either is outside of the view
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        &self.rows[row][col]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FixedTable;
+
+    #[test]
+    fn cell_should_map_to_selected_column_of_table() {
+        let table = FixedTable::from([["a", "b", "c"], ["d", "e", "f"]]);
+        let view = SelectedColumns::new(&table, [2, 0]);
+
+        assert_eq!(view.row_cnt(), 2);
+        assert_eq!(view.col_cnt(), 2);
+        assert_eq!(view.cell(0, 0), Some(&"c"));
+        assert_eq!(view.cell(1, 1), Some(&"d"));
+        assert_eq!(view.cell(0, 2), None);
+    }
+
+    #[test]
+    fn row_should_yield_cells_in_selected_order() {
+        let table = FixedTable::from([["a", "b", "c"], ["d", "e", "f"]]);
+        let view = SelectedColumns::new(&table, [1, 1, 5]);
+
+        let mut row = view.row(1);
+        assert_eq!(row.next(), Some(Some(&"e")));
+        assert_eq!(row.next(), Some(Some(&"e")));
+        assert_eq!(row.next(), Some(None));
+        assert_eq!(row.next(), None);
+    }
+
+    #[test]
+    fn column_should_iterate_through_selected_column_of_table() {
+        let table = FixedTable::from([["a", "b", "c"], ["d", "e", "f"]]);
+        let view = SelectedColumns::new(&table, [2]);
+
+        let mut column = view.column(0).unwrap();
+        assert_eq!(column.next(), Some(&"c"));
+        assert_eq!(column.next(), Some(&"f"));
+        assert_eq!(column.next(), None);
+        assert!(view.column(1).is_none());
+    }
+
+    #[test]
+    fn to_table_should_copy_cells_of_view() {
+        let table = FixedTable::from([["a", "b", "c"], ["d", "e", "f"]]);
+        let view = SelectedColumns::new(&table, [2, 0]);
+
+        let new_table: FixedTable<&'static str, 2, 2> = view.to_table();
+        assert_eq!(new_table, [["c", "a"], ["f", "d"]]);
+    }
+
+    #[test]
+    fn masked_should_hide_cells_not_marked_true() {
+        let table = FixedTable::from([["a", "b", "c"], ["d", "e", "f"]]);
+        let mask = FixedTable::from([[true, false]]);
+        let view = Masked::new(&table, &mask);
+
+        assert_eq!(view.cell(0, 0), Some(&"a"));
+        assert_eq!(view.cell(0, 1), None);
+        assert_eq!(view.cell(0, 2), None);
+        assert_eq!(view.cell(1, 0), None);
+
+        let mut row = view.row(0);
+        assert_eq!(row.next(), Some(Some(&"a")));
+        assert_eq!(row.next(), Some(None));
+        assert_eq!(row.next(), Some(None));
+        assert_eq!(row.next(), None);
+    }
+
+    #[test]
+    fn masked_should_only_iterate_visible_cells_and_rows() {
+        let table = FixedTable::from([["a", "b", "c"], ["d", "e", "f"]]);
+        let mask = FixedTable::from([[false, false, false], [true, false, true]]);
+        let view = Masked::new(&table, &mask);
+
+        let mut cells = view.cells();
+        assert_eq!(cells.next(), Some((Position::new(1, 0), &"d")));
+        assert_eq!(cells.next(), Some((Position::new(1, 2), &"f")));
+        assert_eq!(cells.next(), None);
+
+        let mut rows = view.visible_rows();
+        assert_eq!(rows.next(), Some(1));
+        assert_eq!(rows.next(), None);
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[test]
+    fn filtered_view_should_present_only_matching_rows() {
+        let table = FixedTable::from([[1, 2], [3, 4], [5, 6]]);
+        let view = FilteredView::new(&table, |mut row| row.next() != Some(Some(&3)));
+
+        assert_eq!(view.rows(), &[0, 2]);
+        assert_eq!(view.row_cnt(), 2);
+        assert_eq!(view.cell(&table, 1, 0), Some(&5));
+        assert_eq!(view.cell(&table, 2, 0), None);
+        assert_eq!(
+            view.column(&table, 1).collect::<Vec<_>>(),
+            [Some(&2), Some(&6)]
+        );
+
+        let new_table: FixedTable<usize, 2, 2> = view.to_table(&table);
+        assert_eq!(new_table, [[1, 2], [5, 6]]);
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[test]
+    fn filtered_view_refresh_should_pick_up_edits_to_table() {
+        let mut table = crate::DynamicTable::from([[1], [2], [3]]);
+        let mut view = FilteredView::new(
+            &table,
+            |mut row| matches!(row.next(), Some(Some(x)) if *x >= 2),
+        );
+        assert_eq!(view.rows(), &[1, 2]);
+
+        table.insert_cell(0, 0, 5);
+        table.remove_row(2);
+        assert_eq!(view.rows(), &[1, 2]);
+        view.refresh(&table);
+        assert_eq!(view.rows(), &[0, 1]);
+        assert_eq!(view.row(&table, 0).collect::<Vec<_>>(), [Some(&5)]);
+    }
+
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[test]
+    fn filtered_view_refresh_should_use_latest_predicate_state() {
+        let table = FixedTable::from([[1], [2], [3]]);
+        let min = core::cell::Cell::new(2);
+        let mut view = FilteredView::new(
+            &table,
+            |mut row| matches!(row.next(), Some(Some(x)) if *x >= min.get()),
+        );
+        assert_eq!(view.rows(), &[1, 2]);
+
+        min.set(3);
+        assert_eq!(view.rows(), &[1, 2]);
+        view.refresh(&table);
+        assert_eq!(view.rows(), &[2]);
+    }
+
+    #[test]
+    fn fixed_table_view_should_index_into_block() {
+        let table = FixedTable::from([[1, 2, 3], [4, 5, 6]]);
+        let view = table.block::<2, 2>(0, 1).unwrap();
+
+        assert_eq!((view.row_cnt(), view.col_cnt()), (2, 2));
+        assert_eq!(view[(1, 0)], 5);
+        assert_eq!(view.cell(0, 1), Some(&3));
+        assert_eq!(view.cell(0, 2), None);
+        assert_eq!(view.row(1), Some(&[5, 6]));
+        assert_eq!(view.row(2), None);
+    }
+}
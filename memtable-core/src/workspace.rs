@@ -0,0 +1,330 @@
+use crate::Table;
+use core::{fmt, iter::FromIterator};
+use std::{string::String, vec::Vec};
+
+/// Represents the failure to rename a table within a [`Workspace`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum WorkspaceError {
+    /// No table exists with the name being renamed
+    MissingTable,
+
+    /// Another table already exists with the new name
+    NameTaken,
+}
+
+impl fmt::Display for WorkspaceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingTable => write!(f, "No table exists with that name"),
+            Self::NameTaken => write!(f, "Another table already has that name"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for WorkspaceError {}
+
+/// Represents a collection of named tables, much like the sheets of a
+/// workbook, that are kept in the order they were added
+///
+/// Names are unique within a workspace, and lookups by name search the
+/// tables in order, which suits the handful of tables a workspace holds.
+/// Deserializing a workspace fails if any name is used more than once
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-1", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde-1",
+    serde(
+        bound(
+            serialize = "T: serde::Serialize",
+            deserialize = "T: serde::Deserialize<'de>"
+        ),
+        try_from = "RawWorkspace<T>"
+    )
+)]
+pub struct Workspace<T> {
+    tables: Vec<(String, T)>,
+}
+
+impl<T> Default for Workspace<T> {
+    fn default() -> Self {
+        Self { tables: Vec::new() }
+    }
+}
+
+impl<T> Workspace<T> {
+    /// Creates a new, empty workspace
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the total tables contained in the workspace
+    pub fn len(&self) -> usize {
+        self.tables.len()
+    }
+
+    /// Returns true if the workspace contains no tables
+    pub fn is_empty(&self) -> bool {
+        self.tables.is_empty()
+    }
+
+    /// Returns true if the workspace contains a table with the given name
+    pub fn contains(&self, name: &str) -> bool {
+        self.index_of(name).is_some()
+    }
+
+    /// Adds the table to the end of the workspace under the given name,
+    /// returning the table it replaced if the name was already in use
+    ///
+    /// A replaced table keeps its place within the workspace
+    pub fn insert<S: Into<String>>(&mut self, name: S, table: T) -> Option<T> {
+        let name = name.into();
+        match self.index_of(&name) {
+            Some(idx) => Some(core::mem::replace(&mut self.tables[idx].1, table)),
+            None => {
+                self.tables.push((name, table));
+                None
+            }
+        }
+    }
+
+    /// Removes the table with the given name from the workspace, returning
+    /// it if it existed
+    pub fn remove(&mut self, name: &str) -> Option<T> {
+        let idx = self.index_of(name)?;
+        Some(self.tables.remove(idx).1)
+    }
+
+    /// Changes the name of a table without changing its place within the
+    /// workspace
+    ///
+    /// Renaming a table to its current name does nothing
+    pub fn rename<S: Into<String>>(&mut self, from: &str, to: S) -> Result<(), WorkspaceError> {
+        let to = to.into();
+        let idx = self.index_of(from).ok_or(WorkspaceError::MissingTable)?;
+        if from != to && self.contains(&to) {
+            return Err(WorkspaceError::NameTaken);
+        }
+
+        self.tables[idx].0 = to;
+        Ok(())
+    }
+
+    /// Returns a reference to the table with the given name
+    pub fn get(&self, name: &str) -> Option<&T> {
+        let idx = self.index_of(name)?;
+        Some(&self.tables[idx].1)
+    }
+
+    /// Returns a mutable reference to the table with the given name
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut T> {
+        let idx = self.index_of(name)?;
+        Some(&mut self.tables[idx].1)
+    }
+
+    /// Returns an iterator over the names of the tables in order
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.tables.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// Returns an iterator over the names and tables in order
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &T)> {
+        self.tables
+            .iter()
+            .map(|(name, table)| (name.as_str(), table))
+    }
+
+    /// Returns an iterator over the names and mutable tables in order
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&str, &mut T)> {
+        self.tables
+            .iter_mut()
+            .map(|(name, table)| (name.as_str(), table))
+    }
+
+    fn index_of(&self, name: &str) -> Option<usize> {
+        self.tables.iter().position(|(n, _)| n == name)
+    }
+}
+
+impl<T: Table> Workspace<T> {
+    /// Returns reference to the cell found at the specified row and column of
+    /// the table with the given name
+    pub fn cell(&self, name: &str, row: usize, col: usize) -> Option<&T::Data> {
+        self.get(name)?.cell(row, col)
+    }
+
+    /// Returns reference to the cell referenced by a spreadsheet-style
+    /// reference qualified with the name of its table, like `Sales!B7`
+    ///
+    /// Returns `None` if the reference is invalid, or if either the table or
+    /// the cell does not exist
+    ///
+    /// ### Examples
+    ///
+    /// ```
+    /// # use memtable_core::{prelude::*, workspace::Workspace};
+    /// let mut workspace = Workspace::new();
+    /// workspace.insert("Sales", DynamicTable::from([[1, 2], [3, 4]]));
+    ///
+    /// assert_eq!(workspace.cell_a1("Sales!B2"), Some(&4));
+    /// assert_eq!(workspace.cell_a1("Costs!B2"), None);
+    /// ```
+    #[cfg(feature = "a1")]
+    #[cfg_attr(feature = "docs", doc(cfg(a1)))]
+    pub fn cell_a1(&self, reference: &str) -> Option<&T::Data> {
+        // Split on the last '!' so that table names may contain one
+        let idx = reference.rfind('!')?;
+        let pos = crate::exts::a1::parse_a1(&reference[idx + 1..]).ok()?;
+        self.cell(&reference[..idx], pos.row, pos.col)
+    }
+}
+
+impl<S: Into<String>, T> FromIterator<(S, T)> for Workspace<T> {
+    /// Produces a workspace from the provided iterator of (name, table),
+    /// where later tables replace earlier ones with the same name
+    fn from_iter<I: IntoIterator<Item = (S, T)>>(iter: I) -> Self {
+        let mut workspace = Self::new();
+        for (name, table) in iter {
+            workspace.insert(name, table);
+        }
+        workspace
+    }
+}
+
+/// Unchecked form of a [`Workspace`] that is deserialized first so its
+/// names can be checked for duplicates
+#[cfg(feature = "serde-1")]
+#[derive(serde::Deserialize)]
+struct RawWorkspace<T> {
+    tables: Vec<(String, T)>,
+}
+
+#[cfg(feature = "serde-1")]
+impl<T> core::convert::TryFrom<RawWorkspace<T>> for Workspace<T> {
+    type Error = WorkspaceError;
+
+    fn try_from(raw: RawWorkspace<T>) -> Result<Self, Self::Error> {
+        let mut workspace = Self::new();
+        for (name, table) in raw.tables {
+            if workspace.contains(&name) {
+                return Err(WorkspaceError::NameTaken);
+            }
+            workspace.tables.push((name, table));
+        }
+        Ok(workspace)
+    }
+}
+
+impl<T> IntoIterator for Workspace<T> {
+    type Item = (String, T);
+    type IntoIter = std::vec::IntoIter<(String, T)>;
+
+    /// Converts into an iterator over the names and tables in order
+    fn into_iter(self) -> Self::IntoIter {
+        self.tables.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DynamicTable;
+    use std::{vec, vec::Vec};
+
+    #[test]
+    fn insert_should_replace_table_with_same_name_in_place() {
+        let mut workspace: Workspace<_> = vec![
+            ("Sales", DynamicTable::from([[1, 2], [3, 4]])),
+            ("Costs", DynamicTable::from([[5, 6]])),
+        ]
+        .into_iter()
+        .collect();
+        let old = workspace.insert("Sales", DynamicTable::from([[7]]));
+
+        assert_eq!(old, Some(DynamicTable::from([[1, 2], [3, 4]])));
+        assert_eq!(workspace.names().collect::<Vec<_>>(), ["Sales", "Costs"]);
+        assert_eq!(workspace.cell("Sales", 0, 0), Some(&7));
+    }
+
+    #[test]
+    fn remove_should_return_the_removed_table() {
+        let mut workspace: Workspace<_> = vec![
+            ("Sales", DynamicTable::from([[1, 2], [3, 4]])),
+            ("Costs", DynamicTable::from([[5, 6]])),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(
+            workspace.remove("Costs"),
+            Some(DynamicTable::from([[5, 6]]))
+        );
+        assert_eq!(workspace.remove("Costs"), None);
+        assert_eq!(workspace.len(), 1);
+    }
+
+    #[test]
+    fn rename_should_fail_if_name_is_missing_or_taken() {
+        let mut workspace: Workspace<_> = vec![
+            ("Sales", DynamicTable::from([[1, 2], [3, 4]])),
+            ("Costs", DynamicTable::from([[5, 6]])),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(
+            workspace.rename("Taxes", "Other"),
+            Err(WorkspaceError::MissingTable)
+        );
+        assert_eq!(
+            workspace.rename("Sales", "Costs"),
+            Err(WorkspaceError::NameTaken)
+        );
+        assert_eq!(workspace.rename("Sales", "Sales"), Ok(()));
+        assert_eq!(workspace.rename("Sales", "Revenue"), Ok(()));
+
+        assert_eq!(workspace.names().collect::<Vec<_>>(), ["Revenue", "Costs"]);
+        assert_eq!(workspace.cell("Revenue", 1, 1), Some(&4));
+        assert!(!workspace.contains("Sales"));
+    }
+
+    #[cfg(feature = "a1")]
+    #[test]
+    fn cell_a1_should_look_up_cell_in_named_table() {
+        let workspace: Workspace<_> = vec![
+            ("Sales", DynamicTable::from([[1, 2], [3, 4]])),
+            ("Costs", DynamicTable::from([[5, 6]])),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(workspace.cell_a1("Costs!B1"), Some(&6));
+        assert_eq!(workspace.cell_a1("Sales!A2"), Some(&3));
+        assert_eq!(workspace.cell_a1("Sales!C1"), None);
+        assert_eq!(workspace.cell_a1("B1"), None);
+    }
+
+    #[cfg(feature = "serde-1")]
+    #[test]
+    fn should_round_trip_through_serde() {
+        let workspace: Workspace<_> = vec![
+            ("Sales", DynamicTable::from([[1, 2], [3, 4]])),
+            ("Costs", DynamicTable::from([[5, 6]])),
+        ]
+        .into_iter()
+        .collect();
+        let json = serde_json::to_string(&workspace).unwrap();
+        let restored: Workspace<DynamicTable<usize>> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored, workspace);
+    }
+
+    #[cfg(feature = "serde-1")]
+    #[test]
+    fn deserialize_should_fail_if_name_is_repeated() {
+        let json = r#"{"tables":[["Sales",[[1]]],["Sales",[[2]]]]}"#;
+        let result: Result<Workspace<Vec<Vec<usize>>>, _> = serde_json::from_str(json);
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,587 @@
+use crate::{Capacity, DynamicTable, Position, Table};
+use core::fmt;
+use std::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+type Clock = Box<dyn Fn() -> u64>;
+type AuthorSource<A> = Box<dyn Fn() -> A>;
+
+/// Represents what was done to a cell of an [`AuditedTable`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AuditAction<T> {
+    /// Inserted `new` into the cell, replacing `old` if the cell existed
+    Insert {
+        /// Value of the cell before the insertion
+        old: Option<T>,
+        /// Value placed into the cell
+        new: T,
+    },
+
+    /// Handed out a mutable reference to the cell, which may have been used
+    /// to change it
+    Modify {
+        /// Value of the cell before the reference was handed out
+        old: T,
+    },
+
+    /// Removed the cell
+    Remove {
+        /// Value of the cell before the removal
+        old: T,
+    },
+
+    /// Inserted a row, shifting down all rows at and after it
+    InsertRow {
+        /// Cells placed into the row
+        cells: Vec<T>,
+    },
+
+    /// Removed a row, shifting up all rows after it
+    RemoveRow {
+        /// Cells of the row before the removal, where `None` is a missing cell
+        old: Vec<Option<T>>,
+    },
+
+    /// Inserted a column, shifting right all columns at and after it
+    InsertColumn {
+        /// Cells placed into the column
+        cells: Vec<T>,
+    },
+
+    /// Removed a column, shifting left all columns after it
+    RemoveColumn {
+        /// Cells of the column before the removal, where `None` is a missing
+        /// cell
+        old: Vec<Option<T>>,
+    },
+
+    /// Shifted all rows by an offset
+    ShiftRows {
+        /// Total rows shifted down, or up if negative
+        offset: isize,
+        /// Whether rows shifted past either end wrapped around
+        wrap: bool,
+    },
+
+    /// Shifted all columns by an offset
+    ShiftColumns {
+        /// Total columns shifted right, or left if negative
+        offset: isize,
+        /// Whether columns shifted past either end wrapped around
+        wrap: bool,
+    },
+}
+
+impl<T> AuditAction<T> {
+    /// Returns the name of the action
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Insert { .. } => "insert",
+            Self::Modify { .. } => "modify",
+            Self::Remove { .. } => "remove",
+            Self::InsertRow { .. } => "insert row",
+            Self::RemoveRow { .. } => "remove row",
+            Self::InsertColumn { .. } => "insert column",
+            Self::RemoveColumn { .. } => "remove column",
+            Self::ShiftRows { .. } => "shift rows",
+            Self::ShiftColumns { .. } => "shift columns",
+        }
+    }
+
+    /// Returns true if the action applied to a single cell rather than to a
+    /// whole row, column, or table
+    pub fn is_cell_action(&self) -> bool {
+        matches!(
+            self,
+            Self::Insert { .. } | Self::Modify { .. } | Self::Remove { .. }
+        )
+    }
+
+    /// Returns the value of the cell before the action, if it applied to a
+    /// single cell that had one
+    pub fn old(&self) -> Option<&T> {
+        match self {
+            Self::Insert { old, .. } => old.as_ref(),
+            Self::Modify { old } | Self::Remove { old } => Some(old),
+            _ => None,
+        }
+    }
+
+    /// Returns the value placed into the cell by the action, if it applied to
+    /// a single cell and the value is known
+    pub fn new_value(&self) -> Option<&T> {
+        match self {
+            Self::Insert { new, .. } => Some(new),
+            _ => None,
+        }
+    }
+}
+
+/// Represents a single mutation made to an [`AuditedTable`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AuditRecord<T, A> {
+    /// Position of the cell at the time of the mutation; for an action on a
+    /// row this is the first cell of the row, for an action on a column the
+    /// first cell of the column, and for a shift the first cell of the table
+    pub position: Position,
+
+    /// Time of the mutation as reported by the table's clock
+    pub time: u64,
+
+    /// Author of the mutation as reported by the table's author source
+    pub author: A,
+
+    /// What was done to the cell
+    pub action: AuditAction<T>,
+}
+
+/// Represents a table that wraps another table, recording who changed each
+/// cell, when, and what the change was
+///
+/// Times come from a clock and authors from an author source, both provided
+/// when the table is created, so any notion of time (e.g. seconds since the
+/// Unix epoch or a logical counter) and of author (e.g. the current user of
+/// a request) can be used.
+///
+/// Inserting, removing, and shifting rows and columns are each recorded as
+/// a single mutation, so methods like [`Table::push_row`] and
+/// [`Table::remove_column`] produce one record; other mutations, such as
+/// sorting rows, are captured as the cell insertions and removals they
+/// perform. As changes made through a mutable reference cannot be observed,
+/// [`Table::mut_cell`] is recorded as a possible modification alongside the
+/// value the cell had beforehand
+pub struct AuditedTable<T, U: Table<Data = T>, A = String> {
+    table: U,
+    clock: Clock,
+    author: AuthorSource<A>,
+    records: Vec<AuditRecord<T, A>>,
+}
+
+impl<T: Clone, U: Table<Data = T>, A> AuditedTable<T, U, A> {
+    /// Creates a new audited table wrapping the given table, using `clock` to
+    /// timestamp each mutation and `author` to determine who made it
+    pub fn new(
+        table: U,
+        clock: impl Fn() -> u64 + 'static,
+        author: impl Fn() -> A + 'static,
+    ) -> Self {
+        Self {
+            table,
+            clock: Box::new(clock),
+            author: Box::new(author),
+            records: Vec::new(),
+        }
+    }
+
+    /// Returns a reference to the underlying table
+    pub fn as_inner(&self) -> &U {
+        &self.table
+    }
+
+    /// Consumes the audited table, returning the underlying table
+    pub fn into_inner(self) -> U {
+        self.table
+    }
+
+    /// Returns true if any mutations have been recorded
+    pub fn has_records(&self) -> bool {
+        !self.records.is_empty()
+    }
+
+    /// Returns every mutation recorded so far, oldest first
+    pub fn records(&self) -> &[AuditRecord<T, A>] {
+        &self.records
+    }
+
+    /// Returns an iterator through the mutations recorded for the cell at the
+    /// specified row and column, oldest first, excluding those made to whole
+    /// rows and columns
+    pub fn records_for_cell(
+        &self,
+        row: usize,
+        col: usize,
+    ) -> impl Iterator<Item = &AuditRecord<T, A>> + '_ {
+        self.records
+            .iter()
+            .filter(move |x| x.action.is_cell_action() && x.position == Position { row, col })
+    }
+
+    /// Returns an iterator through the mutations recorded for any cell in the
+    /// specified row or for the row itself, oldest first
+    pub fn records_for_row(&self, row: usize) -> impl Iterator<Item = &AuditRecord<T, A>> + '_ {
+        self.records.iter().filter(move |x| {
+            x.position.row == row
+                && match x.action {
+                    AuditAction::InsertRow { .. } | AuditAction::RemoveRow { .. } => true,
+                    ref action => action.is_cell_action(),
+                }
+        })
+    }
+
+    /// Clears all recorded mutations
+    pub fn clear_records(&mut self) {
+        self.records.clear();
+    }
+
+    /// Takes all recorded mutations, leaving none behind
+    pub fn take_records(&mut self) -> Vec<AuditRecord<T, A>> {
+        core::mem::take(&mut self.records)
+    }
+
+    /// Produces a table of the recorded mutations, one row per mutation, with
+    /// columns for the time, author, action, row, column, old value, and new
+    /// value; a value that does not apply, including the values of actions
+    /// on whole rows and columns, is left empty
+    pub fn to_table(&self) -> DynamicTable<String>
+    where
+        T: fmt::Display,
+        A: fmt::Display,
+    {
+        let mut table = DynamicTable::new();
+
+        for x in self.records.iter() {
+            table.push_row(std::vec![
+                x.time.to_string(),
+                x.author.to_string(),
+                x.action.name().to_string(),
+                x.position.row.to_string(),
+                x.position.col.to_string(),
+                x.action.old().map(ToString::to_string).unwrap_or_default(),
+                x.action
+                    .new_value()
+                    .map(ToString::to_string)
+                    .unwrap_or_default(),
+            ]);
+        }
+
+        table
+    }
+
+    fn record(&mut self, row: usize, col: usize, action: AuditAction<T>) {
+        self.records.push(AuditRecord {
+            position: Position { row, col },
+            time: (self.clock)(),
+            author: (self.author)(),
+            action,
+        });
+    }
+}
+
+impl<T, U: Table<Data = T> + fmt::Debug, A: fmt::Debug> fmt::Debug for AuditedTable<T, U, A>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AuditedTable")
+            .field("table", &self.table)
+            .field("records", &self.records)
+            .finish()
+    }
+}
+
+impl<T: Clone, U: Table<Data = T>, A> Table for AuditedTable<T, U, A> {
+    type Data = T;
+    type Row = U::Row;
+    type Column = U::Column;
+
+    fn max_row_capacity(&self) -> Capacity {
+        self.table.max_row_capacity()
+    }
+
+    fn max_column_capacity(&self) -> Capacity {
+        self.table.max_column_capacity()
+    }
+
+    fn row_cnt(&self) -> usize {
+        self.table.row_cnt()
+    }
+
+    fn col_cnt(&self) -> usize {
+        self.table.col_cnt()
+    }
+
+    fn set_preferred_row_cnt(&mut self, cnt: usize) {
+        self.table.set_preferred_row_cnt(cnt)
+    }
+
+    fn set_preferred_col_cnt(&mut self, cnt: usize) {
+        self.table.set_preferred_col_cnt(cnt)
+    }
+
+    fn truncate(&mut self) {
+        self.table.truncate()
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.table.shrink_to_fit()
+    }
+
+    fn cell(&self, row: usize, col: usize) -> Option<&Self::Data> {
+        self.table.cell(row, col)
+    }
+
+    /// Records a possible modification of the cell if it exists
+    fn mut_cell(&mut self, row: usize, col: usize) -> Option<&mut Self::Data> {
+        if let Some(old) = self.table.cell(row, col).cloned() {
+            self.record(row, col, AuditAction::Modify { old });
+        }
+
+        self.table.mut_cell(row, col)
+    }
+
+    fn insert_cell(&mut self, row: usize, col: usize, value: Self::Data) -> Option<Self::Data> {
+        let new = value.clone();
+        let old = self.table.insert_cell(row, col, value);
+        self.record(
+            row,
+            col,
+            AuditAction::Insert {
+                old: old.clone(),
+                new,
+            },
+        );
+        old
+    }
+
+    fn remove_cell(&mut self, row: usize, col: usize) -> Option<Self::Data> {
+        let old = self.table.remove_cell(row, col);
+        if let Some(x) = old.as_ref() {
+            self.record(row, col, AuditAction::Remove { old: x.clone() });
+        }
+        old
+    }
+
+    /// Records the row as a single insertion
+    fn insert_row<I: IntoIterator<Item = Self::Data>>(&mut self, row: usize, cells: I) {
+        let cells: Vec<T> = cells.into_iter().collect();
+        self.table.insert_row(row, cells.iter().cloned());
+        self.record(row, 0, AuditAction::InsertRow { cells });
+    }
+
+    /// Records each row as a single insertion
+    fn insert_rows<I, R>(&mut self, row: usize, rows: I)
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = Self::Data>,
+    {
+        for (i, cells) in rows.into_iter().enumerate() {
+            self.insert_row(row + i, cells);
+        }
+    }
+
+    /// Records the row as a single removal if it existed
+    fn remove_row(&mut self, row: usize) -> Option<Self::Row> {
+        let old: Vec<Option<T>> = (0..self.table.col_cnt())
+            .map(|col| self.table.cell(row, col).cloned())
+            .collect();
+        let removed = self.table.remove_row(row);
+        if removed.is_some() {
+            self.record(row, 0, AuditAction::RemoveRow { old });
+        }
+        removed
+    }
+
+    /// Records the column as a single insertion
+    fn insert_column<I: IntoIterator<Item = Self::Data>>(&mut self, col: usize, cells: I) {
+        let cells: Vec<T> = cells.into_iter().collect();
+        self.table.insert_column(col, cells.iter().cloned());
+        self.record(0, col, AuditAction::InsertColumn { cells });
+    }
+
+    /// Records each column as a single insertion
+    fn insert_columns<I, C>(&mut self, col: usize, cols: I)
+    where
+        I: IntoIterator<Item = C>,
+        C: IntoIterator<Item = Self::Data>,
+    {
+        for (i, cells) in cols.into_iter().enumerate() {
+            self.insert_column(col + i, cells);
+        }
+    }
+
+    /// Records the column as a single removal if it existed
+    fn remove_column(&mut self, col: usize) -> Option<Self::Column> {
+        let old: Vec<Option<T>> = (0..self.table.row_cnt())
+            .map(|row| self.table.cell(row, col).cloned())
+            .collect();
+        let removed = self.table.remove_column(col);
+        if removed.is_some() {
+            self.record(0, col, AuditAction::RemoveColumn { old });
+        }
+        removed
+    }
+
+    /// Records the shift as a single mutation
+    fn shift_rows(&mut self, offset: isize, wrap: bool) {
+        self.table.shift_rows(offset, wrap);
+        self.record(0, 0, AuditAction::ShiftRows { offset, wrap });
+    }
+
+    /// Records the shift as a single mutation
+    fn shift_columns(&mut self, offset: isize, wrap: bool) {
+        self.table.shift_columns(offset, wrap);
+        self.record(0, 0, AuditAction::ShiftColumns { offset, wrap });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::Cell;
+    use std::{rc::Rc, vec};
+
+    #[test]
+    fn insert_cell_should_record_old_and_new_values() {
+        let time = Rc::new(Cell::new(0));
+        let mut table = AuditedTable::new(
+            DynamicTable::new(),
+            move || {
+                time.set(time.get() + 1);
+                time.get()
+            },
+            || "alice".to_string(),
+        );
+        table.insert_cell(0, 0, 1);
+        table.insert_cell(0, 0, 2);
+
+        assert_eq!(
+            table.records(),
+            &[
+                AuditRecord {
+                    position: Position { row: 0, col: 0 },
+                    time: 1,
+                    author: "alice".to_string(),
+                    action: AuditAction::Insert { old: None, new: 1 },
+                },
+                AuditRecord {
+                    position: Position { row: 0, col: 0 },
+                    time: 2,
+                    author: "alice".to_string(),
+                    action: AuditAction::Insert {
+                        old: Some(1),
+                        new: 2
+                    },
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn remove_cell_should_only_record_if_cell_existed() {
+        let mut table = AuditedTable::new(DynamicTable::new(), || 0, || "alice");
+        table.remove_cell(0, 0);
+        assert!(!table.has_records());
+
+        table.insert_cell(0, 0, 5);
+        table.remove_cell(0, 0);
+        assert_eq!(table.records()[1].action, AuditAction::Remove { old: 5 });
+    }
+
+    #[test]
+    fn mut_cell_should_record_possible_modification() {
+        let mut table = AuditedTable::new(DynamicTable::new(), || 0, || "alice");
+        table.insert_cell(0, 0, 5);
+        *table.mut_cell(0, 0).unwrap() = 6;
+
+        assert_eq!(table.records()[1].action, AuditAction::Modify { old: 5 });
+        assert_eq!(table.cell(0, 0), Some(&6));
+    }
+
+    #[test]
+    fn records_should_be_queryable_by_cell_and_row() {
+        let mut table = AuditedTable::new(DynamicTable::new(), || 0, || "alice");
+        table.push_row(vec![1, 2]);
+        table.push_row(vec![3, 4]);
+        table.insert_cell(1, 1, 40);
+        table.insert_cell(1, 1, 41);
+
+        assert_eq!(table.records_for_cell(1, 1).count(), 2);
+        assert_eq!(table.records_for_cell(1, 0).count(), 0);
+        assert_eq!(table.records_for_row(0).count(), 1);
+        assert_eq!(table.records_for_row(1).count(), 3);
+    }
+
+    #[test]
+    fn structural_changes_should_be_recorded_as_single_entries() {
+        let mut table = AuditedTable::new(DynamicTable::new(), || 0, || "alice");
+        table.push_row(vec![1, 2]);
+        table.push_row(vec![3, 4]);
+        table.push_column(vec![5, 6]);
+        table.remove_cell(0, 1);
+        table.shift_rows(1, true);
+        table.remove_row(1);
+        table.remove_column(0);
+        table.remove_row(5);
+
+        let actions: Vec<_> = table.records().iter().map(|x| &x.action).collect();
+        assert_eq!(
+            actions,
+            [
+                &AuditAction::InsertRow { cells: vec![1, 2] },
+                &AuditAction::InsertRow { cells: vec![3, 4] },
+                &AuditAction::InsertColumn { cells: vec![5, 6] },
+                &AuditAction::Remove { old: 2 },
+                &AuditAction::ShiftRows {
+                    offset: 1,
+                    wrap: true
+                },
+                &AuditAction::RemoveRow {
+                    old: vec![Some(1), None, Some(5)]
+                },
+                &AuditAction::RemoveColumn { old: vec![Some(3)] },
+            ]
+        );
+        assert_eq!(table.records()[2].position, Position::new(0, 2));
+        assert_eq!(table.records()[5].position, Position::new(1, 0));
+        assert_eq!(table.as_inner(), &[[4, 6]]);
+    }
+
+    #[test]
+    fn to_table_should_produce_row_per_record() {
+        let time = Rc::new(Cell::new(0));
+        let mut table = AuditedTable::new(
+            DynamicTable::new(),
+            move || {
+                time.set(time.get() + 1);
+                time.get()
+            },
+            || "alice".to_string(),
+        );
+        table.insert_cell(0, 1, 5);
+        table.remove_cell(0, 1);
+        table.push_row(vec![7, 8]);
+
+        let records = table.to_table();
+        assert_eq!(records.row_cnt(), 3);
+        assert_eq!(
+            records.row(0).map(String::as_str).collect::<Vec<_>>(),
+            ["1", "alice", "insert", "0", "1", "", "5"]
+        );
+        assert_eq!(
+            records.row(1).map(String::as_str).collect::<Vec<_>>(),
+            ["2", "alice", "remove", "0", "1", "5", ""]
+        );
+        assert_eq!(
+            records.row(2).map(String::as_str).collect::<Vec<_>>(),
+            ["3", "alice", "insert row", "1", "0", "", ""]
+        );
+    }
+
+    #[test]
+    fn truncate_should_drop_cells_of_inner_table_outside_of_counts() {
+        let mut table = AuditedTable::new(
+            DynamicTable::from([[1, 2], [3, 4]]),
+            || 0,
+            || "alice".to_string(),
+        );
+        table.set_preferred_row_cnt(1);
+        table.truncate();
+        table.shrink_to_fit();
+
+        assert_eq!(table.as_inner(), &[[1, 2]]);
+        assert!(!table.has_records());
+    }
+}
@@ -0,0 +1,517 @@
+use crate::Table;
+use core::cmp;
+use std::{collections::BTreeMap, vec::Vec};
+
+/// Represents a way of compressing the cells of a column, one chunk of rows
+/// at a time
+///
+/// Implement this to plug in a general-purpose compressor (such as zstd)
+/// by encoding each chunk into bytes; [`RunLengthCodec`] is provided for
+/// columns whose values repeat across neighboring rows
+pub trait ColumnCodec<T> {
+    /// Compressed form of a chunk of cells
+    type Chunk;
+
+    /// Compresses a chunk of cells, where missing cells are `None`
+    fn compress(&self, cells: &[Option<T>]) -> Self::Chunk;
+
+    /// Decompresses a chunk back into the exact cells it was compressed from
+    fn decompress(&self, chunk: &Self::Chunk) -> Vec<Option<T>>;
+}
+
+/// Represents a codec that collapses consecutive equal cells of a column
+/// into a single value and the total times it repeats
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RunLengthCodec;
+
+impl<T: Clone + PartialEq> ColumnCodec<T> for RunLengthCodec {
+    type Chunk = Vec<(Option<T>, usize)>;
+
+    fn compress(&self, cells: &[Option<T>]) -> Self::Chunk {
+        let mut runs: Self::Chunk = Vec::new();
+
+        for cell in cells {
+            match runs.last_mut() {
+                Some((value, cnt)) if value == cell => *cnt += 1,
+                _ => runs.push((cell.clone(), 1)),
+            }
+        }
+
+        runs
+    }
+
+    fn decompress(&self, chunk: &Self::Chunk) -> Vec<Option<T>> {
+        chunk
+            .iter()
+            .flat_map(|(value, cnt)| (0..*cnt).map(move |_| value.clone()))
+            .collect()
+    }
+}
+
+/// Represents a chunk of a compressed column that has been decompressed
+#[derive(Clone, Debug)]
+struct CachedChunk<T> {
+    col: usize,
+    idx: usize,
+    cells: Vec<Option<T>>,
+    is_dirty: bool,
+}
+
+/// Represents a table that wraps another table, keeping selected columns
+/// compressed in chunks of rows and only decompressing a chunk when one of
+/// its cells is accessed
+///
+/// The most recently accessed chunks are kept decompressed in a small cache,
+/// and changes to them are compressed again once they are evicted or the
+/// table is flushed. Because reading a compressed cell may need to update
+/// the cache, accessing cells requires a mutable reference, which is why a
+/// compressed table does not implement [`Table`]
+#[derive(Clone, Debug)]
+pub struct CompressedTable<T, U: Table<Data = T>, C: ColumnCodec<T>> {
+    table: U,
+    codec: C,
+    chunk_size: usize,
+    cache_capacity: usize,
+    columns: BTreeMap<usize, Vec<C::Chunk>>,
+    cache: Vec<CachedChunk<T>>,
+    compressed_row_cnt: usize,
+}
+
+impl<T, U: Table<Data = T>, C: ColumnCodec<T>> CompressedTable<T, U, C> {
+    /// Default total rows compressed together as a chunk
+    pub const DEFAULT_CHUNK_SIZE: usize = 1024;
+
+    /// Default total chunks kept decompressed at a time
+    pub const DEFAULT_CACHE_CAPACITY: usize = 4;
+
+    /// Creates a new compressed table wrapping the given table, where no
+    /// column is compressed until [`CompressedTable::compress_column`] is
+    /// called
+    pub fn new(table: U, codec: C) -> Self {
+        Self {
+            table,
+            codec,
+            chunk_size: Self::DEFAULT_CHUNK_SIZE,
+            cache_capacity: Self::DEFAULT_CACHE_CAPACITY,
+            columns: BTreeMap::new(),
+            cache: Vec::new(),
+            compressed_row_cnt: 0,
+        }
+    }
+
+    /// Sets the total rows compressed together as a chunk, which is at
+    /// least one, recompressing any columns that are already compressed
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        let cols: Vec<usize> = self.compressed_columns().collect();
+        for col in cols.iter().copied() {
+            self.decompress_column(col);
+        }
+
+        self.chunk_size = cmp::max(chunk_size, 1);
+
+        for col in cols {
+            self.compress_column(col);
+        }
+        self
+    }
+
+    /// Sets the total chunks kept decompressed at a time, which is at least
+    /// one
+    pub fn with_cache_capacity(mut self, cache_capacity: usize) -> Self {
+        self.cache_capacity = cmp::max(cache_capacity, 1);
+        self.evict_excess();
+        self
+    }
+
+    /// Returns a reference to the underlying table, which does not contain
+    /// the cells of compressed columns
+    pub fn as_inner(&self) -> &U {
+        &self.table
+    }
+
+    /// Consumes the compressed table, decompressing every column back into
+    /// the underlying table and returning it
+    pub fn into_inner(mut self) -> U {
+        let cols: Vec<usize> = self.compressed_columns().collect();
+        for col in cols {
+            self.decompress_column(col);
+        }
+        self.table
+    }
+
+    /// Returns the total rows contained in the table
+    pub fn row_cnt(&self) -> usize {
+        cmp::max(self.table.row_cnt(), self.compressed_row_cnt)
+    }
+
+    /// Returns the total columns contained in the table
+    pub fn col_cnt(&self) -> usize {
+        let compressed_col_cnt = self.columns.keys().last().map_or(0, |col| col + 1);
+        cmp::max(self.table.col_cnt(), compressed_col_cnt)
+    }
+
+    /// Returns true if the column at the specified index is compressed
+    pub fn is_compressed(&self, col: usize) -> bool {
+        self.columns.contains_key(&col)
+    }
+
+    /// Returns an iterator through the indexes of compressed columns in
+    /// ascending order
+    pub fn compressed_columns(&self) -> impl Iterator<Item = usize> + '_ {
+        self.columns.keys().copied()
+    }
+
+    /// Returns the compressed chunks of the column at the specified index,
+    /// or `None` if the column is not compressed
+    ///
+    /// Changes to cached chunks are not reflected until they are evicted or
+    /// the table is flushed
+    pub fn chunks(&self, col: usize) -> Option<&[C::Chunk]> {
+        self.columns.get(&col).map(Vec::as_slice)
+    }
+
+    /// Moves the cells of the column at the specified index out of the
+    /// underlying table and into compressed chunks, returning false if the
+    /// column was already compressed
+    pub fn compress_column(&mut self, col: usize) -> bool {
+        if self.is_compressed(col) {
+            return false;
+        }
+
+        // Every chunk holds the same total rows, so the last chunk is padded
+        // with missing cells
+        let row_cnt = self.table.row_cnt();
+        let padding = (self.chunk_size - row_cnt % self.chunk_size) % self.chunk_size;
+        let padded_row_cnt = row_cnt + padding;
+        let cells: Vec<Option<T>> = (0..padded_row_cnt)
+            .map(|row| {
+                if row < row_cnt {
+                    self.table.remove_cell(row, col)
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        let chunks = cells
+            .chunks(self.chunk_size)
+            .map(|cells| self.codec.compress(cells))
+            .collect();
+
+        self.columns.insert(col, chunks);
+        self.compressed_row_cnt = cmp::max(self.compressed_row_cnt, row_cnt);
+        true
+    }
+
+    /// Moves the cells of the compressed column at the specified index back
+    /// into the underlying table, returning false if the column was not
+    /// compressed
+    pub fn decompress_column(&mut self, col: usize) -> bool {
+        let chunks = match self.columns.remove(&col) {
+            Some(chunks) => chunks,
+            None => return false,
+        };
+
+        // Cached chunks may hold changes that were never compressed, so they
+        // take priority over the compressed chunks
+        let mut cached = Vec::new();
+        let mut i = 0;
+        while i < self.cache.len() {
+            if self.cache[i].col == col {
+                cached.push(self.cache.remove(i));
+            } else {
+                i += 1;
+            }
+        }
+
+        let chunk_cnt = cached
+            .iter()
+            .map(|x| x.idx + 1)
+            .fold(chunks.len(), cmp::max);
+        for idx in 0..chunk_cnt {
+            let cells = match cached.iter().position(|x| x.idx == idx) {
+                Some(pos) => cached.swap_remove(pos).cells,
+                None => match chunks.get(idx) {
+                    Some(chunk) => self.codec.decompress(chunk),
+                    None => continue,
+                },
+            };
+
+            for (i, cell) in cells.into_iter().enumerate() {
+                if let Some(value) = cell {
+                    self.table
+                        .insert_cell(idx * self.chunk_size + i, col, value);
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Returns reference to the cell found at the specified row and column,
+    /// decompressing its chunk if the column is compressed
+    pub fn cell(&mut self, row: usize, col: usize) -> Option<&T> {
+        if !self.is_compressed(col) {
+            return self.table.cell(row, col);
+        }
+
+        if row >= self.compressed_row_cnt {
+            return None;
+        }
+
+        let chunk_size = self.chunk_size;
+        self.load(row / chunk_size, col).cells[row % chunk_size].as_ref()
+    }
+
+    /// Returns mutable reference to the cell found at the specified row and
+    /// column, decompressing its chunk if the column is compressed
+    pub fn mut_cell(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if !self.is_compressed(col) {
+            return self.table.mut_cell(row, col);
+        }
+
+        if row >= self.compressed_row_cnt {
+            return None;
+        }
+
+        let chunk_size = self.chunk_size;
+        let chunk = self.load(row / chunk_size, col);
+        chunk.is_dirty = true;
+        chunk.cells[row % chunk_size].as_mut()
+    }
+
+    /// Replaces the given value into the cell of the table at the specified
+    /// row and column, returning the previous value contained in the cell
+    pub fn insert_cell(&mut self, row: usize, col: usize, value: T) -> Option<T> {
+        if !self.is_compressed(col) {
+            return self.table.insert_cell(row, col, value);
+        }
+
+        self.compressed_row_cnt = cmp::max(self.compressed_row_cnt, row + 1);
+
+        let chunk_size = self.chunk_size;
+        let chunk = self.load(row / chunk_size, col);
+        chunk.is_dirty = true;
+        chunk.cells[row % chunk_size].replace(value)
+    }
+
+    /// Removes the given value from the cell at the specified position,
+    /// returning the value if it existed
+    pub fn remove_cell(&mut self, row: usize, col: usize) -> Option<T> {
+        if !self.is_compressed(col) {
+            return self.table.remove_cell(row, col);
+        }
+
+        if row >= self.compressed_row_cnt {
+            return None;
+        }
+
+        let chunk_size = self.chunk_size;
+        let chunk = self.load(row / chunk_size, col);
+        let value = chunk.cells[row % chunk_size].take();
+        chunk.is_dirty |= value.is_some();
+        value
+    }
+
+    /// Compresses every cached chunk that has changed since it was
+    /// decompressed, keeping the chunks cached
+    pub fn flush(&mut self) {
+        let Self {
+            codec,
+            columns,
+            cache,
+            ..
+        } = self;
+
+        for chunk in cache.iter_mut().filter(|x| x.is_dirty) {
+            store(codec, columns, chunk);
+            chunk.is_dirty = false;
+        }
+    }
+
+    /// Returns the cached chunk at the specified index of a compressed
+    /// column, decompressing it into the cache if needed and marking it as
+    /// the most recently used
+    fn load(&mut self, idx: usize, col: usize) -> &mut CachedChunk<T> {
+        match self.cache.iter().position(|x| x.col == col && x.idx == idx) {
+            Some(pos) => {
+                let chunk = self.cache.remove(pos);
+                self.cache.insert(0, chunk);
+            }
+            None => {
+                let cells = match self.columns.get(&col).and_then(|x| x.get(idx)) {
+                    Some(chunk) => self.codec.decompress(chunk),
+                    None => (0..self.chunk_size).map(|_| None).collect(),
+                };
+
+                self.cache.insert(
+                    0,
+                    CachedChunk {
+                        col,
+                        idx,
+                        cells,
+                        is_dirty: false,
+                    },
+                );
+                self.evict_excess();
+            }
+        }
+
+        &mut self.cache[0]
+    }
+
+    /// Removes the least recently used chunks beyond the cache's capacity,
+    /// compressing any that have changed
+    fn evict_excess(&mut self) {
+        while self.cache.len() > self.cache_capacity {
+            if let Some(chunk) = self.cache.pop() {
+                if chunk.is_dirty {
+                    store(&self.codec, &mut self.columns, &chunk);
+                }
+            }
+        }
+    }
+}
+
+/// Compresses the cells of a cached chunk, replacing the column's compressed
+/// chunk at the same index and filling in any chunks before it
+fn store<T, C: ColumnCodec<T>>(
+    codec: &C,
+    columns: &mut BTreeMap<usize, Vec<C::Chunk>>,
+    chunk: &CachedChunk<T>,
+) {
+    let chunks = columns
+        .get_mut(&chunk.col)
+        .expect("BUG: Cached chunk of column that is not compressed!");
+
+    while chunks.len() <= chunk.idx {
+        let cells: Vec<Option<T>> = (0..chunk.cells.len()).map(|_| None).collect();
+        chunks.push(codec.compress(&cells));
+    }
+
+    chunks[chunk.idx] = codec.compress(&chunk.cells);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DynamicTable;
+    use std::vec;
+
+    #[test]
+    fn run_length_codec_should_round_trip_cells() {
+        let cells = vec![Some(1), Some(1), None, Some(2), Some(2), Some(2)];
+        let chunk = RunLengthCodec.compress(&cells);
+        assert_eq!(chunk, vec![(Some(1), 2), (None, 1), (Some(2), 3)]);
+        assert_eq!(RunLengthCodec.decompress(&chunk), cells);
+    }
+
+    #[test]
+    fn compress_column_should_move_cells_out_of_underlying_table() {
+        let table = DynamicTable::from([[0, 10], [1, 10], [2, 10], [3, 20], [4, 20]]);
+        let mut table = CompressedTable::new(table, RunLengthCodec).with_chunk_size(2);
+        assert!(table.compress_column(1));
+        assert!(!table.compress_column(1));
+        assert!(table.is_compressed(1));
+
+        assert_eq!(table.as_inner().cell(0, 1), None);
+        assert_eq!(table.row_cnt(), 5);
+        assert_eq!(table.col_cnt(), 2);
+        assert_eq!(table.compressed_columns().collect::<Vec<_>>(), vec![1]);
+        assert_eq!(
+            table.chunks(1),
+            Some(
+                &[
+                    vec![(Some(10), 2)],
+                    vec![(Some(10), 1), (Some(20), 1)],
+                    vec![(Some(20), 1), (None, 1)],
+                ][..]
+            )
+        );
+        assert_eq!(table.chunks(0), None);
+    }
+
+    #[test]
+    fn cell_should_decompress_chunk_of_compressed_column() {
+        let table = DynamicTable::from([[0, 10], [1, 10], [2, 10], [3, 20], [4, 20]]);
+        let mut table = CompressedTable::new(table, RunLengthCodec)
+            .with_chunk_size(2)
+            .with_cache_capacity(1);
+        table.compress_column(1);
+
+        assert_eq!(table.cell(0, 0), Some(&0));
+        assert_eq!(table.cell(2, 1), Some(&10));
+        assert_eq!(table.cell(4, 1), Some(&20));
+        assert_eq!(table.cell(5, 1), None);
+    }
+
+    #[test]
+    fn changes_should_be_kept_when_chunks_are_evicted() {
+        let table = DynamicTable::from([[0, 10], [1, 10], [2, 10], [3, 20], [4, 20]]);
+        let mut table = CompressedTable::new(table, RunLengthCodec)
+            .with_chunk_size(2)
+            .with_cache_capacity(1);
+        table.compress_column(1);
+
+        assert_eq!(table.insert_cell(0, 1, 99), Some(10));
+        *table.mut_cell(3, 1).unwrap() = 98;
+        assert_eq!(table.remove_cell(4, 1), Some(20));
+        assert_eq!(table.insert_cell(6, 1, 97), None);
+
+        assert_eq!(table.cell(0, 1), Some(&99));
+        assert_eq!(table.cell(3, 1), Some(&98));
+        assert_eq!(table.cell(4, 1), None);
+        assert_eq!(table.cell(6, 1), Some(&97));
+        assert_eq!(table.row_cnt(), 7);
+    }
+
+    #[test]
+    fn flush_should_compress_changed_chunks() {
+        let table = DynamicTable::from([[0, 10], [1, 10], [2, 10]]);
+        let mut table = CompressedTable::new(table, RunLengthCodec)
+            .with_chunk_size(2)
+            .with_cache_capacity(4);
+        table.compress_column(1);
+        table.insert_cell(1, 1, 11);
+        assert_eq!(table.chunks(1).unwrap()[0], vec![(Some(10), 2)]);
+
+        table.flush();
+        assert_eq!(
+            table.chunks(1).unwrap()[0],
+            vec![(Some(10), 1), (Some(11), 1)]
+        );
+        assert_eq!(table.cell(1, 1), Some(&11));
+    }
+
+    #[test]
+    fn into_inner_should_decompress_every_column() {
+        let table = DynamicTable::from([[0, 10], [1, 10], [2, 10], [3, 20], [4, 20]]);
+        let mut table = CompressedTable::new(table, RunLengthCodec)
+            .with_chunk_size(2)
+            .with_cache_capacity(1);
+        table.compress_column(0);
+        table.compress_column(1);
+        table.insert_cell(2, 0, 22);
+
+        let table = table.into_inner();
+        assert_eq!(
+            table,
+            DynamicTable::from([[0, 10], [1, 10], [22, 10], [3, 20], [4, 20]])
+        );
+    }
+
+    #[test]
+    fn with_chunk_size_should_recompress_compressed_columns() {
+        let table = DynamicTable::from([[0, 10], [1, 10], [2, 10], [3, 20], [4, 20]]);
+        let mut table = CompressedTable::new(table, RunLengthCodec)
+            .with_chunk_size(2)
+            .with_cache_capacity(1);
+        table.compress_column(1);
+        table.insert_cell(0, 1, 5);
+
+        let mut table = table.with_chunk_size(4);
+        assert_eq!(table.chunks(1).map(<[_]>::len), Some(2));
+        assert_eq!(table.cell(0, 1), Some(&5));
+        assert_eq!(table.cell(4, 1), Some(&20));
+    }
+}
@@ -0,0 +1,193 @@
+use crate::{iter, Capacity, Position, Table};
+use core::{marker::PhantomData, ops::Index};
+
+/// Represents a table that wraps another table, exposing only the methods
+/// that read from it
+///
+/// Unlike the tables it wraps, a frozen table does not implement [`Table`],
+/// so there is no way to insert, remove, or mutate cells through it. Hand
+/// one to code that must not modify the table; only the owner can get back
+/// the underlying table using [`FrozenTable::into_inner`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FrozenTable<T, U: Table<Data = T>> {
+    table: U,
+    _data: PhantomData<T>,
+}
+
+impl<T, U: Table<Data = T>> FrozenTable<T, U> {
+    /// Creates a new frozen table wrapping the given table
+    pub fn new(table: U) -> Self {
+        Self {
+            table,
+            _data: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the underlying table
+    pub fn as_inner(&self) -> &U {
+        &self.table
+    }
+
+    /// Consumes the frozen table, returning the underlying table
+    pub fn into_inner(self) -> U {
+        self.table
+    }
+
+    /// Returns the maximum rows the underlying table can hold
+    pub fn max_row_capacity(&self) -> Capacity {
+        self.table.max_row_capacity()
+    }
+
+    /// Returns the maximum columns the underlying table can hold
+    pub fn max_column_capacity(&self) -> Capacity {
+        self.table.max_column_capacity()
+    }
+
+    /// Returns the total rows contained in the table
+    pub fn row_cnt(&self) -> usize {
+        self.table.row_cnt()
+    }
+
+    /// Returns the total columns contained in the table
+    pub fn col_cnt(&self) -> usize {
+        self.table.col_cnt()
+    }
+
+    /// Returns the total cells (rows * columns) contained in the table
+    pub fn len(&self) -> usize {
+        self.table.len()
+    }
+
+    /// Returns true if the total cells (rows * columns) contained in the
+    /// table is zero
+    pub fn is_empty(&self) -> bool {
+        self.table.is_empty()
+    }
+
+    /// Returns reference to the cell found at the specified row and column
+    pub fn cell(&self, row: usize, col: usize) -> Option<&T> {
+        self.table.cell(row, col)
+    }
+
+    /// Returns true if the cell at the specified row and column exists
+    pub fn has_cell(&self, row: usize, col: usize) -> bool {
+        self.table.has_cell(row, col)
+    }
+
+    /// Returns an iterator of refs through all rows in the table
+    pub fn rows(&self) -> iter::Rows<'_, T, U> {
+        self.table.rows()
+    }
+
+    /// Returns an iterator of refs through a specific row in the table
+    pub fn row(&self, idx: usize) -> iter::Row<'_, T, U> {
+        self.table.row(idx)
+    }
+
+    /// Returns an iterator of refs through all columns in the table
+    pub fn columns(&self) -> iter::Columns<'_, T, U> {
+        self.table.columns()
+    }
+
+    /// Returns an iterator of refs through a specific column in the table
+    pub fn column(&self, idx: usize) -> iter::Column<'_, T, U> {
+        self.table.column(idx)
+    }
+
+    /// Returns an iterator of refs through all cells in the table
+    pub fn cells(&self) -> iter::Cells<'_, T, U> {
+        self.table.cells()
+    }
+
+    /// Returns an iterator through pages of the table, each containing up to
+    /// `page_size` rows
+    pub fn pages(&self, page_size: usize) -> iter::Pages<'_, T, U> {
+        self.table.pages(page_size)
+    }
+
+    /// Returns an iterator of refs through the rows of a specific page of the
+    /// table, where every page contains up to `page_size` rows
+    pub fn page(&self, idx: usize, page_size: usize) -> iter::Page<'_, T, U> {
+        self.table.page(idx, page_size)
+    }
+
+    /// Returns the position of the first cell that satisfies the predicate
+    pub fn find_cell<P: FnMut(&T) -> bool>(&self, f: P) -> Option<Position> {
+        self.table.find_cell(f)
+    }
+
+    /// Returns the index of the first row whose cell in the specified column
+    /// satisfies the predicate
+    pub fn find_row_where<P: FnMut(&T) -> bool>(&self, col: usize, f: P) -> Option<usize> {
+        self.table.find_row_where(col, f)
+    }
+
+    /// Returns the position of the first cell that is equal to the value
+    pub fn position_of(&self, value: &T) -> Option<Position>
+    where
+        T: PartialEq,
+    {
+        self.table.position_of(value)
+    }
+
+    /// Returns an iterator through the positions of all cells that are equal
+    /// to the value
+    pub fn positions_of<'a>(&'a self, value: &'a T) -> iter::PositionsOf<'a, T, U>
+    where
+        T: PartialEq,
+    {
+        self.table.positions_of(value)
+    }
+}
+
+impl<T, U: Table<Data = T>> From<U> for FrozenTable<T, U> {
+    fn from(table: U) -> Self {
+        Self::new(table)
+    }
+}
+
+impl<T, U: Table<Data = T> + Index<(usize, usize), Output = T>> Index<(usize, usize)>
+    for FrozenTable<T, U>
+{
+    type Output = T;
+
+    /// Indexes into the underlying table, panicking if the cell is missing
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        &self.table[(row, col)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DynamicTable;
+
+    #[test]
+    fn should_expose_cells_of_underlying_table() {
+        let table = FrozenTable::new(DynamicTable::from([[1, 2, 3], [4, 5, 6]]));
+        assert_eq!(table.row_cnt(), 2);
+        assert_eq!(table.col_cnt(), 3);
+        assert_eq!(table.cell(1, 2), Some(&6));
+        assert_eq!(table[(0, 1)], 2);
+        assert!(!table.has_cell(2, 0));
+    }
+
+    #[test]
+    fn should_support_iterating_through_table() {
+        let table = FrozenTable::new(DynamicTable::from([[1, 2, 3], [4, 5, 6]]));
+        assert_eq!(table.cells().sum::<usize>(), 21);
+        assert_eq!(table.column(1).copied().sum::<usize>(), 7);
+        assert_eq!(table.rows().count(), 2);
+        assert_eq!(
+            table.find_cell(|x| *x == 5),
+            Some(Position { row: 1, col: 1 })
+        );
+    }
+
+    #[test]
+    fn into_inner_should_return_mutable_table() {
+        let mut table = FrozenTable::new(DynamicTable::from([[1, 2]])).into_inner();
+        table.insert_cell(0, 0, 10);
+        assert_eq!(table.cell(0, 0), Some(&10));
+    }
+}
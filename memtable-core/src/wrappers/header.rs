@@ -0,0 +1,552 @@
+use crate::{iter, utils, Capacity, Table};
+use core::{cmp, fmt, marker::PhantomData};
+use std::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+/// Represents errors that can occur when naming the columns of a
+/// [`HeaderTable`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HeaderError {
+    /// No column has the given name
+    UnknownColumn(String),
+
+    /// More than one column would have the given name
+    DuplicateColumn(String),
+
+    /// The named column was left out of a new column order
+    MissingColumn(String),
+}
+
+impl fmt::Display for HeaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownColumn(name) => write!(f, "No column is named {:?}", name),
+            Self::DuplicateColumn(name) => write!(f, "Column {:?} is named more than once", name),
+            Self::MissingColumn(name) => write!(f, "Column {:?} is missing from order", name),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for HeaderError {}
+
+/// Represents a table that wraps another table, giving its columns unique
+/// names that can be used to look them up, rename them, and reorder them
+///
+/// Names follow their column whenever columns are inserted, removed,
+/// reordered, shifted, or flipped. Columns inserted through
+/// [`Table::insert_column`] and any columns past the last name are unnamed
+/// until given a name through [`HeaderTable::set_column_name`]
+///
+/// Rotating a table turns its columns into rows, leaving nothing for the
+/// names to follow, so this table does not implement [`Default`] and cannot
+/// be rotated through [`Table::rotate_cw`] or [`Table::rotate_ccw`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-1", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde-1",
+    serde(
+        bound(
+            serialize = "U: serde::Serialize",
+            deserialize = "U: serde::Deserialize<'de>"
+        ),
+        try_from = "RawHeaderTable<U>"
+    )
+)]
+pub struct HeaderTable<T, U: Table<Data = T>> {
+    names: Vec<Option<String>>,
+    table: U,
+    #[cfg_attr(feature = "serde-1", serde(skip))]
+    _data: PhantomData<T>,
+}
+
+impl<T, U: Table<Data = T>> HeaderTable<T, U> {
+    /// Creates a new table wrapping the given table, naming its columns in
+    /// order, failing if any name is used more than once
+    pub fn new<I, S>(table: U, names: I) -> Result<Self, HeaderError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self::with_optional_names(table, names.into_iter().map(|x| Some(x.into())))
+    }
+
+    /// Creates a new table wrapping the given table, naming its columns in
+    /// order where `None` leaves a column unnamed, failing if any name is
+    /// used more than once
+    pub fn with_optional_names<I>(table: U, names: I) -> Result<Self, HeaderError>
+    where
+        I: IntoIterator<Item = Option<String>>,
+    {
+        let names: Vec<Option<String>> = names.into_iter().collect();
+        for (i, name) in names.iter().enumerate() {
+            if let Some(name) = name {
+                if names[..i].iter().any(|x| x.as_ref() == Some(name)) {
+                    return Err(HeaderError::DuplicateColumn(name.clone()));
+                }
+            }
+        }
+
+        Ok(Self {
+            names,
+            table,
+            _data: PhantomData,
+        })
+    }
+
+    /// Returns a reference to the underlying table
+    pub fn as_inner(&self) -> &U {
+        &self.table
+    }
+
+    /// Consumes the wrapper, returning the underlying table and discarding
+    /// the column names
+    pub fn into_inner(self) -> U {
+        self.table
+    }
+
+    /// Returns an iterator through the name of every column up to the last
+    /// named one, yielding `None` for unnamed columns
+    pub fn names(&self) -> impl Iterator<Item = Option<&str>> + '_ {
+        self.names.iter().map(Option::as_deref)
+    }
+
+    /// Returns the name of the column at the specified index
+    pub fn column_name(&self, idx: usize) -> Option<&str> {
+        self.names.get(idx)?.as_deref()
+    }
+
+    /// Returns the index of the column with the specified name
+    pub fn column_index(&self, name: &str) -> Option<usize> {
+        self.names.iter().position(|x| x.as_deref() == Some(name))
+    }
+
+    /// Returns an iterator of refs through the column with the specified name
+    pub fn column_by_name(&self, name: &str) -> Option<iter::Column<'_, T, Self>> {
+        self.column_index(name).map(|idx| self.column(idx))
+    }
+
+    /// Names the column at the specified index, returning its old name, or
+    /// fails if another column already has the name
+    pub fn set_column_name(
+        &mut self,
+        idx: usize,
+        name: &str,
+    ) -> Result<Option<String>, HeaderError> {
+        match self.column_index(name) {
+            Some(x) if x != idx => Err(HeaderError::DuplicateColumn(name.to_string())),
+            _ => {
+                if idx >= self.names.len() {
+                    self.names.resize(idx + 1, None);
+                }
+                Ok(self.names[idx].replace(name.to_string()))
+            }
+        }
+    }
+
+    /// Renames the column named `old` to `new`, failing if no column is named
+    /// `old` or another column is already named `new`
+    pub fn rename_column(&mut self, old: &str, new: &str) -> Result<(), HeaderError> {
+        let idx = self
+            .column_index(old)
+            .ok_or_else(|| HeaderError::UnknownColumn(old.to_string()))?;
+        self.set_column_name(idx, new).map(|_| ())
+    }
+
+    /// Pushes a column with the specified name to the end of the table,
+    /// failing if another column already has the name
+    pub fn push_named_column<I: IntoIterator<Item = T>>(
+        &mut self,
+        name: &str,
+        cells: I,
+    ) -> Result<(), HeaderError> {
+        if self.column_index(name).is_some() {
+            return Err(HeaderError::DuplicateColumn(name.to_string()));
+        }
+
+        let idx = cmp::max(self.table.col_cnt(), self.names.len());
+        self.table.insert_column(idx, cells);
+        self.names.resize(idx, None);
+        self.names.push(Some(name.to_string()));
+        Ok(())
+    }
+
+    /// Applies the function to the names of the columns within the table's
+    /// column count, dropping any trailing unnamed columns it adds
+    fn with_column_names(&mut self, f: impl FnOnce(&mut [Option<String>])) {
+        let len = self.names.len();
+        let col_cnt = self.table.col_cnt();
+        if col_cnt > len {
+            self.names.resize(col_cnt, None);
+        }
+
+        f(&mut self.names[..col_cnt]);
+
+        while self.names.len() > len && self.names.last() == Some(&None) {
+            self.names.pop();
+        }
+    }
+
+    /// Moves the named columns into the specified order, which must list
+    /// every named column exactly once
+    ///
+    /// Unnamed columns are moved after the named ones, keeping their order
+    pub fn reorder_columns(&mut self, order: &[&str]) -> Result<(), HeaderError> {
+        let mut indexes = Vec::with_capacity(self.names.len());
+        for (i, name) in order.iter().enumerate() {
+            if order[..i].contains(name) {
+                return Err(HeaderError::DuplicateColumn(name.to_string()));
+            }
+
+            indexes.push(
+                self.column_index(name)
+                    .ok_or_else(|| HeaderError::UnknownColumn(name.to_string()))?,
+            );
+        }
+
+        if let Some(name) = self.names().flatten().find(|x| !order.contains(x)) {
+            return Err(HeaderError::MissingColumn(name.to_string()));
+        }
+
+        let col_cnt = cmp::max(self.table.col_cnt(), self.names.len());
+        indexes.extend((0..col_cnt).filter(|col| self.column_name(*col).is_none()));
+
+        for row in 0..self.table.row_cnt() {
+            let cells: Vec<Option<T>> = indexes
+                .iter()
+                .map(|col| self.table.remove_cell(row, *col))
+                .collect();
+
+            for (col, x) in cells.into_iter().enumerate() {
+                if let Some(x) = x {
+                    self.table.insert_cell(row, col, x);
+                }
+            }
+        }
+
+        self.names = order.iter().map(|x| Some(x.to_string())).collect();
+        Ok(())
+    }
+}
+
+impl<T, U: Table<Data = T>> Table for HeaderTable<T, U> {
+    type Data = T;
+    type Row = U::Row;
+    type Column = U::Column;
+
+    fn max_row_capacity(&self) -> Capacity {
+        self.table.max_row_capacity()
+    }
+
+    fn max_column_capacity(&self) -> Capacity {
+        self.table.max_column_capacity()
+    }
+
+    fn row_cnt(&self) -> usize {
+        self.table.row_cnt()
+    }
+
+    fn col_cnt(&self) -> usize {
+        self.table.col_cnt()
+    }
+
+    fn set_preferred_row_cnt(&mut self, cnt: usize) {
+        self.table.set_preferred_row_cnt(cnt)
+    }
+
+    fn set_preferred_col_cnt(&mut self, cnt: usize) {
+        self.table.set_preferred_col_cnt(cnt)
+    }
+
+    fn truncate(&mut self) {
+        self.table.truncate()
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.table.shrink_to_fit()
+    }
+
+    fn cell(&self, row: usize, col: usize) -> Option<&Self::Data> {
+        self.table.cell(row, col)
+    }
+
+    fn mut_cell(&mut self, row: usize, col: usize) -> Option<&mut Self::Data> {
+        self.table.mut_cell(row, col)
+    }
+
+    fn insert_cell(&mut self, row: usize, col: usize, value: Self::Data) -> Option<Self::Data> {
+        self.table.insert_cell(row, col, value)
+    }
+
+    fn remove_cell(&mut self, row: usize, col: usize) -> Option<Self::Data> {
+        self.table.remove_cell(row, col)
+    }
+
+    /// Inserts the column unnamed, shifting right the names of all columns
+    /// after it
+    fn insert_column<I: IntoIterator<Item = Self::Data>>(&mut self, col: usize, cells: I) {
+        if col < self.names.len() {
+            self.names.insert(col, None);
+        }
+        self.table.insert_column(col, cells)
+    }
+
+    /// Inserts the columns unnamed, shifting right the names of all columns
+    /// after them
+    fn insert_columns<I, C>(&mut self, col: usize, cols: I)
+    where
+        I: IntoIterator<Item = C>,
+        C: IntoIterator<Item = Self::Data>,
+    {
+        let cols: Vec<C> = cols.into_iter().collect();
+        if col < self.names.len() {
+            self.names.splice(col..col, (0..cols.len()).map(|_| None));
+        }
+        self.table.insert_columns(col, cols)
+    }
+
+    /// Removes the column along with its name, shifting left the names of all
+    /// columns after it
+    fn remove_column(&mut self, col: usize) -> Option<Self::Column> {
+        if col < self.names.len() && col < self.col_cnt() {
+            self.names.remove(col);
+        }
+        self.table.remove_column(col)
+    }
+
+    /// Shifts the columns along with their names, dropping the names of
+    /// columns shifted off of either end without wrapping around
+    fn shift_columns(&mut self, offset: isize, wrap: bool) {
+        self.table.shift_columns(offset, wrap);
+        self.with_column_names(|names| utils::shift_slice(names, offset, wrap, || None));
+    }
+
+    /// Flips the columns along with their names
+    fn flip_horizontal(&mut self) {
+        self.table.flip_horizontal();
+        self.with_column_names(|names| names.reverse());
+    }
+}
+
+/// Unchecked form of a [`HeaderTable`] that is deserialized first so its
+/// names can be checked for duplicates
+#[cfg(feature = "serde-1")]
+#[derive(serde::Deserialize)]
+struct RawHeaderTable<U> {
+    names: Vec<Option<String>>,
+    table: U,
+}
+
+#[cfg(feature = "serde-1")]
+impl<T, U: Table<Data = T>> core::convert::TryFrom<RawHeaderTable<U>> for HeaderTable<T, U> {
+    type Error = HeaderError;
+
+    fn try_from(raw: RawHeaderTable<U>) -> Result<Self, Self::Error> {
+        Self::with_optional_names(raw.table, raw.names)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DynamicTable;
+    use std::vec;
+
+    #[test]
+    fn new_should_fail_if_name_is_repeated() {
+        let result = HeaderTable::new(DynamicTable::<usize>::new(), vec!["a", "b", "a"]);
+        assert_eq!(
+            result.unwrap_err(),
+            HeaderError::DuplicateColumn(String::from("a"))
+        );
+    }
+
+    #[test]
+    fn rename_column_should_keep_column_in_place() {
+        let mut table = HeaderTable::new(
+            DynamicTable::from([[1, 2, 3], [4, 5, 6]]),
+            vec!["a", "b", "c"],
+        )
+        .unwrap();
+        table.rename_column("b", "x").unwrap();
+
+        assert_eq!(table.column_index("x"), Some(1));
+        assert_eq!(table.column_index("b"), None);
+        assert_eq!(
+            table
+                .column_by_name("x")
+                .unwrap()
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![2, 5]
+        );
+    }
+
+    #[test]
+    fn rename_column_should_fail_if_names_are_unknown_or_taken() {
+        let mut table = HeaderTable::new(
+            DynamicTable::from([[1, 2, 3], [4, 5, 6]]),
+            vec!["a", "b", "c"],
+        )
+        .unwrap();
+
+        assert_eq!(
+            table.rename_column("z", "x"),
+            Err(HeaderError::UnknownColumn(String::from("z")))
+        );
+        assert_eq!(
+            table.rename_column("a", "c"),
+            Err(HeaderError::DuplicateColumn(String::from("c")))
+        );
+        assert_eq!(table.rename_column("a", "a"), Ok(()));
+    }
+
+    #[test]
+    fn reorder_columns_should_move_cells_with_their_names() {
+        let mut table = HeaderTable::new(
+            DynamicTable::from([[1, 2, 3], [4, 5, 6]]),
+            vec!["a", "b", "c"],
+        )
+        .unwrap();
+        table.reorder_columns(&["c", "a", "b"]).unwrap();
+
+        assert_eq!(
+            table.names().collect::<Vec<_>>(),
+            vec![Some("c"), Some("a"), Some("b")]
+        );
+        assert_eq!(*table.as_inner(), [[3, 1, 2], [6, 4, 5]]);
+    }
+
+    #[test]
+    fn reorder_columns_should_fail_unless_every_name_is_listed_once() {
+        let mut table = HeaderTable::new(
+            DynamicTable::from([[1, 2, 3], [4, 5, 6]]),
+            vec!["a", "b", "c"],
+        )
+        .unwrap();
+
+        assert_eq!(
+            table.reorder_columns(&["c", "a"]),
+            Err(HeaderError::MissingColumn(String::from("b")))
+        );
+        assert_eq!(
+            table.reorder_columns(&["c", "a", "a"]),
+            Err(HeaderError::DuplicateColumn(String::from("a")))
+        );
+        assert_eq!(*table.as_inner(), [[1, 2, 3], [4, 5, 6]]);
+    }
+
+    #[test]
+    fn reorder_columns_should_move_unnamed_columns_last() {
+        let mut table = HeaderTable::new(
+            DynamicTable::from([[1, 2, 3], [4, 5, 6]]),
+            vec!["a", "b", "c"],
+        )
+        .unwrap();
+        table.insert_column(0, vec![7, 8]);
+        table.reorder_columns(&["b", "c", "a"]).unwrap();
+
+        assert_eq!(*table.as_inner(), [[2, 3, 1, 7], [5, 6, 4, 8]]);
+        assert_eq!(table.column_name(3), None);
+
+        table.set_column_name(3, "d").unwrap();
+        assert_eq!(table.column_index("d"), Some(3));
+    }
+
+    #[test]
+    fn insert_and_remove_column_should_shift_names_of_later_columns() {
+        let mut table = HeaderTable::new(
+            DynamicTable::from([[1, 2, 3], [4, 5, 6]]),
+            vec!["a", "b", "c"],
+        )
+        .unwrap();
+
+        table.insert_column(1, vec![7, 8]);
+        assert_eq!(table.column_name(1), None);
+        assert_eq!(table.column_index("b"), Some(2));
+
+        table.remove_column(0);
+        assert_eq!(
+            table.names().collect::<Vec<_>>(),
+            vec![None, Some("b"), Some("c")]
+        );
+        assert_eq!(
+            table
+                .column_by_name("c")
+                .unwrap()
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![3, 6]
+        );
+    }
+
+    #[test]
+    fn shift_columns_should_move_names_with_columns() {
+        let mut table =
+            HeaderTable::new(DynamicTable::from([[1, 2, 3], [4, 5, 6]]), vec!["a", "b"]).unwrap();
+
+        table.shift_columns(1, false);
+        assert_eq!(table.cell(0, 0), None);
+        assert_eq!(table.cell(0, 1), Some(&1));
+        assert_eq!(
+            table.names().collect::<Vec<_>>(),
+            vec![None, Some("a"), Some("b")]
+        );
+
+        table.shift_columns(-2, true);
+        assert_eq!(
+            table.names().collect::<Vec<_>>(),
+            vec![Some("b"), None, Some("a")]
+        );
+        assert_eq!(
+            table
+                .column_by_name("a")
+                .unwrap()
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![1, 4]
+        );
+    }
+
+    #[test]
+    fn flip_horizontal_should_move_names_with_columns() {
+        let mut table = HeaderTable::new(DynamicTable::from([[1, 2, 3]]), vec!["a", "b"]).unwrap();
+
+        table.flip_horizontal();
+        assert_eq!(*table.as_inner(), [[3, 2, 1]]);
+        assert_eq!(
+            table.names().collect::<Vec<_>>(),
+            vec![None, Some("b"), Some("a")]
+        );
+    }
+
+    #[cfg(feature = "serde-1")]
+    #[test]
+    fn should_fail_to_deserialize_repeated_names() {
+        let json = serde_json::json!({
+            "names": ["a", "a"],
+            "table": serde_json::to_value(DynamicTable::from([[1, 2]])).unwrap(),
+        });
+
+        let result: Result<HeaderTable<usize, DynamicTable<usize>>, _> =
+            serde_json::from_value(json);
+        assert!(result.is_err());
+    }
+
+    #[cfg(feature = "serde-1")]
+    #[test]
+    fn should_keep_names_through_json_round_trip() {
+        let table = HeaderTable::new(
+            DynamicTable::from([[1, 2, 3], [4, 5, 6]]),
+            vec!["a", "b", "c"],
+        )
+        .unwrap();
+
+        let json = serde_json::to_string(&table).unwrap();
+        let other: HeaderTable<usize, DynamicTable<usize>> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(other, table);
+    }
+}
@@ -0,0 +1,242 @@
+use crate::{iter, Capacity, Position, Table};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Represents a table that wraps another table, maintaining an index from
+/// every value to the positions of the cells holding it so that
+/// [`Table::position_of`] and [`Table::positions_of`] answer without
+/// scanning the table
+///
+/// Keeping the index accurate means cells can only change through
+/// [`Table::insert_cell`] and [`Table::remove_cell`], so
+/// [`Table::mut_cell`] always returns `None`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IndexedTable<T: Ord + Clone, U: Table<Data = T>> {
+    table: U,
+    index: BTreeMap<T, BTreeSet<Position>>,
+}
+
+impl<T: Ord + Clone, U: Table<Data = T>> IndexedTable<T, U> {
+    /// Creates a new indexed table wrapping the given table, indexing each
+    /// of its existing cells
+    pub fn new(table: U) -> Self {
+        let mut this = Self {
+            table,
+            index: BTreeMap::new(),
+        };
+        this.reindex();
+        this
+    }
+
+    /// Returns a reference to the underlying table
+    pub fn as_inner(&self) -> &U {
+        &self.table
+    }
+
+    /// Consumes the indexed table, returning the underlying table
+    pub fn into_inner(self) -> U {
+        self.table
+    }
+
+    /// Returns the total cells equal to the given value
+    pub fn count_of(&self, value: &T) -> usize {
+        self.index.get(value).map_or(0, BTreeSet::len)
+    }
+
+    /// Rebuilds the index from every cell within the table's rows and columns
+    fn reindex(&mut self) {
+        self.index.clear();
+        for row in 0..self.table.row_cnt() {
+            for col in 0..self.table.col_cnt() {
+                if let Some(x) = self.table.cell(row, col) {
+                    self.index
+                        .entry(x.clone())
+                        .or_default()
+                        .insert(Position { row, col });
+                }
+            }
+        }
+    }
+
+    fn unindex(&mut self, value: &T, pos: Position) {
+        if let Some(positions) = self.index.get_mut(value) {
+            positions.remove(&pos);
+            if positions.is_empty() {
+                self.index.remove(value);
+            }
+        }
+    }
+}
+
+impl<T: Ord + Clone, U: Table<Data = T>> From<U> for IndexedTable<T, U> {
+    fn from(table: U) -> Self {
+        Self::new(table)
+    }
+}
+
+impl<T: Ord + Clone, U: Table<Data = T>> Table for IndexedTable<T, U> {
+    type Data = T;
+    type Row = U::Row;
+    type Column = U::Column;
+
+    fn max_row_capacity(&self) -> Capacity {
+        self.table.max_row_capacity()
+    }
+
+    fn max_column_capacity(&self) -> Capacity {
+        self.table.max_column_capacity()
+    }
+
+    fn row_cnt(&self) -> usize {
+        self.table.row_cnt()
+    }
+
+    fn col_cnt(&self) -> usize {
+        self.table.col_cnt()
+    }
+
+    fn set_preferred_row_cnt(&mut self, cnt: usize) {
+        self.table.set_preferred_row_cnt(cnt);
+        self.reindex();
+    }
+
+    fn set_preferred_col_cnt(&mut self, cnt: usize) {
+        self.table.set_preferred_col_cnt(cnt);
+        self.reindex();
+    }
+
+    fn truncate(&mut self) {
+        self.table.truncate();
+        self.reindex();
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.table.shrink_to_fit()
+    }
+
+    fn cell(&self, row: usize, col: usize) -> Option<&Self::Data> {
+        self.table.cell(row, col)
+    }
+
+    /// Always returns `None`, as changing a cell in place would leave the
+    /// index pointing at its old value
+    fn mut_cell(&mut self, _row: usize, _col: usize) -> Option<&mut Self::Data> {
+        None
+    }
+
+    fn insert_cell(&mut self, row: usize, col: usize, value: Self::Data) -> Option<Self::Data> {
+        let pos = Position { row, col };
+        let key = value.clone();
+        let old = self.table.insert_cell(row, col, value);
+        if let Some(x) = old.as_ref() {
+            self.unindex(x, pos);
+        }
+
+        // Tables with a fixed capacity may have refused the cell
+        if self.table.cell(row, col) == Some(&key) {
+            self.index.entry(key).or_default().insert(pos);
+        }
+
+        old
+    }
+
+    fn remove_cell(&mut self, row: usize, col: usize) -> Option<Self::Data> {
+        let old = self.table.remove_cell(row, col);
+        if let Some(x) = old.as_ref() {
+            self.unindex(x, Position { row, col });
+        }
+        old
+    }
+
+    fn has_cell(&self, row: usize, col: usize) -> bool {
+        self.table.has_cell(row, col)
+    }
+
+    fn position_of(&self, value: &Self::Data) -> Option<Position> {
+        self.index
+            .get(value)
+            .and_then(|positions| positions.iter().next().copied())
+    }
+
+    fn positions_of<'a>(
+        &'a self,
+        value: &'a Self::Data,
+    ) -> iter::PositionsOf<'a, Self::Data, Self> {
+        iter::PositionsOf::from_positions(
+            self.index
+                .get(value)
+                .map(|positions| positions.iter().copied().collect())
+                .unwrap_or_default(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DynamicTable, FixedTable};
+    use std::vec;
+
+    #[test]
+    fn new_should_index_existing_cells() {
+        let table = IndexedTable::new(DynamicTable::from([["a", "b"], ["c", "b"]]));
+        assert_eq!(table.position_of(&"b"), Some(Position::new(0, 1)));
+        assert_eq!(table.position_of(&"c"), Some(Position::new(1, 0)));
+        assert_eq!(table.position_of(&"d"), None);
+        assert_eq!(table.count_of(&"b"), 2);
+
+        let mut positions = table.positions_of(&"b");
+        assert_eq!(positions.next(), Some(Position::new(0, 1)));
+        assert_eq!(positions.next(), Some(Position::new(1, 1)));
+        assert_eq!(positions.next(), None);
+        assert_eq!(table.positions_of(&"d").next(), None);
+    }
+
+    #[test]
+    fn position_of_should_follow_inserted_and_removed_cells() {
+        let mut table = IndexedTable::new(DynamicTable::new());
+        table.push_row(vec![1, 2]);
+        table.push_row(vec![3, 2]);
+
+        table.insert_cell(0, 1, 4);
+        assert_eq!(table.position_of(&2), Some(Position::new(1, 1)));
+        assert_eq!(table.position_of(&4), Some(Position::new(0, 1)));
+
+        table.remove_cell(1, 1);
+        assert_eq!(table.position_of(&2), None);
+    }
+
+    #[test]
+    fn position_of_should_follow_rows_as_they_move() {
+        let mut table = IndexedTable::new(DynamicTable::from([[1, 2], [3, 4]]));
+        table.insert_row(0, vec![5, 6]);
+        assert_eq!(table.position_of(&3), Some(Position::new(2, 0)));
+
+        table.remove_row(0);
+        table.remove_row(0);
+        assert_eq!(table.position_of(&1), None);
+        assert_eq!(table.position_of(&3), Some(Position::new(0, 0)));
+    }
+
+    #[test]
+    fn position_of_should_skip_cells_outside_of_preferred_counts() {
+        let mut table = IndexedTable::new(DynamicTable::from([[1, 2], [3, 4]]));
+        table.set_preferred_row_cnt(1);
+        assert_eq!(table.position_of(&3), None);
+
+        table.set_preferred_row_cnt(2);
+        assert_eq!(table.position_of(&3), Some(Position::new(1, 0)));
+    }
+
+    #[test]
+    fn insert_cell_should_not_index_cells_refused_by_table() {
+        let mut table = IndexedTable::new(FixedTable::from([[1, 2]]));
+        table.insert_cell(1, 0, 5);
+        assert_eq!(table.position_of(&5), None);
+    }
+
+    #[test]
+    fn mut_cell_should_always_return_none() {
+        let mut table = IndexedTable::new(DynamicTable::from([[1]]));
+        assert!(table.mut_cell(0, 0).is_none());
+    }
+}
@@ -0,0 +1,424 @@
+use crate::{iter, Capacity, SortOrder, Table};
+use core::fmt;
+use std::{collections::BTreeMap, vec::Vec};
+
+/// Represents an opaque identifier for a row of a [`KeyedTable`] that stays
+/// the same as other rows are inserted and removed
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde-1", derive(serde::Serialize, serde::Deserialize))]
+pub struct RowId(u64);
+
+impl fmt::Display for RowId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{}", self.0)
+    }
+}
+
+/// Represents a table that wraps another table, assigning a stable
+/// [`RowId`] to every row so that rows can be referenced by id regardless of
+/// how their index changes as earlier rows are inserted and removed
+///
+/// Ids are assigned when a row is first added, whether through
+/// [`Table::insert_row`], [`Table::push_row`], or by inserting a cell beyond
+/// the last row, and are never reused
+#[derive(Clone, Debug)]
+pub struct KeyedTable<T, U: Table<Data = T>> {
+    table: U,
+    ids: Vec<RowId>,
+    indexes: BTreeMap<RowId, usize>,
+    next_id: u64,
+}
+
+impl<T, U: Table<Data = T>> KeyedTable<T, U> {
+    /// Creates a new keyed table wrapping the given table, assigning an id to
+    /// each of its existing rows
+    pub fn new(table: U) -> Self {
+        let mut this = Self {
+            table,
+            ids: Vec::new(),
+            indexes: BTreeMap::new(),
+            next_id: 0,
+        };
+        this.sync_ids();
+        this
+    }
+
+    /// Returns a reference to the underlying table
+    pub fn as_inner(&self) -> &U {
+        &self.table
+    }
+
+    /// Consumes the keyed table, returning the underlying table
+    pub fn into_inner(self) -> U {
+        self.table
+    }
+
+    /// Returns the id of the row at the specified index
+    pub fn row_id(&self, idx: usize) -> Option<RowId> {
+        self.ids.get(idx).copied()
+    }
+
+    /// Returns the current index of the row with the specified id, or `None`
+    /// if the row has been removed
+    pub fn row_index(&self, id: RowId) -> Option<usize> {
+        self.indexes.get(&id).copied()
+    }
+
+    /// Returns an iterator of refs through the row with the specified id
+    pub fn get_row_by_id(&self, id: RowId) -> Option<iter::Row<'_, T, Self>> {
+        self.row_index(id).map(|idx| self.row(idx))
+    }
+
+    /// Removes the row with the specified id, returning it if it existed
+    pub fn remove_row_by_id(&mut self, id: RowId) -> Option<U::Row> {
+        let idx = self.row_index(id)?;
+        self.remove_row(idx)
+    }
+
+    /// Pushes a row to the end of the table, returning the id assigned to it
+    ///
+    /// Returns `None` if the underlying table has no room for another row,
+    /// such as a [`FixedTable`](crate::FixedTable) that is already full
+    pub fn push_row_with_id<I: IntoIterator<Item = T>>(&mut self, cells: I) -> Option<RowId> {
+        let idx = self.row_cnt();
+        self.insert_row(idx, cells);
+
+        // Ensure the row exists, and thereby has an id, even without cells
+        if self.row_cnt() <= idx {
+            self.set_preferred_row_cnt(idx + 1);
+        }
+
+        if self.row_cnt() > idx {
+            self.ids.get(idx).copied()
+        } else {
+            None
+        }
+    }
+
+    fn new_id(&mut self) -> RowId {
+        let id = RowId(self.next_id);
+        self.next_id += 1;
+        id
+    }
+
+    /// Assigns ids to new rows at the end of the table and drops the ids of
+    /// rows that no longer exist
+    fn sync_ids(&mut self) {
+        let row_cnt = self.table.row_cnt();
+        if self.ids.len() > row_cnt {
+            for id in self.ids.drain(row_cnt..) {
+                self.indexes.remove(&id);
+            }
+        }
+
+        while self.ids.len() < row_cnt {
+            let id = self.new_id();
+            self.indexes.insert(id, self.ids.len());
+            self.ids.push(id);
+        }
+    }
+
+    /// Updates the index of every row from the specified index onward
+    fn reindex(&mut self, from: usize) {
+        for (idx, id) in self.ids.iter().enumerate().skip(from) {
+            self.indexes.insert(*id, idx);
+        }
+    }
+
+    /// Removes every row not flagged to be kept along with its id, keeping
+    /// the ids of the rows that remain
+    fn retain_rows(&mut self, keep: &[bool]) -> usize {
+        let mut ids = Vec::with_capacity(self.ids.len());
+        for (idx, id) in self.ids.drain(..).enumerate() {
+            if keep.get(idx).copied().unwrap_or(true) {
+                ids.push(id);
+            } else {
+                self.indexes.remove(&id);
+            }
+        }
+        self.ids = ids;
+        self.reindex(0);
+
+        let removed = crate::retain_rows(&mut self.table, keep);
+        self.sync_ids();
+        removed
+    }
+}
+
+impl<T, U: Table<Data = T>> Table for KeyedTable<T, U> {
+    type Data = T;
+    type Row = U::Row;
+    type Column = U::Column;
+
+    fn max_row_capacity(&self) -> Capacity {
+        self.table.max_row_capacity()
+    }
+
+    fn max_column_capacity(&self) -> Capacity {
+        self.table.max_column_capacity()
+    }
+
+    fn row_cnt(&self) -> usize {
+        self.table.row_cnt()
+    }
+
+    fn col_cnt(&self) -> usize {
+        self.table.col_cnt()
+    }
+
+    fn set_preferred_row_cnt(&mut self, cnt: usize) {
+        self.table.set_preferred_row_cnt(cnt);
+        self.sync_ids();
+    }
+
+    fn set_preferred_col_cnt(&mut self, cnt: usize) {
+        self.table.set_preferred_col_cnt(cnt)
+    }
+
+    fn truncate(&mut self) {
+        self.table.truncate()
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.table.shrink_to_fit();
+        self.sync_ids();
+    }
+
+    fn cell(&self, row: usize, col: usize) -> Option<&Self::Data> {
+        self.table.cell(row, col)
+    }
+
+    fn mut_cell(&mut self, row: usize, col: usize) -> Option<&mut Self::Data> {
+        self.table.mut_cell(row, col)
+    }
+
+    fn insert_cell(&mut self, row: usize, col: usize, value: Self::Data) -> Option<Self::Data> {
+        let old = self.table.insert_cell(row, col, value);
+        self.sync_ids();
+        old
+    }
+
+    fn remove_cell(&mut self, row: usize, col: usize) -> Option<Self::Data> {
+        let old = self.table.remove_cell(row, col);
+        self.sync_ids();
+        old
+    }
+
+    /// Inserts the row with a new id, shifting down the rows after it while
+    /// keeping their ids
+    fn insert_row<I: IntoIterator<Item = Self::Data>>(&mut self, row: usize, cells: I) {
+        if row < self.ids.len() {
+            let id = self.new_id();
+            self.ids.insert(row, id);
+            self.reindex(row);
+        }
+
+        self.table.insert_row(row, cells);
+        self.sync_ids();
+    }
+
+    /// Inserts the rows with new ids, shifting down the rows after them
+    /// while keeping their ids
+    fn insert_rows<I, R>(&mut self, row: usize, rows: I)
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = Self::Data>,
+    {
+        let rows: Vec<R> = rows.into_iter().collect();
+        if row < self.ids.len() {
+            let ids: Vec<RowId> = (0..rows.len()).map(|_| self.new_id()).collect();
+            self.ids.splice(row..row, ids);
+            self.reindex(row);
+        }
+
+        self.table.insert_rows(row, rows);
+        self.sync_ids();
+    }
+
+    /// Removes the row and its id, shifting up the rows after it while
+    /// keeping their ids
+    fn remove_row(&mut self, row: usize) -> Option<Self::Row> {
+        if row < self.ids.len() {
+            let id = self.ids.remove(row);
+            self.indexes.remove(&id);
+            self.reindex(row);
+        }
+
+        let removed = self.table.remove_row(row);
+        self.sync_ids();
+        removed
+    }
+
+    /// Sorts the rows, moving each id along with its row
+    fn sort_rows_by_columns_with<F>(&mut self, keys: &[(usize, SortOrder)], compare: F)
+    where
+        F: FnMut(&Self::Data, &Self::Data) -> core::cmp::Ordering,
+    {
+        let order = crate::sorted_row_order(&self.table, keys, compare);
+        crate::reorder_rows(&mut self.table, &order);
+
+        self.ids = order
+            .into_iter()
+            .filter_map(|idx| self.ids.get(idx).copied())
+            .collect();
+        self.reindex(0);
+        self.sync_ids();
+    }
+
+    /// Removes duplicate rows along with their ids, keeping the ids of the
+    /// rows that remain
+    fn dedup_rows(&mut self) -> usize
+    where
+        Self::Data: core::hash::Hash + Eq,
+    {
+        let keep = crate::unique_rows(&self.table);
+        self.retain_rows(&keep)
+    }
+
+    /// Removes rows with duplicate keys along with their ids, keeping the ids
+    /// of the rows that remain
+    fn dedup_rows_by_key(&mut self, col: usize) -> usize
+    where
+        Self::Data: core::hash::Hash + Eq,
+    {
+        let keep = crate::unique_rows_by_key(&self.table, col);
+        self.retain_rows(&keep)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DynamicTable, FixedTable};
+    use std::vec;
+
+    #[test]
+    fn new_should_assign_ids_to_existing_rows() {
+        let table = KeyedTable::new(DynamicTable::from([[1], [2]]));
+        assert_eq!(table.row_id(0), Some(RowId(0)));
+        assert_eq!(table.row_id(1), Some(RowId(1)));
+        assert_eq!(table.row_id(2), None);
+    }
+
+    #[test]
+    fn ids_should_survive_removal_of_earlier_rows() {
+        let mut table = KeyedTable::new(DynamicTable::new());
+        let a = table.push_row_with_id(vec![1, 2]).unwrap();
+        let b = table.push_row_with_id(vec![3, 4]).unwrap();
+        let c = table.push_row_with_id(vec![5, 6]).unwrap();
+
+        table.remove_row_by_id(a);
+        assert_eq!(table.row_index(a), None);
+        assert_eq!(table.row_index(b), Some(0));
+        assert_eq!(table.row_index(c), Some(1));
+        assert_eq!(
+            table.get_row_by_id(c).unwrap().copied().collect::<Vec<_>>(),
+            vec![5, 6]
+        );
+    }
+
+    #[test]
+    fn ids_should_survive_insertion_of_earlier_rows() {
+        let mut table = KeyedTable::new(DynamicTable::new());
+        let a = table.push_row_with_id(vec![1]).unwrap();
+        let b = table.push_row_with_id(vec![2]).unwrap();
+
+        table.insert_row(0, vec![0]);
+        assert_eq!(table.row_index(a), Some(1));
+        assert_eq!(table.row_index(b), Some(2));
+
+        let new = table.row_id(0).unwrap();
+        assert_ne!(new, a);
+        assert_ne!(new, b);
+        assert_eq!(table.get_row_by_id(new).unwrap().next(), Some(&0));
+    }
+
+    #[test]
+    fn insert_rows_should_assign_new_ids_in_order() {
+        let mut table = KeyedTable::new(DynamicTable::from([[1], [4]]));
+        let a = table.row_id(0).unwrap();
+        let b = table.row_id(1).unwrap();
+
+        table.insert_rows(1, vec![vec![2], vec![3]]);
+        assert_eq!(table.row_index(a), Some(0));
+        assert_eq!(table.row_index(b), Some(3));
+
+        let (x, y) = (table.row_id(1).unwrap(), table.row_id(2).unwrap());
+        assert!(x < y);
+        assert_eq!(table.get_row_by_id(y).unwrap().next(), Some(&3));
+    }
+
+    #[test]
+    fn inserting_cell_beyond_last_row_should_assign_ids() {
+        let mut table = KeyedTable::new(DynamicTable::new());
+        table.insert_cell(2, 0, "c");
+        assert_eq!(table.row_id(2), Some(RowId(2)));
+        assert_eq!(table.row_index(RowId(1)), Some(1));
+    }
+
+    #[test]
+    fn shrink_to_fit_should_drop_ids_of_empty_trailing_rows() {
+        let mut table = KeyedTable::new(DynamicTable::from([[1], [2], [3]]));
+        table.remove_cell(2, 0);
+        table.remove_cell(1, 0);
+
+        table.shrink_to_fit();
+        assert_eq!(table.row_id(0), Some(RowId(0)));
+        assert_eq!(table.row_index(RowId(1)), None);
+        assert_eq!(table.row_index(RowId(2)), None);
+    }
+
+    #[test]
+    fn remove_row_by_id_should_return_none_if_id_unknown() {
+        let mut table = KeyedTable::new(DynamicTable::from([[1]]));
+        let id = table.row_id(0).unwrap();
+
+        assert!(table.remove_row_by_id(id).is_some());
+        assert!(table.remove_row_by_id(id).is_none());
+    }
+
+    #[test]
+    fn sort_rows_by_columns_should_move_ids_with_rows() {
+        let mut table = KeyedTable::new(DynamicTable::from([[3], [1], [2]]));
+        let (a, b, c) = (RowId(0), RowId(1), RowId(2));
+
+        table.sort_rows_by_columns(&[(0, SortOrder::Ascending)]);
+        assert_eq!(table.row_index(a), Some(2));
+        assert_eq!(table.row_index(b), Some(0));
+        assert_eq!(table.row_index(c), Some(1));
+        assert_eq!(table.get_row_by_id(a).unwrap().next(), Some(&3));
+    }
+
+    #[test]
+    fn dedup_rows_should_drop_ids_of_removed_rows() {
+        let mut table = KeyedTable::new(DynamicTable::from([[1, 2], [1, 2], [3, 4]]));
+
+        assert_eq!(table.dedup_rows(), 1);
+        assert_eq!(table.row_index(RowId(0)), Some(0));
+        assert_eq!(table.row_index(RowId(1)), None);
+        assert_eq!(table.row_index(RowId(2)), Some(1));
+        assert_eq!(table.get_row_by_id(RowId(2)).unwrap().next(), Some(&3));
+    }
+
+    #[test]
+    fn dedup_rows_by_key_should_drop_ids_of_removed_rows() {
+        let mut table = KeyedTable::new(DynamicTable::from([["a", "x"], ["a", "y"], ["b", "z"]]));
+
+        assert_eq!(table.dedup_rows_by_key(0), 1);
+        assert_eq!(table.row_index(RowId(1)), None);
+        assert_eq!(table.row_index(RowId(2)), Some(1));
+
+        let id = table.push_row_with_id(vec!["c", "w"]).unwrap();
+        assert_eq!(id, RowId(3));
+    }
+
+    #[test]
+    fn push_row_with_id_should_return_none_if_table_is_full() {
+        let mut table = KeyedTable::new(FixedTable::from([[1, 2]]));
+
+        assert_eq!(table.push_row_with_id(vec![3, 4]), None);
+        assert_eq!(table.row_cnt(), 1);
+        assert_eq!(table.row_id(0), Some(RowId(0)));
+        assert_eq!(table.row_id(1), None);
+    }
+}
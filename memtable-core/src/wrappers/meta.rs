@@ -0,0 +1,673 @@
+use crate::{utils, Capacity, Position, SortOrder, Table};
+use core::{marker::PhantomData, mem};
+use std::{collections::BTreeMap, vec, vec::Vec};
+
+/// Represents a table that wraps another table, associating an optional
+/// metadata value (e.g. a style, a dirty flag, or provenance) with each cell
+///
+/// Metadata is stored separately from the cells and only for the cells that
+/// have it. It follows its cell whenever rows or columns are inserted or
+/// removed, and is dropped when its cell is removed
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MetaTable<T, M, U: Table<Data = T>> {
+    table: U,
+    meta: BTreeMap<Position, M>,
+    _data: PhantomData<T>,
+}
+
+impl<T, M, U: Table<Data = T>> MetaTable<T, M, U> {
+    /// Creates a new table wrapping the given table, initially without any
+    /// metadata
+    pub fn new(table: U) -> Self {
+        Self {
+            table,
+            meta: BTreeMap::new(),
+            _data: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the underlying table
+    pub fn as_inner(&self) -> &U {
+        &self.table
+    }
+
+    /// Consumes the wrapper, returning the underlying table and discarding
+    /// all metadata
+    pub fn into_inner(self) -> U {
+        self.table
+    }
+
+    /// Returns a reference to the metadata of the cell at the specified row
+    /// and column
+    pub fn meta(&self, row: usize, col: usize) -> Option<&M> {
+        self.meta.get(&Position { row, col })
+    }
+
+    /// Returns a mutable reference to the metadata of the cell at the
+    /// specified row and column
+    pub fn mut_meta(&mut self, row: usize, col: usize) -> Option<&mut M> {
+        self.meta.get_mut(&Position { row, col })
+    }
+
+    /// Assigns metadata to the cell at the specified row and column,
+    /// returning the metadata it previously had
+    pub fn set_meta(&mut self, row: usize, col: usize, meta: M) -> Option<M> {
+        self.meta.insert(Position { row, col }, meta)
+    }
+
+    /// Removes the metadata of the cell at the specified row and column,
+    /// returning it if it existed
+    pub fn remove_meta(&mut self, row: usize, col: usize) -> Option<M> {
+        self.meta.remove(&Position { row, col })
+    }
+
+    /// Removes the metadata of every cell
+    pub fn clear_meta(&mut self) {
+        self.meta.clear();
+    }
+
+    /// Returns an iterator through the positions and metadata of every cell
+    /// that has metadata, ordered row by row
+    pub fn metas(&self) -> impl Iterator<Item = (Position, &M)> + '_ {
+        self.meta.iter().map(|(pos, meta)| (*pos, meta))
+    }
+
+    /// Returns an iterator through every cell (searching row by row),
+    /// alongside its position and its metadata if it has any
+    pub fn cells_with_meta(&self) -> impl Iterator<Item = (Position, &T, Option<&M>)> + '_ {
+        let col_cnt = self.table.col_cnt();
+        (0..self.table.row_cnt())
+            .flat_map(move |row| (0..col_cnt).map(move |col| Position { row, col }))
+            .filter_map(move |pos| {
+                self.table
+                    .cell(pos.row, pos.col)
+                    .map(|x| (pos, x, self.meta.get(&pos)))
+            })
+    }
+
+    /// Moves metadata to new positions, dropping any that maps to `None`
+    fn remap_meta(&mut self, f: impl Fn(Position) -> Option<Position>) {
+        self.meta = mem::take(&mut self.meta)
+            .into_iter()
+            .filter_map(|(pos, meta)| f(pos).map(|pos| (pos, meta)))
+            .collect();
+    }
+
+    /// Moves the metadata of each row within the table's rows and columns
+    /// to the row at the same index in `new_rows`, dropping the metadata of
+    /// rows that map to `None`
+    fn remap_meta_rows(&mut self, new_rows: &[Option<usize>]) {
+        let col_cnt = self.table.col_cnt();
+        self.remap_meta(|pos| match new_rows.get(pos.row) {
+            Some(row) if pos.col < col_cnt => row.map(|row| Position::new(row, pos.col)),
+            _ => Some(pos),
+        });
+    }
+
+    /// Moves every cell and its metadata into a new table with the given
+    /// counts, where `f` maps the position of each cell to its new position
+    fn rearranged(
+        mut self,
+        row_cnt: usize,
+        col_cnt: usize,
+        f: impl Fn(Position) -> Option<Position>,
+    ) -> Self
+    where
+        Self: Default,
+    {
+        let mut new_table = Self::default();
+
+        for row in 0..self.table.row_cnt() {
+            for col in 0..self.table.col_cnt() {
+                let pos = Position::new(row, col);
+                let new_pos = match f(pos) {
+                    Some(x) => x,
+                    None => continue,
+                };
+
+                if let Some(x) = self.table.remove_cell(row, col) {
+                    new_table.table.insert_cell(new_pos.row, new_pos.col, x);
+                    if let Some(meta) = self.meta.remove(&pos) {
+                        if utils::within_capacity(&new_table.table, new_pos) {
+                            new_table.meta.insert(new_pos, meta);
+                        }
+                    }
+                }
+            }
+        }
+
+        new_table.set_preferred_row_cnt(row_cnt);
+        new_table.set_preferred_col_cnt(col_cnt);
+        new_table
+    }
+}
+
+impl<T, M, U: Table<Data = T> + Default> Default for MetaTable<T, M, U> {
+    fn default() -> Self {
+        Self::new(U::default())
+    }
+}
+
+impl<T, M, U: Table<Data = T>> Table for MetaTable<T, M, U> {
+    type Data = T;
+    type Row = U::Row;
+    type Column = U::Column;
+
+    fn max_row_capacity(&self) -> Capacity {
+        self.table.max_row_capacity()
+    }
+
+    fn max_column_capacity(&self) -> Capacity {
+        self.table.max_column_capacity()
+    }
+
+    fn row_cnt(&self) -> usize {
+        self.table.row_cnt()
+    }
+
+    fn col_cnt(&self) -> usize {
+        self.table.col_cnt()
+    }
+
+    fn set_preferred_row_cnt(&mut self, cnt: usize) {
+        self.table.set_preferred_row_cnt(cnt)
+    }
+
+    fn set_preferred_col_cnt(&mut self, cnt: usize) {
+        self.table.set_preferred_col_cnt(cnt)
+    }
+
+    /// Removes the cells outside of the current row & column counts along
+    /// with their metadata
+    fn truncate(&mut self) {
+        let row_cnt = self.table.row_cnt();
+        let col_cnt = self.table.col_cnt();
+        self.meta
+            .retain(|pos, _| pos.row < row_cnt && pos.col < col_cnt);
+        self.table.truncate()
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.table.shrink_to_fit()
+    }
+
+    fn cell(&self, row: usize, col: usize) -> Option<&Self::Data> {
+        self.table.cell(row, col)
+    }
+
+    fn mut_cell(&mut self, row: usize, col: usize) -> Option<&mut Self::Data> {
+        self.table.mut_cell(row, col)
+    }
+
+    /// Inserts the value into the cell, keeping any metadata the cell has
+    fn insert_cell(&mut self, row: usize, col: usize, value: Self::Data) -> Option<Self::Data> {
+        self.table.insert_cell(row, col, value)
+    }
+
+    /// Removes the cell along with its metadata
+    fn remove_cell(&mut self, row: usize, col: usize) -> Option<Self::Data> {
+        self.meta.remove(&Position { row, col });
+        self.table.remove_cell(row, col)
+    }
+
+    /// Inserts the row, shifting down the metadata of all rows after it
+    fn insert_row<I: IntoIterator<Item = Self::Data>>(&mut self, row: usize, cells: I) {
+        self.remap_meta(|pos| {
+            Some(if pos.row >= row {
+                Position::new(pos.row + 1, pos.col)
+            } else {
+                pos
+            })
+        });
+        self.table.insert_row(row, cells)
+    }
+
+    /// Inserts the rows, shifting down the metadata of all rows after them
+    fn insert_rows<I, R>(&mut self, row: usize, rows: I)
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = Self::Data>,
+    {
+        let rows: Vec<R> = rows.into_iter().collect();
+        let n = rows.len();
+        self.remap_meta(|pos| {
+            Some(if pos.row >= row {
+                Position::new(pos.row + n, pos.col)
+            } else {
+                pos
+            })
+        });
+        self.table.insert_rows(row, rows)
+    }
+
+    /// Removes the row along with its metadata, shifting up the metadata of
+    /// all rows after it
+    fn remove_row(&mut self, row: usize) -> Option<Self::Row> {
+        if row < self.row_cnt() {
+            self.remap_meta(|pos| match pos.row {
+                x if x == row => None,
+                x if x > row => Some(Position::new(x - 1, pos.col)),
+                _ => Some(pos),
+            });
+        }
+        self.table.remove_row(row)
+    }
+
+    /// Inserts the column, shifting right the metadata of all columns after it
+    fn insert_column<I: IntoIterator<Item = Self::Data>>(&mut self, col: usize, cells: I) {
+        self.remap_meta(|pos| {
+            Some(if pos.col >= col {
+                Position::new(pos.row, pos.col + 1)
+            } else {
+                pos
+            })
+        });
+        self.table.insert_column(col, cells)
+    }
+
+    /// Inserts the columns, shifting right the metadata of all columns after
+    /// them
+    fn insert_columns<I, C>(&mut self, col: usize, cols: I)
+    where
+        I: IntoIterator<Item = C>,
+        C: IntoIterator<Item = Self::Data>,
+    {
+        let cols: Vec<C> = cols.into_iter().collect();
+        let n = cols.len();
+        self.remap_meta(|pos| {
+            Some(if pos.col >= col {
+                Position::new(pos.row, pos.col + n)
+            } else {
+                pos
+            })
+        });
+        self.table.insert_columns(col, cols)
+    }
+
+    /// Removes the column along with its metadata, shifting left the metadata
+    /// of all columns after it
+    fn remove_column(&mut self, col: usize) -> Option<Self::Column> {
+        if col < self.col_cnt() {
+            self.remap_meta(|pos| match pos.col {
+                x if x == col => None,
+                x if x > col => Some(Position::new(pos.row, x - 1)),
+                _ => Some(pos),
+            });
+        }
+        self.table.remove_column(col)
+    }
+
+    /// Swaps the cells along with their metadata
+    fn swap_cells(&mut self, a: Position, b: Position) {
+        self.table.swap_cells(a, b);
+
+        let meta_a = self.meta.remove(&a);
+        let meta_b = self.meta.remove(&b);
+        if let Some(meta) = meta_a {
+            self.meta.insert(b, meta);
+        }
+        if let Some(meta) = meta_b {
+            self.meta.insert(a, meta);
+        }
+    }
+
+    /// Moves the cell along with its metadata, dropping the metadata of the
+    /// cell that it displaces
+    fn move_cell(&mut self, from: Position, to: Position) -> Option<Self::Data> {
+        let moves = from != to
+            && utils::within_capacity(&self.table, to)
+            && self.table.has_cell(from.row, from.col);
+
+        let displaced = self.table.move_cell(from, to);
+        if moves {
+            self.meta.remove(&to);
+            if let Some(meta) = self.meta.remove(&from) {
+                self.meta.insert(to, meta);
+            }
+        }
+
+        displaced
+    }
+
+    /// Shifts the rows along with their metadata, dropping the metadata of
+    /// rows shifted off of either end without wrapping around
+    fn shift_rows(&mut self, offset: isize, wrap: bool) {
+        let row_cnt = self.table.row_cnt();
+        self.table.shift_rows(offset, wrap);
+
+        if row_cnt > 0 {
+            let new_rows: Vec<Option<usize>> = (0..row_cnt)
+                .map(|row| utils::shifted_index(row, row_cnt, offset, wrap))
+                .collect();
+            self.remap_meta_rows(&new_rows);
+        }
+    }
+
+    /// Shifts the columns along with their metadata, dropping the metadata
+    /// of columns shifted off of either end without wrapping around
+    fn shift_columns(&mut self, offset: isize, wrap: bool) {
+        let row_cnt = self.table.row_cnt();
+        let col_cnt = self.table.col_cnt();
+        self.table.shift_columns(offset, wrap);
+
+        if col_cnt > 0 {
+            self.remap_meta(|pos| {
+                if pos.row < row_cnt && pos.col < col_cnt {
+                    utils::shifted_index(pos.col, col_cnt, offset, wrap)
+                        .map(|col| Position::new(pos.row, col))
+                } else {
+                    Some(pos)
+                }
+            });
+        }
+    }
+
+    /// Sorts the rows along with their metadata
+    fn sort_rows_by_columns_with<F>(&mut self, keys: &[(usize, SortOrder)], compare: F)
+    where
+        F: FnMut(&Self::Data, &Self::Data) -> core::cmp::Ordering,
+    {
+        let order = crate::sorted_row_order(&self.table, keys, compare);
+        crate::reorder_rows(&mut self.table, &order);
+
+        let mut new_rows = vec![None; order.len()];
+        for (row, old_row) in order.into_iter().enumerate() {
+            new_rows[old_row] = Some(row);
+        }
+        self.remap_meta_rows(&new_rows);
+    }
+
+    /// Removes duplicate rows along with their metadata, shifting up the
+    /// metadata of the rows that are kept
+    fn dedup_rows(&mut self) -> usize
+    where
+        Self::Data: core::hash::Hash + Eq,
+    {
+        let keep = crate::unique_rows(&self.table);
+        self.remap_meta_rows(&kept_rows(&keep));
+        crate::retain_rows(&mut self.table, &keep)
+    }
+
+    /// Removes rows with duplicate keys along with their metadata, shifting
+    /// up the metadata of the rows that are kept
+    fn dedup_rows_by_key(&mut self, col: usize) -> usize
+    where
+        Self::Data: core::hash::Hash + Eq,
+    {
+        let keep = crate::unique_rows_by_key(&self.table, col);
+        self.remap_meta_rows(&kept_rows(&keep));
+        crate::retain_rows(&mut self.table, &keep)
+    }
+
+    /// Selects the columns along with their metadata
+    fn into_selected_columns<C: AsRef<[usize]>>(self, cols: C) -> Self
+    where
+        Self: Default,
+    {
+        let row_cnt = self.table.row_cnt();
+        let cols = cols.as_ref();
+
+        // A column selected more than once only keeps its first occurrence,
+        // as its cells are moved rather than copied
+        self.rearranged(row_cnt, cols.len(), |pos| {
+            cols.iter()
+                .position(|col| *col == pos.col)
+                .map(|col| Position::new(pos.row, col))
+        })
+    }
+
+    /// Rotates the cells along with their metadata
+    fn rotate_cw(self) -> Self
+    where
+        Self: Default,
+    {
+        let row_cnt = self.table.row_cnt();
+        let col_cnt = self.table.col_cnt();
+        self.rearranged(col_cnt, row_cnt, |pos| {
+            Some(Position::new(pos.col, row_cnt - 1 - pos.row))
+        })
+    }
+
+    /// Rotates the cells along with their metadata
+    fn rotate_ccw(self) -> Self
+    where
+        Self: Default,
+    {
+        let row_cnt = self.table.row_cnt();
+        let col_cnt = self.table.col_cnt();
+        self.rearranged(col_cnt, row_cnt, |pos| {
+            Some(Position::new(col_cnt - 1 - pos.col, pos.row))
+        })
+    }
+}
+
+/// Maps every row flagged to be kept to its index once the other rows are
+/// removed
+fn kept_rows(keep: &[bool]) -> Vec<Option<usize>> {
+    let mut kept = 0;
+    keep.iter()
+        .map(|keep| {
+            if *keep {
+                kept += 1;
+                Some(kept - 1)
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DynamicTable;
+
+    #[test]
+    fn cells_with_meta_should_include_metadata_of_each_cell() {
+        let mut table = MetaTable::new(DynamicTable::from([[1, 2], [3, 4], [5, 6]]));
+        table.set_meta(0, 0, "first");
+        table.set_meta(1, 1, "bold");
+
+        let cells: Vec<(Position, usize, Option<&str>)> = table
+            .cells_with_meta()
+            .map(|(pos, x, meta)| (pos, *x, meta.copied()))
+            .collect();
+
+        assert_eq!(cells.len(), 6);
+        assert_eq!(cells[0], (Position::new(0, 0), 1, Some("first")));
+        assert_eq!(cells[1], (Position::new(0, 1), 2, None));
+        assert_eq!(cells[3], (Position::new(1, 1), 4, Some("bold")));
+    }
+
+    #[test]
+    fn remove_cell_should_drop_metadata_of_cell() {
+        let mut table = MetaTable::new(DynamicTable::from([[1, 2], [3, 4]]));
+        table.set_meta(1, 1, "bold");
+
+        assert_eq!(table.remove_cell(1, 1), Some(4));
+        assert_eq!(table.meta(1, 1), None);
+    }
+
+    #[test]
+    fn truncate_should_drop_metadata_of_cells_outside_of_counts() {
+        let mut table = MetaTable::new(DynamicTable::from([[1, 2], [3, 4]]));
+        table.set_meta(0, 1, "bold");
+        table.set_meta(1, 0, "italic");
+
+        table.set_preferred_row_cnt(1);
+        table.truncate();
+        table.set_preferred_row_cnt(2);
+
+        assert_eq!(table.meta(0, 1), Some(&"bold"));
+        assert_eq!(table.meta(1, 0), None);
+    }
+
+    #[test]
+    fn remove_row_should_shift_metadata_of_later_rows() {
+        let mut table = MetaTable::new(DynamicTable::from([[1, 2], [3, 4], [5, 6]]));
+        table.set_meta(0, 0, "first");
+        table.set_meta(1, 1, "bold");
+        table.set_meta(2, 0, "last");
+        table.remove_row(1);
+
+        assert_eq!(table.meta(0, 0), Some(&"first"));
+        assert_eq!(table.meta(1, 0), Some(&"last"));
+        assert_eq!(table.meta(1, 1), None);
+        assert_eq!(table.metas().count(), 2);
+    }
+
+    #[test]
+    fn insert_row_should_shift_metadata_of_later_rows() {
+        let mut table = MetaTable::new(DynamicTable::from([[1, 2], [3, 4], [5, 6]]));
+        table.set_meta(0, 0, "first");
+        table.set_meta(1, 1, "bold");
+        table.insert_row(1, [7, 8].iter().copied());
+
+        assert_eq!(table.cell(2, 1), Some(&4));
+        assert_eq!(table.meta(0, 0), Some(&"first"));
+        assert_eq!(table.meta(2, 1), Some(&"bold"));
+    }
+
+    #[test]
+    fn insert_columns_should_shift_metadata_of_later_columns() {
+        let mut table = MetaTable::new(DynamicTable::from([[1, 2], [3, 4]]));
+        table.set_meta(0, 0, "first");
+        table.set_meta(1, 1, "bold");
+        table.insert_columns(1, vec![vec![7, 8], vec![9, 10]]);
+
+        assert_eq!(table.cell(1, 3), Some(&4));
+        assert_eq!(table.meta(0, 0), Some(&"first"));
+        assert_eq!(table.meta(1, 3), Some(&"bold"));
+    }
+
+    #[test]
+    fn insert_and_remove_column_should_shift_metadata_of_later_columns() {
+        let mut table = MetaTable::new(DynamicTable::from([[1, 2], [3, 4]]));
+        table.set_meta(0, 0, "first");
+        table.set_meta(1, 1, "bold");
+
+        table.insert_column(0, [0, 0].iter().copied());
+        assert_eq!(table.meta(1, 2), Some(&"bold"));
+        assert_eq!(table.cell(1, 2), Some(&4));
+
+        table.remove_column(1);
+        assert_eq!(table.meta(0, 0), None);
+        assert_eq!(table.meta(1, 1), Some(&"bold"));
+        assert_eq!(table.cell(1, 1), Some(&4));
+    }
+
+    #[test]
+    fn sort_rows_by_columns_should_move_metadata_with_rows() {
+        let mut table = MetaTable::new(DynamicTable::from([[3, 30], [1, 10], [2, 20]]));
+        table.set_meta(0, 1, "thirty");
+        table.set_meta(1, 0, "one");
+
+        table.sort_rows_by_columns(&[(0, SortOrder::Ascending)]);
+        assert_eq!(table.as_inner(), &[[1, 10], [2, 20], [3, 30]]);
+        assert_eq!(table.meta(0, 0), Some(&"one"));
+        assert_eq!(table.meta(2, 1), Some(&"thirty"));
+        assert_eq!(table.metas().count(), 2);
+    }
+
+    #[test]
+    fn dedup_rows_should_drop_metadata_of_removed_rows_and_shift_the_rest() {
+        let mut table = MetaTable::new(DynamicTable::from([[1, 2], [1, 2], [3, 4]]));
+        table.set_meta(1, 0, "duplicate");
+        table.set_meta(2, 1, "last");
+
+        assert_eq!(table.dedup_rows(), 1);
+        assert_eq!(table.meta(1, 0), None);
+        assert_eq!(table.meta(1, 1), Some(&"last"));
+        assert_eq!(table.metas().count(), 1);
+    }
+
+    #[test]
+    fn dedup_rows_by_key_should_move_metadata_of_kept_rows() {
+        let mut table = MetaTable::new(DynamicTable::from([["a", "x"], ["a", "y"], ["b", "z"]]));
+        table.set_meta(2, 0, "b");
+
+        assert_eq!(table.dedup_rows_by_key(0), 1);
+        assert_eq!(table.meta(1, 0), Some(&"b"));
+    }
+
+    #[test]
+    fn swap_and_move_cell_should_carry_metadata() {
+        let mut table = MetaTable::new(DynamicTable::from([[1, 2], [3, 4]]));
+        table.set_meta(0, 0, "a");
+        table.set_meta(1, 1, "d");
+
+        table.swap_cells(Position::new(0, 0), Position::new(0, 1));
+        assert_eq!(table.meta(0, 0), None);
+        assert_eq!(table.meta(0, 1), Some(&"a"));
+
+        assert_eq!(
+            table.move_cell(Position::new(0, 1), Position::new(1, 1)),
+            Some(4)
+        );
+        assert_eq!(table.meta(0, 1), None);
+        assert_eq!(table.meta(1, 1), Some(&"a"));
+    }
+
+    #[test]
+    fn flip_and_shift_should_carry_metadata() {
+        let mut table = MetaTable::new(DynamicTable::from([[1, 2, 3], [4, 5, 6]]));
+        table.set_meta(0, 0, "a");
+
+        table.flip_horizontal();
+        assert_eq!(table.meta(0, 2), Some(&"a"));
+
+        table.flip_vertical();
+        assert_eq!(table.meta(1, 2), Some(&"a"));
+
+        table.shift_rows(1, true);
+        assert_eq!(table.meta(0, 2), Some(&"a"));
+
+        table.shift_columns(-1, false);
+        assert_eq!(table.meta(0, 1), Some(&"a"));
+
+        table.shift_columns(-2, false);
+        assert_eq!(table.metas().count(), 0);
+    }
+
+    #[test]
+    fn into_selected_columns_should_carry_metadata() {
+        let mut table = MetaTable::new(DynamicTable::from([["a", "b", "c"], ["d", "e", "f"]]));
+        table.set_meta(0, 0, "a");
+        table.set_meta(1, 1, "e");
+
+        let table = table.into_selected_columns([2, 0]);
+        assert_eq!(table.as_inner(), &[["c", "a"], ["f", "d"]]);
+        assert_eq!(table.meta(0, 1), Some(&"a"));
+        assert_eq!(table.metas().count(), 1);
+    }
+
+    #[test]
+    fn rotate_should_carry_metadata() {
+        let mut table = MetaTable::new(DynamicTable::from([[1, 2, 3], [4, 5, 6]]));
+        table.set_meta(0, 0, "one");
+
+        let table = table.rotate_cw();
+        assert_eq!(table.as_inner(), &[[4, 1], [5, 2], [6, 3]]);
+        assert_eq!(table.meta(0, 1), Some(&"one"));
+
+        let table = table.rotate_ccw();
+        assert_eq!(table.as_inner(), &[[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(table.meta(0, 0), Some(&"one"));
+    }
+
+    #[test]
+    fn set_block_should_keep_metadata_of_overwritten_cells() {
+        let mut table = MetaTable::new(DynamicTable::from([[1, 2], [3, 4]]));
+        table.set_meta(0, 0, "kept");
+        table.set_meta(0, 1, "removed");
+
+        let mut block = DynamicTable::new();
+        block.insert_cell(0, 0, 9);
+        block.set_preferred_col_cnt(2);
+
+        table.set_block(0, 0, &block).unwrap();
+        assert_eq!(table.cell(0, 0), Some(&9));
+        assert_eq!(table.meta(0, 0), Some(&"kept"));
+        assert_eq!(table.meta(0, 1), None);
+    }
+}
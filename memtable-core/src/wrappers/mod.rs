@@ -0,0 +1,29 @@
+mod audited;
+pub use audited::{AuditAction, AuditRecord, AuditedTable};
+
+mod compressed;
+pub use compressed::{ColumnCodec, CompressedTable, RunLengthCodec};
+
+mod frozen;
+pub use frozen::FrozenTable;
+
+mod header;
+pub use header::{HeaderError, HeaderTable};
+
+mod indexed;
+pub use indexed::IndexedTable;
+
+mod keyed;
+pub use keyed::{KeyedTable, RowId};
+
+mod meta;
+pub use meta::MetaTable;
+
+mod replicated;
+pub use replicated::{ReplicatedTable, ReplicationError, SequencedOp};
+
+/// Re-export of the operation shared by every table that records mutations
+pub use crate::ops::Op;
+
+mod validated;
+pub use validated::{ValidatedTable, Violation};
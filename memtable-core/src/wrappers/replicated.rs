@@ -0,0 +1,427 @@
+use crate::{ops::Op, Capacity, Table};
+use core::fmt;
+use std::vec::Vec;
+
+/// Represents an [`Op`] alongside its position within a table's operation log
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-1", derive(serde::Serialize, serde::Deserialize))]
+pub struct SequencedOp<T> {
+    /// Position of the operation within the log, starting at 0
+    pub seq: u64,
+
+    /// The operation itself
+    pub op: Op<T>,
+}
+
+/// Represents errors that can occur when applying operations to a
+/// [`ReplicatedTable`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ReplicationError {
+    /// Operation was not the next one in sequence, meaning that operations
+    /// were lost, reordered, or already applied
+    UnexpectedSeq {
+        /// Sequence number that the table expected next
+        expected: u64,
+        /// Sequence number of the operation that was received
+        actual: u64,
+    },
+}
+
+impl fmt::Display for ReplicationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedSeq { expected, actual } => write!(
+                f,
+                "Expected operation {}, but received operation {}",
+                expected, actual
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ReplicationError {}
+
+/// Represents a table that wraps another table, recording every mutation
+/// into an ordered log of operations that can be shipped elsewhere and
+/// applied to a replica to keep it in sync
+///
+/// Inserting, removing, and shifting rows and columns is recorded as a single
+/// operation, while every other mutation (such as sorting rows) is recorded
+/// as the cell insertions and removals it performs. As changes made through
+/// a mutable reference cannot be observed, [`Table::mut_cell`] always returns
+/// `None`; use [`Table::insert_cell`] to change a cell instead
+#[derive(Debug)]
+pub struct ReplicatedTable<T, U: Table<Data = T>> {
+    table: U,
+    log: Vec<SequencedOp<T>>,
+    next_seq: u64,
+}
+
+impl<T: Clone, U: Table<Data = T>> ReplicatedTable<T, U> {
+    /// Creates a new replicated table wrapping the given table, starting
+    /// with an empty log
+    ///
+    /// Any data already in the table is not part of the log, so a replica
+    /// must start from an identical table
+    pub fn new(table: U) -> Self {
+        Self {
+            table,
+            log: Vec::new(),
+            next_seq: 0,
+        }
+    }
+
+    /// Returns a reference to the underlying table
+    pub fn as_inner(&self) -> &U {
+        &self.table
+    }
+
+    /// Consumes the replicated table, returning the underlying table
+    pub fn into_inner(self) -> U {
+        self.table
+    }
+
+    /// Returns the sequence number that will be assigned to the next operation
+    pub fn next_seq(&self) -> u64 {
+        self.next_seq
+    }
+
+    /// Returns the operations currently held in the log
+    pub fn log(&self) -> &[SequencedOp<T>] {
+        &self.log
+    }
+
+    /// Returns the operations in the log with a sequence number of at least
+    /// `seq`, which is useful for catching up a replica that has already
+    /// applied everything before `seq`
+    pub fn ops_since(&self, seq: u64) -> &[SequencedOp<T>] {
+        let idx = self.log.iter().position(|x| x.seq >= seq);
+        &self.log[idx.unwrap_or(self.log.len())..]
+    }
+
+    /// Takes all operations out of the log, typically once they have been
+    /// shipped to replicas. Sequence numbers continue from where they left off
+    pub fn take_log(&mut self) -> Vec<SequencedOp<T>> {
+        core::mem::take(&mut self.log)
+    }
+
+    /// Applies an operation received from another replicated table, which
+    /// must be the next operation in sequence. The operation is also added to
+    /// this table's log so it can be forwarded further
+    pub fn apply(&mut self, op: SequencedOp<T>) -> Result<(), ReplicationError> {
+        if op.seq != self.next_seq {
+            return Err(ReplicationError::UnexpectedSeq {
+                expected: self.next_seq,
+                actual: op.seq,
+            });
+        }
+
+        op.op.clone().apply_to(&mut self.table);
+        self.log.push(op);
+        self.next_seq += 1;
+        Ok(())
+    }
+
+    /// Applies a stream of operations in order, stopping at the first one
+    /// that fails
+    pub fn apply_all<I: IntoIterator<Item = SequencedOp<T>>>(
+        &mut self,
+        ops: I,
+    ) -> Result<(), ReplicationError> {
+        for op in ops {
+            self.apply(op)?;
+        }
+
+        Ok(())
+    }
+
+    fn record(&mut self, op: Op<T>) {
+        self.log.push(SequencedOp {
+            seq: self.next_seq,
+            op,
+        });
+        self.next_seq += 1;
+    }
+}
+
+impl<T: Clone, U: Table<Data = T>> Table for ReplicatedTable<T, U> {
+    type Data = T;
+    type Row = U::Row;
+    type Column = U::Column;
+
+    fn max_row_capacity(&self) -> Capacity {
+        self.table.max_row_capacity()
+    }
+
+    fn max_column_capacity(&self) -> Capacity {
+        self.table.max_column_capacity()
+    }
+
+    fn row_cnt(&self) -> usize {
+        self.table.row_cnt()
+    }
+
+    fn col_cnt(&self) -> usize {
+        self.table.col_cnt()
+    }
+
+    fn set_preferred_row_cnt(&mut self, cnt: usize) {
+        self.table.set_preferred_row_cnt(cnt);
+        self.record(Op::SetPreferredRowCnt(cnt));
+    }
+
+    fn set_preferred_col_cnt(&mut self, cnt: usize) {
+        self.table.set_preferred_col_cnt(cnt);
+        self.record(Op::SetPreferredColCnt(cnt));
+    }
+
+    fn truncate(&mut self) {
+        self.table.truncate();
+        self.record(Op::Truncate);
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.table.shrink_to_fit();
+        self.record(Op::ShrinkToFit);
+    }
+
+    fn cell(&self, row: usize, col: usize) -> Option<&Self::Data> {
+        self.table.cell(row, col)
+    }
+
+    /// Always returns `None` as changes made through the reference could not
+    /// be recorded
+    fn mut_cell(&mut self, _row: usize, _col: usize) -> Option<&mut Self::Data> {
+        None
+    }
+
+    fn insert_cell(&mut self, row: usize, col: usize, value: Self::Data) -> Option<Self::Data> {
+        let old = self.table.insert_cell(row, col, value.clone());
+        self.record(Op::InsertCell { row, col, value });
+        old
+    }
+
+    fn remove_cell(&mut self, row: usize, col: usize) -> Option<Self::Data> {
+        let old = self.table.remove_cell(row, col);
+        self.record(Op::RemoveCell { row, col });
+        old
+    }
+
+    fn insert_row<I: IntoIterator<Item = Self::Data>>(&mut self, row: usize, cells: I) {
+        let cells: Vec<T> = cells.into_iter().collect();
+        self.table.insert_row(row, cells.iter().cloned());
+        self.record(Op::InsertRow { row, cells });
+    }
+
+    fn insert_rows<I, R>(&mut self, row: usize, rows: I)
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = Self::Data>,
+    {
+        for (i, cells) in rows.into_iter().enumerate() {
+            self.insert_row(row + i, cells);
+        }
+    }
+
+    fn remove_row(&mut self, row: usize) -> Option<Self::Row> {
+        let removed = self.table.remove_row(row);
+        if removed.is_some() {
+            self.record(Op::RemoveRow(row));
+        }
+        removed
+    }
+
+    fn insert_column<I: IntoIterator<Item = Self::Data>>(&mut self, col: usize, cells: I) {
+        let cells: Vec<T> = cells.into_iter().collect();
+        self.table.insert_column(col, cells.iter().cloned());
+        self.record(Op::InsertColumn { col, cells });
+    }
+
+    fn insert_columns<I, C>(&mut self, col: usize, cols: I)
+    where
+        I: IntoIterator<Item = C>,
+        C: IntoIterator<Item = Self::Data>,
+    {
+        for (i, cells) in cols.into_iter().enumerate() {
+            self.insert_column(col + i, cells);
+        }
+    }
+
+    fn remove_column(&mut self, col: usize) -> Option<Self::Column> {
+        let removed = self.table.remove_column(col);
+        if removed.is_some() {
+            self.record(Op::RemoveColumn(col));
+        }
+        removed
+    }
+
+    fn shift_rows(&mut self, offset: isize, wrap: bool) {
+        self.table.shift_rows(offset, wrap);
+        self.record(Op::ShiftRows { offset, wrap });
+    }
+
+    fn shift_columns(&mut self, offset: isize, wrap: bool) {
+        self.table.shift_columns(offset, wrap);
+        self.record(Op::ShiftColumns { offset, wrap });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DynamicTable;
+    use std::vec;
+
+    #[test]
+    fn insert_cell_should_record_sequenced_op() {
+        let mut table = ReplicatedTable::new(DynamicTable::new());
+        table.insert_cell(0, 0, 'a');
+        table.insert_cell(1, 2, 'b');
+
+        assert_eq!(
+            table.log(),
+            &[
+                SequencedOp {
+                    seq: 0,
+                    op: Op::InsertCell {
+                        row: 0,
+                        col: 0,
+                        value: 'a'
+                    },
+                },
+                SequencedOp {
+                    seq: 1,
+                    op: Op::InsertCell {
+                        row: 1,
+                        col: 2,
+                        value: 'b'
+                    },
+                },
+            ]
+        );
+        assert_eq!(table.next_seq(), 2);
+    }
+
+    #[test]
+    fn remove_cell_should_record_sequenced_op() {
+        let mut table = ReplicatedTable::new(DynamicTable::from([[1, 2]]));
+        assert_eq!(table.remove_cell(0, 1), Some(2));
+        assert_eq!(
+            table.log(),
+            &[SequencedOp {
+                seq: 0,
+                op: Op::RemoveCell { row: 0, col: 1 },
+            }]
+        );
+    }
+
+    #[test]
+    fn structural_changes_should_be_recorded_as_single_ops() {
+        let mut table = ReplicatedTable::new(DynamicTable::new());
+        table.push_row(vec![1, 2]);
+        table.insert_column(0, vec![3]);
+        table.shift_rows(1, true);
+        table.remove_row(0);
+        table.remove_row(5);
+
+        let ops: Vec<Op<usize>> = table.take_log().into_iter().map(|x| x.op).collect();
+        assert_eq!(
+            ops,
+            vec![
+                Op::InsertRow {
+                    row: 0,
+                    cells: vec![1, 2],
+                },
+                Op::InsertColumn {
+                    col: 0,
+                    cells: vec![3],
+                },
+                Op::ShiftRows {
+                    offset: 1,
+                    wrap: true,
+                },
+                Op::RemoveRow(0),
+            ]
+        );
+    }
+
+    #[test]
+    fn mut_cell_should_always_return_none() {
+        let mut table = ReplicatedTable::new(DynamicTable::from([[1, 2]]));
+        assert_eq!(table.mut_cell(0, 0), None);
+    }
+
+    #[test]
+    fn ops_since_should_return_ops_starting_at_seq() {
+        let mut table = ReplicatedTable::new(DynamicTable::new());
+        for col in 0..4 {
+            table.insert_cell(0, col, col);
+        }
+
+        let seqs: Vec<u64> = table.ops_since(2).iter().map(|x| x.seq).collect();
+        assert_eq!(seqs, vec![2, 3]);
+        assert!(table.ops_since(100).is_empty());
+    }
+
+    #[test]
+    fn take_log_should_empty_log_but_keep_sequence() {
+        let mut table = ReplicatedTable::new(DynamicTable::new());
+        table.insert_cell(0, 0, 1);
+        assert_eq!(table.take_log().len(), 1);
+        assert!(table.log().is_empty());
+
+        table.insert_cell(0, 1, 2);
+        assert_eq!(table.log()[0].seq, 1);
+    }
+
+    #[test]
+    fn apply_all_should_bring_replica_in_sync_with_primary() {
+        let mut primary = ReplicatedTable::new(DynamicTable::new());
+        let mut replica = ReplicatedTable::new(DynamicTable::new());
+
+        primary.push_row(vec![1, 2, 3]);
+        primary.push_row(vec![4, 5, 6]);
+        primary.remove_column(1);
+        primary.insert_cell(0, 0, 10);
+        primary.shift_columns(-1, false);
+
+        replica.apply_all(primary.take_log()).unwrap();
+        assert_eq!(replica.as_inner(), primary.as_inner());
+        assert_eq!(replica.next_seq(), primary.next_seq());
+    }
+
+    #[test]
+    fn truncate_and_shrink_to_fit_should_be_replayed_on_replica() {
+        let mut primary = ReplicatedTable::new(DynamicTable::from([[1, 2], [3, 4]]));
+        let mut replica = ReplicatedTable::new(DynamicTable::from([[1, 2], [3, 4]]));
+
+        primary.set_preferred_col_cnt(1);
+        primary.truncate();
+        primary.set_preferred_col_cnt(3);
+        primary.shrink_to_fit();
+
+        replica.apply_all(primary.take_log()).unwrap();
+        assert_eq!(replica.as_inner(), &[[1], [3]]);
+        assert_eq!(replica.as_inner(), primary.as_inner());
+    }
+
+    #[test]
+    fn apply_should_fail_if_op_out_of_sequence() {
+        let mut primary = ReplicatedTable::new(DynamicTable::new());
+        let mut replica = ReplicatedTable::new(DynamicTable::new());
+
+        primary.insert_cell(0, 0, 1);
+        primary.insert_cell(0, 1, 2);
+        let mut log = primary.take_log();
+
+        assert_eq!(
+            replica.apply(log.pop().unwrap()),
+            Err(ReplicationError::UnexpectedSeq {
+                expected: 0,
+                actual: 1
+            })
+        );
+        assert!(replica.as_inner().is_empty());
+    }
+}
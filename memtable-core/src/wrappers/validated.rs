@@ -0,0 +1,545 @@
+use crate::{utils, Capacity, Position, Table};
+use core::{fmt, iter, mem};
+use std::{boxed::Box, collections::BTreeMap, string::String, vec::Vec};
+
+type Validator<T, E> = Box<dyn Fn(&T) -> Result<(), E>>;
+type Normalizer<T> = Box<dyn Fn(T) -> T>;
+
+/// Represents a value that was rejected when inserted into a [`ValidatedTable`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Violation<E> {
+    /// Position where the value would have been inserted
+    pub position: Position,
+
+    /// Error produced by the column's validator
+    pub error: E,
+}
+
+/// Represents a table that wraps another table, normalizing and validating
+/// every value inserted into a column that has a normalizer or validator
+///
+/// Values that fail validation are not inserted; instead, a [`Violation`]
+/// is recorded and can be inspected or taken later. This means that methods
+/// like [`Table::push_row`] still succeed, but leave out any rejected cells
+///
+/// Validators and normalizers follow their columns when columns are
+/// inserted, removed, shifted, or flipped. Only new values are validated:
+/// cells already in the table are moved as-is when rows or columns are
+/// inserted, removed, or shifted, and
+/// [`Table::mut_cell`] always returns `None` so that no value can be changed
+/// without passing through validation
+pub struct ValidatedTable<T, U: Table<Data = T>, E = String> {
+    table: U,
+    validators: BTreeMap<usize, Validator<T, E>>,
+    normalizers: BTreeMap<usize, Normalizer<T>>,
+    violations: Vec<Violation<E>>,
+}
+
+impl<T, U: Table<Data = T>, E> ValidatedTable<T, U, E> {
+    /// Creates a new validated table wrapping the given table, initially
+    /// without any validators or normalizers
+    pub fn new(table: U) -> Self {
+        Self {
+            table,
+            validators: BTreeMap::new(),
+            normalizers: BTreeMap::new(),
+            violations: Vec::new(),
+        }
+    }
+
+    /// Assigns a validator for the specified column, replacing any existing
+    /// validator for that column
+    pub fn with_validator(mut self, col: usize, f: impl Fn(&T) -> Result<(), E> + 'static) -> Self {
+        self.validators.insert(col, Box::new(f));
+        self
+    }
+
+    /// Assigns a normalizer for the specified column, replacing any existing
+    /// normalizer for that column. Normalizers are applied before validators
+    pub fn with_normalizer(mut self, col: usize, f: impl Fn(T) -> T + 'static) -> Self {
+        self.normalizers.insert(col, Box::new(f));
+        self
+    }
+
+    /// Moves the validator & normalizer of each column to the column given
+    /// by `f`, dropping those of columns that map to `None`
+    fn remap_columns(&mut self, f: impl Fn(usize) -> Option<usize>) {
+        self.validators = mem::take(&mut self.validators)
+            .into_iter()
+            .filter_map(|(col, x)| f(col).map(|col| (col, x)))
+            .collect();
+        self.normalizers = mem::take(&mut self.normalizers)
+            .into_iter()
+            .filter_map(|(col, x)| f(col).map(|col| (col, x)))
+            .collect();
+    }
+
+    /// Returns a reference to the underlying table
+    pub fn as_inner(&self) -> &U {
+        &self.table
+    }
+
+    /// Consumes the validated table, returning the underlying table
+    pub fn into_inner(self) -> U {
+        self.table
+    }
+
+    /// Normalizes and validates the value for the given position, inserting
+    /// it if valid and otherwise returning the validation error without
+    /// recording a violation
+    pub fn try_insert_cell(&mut self, row: usize, col: usize, value: T) -> Result<Option<T>, E> {
+        let value = self.check(col, value)?;
+        Ok(self.table.insert_cell(row, col, value))
+    }
+
+    /// Normalizes and validates the value for the given column
+    fn check(&self, col: usize, value: T) -> Result<T, E> {
+        let value = match self.normalizers.get(&col) {
+            Some(f) => f(value),
+            None => value,
+        };
+
+        if let Some(f) = self.validators.get(&col) {
+            f(&value)?;
+        }
+
+        Ok(value)
+    }
+
+    /// Normalizes and validates the value for the given position, recording
+    /// a violation and returning `None` if it is rejected
+    fn check_or_record(&mut self, row: usize, col: usize, value: T) -> Option<T> {
+        match self.check(col, value) {
+            Ok(x) => Some(x),
+            Err(error) => {
+                self.violations.push(Violation {
+                    position: Position { row, col },
+                    error,
+                });
+                None
+            }
+        }
+    }
+
+    /// Returns true if any violations have been recorded
+    pub fn has_violations(&self) -> bool {
+        !self.violations.is_empty()
+    }
+
+    /// Returns the violations recorded so far
+    pub fn violations(&self) -> &[Violation<E>] {
+        &self.violations
+    }
+
+    /// Clears all recorded violations
+    pub fn clear_violations(&mut self) {
+        self.violations.clear();
+    }
+
+    /// Takes all recorded violations, leaving none behind
+    pub fn take_violations(&mut self) -> Vec<Violation<E>> {
+        core::mem::take(&mut self.violations)
+    }
+}
+
+impl<T, U: Table<Data = T> + fmt::Debug, E: fmt::Debug> fmt::Debug for ValidatedTable<T, U, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ValidatedTable")
+            .field("table", &self.table)
+            .field("validated_columns", &self.validators.keys())
+            .field("normalized_columns", &self.normalizers.keys())
+            .field("violations", &self.violations)
+            .finish()
+    }
+}
+
+impl<T, U: Table<Data = T>, E> Table for ValidatedTable<T, U, E> {
+    type Data = T;
+    type Row = U::Row;
+    type Column = U::Column;
+
+    fn max_row_capacity(&self) -> Capacity {
+        self.table.max_row_capacity()
+    }
+
+    fn max_column_capacity(&self) -> Capacity {
+        self.table.max_column_capacity()
+    }
+
+    fn row_cnt(&self) -> usize {
+        self.table.row_cnt()
+    }
+
+    fn col_cnt(&self) -> usize {
+        self.table.col_cnt()
+    }
+
+    fn set_preferred_row_cnt(&mut self, cnt: usize) {
+        self.table.set_preferred_row_cnt(cnt)
+    }
+
+    fn set_preferred_col_cnt(&mut self, cnt: usize) {
+        self.table.set_preferred_col_cnt(cnt)
+    }
+
+    fn truncate(&mut self) {
+        self.table.truncate()
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.table.shrink_to_fit()
+    }
+
+    fn cell(&self, row: usize, col: usize) -> Option<&Self::Data> {
+        self.table.cell(row, col)
+    }
+
+    /// Always returns `None`, as a value changed in place would skip
+    /// normalization and validation
+    fn mut_cell(&mut self, _row: usize, _col: usize) -> Option<&mut Self::Data> {
+        None
+    }
+
+    fn insert_cell(&mut self, row: usize, col: usize, value: Self::Data) -> Option<Self::Data> {
+        let value = self.check_or_record(row, col, value)?;
+        self.table.insert_cell(row, col, value)
+    }
+
+    fn remove_cell(&mut self, row: usize, col: usize) -> Option<Self::Data> {
+        self.table.remove_cell(row, col)
+    }
+
+    /// Validates the cells of the new row, shifting down the rows after it
+    /// without validating them again
+    fn insert_row<I: IntoIterator<Item = Self::Data>>(&mut self, row: usize, cells: I) {
+        self.insert_rows(row, iter::once(cells))
+    }
+
+    /// Validates the cells of the new rows, shifting down the rows after them
+    /// without validating them again
+    fn insert_rows<I, R>(&mut self, row: usize, rows: I)
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = Self::Data>,
+    {
+        let rows: Vec<Vec<Option<T>>> = rows
+            .into_iter()
+            .enumerate()
+            .map(|(i, cells)| {
+                cells
+                    .into_iter()
+                    .enumerate()
+                    .map(|(col, x)| self.check_or_record(row + i, col, x))
+                    .collect()
+            })
+            .collect();
+
+        self.table
+            .insert_rows(row, rows.iter().map(|_| iter::empty()));
+        for (i, cells) in rows.into_iter().enumerate() {
+            for (col, x) in cells.into_iter().enumerate() {
+                if let Some(x) = x {
+                    self.table.insert_cell(row + i, col, x);
+                }
+            }
+        }
+    }
+
+    fn remove_row(&mut self, row: usize) -> Option<Self::Row> {
+        self.table.remove_row(row)
+    }
+
+    /// Validates the cells of the new column, shifting right the columns
+    /// after it without validating them again
+    fn insert_column<I: IntoIterator<Item = Self::Data>>(&mut self, col: usize, cells: I) {
+        self.insert_columns(col, iter::once(cells))
+    }
+
+    /// Validates the cells of the new columns, shifting right the columns
+    /// after them, along with their validators & normalizers, without
+    /// validating them again
+    fn insert_columns<I, C>(&mut self, col: usize, cols: I)
+    where
+        I: IntoIterator<Item = C>,
+        C: IntoIterator<Item = Self::Data>,
+    {
+        let cols: Vec<C> = cols.into_iter().collect();
+        let n = cols.len();
+        self.remap_columns(|x| Some(if x >= col { x + n } else { x }));
+
+        let cols: Vec<Vec<Option<T>>> = cols
+            .into_iter()
+            .enumerate()
+            .map(|(i, cells)| {
+                cells
+                    .into_iter()
+                    .enumerate()
+                    .map(|(row, x)| self.check_or_record(row, col + i, x))
+                    .collect()
+            })
+            .collect();
+
+        self.table
+            .insert_columns(col, cols.iter().map(|_| iter::empty()));
+        for (i, cells) in cols.into_iter().enumerate() {
+            for (row, x) in cells.into_iter().enumerate() {
+                if let Some(x) = x {
+                    self.table.insert_cell(row, col + i, x);
+                }
+            }
+        }
+    }
+
+    /// Removes the column along with its validator & normalizer, shifting
+    /// left those of all columns after it
+    fn remove_column(&mut self, col: usize) -> Option<Self::Column> {
+        if col < self.col_cnt() {
+            self.remap_columns(|x| match x {
+                x if x == col => None,
+                x if x > col => Some(x - 1),
+                x => Some(x),
+            });
+        }
+        self.table.remove_column(col)
+    }
+
+    fn shift_rows(&mut self, offset: isize, wrap: bool) {
+        self.table.shift_rows(offset, wrap)
+    }
+
+    /// Shifts the columns along with their validators & normalizers,
+    /// dropping those of columns shifted off of either end without wrapping
+    /// around
+    fn shift_columns(&mut self, offset: isize, wrap: bool) {
+        let col_cnt = self.table.col_cnt();
+        self.table.shift_columns(offset, wrap);
+
+        if col_cnt > 0 {
+            self.remap_columns(|x| {
+                if x < col_cnt {
+                    utils::shifted_index(x, col_cnt, offset, wrap)
+                } else {
+                    Some(x)
+                }
+            });
+        }
+    }
+
+    /// Flips the columns along with their validators & normalizers
+    fn flip_horizontal(&mut self) {
+        let col_cnt = self.table.col_cnt();
+        self.table.flip_horizontal();
+        self.remap_columns(|x| Some(if x < col_cnt { col_cnt - 1 - x } else { x }));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DynamicTable;
+    use std::{string::ToString, vec};
+
+    #[test]
+    fn insert_cell_should_normalize_value_before_inserting() {
+        let mut table = ValidatedTable::<_, _, String>::new(DynamicTable::new())
+            .with_normalizer(0, |s: String| s.trim().to_string());
+
+        table.insert_cell(0, 0, "  alice ".to_string());
+        assert_eq!(table.cell(0, 0).map(String::as_str), Some("alice"));
+        assert!(!table.has_violations());
+    }
+
+    #[test]
+    fn insert_cell_should_record_violation_and_skip_invalid_value() {
+        let mut table = ValidatedTable::new(DynamicTable::new()).with_validator(1, |s: &String| {
+            s.parse::<u8>().map(|_| ()).map_err(|x| x.to_string())
+        });
+
+        assert_eq!(table.insert_cell(0, 1, "abc".to_string()), None);
+        assert!(!table.has_cell(0, 1));
+        assert_eq!(
+            table.violations(),
+            &[Violation {
+                position: Position { row: 0, col: 1 },
+                error: "invalid digit found in string".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn insert_cell_should_apply_validator_after_normalizer() {
+        let mut table = ValidatedTable::new(DynamicTable::new())
+            .with_normalizer(0, |s: String| s.trim().to_string())
+            .with_validator(0, |s: &String| {
+                if s.is_empty() {
+                    Err("name is empty".to_string())
+                } else {
+                    Ok(())
+                }
+            });
+
+        table.insert_cell(0, 0, "   ".to_string());
+        assert!(!table.has_cell(0, 0));
+        assert_eq!(table.violations()[0].error, "name is empty");
+    }
+
+    #[test]
+    fn insert_cell_should_accept_anything_in_unvalidated_columns() {
+        let mut table = ValidatedTable::new(DynamicTable::new()).with_validator(1, |s: &String| {
+            s.parse::<u8>().map(|_| ()).map_err(|x| x.to_string())
+        });
+
+        table.insert_cell(0, 2, "".to_string());
+        assert_eq!(table.cell(0, 2).map(String::as_str), Some(""));
+        assert!(!table.has_violations());
+    }
+
+    #[test]
+    fn push_row_should_insert_valid_cells_and_record_violations_for_others() {
+        let mut table = ValidatedTable::new(DynamicTable::new()).with_validator(1, |s: &String| {
+            s.parse::<u8>().map(|_| ()).map_err(|x| x.to_string())
+        });
+        table.push_row(vec!["bob".to_string(), "300".to_string()]);
+
+        assert_eq!(table.cell(0, 0).map(String::as_str), Some("bob"));
+        assert!(!table.has_cell(0, 1));
+
+        let violations = table.take_violations();
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].position, Position { row: 0, col: 1 });
+        assert!(!table.has_violations());
+    }
+
+    #[test]
+    fn insert_row_should_only_validate_cells_of_new_row() {
+        let mut table = ValidatedTable::new(DynamicTable::new())
+            .with_normalizer(0, |s: String| s.to_uppercase())
+            .with_validator(1, |s: &String| {
+                s.parse::<u8>().map(|_| ()).map_err(|x| x.to_string())
+            });
+        table.push_row(vec!["a".to_string(), "1".to_string()]);
+        table.insert_row(0, vec!["b".to_string(), "x".to_string()]);
+
+        assert_eq!(table.row_cnt(), 2);
+        assert_eq!(table.cell(0, 0).map(String::as_str), Some("B"));
+        assert!(!table.has_cell(0, 1));
+        assert_eq!(table.cell(1, 0).map(String::as_str), Some("A"));
+        assert_eq!(table.cell(1, 1).map(String::as_str), Some("1"));
+        assert_eq!(table.violations()[0].position, Position { row: 0, col: 1 });
+    }
+
+    #[test]
+    fn remove_column_should_move_validator_with_its_column() {
+        let mut table = ValidatedTable::new(DynamicTable::new()).with_validator(1, |s: &String| {
+            s.parse::<u8>().map(|_| ()).map_err(|x| x.to_string())
+        });
+        table.push_row(vec!["1".to_string(), "2".to_string(), "abc".to_string()]);
+        table.remove_column(0);
+
+        assert_eq!(table.cell(0, 0).map(String::as_str), Some("2"));
+        assert_eq!(table.cell(0, 1).map(String::as_str), Some("abc"));
+        assert!(!table.has_violations());
+
+        table.insert_cell(0, 0, "xyz".to_string());
+        table.insert_cell(0, 1, "def".to_string());
+        assert_eq!(table.cell(0, 0).map(String::as_str), Some("2"));
+        assert_eq!(table.cell(0, 1).map(String::as_str), Some("def"));
+        assert_eq!(table.violations().len(), 1);
+        assert_eq!(table.violations()[0].position, Position { row: 0, col: 0 });
+    }
+
+    #[test]
+    fn remove_column_should_drop_validator_of_removed_column() {
+        let mut table = ValidatedTable::new(DynamicTable::new()).with_validator(0, |s: &String| {
+            s.parse::<u8>().map(|_| ()).map_err(|x| x.to_string())
+        });
+        table.push_row(vec!["1".to_string(), "abc".to_string()]);
+        table.remove_column(0);
+
+        table.insert_cell(0, 0, "def".to_string());
+        assert_eq!(table.cell(0, 0).map(String::as_str), Some("def"));
+        assert!(!table.has_violations());
+    }
+
+    #[test]
+    fn insert_column_should_move_validator_with_its_column() {
+        let mut table = ValidatedTable::new(DynamicTable::new())
+            .with_normalizer(0, |s: String| s.to_uppercase())
+            .with_validator(0, |s: &String| {
+                s.parse::<u8>().map(|_| ()).map_err(|x| x.to_string())
+            });
+        table.push_row(vec!["1".to_string()]);
+        table.insert_column(0, vec!["abc".to_string()]);
+
+        assert_eq!(table.cell(0, 0).map(String::as_str), Some("abc"));
+        assert_eq!(table.cell(0, 1).map(String::as_str), Some("1"));
+        assert!(!table.has_violations());
+
+        table.insert_cell(0, 1, "x".to_string());
+        assert_eq!(table.violations()[0].position, Position { row: 0, col: 1 });
+    }
+
+    #[test]
+    fn shift_columns_should_move_cells_without_validating_them() {
+        let mut table = ValidatedTable::new(DynamicTable::new()).with_validator(0, |s: &String| {
+            s.parse::<u8>().map(|_| ()).map_err(|x| x.to_string())
+        });
+        table.push_row(vec!["1".to_string(), "abc".to_string()]);
+        table.shift_columns(1, true);
+
+        assert_eq!(table.cell(0, 0).map(String::as_str), Some("abc"));
+        assert_eq!(table.cell(0, 1).map(String::as_str), Some("1"));
+        assert!(!table.has_violations());
+    }
+
+    #[test]
+    fn shift_columns_should_move_validator_with_its_column() {
+        let mut table = ValidatedTable::new(DynamicTable::new()).with_validator(0, |s: &String| {
+            s.parse::<u8>().map(|_| ()).map_err(|x| x.to_string())
+        });
+        table.push_row(vec!["1".to_string(), "abc".to_string()]);
+        table.shift_columns(1, true);
+
+        table.insert_cell(0, 0, "def".to_string());
+        table.insert_cell(0, 1, "x".to_string());
+        assert_eq!(table.cell(0, 0).map(String::as_str), Some("def"));
+        assert_eq!(table.cell(0, 1).map(String::as_str), Some("1"));
+        assert_eq!(table.violations()[0].position, Position { row: 0, col: 1 });
+
+        table.shift_columns(1, false);
+        table.insert_cell(0, 1, "y".to_string());
+        assert_eq!(table.cell(0, 1).map(String::as_str), Some("y"));
+        assert_eq!(table.violations().len(), 1);
+    }
+
+    #[test]
+    fn flip_horizontal_should_move_validator_with_its_column() {
+        let mut table = ValidatedTable::new(DynamicTable::new()).with_validator(0, |s: &String| {
+            s.parse::<u8>().map(|_| ()).map_err(|x| x.to_string())
+        });
+        table.push_row(vec!["1".to_string(), "a".to_string(), "b".to_string()]);
+        table.flip_horizontal();
+
+        table.insert_cell(0, 0, "c".to_string());
+        table.insert_cell(0, 2, "x".to_string());
+        assert_eq!(table.cell(0, 0).map(String::as_str), Some("c"));
+        assert_eq!(table.cell(0, 2).map(String::as_str), Some("1"));
+        assert_eq!(table.violations()[0].position, Position { row: 0, col: 2 });
+    }
+
+    #[test]
+    fn mut_cell_should_always_return_none() {
+        let mut table = ValidatedTable::<_, _, String>::new(DynamicTable::from([["a"]]));
+        assert!(table.mut_cell(0, 0).is_none());
+    }
+
+    #[test]
+    fn try_insert_cell_should_return_error_without_recording_violation() {
+        let mut table = ValidatedTable::new(DynamicTable::new()).with_validator(1, |s: &String| {
+            s.parse::<u8>().map(|_| ()).map_err(|x| x.to_string())
+        });
+        assert!(table.try_insert_cell(0, 1, "-1".to_string()).is_err());
+        assert!(!table.has_violations());
+        assert_eq!(table.try_insert_cell(0, 1, "42".to_string()), Ok(None));
+    }
+}
@@ -1,15 +1,17 @@
 use super::{utils, TableColumn, TableMode};
 use darling::{ast::Style, util::PathList};
 use quote::quote;
-use syn::{parse_quote, Generics, Ident, ItemEnum, ItemImpl, Visibility};
+use syn::{parse_quote, Generics, Ident, ItemEnum, ItemImpl, Path, Type, Visibility};
 
 pub struct Args<'a> {
-    pub mode: TableMode,
+    pub root: &'a Path,
+    pub mode: &'a TableMode,
     pub style: Style,
     pub vis: &'a Visibility,
     pub table_data_name: &'a Ident,
     pub generics: &'a Generics,
     pub derive: Option<&'a PathList>,
+    pub skip_auto_derive: bool,
     pub columns: &'a [&'a TableColumn],
 }
 
@@ -17,22 +19,25 @@ pub struct Return {
     pub definition: ItemEnum,
     pub core_impl: ItemImpl,
     pub default_impl: Option<ItemImpl>,
+    pub auto_derive_impls: Vec<ItemImpl>,
 }
 
 pub fn make(args: Args) -> Return {
     let Args {
+        root,
         mode,
         style,
         vis,
         table_data_name,
         generics,
         derive,
+        skip_auto_derive,
         columns,
     } = args;
 
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
     let variant = utils::make_variant_idents(columns);
-    let variant_ty = utils::make_variant_types(columns);
+    let variant_ty = utils::make_data_types(root, columns);
     let utils::VariantMethodIdents {
         is_variant,
         as_variant,
@@ -45,46 +50,74 @@ pub fn make(args: Args) -> Return {
         .filter(|list| !list.is_empty())
         .map(|list| quote!(#[derive(#(#list),*)]));
 
+    // When the data is deserializable, variants of renamed columns continue
+    // to accept the variant name they had prior to being renamed
+    let is_deserialize = derive
+        .into_iter()
+        .flat_map(|list| list.iter())
+        .filter_map(|path| path.segments.last())
+        .any(|segment| segment.ident == "Deserialize");
+    let variant_attr = utils::make_renamed_variant_idents(columns)
+        .into_iter()
+        .map(|old_variant| match old_variant {
+            Some(old_variant) if is_deserialize => {
+                let old_variant = old_variant.to_string();
+                quote!(#[serde(alias = #old_variant)])
+            }
+            _ => quote!(),
+        });
+
     let definition = parse_quote! {
         #[automatically_derived]
+        #[allow(dead_code)]
         #derive_attr
         #vis enum #table_data_name #impl_generics #where_clause {
-            #(#variant(#variant_ty)),*
+            #(#variant_attr #variant(#variant_ty)),*
         }
     };
 
-    // All modes other than dynamic require the data to implement default,
-    // which we do by hand-crafting an impl (can't derive on enum).
+    // Fixed modes require the data to implement default, which we do by
+    // hand-crafting an impl (can't derive on enum). Custom tables are left
+    // to the user since we cannot know what they require.
     //
     // TODO: By default, we'll attempt to use the first variant's value as the
     //       default; however, we should support letting the user choose the
     //       variant via an attribute on the column
-    let default_impl: Option<ItemImpl> = if !matches!(mode, TableMode::Dynamic) {
-        let body = if variant.is_empty() {
-            quote!(::core::compile_error!("At least one field is required!"))
-        } else {
-            let name = &variant[0];
-            let ty = &variant_ty[0];
-            quote!(Self::#name(<#ty as ::core::default::Default>::default()))
-        };
+    let default_impl: Option<ItemImpl> =
+        if matches!(mode, TableMode::FixedColumn | TableMode::Fixed { .. }) {
+            let body = if variant.is_empty() {
+                quote!(::core::compile_error!("At least one field is required!"))
+            } else {
+                let name = &variant[0];
+                let ty = &variant_ty[0];
+                quote!(Self::#name(<#ty as ::core::default::Default>::default()))
+            };
 
-        Some(parse_quote! {
-            #[automatically_derived]
-            impl #impl_generics ::core::default::Default
-                for #table_data_name #ty_generics #where_clause
-            {
-                fn default() -> Self {
-                    #body
+            Some(parse_quote! {
+                #[automatically_derived]
+                impl #impl_generics ::core::default::Default
+                    for #table_data_name #ty_generics #where_clause
+                {
+                    fn default() -> Self {
+                        #body
+                    }
                 }
-            }
-        })
-    } else {
-        None
-    };
+            })
+        } else {
+            None
+        };
 
+    let variant_name = variant.iter().map(ToString::to_string);
     let core_impl = parse_quote! {
         #[automatically_derived]
         impl #impl_generics #table_data_name #ty_generics #where_clause {
+            /// Returns the name of the variant held by the data
+            pub fn variant_name(&self) -> &'static ::core::primitive::str {
+                match *self {
+                    #(Self::#variant(_) => #variant_name,)*
+                }
+            }
+
             #(
                 pub fn #is_variant(&self) -> ::core::primitive::bool {
                     match self {
@@ -117,9 +150,155 @@ pub fn make(args: Args) -> Return {
         }
     };
 
+    // Traits that are explicitly derived are left alone so as not to
+    // conflict with the derived impls
+    let is_derived = |name: &str| {
+        derive
+            .into_iter()
+            .flat_map(|list| list.iter())
+            .filter_map(|path| path.segments.last())
+            .any(|segment| segment.ident == name)
+    };
+    let mut auto_derive_impls = Vec::new();
+    if !skip_auto_derive {
+        let auto_args = AutoDeriveArgs {
+            table_data_name,
+            generics,
+            variant: &variant,
+            variant_ty: &variant_ty,
+        };
+
+        if !is_derived("Clone") {
+            auto_derive_impls.push(make_clone_impl(&auto_args));
+        }
+        if !is_derived("PartialEq") {
+            auto_derive_impls.push(make_partial_eq_impl(&auto_args));
+        }
+        if !is_derived("Eq") {
+            auto_derive_impls.push(make_eq_impl(&auto_args));
+        }
+        if !is_derived("Hash") {
+            auto_derive_impls.push(make_hash_impl(&auto_args));
+        }
+    }
+
     Return {
         definition,
         core_impl,
         default_impl,
+        auto_derive_impls,
+    }
+}
+
+struct AutoDeriveArgs<'a> {
+    table_data_name: &'a Ident,
+    generics: &'a Generics,
+    variant: &'a [Ident],
+    variant_ty: &'a [Type],
+}
+
+/// Produces generics whose where clause requires every variant's type to
+/// implement the given trait
+///
+/// The bounds are higher-ranked so that they are only checked when used
+/// rather than failing the derive for types that do not implement the trait
+fn make_bounded_generics(args: &AutoDeriveArgs, bound: Path) -> Generics {
+    let mut generics = args.generics.clone();
+    let where_clause = generics.make_where_clause();
+    for ty in args.variant_ty {
+        where_clause
+            .predicates
+            .push(parse_quote!(for<'__a> #ty: #bound));
+    }
+    generics
+}
+
+fn make_clone_impl(args: &AutoDeriveArgs) -> ItemImpl {
+    let AutoDeriveArgs {
+        table_data_name,
+        variant,
+        ..
+    } = args;
+    let generics = make_bounded_generics(args, parse_quote!(::core::clone::Clone));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    parse_quote! {
+        #[automatically_derived]
+        impl #impl_generics ::core::clone::Clone
+            for #table_data_name #ty_generics #where_clause
+        {
+            fn clone(&self) -> Self {
+                match *self {
+                    #(Self::#variant(ref x) => Self::#variant(::core::clone::Clone::clone(x)),)*
+                }
+            }
+        }
+    }
+}
+
+fn make_partial_eq_impl(args: &AutoDeriveArgs) -> ItemImpl {
+    let AutoDeriveArgs {
+        table_data_name,
+        variant,
+        ..
+    } = args;
+    let generics = make_bounded_generics(args, parse_quote!(::core::cmp::PartialEq));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    parse_quote! {
+        #[automatically_derived]
+        impl #impl_generics ::core::cmp::PartialEq
+            for #table_data_name #ty_generics #where_clause
+        {
+            fn eq(&self, other: &Self) -> ::core::primitive::bool {
+                match (self, other) {
+                    #(
+                        (Self::#variant(x), Self::#variant(y)) => {
+                            ::core::cmp::PartialEq::eq(x, y)
+                        }
+                    )*
+                    #[allow(unreachable_patterns)]
+                    _ => false,
+                }
+            }
+        }
+    }
+}
+
+fn make_eq_impl(args: &AutoDeriveArgs) -> ItemImpl {
+    let table_data_name = args.table_data_name;
+    let generics = make_bounded_generics(args, parse_quote!(::core::cmp::Eq));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    parse_quote! {
+        #[automatically_derived]
+        impl #impl_generics ::core::cmp::Eq
+            for #table_data_name #ty_generics #where_clause
+        {
+        }
+    }
+}
+
+fn make_hash_impl(args: &AutoDeriveArgs) -> ItemImpl {
+    let AutoDeriveArgs {
+        table_data_name,
+        variant,
+        ..
+    } = args;
+    let generics = make_bounded_generics(args, parse_quote!(::core::hash::Hash));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    parse_quote! {
+        #[automatically_derived]
+        impl #impl_generics ::core::hash::Hash
+            for #table_data_name #ty_generics #where_clause
+        {
+            fn hash<__Hasher: ::core::hash::Hasher>(&self, state: &mut __Hasher) {
+                ::core::hash::Hash::hash(&::core::mem::discriminant(self), state);
+                match *self {
+                    #(Self::#variant(ref x) => ::core::hash::Hash::hash(x, state),)*
+                }
+            }
+        }
     }
 }
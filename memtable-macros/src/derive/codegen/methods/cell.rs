@@ -1,12 +1,14 @@
+use proc_macro2::TokenStream;
 use syn::{parse_quote, Ident, ItemFn, Path, Type};
 
 pub struct Args<'a> {
     pub root: &'a Path,
-    pub idx: &'a syn::Index,
+    pub idx: &'a TokenStream,
     pub method_name: &'a Ident,
     pub variant_ty: &'a Type,
     pub table_data_name: &'a Ident,
     pub as_variant: &'a Ident,
+    pub option_inner_ty: Option<&'a Type>,
 }
 
 pub fn make(args: Args) -> ItemFn {
@@ -17,15 +19,29 @@ pub fn make(args: Args) -> ItemFn {
         variant_ty,
         table_data_name,
         as_variant,
+        option_inner_ty,
     } = args;
 
-    parse_quote! {
-        pub fn #method_name(
-            &self,
-            row: ::core::primitive::usize,
-        ) -> ::core::option::Option<&#variant_ty> {
-            #root::Table::cell(&self.0, row, #idx)
-                .and_then(#table_data_name::#as_variant)
-        }
+    // Optional columns treat a missing cell the same as a cell of None
+    match option_inner_ty {
+        Some(inner_ty) => parse_quote! {
+            pub fn #method_name(
+                &self,
+                row: ::core::primitive::usize,
+            ) -> ::core::option::Option<&#inner_ty> {
+                #root::Table::cell(&self.0, row, #idx)
+                    .and_then(#table_data_name::#as_variant)
+                    .and_then(::core::option::Option::as_ref)
+            }
+        },
+        None => parse_quote! {
+            pub fn #method_name(
+                &self,
+                row: ::core::primitive::usize,
+            ) -> ::core::option::Option<&#variant_ty> {
+                #root::Table::cell(&self.0, row, #idx)
+                    .and_then(#table_data_name::#as_variant)
+            }
+        },
     }
 }
@@ -1,12 +1,14 @@
+use proc_macro2::TokenStream;
 use syn::{parse_quote, Ident, ItemFn, Path, Type};
 
 pub struct Args<'a> {
     pub root: &'a Path,
-    pub idx: &'a syn::Index,
+    pub idx: &'a TokenStream,
     pub method_name: &'a Ident,
     pub variant_ty: &'a Type,
     pub table_data_name: &'a Ident,
     pub as_variant: &'a Ident,
+    pub option_inner_ty: Option<&'a Type>,
 }
 
 pub fn make(args: Args) -> ItemFn {
@@ -17,12 +19,28 @@ pub fn make(args: Args) -> ItemFn {
         variant_ty,
         table_data_name,
         as_variant,
+        option_inner_ty,
     } = args;
 
-    parse_quote! {
-        pub fn #method_name(&self) -> impl ::core::iter::Iterator<Item = &#variant_ty> {
-            let iter = #root::Table::column(&self.0, #idx);
-            ::core::iter::Iterator::filter_map(iter, #table_data_name::#as_variant)
-        }
+    // Optional columns yield an item for every row, even if its cell is missing
+    match option_inner_ty {
+        Some(inner_ty) => parse_quote! {
+            pub fn #method_name(
+                &self,
+            ) -> impl ::core::iter::Iterator<Item = ::core::option::Option<&#inner_ty>> {
+                let table = &self.0;
+                ::core::iter::Iterator::map(0..#root::Table::row_cnt(table), move |row| {
+                    #root::Table::cell(table, row, #idx)
+                        .and_then(#table_data_name::#as_variant)
+                        .and_then(::core::option::Option::as_ref)
+                })
+            }
+        },
+        None => parse_quote! {
+            pub fn #method_name(&self) -> impl ::core::iter::Iterator<Item = &#variant_ty> {
+                let iter = #root::Table::column(&self.0, #idx);
+                ::core::iter::Iterator::filter_map(iter, #table_data_name::#as_variant)
+            }
+        },
     }
 }
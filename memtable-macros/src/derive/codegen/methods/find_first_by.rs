@@ -0,0 +1,59 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, Ident, ItemFn, Path, Type};
+
+pub struct Args<'a> {
+    pub root: &'a Path,
+    pub method_name: &'a Ident,
+    pub cell_fn: &'a Ident,
+    pub variant_ty: &'a Type,
+    pub option_inner_ty: Option<&'a Type>,
+}
+
+pub fn make(args: Args) -> ItemFn {
+    let Args {
+        root,
+        method_name,
+        cell_fn,
+        variant_ty,
+        option_inner_ty,
+    } = args;
+
+    // The bound is written in a higher-ranked form so that rustc only checks
+    // it when the method is called, allowing columns whose type is not
+    // PartialEq
+    //
+    // Optional columns compare a missing cell as None
+    let (value_ty, bound_ty, is_match): (TokenStream, &Type, TokenStream) = match option_inner_ty {
+        Some(inner_ty) => (
+            quote!(::core::option::Option<&#inner_ty>),
+            inner_ty,
+            quote!(::core::cmp::PartialEq::eq(&self.#cell_fn(*row), &value)),
+        ),
+        None => (
+            quote!(&#variant_ty),
+            variant_ty,
+            quote!(match self.#cell_fn(*row) {
+                ::core::option::Option::Some(x) => ::core::cmp::PartialEq::eq(&x, &value),
+                ::core::option::Option::None => false,
+            }),
+        ),
+    };
+
+    parse_quote! {
+        /// Returns the index of the first row whose value for the explicit
+        /// column matches the provided value
+        pub fn #method_name(
+            &self,
+            value: #value_ty,
+        ) -> ::core::option::Option<::core::primitive::usize>
+        where
+            for<'__a> &'__a #bound_ty: ::core::cmp::PartialEq,
+        {
+            ::core::iter::Iterator::find(
+                &mut (0..#root::Table::row_cnt(&self.0)),
+                |row| #is_match,
+            )
+        }
+    }
+}
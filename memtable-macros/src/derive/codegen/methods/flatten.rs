@@ -0,0 +1,84 @@
+use proc_macro2::TokenStream;
+use syn::{parse_quote, Ident, ItemFn, Path, Type};
+
+pub struct Args<'a> {
+    pub root: &'a Path,
+    pub idx: &'a TokenStream,
+    pub method_name: &'a Ident,
+    pub replace_method_name: &'a Ident,
+    pub variant_ty: &'a Type,
+    pub table_data_name: &'a Ident,
+    pub variant: &'a Ident,
+    pub as_variant: &'a Ident,
+    pub into_variant: &'a Ident,
+}
+
+pub fn make(args: Args) -> (ItemFn, ItemFn) {
+    let Args {
+        root,
+        idx,
+        method_name,
+        replace_method_name,
+        variant_ty,
+        table_data_name,
+        variant,
+        as_variant,
+        into_variant,
+    } = args;
+
+    let cell_fn = parse_quote! {
+        /// Rebuilds the value of the flattened column from each of the cells
+        /// it spans within the specified row
+        pub fn #method_name(
+            &self,
+            row: ::core::primitive::usize,
+        ) -> ::core::option::Option<#variant_ty>
+        where
+            for<'__a> <#variant_ty as #root::FlattenColumns>::Data: ::core::clone::Clone,
+        {
+            <#variant_ty as #root::FlattenColumns>::from_cells(::core::iter::Iterator::map(
+                0..<#variant_ty as #root::FlattenColumns>::COLUMN_CNT,
+                |i| {
+                    #root::Table::cell(&self.0, row, #idx + i)
+                        .and_then(#table_data_name::#as_variant)
+                        .cloned()
+                },
+            ))
+        }
+    };
+
+    let replace_fn = parse_quote! {
+        /// Swaps the current value of the flattened column with the provided
+        /// one, doing nothing if the specified row does not exist
+        pub fn #replace_method_name<__Value: ::core::convert::Into<#variant_ty>>(
+            &mut self,
+            row: ::core::primitive::usize,
+            value: __Value,
+        ) -> ::core::option::Option<#variant_ty> {
+            if row < #root::Table::row_cnt(&self.0) {
+                let cells = ::core::iter::Iterator::enumerate(
+                    <#variant_ty as #root::FlattenColumns>::into_cells(
+                        ::core::convert::Into::<#variant_ty>::into(value),
+                    ),
+                );
+
+                <#variant_ty as #root::FlattenColumns>::from_cells(::core::iter::Iterator::map(
+                    cells,
+                    |(i, x)| {
+                        #root::Table::insert_cell(
+                            &mut self.0,
+                            row,
+                            #idx + i,
+                            #table_data_name::#variant(x),
+                        )
+                        .and_then(#table_data_name::#into_variant)
+                    },
+                ))
+            } else {
+                ::core::option::Option::None
+            }
+        }
+    };
+
+    (cell_fn, replace_fn)
+}
@@ -1,4 +1,5 @@
 use super::{utils, TableColumn};
+use quote::quote;
 use syn::{parse_quote, Generics, Ident, ItemFn, Path};
 
 pub struct Args<'a> {
@@ -22,6 +23,37 @@ pub fn make(args: Args) -> ItemFn {
     let fields = utils::make_field_tokens(columns);
     let variants = utils::make_variant_idents(columns);
 
+    // Flattened columns expand into many cells, so rather than an array we
+    // chain together the cells of each column
+    let cells = if columns.iter().any(|col| col.flatten.is_some()) {
+        columns.iter().zip(fields.iter().zip(variants.iter())).fold(
+            quote!(::core::iter::empty()),
+            |acc, (col, (field, variant))| {
+                let ty = &col.ty;
+                if col.flatten.is_some() {
+                    quote!(::core::iter::Iterator::chain(
+                        #acc,
+                        ::core::iter::Iterator::map(
+                            <#ty as #root::FlattenColumns>::into_cells(data.#field),
+                            #table_data_name::#variant,
+                        ),
+                    ))
+                } else {
+                    quote!(::core::iter::Iterator::chain(
+                        #acc,
+                        ::core::iter::once(#table_data_name::#variant(data.#field)),
+                    ))
+                }
+            },
+        )
+    } else {
+        quote! {
+            ::core::array::IntoIter::new([
+                #(#table_data_name::#variants(data.#fields)),*
+            ])
+        }
+    };
+
     parse_quote! {
         /// Inserts a new row into the table at the given position, shifting down
         /// all rows after it
@@ -30,13 +62,11 @@ pub fn make(args: Args) -> ItemFn {
             row: ::core::primitive::usize,
             data: __RowData,
         ) {
-            let data = data.into();
+            let data = ::core::convert::Into::<#origin_struct_name #ty_generics>::into(data);
             #root::Table::insert_row(
                 &mut self.0,
                 row,
-                ::core::array::IntoIter::new([
-                    #(#table_data_name::#variants(data.#fields)),*
-                ])
+                #cells
             );
         }
     }
@@ -1,12 +1,14 @@
+use proc_macro2::TokenStream;
 use syn::{parse_quote, Ident, ItemFn, Path, Type};
 
 pub struct Args<'a> {
     pub root: &'a Path,
-    pub idx: &'a syn::Index,
+    pub idx: &'a TokenStream,
     pub method_name: &'a Ident,
     pub variant_ty: &'a Type,
     pub table_data_name: &'a Ident,
     pub into_variant: &'a Ident,
+    pub option_inner_ty: Option<&'a Type>,
 }
 
 pub fn make(args: Args) -> ItemFn {
@@ -17,15 +19,29 @@ pub fn make(args: Args) -> ItemFn {
         variant_ty,
         table_data_name,
         into_variant,
+        option_inner_ty,
     } = args;
 
-    parse_quote! {
-        pub fn #method_name(self) -> impl ::core::iter::Iterator<Item = #variant_ty> {
-            let iter = #root::Table::into_column(self.0, #idx);
-            ::core::iter::Iterator::filter_map(
-                iter,
-                #table_data_name::#into_variant,
-            )
-        }
+    // Optional columns yield an item for every row, even if its cell is missing
+    match option_inner_ty {
+        Some(_) => parse_quote! {
+            pub fn #method_name(self) -> impl ::core::iter::Iterator<Item = #variant_ty> {
+                let mut table = self.0;
+                ::core::iter::Iterator::map(0..#root::Table::row_cnt(&table), move |row| {
+                    #root::Table::remove_cell(&mut table, row, #idx)
+                        .and_then(#table_data_name::#into_variant)
+                        .and_then(::core::convert::identity)
+                })
+            }
+        },
+        None => parse_quote! {
+            pub fn #method_name(self) -> impl ::core::iter::Iterator<Item = #variant_ty> {
+                let iter = #root::Table::into_column(self.0, #idx);
+                ::core::iter::Iterator::filter_map(
+                    iter,
+                    #table_data_name::#into_variant,
+                )
+            }
+        },
     }
 }
@@ -1,9 +1,10 @@
 use super::{utils, TableColumn, TableMode};
+use quote::quote;
 use syn::{parse_quote, Generics, Ident, ItemFn, Path};
 
 pub struct Args<'a> {
     pub root: &'a Path,
-    pub mode: TableMode,
+    pub mode: &'a TableMode,
     pub generics: &'a Generics,
     pub table_data_name: &'a Ident,
     pub columns: &'a [&'a TableColumn],
@@ -19,13 +20,30 @@ pub fn make(args: Args) -> ItemFn {
     } = args;
 
     let (_, ty_generics, _) = generics.split_for_impl();
-    let column_names = utils::make_column_names(columns, ToString::to_string);
-    let idx = utils::make_column_indexes(columns);
-    let inner_table_ty =
-        utils::make_inner_table_type(root, mode, table_data_name, generics, columns.len());
+    // Flattened columns are looked up by their nested names instead
+    let (column_names, idx): (Vec<_>, Vec<_>) = utils::make_column_name_patterns(columns)
+        .into_iter()
+        .zip(utils::make_column_indexes(root, columns))
+        .zip(columns)
+        .filter(|(_, col)| col.flatten.is_none())
+        .map(|(x, _)| x)
+        .unzip();
+    let flatten_lookup = utils::make_flatten_name_lookup(
+        root,
+        columns,
+        |idx| quote!(#root::Table::into_column(self.0, #idx)),
+    );
+    let inner_table_ty = utils::make_inner_table_type(
+        root,
+        mode,
+        table_data_name,
+        generics,
+        &utils::make_col_cnt(root, columns),
+    );
 
     parse_quote! {
-        /// Converts into a column by its name
+        /// Converts into a column by its name, also accepting any name the
+        /// column was renamed from
         pub fn into_column_by_name(
             self,
             name: &::core::primitive::str,
@@ -39,7 +57,10 @@ pub fn make(args: Args) -> ItemFn {
                         #root::Table::into_column(self.0, #idx)
                     ),
                 )*
-                _ => ::core::option::Option::None,
+                _ => {
+                    #flatten_lookup
+                    ::core::option::Option::None
+                }
             }
         }
     }
@@ -0,0 +1,87 @@
+use super::{utils, TableColumn};
+use quote::{format_ident, quote};
+use syn::{parse_quote, Generics, Ident, ItemFn, Path};
+
+pub struct Args<'a> {
+    pub root: &'a Path,
+    pub generics: &'a Generics,
+    pub table_data_name: &'a Ident,
+    pub version: usize,
+    pub columns: &'a [&'a TableColumn],
+}
+
+pub fn make(args: Args) -> ItemFn {
+    let Args {
+        root,
+        generics,
+        table_data_name,
+        version,
+        columns,
+    } = args;
+
+    let (_, ty_generics, _) = generics.split_for_impl();
+    let method_name = format_ident!("migrate_from_v{}", version);
+    let variants = utils::make_variant_idents(columns);
+    let variant_tys = utils::make_variant_types(columns);
+    let doc = format!(
+        concat!(
+            " Migrates a table persisted using version {} of this table's schema, ",
+            "moving each cell into its current column and populating columns ",
+            "added since then using their defaults",
+        ),
+        version,
+    );
+
+    // Columns that existed in the old version keep their relative order,
+    // so we map each old column index to its current index
+    let (old_idx, new_idx): (Vec<usize>, Vec<usize>) = columns
+        .iter()
+        .enumerate()
+        .filter(|(_, col)| col.to_added_in() <= version)
+        .map(|(idx, _)| idx)
+        .enumerate()
+        .unzip();
+
+    // Columns added after the old version are populated for every row
+    let mut added_idx = Vec::new();
+    let mut added_data = Vec::new();
+    for (idx, col) in columns.iter().enumerate() {
+        if col.to_added_in() > version {
+            let variant = &variants[idx];
+            let variant_ty = &variant_tys[idx];
+            let value = match col.to_default_expr().and_then(Result::ok) {
+                Some(expr) => quote!(::core::convert::Into::<#variant_ty>::into(#expr)),
+                None => quote!(<#variant_ty as ::core::default::Default>::default()),
+            };
+
+            added_idx.push(idx);
+            added_data.push(quote!(#table_data_name::#variant(#value)));
+        }
+    }
+
+    parse_quote! {
+        #[doc = #doc]
+        pub fn #method_name<__Table>(mut old: __Table) -> Self
+        where
+            __Table: #root::Table<Data = #table_data_name #ty_generics>,
+        {
+            let mut table = Self::new();
+
+            for row in 0..#root::Table::row_cnt(&old) {
+                #(
+                    if let ::core::option::Option::Some(x) =
+                        #root::Table::remove_cell(&mut old, row, #old_idx)
+                    {
+                        #root::Table::insert_cell(&mut table.0, row, #new_idx, x);
+                    }
+                )*
+
+                #(
+                    #root::Table::insert_cell(&mut table.0, row, #added_idx, #added_data);
+                )*
+            }
+
+            table
+        }
+    }
+}
@@ -1,9 +1,12 @@
 pub mod cell;
 pub mod column;
 pub mod column_by_name;
+pub mod find_first_by;
+pub mod flatten;
 pub mod insert_row;
 pub mod into_column;
 pub mod into_column_by_name;
+pub mod migrate_from;
 pub mod mut_cell;
 pub mod new;
 pub mod pop_row;
@@ -12,11 +15,14 @@ pub mod remove_row;
 pub mod replace_cell;
 pub mod row;
 pub mod rows;
+pub mod stats;
+pub mod upsert_by;
 
 use super::{utils, TableColumn, TableMode};
 use darling::ast::Style;
+use proc_macro2::TokenStream;
 use quote::format_ident;
-use syn::{Ident, ItemFn, Path, Type};
+use syn::{Generics, Ident, ItemFn, Path, Type};
 
 pub fn make_cell_fns(
     root: &Path,
@@ -25,6 +31,7 @@ pub fn make_cell_fns(
     columns: &[&TableColumn],
 ) -> Vec<ItemFn> {
     make_many(
+        root,
         style,
         columns,
         |name| format_ident!("{}", name),
@@ -34,16 +41,18 @@ pub fn make_cell_fns(
                 idx,
                 variant_ty,
                 as_variant,
+                option_inner_ty,
                 ..
             } = args;
 
             cell::make(cell::Args {
                 root,
                 idx,
-                method_name: &method_name,
+                method_name,
                 variant_ty,
                 table_data_name,
                 as_variant,
+                option_inner_ty,
             })
         },
     )
@@ -56,6 +65,7 @@ pub fn make_mut_cell_fns(
     columns: &[&TableColumn],
 ) -> Vec<ItemFn> {
     make_many(
+        root,
         style,
         columns,
         |name| format_ident!("mut{}{}", u(style), name),
@@ -71,7 +81,7 @@ pub fn make_mut_cell_fns(
             mut_cell::make(mut_cell::Args {
                 root,
                 idx,
-                method_name: &method_name,
+                method_name,
                 variant_ty,
                 table_data_name,
                 as_mut_variant,
@@ -87,6 +97,7 @@ pub fn make_column_fns(
     columns: &[&TableColumn],
 ) -> Vec<ItemFn> {
     make_many(
+        root,
         style,
         columns,
         |name| format_ident!("{}_column", name),
@@ -96,16 +107,18 @@ pub fn make_column_fns(
                 idx,
                 variant_ty,
                 as_variant,
+                option_inner_ty,
                 ..
             } = args;
 
             column::make(column::Args {
                 root,
                 idx,
-                method_name: &method_name,
+                method_name,
                 variant_ty,
                 table_data_name,
                 as_variant,
+                option_inner_ty,
             })
         },
     )
@@ -118,6 +131,7 @@ pub fn make_into_column_fns(
     columns: &[&TableColumn],
 ) -> Vec<ItemFn> {
     make_many(
+        root,
         style,
         columns,
         |name| format_ident!("into{}{}_column", u(style), name),
@@ -127,16 +141,18 @@ pub fn make_into_column_fns(
                 idx,
                 variant_ty,
                 into_variant,
+                option_inner_ty,
                 ..
             } = args;
 
             into_column::make(into_column::Args {
                 root,
                 idx,
-                method_name: &method_name,
+                method_name,
                 variant_ty,
                 table_data_name,
                 into_variant,
+                option_inner_ty,
             })
         },
     )
@@ -149,6 +165,7 @@ pub fn make_replace_cell_fns(
     columns: &[&TableColumn],
 ) -> Vec<ItemFn> {
     make_many(
+        root,
         style,
         columns,
         |name| format_ident!("replace{}{}", u(style), name),
@@ -165,7 +182,7 @@ pub fn make_replace_cell_fns(
             replace_cell::make(replace_cell::Args {
                 root,
                 idx,
-                method_name: &method_name,
+                method_name,
                 variant_ty,
                 table_data_name,
                 variant,
@@ -175,24 +192,157 @@ pub fn make_replace_cell_fns(
     )
 }
 
+pub fn make_flatten_fns(
+    root: &Path,
+    style: Style,
+    table_data_name: &Ident,
+    columns: &[&TableColumn],
+) -> Vec<ItemFn> {
+    let idx = utils::make_column_indexes(root, columns);
+    let variant_tys = utils::make_variant_types(columns);
+    let utils::VariantMethodIdents {
+        as_variant,
+        into_variant,
+        ..
+    } = utils::make_variant_method_idents(style, columns);
+    let snake_idents = utils::make_snake_idents(columns);
+    let variants = utils::make_variant_idents(columns);
+
+    let mut fns = Vec::new();
+    for (i, col) in columns.iter().enumerate() {
+        if col.flatten.is_none() {
+            continue;
+        }
+
+        let (cell_fn, replace_fn) = flatten::make(flatten::Args {
+            root,
+            idx: &idx[i],
+            method_name: &snake_idents[i],
+            replace_method_name: &format_ident!("replace{}{}", u(style), snake_idents[i]),
+            variant_ty: &variant_tys[i],
+            table_data_name,
+            variant: &variants[i],
+            as_variant: &as_variant[i],
+            into_variant: &into_variant[i],
+        });
+        fns.push(cell_fn);
+        fns.push(replace_fn);
+    }
+    fns
+}
+
+pub fn make_upsert_by_fns(
+    root: &Path,
+    style: Style,
+    generics: &Generics,
+    origin_struct_name: &Ident,
+    columns: &[&TableColumn],
+) -> Vec<ItemFn> {
+    let fields = utils::make_field_tokens(columns);
+    let snake_idents = utils::make_snake_idents(columns);
+
+    make_many(
+        root,
+        style,
+        columns,
+        |name| format_ident!("upsert_by{}{}", u(style), name),
+        |args| {
+            let i = args.pos;
+            upsert_by::make(upsert_by::Args {
+                root,
+                generics,
+                method_name: args.method_name,
+                cell_fn: &snake_idents[i],
+                variant_ty: args.variant_ty,
+                option_inner_ty: args.option_inner_ty,
+                field: &fields[i],
+                origin_struct_name,
+            })
+        },
+    )
+    .into_iter()
+    .zip(columns.iter().filter(|col| col.flatten.is_none()))
+    .filter(|(_, col)| col.indexed.is_some())
+    .map(|(f, _)| f)
+    .collect()
+}
+
+pub fn make_find_first_by_fns(root: &Path, style: Style, columns: &[&TableColumn]) -> Vec<ItemFn> {
+    let snake_idents = utils::make_snake_idents(columns);
+
+    make_many(
+        root,
+        style,
+        columns,
+        |name| format_ident!("find_first_by{}{}", u(style), name),
+        |args| {
+            find_first_by::make(find_first_by::Args {
+                root,
+                method_name: args.method_name,
+                cell_fn: &snake_idents[args.pos],
+                variant_ty: args.variant_ty,
+                option_inner_ty: args.option_inner_ty,
+            })
+        },
+    )
+}
+
+pub fn make_stats_fns(root: &Path, style: Style, columns: &[&TableColumn]) -> Vec<ItemFn> {
+    let snake_idents = utils::make_snake_idents(columns);
+    let stats = [
+        ("min", stats::Stat::Min),
+        ("max", stats::Stat::Max),
+        ("sum", stats::Stat::Sum),
+    ];
+
+    let mut fns = Vec::new();
+    for &(suffix, stat) in stats.iter() {
+        fns.extend(
+            make_many(
+                root,
+                style,
+                columns,
+                |name| format_ident!("{}_column_{}", name, suffix),
+                |args| {
+                    stats::make(stats::Args {
+                        stat,
+                        method_name: args.method_name,
+                        column_fn: &format_ident!("{}_column", snake_idents[args.pos]),
+                        variant_ty: args.variant_ty,
+                        option_inner_ty: args.option_inner_ty,
+                    })
+                },
+            )
+            .into_iter()
+            .zip(columns.iter().filter(|col| col.flatten.is_none()))
+            .filter(|(_, col)| col.stats.is_some())
+            .map(|(f, _)| f),
+        );
+    }
+    fns
+}
+
 struct ManyArgs<'a> {
+    pub pos: usize,
     pub method_name: &'a Ident,
-    pub idx: &'a syn::Index,
+    pub idx: &'a TokenStream,
     pub variant_ty: &'a Type,
     pub as_variant: &'a Ident,
     pub as_mut_variant: &'a Ident,
     pub into_variant: &'a Ident,
     pub variant: &'a Ident,
+    pub option_inner_ty: Option<&'a Type>,
 }
 
 fn make_many(
+    root: &Path,
     style: Style,
     columns: &[&TableColumn],
     mut make_method_name: impl FnMut(&Ident) -> Ident,
     mut make_fn: impl FnMut(ManyArgs) -> ItemFn,
 ) -> Vec<ItemFn> {
     let cnt = columns.len();
-    let idx = utils::make_column_indexes(columns);
+    let idx = utils::make_column_indexes(root, columns);
     let variant_tys = utils::make_variant_types(columns);
     let utils::VariantMethodIdents {
         as_variant,
@@ -202,10 +352,17 @@ fn make_many(
     } = utils::make_variant_method_idents(style, columns);
     let snake_idents = utils::make_snake_idents(columns);
     let variants = utils::make_variant_idents(columns);
+    let option_inner_tys = utils::make_option_inner_types(columns);
 
+    // Flattened columns have their own methods as they span multiple columns
     let mut fns = Vec::new();
     for i in 0..cnt {
+        if columns[i].flatten.is_some() {
+            continue;
+        }
+
         let args = ManyArgs {
+            pos: i,
             method_name: &make_method_name(&snake_idents[i]),
             idx: &idx[i],
             variant_ty: &variant_tys[i],
@@ -213,6 +370,7 @@ fn make_many(
             as_mut_variant: &as_mut_variant[i],
             into_variant: &into_variant[i],
             variant: &variants[i],
+            option_inner_ty: option_inner_tys[i].as_ref(),
         };
 
         fns.push(make_fn(args));
@@ -220,6 +378,26 @@ fn make_many(
     fns
 }
 
+pub fn make_migrate_from_fns(
+    root: &Path,
+    generics: &Generics,
+    table_data_name: &Ident,
+    version: usize,
+    columns: &[&TableColumn],
+) -> Vec<ItemFn> {
+    (1..version)
+        .map(|version| {
+            migrate_from::make(migrate_from::Args {
+                root,
+                generics,
+                table_data_name,
+                version,
+                columns,
+            })
+        })
+        .collect()
+}
+
 /// Returns _ if style is not a tuple struct (so no extra _ prefix)
 fn u(style: Style) -> &'static str {
     if !style.is_tuple() {
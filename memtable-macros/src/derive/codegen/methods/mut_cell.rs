@@ -1,8 +1,9 @@
+use proc_macro2::TokenStream;
 use syn::{parse_quote, Ident, ItemFn, Path, Type};
 
 pub struct Args<'a> {
     pub root: &'a Path,
-    pub idx: &'a syn::Index,
+    pub idx: &'a TokenStream,
     pub method_name: &'a Ident,
     pub variant_ty: &'a Type,
     pub table_data_name: &'a Ident,
@@ -1,5 +1,6 @@
 use super::{utils, TableColumn};
 use darling::ast::Style;
+use quote::quote;
 use syn::{parse_quote, Expr, Generics, Ident, ItemFn, Path};
 
 pub struct Args<'a> {
@@ -24,24 +25,33 @@ pub fn make(args: Args) -> ItemFn {
     let utils::VariantMethodIdents { into_variant, .. } =
         utils::make_variant_method_idents(style, columns);
     let bug_msg = utils::bug_str();
+    let idx = utils::make_column_indexes(root, columns);
 
-    let create_struct_expr: Expr = match style {
-        Style::Tuple => parse_quote! {
-            #origin_struct_name(#(
-                ::core::iter::Iterator::next(&mut iter)
-                    .expect(#bug_msg)
-                    .#into_variant()
-                    .expect(#bug_msg)
-            ),*)
-        },
-        Style::Struct => parse_quote! {
-            #origin_struct_name {#(
-                #fields: ::core::iter::Iterator::next(&mut iter)
-                    .expect(#bug_msg)
-                    .#into_variant()
+    // Optional columns treat a missing cell the same as a cell of None, and
+    // flattened columns are rebuilt from each of the cells they span
+    let take_cell = utils::make_option_inner_types(columns)
+        .into_iter()
+        .zip(columns.iter().zip(idx.iter().zip(into_variant.iter())))
+        .map(|(option_inner_ty, (col, (idx, into_variant)))| {
+            let ty = &col.ty;
+            let cell = quote!(#root::Table::remove_cell(&mut self.0, row, #idx));
+            match option_inner_ty {
+                _ if col.flatten.is_some() => quote! {
+                    <#ty as #root::FlattenColumns>::from_cells(::core::iter::Iterator::map(
+                        0..<#ty as #root::FlattenColumns>::COLUMN_CNT,
+                        |i| #root::Table::remove_cell(&mut self.0, row, #idx + i)
+                            .and_then(|x| x.#into_variant()),
+                    ))
                     .expect(#bug_msg)
-            ),*}
-        },
+                },
+                Some(_) => quote!(#cell.and_then(|x| x.#into_variant()).and_then(::core::convert::identity)),
+                None => quote!(#cell.expect(#bug_msg).#into_variant().expect(#bug_msg)),
+            }
+        });
+
+    let create_struct_expr: Expr = match style {
+        Style::Tuple => parse_quote!(#origin_struct_name(#(#take_cell),*)),
+        Style::Struct => parse_quote!(#origin_struct_name {#(#fields: #take_cell),*}),
         Style::Unit => unreachable!(),
     };
 
@@ -51,18 +61,20 @@ pub fn make(args: Args) -> ItemFn {
             &mut self,
             row: ::core::primitive::usize,
         ) -> ::core::option::Option<#origin_struct_name #ty_generics> {
-            #root::Table::remove_row(&mut self.0, row).and_then(|row| {
-                // Build an iterator so we can consume the row values
-                let mut iter = ::core::iter::IntoIterator::into_iter(row);
-
+            if row < #root::Table::row_cnt(&self.0) {
                 // NOTE: Because we don't allow access to the underlying table
                 //       at the level where the cell enum can be changed to
-                //       another type, this should NEVER fail. We want to rely
-                //       on that guarantee as it would be considered corrupt
-                //       if the data is removed (by single cell) or changes
-                //       types underneath.
-                ::core::option::Option::Some(#create_struct_expr)
-            })
+                //       another type, this should NEVER fail for a column
+                //       that is not optional. We want to rely on that
+                //       guarantee as it would be considered corrupt if the
+                //       data is removed (by single cell) or changes types
+                //       underneath.
+                let data = #create_struct_expr;
+                #root::Table::remove_row(&mut self.0, row);
+                ::core::option::Option::Some(data)
+            } else {
+                ::core::option::Option::None
+            }
         }
     }
 }
@@ -1,8 +1,9 @@
+use proc_macro2::TokenStream;
 use syn::{parse_quote, Ident, ItemFn, Path, Type};
 
 pub struct Args<'a> {
     pub root: &'a Path,
-    pub idx: &'a syn::Index,
+    pub idx: &'a TokenStream,
     pub method_name: &'a Ident,
     pub variant_ty: &'a Type,
     pub table_data_name: &'a Ident,
@@ -34,7 +35,7 @@ pub fn make(args: Args) -> ItemFn {
                     &mut self.0,
                     row,
                     #idx,
-                    #table_data_name::#variant(value.into()),
+                    #table_data_name::#variant(::core::convert::Into::<#variant_ty>::into(value)),
                 ).and_then(#table_data_name::#into_variant)
             } else {
                 ::core::option::Option::None
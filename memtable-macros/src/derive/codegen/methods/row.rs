@@ -1,4 +1,5 @@
 use super::{utils, TableColumn};
+use quote::quote;
 use syn::{parse_quote, Ident, ItemFn, Path, Type};
 
 pub struct Args<'a> {
@@ -9,20 +10,31 @@ pub struct Args<'a> {
 pub fn make(args: Args) -> ItemFn {
     let Args { root, columns } = args;
 
-    let variant_tys = utils::make_variant_types(columns);
+    let ref_tys = utils::make_ref_types(columns);
     let cell_fns: Vec<Ident> = utils::make_snake_idents(columns);
+    let bug_msg = utils::bug_str();
+    let where_clause = utils::make_flatten_where_clause(root, columns);
 
-    // (type1, type2, ...)
-    let option_inner_ty: Type = parse_quote!((#(&#variant_tys),*));
+    // Optional columns pass along a missing cell as None
+    let cells = utils::make_option_inner_types(columns)
+        .into_iter()
+        .zip(cell_fns.iter())
+        .map(|(option_inner_ty, cell_fn)| match option_inner_ty {
+            Some(_) => quote!(self.#cell_fn(row)),
+            None => quote!(self.#cell_fn(row).expect(#bug_msg)),
+        });
 
-    let bug_msg = utils::bug_str();
+    // (type1, type2, ...)
+    let option_inner_ty: Type = parse_quote!((#(#ref_tys),*));
 
     parse_quote! {
         /// Returns a tuple containing refs to each column's data within a row
         pub fn row(
             &self,
             row: ::core::primitive::usize,
-        ) -> ::core::option::Option<#option_inner_ty> {
+        ) -> ::core::option::Option<#option_inner_ty>
+        #where_clause
+        {
             // NOTE: Because we don't allow access to the underlying table
             //       at the level where the cell enum can be changed to
             //       another type, this should NEVER fail. We want to rely
@@ -30,9 +42,7 @@ pub fn make(args: Args) -> ItemFn {
             //       if the data changed types underneath.
             if row < #root::Table::row_cnt(&self.0) {
                 ::core::option::Option::Some(
-                    (#(
-                        self.#cell_fns(row).expect(#bug_msg)
-                    ),*)
+                    (#(#cells),*)
                 )
             } else {
                 ::core::option::Option::None
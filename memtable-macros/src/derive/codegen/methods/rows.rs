@@ -9,16 +9,19 @@ pub struct Args<'a> {
 pub fn make(args: Args) -> ItemFn {
     let Args { root, columns } = args;
 
-    let variant_tys = utils::make_variant_types(columns);
+    let ref_tys = utils::make_ref_types(columns);
 
     // (type1, type2, ...)
-    let iter_item_ty: Type = parse_quote!((#(&#variant_tys),*));
+    let iter_item_ty: Type = parse_quote!((#(#ref_tys),*));
     let bug_msg = utils::bug_str();
+    let where_clause = utils::make_flatten_where_clause(root, columns);
 
     parse_quote! {
         /// Iterates through each row of the table, returning a tuple of references
         /// to the individual fields
-        pub fn rows(&self) -> impl ::core::iter::Iterator<Item = #iter_item_ty> {
+        pub fn rows(&self) -> impl ::core::iter::Iterator<Item = #iter_item_ty>
+        #where_clause
+        {
             // NOTE: The expect(...) should never happen as we should have
             //       all of the rows available in the described range
             ::core::iter::Iterator::map(
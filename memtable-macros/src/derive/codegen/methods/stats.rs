@@ -0,0 +1,72 @@
+use quote::quote;
+use syn::{parse_quote, Ident, ItemFn, Type};
+
+#[derive(Copy, Clone)]
+pub enum Stat {
+    Min,
+    Max,
+    Sum,
+}
+
+pub struct Args<'a> {
+    pub stat: Stat,
+    pub method_name: &'a Ident,
+    pub column_fn: &'a Ident,
+    pub variant_ty: &'a Type,
+    pub option_inner_ty: Option<&'a Type>,
+}
+
+pub fn make(args: Args) -> ItemFn {
+    let Args {
+        stat,
+        method_name,
+        column_fn,
+        variant_ty,
+        option_inner_ty,
+    } = args;
+
+    // Bounds are written in a higher-ranked form so that rustc only checks
+    // them when a method is called, otherwise deriving for a column whose
+    // type does not satisfy them (such as min over f64) would fail to compile
+    //
+    // Optional columns skip over cells that are missing or None
+    let (bound_ty, values) = match option_inner_ty {
+        Some(inner_ty) => (
+            inner_ty,
+            quote!(::core::iter::Iterator::flatten(self.#column_fn())),
+        ),
+        None => (variant_ty, quote!(self.#column_fn())),
+    };
+
+    match stat {
+        Stat::Min => parse_quote! {
+            /// Returns the smallest value within the column, or `None` if the
+            /// column has no values
+            pub fn #method_name(&self) -> ::core::option::Option<&#bound_ty>
+            where
+                for<'__a> &'__a #bound_ty: ::core::cmp::Ord,
+            {
+                ::core::iter::Iterator::min(#values)
+            }
+        },
+        Stat::Max => parse_quote! {
+            /// Returns the largest value within the column, or `None` if the
+            /// column has no values
+            pub fn #method_name(&self) -> ::core::option::Option<&#bound_ty>
+            where
+                for<'__a> &'__a #bound_ty: ::core::cmp::Ord,
+            {
+                ::core::iter::Iterator::max(#values)
+            }
+        },
+        Stat::Sum => parse_quote! {
+            /// Returns the sum of the values within the column
+            pub fn #method_name(&self) -> #bound_ty
+            where
+                #bound_ty: for<'__sum> ::core::iter::Sum<&'__sum #bound_ty>,
+            {
+                ::core::iter::Iterator::sum(#values)
+            }
+        },
+    }
+}
@@ -0,0 +1,80 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, Generics, Ident, ItemFn, Path, Type};
+
+pub struct Args<'a> {
+    pub root: &'a Path,
+    pub generics: &'a Generics,
+    pub method_name: &'a Ident,
+    pub cell_fn: &'a Ident,
+    pub variant_ty: &'a Type,
+    pub option_inner_ty: Option<&'a Type>,
+    pub field: &'a TokenStream,
+    pub origin_struct_name: &'a Ident,
+}
+
+pub fn make(args: Args) -> ItemFn {
+    let Args {
+        root,
+        generics,
+        method_name,
+        cell_fn,
+        variant_ty,
+        option_inner_ty,
+        field,
+        origin_struct_name,
+    } = args;
+
+    let (_, ty_generics, _) = generics.split_for_impl();
+
+    // Optional columns compare a missing cell as None
+    let (bound_ty, is_match) = match option_inner_ty {
+        Some(inner_ty) => (
+            inner_ty,
+            quote!(::core::cmp::PartialEq::eq(
+                &self.#cell_fn(*row),
+                &::core::option::Option::as_ref(&data.#field),
+            )),
+        ),
+        None => (
+            variant_ty,
+            quote!(match self.#cell_fn(*row) {
+                ::core::option::Option::Some(x) => ::core::cmp::PartialEq::eq(x, &data.#field),
+                ::core::option::Option::None => false,
+            }),
+        ),
+    };
+
+    parse_quote! {
+        /// Replaces the first row whose value for the explicit column matches
+        /// that of the provided row, or pushes the row to the end of the table
+        /// if no row matches, returning the replaced row
+        ///
+        /// Finds the matching row by scanning the column from the first row
+        pub fn #method_name<__RowData: ::core::convert::Into<#origin_struct_name #ty_generics>>(
+            &mut self,
+            data: __RowData,
+        ) -> ::core::option::Option<#origin_struct_name #ty_generics>
+        where
+            for<'__a> #bound_ty: ::core::cmp::PartialEq,
+        {
+            let data = ::core::convert::Into::<#origin_struct_name #ty_generics>::into(data);
+            let row = ::core::iter::Iterator::find(
+                &mut (0..#root::Table::row_cnt(&self.0)),
+                |row| #is_match,
+            );
+
+            match row {
+                ::core::option::Option::Some(row) => {
+                    let old = self.remove_row(row);
+                    self.insert_row(row, data);
+                    old
+                }
+                ::core::option::Option::None => {
+                    self.push_row(data);
+                    ::core::option::Option::None
+                }
+            }
+        }
+    }
+}
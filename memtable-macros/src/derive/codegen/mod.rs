@@ -2,6 +2,7 @@ pub mod constants;
 pub mod data;
 pub mod methods;
 pub mod parts;
+pub mod queries;
 pub mod traits;
 pub mod utils;
 
@@ -11,12 +12,14 @@ use syn::{parse_quote, Generics, Ident, ItemImpl, Path};
 
 pub struct TableImplArgs<'a> {
     pub root: &'a Path,
-    pub mode: TableMode,
+    pub mode: &'a TableMode,
     pub style: Style,
     pub origin_struct_name: &'a Ident,
     pub table_name: &'a Ident,
     pub generics: &'a Generics,
     pub table_data_name: &'a Ident,
+    pub version: usize,
+    pub queries: bool,
     pub columns: &'a [&'a TableColumn],
 }
 
@@ -29,13 +32,22 @@ pub fn make_table_impl(args: TableImplArgs) -> ItemImpl {
         table_name,
         generics,
         table_data_name,
+        version,
+        queries,
         columns,
     } = args;
 
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-    let column_names_const =
-        constants::column_names::make(constants::column_names::Args { columns });
+    // Flattened columns have names only known at runtime, so they are looked
+    // up by name instead
+    let column_names_const = if columns.iter().all(|col| col.flatten.is_none()) {
+        Some(constants::column_names::make(
+            constants::column_names::Args { columns },
+        ))
+    } else {
+        None
+    };
     let new_fn = methods::new::make(methods::new::Args {});
     let column_by_name_fn = methods::column_by_name::make(methods::column_by_name::Args {
         root,
@@ -84,6 +96,17 @@ pub fn make_table_impl(args: TableImplArgs) -> ItemImpl {
     let replace_cell_fns = methods::make_replace_cell_fns(root, style, table_data_name, columns);
     let column_fns = methods::make_column_fns(root, style, table_data_name, columns);
     let into_column_fns = methods::make_into_column_fns(root, style, table_data_name, columns);
+    let upsert_by_fns =
+        methods::make_upsert_by_fns(root, style, generics, origin_struct_name, columns);
+    let find_first_by_fns = if queries {
+        methods::make_find_first_by_fns(root, style, columns)
+    } else {
+        Vec::new()
+    };
+    let stats_fns = methods::make_stats_fns(root, style, columns);
+    let flatten_fns = methods::make_flatten_fns(root, style, table_data_name, columns);
+    let migrate_from_fns =
+        methods::make_migrate_from_fns(root, generics, table_data_name, version, columns);
 
     parse_quote! {
         #[automatically_derived]
@@ -107,6 +130,12 @@ pub fn make_table_impl(args: TableImplArgs) -> ItemImpl {
                 #column_fns
                 #into_column_fns
             )*
+
+            #(#flatten_fns)*
+            #(#upsert_by_fns)*
+            #(#find_first_by_fns)*
+            #(#stats_fns)*
+            #(#migrate_from_fns)*
         }
     }
 }
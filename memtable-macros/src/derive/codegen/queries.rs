@@ -0,0 +1,119 @@
+use super::{utils, TableColumn};
+use darling::ast::Style;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse_quote, Expr, Generics, Ident, Path};
+
+pub struct Args<'a> {
+    pub root: &'a Path,
+    pub style: Style,
+    pub origin_struct_name: &'a Ident,
+    pub table_name: &'a Ident,
+    pub generics: &'a Generics,
+    pub columns: &'a [&'a TableColumn],
+}
+
+pub fn make(args: Args) -> TokenStream {
+    let Args {
+        root,
+        style,
+        origin_struct_name,
+        table_name,
+        generics,
+        columns,
+    } = args;
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let row_ty = quote!(#origin_struct_name #ty_generics);
+    let fields = utils::make_field_tokens(columns);
+    let option_inner_tys = utils::make_option_inner_types(columns);
+    let bug_msg = utils::bug_str();
+
+    // Rows are rebuilt from the references returned for them, where optional
+    // and regular columns are cloned and flattened columns are already owned
+    let binding: Vec<Ident> = (0..columns.len())
+        .map(|i| format_ident!("__field{}", i))
+        .collect();
+    let owned_field = columns
+        .iter()
+        .zip(option_inner_tys.iter().zip(binding.iter()))
+        .map(|(col, (option_inner_ty, binding))| match option_inner_ty {
+            _ if col.flatten.is_some() => quote!(#binding),
+            Some(_) => quote!(::core::option::Option::<&_>::cloned(#binding)),
+            None => quote!(::core::clone::Clone::clone(#binding)),
+        });
+    let owned_row: Expr = match style {
+        Style::Tuple => parse_quote!(#origin_struct_name(#(#owned_field),*)),
+        Style::Struct => parse_quote!(#origin_struct_name {#(#fields: #owned_field),*}),
+        Style::Unit => unreachable!(),
+    };
+
+    // The bounds are higher-ranked so that they are only checked when used
+    // rather than failing the derive for fields that are not cloneable
+    let clone_bound = columns
+        .iter()
+        .zip(option_inner_tys.iter())
+        .map(|(col, option_inner_ty)| {
+            let ty = &col.ty;
+            match option_inner_ty {
+                _ if col.flatten.is_some() => quote! {
+                    for<'__a> <#ty as #root::FlattenColumns>::Data: ::core::clone::Clone
+                },
+                Some(inner_ty) => quote!(for<'__a> #inner_ty: ::core::clone::Clone),
+                None => quote!(for<'__a> #ty: ::core::clone::Clone),
+            }
+        });
+    let clone_where_clause = quote!(where #(#clone_bound),*);
+
+    let owned_row_at = quote! {
+        {
+            let (#(#binding),*) = self.row(row).expect(#bug_msg);
+            #owned_row
+        }
+    };
+
+    quote! {
+        #root::__cfg_alloc! {
+            #[automatically_derived]
+            impl #impl_generics #table_name #ty_generics #where_clause {
+                /// Returns copies of the rows that satisfy the predicate, in order
+                pub fn filter_rows<__Predicate: ::core::ops::FnMut(&#row_ty) -> ::core::primitive::bool>(
+                    &self,
+                    mut predicate: __Predicate,
+                ) -> #root::__private::Vec<#row_ty>
+                #clone_where_clause
+                {
+                    let mut rows = #root::__private::Vec::new();
+                    for row in 0..#root::Table::row_cnt(&self.0) {
+                        let data = #owned_row_at;
+                        if predicate(&data) {
+                            rows.push(data);
+                        }
+                    }
+                    rows
+                }
+
+                /// Returns copies of the rows within the page at the specified
+                /// index, where each page holds `size` rows and the last page
+                /// may hold fewer
+                pub fn rows_page(
+                    &self,
+                    page: ::core::primitive::usize,
+                    size: ::core::primitive::usize,
+                ) -> #root::__private::Vec<#row_ty>
+                #clone_where_clause
+                {
+                    let row_cnt = #root::Table::row_cnt(&self.0);
+                    let start = ::core::cmp::min(page.saturating_mul(size), row_cnt);
+                    let end = ::core::cmp::min(start.saturating_add(size), row_cnt);
+
+                    let mut rows = #root::__private::Vec::with_capacity(end - start);
+                    for row in start..end {
+                        rows.push(#owned_row_at);
+                    }
+                    rows
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use super::TableMode;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Generics, Ident, Path};
+
+pub struct Args<'a> {
+    pub root: &'a Path,
+    pub mode: &'a TableMode,
+    pub origin_struct_name: &'a Ident,
+    pub table_name: &'a Ident,
+    pub generics: &'a Generics,
+}
+
+pub fn make(args: Args) -> TokenStream {
+    let Args {
+        root,
+        mode,
+        origin_struct_name,
+        table_name,
+        generics,
+    } = args;
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let row_ty = quote!(#origin_struct_name #ty_generics);
+
+    // Tables with a fixed number of rows cannot hold every row of a Vec, so
+    // they are converted with TryFrom, failing rather than dropping rows
+    let from_vec = match mode {
+        TableMode::Fixed { rows } => quote! {
+            #[automatically_derived]
+            impl #impl_generics ::core::convert::TryFrom<#root::__private::Vec<#row_ty>>
+                for #table_name #ty_generics #where_clause
+            {
+                type Error = #root::ShapeError;
+
+                /// Builds a table by pushing each row in order, failing if
+                /// there are more rows than the table can hold
+                fn try_from(
+                    rows: #root::__private::Vec<#row_ty>,
+                ) -> ::core::result::Result<Self, Self::Error> {
+                    if rows.len() > #rows {
+                        return ::core::result::Result::Err(#root::ShapeError::ExceedsCapacity {
+                            capacity: #root::Capacity::Limited(#rows),
+                            required: rows.len(),
+                        });
+                    }
+
+                    ::core::result::Result::Ok(::core::iter::FromIterator::from_iter(rows))
+                }
+            }
+        },
+        _ => quote! {
+            #[automatically_derived]
+            impl #impl_generics ::core::convert::From<#root::__private::Vec<#row_ty>>
+                for #table_name #ty_generics #where_clause
+            {
+                /// Builds a table by pushing each row in order
+                fn from(rows: #root::__private::Vec<#row_ty>) -> Self {
+                    ::core::iter::FromIterator::from_iter(rows)
+                }
+            }
+        },
+    };
+
+    // Conversions involving Vec are only emitted when alloc is available
+    quote! {
+        #[automatically_derived]
+        impl #impl_generics ::core::iter::FromIterator<#row_ty>
+            for #table_name #ty_generics #where_clause
+        {
+            /// Builds a table by pushing each row in order, where rows beyond
+            /// what the table can hold are dropped
+            fn from_iter<__Rows: ::core::iter::IntoIterator<Item = #row_ty>>(
+                rows: __Rows,
+            ) -> Self {
+                let mut table = Self::new();
+                for row in rows {
+                    table.push_row(row);
+                }
+                table
+            }
+        }
+
+        #root::__cfg_alloc! {
+            #from_vec
+
+            #[automatically_derived]
+            impl #impl_generics #table_name #ty_generics #where_clause {
+                /// Consumes the table, returning its rows in order
+                pub fn into_vec(mut self) -> #root::__private::Vec<#row_ty> {
+                    let mut rows = #root::__private::Vec::with_capacity(
+                        #root::Table::row_cnt(&self.0),
+                    );
+
+                    // Popping from the end avoids shifting the remaining rows,
+                    // so we reverse afterwards to restore their order
+                    while let ::core::option::Option::Some(row) = self.pop_row() {
+                        rows.push(row);
+                    }
+                    rows.reverse();
+                    rows
+                }
+            }
+        }
+    }
+}
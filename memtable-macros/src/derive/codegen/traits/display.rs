@@ -0,0 +1,99 @@
+use super::{utils, TableColumn, TableMode};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, Generics, Ident, Path};
+
+pub struct Args<'a> {
+    pub root: &'a Path,
+    pub mode: &'a TableMode,
+    pub table_name: &'a Ident,
+    pub generics: &'a Generics,
+    pub table_data_name: &'a Ident,
+    pub columns: &'a [&'a TableColumn],
+}
+
+pub fn make(args: Args) -> TokenStream {
+    let Args {
+        root,
+        mode,
+        table_name,
+        generics,
+        table_data_name,
+        columns,
+    } = args;
+
+    let inner_table_ty = utils::make_inner_table_type(
+        root,
+        mode,
+        table_data_name,
+        generics,
+        &utils::make_col_cnt(root, columns),
+    );
+    let variant = utils::make_variant_idents(columns);
+    let variant_ty = utils::make_variant_types(columns);
+    let option_inner_ty = utils::make_option_inner_types(columns);
+
+    // Optional columns display nothing for None
+    let fmt_arm = variant
+        .iter()
+        .zip(option_inner_ty.iter())
+        .map(|(variant, inner_ty)| match inner_ty {
+            Some(_) => quote! {
+                Self::#variant(::core::option::Option::Some(x)) => ::core::fmt::Display::fmt(x, f),
+                Self::#variant(::core::option::Option::None) => ::core::result::Result::Ok(()),
+            },
+            None => quote!(Self::#variant(x) => ::core::fmt::Display::fmt(x, f),),
+        });
+
+    // Every column's type needs to be displayable for the data to be, where
+    // optional columns only need their inner type to be displayable
+    let mut generics = generics.clone();
+    {
+        let where_clause = generics.make_where_clause();
+        for (ty, inner_ty) in variant_ty.iter().zip(option_inner_ty.iter()) {
+            let ty = inner_ty.as_ref().unwrap_or(ty);
+            where_clause
+                .predicates
+                .push(parse_quote!(#ty: ::core::fmt::Display));
+        }
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let data_ty = quote!(#table_data_name #ty_generics);
+
+    quote! {
+        #[automatically_derived]
+        impl #impl_generics ::core::fmt::Display
+            for #table_data_name #ty_generics #where_clause
+        {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self {
+                    #(#fmt_arm)*
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::core::fmt::Display
+            for #table_name #ty_generics #where_clause
+        {
+            /// Displays the table as an aligned text grid with column headers
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(
+                    &#root::display::TextTable::new(&self.0).with_headers(Self::COLUMN_NAMES),
+                    f,
+                )
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics #table_name #ty_generics #where_clause {
+            /// Returns a rendering of the table as a markdown table with
+            /// column headers
+            pub fn markdown(&self) -> #root::display::TextTable<'_, #data_ty, #inner_table_ty> {
+                #root::display::TextTable::new(&self.0)
+                    .with_headers(Self::COLUMN_NAMES)
+                    .with_style(#root::display::TextStyle::Markdown)
+            }
+        }
+    }
+}
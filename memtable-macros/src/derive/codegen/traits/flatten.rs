@@ -0,0 +1,90 @@
+use super::{utils, TableColumn};
+use darling::ast::Style;
+use quote::{format_ident, quote};
+use syn::{parse_quote, Generics, Ident, ItemImpl, Path};
+
+pub struct Args<'a> {
+    pub root: &'a Path,
+    pub style: Style,
+    pub origin_struct_name: &'a Ident,
+    pub generics: &'a Generics,
+    pub table_data_name: &'a Ident,
+    pub columns: &'a [&'a TableColumn],
+}
+
+pub fn make(args: Args) -> ItemImpl {
+    let Args {
+        root,
+        style,
+        origin_struct_name,
+        generics,
+        table_data_name,
+        columns,
+    } = args;
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let col_cnt = columns.len();
+    let column_names = utils::make_column_names(columns, ToString::to_string);
+    let fields = utils::make_field_tokens(columns);
+    let variants = utils::make_variant_idents(columns);
+    let utils::VariantMethodIdents { into_variant, .. } =
+        utils::make_variant_method_idents(style, columns);
+
+    // Every cell is taken before building so that all of them are consumed,
+    // where optional columns treat a missing cell the same as a cell of None
+    let cells: Vec<Ident> = (0..col_cnt).map(|i| format_ident!("__cell{}", i)).collect();
+    let take_cell = utils::make_option_inner_types(columns)
+        .into_iter()
+        .zip(into_variant.iter())
+        .map(|(option_inner_ty, into_variant)| {
+            let cell = quote! {
+                ::core::iter::Iterator::next(&mut cells)
+                    .and_then(::core::convert::identity)
+                    .and_then(|x| x.#into_variant())
+            };
+            match option_inner_ty {
+                Some(_) => quote!(#cell.and_then(::core::convert::identity)),
+                None => cell,
+            }
+        });
+    let values = utils::make_option_inner_types(columns)
+        .into_iter()
+        .zip(cells.iter())
+        .map(|(option_inner_ty, cell)| match option_inner_ty {
+            Some(_) => quote!(#cell),
+            None => quote!(#cell?),
+        });
+
+    let create_struct_expr = match style {
+        Style::Tuple => quote!(Self(#(#values),*)),
+        Style::Struct => quote!(Self {#(#fields: #values),*}),
+        Style::Unit => unreachable!(),
+    };
+
+    parse_quote! {
+        #[automatically_derived]
+        impl #impl_generics #root::FlattenColumns
+            for #origin_struct_name #ty_generics #where_clause
+        {
+            type Data = #table_data_name #ty_generics;
+            type IntoCells = ::core::array::IntoIter<Self::Data, #col_cnt>;
+
+            const COLUMN_CNT: ::core::primitive::usize = #col_cnt;
+            const COLUMN_NAMES: &'static [&'static ::core::primitive::str] = &[#(#column_names),*];
+
+            fn into_cells(self) -> Self::IntoCells {
+                ::core::array::IntoIter::new([
+                    #(#table_data_name::#variants(self.#fields)),*
+                ])
+            }
+
+            fn from_cells<__Cells>(mut cells: __Cells) -> ::core::option::Option<Self>
+            where
+                __Cells: ::core::iter::Iterator<Item = ::core::option::Option<Self::Data>>,
+            {
+                #(let #cells = #take_cell;)*
+                ::core::option::Option::Some(#create_struct_expr)
+            }
+        }
+    }
+}
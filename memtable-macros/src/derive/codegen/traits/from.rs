@@ -1,13 +1,14 @@
 use super::{utils, TableMode};
+use proc_macro2::TokenStream;
 use syn::{parse_quote, Generics, Ident, ItemImpl, Path};
 
 pub struct Args<'a> {
     pub root: &'a Path,
-    pub mode: TableMode,
+    pub mode: &'a TableMode,
     pub table_name: &'a Ident,
     pub generics: &'a Generics,
     pub table_data_name: &'a Ident,
-    pub col_cnt: usize,
+    pub col_cnt: &'a TokenStream,
 }
 
 pub fn make(args: Args) -> ItemImpl {
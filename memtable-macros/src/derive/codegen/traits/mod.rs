@@ -1,6 +1,9 @@
 pub mod as_ref;
+pub mod collect;
 pub mod default;
 pub mod deref;
+pub mod display;
+pub mod flatten;
 pub mod from;
 pub mod table;
 pub mod try_from;
@@ -12,7 +15,7 @@ use syn::{Generics, Ident, Path};
 
 pub struct CommonArgs<'a> {
     pub root: &'a Path,
-    pub mode: TableMode,
+    pub mode: &'a TableMode,
     pub table_name: &'a Ident,
     pub generics: &'a Generics,
     pub table_data_name: &'a Ident,
@@ -29,13 +32,15 @@ pub fn make_common(args: CommonArgs) -> TokenStream {
         columns,
     } = args;
 
+    let col_cnt = utils::make_col_cnt(root, columns);
+
     let as_ref_trait = as_ref::make(as_ref::Args {
         root,
         mode,
         table_name,
         generics,
         table_data_name,
-        col_cnt: columns.len(),
+        col_cnt: &col_cnt,
     });
 
     let default_trait = default::make(default::Args {
@@ -44,7 +49,7 @@ pub fn make_common(args: CommonArgs) -> TokenStream {
         table_name,
         generics,
         table_data_name,
-        col_cnt: columns.len(),
+        col_cnt: &col_cnt,
     });
 
     let deref_trait = deref::make(deref::Args {
@@ -53,7 +58,7 @@ pub fn make_common(args: CommonArgs) -> TokenStream {
         table_name,
         generics,
         table_data_name,
-        col_cnt: columns.len(),
+        col_cnt: &col_cnt,
     });
 
     let from_trait = from::make(from::Args {
@@ -62,7 +67,7 @@ pub fn make_common(args: CommonArgs) -> TokenStream {
         table_name,
         generics,
         table_data_name,
-        col_cnt: columns.len(),
+        col_cnt: &col_cnt,
     });
 
     let try_from_trait = try_from::make(try_from::Args {
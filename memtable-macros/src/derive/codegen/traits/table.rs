@@ -1,9 +1,9 @@
-use super::{TableColumn, TableMode};
+use super::{utils, TableColumn, TableMode};
 use syn::{parse_quote, Generics, Ident, ItemImpl, Path, Type};
 
 pub struct Args<'a> {
     pub root: &'a Path,
-    pub mode: TableMode,
+    pub mode: &'a TableMode,
     pub table_name: &'a Ident,
     pub generics: &'a Generics,
     pub table_data_name: &'a Ident,
@@ -21,7 +21,8 @@ pub fn make(args: Args) -> ItemImpl {
     } = args;
 
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
-    let cols = columns.len();
+    let cols = utils::make_col_cnt(root, columns);
+    let inner_table_ty = utils::make_inner_table_type(root, mode, table_data_name, generics, &cols);
 
     let row_t: Type = match mode {
         TableMode::Dynamic => {
@@ -30,12 +31,14 @@ pub fn make(args: Args) -> ItemImpl {
         TableMode::Fixed { .. } | TableMode::FixedColumn => {
             parse_quote!(#root::list::FixedList<Self::Data, #cols>)
         }
+        TableMode::Custom(_) => parse_quote!(<#inner_table_ty as #root::Table>::Row),
     };
     let col_t: Type = match mode {
         TableMode::Dynamic | TableMode::FixedColumn => {
             parse_quote!(#root::list::DynamicList<Self::Data>)
         }
         TableMode::Fixed { rows } => parse_quote!(#root::list::FixedList<Self::Data, #rows>),
+        TableMode::Custom(_) => parse_quote!(<#inner_table_ty as #root::Table>::Column),
     };
 
     parse_quote! {
@@ -1,18 +1,19 @@
 use super::{utils, TableColumn, TableMode};
-use quote::format_ident;
-use syn::{parse_quote, Generics, Ident, ItemImpl, Path};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Generics, Ident, Path};
 use voca_rs::case;
 
 pub struct Args<'a> {
     pub root: &'a Path,
-    pub mode: TableMode,
+    pub mode: &'a TableMode,
     pub table_name: &'a Ident,
     pub generics: &'a Generics,
     pub table_data_name: &'a Ident,
     pub columns: &'a [&'a TableColumn],
 }
 
-pub fn make(args: Args) -> ItemImpl {
+pub fn make(args: Args) -> TokenStream {
     let Args {
         root,
         mode,
@@ -23,57 +24,113 @@ pub fn make(args: Args) -> ItemImpl {
     } = args;
 
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
-    let variant = utils::make_variant_idents(columns);
     let ty = utils::make_variant_types(columns);
     let is_ty: Vec<Ident> = utils::make_column_names(columns, case::snake_case)
         .into_iter()
         .map(|name| format_ident!("is_{}", name))
         .collect();
-    let idx = utils::make_column_indexes(columns);
-    let inner_table_ty =
-        utils::make_inner_table_type(root, mode, table_data_name, generics, columns.len());
+    let idx = utils::make_column_indexes(root, columns);
+    let name = utils::make_column_names(columns, ToString::to_string);
+    let inner_table_ty = utils::make_inner_table_type(
+        root,
+        mode,
+        table_data_name,
+        generics,
+        &utils::make_col_cnt(root, columns),
+    );
+
+    // Flattened columns check each of the cells they span, and whatever is
+    // done with a failed check is left to the caller
+    let make_checks = |on_error: TokenStream| {
+        let checks = columns.iter().enumerate().map(|(i, col)| {
+            let idx = &idx[i];
+            let is_ty = &is_ty[i];
+            let ty = &ty[i];
+            let name = &name[i];
+            let make_check = |idx: &TokenStream, nested_name: &TokenStream| {
+                quote! {
+                    match #root::Table::cell(&table, row, #idx) {
+                        ::core::option::Option::Some(cell) if cell.#is_ty() => {}
+                        cell => {
+                            let error = #root::CellError {
+                                row,
+                                column: #idx,
+                                name: #name,
+                                nested_name: #nested_name,
+                                expected: ::core::stringify!(#ty),
+                                found: cell.map(|cell| cell.variant_name()),
+                            };
+                            #on_error
+                        }
+                    }
+                }
+            };
+
+            if col.flatten.is_some() {
+                let check = make_check(
+                    &quote!(#idx + i),
+                    &quote! {
+                        ::core::option::Option::Some(
+                            <#ty as #root::FlattenColumns>::COLUMN_NAMES[i],
+                        )
+                    },
+                );
+                quote! {
+                    for i in 0..<#ty as #root::FlattenColumns>::COLUMN_CNT {
+                        #check
+                    }
+                }
+            } else {
+                make_check(idx, &quote!(::core::option::Option::None))
+            }
+        });
+
+        quote!(#(#checks)*)
+    };
 
-    parse_quote! {
+    let checks = make_checks(quote!(return ::core::result::Result::Err(error);));
+    let report_checks = make_checks(quote!(errors.push(error);));
+
+    quote! {
         #[automatically_derived]
         impl #impl_generics ::core::convert::TryFrom<#inner_table_ty>
             for #table_name #ty_generics #where_clause
         {
-            type Error = &'static ::core::primitive::str;
+            type Error = #root::CellError;
 
+            /// Converts the table, failing at the first cell that is missing
+            /// or holds the wrong variant for its column
             fn try_from(table: #inner_table_ty) -> ::core::result::Result<Self, Self::Error> {
                 for row in 0..#root::Table::row_cnt(&table) {
-                    #(
-                        let cell = #root::Table::cell(&table, row, #idx);
-
-                        if cell.is_none() {
-                            return ::core::result::Result::Err(
-                                ::core::concat!(
-                                    "Cell in column ",
-                                    ::core::stringify!(#idx),
-                                    "/",
-                                    ::core::stringify!(#variant),
-                                    " is missing",
-                                )
-                            );
-                        }
-
-                        if !cell.unwrap().#is_ty() {
-                            return ::core::result::Result::Err(
-                                ::core::concat!(
-                                    "Cell in column ",
-                                    ::core::stringify!(#idx),
-                                    "/",
-                                    ::core::stringify!(#variant),
-                                    " is not of type ",
-                                    ::core::stringify!(#ty),
-                                )
-                            );
-                        }
-                    )*
+                    #checks
                 }
 
                 ::core::result::Result::Ok(Self(table))
             }
         }
+
+        #root::__cfg_alloc! {
+            #[automatically_derived]
+            impl #impl_generics #table_name #ty_generics #where_clause {
+                /// Converts the table like `try_from`, but checks
+                /// every cell and reports all that are missing or hold the
+                /// wrong variant for their column
+                pub fn try_from_report(
+                    table: #inner_table_ty,
+                ) -> ::core::result::Result<Self, #root::__private::Vec<#root::CellError>> {
+                    let mut errors = #root::__private::Vec::new();
+
+                    for row in 0..#root::Table::row_cnt(&table) {
+                        #report_checks
+                    }
+
+                    if errors.is_empty() {
+                        ::core::result::Result::Ok(Self(table))
+                    } else {
+                        ::core::result::Result::Err(errors)
+                    }
+                }
+            }
+        }
     }
 }
@@ -2,18 +2,19 @@ use super::{TableColumn, TableMode};
 use darling::ast::Style;
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
-use syn::{parse_quote, Generics, Ident, LitInt, LitStr, Path, Type};
+use syn::{
+    parse_quote, GenericArgument, Generics, Ident, LitInt, LitStr, Path, PathArguments, Type,
+};
 use voca_rs::case;
 
 pub fn make_inner_table_type(
     root: &Path,
-    mode: TableMode,
+    mode: &TableMode,
     table_data_name: &Ident,
     generics: &Generics,
-    col_cnt: usize,
+    col_cnt: &TokenStream,
 ) -> Type {
     let (_, ty_generics, _) = generics.split_for_impl();
-    let col_cnt: LitInt = parse_quote!(#col_cnt);
 
     match mode {
         TableMode::Dynamic => {
@@ -26,6 +27,32 @@ pub fn make_inner_table_type(
             let row_cnt: LitInt = parse_quote!(#rows);
             parse_quote!(#root::FixedTable<#table_data_name #ty_generics, #row_cnt, #col_cnt>)
         }
+        TableMode::Custom(_) => {
+            let path = mode
+                .to_custom_path()
+                .expect("BUG: Custom mode missing path!")
+                .expect("BUG: Custom mode path not validated!");
+            parse_quote!(#path<#table_data_name #ty_generics>)
+        }
+    }
+}
+
+/// Produces the total columns of the table, which is a literal unless some
+/// columns are flattened, in which case it is a braced const expression
+pub fn make_col_cnt(root: &Path, columns: &[&TableColumn]) -> TokenStream {
+    let cnt = columns.iter().filter(|col| col.flatten.is_none()).count();
+    let flatten_tys: Vec<&Type> = columns
+        .iter()
+        .filter(|col| col.flatten.is_some())
+        .map(|col| &col.ty)
+        .collect();
+
+    if flatten_tys.is_empty() {
+        let cnt: LitInt = parse_quote!(#cnt);
+        quote!(#cnt)
+    } else {
+        let cnt = syn::Index::from(cnt);
+        quote!({ #cnt #(+ <#flatten_tys as #root::FlattenColumns>::COLUMN_CNT)* })
     }
 }
 
@@ -35,7 +62,7 @@ pub fn make_variant_idents(columns: &[&TableColumn]) -> Vec<Ident> {
         .enumerate()
         .map(|(idx, col)| {
             let name = if let Some(name) = col.name.as_ref() {
-                case::pascal_case(&name)
+                case::pascal_case(name)
             } else if let Some(name) = col.ident.as_ref() {
                 case::pascal_case(&name.to_string())
             } else {
@@ -50,8 +77,120 @@ pub fn make_variant_types(columns: &[&TableColumn]) -> Vec<Type> {
     columns.iter().map(|col| col.ty.clone()).collect()
 }
 
-pub fn make_column_indexes(columns: &[&TableColumn]) -> Vec<syn::Index> {
-    (0..columns.len()).map(syn::Index::from).collect()
+/// Produces the type stored by each variant of the table's data, which is
+/// the data of the nested type for flattened columns
+pub fn make_data_types(root: &Path, columns: &[&TableColumn]) -> Vec<Type> {
+    columns
+        .iter()
+        .map(|col| {
+            let ty = &col.ty;
+            if col.flatten.is_some() {
+                parse_quote!(<#ty as #root::FlattenColumns>::Data)
+            } else {
+                ty.clone()
+            }
+        })
+        .collect()
+}
+
+/// Produces the inner type `T` of each column whose type is `Option<T>`,
+/// which is treated as an optional column where a missing cell means `None`
+pub fn make_option_inner_types(columns: &[&TableColumn]) -> Vec<Option<Type>> {
+    columns
+        .iter()
+        .map(|col| match col.flatten {
+            Some(_) => None,
+            None => option_inner_type(&col.ty).cloned(),
+        })
+        .collect()
+}
+
+/// Produces the type of reference yielded when accessing each column, which
+/// is `Option<&T>` for optional columns, `T` for flattened columns as they
+/// are rebuilt from their cells, and `&T` otherwise
+pub fn make_ref_types(columns: &[&TableColumn]) -> Vec<Type> {
+    columns
+        .iter()
+        .map(|col| match option_inner_type(&col.ty) {
+            _ if col.flatten.is_some() => col.ty.clone(),
+            Some(ty) => parse_quote!(::core::option::Option<&#ty>),
+            None => {
+                let ty = &col.ty;
+                parse_quote!(&#ty)
+            }
+        })
+        .collect()
+}
+
+/// Returns the type `T` if the provided type is `Option<T>`, which is
+/// recognized by its last path segment being `Option` with one type argument
+pub fn option_inner_type(ty: &Type) -> Option<&Type> {
+    let path = match ty {
+        Type::Path(x) if x.qself.is_none() => &x.path,
+        _ => return None,
+    };
+
+    let segment = path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+
+    match &segment.arguments {
+        PathArguments::AngleBracketed(x) if x.args.len() == 1 => match x.args.first() {
+            Some(GenericArgument::Type(ty)) => Some(ty),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Produces a where clause requiring the data of each flattened column to be
+/// cloneable, which is needed to rebuild the column's value from its cells,
+/// or nothing if no column is flattened
+///
+/// The bound is higher-ranked so that it is only checked when used rather
+/// than failing the derive for data that is not cloneable
+pub fn make_flatten_where_clause(root: &Path, columns: &[&TableColumn]) -> TokenStream {
+    let flatten_tys: Vec<&Type> = columns
+        .iter()
+        .filter(|col| col.flatten.is_some())
+        .map(|col| &col.ty)
+        .collect();
+
+    if flatten_tys.is_empty() {
+        quote!()
+    } else {
+        quote! {
+            where #(for<'__a> <#flatten_tys as #root::FlattenColumns>::Data: ::core::clone::Clone),*
+        }
+    }
+}
+
+/// Produces the index of each column within the table, which is a literal
+/// unless flattened columns precede it, in which case it is an expression
+/// accounting for the columns they expand into; flattened columns start
+/// at their index
+pub fn make_column_indexes(root: &Path, columns: &[&TableColumn]) -> Vec<TokenStream> {
+    let mut cnt = 0;
+    let mut flatten_tys = Vec::new();
+    let mut indexes = Vec::new();
+
+    for col in columns {
+        let idx = syn::Index::from(cnt);
+        indexes.push(if flatten_tys.is_empty() {
+            quote!(#idx)
+        } else {
+            quote!((#idx #(+ <#flatten_tys as #root::FlattenColumns>::COLUMN_CNT)*))
+        });
+
+        if col.flatten.is_some() {
+            flatten_tys.push(&col.ty);
+        } else {
+            cnt += 1;
+        }
+    }
+
+    indexes
 }
 
 pub fn make_snake_idents(columns: &[&TableColumn]) -> Vec<Ident> {
@@ -60,7 +199,7 @@ pub fn make_snake_idents(columns: &[&TableColumn]) -> Vec<Ident> {
         .enumerate()
         .map(|(idx, col)| {
             let name = if let Some(name) = col.name.as_ref() {
-                case::snake_case(&name)
+                case::snake_case(name)
             } else if let Some(name) = col.ident.as_ref() {
                 case::snake_case(&name.to_string())
             } else {
@@ -141,7 +280,7 @@ pub fn make_column_names(
         .enumerate()
         .map(|(idx, col)| {
             if let Some(name) = col.name.as_ref() {
-                map_name(&name)
+                map_name(name)
             } else if let Some(name) = col.ident.as_ref() {
                 map_name(&name.to_string())
             } else {
@@ -151,6 +290,68 @@ pub fn make_column_names(
         .collect()
 }
 
+/// Produces a pattern per column that matches its current name as well as
+/// the name it was renamed from, if any
+pub fn make_column_name_patterns(columns: &[&TableColumn]) -> Vec<TokenStream> {
+    make_column_names(columns, ToString::to_string)
+        .into_iter()
+        .zip(columns)
+        .map(|(name, col)| match col.renamed_from.as_ref() {
+            Some(old_name) => quote!(#name | #old_name),
+            None => quote!(#name),
+        })
+        .collect()
+}
+
+/// Produces statements that return the result of `make_expr` for the index
+/// of a column nested within a flattened column, which is named by the
+/// flattened column's name followed by an underscore and the nested name
+pub fn make_flatten_name_lookup(
+    root: &Path,
+    columns: &[&TableColumn],
+    make_expr: impl Fn(&TokenStream) -> TokenStream,
+) -> TokenStream {
+    let idx = make_column_indexes(root, columns);
+    let lookups = make_column_names(columns, |name| format!("{}_", name))
+        .into_iter()
+        .zip(columns.iter().zip(idx.iter()))
+        .filter(|(_, (col, _))| col.flatten.is_some())
+        .map(|(prefix, (col, idx))| {
+            let ty = &col.ty;
+            let expr = make_expr(&quote!(#idx + i));
+            quote! {
+                if let ::core::option::Option::Some(name) =
+                    ::core::primitive::str::strip_prefix(name, #prefix)
+                {
+                    let i = ::core::iter::Iterator::position(
+                        &mut ::core::iter::IntoIterator::into_iter(
+                            <#ty as #root::FlattenColumns>::COLUMN_NAMES,
+                        ),
+                        |x| *x == name,
+                    );
+
+                    if let ::core::option::Option::Some(i) = i {
+                        return ::core::option::Option::Some(#expr);
+                    }
+                }
+            }
+        });
+
+    quote!(#(#lookups)*)
+}
+
+/// Produces the variant idents that columns had prior to being renamed
+pub fn make_renamed_variant_idents(columns: &[&TableColumn]) -> Vec<Option<Ident>> {
+    columns
+        .iter()
+        .map(|col| {
+            col.renamed_from
+                .as_ref()
+                .map(|name| format_ident!("{}", case::pascal_case(name)))
+        })
+        .collect()
+}
+
 #[inline]
 pub fn bug_str() -> LitStr {
     let msg = concat!(
@@ -10,10 +10,11 @@ use parse::{StructTable, TableColumn, TableMode};
 
 pub fn do_derive_table(root: Path, input: DeriveInput) -> darling::Result<TokenStream> {
     match &input.data {
-        Data::Struct(_) => Ok(derive_table_from_struct(
-            root,
-            StructTable::from_derive_input(&input)?,
-        )),
+        Data::Struct(_) => {
+            let table = StructTable::from_derive_input(&input)?;
+            table.validate()?;
+            Ok(derive_table_from_struct(root, table))
+        }
         Data::Enum(_) => Err(darling::Error::custom("Enums are not supported").with_span(&input)),
         Data::Union(_) => Err(darling::Error::custom("Unions are not supported").with_span(&input)),
     }
@@ -27,20 +28,26 @@ fn derive_table_from_struct(root: Path, table: StructTable) -> TokenStream {
     let table_data_name = table.to_table_data_name();
     let generics = &table.generics;
     let columns = table.columns();
-    let mode = table.mode;
+    let mode = &table.mode;
     let style = table.as_style();
 
     let codegen::data::Return {
         definition: data_definition,
         core_impl: data_core_impl,
         default_impl: data_default_impl,
+        auto_derive_impls: data_auto_derive_impls,
     } = codegen::data::make(codegen::data::Args {
+        root: &root,
         vis,
         style,
         mode,
         table_data_name: &table_data_name,
         generics,
         derive: table.data_attr.as_ref().and_then(|x| x.derive.as_ref()),
+        skip_auto_derive: table
+            .data_attr
+            .as_ref()
+            .is_some_and(|x| x.skip_auto_derive.is_some()),
         columns: &columns,
     });
 
@@ -74,6 +81,53 @@ fn derive_table_from_struct(root: Path, table: StructTable) -> TokenStream {
         (None, None)
     };
 
+    let display_traits = table.display.as_ref().map(|_| {
+        codegen::traits::display::make(codegen::traits::display::Args {
+            root: &root,
+            mode,
+            table_name: &table_name,
+            generics,
+            table_data_name: &table_data_name,
+            columns: &columns,
+        })
+    });
+
+    let collect_traits = codegen::traits::collect::make(codegen::traits::collect::Args {
+        root: &root,
+        mode,
+        origin_struct_name: &table.ident,
+        table_name: &table_name,
+        generics,
+    });
+
+    // Tables with flattened columns cannot themselves be flattened as the
+    // names of their columns are only known at runtime
+    let flatten_trait = if !table.has_flatten() {
+        Some(codegen::traits::flatten::make(
+            codegen::traits::flatten::Args {
+                root: &root,
+                style,
+                origin_struct_name: &table.ident,
+                generics,
+                table_data_name: &table_data_name,
+                columns: &columns,
+            },
+        ))
+    } else {
+        None
+    };
+
+    let query_methods = table.queries.as_ref().map(|_| {
+        codegen::queries::make(codegen::queries::Args {
+            root: &root,
+            style,
+            origin_struct_name: &table.ident,
+            table_name: &table_name,
+            generics,
+            columns: &columns,
+        })
+    });
+
     let table_impl = codegen::make_table_impl(codegen::TableImplArgs {
         root: &root,
         mode,
@@ -82,6 +136,8 @@ fn derive_table_from_struct(root: Path, table: StructTable) -> TokenStream {
         table_name: &table_name,
         generics: &table.generics,
         table_data_name: &table_data_name,
+        version: table.to_version(),
+        queries: table.queries.is_some(),
         columns: &columns,
     });
 
@@ -90,7 +146,7 @@ fn derive_table_from_struct(root: Path, table: StructTable) -> TokenStream {
         mode,
         &table_data_name,
         &table.generics,
-        columns.len(),
+        &codegen::utils::make_col_cnt(&root, &columns),
     );
 
     let derive_attr = table
@@ -106,10 +162,15 @@ fn derive_table_from_struct(root: Path, table: StructTable) -> TokenStream {
         #data_definition
         #data_core_impl
         #data_default_impl
+        #(#data_auto_derive_impls)*
         #common_traits
         #struct_to_parts
         #parts_to_struct
         #table_trait
+        #flatten_trait
         #table_impl
+        #collect_traits
+        #display_traits
+        #query_methods
     }
 }
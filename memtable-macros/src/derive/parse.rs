@@ -4,7 +4,7 @@ use darling::{
     FromDeriveInput, FromField, FromMeta,
 };
 use quote::format_ident;
-use syn::{Generics, Ident, Type, Visibility};
+use syn::{Expr, Generics, Ident, Path, Type, Visibility};
 
 /// Information about a table's Rust struct
 #[derive(Debug, FromDeriveInput)]
@@ -27,6 +27,16 @@ pub struct StructTable {
     #[darling(default)]
     pub skip_parts: Option<SpannedValue<()>>,
 
+    /// If provided, will implement Display for the table and its data,
+    /// rendering the table as an aligned text grid with column headers
+    #[darling(default)]
+    pub display: Option<SpannedValue<()>>,
+
+    /// If provided, will generate methods to find, filter, and page through
+    /// the rows of the table
+    #[darling(default)]
+    pub queries: Option<SpannedValue<()>>,
+
     /// Attributes within data(...)
     #[darling(default, rename = "data")]
     pub data_attr: Option<TableDataAttr>,
@@ -38,6 +48,11 @@ pub struct StructTable {
     /// Mode to use when generating the table
     #[darling(default)]
     pub mode: TableMode,
+
+    /// If provided, current version of the table's schema, used to generate
+    /// migrations from earlier versions
+    #[darling(default)]
+    pub version: Option<SpannedValue<usize>>,
 }
 
 impl StructTable {
@@ -68,6 +83,100 @@ impl StructTable {
         let x = self.data.as_ref().take_struct();
         x.unwrap().fields
     }
+
+    /// Returns true if any column is flattened
+    pub fn has_flatten(&self) -> bool {
+        self.columns().iter().any(|col| col.flatten.is_some())
+    }
+
+    /// Returns the current version of the table's schema, defaulting to 1
+    pub fn to_version(&self) -> usize {
+        self.version.as_ref().map(|x| **x).unwrap_or(1)
+    }
+
+    /// Validates attributes whose correctness depends on one another
+    pub fn validate(&self) -> darling::Result<()> {
+        let mut errors = Vec::new();
+
+        if let Some(version) = self.version.as_ref() {
+            if **version == 0 {
+                errors
+                    .push(darling::Error::custom("Version must be at least 1").with_span(version));
+            }
+        }
+
+        // Flattened columns have names and counts only known at runtime
+        let flatten = self
+            .columns()
+            .into_iter()
+            .find_map(|col| col.flatten.as_ref());
+        if let Some(flatten) = flatten {
+            if let Some(display) = self.display.as_ref() {
+                errors.push(
+                    darling::Error::custom("Display is not supported alongside flattened columns")
+                        .with_span(display),
+                );
+            }
+
+            if self.to_version() > 1 {
+                errors.push(
+                    darling::Error::custom(
+                        "Migrations are not supported alongside flattened columns",
+                    )
+                    .with_span(flatten),
+                );
+            }
+        }
+
+        if let Some(Err(x)) = self.mode.to_custom_path() {
+            errors.push(darling::Error::custom(format!(
+                "Invalid custom table type: {}",
+                x
+            )));
+        }
+
+        let version = self.to_version();
+        for col in self.columns() {
+            if let Some(flatten) = col.flatten.as_ref() {
+                let is_unsupported = col.indexed.is_some()
+                    || col.stats.is_some()
+                    || col.renamed_from.is_some()
+                    || col.added_in.is_some()
+                    || col.default.is_some();
+                if is_unsupported {
+                    errors.push(
+                        darling::Error::custom(concat!(
+                            "Flattened columns do not support ",
+                            "indexed, stats, renamed_from, added_in, or default",
+                        ))
+                        .with_span(flatten),
+                    );
+                }
+            }
+
+            if let Some(added_in) = col.added_in.as_ref() {
+                if **added_in == 0 || **added_in > version {
+                    errors.push(
+                        darling::Error::custom(format!(
+                            "Column must be added in a version between 1 and {}",
+                            version
+                        ))
+                        .with_span(added_in),
+                    );
+                }
+            }
+
+            if let Some(Err(x)) = col.to_default_expr() {
+                errors.push(darling::Error::from(x));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(darling::Error::multiple(errors))
+        }
+    }
 }
 
 /// Information for a data(...) attribute
@@ -81,10 +190,15 @@ pub struct TableDataAttr {
     /// Derives to forward to derived table data
     #[darling(default)]
     pub derive: Option<PathList>,
+
+    /// If provided, will skip implementing Clone, PartialEq, Eq, and Hash
+    /// for the derived table data
+    #[darling(default)]
+    pub skip_auto_derive: Option<SpannedValue<()>>,
 }
 
 /// Represents the mode to use when generating code for a table
-#[derive(Copy, Clone, Debug, PartialEq, Eq, FromMeta)]
+#[derive(Clone, Debug, PartialEq, Eq, FromMeta)]
 #[darling(rename_all = "snake_case")]
 pub enum TableMode {
     /// Generated table leverages a dynamic table underneath
@@ -98,6 +212,21 @@ pub enum TableMode {
     /// the column count matches the total number of fields from the struct
     /// and the row count is specified manually by the end user
     Fixed { rows: usize },
+
+    /// Generated table leverages a user-provided table underneath, where the
+    /// type is specified by path and must be generic over the table's data
+    /// as its only type parameter
+    Custom(String),
+}
+
+impl TableMode {
+    /// Returns the path to the user-provided table type if the mode is custom
+    pub fn to_custom_path(&self) -> Option<syn::Result<Path>> {
+        match self {
+            Self::Custom(x) => Some(syn::parse_str(x)),
+            _ => None,
+        }
+    }
 }
 
 impl Default for TableMode {
@@ -121,7 +250,44 @@ pub struct TableColumn {
     #[darling(default)]
     pub indexed: Option<SpannedValue<()>>,
 
+    /// If provided, flags column to have min, max, and sum methods generated
+    #[darling(default)]
+    pub stats: Option<SpannedValue<()>>,
+
     /// If provided, name to use for column instead of its field name
     #[darling(default)]
     pub name: Option<String>,
+
+    /// If provided, flags column to be expanded into the columns of its type,
+    /// which must implement `FlattenColumns`
+    #[darling(default)]
+    pub flatten: Option<SpannedValue<()>>,
+
+    /// If provided, name the column had in earlier versions of the table
+    #[darling(default)]
+    pub renamed_from: Option<String>,
+
+    /// If provided, version of the table in which the column was added
+    #[darling(default)]
+    pub added_in: Option<SpannedValue<usize>>,
+
+    /// If provided, expression used to populate the column when migrating
+    /// from a version prior to it being added
+    #[darling(default)]
+    pub default: Option<SpannedValue<String>>,
+}
+
+impl TableColumn {
+    /// Returns the version of the table in which the column was added,
+    /// defaulting to 1
+    pub fn to_added_in(&self) -> usize {
+        self.added_in.as_ref().map(|x| **x).unwrap_or(1)
+    }
+
+    /// Parses the default expression of the column, if one was provided
+    pub fn to_default_expr(&self) -> Option<syn::Result<Expr>> {
+        self.default
+            .as_ref()
+            .map(|x| syn::parse_str(x).map_err(|e| syn::Error::new(x.span(), e)))
+    }
 }
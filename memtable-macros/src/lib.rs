@@ -17,6 +17,12 @@ mod utils;
 /// unique data enum whose variants represent the different possible types
 /// outlined by individual fields.
 ///
+/// The table can be collected from an iterator of the struct and, when alloc
+/// is available, converted to and from a `Vec` of the struct while keeping
+/// the order of rows. Collecting into a table with a fixed number of rows
+/// drops any rows beyond it, so these tables are converted from a `Vec` with
+/// `TryFrom`, which fails with a `ShapeError` instead.
+///
 /// ### Table-wide Attributes
 ///
 /// All table-level attributes use `#[table(...)]` as the starting point.
@@ -28,6 +34,9 @@ mod utils;
 /// |**derive**    |`derive(Debug, ...)`     |Forwards derive attributes to the derived table|
 /// |**skip_parts**|`skip_parts`             |Skips implementing `From` bidirectionally between the table and a tuple of its field types|
 /// |**data**      |`data(...)`              |Specify attributes on a derived table's data   |
+/// |**display**   |`display`                |Implements `Display` for the table as an aligned text grid with column headers (and for its data), along with a `markdown` method to render a markdown table|
+/// |**queries**   |`queries`                |Generates a `find_first_by_...` method per column that returns the index of the first matching row and, when alloc is available, `filter_rows` and `rows_page` methods that return copies of rows|
+/// |**version**   |`version = 2`            |Sets the current version of the table's schema, generating a `migrate_from_v{N}` method for each earlier version|
 ///
 /// The mode attribute is a bit special in that it decides the underlying table
 /// used to power the derived table. By default, `dynamic` is the mode used when
@@ -40,6 +49,11 @@ mod utils;
 /// |**dynamic**     |`mode = "dynamic"`         |Produces a table that wraps `DynamicTable`|
 /// |**fixed_column**|`mode = "fixed_column"`    |Produces a table that wraps `FixedColumnTable` where the total columns matches total fields|
 /// |**fixed**       |`mode(fixed(rows = "..."))`|Produces a table that wraps `FixedTable` where the total columns matches total fields and the total rows is specified via the `rows` param|
+/// |**custom**      |`mode(custom = "...")`     |Produces a table that wraps the user-provided table type at the given path, which must take the data as its only type parameter and implement `Table` and `Default`|
+///
+/// Unlike the fixed modes, the `custom` mode does not implement `Default` for
+/// the derived data, so a custom table that requires it needs the impl to be
+/// written by hand.
 ///
 /// ### Data-wide Attributes
 ///
@@ -49,6 +63,11 @@ mod utils;
 /// |--------------|------------------------|-----------------------------------------------|
 /// |**name**      |`name = "SomeOtherData"`|Changes the name of the derived table data     |
 /// |**derive**    |`derive(Debug, ...)`    |Forwards derive attributes to the derived data |
+/// |**skip_auto_derive**|`skip_auto_derive`|Skips implementing `Clone`, `PartialEq`, `Eq`, and `Hash` for the derived data|
+///
+/// The derived data implements `Clone`, `PartialEq`, `Eq`, and `Hash`
+/// whenever every column's type does, unless the trait is already listed
+/// within `derive(...)`. Use `skip_auto_derive` to implement them by hand.
 ///
 /// ### Column-specific Attributes
 ///
@@ -57,7 +76,34 @@ mod utils;
 /// |Attribute Name|Usage         |Description                                                     |
 /// |--------------|--------------|----------------------------------------------------------------|
 /// |**name**      |`name = "..."`|Changes the name of column when generating methods related to it|
-/// |**indexed**   |`indexed`     |Flags the column as indexed for faster lookups at the cost of additional storage|
+/// |**indexed**   |`indexed`     |Flags the column as indexed for faster lookups at the cost of additional storage; currently generates an `upsert_by_...` method that treats the column as a primary key, finding the matching row with a linear scan of the column|
+/// |**stats**     |`stats`       |Generates `..._column_min`, `..._column_max`, and `..._column_sum` methods for the column, available when its type implements `Ord` and `Sum` respectively (optional columns skip `None`)|
+/// |**flatten**   |`flatten`     |Expands the column into the columns of its type, which must implement `FlattenColumns` (as every derived table's struct does), naming each `{column}_{nested}`|
+/// |**renamed_from**|`renamed_from = "..."`|Marks the name the column had before being renamed, which is still accepted when looking up the column by name and, if the data derives `Deserialize`, when deserializing its data|
+/// |**added_in**  |`added_in = 2`|Marks the version of the table in which the column was added, meaning migrations from earlier versions populate it rather than expect it|
+/// |**default**   |`default = "..."`|Expression converted via `Into` to populate the column when migrating from a version prior to it being added, otherwise using `Default`|
+///
+/// When migrating, the old table is expected to hold the data of the current
+/// table, where the columns that existed at the time appear in their current
+/// relative order.
+///
+/// ### Optional Columns
+///
+/// A column whose type is `Option<T>` is treated as optional, where a missing
+/// cell is the same as a cell of `None`. Accessors of an optional column yield
+/// `Option<&T>` instead of `&Option<T>`, its column iterators yield an item for
+/// every row rather than stopping at the first missing cell, and rows never
+/// fail to be retrieved because of it.
+///
+/// ### Flattened Columns
+///
+/// A flattened column spans each of the columns of its type, where its
+/// accessors rebuild the value from those cells (requiring the nested data to
+/// be `Clone`) and the `..._column` methods are not generated. As the names of
+/// the nested columns are only known at runtime, a table with flattened
+/// columns does not have `COLUMN_NAMES`, cannot be flattened itself, and
+/// does not support `display` or `version`. Flattened columns do not support
+/// any other column attributes.
 ///
 /// ### Examples
 ///
@@ -14,7 +14,7 @@ fn get_crate(cname: &str, no_error: bool) -> darling::Result<Path> {
     let res = crate_name(cname)
         .map(|found_crate| match found_crate {
             FoundCrate::Itself => {
-                let crate_ident = Ident::new(&cname, Span::mixed_site());
+                let crate_ident = Ident::new(cname, Span::mixed_site());
                 parse_quote!(::#crate_ident)
             }
             FoundCrate::Name(name) => {
@@ -26,7 +26,7 @@ fn get_crate(cname: &str, no_error: bool) -> darling::Result<Path> {
 
     match res {
         Err(_) if no_error => {
-            let crate_ident = Ident::new(&cname, Span::mixed_site());
+            let crate_ident = Ident::new(cname, Span::mixed_site());
             Ok(parse_quote!(::#crate_ident))
         }
         x => x,
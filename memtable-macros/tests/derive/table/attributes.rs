@@ -85,6 +85,61 @@ fn should_support_renaming_table_data() {
     let _ = OtherData::Field2(true);
 }
 
+#[test]
+fn should_implement_common_traits_for_data_when_columns_do() {
+    use std::collections::HashSet;
+
+    #[derive(Table)]
+    struct MyStruct {
+        field1: u8,
+        field2: String,
+    }
+
+    // Columns that do not implement the traits must not fail the derive
+    #[allow(dead_code)]
+    struct NotComparable;
+
+    #[derive(Table)]
+    #[allow(dead_code)]
+    struct OtherStruct {
+        field1: NotComparable,
+    }
+
+    let data = MyStructTableData::Field2(String::from("abc"));
+    assert!(data.clone() == data);
+    assert!(data != MyStructTableData::Field2(String::from("def")));
+    assert!(data != MyStructTableData::Field1(123));
+
+    let set: HashSet<MyStructTableData> = vec![
+        MyStructTableData::Field1(1),
+        MyStructTableData::Field1(1),
+        MyStructTableData::Field2(String::from("1")),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn should_support_skipping_auto_derive_for_data() {
+    #[derive(Table)]
+    #[table(data(skip_auto_derive))]
+    struct MyStruct {
+        field1: u8,
+        field2: bool,
+    }
+
+    // Implementing would conflict with the derived data
+    // if we didn't skip it
+    impl PartialEq for MyStructTableData {
+        fn eq(&self, _: &Self) -> bool {
+            true
+        }
+    }
+
+    assert!(MyStructTableData::Field1(1) == MyStructTableData::Field2(false));
+}
+
 #[test]
 fn should_support_renaming_columns() {
     #[derive(Table)]
@@ -130,9 +185,244 @@ fn should_support_indexing_columns() {
         field2: bool,
     }
 
-    // NOTE: This currently does nothing, but when it does we'll want to update
-    //       this test with whatever logic is appropriate! Reserved here to
-    //       ensure that it compiles to enable future-forward development
-    //       where users can go ahead and mark columns as indexed and expect
-    //       performance improvements later on
+    let mut table = MyStructTable::new();
+    table.push_row(MyStruct {
+        field1: 1,
+        field2: false,
+    });
+
+    // Check upsert_by_{} replaces a row with a matching indexed column
+    let old = table.upsert_by_field1(MyStruct {
+        field1: 1,
+        field2: true,
+    });
+    assert_eq!(old.map(|x| x.field2), Some(false));
+    assert_eq!(table.row(0), Some((&1, &true)));
+
+    // Check upsert_by_{} pushes a row without a matching indexed column
+    let old = table.upsert_by_field1(MyStruct {
+        field1: 2,
+        field2: false,
+    });
+    assert!(old.is_none());
+    assert_eq!(table.row(1), Some((&2, &false)));
+}
+
+#[test]
+fn should_support_column_stats() {
+    #[derive(Table)]
+    struct MyStruct {
+        #[column(stats)]
+        field1: u8,
+        #[column(stats)]
+        field2: Option<i32>,
+    }
+
+    let mut table = MyStructTable::new();
+    assert_eq!(table.field1_column_min(), None);
+    assert_eq!(table.field1_column_max(), None);
+    assert_eq!(table.field1_column_sum(), 0);
+
+    table.push_row(MyStruct {
+        field1: 3,
+        field2: Some(-5),
+    });
+    table.push_row(MyStruct {
+        field1: 1,
+        field2: None,
+    });
+    table.push_row(MyStruct {
+        field1: 7,
+        field2: Some(10),
+    });
+
+    assert_eq!(table.field1_column_min(), Some(&1));
+    assert_eq!(table.field1_column_max(), Some(&7));
+    assert_eq!(table.field1_column_sum(), 11);
+
+    // Optional columns skip cells of None
+    assert_eq!(table.field2_column_min(), Some(&-5));
+    assert_eq!(table.field2_column_max(), Some(&10));
+    assert_eq!(table.field2_column_sum(), 5);
+}
+
+#[test]
+fn should_support_column_stats_over_floats() {
+    #[derive(Table)]
+    struct MyStruct {
+        #[column(stats)]
+        field1: f64,
+        #[column(stats)]
+        field2: Option<f32>,
+    }
+
+    let mut table = MyStructTable::new();
+    table.push_row(MyStruct {
+        field1: 1.5,
+        field2: Some(0.5),
+    });
+    table.push_row(MyStruct {
+        field1: 2.25,
+        field2: None,
+    });
+
+    // Floats are not Ord, so only the sums can be called
+    assert_eq!(table.field1_column_sum(), 3.75);
+    assert_eq!(table.field2_column_sum(), 0.5);
+}
+
+#[test]
+fn should_support_generating_queries() {
+    #[derive(Debug, PartialEq, Eq, Table)]
+    #[table(queries)]
+    struct MyStruct {
+        field1: u8,
+        field2: Option<String>,
+    }
+
+    let mut table = MyStructTable::new();
+    for i in 0..5 {
+        table.push_row(MyStruct {
+            field1: i,
+            field2: if i % 2 == 0 {
+                Some(i.to_string())
+            } else {
+                None
+            },
+        });
+    }
+
+    assert_eq!(table.find_first_by_field1(&3), Some(3));
+    assert_eq!(table.find_first_by_field1(&9), None);
+    assert_eq!(
+        table.find_first_by_field2(Some(&String::from("2"))),
+        Some(2)
+    );
+    assert_eq!(table.find_first_by_field2(None), Some(1));
+
+    let rows = table.filter_rows(|row| row.field2.is_some());
+    assert_eq!(
+        rows.iter().map(|row| row.field1).collect::<Vec<_>>(),
+        vec![0, 2, 4]
+    );
+
+    let page = table.rows_page(1, 2);
+    assert_eq!(
+        page,
+        vec![
+            MyStruct {
+                field1: 2,
+                field2: Some(String::from("2")),
+            },
+            MyStruct {
+                field1: 3,
+                field2: None,
+            },
+        ]
+    );
+    assert_eq!(table.rows_page(2, 2).len(), 1);
+    assert!(table.rows_page(3, 2).is_empty());
+    assert!(table.rows_page(0, 0).is_empty());
+}
+
+#[test]
+fn should_support_generating_queries_with_columns_that_are_not_partial_eq() {
+    #[derive(Clone, Debug)]
+    struct Opaque(u8);
+
+    #[derive(Table)]
+    #[table(queries)]
+    struct MyStruct {
+        field1: u8,
+        field2: Opaque,
+    }
+
+    let mut table = MyStructTable::new();
+    table.push_row(MyStruct {
+        field1: 1,
+        field2: Opaque(2),
+    });
+
+    assert_eq!(table.find_first_by_field1(&1), Some(0));
+    assert_eq!(table.filter_rows(|row| row.field2.0 == 2).len(), 1);
+}
+
+#[test]
+fn should_support_migrating_from_older_versions() {
+    use memtable_core::{DynamicTable, Table as _};
+
+    #[derive(Table)]
+    #[table(version = 3)]
+    struct MyStruct {
+        #[column(renamed_from = "field")]
+        field1: u8,
+        #[column(added_in = 2)]
+        field2: bool,
+        #[column(added_in = 3, default = "\"unknown\"")]
+        field3: String,
+    }
+
+    // Check column_by_name accepts the name prior to renaming
+    let mut table = MyStructTable::new();
+    table.push_row(MyStruct {
+        field1: 1,
+        field2: true,
+        field3: String::from("known"),
+    });
+    assert_eq!(table.column_by_name("field").unwrap().count(), 1);
+    assert_eq!(table.column_by_name("field1").unwrap().count(), 1);
+
+    // Check migrate_from_v1 populates all added columns
+    let mut old = DynamicTable::new();
+    old.push_row(vec![MyStructTableData::Field1(1)]);
+    old.push_row(vec![MyStructTableData::Field1(2)]);
+
+    let table = MyStructTable::migrate_from_v1(old);
+    assert_eq!(table.row(0), Some((&1, &false, &String::from("unknown"))));
+    assert_eq!(table.row(1), Some((&2, &false, &String::from("unknown"))));
+
+    // Check migrate_from_v2 only populates columns added after version 2
+    let mut old = DynamicTable::new();
+    old.push_row(vec![
+        MyStructTableData::Field1(3),
+        MyStructTableData::Field2(true),
+    ]);
+
+    let table = MyStructTable::migrate_from_v2(old);
+    assert_eq!(table.row(0), Some((&3, &true, &String::from("unknown"))));
+}
+
+#[test]
+fn should_support_displaying_table() {
+    #[derive(Table)]
+    #[table(display)]
+    struct MyStruct {
+        name: &'static str,
+        age: u8,
+    }
+
+    let mut table = MyStructTable::new();
+    table.push_row(MyStruct {
+        name: "alice",
+        age: 30,
+    });
+    table.push_row(MyStruct {
+        name: "bob",
+        age: 4,
+    });
+
+    // Check Display renders an aligned grid with headers
+    assert_eq!(
+        table.to_string(),
+        "name  | age\n------+----\nalice | 30\nbob   | 4"
+    );
+
+    // Check markdown renders a markdown table with headers
+    assert_eq!(
+        table.markdown().to_string(),
+        "| name  | age |\n|-------|-----|\n| alice | 30  |\n| bob   | 4   |"
+    );
+
+    // Check data is displayed using its inner value
+    assert_eq!(MyStructTableData::Age(5).to_string(), "5");
 }
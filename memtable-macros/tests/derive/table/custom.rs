@@ -0,0 +1,121 @@
+use memtable_core::{list::DynamicList, Capacity, DynamicTable, Table};
+use memtable_macros::Table;
+use std::convert::TryFrom;
+
+// Table provided by the user that records the total insertions made
+#[derive(Debug)]
+struct CountingTable<D> {
+    inner: DynamicTable<D>,
+    insert_cnt: usize,
+}
+
+// Implemented by hand as deriving would require the data to be default
+impl<D> Default for CountingTable<D> {
+    fn default() -> Self {
+        Self {
+            inner: DynamicTable::new(),
+            insert_cnt: 0,
+        }
+    }
+}
+
+impl<D> Table for CountingTable<D> {
+    type Data = D;
+    type Row = DynamicList<D>;
+    type Column = DynamicList<D>;
+
+    fn max_row_capacity(&self) -> Capacity {
+        self.inner.max_row_capacity()
+    }
+
+    fn max_column_capacity(&self) -> Capacity {
+        self.inner.max_column_capacity()
+    }
+
+    fn row_cnt(&self) -> usize {
+        self.inner.row_cnt()
+    }
+
+    fn col_cnt(&self) -> usize {
+        self.inner.col_cnt()
+    }
+
+    fn cell(&self, row: usize, col: usize) -> Option<&Self::Data> {
+        self.inner.cell(row, col)
+    }
+
+    fn mut_cell(&mut self, row: usize, col: usize) -> Option<&mut Self::Data> {
+        self.inner.mut_cell(row, col)
+    }
+
+    fn insert_cell(&mut self, row: usize, col: usize, value: Self::Data) -> Option<Self::Data> {
+        self.insert_cnt += 1;
+        self.inner.insert_cell(row, col, value)
+    }
+
+    fn remove_cell(&mut self, row: usize, col: usize) -> Option<Self::Data> {
+        self.inner.remove_cell(row, col)
+    }
+
+    fn set_preferred_row_cnt(&mut self, capacity: usize) {
+        self.inner.set_preferred_row_cnt(capacity)
+    }
+
+    fn set_preferred_col_cnt(&mut self, capacity: usize) {
+        self.inner.set_preferred_col_cnt(capacity)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Table)]
+#[table(mode(custom = "CountingTable"))]
+struct MyRow {
+    field1: bool,
+    field2: usize,
+}
+
+// Struct should support generics
+#[derive(Table)]
+#[table(mode(custom = "CountingTable"))]
+struct GenericRow<A, B> {
+    field1: A,
+    field2: B,
+}
+
+#[test]
+fn should_use_custom_table_underneath() {
+    let mut table = MyRowTable::new();
+    table.push_row(MyRow {
+        field1: false,
+        field2: 123,
+    });
+    table.push_row((true, 999));
+
+    let inner: &CountingTable<MyRowTableData> = table.as_ref();
+    assert_eq!(inner.insert_cnt, 4);
+
+    assert_eq!(table.row_cnt(), 2);
+    assert_eq!(table.col_cnt(), 2);
+    assert_eq!(table.row(0), Some((&false, &123)));
+    assert_eq!(table.row(1), Some((&true, &999)));
+}
+
+#[test]
+fn should_support_generic_structs() {
+    let mut table = GenericRowTable::<u8, &str>::new();
+    table.push_row((1, "one"));
+
+    assert_eq!(table.row_cnt(), 1);
+    assert_eq!(table.row(0), Some((&1, &"one")));
+}
+
+#[test]
+fn should_support_converting_from_custom_table() {
+    let mut inner = CountingTable::default();
+    inner.push_row(vec![
+        MyRowTableData::Field1(true),
+        MyRowTableData::Field2(5),
+    ]);
+
+    let table = MyRowTable::try_from(inner).unwrap();
+    assert_eq!(table.row(0), Some((&true, &5)));
+}
@@ -262,6 +262,39 @@ fn should_support_removing_typed_rows_from_end() {
     }
 }
 
+#[test]
+fn should_support_converting_to_and_from_vec_of_rows() {
+    let rows = vec![
+        MyRow {
+            field1: false,
+            field2: 1,
+        },
+        MyRow {
+            field1: true,
+            field2: 2,
+        },
+    ];
+
+    let table = MyRowTable::from(rows);
+    assert_eq!(table.row(0), Some((&false, &1)));
+    assert_eq!(table.row(1), Some((&true, &2)));
+
+    let table: MyRowTable = table.into_vec().into_iter().rev().collect();
+    assert_eq!(
+        table.into_vec(),
+        vec![
+            MyRow {
+                field1: true,
+                field2: 2,
+            },
+            MyRow {
+                field1: false,
+                field2: 1,
+            },
+        ]
+    );
+}
+
 #[test]
 fn should_support_retrieving_typed_columns() {
     let mut table = MyRowTable::new();
@@ -419,3 +452,72 @@ fn should_support_trying_to_convert_from_untyped_table() {
         assert!(MyRowTable::try_from(table).is_err());
     }
 }
+
+#[test]
+fn try_from_should_describe_the_first_cell_that_failed() {
+    let mut table = memtable::DynamicTable::new();
+    table.push_row(vec![
+        MyRowTableData::Field1(false),
+        MyRowTableData::Field2(123),
+    ]);
+    table.push_row(vec![
+        MyRowTableData::Field2(999),
+        MyRowTableData::Field1(true),
+    ]);
+
+    let err = match MyRowTable::try_from(table) {
+        Ok(_) => panic!("Conversion unexpectedly succeeded"),
+        Err(x) => x,
+    };
+    assert_eq!(
+        err,
+        memtable::CellError {
+            row: 1,
+            column: 0,
+            name: "field1",
+            nested_name: None,
+            expected: "bool",
+            found: Some("Field2"),
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "Cell at row 1, column 0 (field1) has variant Field2, but expected bool"
+    );
+}
+
+#[test]
+fn try_from_report_should_collect_every_cell_that_failed() {
+    let mut table = memtable::DynamicTable::new();
+    table.push_row(vec![
+        MyRowTableData::Field1(false),
+        MyRowTableData::Field2(123),
+    ]);
+    table.push_row(vec![MyRowTableData::Field2(999)]);
+    table.insert_cell(2, 0, MyRowTableData::Field1(true));
+
+    let errors = match MyRowTable::try_from_report(table) {
+        Ok(_) => panic!("Conversion unexpectedly succeeded"),
+        Err(x) => x,
+    };
+    let errors: Vec<_> = errors
+        .into_iter()
+        .map(|err| (err.row, err.name, err.found))
+        .collect();
+    assert_eq!(
+        errors,
+        vec![
+            (1, "field1", Some("Field2")),
+            (1, "field2", None),
+            (2, "field2", None),
+        ]
+    );
+
+    let mut table = memtable::DynamicTable::new();
+    table.push_row(vec![
+        MyRowTableData::Field1(false),
+        MyRowTableData::Field2(123),
+    ]);
+    let table = MyRowTable::try_from_report(table).unwrap();
+    assert_eq!(table.field2(0), Some(&123));
+}
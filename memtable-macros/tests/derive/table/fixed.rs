@@ -262,6 +262,61 @@ fn should_support_removing_typed_rows_from_end() {
     }
 }
 
+#[test]
+fn should_support_converting_to_and_from_vec_of_rows() {
+    let rows = vec![
+        MyRow {
+            field1: false,
+            field2: 1,
+        },
+        MyRow {
+            field1: true,
+            field2: 2,
+        },
+    ];
+
+    let table = MyRowTable::try_from(rows).unwrap();
+    assert_eq!(table.row(0), Some((&false, &1)));
+    assert_eq!(table.row(1), Some((&true, &2)));
+
+    let table: MyRowTable = table.into_vec().into_iter().rev().collect();
+    assert_eq!(
+        table.into_vec(),
+        vec![
+            MyRow {
+                field1: true,
+                field2: 2,
+            },
+            MyRow {
+                field1: false,
+                field2: 1,
+            },
+        ]
+    );
+}
+
+#[test]
+fn should_fail_converting_from_vec_with_more_rows_than_capacity() {
+    let rows: Vec<MyRow> = (0..124)
+        .map(|i| MyRow {
+            field1: false,
+            field2: i,
+        })
+        .collect();
+
+    let err = match MyRowTable::try_from(rows) {
+        Ok(_) => panic!("Conversion unexpectedly succeeded"),
+        Err(x) => x,
+    };
+    assert_eq!(
+        err,
+        memtable_core::ShapeError::ExceedsCapacity {
+            capacity: memtable_core::Capacity::Limited(123),
+            required: 124,
+        }
+    );
+}
+
 #[test]
 fn should_support_retrieving_typed_columns() {
     let mut table = MyRowTable::new();
@@ -262,6 +262,39 @@ fn should_support_removing_typed_rows_from_end() {
     }
 }
 
+#[test]
+fn should_support_converting_to_and_from_vec_of_rows() {
+    let rows = vec![
+        MyRow {
+            field1: false,
+            field2: 1,
+        },
+        MyRow {
+            field1: true,
+            field2: 2,
+        },
+    ];
+
+    let table = MyRowTable::from(rows);
+    assert_eq!(table.row(0), Some((&false, &1)));
+    assert_eq!(table.row(1), Some((&true, &2)));
+
+    let table: MyRowTable = table.into_vec().into_iter().rev().collect();
+    assert_eq!(
+        table.into_vec(),
+        vec![
+            MyRow {
+                field1: true,
+                field2: 2,
+            },
+            MyRow {
+                field1: false,
+                field2: 1,
+            },
+        ]
+    );
+}
+
 #[test]
 fn should_support_retrieving_typed_columns() {
     let mut table = MyRowTable::new();
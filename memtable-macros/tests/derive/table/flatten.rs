@@ -0,0 +1,175 @@
+use memtable_core::{FlattenColumns, Table};
+use memtable_macros::Table;
+use std::convert::TryFrom;
+
+#[derive(Clone, Debug, PartialEq, Eq, Table)]
+#[table(data(derive(Clone, Debug, PartialEq, Eq)))]
+struct Address {
+    street: String,
+    zip: Option<u32>,
+}
+
+#[derive(Debug, PartialEq, Eq, Table)]
+#[table(data(derive(Clone, Debug, PartialEq, Eq)))]
+struct User {
+    name: String,
+    #[column(flatten)]
+    address: Address,
+    age: u8,
+}
+
+fn make_user(name: &str, street: &str, zip: Option<u32>, age: u8) -> User {
+    User {
+        name: name.to_string(),
+        address: Address {
+            street: street.to_string(),
+            zip,
+        },
+        age,
+    }
+}
+
+#[test]
+fn should_implement_flatten_columns_for_derived_structs() {
+    assert_eq!(Address::COLUMN_CNT, 2);
+    assert_eq!(Address::COLUMN_NAMES, &["street", "zip"]);
+
+    let address = Address {
+        street: String::from("Main St"),
+        zip: None,
+    };
+    let cells: Vec<_> = address.clone().into_cells().collect();
+    assert_eq!(
+        cells,
+        vec![
+            AddressTableData::Street(String::from("Main St")),
+            AddressTableData::Zip(None),
+        ]
+    );
+    assert_eq!(
+        Address::from_cells(cells.into_iter().map(Some)),
+        Some(address)
+    );
+    assert_eq!(Address::from_cells(vec![None, None].into_iter()), None);
+}
+
+#[test]
+fn should_expand_flattened_columns() {
+    let mut table = UserTable::new();
+    table.push_row(make_user("alice", "Main St", Some(12345), 30));
+
+    assert_eq!(table.col_cnt(), 4);
+    assert_eq!(
+        table.cell(0, 1),
+        Some(&UserTableData::Address(AddressTableData::Street(
+            String::from("Main St")
+        )))
+    );
+    assert_eq!(table.cell(0, 3), Some(&UserTableData::Age(30)));
+    assert_eq!(table.age(0), Some(&30));
+    assert_eq!(
+        table.address(0),
+        Some(Address {
+            street: String::from("Main St"),
+            zip: Some(12345),
+        })
+    );
+}
+
+#[test]
+fn should_rebuild_flattened_columns_in_rows() {
+    let mut table = UserTable::new();
+    table.push_row(make_user("alice", "Main St", Some(12345), 30));
+    table.push_row(make_user("bob", "Side St", None, 4));
+
+    let (name, address, age) = table.row(1).unwrap();
+    assert_eq!(name, "bob");
+    assert_eq!(address.street, "Side St");
+    assert_eq!(age, &4);
+    assert_eq!(table.rows().count(), 2);
+
+    assert_eq!(
+        table.remove_row(0),
+        Some(make_user("alice", "Main St", Some(12345), 30))
+    );
+    assert_eq!(table.row_cnt(), 1);
+    assert_eq!(table.name(0).map(String::as_str), Some("bob"));
+}
+
+#[test]
+fn should_replace_flattened_columns() {
+    let mut table = UserTable::new();
+    table.push_row(make_user("alice", "Main St", Some(12345), 30));
+
+    let old = table.replace_address(
+        0,
+        Address {
+            street: String::from("Side St"),
+            zip: None,
+        },
+    );
+    assert_eq!(old.map(|x| x.street), Some(String::from("Main St")));
+    assert_eq!(
+        table.address(0).map(|x| x.street),
+        Some(String::from("Side St"))
+    );
+    assert_eq!(table.replace_address(1, table.address(0).unwrap()), None);
+}
+
+#[test]
+fn should_retrieve_flattened_columns_by_prefixed_name() {
+    let mut table = UserTable::new();
+    table.push_row(make_user("alice", "Main St", Some(12345), 30));
+
+    assert_eq!(
+        table.column_by_name("address_zip").unwrap().next(),
+        Some(&UserTableData::Address(AddressTableData::Zip(Some(12345))))
+    );
+    assert_eq!(
+        table.column_by_name("age").unwrap().next(),
+        Some(&UserTableData::Age(30))
+    );
+    assert!(table.column_by_name("address").is_none());
+    assert!(table.column_by_name("address_city").is_none());
+}
+
+#[test]
+fn should_validate_flattened_columns_when_converting_from_inner_table() {
+    let mut table = UserTable::new();
+    table.push_row(make_user("alice", "Main St", Some(12345), 30));
+    assert!(UserTable::try_from(table.0.clone()).is_ok());
+
+    let mut inner = table.0;
+    inner.insert_cell(0, 2, UserTableData::Age(1));
+    let err = match UserTable::try_from(inner) {
+        Ok(_) => panic!("Conversion unexpectedly succeeded"),
+        Err(x) => x,
+    };
+    assert_eq!(err.column, 2);
+    assert_eq!(err.name, "address");
+    assert_eq!(err.nested_name, Some("zip"));
+    assert_eq!(err.found, Some("Age"));
+}
+
+#[test]
+fn should_support_flattening_into_fixed_column_tables() {
+    #[derive(Table)]
+    #[table(mode = "fixed_column", data(derive(Clone)))]
+    struct Point {
+        x: u8,
+        y: u8,
+    }
+
+    #[derive(Table)]
+    #[table(mode = "fixed_column")]
+    struct Shape(String, #[column(flatten)] Point);
+
+    let mut table = ShapeTable::new();
+    table.push_row(Shape(String::from("dot"), Point { x: 1, y: 2 }));
+
+    assert_eq!(table.col_cnt(), 3);
+    assert_eq!(
+        table.row(0).map(|(_, point)| (point.x, point.y)),
+        Some((1, 2))
+    );
+}
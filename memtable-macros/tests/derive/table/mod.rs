@@ -1,5 +1,8 @@
 mod attributes;
+mod custom;
 mod dynamic;
 mod fixed;
 mod fixed_column;
+mod flatten;
+mod optional_columns;
 mod tuple_structs;
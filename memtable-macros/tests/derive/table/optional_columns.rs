@@ -0,0 +1,90 @@
+use memtable_core::Table;
+use memtable_macros::Table;
+
+#[derive(Debug, PartialEq, Eq, Table)]
+#[table(display)]
+struct MyStruct {
+    #[column(indexed)]
+    name: Option<String>,
+    age: u8,
+}
+
+fn make_table() -> MyStructTable {
+    let mut table = MyStructTable::new();
+    table.push_row(MyStruct {
+        name: Some(String::from("alice")),
+        age: 30,
+    });
+    table.push_row(MyStruct { name: None, age: 4 });
+    table
+}
+
+#[test]
+fn should_flatten_optional_cells() {
+    let table = make_table();
+    assert_eq!(table.name(0).map(String::as_str), Some("alice"));
+    assert_eq!(table.name(1), None);
+    assert_eq!(table.age(1), Some(&4));
+}
+
+#[test]
+fn should_yield_every_row_of_optional_columns() {
+    let mut table = make_table();
+    assert_eq!(
+        table.name_column().collect::<Vec<_>>(),
+        vec![Some(&String::from("alice")), None]
+    );
+
+    // A missing cell is yielded as None rather than ending the column
+    Table::remove_cell(&mut table, 0, 0);
+    assert_eq!(table.name_column().collect::<Vec<_>>(), vec![None, None]);
+    assert_eq!(
+        table.into_name_column().collect::<Vec<_>>(),
+        vec![None, None]
+    );
+}
+
+#[test]
+fn should_treat_missing_optional_cells_as_none_in_rows() {
+    let mut table = make_table();
+    Table::remove_cell(&mut table, 0, 0);
+
+    assert_eq!(table.row(0), Some((None, &30)));
+    assert_eq!(
+        table.rows().collect::<Vec<_>>(),
+        vec![(None, &30), (None, &4)]
+    );
+    assert_eq!(
+        table.remove_row(0),
+        Some(MyStruct {
+            name: None,
+            age: 30
+        })
+    );
+    assert_eq!(table.row(0), Some((None, &4)));
+}
+
+#[test]
+fn should_compare_optional_indexed_columns_when_upserting() {
+    let mut table = make_table();
+
+    let old = table.upsert_by_name(MyStruct { name: None, age: 5 });
+    assert_eq!(old, Some(MyStruct { name: None, age: 4 }));
+    assert_eq!(table.row(1), Some((None, &5)));
+
+    let old = table.upsert_by_name(MyStruct {
+        name: Some(String::from("bob")),
+        age: 6,
+    });
+    assert_eq!(old, None);
+    assert_eq!(table.row_cnt(), 3);
+}
+
+#[test]
+fn should_display_optional_cells_as_blank() {
+    let table = make_table();
+    assert_eq!(
+        table.to_string(),
+        "name  | age\n------+----\nalice | 30\n      | 4"
+    );
+}
@@ -34,6 +34,20 @@ fn should_support_tuple_structs_with_lifetimes() {
     table.push_row(MyTuple(&data.0, &data.1));
 }
 
+#[test]
+fn should_support_indexing_columns_of_tuple_structs() {
+    #[derive(Table)]
+    struct MyTuple<A, B>(#[column(indexed)] A, B);
+
+    let mut table = MyTupleTable::new();
+    table.push_row(MyTuple("a", 1));
+
+    assert_eq!(table.upsert_by_0(MyTuple("a", 2)).map(|x| x.1), Some(1));
+    assert!(table.upsert_by_0(MyTuple("b", 3)).is_none());
+    assert_eq!(table.row(0), Some((&"a", &2)));
+    assert_eq!(table.row(1), Some((&"b", &3)));
+}
+
 #[test]
 fn should_support_retrieving_column_names() {
     assert_eq!(MyTupleTable::COLUMN_NAMES, &["0", "1"]);
@@ -184,7 +198,7 @@ fn should_support_removing_typed_rows() {
     table.push_row((false, 5));
 
     // Remove from the beginning
-    assert_eq!(table.remove_row(0), Some(MyTuple { 0: false, 1: 1 }));
+    assert_eq!(table.remove_row(0), Some(MyTuple(false, 1)));
     {
         let mut rows = table.rows();
         assert_eq!(rows.next(), Some((&false, &2)));
@@ -197,7 +211,7 @@ fn should_support_removing_typed_rows() {
     // Remove from the end
     assert_eq!(
         table.remove_row(table.row_cnt() - 1),
-        Some(MyTuple { 0: false, 1: 5 })
+        Some(MyTuple(false, 5))
     );
     {
         let mut rows = table.rows();
@@ -210,7 +224,7 @@ fn should_support_removing_typed_rows() {
     // Remove from the middle
     assert_eq!(
         table.remove_row(table.row_cnt() / 2),
-        Some(MyTuple { 0: false, 1: 3 })
+        Some(MyTuple(false, 3))
     );
     {
         let mut rows = table.rows();
@@ -236,7 +250,7 @@ fn should_support_removing_typed_rows_from_end() {
     table.push_row((false, 5));
 
     // Remove from the end
-    assert_eq!(table.pop_row(), Some(MyTuple { 0: false, 1: 5 }));
+    assert_eq!(table.pop_row(), Some(MyTuple(false, 5)));
     {
         let mut rows = table.rows();
         assert_eq!(rows.next(), Some((&false, &1)));
@@ -3,6 +3,8 @@
 //       macros produce code with proper pathing; no tests are needed here as
 //       this is purely validating that the macros are hygienic via compilation
 
+mod shadowed;
+
 // Struct should be supported with all primitive types
 #[derive(::memtable_macros::Table)]
 struct MyRow {
@@ -57,41 +59,85 @@ struct Fixed {
     field3: ::std::primitive::bool,
 }
 
+#[derive(::memtable_macros::Table)]
+#[table(mode(custom = "::memtable_core::DynamicTable"))]
+struct Custom {
+    field1: ::std::string::String,
+    field2: ::std::primitive::usize,
+    field3: ::std::primitive::bool,
+}
+
+// Support migrating from older versions
+#[derive(::memtable_macros::Table)]
+#[table(version = 2)]
+struct Versioned {
+    #[column(renamed_from = "field")]
+    field1: ::std::string::String,
+    #[column(added_in = 2, default = "123usize")]
+    field2: ::std::primitive::usize,
+}
+
+// Support displaying tables
+#[derive(::memtable_macros::Table)]
+#[table(display)]
+struct Displayed<A> {
+    field1: ::std::string::String,
+    field2: A,
+}
+
+// Support optional columns
+#[derive(::memtable_macros::Table)]
+#[table(display)]
+struct Optional {
+    #[column(indexed)]
+    field1: ::std::option::Option<::std::string::String>,
+    field2: ::std::primitive::usize,
+}
+
+// Support flattening columns
+#[derive(::memtable_macros::Table)]
+#[table(mode = "fixed_column")]
+struct Flattened {
+    field1: ::std::primitive::usize,
+    #[column(flatten)]
+    field2: FixedColumn,
+}
+
 // These traits exist to make sure we properly import using
 // ::std::primitive::<TYPE> instead of purely <TYPE>
 //
 // Only works for Rust 1.43.0+
-#[allow(non_camel_case_types)]
+#[allow(non_camel_case_types, dead_code)]
 trait bool {}
-#[allow(non_camel_case_types)]
+#[allow(non_camel_case_types, dead_code)]
 trait char {}
-#[allow(non_camel_case_types)]
+#[allow(non_camel_case_types, dead_code)]
 trait f32 {}
-#[allow(non_camel_case_types)]
+#[allow(non_camel_case_types, dead_code)]
 trait f64 {}
-#[allow(non_camel_case_types)]
+#[allow(non_camel_case_types, dead_code)]
 trait i128 {}
-#[allow(non_camel_case_types)]
+#[allow(non_camel_case_types, dead_code)]
 trait i16 {}
-#[allow(non_camel_case_types)]
+#[allow(non_camel_case_types, dead_code)]
 trait i32 {}
-#[allow(non_camel_case_types)]
+#[allow(non_camel_case_types, dead_code)]
 trait i64 {}
-#[allow(non_camel_case_types)]
+#[allow(non_camel_case_types, dead_code)]
 trait i8 {}
-#[allow(non_camel_case_types)]
+#[allow(non_camel_case_types, dead_code)]
 trait isize {}
-#[allow(non_camel_case_types)]
+#[allow(non_camel_case_types, dead_code)]
 trait str {}
-#[allow(non_camel_case_types)]
+#[allow(non_camel_case_types, dead_code)]
 trait u128 {}
-#[allow(non_camel_case_types)]
+#[allow(non_camel_case_types, dead_code)]
 trait u16 {}
-#[allow(non_camel_case_types)]
+#[allow(non_camel_case_types, dead_code)]
 trait u32 {}
-#[allow(non_camel_case_types)]
+#[allow(non_camel_case_types, dead_code)]
 trait u64 {}
-#[allow(non_camel_case_types)]
+#[allow(non_camel_case_types, dead_code)]
 trait u8 {}
-#[allow(non_camel_case_types)]
+#[allow(non_camel_case_types, dead_code)]
 trait usize {}
@@ -0,0 +1,97 @@
+// NOTE: This file exists to validate that the macros produce code that is
+//       unaffected by user items sharing names with those that the code
+//       refers to; like its parent, this is purely validated via compilation
+#![allow(dead_code)]
+
+// Types and traits named after those used by the generated code
+struct Option<T>(T);
+struct Result<T, E>(T, E);
+struct Vec;
+struct String;
+struct Cell;
+struct Position;
+struct Capacity;
+struct CellError;
+struct DynamicTable;
+trait Table {}
+trait FlattenColumns {}
+trait Default {}
+trait Clone {}
+trait PartialEq {}
+trait Iterator {}
+trait IntoIterator {}
+trait FromIterator {}
+trait From {}
+trait Display {}
+
+// Method calls to `into` are ambiguous whenever a trait like this is in scope
+trait Into: ::core::marker::Sized {
+    fn into(self) -> Self;
+}
+
+impl<T> Into for T {
+    fn into(self) -> Self {
+        self
+    }
+}
+
+// Modules named after the crates referenced by the generated code
+mod core {}
+mod memtable {}
+
+#[derive(::memtable_macros::Table)]
+#[table(display, queries)]
+struct Shadowed<A> {
+    #[column(indexed, stats)]
+    field1: ::std::primitive::usize,
+    #[column(stats)]
+    field2: ::std::option::Option<::std::primitive::u32>,
+    field3: A,
+}
+
+#[derive(::memtable_macros::Table)]
+#[table(mode = "fixed_column")]
+struct ShadowedFlattened {
+    field1: ::std::string::String,
+    #[column(flatten)]
+    field2: Nested,
+}
+
+#[derive(::memtable_macros::Table)]
+#[table(mode = "fixed_column", version = 2)]
+struct ShadowedMigrated {
+    #[column(renamed_from = "field")]
+    field1: ::std::string::String,
+    #[column(added_in = 2)]
+    field2: ::std::primitive::bool,
+}
+
+#[derive(::memtable_macros::Table)]
+#[table(mode = "fixed_column")]
+struct Nested {
+    field1: ::std::primitive::u8,
+    field2: ::std::primitive::u8,
+}
+
+// Structs named after the types used by the generated code
+mod named {
+    #[derive(::memtable_macros::Table)]
+    pub struct Cell {
+        field1: ::std::primitive::usize,
+    }
+
+    #[derive(::memtable_macros::Table)]
+    #[table(display, queries)]
+    pub struct Table {
+        #[column(indexed)]
+        field1: ::std::primitive::usize,
+    }
+
+    #[derive(::memtable_macros::Table)]
+    pub struct Option {
+        field1: ::std::option::Option<::std::primitive::usize>,
+    }
+
+    #[derive(::memtable_macros::Table)]
+    pub struct Result(::std::primitive::usize, ::std::primitive::bool);
+}
@@ -73,6 +73,19 @@
 //! assert_eq!(table[(1, 2)], 999);
 //! ```
 //!
+//! Tables can also be written inline using the `table!` macro:
+//!
+//! ```rust
+//! use memtable::{prelude::*, table};
+//!
+//! let table = table![[1, 2, 3], [4, 5, 6]];
+//! assert_eq!(table[(1, 2)], 6);
+//!
+//! // Comma-separated values produce a table of strings
+//! let table = table!(csv: "name,age\nalice,30");
+//! assert_eq!(table[(1, 0)], "alice");
+//! ```
+//!
 //! ## The Tables
 //!
 //! In the core library, you will find four primary tables:
@@ -82,37 +95,138 @@
 //! - [`FixedRowTable`]: table with a fixed capacity for rows & dynamic capacity for columns
 //! - [`FixedColumnTable`]: table with a dynamic capacity for rows & fixed capacity for columns
 //!
+//! Each of these can be indexed by `table[(row, col)]`. The three fixed tables
+//! can also be indexed by `table[row]`, returning the row's cells as a slice
+//! that can be further indexed by column. [`DynamicTable`] does not store its
+//! cells by row, so it only supports `table[(row, col)]`.
+//!
+//! ```rust
+//! use memtable::prelude::*;
+//!
+//! let mut table = FixedTable::from([[1, 2, 3], [4, 5, 6]]);
+//! assert_eq!(table[1], [4, 5, 6]);
+//!
+//! table[1][2] = 999;
+//! assert_eq!(table[(1, 2)], 999);
+//! ```
+//!
+//! Additionally, [`CowTable`] provides a dynamic capacity for rows & columns
+//! while supporting cheap, read-only snapshots of itself via
+//! [`CowTable::snapshot`], and [`SmallTable`] stores up to a fixed number of
+//! cells inline before spilling onto the heap, avoiding allocations for many
+//! tiny tables.
+//!
 //! ## The Traits
 //!
 //! - [`Table`]: primary trait that exposes majority of common operations to
-//!              perform on tables
+//!   perform on tables, which is also implemented for `&mut T` and `Box<T>`
+//!   of any table `T`
+//! - [`DynTable`]: object-safe subset of [`Table`] implemented for every
+//!   table, allowing tables of different types to be handed around as
+//!   `Box<dyn DynTable<Data = D>>`, which is itself a [`Table`]
 //! - [`iter::CellIter`]: common trait that table iterators focused on
-//!                       individual cells that enables zipping with a cell's
-//!                       position and getting the current row & column of
-//!                       the iterator
+//!   individual cells that enables zipping with a cell's position and getting
+//!   the current row & column of the iterator
 //!
 //! ## The Extra Features
 //!
 //! Alongside the essentials, the library also provides several features that
 //! provide extensions to the table arsenal:
 //!
+//! - **a1**: enables [`exts::a1::A1`] and [`exts::a1::A1Range`], which
+//!   convert between spreadsheet-style references like `B7` and `A1:C10` and
+//!   positions within a table
 //! - **alloc**: opts into the alloc crate in the situation that `no_std` is
-//!              in effect
+//!   in effect
+//! - **allocator-api**: enables [`exts::allocator_api::AllocDynamicTable`],
+//!   [`exts::allocator_api::AllocFixedColumnTable`], and
+//!   [`exts::allocator_api::AllocFixedRowTable`], tables whose cells are
+//!   allocated with a custom `Allocator` such as a pool; requires a nightly
+//!   compiler for the unstable `allocator_api`
+//! - **bumpalo**: enables [`exts::bumpalo::DynamicTableIn`], a dynamic table
+//!   whose cells are allocated within a `bumpalo::Bump` arena, so short-lived
+//!   tables avoid per-cell allocator churn
+//! - **crdt**: enables [`exts::crdt::LwwTable`], a table of last-writer-wins
+//!   cells that can be edited independently on several replicas and merged
+//!   without conflicts
 //! - **csv**: enables CSV support and
 //!     - [`exts::csv::FromCsv`]: convert CSV into an inmemory table
 //!     - [`exts::csv::ToCsv`]: convert an inmemory table to CSV
+//!     - [`exts::csv::FromCsvInferred`]: convert CSV into a table of typed
+//!       values, inferring column types and whether there is a header row
 //! - **cell**: enables [`exts::cell::Cell2`] and more up to
-//!             [`exts::cell::Cell26`], which represent generic enums that can
-//!             be used as the data type for a table to enable multiple data
-//!             types within a table (e.g. `DynamicTable<Cell2<String, bool>>`)
+//!   [`exts::cell::Cell26`], which represent generic enums that can be used as
+//!   the data type for a table to enable multiple data types within a table
+//!   (e.g. `DynamicTable<Cell2<String, bool>>`)
+//! - **datafusion**: enables [`exts::datafusion::ToRecordBatch`], which
+//!   converts a table into an arrow record batch or a DataFusion
+//!   `TableProvider` so it can be registered and queried with sql; requires
+//!   Rust 1.88 or newer
+//! - **formula**: enables [`exts::formula::FormulaTable`], a spreadsheet-like
+//!   table of numbers where cells can hold arithmetic formulas referencing
+//!   other cells that are recalculated whenever those cells change
+//! - **http**: enables [`exts::http::FromHttpBody`] (load a table from a csv or
+//!   json response body) and [`exts::http::ToHttpBody`] (stream a table as csv
+//!   or json chunks, one per row, for use as a response body in frameworks
+//!   such as axum and warp); the chunks implement `futures_core::Stream`,
+//!   while sending requests and building responses is left to the http client
+//!   and framework
+//! - **image**: enables conversions between tables and images
+//!     - [`exts::image::ToImage`]: convert a table of pixels or intensities to
+//!       an image
+//!     - [`exts::image::FromImage`]: convert an image to a table
 //! - **macros**: enables [`macro@Table`] macro to derive new struct that
-//!               implements the [`Table`] trait to be able to store some
-//!               struct into a dedicated, inmemory table
-//! - **serde**: enables *serde* support on all table & cell implementations
+//!   implements the [`Table`] trait to be able to store some struct into a
+//!   dedicated, inmemory table
+//! - **mmap**: enables [`exts::mmap::MmapTable`], which memory-maps a file
+//!   holding a dense table of plain-old-data cells (via bytemuck) to read
+//!   datasets larger than available memory without copying them
+//! - **nalgebra**: enables conversions between [`FixedTable`] and nalgebra's
+//!   `SMatrix` as well as between [`DynamicTable`] and nalgebra's `DMatrix`
+//! - **proto**: enables [`exts::proto::ToProto`] and
+//!   [`exts::proto::FromProto`], which encode and decode tables via prost
+//!   using the canonical protobuf schema found in [`exts::proto::SCHEMA`]
+//! - **rand**: enables [`exts::rand::RandomTable`] to create a table filled
+//!   with random data as well as random sampling of [`exts::cell::Cell2`]
+//!   through [`exts::cell::Cell26`]
+//! - **rayon**: enables building a [`DynamicTable`] from a parallel iterator,
+//!   where shards of cells are built on multiple threads and then merged,
+//!   along with `DynamicTable::from_csv_par` when **csv** is also enabled
+//! - **regex**: enables regular expression support and
+//!     - [`exts::regex::SearchRegex`]: find matches and their captured groups
+//!       within a table
+//!     - [`exts::regex::ReplaceRegex`]: replace matches within a table in place
+//! - **serde**: enables *serde* support on all table & cell implementations,
+//!   along with `dedup` (opt-in encoding that writes each distinct value once)
 //! - **sled**:  enables [`exts::sled::SledTable`], which provides persistent
-//!              storage on top of other tables via the sled database
+//!   storage on top of other tables via the sled database
+//! - **sled-next**: same as **sled**, but built against the upcoming sled 1.0
+//!   series instead of 0.34; takes precedence if both are enabled
+//! - **sql**: enables [`exts::sql::QuerySql`], which runs a basic SELECT
+//!   statement (projection, WHERE with comparisons, ORDER BY, and LIMIT) over
+//!   a table whose first row names its columns, returning a new
+//!   [`DynamicTable`]
+//! - **test-util**: enables [`assert_table_eq!`] and [`assert_table_matches!`],
+//!   which compare tables within tests and list every mismatched cell on
+//!   failure
+//! - **tokio**: enables [`exts::tokio::TableHandle`], which owns a table
+//!   within a dedicated tokio task and lets other async tasks get, insert,
+//!   push rows, and run queries against it through a channel rather than a
+//!   lock
+//! - **tracing**: emits *tracing* spans and events for expensive operations
+//!   such as row and column shifts, CSV parsing, and sled reloads and flushes,
+//!   with cell counts as fields
+//! - **ui**: enables [`exts::ui::TableModel`], which wraps a table to provide
+//!   the row count, column count, and cached cell text expected by grid
+//!   widgets such as egui's table and tui-rs's `Table`
+//! - **web**: enables [`exts::web::WebTable`], which provides persistent
+//!   storage on top of other tables via browser storage such as
+//!   `localStorage` so data survives page reloads in WASM applications
+//! - **web-indexeddb**: enables [`exts::web::IndexedDbStorage`], which lets a
+//!   [`exts::web::WebTable`] be stored within IndexedDB for tables too large
+//!   for `localStorage`
 //! - **std**: *(enabled by default)* opts into the std library; if removed
-//!            then `no_std` is enabled
+//!   then `no_std` is enabled
 //!
 //! ## The Macros
 //!
@@ -158,6 +272,12 @@
 //! # }
 //! ```
 //!
+//! The data enum derived alongside the table (`UserTableData` above)
+//! implements `Clone`, `PartialEq`, `Eq`, and `Hash` whenever every field's
+//! type does. This conflicts with impls of those traits written by hand, so
+//! tables that have them need `#[table(data(skip_auto_derive))]`, which turns
+//! the automatic impls off.
+//!
 //! ## The License
 //!
 //! <sup>
@@ -191,5 +311,48 @@ pub use memtable_core::*;
 #[cfg(feature = "macros")]
 pub use memtable_macros::*;
 
+/// Creates a [`DynamicTable`] inline, either from rows of cells or from a
+/// literal of comma-separated values
+///
+/// Rows of cells can be ragged, in which case the end of any row shorter
+/// than the longest row is missing. Comma-separated values produce a table
+/// of strings using the table's `TryFrom<&str>` impl, panicking if the rows
+/// are ragged
+///
+/// ### Examples
+///
+/// ```
+/// use memtable::{prelude::*, table};
+///
+/// let table = table![[1, 2, 3], [4, 5, 6]];
+/// assert_eq!(table, [[1, 2, 3], [4, 5, 6]]);
+///
+/// let table = table!(csv: "name,age\nalice,30");
+/// assert_eq!(table, [["name", "age"], ["alice", "30"]]);
+/// ```
+#[cfg(any(feature = "alloc", feature = "std"))]
+#[cfg_attr(feature = "docs", doc(cfg(any(alloc, std))))]
+#[macro_export]
+macro_rules! table {
+    (csv: $text:expr $(,)?) => {
+        match <$crate::DynamicTable<$crate::__private::String> as ::core::convert::TryFrom<
+            &::core::primitive::str,
+        >>::try_from($text)
+        {
+            ::core::result::Result::Ok(table) => table,
+            ::core::result::Result::Err(x) => ::core::panic!("Invalid csv literal: {}", x),
+        }
+    };
+    ($([$($cell:expr),* $(,)?]),* $(,)?) => {{
+        let mut rows = $crate::__private::Vec::new();
+        $(
+            let mut row = $crate::__private::Vec::new();
+            $(row.push($cell);)*
+            rows.push(row);
+        )*
+        $crate::DynamicTable::from(rows)
+    }};
+}
+
 #[cfg(all(doctest, feature = "macros", any(feature = "alloc", feature = "std")))]
 doc_comment::doctest!("../README.md");
@@ -30,3 +30,18 @@ fn fixed_column_table() {
     table.push_row([7, 8, 9].iter().copied());
     assert_eq!(table, [[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
 }
+
+#[test]
+fn table_macro() {
+    let table = memtable::table![[1, 2, 3], [4], []];
+    assert_eq!(table.row_cnt(), 3);
+    assert_eq!(table.col_cnt(), 3);
+    assert_eq!(table.cell(1, 0), Some(&4));
+    assert_eq!(table.cell(1, 1), None);
+
+    let table = memtable::table!(csv: "
+        a, b
+        1, 2
+    ");
+    assert_eq!(table, [["a", "b"], ["1", "2"]]);
+}